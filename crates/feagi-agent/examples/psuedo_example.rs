@@ -59,6 +59,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             registration_deadline_ms: None,
         },
         sensory_rate_negotiation: None,
+        supported_compression: Vec::new(),
+        supports_xyzp_delta: false,
     };
 
     let mut agent = TokioEmbodimentAgent::new_connect_and_register(
@@ -16,6 +16,7 @@ use feagi_io::traits_and_enums::client::FeagiClientRequesterProperties;
 use feagi_io::traits_and_enums::client::{FeagiClientPusher, FeagiClientSubscriber};
 use feagi_io::traits_and_enums::shared::FeagiEndpointState;
 use feagi_sensorimotor::ConnectorCache;
+use feagi_serialization::{PayloadCompression, PayloadCompressor};
 
 use crate::clients::{
     NowMs, SessionAction, SessionEvent, SessionInit, SessionPhase, SessionStateMachine,
@@ -47,6 +48,12 @@ pub struct TokioDriverConfig {
     pub timing: SessionTimingConfig,
     /// Optional sensory-rate negotiation policy applied after registration.
     pub sensory_rate_negotiation: Option<SensoryRateNegotiationConfig>,
+    /// Payload compression algorithms advertised during registration.
+    ///
+    /// Empty (the default) keeps every data stream uncompressed, whatever FEAGI is configured for.
+    pub supported_compression: Vec<PayloadCompression>,
+    /// Advertise that delta-coded XYZP motor frames can be decoded (off keeps full frames).
+    pub supports_xyzp_delta: bool,
 }
 
 /// Tokio adapter over the runtime-agnostic session state machine.
@@ -59,6 +66,7 @@ pub struct TokioEmbodimentAgent {
 
     control: crate::clients::CommandControlAgent,
     sensor_pusher: Option<Box<dyn FeagiClientPusher>>,
+    sensor_compressor: PayloadCompressor,
     motor_subscriber: Option<Box<dyn FeagiClientSubscriber>>,
 
     embodiment: ConnectorCache,
@@ -84,6 +92,8 @@ impl TokioEmbodimentAgent {
             agent_descriptor,
             auth_token,
             requested_capabilities,
+            supported_compression: driver.supported_compression.clone(),
+            supports_xyzp_delta: driver.supports_xyzp_delta,
             timing: driver.timing.clone(),
         };
         Self {
@@ -92,6 +102,7 @@ impl TokioEmbodimentAgent {
             base: Instant::now(),
             control: crate::clients::CommandControlAgent::new(registration_endpoint),
             sensor_pusher: None,
+            sensor_compressor: PayloadCompressor::disabled(),
             motor_subscriber: None,
             embodiment: ConnectorCache::new(),
             effective_sensory_rate_hz: None,
@@ -181,10 +192,12 @@ impl TokioEmbodimentAgent {
         &mut self.embodiment
     }
 
-    /// Drive one tick of session maintenance (poll + step + execute actions).
+    /// Drive one tick of session maintenance (poll + step + execute actions), and let time based
+    /// motor pipeline stages (ramping, watchdogs) react to time passing without new motor data.
     pub fn tick(&mut self) -> Result<(), FeagiAgentError> {
         let actions = self.poll_and_step()?;
         self.execute_actions(&actions)?;
+        self.tick_motor_pipelines()?;
         Ok(())
     }
 
@@ -249,7 +262,7 @@ impl TokioEmbodimentAgent {
         sensors.encode_neurons_to_bytes()?;
         let bytes = sensors.get_feagi_byte_container_mut();
         bytes.set_agent_identifier(session_id)?;
-        pusher.publish_data(bytes.get_byte_ref())?;
+        pusher.publish_data(self.sensor_compressor.compress(bytes.get_byte_ref())?)?;
         self.last_sensor_payload_sent_at = Some(now);
         Ok(())
     }
@@ -275,6 +288,8 @@ impl TokioEmbodimentAgent {
             if self.poll_and_decode_motor_once()? {
                 return Ok(());
             }
+            // Watchdogs must still trip while FEAGI is silent
+            self.tick_motor_pipelines()?;
             tokio::time::sleep(self.driver.poll_interval).await;
         }
     }
@@ -387,7 +402,7 @@ impl TokioEmbodimentAgent {
                 let mut motor_cache = self.embodiment.get_motor_cache();
                 motor_cache
                     .get_feagi_byte_container_mut()
-                    .try_write_data_by_copy_and_verify(&payload)?;
+                    .try_write_possibly_compressed_data_by_copy_and_verify(&payload)?;
                 let had_neural_data = motor_cache.try_decode_bytes_to_neural_data()?;
                 if had_neural_data {
                     motor_cache.try_decode_neural_data_into_cache(Instant::now())?;
@@ -400,6 +415,13 @@ impl TokioEmbodimentAgent {
         }
     }
 
+    fn tick_motor_pipelines(&mut self) -> Result<(), FeagiAgentError> {
+        self.embodiment
+            .get_motor_cache()
+            .tick_motor_pipelines(Instant::now())?;
+        Ok(())
+    }
+
     fn execute_actions(&mut self, actions: &[SessionAction]) -> Result<(), FeagiAgentError> {
         for action in actions {
             match action {
@@ -410,11 +432,15 @@ impl TokioEmbodimentAgent {
                     agent_descriptor,
                     auth_token,
                     requested_capabilities,
+                    supported_compression,
+                    supports_xyzp_delta,
                 } => {
-                    self.control.request_registration(
+                    self.control.request_registration_with_compression(
                         agent_descriptor.clone(),
                         auth_token.clone(),
                         requested_capabilities.clone(),
+                        supported_compression.clone(),
+                        *supports_xyzp_delta,
                     )?;
                 }
                 SessionAction::ControlSendHeartbeat => {
@@ -424,6 +450,10 @@ impl TokioEmbodimentAgent {
                     self.control.request_deregistration(reason.clone())?;
                 }
                 SessionAction::SensorConnectTo { endpoint } => {
+                    self.sensor_compressor = self
+                        .sm
+                        .stream_compression()
+                        .compressor_for(AgentCapabilities::SendSensorData);
                     let props = endpoint.try_create_boxed_client_pusher_properties()?;
                     let mut pusher = props.as_boxed_client_pusher();
                     pusher.request_connect()?;
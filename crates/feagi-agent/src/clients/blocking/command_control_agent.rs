@@ -1,13 +1,13 @@
 use crate::command_and_control::agent_registration_message::{
     AgentRegistrationMessage, DeregistrationRequest, DeregistrationResponse, RegistrationRequest,
-    RegistrationResponse,
+    RegistrationResponse, StreamCompression,
 };
 use crate::command_and_control::FeagiMessage;
 use crate::{AgentCapabilities, AgentDescriptor, AuthToken, FeagiAgentError};
 use feagi_io::traits_and_enums::client::{FeagiClientRequester, FeagiClientRequesterProperties};
 use feagi_io::traits_and_enums::shared::{FeagiEndpointState, TransportProtocolEndpoint};
 use feagi_io::AgentID;
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::{FeagiByteContainer, PayloadCompression};
 use std::collections::HashMap;
 
 pub struct CommandControlAgent {
@@ -16,6 +16,7 @@ pub struct CommandControlAgent {
     request_buffer: FeagiByteContainer,
     send_buffer: FeagiByteContainer,
     registration_status: AgentRegistrationStatus,
+    stream_compression: StreamCompression,
 }
 
 impl CommandControlAgent {
@@ -26,6 +27,7 @@ impl CommandControlAgent {
             requester: None,
             request_buffer: FeagiByteContainer::new_empty(),
             send_buffer: FeagiByteContainer::new_empty(),
+            stream_compression: StreamCompression::none(),
         }
     }
 
@@ -37,6 +39,11 @@ impl CommandControlAgent {
     pub fn registered_endpoint_target(&mut self) -> TransportProtocolEndpoint {
         self.properties.get_endpoint_target()
     }
+
+    /// Payload compression FEAGI enabled for this session's data streams
+    pub fn stream_compression(&self) -> &StreamCompression {
+        &self.stream_compression
+    }
     //endregion
 
     //region Helpers
@@ -74,6 +81,25 @@ impl CommandControlAgent {
         agent_descriptor: AgentDescriptor,
         auth_token: AuthToken,
         requested_capabilities: Vec<AgentCapabilities>,
+    ) -> Result<(), FeagiAgentError> {
+        self.request_registration_with_compression(
+            agent_descriptor,
+            auth_token,
+            requested_capabilities,
+            Vec::new(),
+            false,
+        )
+    }
+
+    /// Request registration, advertising the payload compression algorithms and whether
+    /// delta-coded XYZP frames can be decoded, so FEAGI may compress its data streams.
+    pub fn request_registration_with_compression(
+        &mut self,
+        agent_descriptor: AgentDescriptor,
+        auth_token: AuthToken,
+        requested_capabilities: Vec<AgentCapabilities>,
+        supported_compression: Vec<PayloadCompression>,
+        supports_xyzp_delta: bool,
     ) -> Result<(), FeagiAgentError> {
         let transport_protocol = if let Some(requester) = &mut self.requester {
             requester
@@ -90,7 +116,9 @@ impl CommandControlAgent {
             auth_token,
             requested_capabilities,
             transport_protocol,
-        );
+        )
+        .with_supported_compression(supported_compression)
+        .with_xyzp_delta_support(supports_xyzp_delta);
 
         let request_message = FeagiMessage::AgentRegistration(
             AgentRegistrationMessage::ClientRequestRegistration(request),
@@ -194,6 +222,20 @@ impl CommandControlAgent {
                                                 *session_id,
                                                 endpoints.clone(),
                                             );
+                                        self.stream_compression = StreamCompression::none();
+                                        Ok(Some(feagi_message))
+                                    }
+                                    RegistrationResponse::SuccessWithCompression(
+                                        session_id,
+                                        endpoints,
+                                        stream_compression,
+                                    ) => {
+                                        self.registration_status =
+                                            AgentRegistrationStatus::Registered(
+                                                *session_id,
+                                                endpoints.clone(),
+                                            );
+                                        self.stream_compression = *stream_compression;
                                         Ok(Some(feagi_message))
                                     }
                                 },
@@ -68,7 +68,7 @@ impl MotorAgent {
             FeagiEndpointState::ActiveHasData => {
                 let data = subscriber.consume_retrieved_data()?;
                 self.receive_buffer
-                    .try_write_data_by_copy_and_verify(data)?;
+                    .try_write_possibly_compressed_data_by_copy_and_verify(data)?;
                 Ok(())
             }
             FeagiEndpointState::Errored(err) => {
@@ -91,7 +91,7 @@ impl MotorAgent {
                 // return data
                 let data = subscriber.consume_retrieved_data()?;
                 self.receive_buffer
-                    .try_write_data_by_copy_and_verify(data)?;
+                    .try_write_possibly_compressed_data_by_copy_and_verify(data)?;
                 Ok(Some(&self.receive_buffer))
             }
             FeagiEndpointState::ActiveHasData => {
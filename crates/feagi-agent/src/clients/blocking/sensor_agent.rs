@@ -1,13 +1,15 @@
-use crate::FeagiAgentError;
+use crate::command_and_control::agent_registration_message::StreamCompression;
+use crate::{AgentCapabilities, FeagiAgentError};
 use feagi_io::traits_and_enums::client::{FeagiClientPusher, FeagiClientPusherProperties};
 use feagi_io::traits_and_enums::shared::FeagiEndpointState;
 use feagi_io::AgentID;
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::{FeagiByteContainer, PayloadCompressor};
 
 #[allow(dead_code)]
 pub struct SensorAgent {
     properties: Box<dyn FeagiClientPusherProperties>,
     pusher: Option<Box<dyn FeagiClientPusher>>,
+    compressor: PayloadCompressor,
 }
 
 #[allow(dead_code)]
@@ -19,9 +21,15 @@ impl SensorAgent {
         SensorAgent {
             properties,
             pusher: None,
+            compressor: PayloadCompressor::disabled(),
         }
     }
 
+    /// Compress outgoing sensor payloads as negotiated during registration
+    pub fn set_stream_compression(&mut self, stream_compression: &StreamCompression) {
+        self.compressor = stream_compression.compressor_for(AgentCapabilities::SendSensorData);
+    }
+
     pub fn request_connect(&mut self) -> Result<(), FeagiAgentError> {
         if self.pusher.is_none() {
             self.pusher = Some(self.properties.as_boxed_client_pusher());
@@ -54,7 +62,7 @@ impl SensorAgent {
                 "Cannot send to pending socket".to_string(),
             )),
             FeagiEndpointState::ActiveWaiting => {
-                pusher.publish_data(buffer.get_byte_ref())?;
+                pusher.publish_data(self.compressor.compress(buffer.get_byte_ref())?)?;
                 Ok(())
             }
             FeagiEndpointState::ActiveHasData => Err(FeagiAgentError::UnableToSendData(
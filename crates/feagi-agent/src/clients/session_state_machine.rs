@@ -12,12 +12,13 @@
 //! @cursor:critical-path
 
 use crate::command_and_control::agent_registration_message::{
-    AgentRegistrationMessage, DeregistrationResponse, RegistrationResponse,
+    AgentRegistrationMessage, DeregistrationResponse, RegistrationResponse, StreamCompression,
 };
 use crate::command_and_control::FeagiMessage;
 use crate::{AgentCapabilities, AgentDescriptor, AuthToken};
 use feagi_io::traits_and_enums::shared::{FeagiEndpointState, TransportProtocolEndpoint};
 use feagi_io::AgentID;
+use feagi_serialization::PayloadCompression;
 use std::collections::HashMap;
 
 /// Milliseconds in a monotonic clock domain provided by the driver.
@@ -36,6 +37,10 @@ pub struct SessionInit {
     pub agent_descriptor: AgentDescriptor,
     pub auth_token: AuthToken,
     pub requested_capabilities: Vec<AgentCapabilities>,
+    /// Payload compression algorithms advertised during registration (empty: never compress)
+    pub supported_compression: Vec<PayloadCompression>,
+    /// Whether delta-coded XYZP motor frames are accepted (advertised during registration)
+    pub supports_xyzp_delta: bool,
     pub timing: SessionTimingConfig,
 }
 
@@ -58,6 +63,7 @@ pub struct SessionStateMachine {
     last_heartbeat_sent_at_ms: Option<NowMs>,
     session_id: Option<AgentID>,
     endpoints: Option<HashMap<AgentCapabilities, TransportProtocolEndpoint>>,
+    stream_compression: StreamCompression,
     last_error: Option<String>,
 }
 
@@ -70,6 +76,7 @@ impl SessionStateMachine {
             last_heartbeat_sent_at_ms: None,
             session_id: None,
             endpoints: None,
+            stream_compression: StreamCompression::none(),
             last_error: None,
         }
     }
@@ -86,6 +93,11 @@ impl SessionStateMachine {
         self.endpoints.as_ref()
     }
 
+    /// Payload compression FEAGI enabled for this session's data streams
+    pub fn stream_compression(&self) -> &StreamCompression {
+        &self.stream_compression
+    }
+
     pub fn last_error(&self) -> Option<&str> {
         self.last_error.as_deref()
     }
@@ -96,6 +108,7 @@ impl SessionStateMachine {
         self.last_error = None;
         self.session_id = None;
         self.endpoints = None;
+        self.stream_compression = StreamCompression::none();
         self.last_heartbeat_sent_at_ms = None;
         self.phase = SessionPhase::ControlConnecting;
         vec![SessionAction::ControlRequestConnect]
@@ -184,6 +197,8 @@ impl SessionStateMachine {
                         agent_descriptor: self.init.agent_descriptor.clone(),
                         auth_token: self.init.auth_token.clone(),
                         requested_capabilities: self.init.requested_capabilities.clone(),
+                        supported_compression: self.init.supported_compression.clone(),
+                        supports_xyzp_delta: self.init.supports_xyzp_delta,
                     }]
                 }
                 FeagiEndpointState::Errored(e) => {
@@ -245,42 +260,50 @@ impl SessionStateMachine {
         now_ms: NowMs,
         resp: RegistrationResponse,
     ) -> Vec<SessionAction> {
-        match resp {
+        let (session_id, endpoints, stream_compression) = match resp {
             RegistrationResponse::Success(session_id, endpoints) => {
-                self.session_id = Some(session_id);
-                self.endpoints = Some(endpoints.clone());
-                self.phase = SessionPhase::DataConnecting;
-                self.last_heartbeat_sent_at_ms = Some(now_ms);
-
-                // Require sensory + motor endpoints.
-                let sensory = endpoints.get(&AgentCapabilities::SendSensorData);
-                let motor = endpoints.get(&AgentCapabilities::ReceiveMotorData);
-                if sensory.is_none() || motor.is_none() {
-                    self.fail("registration success missing required endpoints");
-                    return Vec::new();
-                }
-                vec![
-                    SessionAction::SensorConnectTo {
-                        endpoint: sensory.unwrap().clone(),
-                    },
-                    SessionAction::MotorConnectTo {
-                        endpoint: motor.unwrap().clone(),
-                    },
-                ]
+                (session_id, endpoints, StreamCompression::none())
             }
+            RegistrationResponse::SuccessWithCompression(
+                session_id,
+                endpoints,
+                stream_compression,
+            ) => (session_id, endpoints, stream_compression),
             RegistrationResponse::FailedInvalidAuth => {
                 self.fail("registration failed: invalid auth");
-                Vec::new()
+                return Vec::new();
             }
             RegistrationResponse::FailedInvalidRequest => {
                 self.fail("registration failed: invalid request");
-                Vec::new()
+                return Vec::new();
             }
             RegistrationResponse::AlreadyRegistered => {
                 self.fail("registration failed: already registered");
-                Vec::new()
+                return Vec::new();
             }
+        };
+
+        self.session_id = Some(session_id);
+        self.stream_compression = stream_compression;
+        self.endpoints = Some(endpoints.clone());
+        self.phase = SessionPhase::DataConnecting;
+        self.last_heartbeat_sent_at_ms = Some(now_ms);
+
+        // Require sensory + motor endpoints.
+        let sensory = endpoints.get(&AgentCapabilities::SendSensorData);
+        let motor = endpoints.get(&AgentCapabilities::ReceiveMotorData);
+        if sensory.is_none() || motor.is_none() {
+            self.fail("registration success missing required endpoints");
+            return Vec::new();
         }
+        vec![
+            SessionAction::SensorConnectTo {
+                endpoint: sensory.unwrap().clone(),
+            },
+            SessionAction::MotorConnectTo {
+                endpoint: motor.unwrap().clone(),
+            },
+        ]
     }
 
     fn on_sensor_observed(&mut self, state: FeagiEndpointState) -> Vec<SessionAction> {
@@ -315,6 +338,7 @@ impl SessionStateMachine {
                 self.phase = SessionPhase::Idle;
                 self.session_id = None;
                 self.endpoints = None;
+                self.stream_compression = StreamCompression::none();
                 self.last_heartbeat_sent_at_ms = None;
                 self.connect_started_at_ms = None;
             }
@@ -376,6 +400,8 @@ pub enum SessionAction {
         agent_descriptor: AgentDescriptor,
         auth_token: AuthToken,
         requested_capabilities: Vec<AgentCapabilities>,
+        supported_compression: Vec<PayloadCompression>,
+        supports_xyzp_delta: bool,
     },
     ControlSendHeartbeat,
     ControlSendDeregistration {
@@ -3,6 +3,7 @@ use feagi_io::traits_and_enums::shared::{
     TransportProtocolEndpoint, TransportProtocolImplementation,
 };
 use feagi_io::AgentID;
+use feagi_serialization::{PayloadCompression, PayloadCompressor, XYZPDeltaEncoder};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -32,6 +33,14 @@ pub struct RegistrationRequest {
     requested_capabilities: Vec<AgentCapabilities>,
     connection_protocol: TransportProtocolImplementation,
     api_version: FeagiApiVersion,
+    /// Payload compression algorithms the agent can handle on its data streams. Agents that
+    /// predate stream compression omit this and are always served uncompressed payloads.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    supported_compression: Vec<PayloadCompression>,
+    /// Whether the agent can reconstruct delta-coded XYZP frames on its motor and visualization
+    /// streams. Agents that predate delta coding omit this and always get full frames.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    supports_xyzp_delta: bool,
 }
 
 impl RegistrationRequest {
@@ -57,9 +66,26 @@ impl RegistrationRequest {
             requested_capabilities,
             connection_protocol,
             api_version: FeagiApiVersion::get_current_api_version(),
+            supported_compression: Vec::new(),
+            supports_xyzp_delta: false,
         }
     }
 
+    /// Advertise the payload compression algorithms this agent can handle on its data streams
+    pub fn with_supported_compression(
+        mut self,
+        supported_compression: Vec<PayloadCompression>,
+    ) -> Self {
+        self.supported_compression = supported_compression;
+        self
+    }
+
+    /// Advertise whether this agent can decode delta-coded XYZP motor and visualization frames
+    pub fn with_xyzp_delta_support(mut self, supports_xyzp_delta: bool) -> Self {
+        self.supports_xyzp_delta = supports_xyzp_delta;
+        self
+    }
+
     /// Get the reported API version
     pub fn api_version(&self) -> &FeagiApiVersion {
         &self.api_version
@@ -84,6 +110,16 @@ impl RegistrationRequest {
     pub fn connection_protocol(&self) -> &TransportProtocolImplementation {
         &self.connection_protocol
     }
+
+    /// Get the payload compression algorithms the agent advertised.
+    pub fn supported_compression(&self) -> &[PayloadCompression] {
+        &self.supported_compression
+    }
+
+    /// Whether the agent advertised it can decode delta-coded XYZP frames.
+    pub fn supports_xyzp_delta(&self) -> bool {
+        self.supports_xyzp_delta
+    }
 }
 
 //endregion
@@ -100,6 +136,132 @@ pub enum RegistrationResponse {
         AgentID,
         HashMap<AgentCapabilities, TransportProtocolEndpoint>,
     ),
+    /// Same as `Success`, but the server also enabled payload compression or XYZP delta coding on
+    /// some of the agent's data streams. Only sent to agents that advertised support for either.
+    SuccessWithCompression(
+        AgentID,
+        HashMap<AgentCapabilities, TransportProtocolEndpoint>,
+        StreamCompression,
+    ),
+}
+
+//endregion
+
+//region Stream Compression
+
+/// Payload compression negotiated for the data streams of one agent session.
+///
+/// Compressed payloads are only sent on streams enabled here, but receivers accept both raw and
+/// compressed payloads, so either side may skip compressing small frames.
+///
+/// Independently of the algorithm, the server may delta-code the XYZP frames of the motor and
+/// visualization streams, sending a keyframe every `xyzp_delta_keyframe_interval` frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StreamCompression {
+    algorithm: PayloadCompression,
+    min_size_threshold: u32,
+    sensory: bool,
+    motor: bool,
+    visualization: bool,
+    /// 0 when full XYZP frames are sent
+    #[serde(default)]
+    xyzp_delta_keyframe_interval: u32,
+}
+
+impl StreamCompression {
+    pub fn new(
+        algorithm: PayloadCompression,
+        min_size_threshold: u32,
+        sensory: bool,
+        motor: bool,
+        visualization: bool,
+    ) -> Self {
+        Self {
+            algorithm,
+            min_size_threshold,
+            sensory,
+            motor,
+            visualization,
+            xyzp_delta_keyframe_interval: 0,
+        }
+    }
+
+    /// Also delta-code XYZP motor and visualization frames, with a keyframe every
+    /// `keyframe_interval` frames (0 keeps full frames)
+    pub fn with_xyzp_delta(mut self, keyframe_interval: u32) -> Self {
+        self.xyzp_delta_keyframe_interval = keyframe_interval;
+        self
+    }
+
+    /// No compression on any stream
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn algorithm(&self) -> PayloadCompression {
+        self.algorithm
+    }
+
+    /// Payloads smaller than this many bytes are sent uncompressed
+    pub fn min_size_threshold(&self) -> u32 {
+        self.min_size_threshold
+    }
+
+    /// Whether payloads of the stream serving the given capability are compressed
+    pub fn is_enabled_for(&self, capability: AgentCapabilities) -> bool {
+        if self.algorithm == PayloadCompression::None {
+            return false;
+        }
+        match capability {
+            AgentCapabilities::SendSensorData => self.sensory,
+            AgentCapabilities::ReceiveMotorData => self.motor,
+            AgentCapabilities::ReceiveNeuronVisualizations => self.visualization,
+            AgentCapabilities::ReceiveSystemMessages => false,
+        }
+    }
+
+    /// Keyframe interval of delta-coded XYZP frames, `None` when full frames are sent
+    pub fn xyzp_delta_keyframe_interval(&self) -> Option<u32> {
+        match self.xyzp_delta_keyframe_interval {
+            0 => None,
+            interval => Some(interval),
+        }
+    }
+
+    /// Whether any stream is compressed or delta-coded
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled_for(AgentCapabilities::SendSensorData)
+            || self.is_enabled_for(AgentCapabilities::ReceiveMotorData)
+            || self.is_enabled_for(AgentCapabilities::ReceiveNeuronVisualizations)
+            || self.xyzp_delta_keyframe_interval().is_some()
+    }
+
+    /// Build the compressor for the sending side of the stream serving the given capability
+    pub fn compressor_for(&self, capability: AgentCapabilities) -> PayloadCompressor {
+        if self.is_enabled_for(capability) {
+            PayloadCompressor::new(self.algorithm, self.min_size_threshold as usize)
+        } else {
+            PayloadCompressor::disabled()
+        }
+    }
+
+    /// Build the XYZP delta encoder for the sending side of the stream serving the given
+    /// capability, if its frames are delta-coded. Only motor and visualization streams are.
+    ///
+    /// Motor encoders send an empty delta for unchanged frames, so agents keep seeing a held
+    /// command as fresh input and their watchdogs do not trip; visualization skips them.
+    pub fn delta_encoder_for(&self, capability: AgentCapabilities) -> Option<XYZPDeltaEncoder> {
+        let encoder = self
+            .xyzp_delta_keyframe_interval()
+            .and_then(|interval| XYZPDeltaEncoder::new(interval).ok());
+        match capability {
+            AgentCapabilities::ReceiveMotorData => {
+                encoder.map(XYZPDeltaEncoder::with_empty_deltas_for_unchanged_frames)
+            }
+            AgentCapabilities::ReceiveNeuronVisualizations => encoder,
+            AgentCapabilities::SendSensorData | AgentCapabilities::ReceiveSystemMessages => None,
+        }
+    }
 }
 
 //endregion
@@ -139,7 +301,46 @@ pub enum DeregistrationResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::{AgentRegistrationMessage, DeregistrationRequest, DeregistrationResponse};
+    use super::{
+        AgentRegistrationMessage, DeregistrationRequest, DeregistrationResponse,
+        RegistrationRequest,
+    };
+    use crate::{AgentCapabilities, AgentDescriptor, AuthToken};
+    use feagi_io::traits_and_enums::shared::TransportProtocolImplementation;
+    use feagi_serialization::PayloadCompression;
+
+    #[test]
+    fn registration_request_without_compression_matches_legacy_format() {
+        let request = RegistrationRequest::new(
+            AgentDescriptor::new("m", "n", 1).expect("descriptor"),
+            AuthToken::new([0u8; 32]),
+            vec![AgentCapabilities::ReceiveMotorData],
+            TransportProtocolImplementation::Zmq,
+        );
+        let legacy = serde_json::to_value(&request).expect("request should serialize");
+        assert!(legacy.get("supported_compression").is_none());
+        assert!(legacy.get("supports_xyzp_delta").is_none());
+
+        let advertising = request
+            .clone()
+            .with_supported_compression(PayloadCompression::SUPPORTED.to_vec())
+            .with_xyzp_delta_support(true);
+        let encoded = serde_json::to_string(&advertising).expect("request should serialize");
+        let decoded: RegistrationRequest =
+            serde_json::from_str(&encoded).expect("request should deserialize");
+        assert_eq!(
+            decoded.supported_compression(),
+            &PayloadCompression::SUPPORTED
+        );
+        assert!(decoded.supports_xyzp_delta());
+
+        // Requests from agents that predate compression still parse
+        let decoded: RegistrationRequest =
+            serde_json::from_value(legacy).expect("legacy request should deserialize");
+        assert_eq!(decoded, request);
+        assert!(decoded.supported_compression().is_empty());
+        assert!(!decoded.supports_xyzp_delta());
+    }
 
     #[test]
     fn deregistration_request_round_trip_serialization_preserves_reason() {
@@ -1,5 +1,5 @@
 use crate::command_and_control::agent_registration_message::{
-    AgentRegistrationMessage, DeregistrationResponse, RegistrationResponse,
+    AgentRegistrationMessage, DeregistrationResponse, RegistrationResponse, StreamCompression,
 };
 use crate::command_and_control::FeagiMessage;
 use crate::server::auth::AgentAuth;
@@ -15,7 +15,8 @@ use feagi_io::traits_and_enums::shared::{
     TransportProtocolEndpoint, TransportProtocolImplementation,
 };
 use feagi_io::AgentID;
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::payload_compression::MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT;
+use feagi_serialization::{FeagiByteContainer, PayloadCompression};
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 use tracing::info;
@@ -42,6 +43,68 @@ impl Default for AgentLivenessConfig {
     }
 }
 
+/// Server-side payload compression policy for agent data streams.
+///
+/// Compression is only enabled for agents that advertise support for `algorithm` during
+/// registration, and XYZP delta coding only for agents that advertise they can decode deltas;
+/// everyone else keeps receiving raw, full payloads. The default disables both.
+#[derive(Debug, Clone)]
+pub struct StreamCompressionPolicy {
+    pub algorithm: PayloadCompression,
+    pub min_size_threshold: usize,
+    pub compress_sensory: bool,
+    pub compress_motor: bool,
+    pub compress_visualization: bool,
+    /// Keyframe interval of delta-coded motor and visualization frames (0 sends full frames)
+    pub xyzp_delta_keyframe_interval: u32,
+    /// Largest size in bytes a compressed sensor payload may inflate to before it is rejected
+    pub max_decompressed_byte_count: usize,
+}
+
+impl Default for StreamCompressionPolicy {
+    fn default() -> Self {
+        Self {
+            algorithm: PayloadCompression::default(),
+            min_size_threshold: 0,
+            compress_sensory: false,
+            compress_motor: false,
+            compress_visualization: false,
+            xyzp_delta_keyframe_interval: 0,
+            max_decompressed_byte_count: MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT,
+        }
+    }
+}
+
+impl StreamCompressionPolicy {
+    /// Decide the compression of a new session from what the agent supports and requested
+    pub fn negotiate(
+        &self,
+        supported_by_agent: &[PayloadCompression],
+        agent_supports_xyzp_delta: bool,
+        capabilities: &[AgentCapabilities],
+    ) -> StreamCompression {
+        let compression = if self.algorithm == PayloadCompression::None
+            || !supported_by_agent.contains(&self.algorithm)
+        {
+            StreamCompression::none()
+        } else {
+            StreamCompression::new(
+                self.algorithm,
+                self.min_size_threshold.min(u32::MAX as usize) as u32,
+                self.compress_sensory && capabilities.contains(&AgentCapabilities::SendSensorData),
+                self.compress_motor && capabilities.contains(&AgentCapabilities::ReceiveMotorData),
+                self.compress_visualization
+                    && capabilities.contains(&AgentCapabilities::ReceiveNeuronVisualizations),
+            )
+        };
+        if agent_supports_xyzp_delta {
+            compression.with_xyzp_delta(self.xyzp_delta_keyframe_interval)
+        } else {
+            compression
+        }
+    }
+}
+
 pub struct FeagiAgentHandler {
     agent_auth_backend: Box<dyn AgentAuth>,
     available_publishers: Vec<Box<dyn FeagiServerPublisherProperties>>,
@@ -56,6 +119,8 @@ pub struct FeagiAgentHandler {
     visualizations: HashMap<AgentID, VisualizationTranslator>,
     liveness_config: AgentLivenessConfig,
     last_stale_check_at: Instant,
+    compression_policy: StreamCompressionPolicy,
+    stream_compression_by_agent: HashMap<AgentID, StreamCompression>,
 
     // this stuff is likely redundant
     // REST STUFF
@@ -128,6 +193,8 @@ impl FeagiAgentHandler {
             visualizations: Default::default(),
             liveness_config,
             last_stale_check_at: Instant::now(),
+            compression_policy: StreamCompressionPolicy::default(),
+            stream_compression_by_agent: HashMap::new(),
 
             device_registrations_by_descriptor: HashMap::new(),
             agent_id_by_descriptor: HashMap::new(),
@@ -135,8 +202,22 @@ impl FeagiAgentHandler {
         }
     }
 
+    /// Set the payload compression policy applied to agents registering from now on
+    pub fn set_stream_compression_policy(&mut self, policy: StreamCompressionPolicy) {
+        self.compression_policy = policy;
+    }
+
     //region Get Properties
 
+    pub fn get_stream_compression_policy(&self) -> &StreamCompressionPolicy {
+        &self.compression_policy
+    }
+
+    /// Payload compression negotiated with a registered agent
+    pub fn get_stream_compression_for_agent(&self, agent_id: AgentID) -> Option<StreamCompression> {
+        self.stream_compression_by_agent.get(&agent_id).copied()
+    }
+
     pub fn get_all_registered_agents(
         &self,
     ) -> &HashMap<AgentID, (AgentDescriptor, Vec<AgentCapabilities>)> {
@@ -605,6 +686,12 @@ impl FeagiAgentHandler {
                             self.deregister_agent_internal(existing_agent_id, &replacement_reason);
                         }
 
+                        let stream_compression = self.compression_policy.negotiate(
+                            registration_request.supported_compression(),
+                            registration_request.supports_xyzp_delta(),
+                            registration_request.requested_capabilities(),
+                        );
+
                         // register and always respond deterministically (avoid client timeouts).
                         let mappings = match self.register_agent(
                             agent_id,
@@ -612,6 +699,7 @@ impl FeagiAgentHandler {
                             registration_request.requested_capabilities().to_vec(),
                             registration_request.agent_descriptor().clone(),
                             command_control_index,
+                            stream_compression,
                         ) {
                             Ok(mappings) => mappings,
                             Err(_) => {
@@ -629,7 +717,15 @@ impl FeagiAgentHandler {
                             }
                         };
 
-                        let response = RegistrationResponse::Success(agent_id, mappings);
+                        let response = if stream_compression.is_enabled() {
+                            RegistrationResponse::SuccessWithCompression(
+                                agent_id,
+                                mappings,
+                                stream_compression,
+                            )
+                        } else {
+                            RegistrationResponse::Success(agent_id, mappings)
+                        };
                         let response_message = FeagiMessage::AgentRegistration(
                             AgentRegistrationMessage::ServerRespondsRegistration(response),
                         );
@@ -726,6 +822,7 @@ impl FeagiAgentHandler {
         agent_capabilities: Vec<AgentCapabilities>,
         descriptor: AgentDescriptor,
         command_server_index: CommandServerIndex,
+        stream_compression: StreamCompression,
     ) -> Result<HashMap<AgentCapabilities, TransportProtocolEndpoint>, FeagiAgentError> {
         // TODO prevent duplicate registration
         /*
@@ -810,19 +907,32 @@ impl FeagiAgentHandler {
 
         // insert the servers into the cache
         for sensor_server in sensor_servers {
-            let sensor_translator: SensorTranslator =
-                SensorTranslator::new(agent_id, sensor_server);
+            let sensor_translator: SensorTranslator = SensorTranslator::new(
+                agent_id,
+                sensor_server,
+                self.compression_policy.max_decompressed_byte_count,
+            );
             self.sensors.insert(agent_id, sensor_translator);
         }
 
         for motor_server in motor_servers {
-            let motor_translator: MotorTranslator = MotorTranslator::new(agent_id, motor_server);
+            let motor_translator: MotorTranslator = MotorTranslator::new(
+                agent_id,
+                motor_server,
+                stream_compression.compressor_for(AgentCapabilities::ReceiveMotorData),
+                stream_compression.delta_encoder_for(AgentCapabilities::ReceiveMotorData),
+            );
             self.motors.insert(agent_id, motor_translator);
         }
 
         for visualizer_server in visualizer_servers {
-            let visualizer_translator: VisualizationTranslator =
-                VisualizationTranslator::new(agent_id, visualizer_server);
+            let visualizer_translator: VisualizationTranslator = VisualizationTranslator::new(
+                agent_id,
+                visualizer_server,
+                stream_compression.compressor_for(AgentCapabilities::ReceiveNeuronVisualizations),
+                stream_compression
+                    .delta_encoder_for(AgentCapabilities::ReceiveNeuronVisualizations),
+            );
             self.visualizations.insert(agent_id, visualizer_translator);
         }
 
@@ -831,6 +941,8 @@ impl FeagiAgentHandler {
         self.agent_mapping_to_command_control_server_index
             .insert(agent_id, command_server_index);
         self.last_activity_by_agent.insert(agent_id, Instant::now());
+        self.stream_compression_by_agent
+            .insert(agent_id, stream_compression);
 
         Ok(endpoint_mappings)
     }
@@ -908,6 +1020,7 @@ impl FeagiAgentHandler {
             reason
         );
         self.device_registrations_by_agent.remove(&agent_id);
+        self.stream_compression_by_agent.remove(&agent_id);
 
         if let Some(sensor) = self.sensors.remove(&agent_id) {
             self.available_pullers.push(sensor.into_puller_properties());
@@ -2,4 +2,4 @@ mod feagi_agent_handler;
 mod wrappers;
 
 pub mod auth;
-pub use feagi_agent_handler::{AgentLivenessConfig, FeagiAgentHandler};
+pub use feagi_agent_handler::{AgentLivenessConfig, FeagiAgentHandler, StreamCompressionPolicy};
@@ -7,3 +7,290 @@ pub use command_control_wrapper::CommandControlWrapper;
 pub use motor_translator::MotorTranslator;
 pub use sensor_translator::SensorTranslator;
 pub use visualization_translator::VisualizationTranslator;
+
+use crate::FeagiAgentError;
+use feagi_io::traits_and_enums::server::FeagiServerPublisher;
+use feagi_serialization::{
+    FeagiByteContainer, PayloadCompressor, XYZPDeltaContainerEncoding, XYZPDeltaEncoder,
+};
+
+/// Publish an outgoing XYZP stream frame, delta-coding it first if the agent negotiated deltas.
+///
+/// Unchanged frames are not sent, unless the encoder emits empty deltas for them (motor streams).
+/// A frame the publisher rejects makes the next one a keyframe; drops the publisher cannot
+/// detect are covered by the encoder's periodic keyframes.
+pub(crate) fn send_possibly_delta_coded(
+    server: &mut dyn FeagiServerPublisher,
+    compressor: &mut PayloadCompressor,
+    delta_encoder: Option<&mut XYZPDeltaEncoder>,
+    delta_buffer: &mut FeagiByteContainer,
+    data: &FeagiByteContainer,
+) -> Result<(), FeagiAgentError> {
+    let Some(delta_encoder) = delta_encoder else {
+        server.publish_data(compressor.compress(data.get_byte_ref())?)?;
+        return Ok(());
+    };
+    let frame = match delta_encoder.try_encode_from_container(data, delta_buffer)? {
+        XYZPDeltaContainerEncoding::NotXYZP => data,
+        XYZPDeltaContainerEncoding::Unchanged => return Ok(()),
+        XYZPDeltaContainerEncoding::Encoded => &*delta_buffer,
+    };
+    let published = compressor
+        .compress(frame.get_byte_ref())
+        .map_err(FeagiAgentError::from)
+        .and_then(|payload| server.publish_data(payload).map_err(FeagiAgentError::from));
+    if published.is_err() {
+        delta_encoder.request_keyframe();
+    }
+    published
+}
+
+#[cfg(test)]
+mod tests {
+    use super::send_possibly_delta_coded;
+    use crate::command_and_control::agent_registration_message::StreamCompression;
+    use crate::AgentCapabilities;
+    use feagi_io::traits_and_enums::server::{
+        FeagiServer, FeagiServerPublisher, FeagiServerPublisherProperties,
+    };
+    use feagi_io::traits_and_enums::shared::{
+        FeagiEndpointState, TransportProtocolEndpoint, TransportProtocolImplementation,
+    };
+    use feagi_io::FeagiNetworkError;
+    use feagi_sensorimotor::data_pipeline::{PipelineStageProperties, ScalarSignalType};
+    use feagi_sensorimotor::ConnectorCache;
+    use feagi_serialization::{
+        FeagiByteContainer, PayloadCompressor, XYZPDeltaDecoder, XYZPDeltaEncoder,
+    };
+    use feagi_structures::genomic::cortical_area::descriptors::{
+        CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+    };
+    use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+        FrameChangeHandling, PercentageNeuronPositioning,
+    };
+    use feagi_structures::genomic::cortical_area::CoreCorticalType;
+    use feagi_structures::genomic::MotorCorticalUnit;
+    use feagi_structures::neuron_voxels::xyzp::{
+        CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+    };
+    use std::time::{Duration, Instant};
+
+    /// Publisher that records what it sent, optionally rejecting the next frame
+    struct RecordingPublisher {
+        state: FeagiEndpointState,
+        sent: Vec<Vec<u8>>,
+        fail_next: bool,
+    }
+
+    impl FeagiServer for RecordingPublisher {
+        fn poll(&mut self) -> &FeagiEndpointState {
+            &self.state
+        }
+        fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+            Ok(())
+        }
+        fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+            Ok(())
+        }
+        fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+            Ok(())
+        }
+        fn get_bind_point(&self) -> TransportProtocolEndpoint {
+            unimplemented!()
+        }
+        fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+            unimplemented!()
+        }
+        fn get_protocol(&self) -> TransportProtocolImplementation {
+            TransportProtocolImplementation::Zmq
+        }
+    }
+
+    impl FeagiServerPublisher for RecordingPublisher {
+        fn publish_data(&mut self, data: &[u8]) -> Result<(), FeagiNetworkError> {
+            if std::mem::take(&mut self.fail_next) {
+                return Err(FeagiNetworkError::SendFailed("dropped".into()));
+            }
+            self.sent.push(data.to_vec());
+            Ok(())
+        }
+        fn as_boxed_publisher_properties(&self) -> Box<dyn FeagiServerPublisherProperties> {
+            unimplemented!()
+        }
+    }
+
+    fn frame(xs: &[u32]) -> CorticalMappedXYZPNeuronVoxels {
+        let mut voxels = NeuronVoxelXYZPArrays::new();
+        for x in xs {
+            voxels.push_raw(*x, 0, 0, 1.0);
+        }
+        let mut frame = CorticalMappedXYZPNeuronVoxels::new();
+        frame.insert(CoreCorticalType::Power.to_cortical_id(), voxels);
+        frame
+    }
+
+    fn frame_container(xs: &[u32]) -> FeagiByteContainer {
+        let mut container = FeagiByteContainer::new_empty();
+        container
+            .overwrite_byte_data_with_single_struct_data(&frame(xs), 0)
+            .unwrap();
+        container
+    }
+
+    #[test]
+    fn test_delta_coded_stream_skips_unchanged_frames_and_resyncs_after_drops() {
+        let mut publisher = RecordingPublisher {
+            state: FeagiEndpointState::ActiveWaiting,
+            sent: Vec::new(),
+            fail_next: false,
+        };
+        let mut compressor = PayloadCompressor::disabled();
+        let mut encoder = XYZPDeltaEncoder::new(100).unwrap();
+        let mut buffer = FeagiByteContainer::new_empty();
+        let mut send = |publisher: &mut RecordingPublisher, xs: &[u32]| {
+            send_possibly_delta_coded(
+                publisher,
+                &mut compressor,
+                Some(&mut encoder),
+                &mut buffer,
+                &frame_container(xs),
+            )
+        };
+
+        send(&mut publisher, &[1, 2]).unwrap();
+        send(&mut publisher, &[1, 2]).unwrap();
+        assert_eq!(publisher.sent.len(), 1, "unchanged frames are not sent");
+        send(&mut publisher, &[1, 3]).unwrap();
+        assert_eq!(publisher.sent.len(), 2);
+
+        publisher.fail_next = true;
+        assert!(send(&mut publisher, &[4]).is_err());
+        send(&mut publisher, &[4, 5]).unwrap();
+
+        let mut decoder = XYZPDeltaDecoder::new();
+        let mut reconstructed = CorticalMappedXYZPNeuronVoxels::new();
+        let mut received = FeagiByteContainer::new_empty();
+        for payload in &publisher.sent {
+            received.try_write_data_by_copy_and_verify(payload).unwrap();
+            assert!(decoder
+                .try_apply_from_container(&received, &mut reconstructed)
+                .unwrap());
+        }
+        // The frame after the dropped delta was a keyframe, so the receiver stays in sync
+        assert!(decoder.is_synchronized());
+        // Keyframes do not preserve voxel order
+        let power = CoreCorticalType::Power.to_cortical_id();
+        let mut xs: Vec<u32> = reconstructed
+            .get_neurons_of(&power)
+            .unwrap()
+            .iter()
+            .map(|neuron| neuron.as_tuple().0)
+            .collect();
+        xs.sort_unstable();
+        assert_eq!(reconstructed.len(), 1);
+        assert_eq!(xs, vec![4, 5]);
+    }
+
+    /// Drives a rotary motor guarded by a 50 ms watchdog with the same delta-coded command for
+    /// 200 ms of 10 ms bursts, returning the motor position after every burst.
+    fn held_command_positions(mut encoder: XYZPDeltaEncoder) -> Vec<f32> {
+        let cache = ConnectorCache::new();
+        let mut motors = cache.get_motor_cache();
+        motors
+            .rotary_motor_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(1).unwrap(),
+                FrameChangeHandling::Absolute,
+                NeuronDepth::new(10).unwrap(),
+                PercentageNeuronPositioning::Linear,
+            )
+            .unwrap();
+        let limiter = PipelineStageProperties::new_motor_safety_limiter(
+            ScalarSignalType::SignedPercentage,
+            -1.0..=1.0,
+            None,
+            None,
+            Some(50),
+            0.0,
+            false,
+        );
+        motors
+            .rotary_motor_replace_all_stages(0.into(), 0.into(), vec![limiter])
+            .unwrap();
+
+        // Positive column 0, halfway down a depth of 10: a command of 0.5
+        let cortical_id =
+            MotorCorticalUnit::get_cortical_ids_array_for_rotary_motor_with_parameters(
+                FrameChangeHandling::Absolute,
+                PercentageNeuronPositioning::Linear,
+                0.into(),
+            )[0];
+        let mut voxels = NeuronVoxelXYZPArrays::new();
+        voxels.push_raw(0, 0, 5, 1.0);
+        let mut command = CorticalMappedXYZPNeuronVoxels::new();
+        command.insert(cortical_id, voxels);
+        let mut container = FeagiByteContainer::new_empty();
+        container
+            .overwrite_byte_data_with_single_struct_data(&command, 0)
+            .unwrap();
+
+        let mut publisher = RecordingPublisher {
+            state: FeagiEndpointState::ActiveWaiting,
+            sent: Vec::new(),
+            fail_next: false,
+        };
+        let mut compressor = PayloadCompressor::disabled();
+        let mut buffer = FeagiByteContainer::new_empty();
+        let start = Instant::now();
+        let mut positions = Vec::new();
+        for burst in 0..20u64 {
+            let now = start + Duration::from_millis(burst * 10);
+            send_possibly_delta_coded(
+                &mut publisher,
+                &mut compressor,
+                Some(&mut encoder),
+                &mut buffer,
+                &container,
+            )
+            .unwrap();
+            for payload in publisher.sent.drain(..) {
+                motors
+                    .get_feagi_byte_container_mut()
+                    .try_write_possibly_compressed_data_by_copy_and_verify(&payload)
+                    .unwrap();
+                if motors.try_decode_bytes_to_neural_data().unwrap() {
+                    motors.try_decode_neural_data_into_cache(now).unwrap();
+                }
+            }
+            motors.tick_motor_pipelines(now).unwrap();
+            positions.push(
+                motors
+                    .rotary_motor_read_postprocessed_cache_value(0.into(), 0.into())
+                    .unwrap()
+                    .get_as_m1_1(),
+            );
+        }
+        positions
+    }
+
+    #[test]
+    fn test_held_motor_command_keeps_watchdog_fed_between_keyframes() {
+        let negotiated = StreamCompression::none().with_xyzp_delta(30);
+        let motor_encoder = negotiated
+            .delta_encoder_for(AgentCapabilities::ReceiveMotorData)
+            .unwrap();
+        let positions = held_command_positions(motor_encoder);
+        assert!(
+            positions.iter().all(|p| (p - 0.5).abs() < 1e-4),
+            "held command must not fall back to the safe value: {:?}",
+            positions
+        );
+
+        // Skipping unchanged frames, as visualization does, starves the watchdog
+        let visualization_encoder = negotiated
+            .delta_encoder_for(AgentCapabilities::ReceiveNeuronVisualizations)
+            .unwrap();
+        let positions = held_command_positions(visualization_encoder);
+        assert_eq!(positions.last().copied(), Some(0.0));
+    }
+}
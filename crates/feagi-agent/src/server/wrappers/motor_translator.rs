@@ -1,21 +1,34 @@
+use crate::server::wrappers::send_possibly_delta_coded;
 use crate::FeagiAgentError;
 use feagi_io::traits_and_enums::server::{FeagiServerPublisher, FeagiServerPublisherProperties};
 use feagi_io::traits_and_enums::shared::FeagiEndpointState;
 use feagi_io::AgentID;
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::{FeagiByteContainer, PayloadCompressor, XYZPDeltaEncoder};
 
 // TODO Error handling, error states if one stream fails
 
 pub struct MotorTranslator {
     session_id: AgentID,
     motor_server: Box<dyn FeagiServerPublisher>,
+    compressor: PayloadCompressor,
+    /// Set when this agent negotiated delta-coded motor frames
+    delta_encoder: Option<XYZPDeltaEncoder>,
+    delta_buffer: FeagiByteContainer,
 }
 
 impl MotorTranslator {
-    pub fn new(session_id: AgentID, motor_server: Box<dyn FeagiServerPublisher>) -> Self {
+    pub fn new(
+        session_id: AgentID,
+        motor_server: Box<dyn FeagiServerPublisher>,
+        compressor: PayloadCompressor,
+        delta_encoder: Option<XYZPDeltaEncoder>,
+    ) -> Self {
         MotorTranslator {
             session_id,
             motor_server,
+            compressor,
+            delta_encoder,
+            delta_buffer: FeagiByteContainer::new_empty(),
         }
     }
 
@@ -62,10 +75,13 @@ impl MotorTranslator {
         let motor_server = &mut self.motor_server;
         let state = motor_server.poll();
         match state {
-            FeagiEndpointState::ActiveWaiting => {
-                motor_server.publish_data(motor_data.get_byte_ref())?;
-                Ok(())
-            }
+            FeagiEndpointState::ActiveWaiting => send_possibly_delta_coded(
+                motor_server.as_mut(),
+                &mut self.compressor,
+                self.delta_encoder.as_mut(),
+                &mut self.delta_buffer,
+                motor_data,
+            ),
             _ => {
                 // Socket is not in a state to handle incoming data
                 Err(FeagiAgentError::UnableToSendData(
@@ -11,6 +11,7 @@ pub struct SensorTranslator {
     session_id: AgentID,
     sensor_server: Box<dyn FeagiServerPuller>,
     sensor_byte_cache: FeagiByteContainer,
+    max_decompressed_byte_count: usize,
 }
 
 impl SensorTranslator {
@@ -22,7 +23,11 @@ impl SensorTranslator {
         )
     }
 
-    pub fn new(session_id: AgentID, sensor_server: Box<dyn FeagiServerPuller>) -> Self {
+    pub fn new(
+        session_id: AgentID,
+        sensor_server: Box<dyn FeagiServerPuller>,
+        max_decompressed_byte_count: usize,
+    ) -> Self {
         let mut sensor_byte_cache = FeagiByteContainer::new_empty();
         let _ = sensor_byte_cache.set_agent_identifier(session_id);
 
@@ -30,6 +35,7 @@ impl SensorTranslator {
             session_id,
             sensor_server,
             sensor_byte_cache,
+            max_decompressed_byte_count,
         }
     }
 
@@ -56,11 +62,14 @@ impl SensorTranslator {
             FeagiEndpointState::ActiveWaiting => Ok(None),
             FeagiEndpointState::ActiveHasData => {
                 let data = self.sensor_server.consume_retrieved_data()?;
+                // Agents that negotiated compression may still send small frames uncompressed
                 match self
                     .sensor_byte_cache
-                    .try_write_data_by_copy_and_verify(data)
-                {
-                    Ok(()) => Ok(Some(&self.sensor_byte_cache)),
+                    .try_write_possibly_compressed_data_by_copy_and_verify_with_limit(
+                        data,
+                        self.max_decompressed_byte_count,
+                    ) {
+                    Ok(_) => Ok(Some(&self.sensor_byte_cache)),
                     Err(e) => {
                         let agent_err: FeagiAgentError = e.into();
                         if Self::should_drop_malformed_sensor_frame(&agent_err) {
@@ -1,21 +1,34 @@
+use crate::server::wrappers::send_possibly_delta_coded;
 use crate::FeagiAgentError;
 use feagi_io::traits_and_enums::server::{FeagiServerPublisher, FeagiServerPublisherProperties};
 use feagi_io::traits_and_enums::shared::FeagiEndpointState;
 use feagi_io::AgentID;
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::{FeagiByteContainer, PayloadCompressor, XYZPDeltaEncoder};
 
 // TODO Error handling, error states if one stream fails
 
 pub struct VisualizationTranslator {
     session_id: AgentID,
     visualization_server: Box<dyn FeagiServerPublisher>,
+    compressor: PayloadCompressor,
+    /// Set when this agent negotiated delta-coded visualization frames
+    delta_encoder: Option<XYZPDeltaEncoder>,
+    delta_buffer: FeagiByteContainer,
 }
 
 impl VisualizationTranslator {
-    pub fn new(session_id: AgentID, visualization_server: Box<dyn FeagiServerPublisher>) -> Self {
+    pub fn new(
+        session_id: AgentID,
+        visualization_server: Box<dyn FeagiServerPublisher>,
+        compressor: PayloadCompressor,
+        delta_encoder: Option<XYZPDeltaEncoder>,
+    ) -> Self {
         VisualizationTranslator {
             session_id,
             visualization_server,
+            compressor,
+            delta_encoder,
+            delta_buffer: FeagiByteContainer::new_empty(),
         }
     }
 
@@ -58,10 +71,13 @@ impl VisualizationTranslator {
         let viz_server = &mut self.visualization_server;
         let state = viz_server.poll();
         match state {
-            FeagiEndpointState::ActiveWaiting => {
-                viz_server.publish_data(viz_data.get_byte_ref())?;
-                Ok(())
-            }
+            FeagiEndpointState::ActiveWaiting => send_possibly_delta_coded(
+                viz_server.as_mut(),
+                &mut self.compressor,
+                self.delta_encoder.as_mut(),
+                &mut self.delta_buffer,
+                viz_data,
+            ),
             _ => Err(FeagiAgentError::UnableToSendData(
                 "Visualization socket not ready!".to_string(),
             )),
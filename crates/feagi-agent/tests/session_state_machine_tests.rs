@@ -6,6 +6,7 @@ use feagi_agent::clients::{
 };
 use feagi_agent::{AgentCapabilities, AgentDescriptor, AuthToken};
 use feagi_io::traits_and_enums::shared::{FeagiEndpointState, TransportProtocolEndpoint};
+use feagi_serialization::PayloadCompression;
 
 fn make_init() -> SessionInit {
     let agent_descriptor =
@@ -18,6 +19,8 @@ fn make_init() -> SessionInit {
             AgentCapabilities::SendSensorData,
             AgentCapabilities::ReceiveMotorData,
         ],
+        supported_compression: PayloadCompression::SUPPORTED.to_vec(),
+        supports_xyzp_delta: true,
         timing: SessionTimingConfig {
             heartbeat_interval_ms: 1000,
             registration_deadline_ms: None,
@@ -85,3 +88,58 @@ fn registration_success_without_required_endpoints_fails() {
     assert!(matches!(sm.phase(), SessionPhase::Failed));
     assert!(sm.last_error().is_some());
 }
+
+#[test]
+fn registration_success_with_compression_records_negotiated_streams() {
+    use feagi_agent::command_and_control::agent_registration_message::{
+        AgentRegistrationMessage, RegistrationResponse, StreamCompression,
+    };
+    use feagi_agent::command_and_control::FeagiMessage;
+    use feagi_io::protocol_implementations::zmq::ZmqUrl;
+
+    let mut sm = SessionStateMachine::new(make_init());
+    let _ = sm.start_connect(0);
+    let actions = sm.step(
+        1,
+        &[SessionEvent::ControlObserved {
+            state: FeagiEndpointState::ActiveWaiting,
+            message: None,
+        }],
+    );
+    assert!(actions.iter().any(|a| matches!(
+        a,
+        SessionAction::ControlSendRegistration { supported_compression, supports_xyzp_delta, .. }
+            if supported_compression == &PayloadCompression::SUPPORTED.to_vec()
+                && *supports_xyzp_delta
+    )));
+
+    let endpoint = TransportProtocolEndpoint::Zmq(ZmqUrl::new("tcp://example:1").unwrap());
+    let mut endpoints: HashMap<AgentCapabilities, TransportProtocolEndpoint> = HashMap::new();
+    endpoints.insert(AgentCapabilities::SendSensorData, endpoint.clone());
+    endpoints.insert(AgentCapabilities::ReceiveMotorData, endpoint);
+    let negotiated = StreamCompression::new(PayloadCompression::Zstd, 100, false, true, false);
+    let msg =
+        FeagiMessage::AgentRegistration(AgentRegistrationMessage::ServerRespondsRegistration(
+            RegistrationResponse::SuccessWithCompression(
+                feagi_io::AgentID::new_blank(),
+                endpoints,
+                negotiated,
+            ),
+        ));
+    let actions = sm.step(
+        2,
+        &[SessionEvent::ControlObserved {
+            state: FeagiEndpointState::ActiveWaiting,
+            message: Some(msg),
+        }],
+    );
+    assert!(matches!(sm.phase(), SessionPhase::DataConnecting));
+    assert_eq!(actions.len(), 2);
+    assert_eq!(sm.stream_compression(), &negotiated);
+    assert!(sm
+        .stream_compression()
+        .is_enabled_for(AgentCapabilities::ReceiveMotorData));
+    assert!(!sm
+        .stream_compression()
+        .is_enabled_for(AgentCapabilities::SendSensorData));
+}
@@ -254,6 +254,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         feagi_session_timestamp
     );
 
+    // Journal state changes from startup on (off unless [event_journal] enables it)
+    let feagi_config = feagi_config::load_config(None, None).unwrap_or_default();
+    if ApiState::init_event_journal(&feagi_config).is_some() {
+        println!(
+            "✅ State event journal: {}\n",
+            feagi_config.event_journal.path
+        );
+    }
+
     // ========================================================================
     // STEP 4: Create and Start HTTP Server
     // ========================================================================
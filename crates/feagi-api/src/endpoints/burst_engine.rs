@@ -558,7 +558,8 @@ pub async fn get_stats(
     }
 }
 
-/// Get burst engine status including active state, pause state, and burst count.
+/// Get burst engine status including active state, pause state, burst count, and the
+/// error that stopped the engine (null while healthy).
 #[utoipa::path(
     get,
     path = "/v1/burst_engine/status",
@@ -586,6 +587,7 @@ pub async fn get_status(
                 "frequency_hz".to_string(),
                 serde_json::json!(status.frequency_hz),
             );
+            response.insert("error".to_string(), serde_json::json!(status.error));
 
             Ok(Json(response))
         }
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 
 use crate::amalgamation;
 use crate::common::ApiState;
-use crate::common::{ApiError, ApiResult, Json, State};
+use crate::common::{ApiError, ApiResult, Json, Query, State};
 
 // ============================================================================
 // REQUEST/RESPONSE MODELS (matching Python schemas exactly)
@@ -723,6 +723,95 @@ pub async fn get_unique_logs(
     Ok(Json(response))
 }
 
+/// Filters for querying the state event journal
+#[derive(Debug, Clone, Default, Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct StateEventQuery {
+    /// Only return events with a sequence number >= this value
+    pub from_sequence: Option<u64>,
+    /// Only return events recorded at or after this time (ms since Unix epoch)
+    pub start_ms: Option<u64>,
+    /// Only return events recorded at or before this time (ms since Unix epoch)
+    pub end_ms: Option<u64>,
+    /// Maximum number of events to return (most recent are kept)
+    pub limit: Option<usize>,
+}
+
+/// One journaled state change
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StateEventRecord {
+    pub sequence: u64,
+    pub timestamp_ms: u64,
+    /// Component or client that caused the change
+    pub actor: String,
+    /// Event kind, e.g. `cortical_area_created`
+    pub event_type: String,
+    /// Event payload (tagged with `type`)
+    pub event: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StateEventsResponse {
+    /// Sequence number of the most recent event in the journal
+    pub last_sequence: u64,
+    pub events: Vec<StateEventRecord>,
+}
+
+/// Query the persistent state event journal (genome loads, cortical area and
+/// mapping changes, agent registrations, burst engine state changes).
+#[utoipa::path(
+    get,
+    path = "/v1/system/events",
+    tag = "system",
+    params(StateEventQuery),
+    responses(
+        (status = 200, description = "Journaled state events", body = StateEventsResponse),
+        (status = 501, description = "Event journal not enabled")
+    )
+)]
+pub async fn get_state_events(
+    State(_state): State<ApiState>,
+    Query(query): Query<StateEventQuery>,
+) -> ApiResult<Json<StateEventsResponse>> {
+    #[cfg(feature = "services")]
+    {
+        let journal = feagi_state_manager::StateManager::instance()
+            .read()
+            .get_event_journal()
+            .ok_or_else(|| ApiError::not_implemented("Event journal is not enabled"))?;
+
+        let records = journal
+            .query(&feagi_state_manager::JournalQuery {
+                from_sequence: query.from_sequence,
+                start_ms: query.start_ms,
+                end_ms: query.end_ms,
+                limit: query.limit,
+            })
+            .map_err(|e| ApiError::internal(format!("Failed to read event journal: {}", e)))?;
+
+        let events = records
+            .into_iter()
+            .map(|record| StateEventRecord {
+                sequence: record.sequence,
+                timestamp_ms: record.timestamp_ms,
+                actor: record.actor,
+                event_type: record.event.kind().to_string(),
+                event: serde_json::to_value(&record.event).unwrap_or(serde_json::Value::Null),
+            })
+            .collect();
+
+        Ok(Json(StateEventsResponse {
+            last_sequence: journal.last_sequence(),
+            events,
+        }))
+    }
+    #[cfg(not(feature = "services"))]
+    {
+        let _ = query;
+        Err(ApiError::not_implemented("Event journal is not enabled"))
+    }
+}
+
 /// Configure logging settings including log level and output destinations.
 #[utoipa::path(
     post,
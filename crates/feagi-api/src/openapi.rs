@@ -118,6 +118,8 @@ use crate::{
         crate::endpoints::system::post_fcl_reset_system,
         crate::endpoints::system::get_processes,
         crate::endpoints::system::get_unique_logs,
+        crate::endpoints::system::get_state_events,
+        crate::transports::http::push::get_state_change_stream,
         crate::endpoints::system::post_logs,
         crate::endpoints::system::get_beacon_subscribers,
         crate::endpoints::system::post_beacon_subscribe,
@@ -528,6 +530,11 @@ use crate::{
 
             // System
             crate::endpoints::system::HealthCheckResponse,
+            crate::endpoints::system::StateEventQuery,
+            crate::endpoints::system::StateEventRecord,
+            crate::endpoints::system::StateEventsResponse,
+            crate::transports::http::push::StateSnapshotPush,
+            crate::transports::http::push::HashChangedPush,
 
             // Cortical Area
             crate::endpoints::cortical_area::CorticalAreaIdListResponse,
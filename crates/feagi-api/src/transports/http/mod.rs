@@ -3,6 +3,7 @@
 
 // HTTP transport adapter (Axum)
 
+pub mod push;
 pub mod server;
 
 // TODO: Add more HTTP modules
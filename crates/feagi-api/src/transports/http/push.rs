@@ -0,0 +1,164 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Server-sent events push channel for state changes
+//
+// Clients (e.g. Brain Visualizer) subscribe to `/v1/system/events/stream`
+// instead of polling `/v1/system/health_check`. The stream starts with a
+// `snapshot` event carrying every hash and the burst engine state, followed by
+// one typed event per change:
+//
+// - `<kind>_hash_changed` (e.g. `cortical_areas_hash_changed`) with the new hash
+// - the state event kind (e.g. `burst_engine_state_changed`, `agent_registered`)
+//   with the event payload
+//
+// Changes come from the state manager's bounded broadcast channel. A client
+// that falls too far behind skips the missed changes and is sent a fresh
+// `snapshot` instead.
+
+use std::convert::Infallible;
+
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use feagi_state_manager::{HashKind, StateChange, StateManager};
+use serde::{Deserialize, Serialize};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+
+use crate::common::ApiState;
+
+/// Payload of the initial `snapshot` event
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct StateSnapshotPush {
+    pub brain_regions_hash: u64,
+    pub cortical_areas_hash: u64,
+    pub brain_geometry_hash: u64,
+    pub morphologies_hash: u64,
+    pub cortical_mappings_hash: u64,
+    pub agent_data_hash: u64,
+    pub burst_engine_state: String,
+}
+
+impl StateSnapshotPush {
+    fn capture(state_manager: &StateManager) -> Self {
+        Self {
+            brain_regions_hash: state_manager.get_hash(HashKind::BrainRegions),
+            cortical_areas_hash: state_manager.get_hash(HashKind::CorticalAreas),
+            brain_geometry_hash: state_manager.get_hash(HashKind::BrainGeometry),
+            morphologies_hash: state_manager.get_hash(HashKind::Morphologies),
+            cortical_mappings_hash: state_manager.get_hash(HashKind::CorticalMappings),
+            agent_data_hash: state_manager.get_hash(HashKind::AgentData),
+            burst_engine_state: format!("{:?}", state_manager.get_burst_engine_state()),
+        }
+    }
+}
+
+/// Payload of `<kind>_hash_changed` events
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct HashChangedPush {
+    pub hash: u64,
+}
+
+/// Stream state changes as server-sent events.
+#[utoipa::path(
+    get,
+    path = "/v1/system/events/stream",
+    tag = "system",
+    responses(
+        (status = 200, description = "Server-sent event stream of state changes", content_type = "text/event-stream", body = String)
+    )
+)]
+pub async fn get_state_change_stream(
+    State(_state): State<ApiState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let (snapshot, changes) = {
+        let state_manager = StateManager::instance();
+        let state_manager = state_manager.read();
+        // Subscribe before taking the snapshot so no change falls in between
+        let changes = state_manager.subscribe_changes();
+        (StateSnapshotPush::capture(&state_manager), changes)
+    };
+
+    let changes = BroadcastStream::new(changes).filter_map(|change| match change {
+        Ok(change) => state_change_to_sse(&change),
+        Err(BroadcastStreamRecvError::Lagged(missed)) => {
+            tracing::debug!(target: "feagi-api", "State change stream lagged by {} changes, resending snapshot", missed);
+            let snapshot = StateSnapshotPush::capture(&StateManager::instance().read());
+            Some(snapshot_to_sse(&snapshot))
+        }
+    });
+    let stream = tokio_stream::once(snapshot_to_sse(&snapshot)).chain(changes);
+
+    Sse::new(stream.map(Ok)).keep_alive(KeepAlive::default())
+}
+
+/// SSE event name for a state change
+pub fn state_change_event_name(change: &StateChange) -> String {
+    match change {
+        StateChange::HashChanged { kind, .. } => format!("{}_hash_changed", kind.as_str()),
+        StateChange::Event(event) => event.kind().to_string(),
+    }
+}
+
+/// JSON payload for a state change
+pub fn state_change_payload(change: &StateChange) -> serde_json::Result<serde_json::Value> {
+    match change {
+        StateChange::HashChanged { hash, .. } => {
+            serde_json::to_value(HashChangedPush { hash: *hash })
+        }
+        StateChange::Event(event) => serde_json::to_value(event),
+    }
+}
+
+fn snapshot_to_sse(snapshot: &StateSnapshotPush) -> Event {
+    Event::default()
+        .event("snapshot")
+        .json_data(snapshot)
+        .unwrap_or_else(|_| Event::default().event("snapshot"))
+}
+
+fn state_change_to_sse(change: &StateChange) -> Option<Event> {
+    let payload = state_change_payload(change).ok()?;
+    Event::default()
+        .event(state_change_event_name(change))
+        .json_data(payload)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feagi_state_manager::{BurstEngineState, StateEvent};
+
+    #[test]
+    fn test_hash_change_naming_and_payload() {
+        let change = StateChange::HashChanged {
+            kind: HashKind::CorticalMappings,
+            hash: 7,
+        };
+        assert_eq!(
+            state_change_event_name(&change),
+            "cortical_mappings_hash_changed"
+        );
+        assert_eq!(
+            state_change_payload(&change).unwrap(),
+            serde_json::json!({ "hash": 7 })
+        );
+    }
+
+    #[test]
+    fn test_state_event_naming_and_payload() {
+        let change = StateChange::Event(StateEvent::BurstEngineStateChanged {
+            state: BurstEngineState::Paused,
+        });
+        assert_eq!(
+            state_change_event_name(&change),
+            "burst_engine_state_changed"
+        );
+        assert_eq!(
+            state_change_payload(&change).unwrap(),
+            serde_json::json!({ "type": "burst_engine_state_changed", "state": "Paused" })
+        );
+    }
+}
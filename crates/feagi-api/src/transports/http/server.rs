@@ -30,6 +30,18 @@ use crate::openapi::ApiDoc;
 #[cfg(feature = "feagi-agent")]
 use feagi_config::load_config;
 #[cfg(feature = "feagi-agent")]
+use feagi_io::protocol_implementations::shared_memory::{
+    FeagiSharedMemoryServerPublisherProperties, FeagiSharedMemoryServerPullerProperties,
+};
+#[cfg(feature = "feagi-agent")]
+use feagi_io::protocol_implementations::tcp::{
+    FeagiTcpServerPublisherProperties, FeagiTcpServerPullerProperties,
+};
+#[cfg(all(feature = "feagi-agent", unix))]
+use feagi_io::protocol_implementations::unix_socket::{
+    FeagiUnixSocketServerPublisherProperties, FeagiUnixSocketServerPullerProperties,
+};
+#[cfg(feature = "feagi-agent")]
 use feagi_io::protocol_implementations::websocket::websocket_std::{
     FeagiWebSocketServerPublisherProperties, FeagiWebSocketServerPullerProperties,
 };
@@ -170,15 +182,190 @@ impl ApiState {
             handler.add_publisher_server(Box::new(visualization));
         }
 
+        if available_transports
+            .iter()
+            .any(|transport| transport == "shm")
+        {
+            let instance_name = config.shm.resolved_instance_name();
+            let ring_path = |name: &str| {
+                std::path::Path::new(&config.shm.directory)
+                    .join(format!("feagi_{instance_name}_{name}.ring"))
+                    .to_string_lossy()
+                    .into_owned()
+            };
+            let sensory_path = ring_path("sensory");
+            let motor_path = ring_path("motor");
+            let visualization_path = ring_path("visualization");
+
+            let sensory = FeagiSharedMemoryServerPullerProperties::new_with_ring_size(
+                &sensory_path,
+                &sensory_path,
+                config.shm.slot_count,
+                config.shm.max_frame_size,
+            )
+            .expect("Failed to create shared memory sensory puller properties");
+            handler.add_puller_server(Box::new(sensory));
+
+            let motor = FeagiSharedMemoryServerPublisherProperties::new_with_ring_size(
+                &motor_path,
+                &motor_path,
+                config.shm.slot_count,
+                config.shm.max_frame_size,
+            )
+            .expect("Failed to create shared memory motor publisher properties");
+            let visualization = FeagiSharedMemoryServerPublisherProperties::new_with_ring_size(
+                &visualization_path,
+                &visualization_path,
+                config.shm.slot_count,
+                config.shm.max_frame_size,
+            )
+            .expect("Failed to create shared memory visualization publisher properties");
+            handler.add_publisher_server(Box::new(motor));
+            handler.add_publisher_server(Box::new(visualization));
+        }
+
+        if available_transports
+            .iter()
+            .any(|transport| transport == "tcp")
+        {
+            let sensory_address =
+                format_tcp_endpoint(&config.tcp.bind_host, config.tcp.sensory_port);
+            let sensory_adv_address =
+                format_tcp_endpoint(&config.tcp.advertised_host, config.tcp.sensory_port);
+            let motor_address = format_tcp_endpoint(&config.tcp.bind_host, config.tcp.motor_port);
+            let motor_adv_address =
+                format_tcp_endpoint(&config.tcp.advertised_host, config.tcp.motor_port);
+            let visualization_address =
+                format_tcp_endpoint(&config.tcp.bind_host, config.tcp.visualization_port);
+            let visualization_adv_address =
+                format_tcp_endpoint(&config.tcp.advertised_host, config.tcp.visualization_port);
+
+            let sensory =
+                FeagiTcpServerPullerProperties::new(&sensory_address, &sensory_adv_address)
+                    .expect("Failed to create TCP sensory puller properties");
+            handler.add_puller_server(Box::new(sensory));
+
+            let motor = FeagiTcpServerPublisherProperties::new(&motor_address, &motor_adv_address)
+                .expect("Failed to create TCP motor publisher properties");
+            let visualization = FeagiTcpServerPublisherProperties::new(
+                &visualization_address,
+                &visualization_adv_address,
+            )
+            .expect("Failed to create TCP visualization publisher properties");
+            handler.add_publisher_server(Box::new(motor));
+            handler.add_publisher_server(Box::new(visualization));
+        }
+
+        #[cfg(unix)]
+        if available_transports
+            .iter()
+            .any(|transport| transport == "uds" || transport == "unix")
+        {
+            let socket_path = |name: &str| {
+                std::path::Path::new(&config.uds.directory)
+                    .join(format!("feagi_{name}.sock"))
+                    .to_string_lossy()
+                    .into_owned()
+            };
+            let sensory_path = socket_path("sensory");
+            let motor_path = socket_path("motor");
+            let visualization_path = socket_path("visualization");
+
+            let sensory = FeagiUnixSocketServerPullerProperties::new(&sensory_path, &sensory_path)
+                .expect("Failed to create Unix socket sensory puller properties");
+            handler.add_puller_server(Box::new(sensory));
+
+            let motor = FeagiUnixSocketServerPublisherProperties::new(&motor_path, &motor_path)
+                .expect("Failed to create Unix socket motor publisher properties");
+            let visualization = FeagiUnixSocketServerPublisherProperties::new(
+                &visualization_path,
+                &visualization_path,
+            )
+            .expect("Failed to create Unix socket visualization publisher properties");
+            handler.add_publisher_server(Box::new(motor));
+            handler.add_publisher_server(Box::new(visualization));
+        }
+
+        handler.set_stream_compression_policy(stream_compression_policy(&config.compression));
+
         Arc::new(std::sync::Mutex::new(handler))
     }
 
+    /// Open the state event journal from the server's `[event_journal]` settings
+    /// and attach it to the state manager.
+    ///
+    /// Call once at startup, before serving requests, so
+    /// GET /v1/system/events covers everything the API can change.
+    /// Does nothing if the journal is disabled or one is already attached.
+    /// Failing to open the journal is logged and leaves journaling off.
+    #[cfg(feature = "services")]
+    pub fn init_event_journal(
+        config: &feagi_config::FeagiConfig,
+    ) -> Option<Arc<feagi_state_manager::EventJournal>> {
+        let state_manager = feagi_state_manager::StateManager::instance();
+        if let Some(journal) = state_manager.read().get_event_journal() {
+            return Some(journal);
+        }
+
+        let config = &config.event_journal;
+        if !config.enabled {
+            return None;
+        }
+        let limits = feagi_state_manager::JournalLimits {
+            max_segment_bytes: config.max_file_size_mb.saturating_mul(1024 * 1024),
+            max_segments: config.max_files,
+        };
+        match feagi_state_manager::EventJournal::open_with_limits(&config.path, limits) {
+            Ok(journal) => {
+                let journal = Arc::new(journal);
+                state_manager.read().attach_event_journal(journal.clone());
+                tracing::info!(target: "feagi-api", "Journaling state events to {}", config.path);
+                Some(journal)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    target: "feagi-api",
+                    "Event journal disabled: cannot open {}: {}",
+                    config.path,
+                    e
+                );
+                None
+            }
+        }
+    }
+
     /// Initialize amalgamation_state field (empty state).
     pub fn init_amalgamation_state() -> amalgamation::SharedAmalgamationState {
         amalgamation::new_shared_state()
     }
 }
 
+/// Map `[compression]` settings onto the policy offered to agents during registration
+#[cfg(feature = "feagi-agent")]
+fn stream_compression_policy(
+    config: &feagi_config::CompressionConfig,
+) -> feagi_agent::server::StreamCompressionPolicy {
+    use feagi_serialization::PayloadCompression;
+
+    let algorithm = if config.enabled {
+        PayloadCompression::from_name(&config.algorithm).unwrap_or_else(|err| {
+            tracing::warn!("Agent stream compression disabled: {}", err);
+            PayloadCompression::None
+        })
+    } else {
+        PayloadCompression::None
+    };
+    feagi_agent::server::StreamCompressionPolicy {
+        algorithm,
+        min_size_threshold: config.min_size_threshold,
+        compress_sensory: config.compress_sensory,
+        compress_motor: config.compress_motor,
+        compress_visualization: config.compress_visualization,
+        xyzp_delta_keyframe_interval: config.xyzp_delta_keyframe_interval,
+        max_decompressed_byte_count: config.max_decompressed_size,
+    }
+}
+
 #[cfg(feature = "feagi-agent")]
 fn format_tcp_endpoint(host: &str, port: u16) -> String {
     if host.contains(':') {
@@ -211,9 +398,17 @@ pub fn create_http_server(state: ApiState) -> Router {
             Json(ApiDoc::openapi())
         }))
 
+        // OpenAPI spec for the typed v2 API
+        .route("/api-docs/v2/openapi.json", get(|| async {
+            Json(crate::v2::openapi::ApiDocV2::openapi())
+        }))
+
         // Python-compatible paths: /v1/* (ONLY this, matching Python exactly)
         .nest("/v1", create_v1_router())
 
+        // Typed API: /v2/* (side-by-side with v1)
+        .nest("/v2", crate::v2::create_v2_router())
+
         // Catch-all route for debugging unmatched requests
         .fallback(|| async {
             tracing::warn!(target: "feagi-api", "Unmatched request - 404 Not Found");
@@ -335,6 +530,11 @@ fn create_v1_router() -> Router<ApiState> {
         )
         .route("/system/processes", get(system::get_processes))
         .route("/system/unique_logs", get(system::get_unique_logs))
+        .route("/system/events", get(system::get_state_events))
+        .route(
+            "/system/events/stream",
+            get(super::push::get_state_change_stream),
+        )
         .route("/system/logs", axum::routing::post(system::post_logs))
         .route(
             "/system/beacon/subscribers",
@@ -1170,6 +1370,16 @@ async fn log_request_response_bodies(
     // Call the next handler
     let response = next.run(request).await;
 
+    // Streaming responses (server-sent events) never end; pass them through untouched
+    let is_event_stream = response
+        .headers()
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if is_event_stream {
+        return Ok(response);
+    }
+
     // Log response body
     let (parts, body) = response.into_parts();
 
@@ -30,6 +30,7 @@ impl RuntimeService for WasmRuntimeService {
             current_rate_hz: 0.0,       // TODO: Get from NPU if available
             last_burst_neuron_count: 0, // TODO: Get from NPU if available
             avg_burst_time_ms: 0.0,     // TODO: Get from NPU if available
+            error: None,
         })
     }
 
@@ -43,6 +44,22 @@ impl RuntimeService for WasmRuntimeService {
         ))
     }
 
+    async fn get_pacing(&self) -> ServiceResult<BurstPacingMode> {
+        Ok(BurstPacingMode::RealTime)
+    }
+
+    async fn set_pacing(&self, _pacing: BurstPacingMode) -> ServiceResult<()> {
+        Err(ServiceError::NotImplemented(
+            "WASM mode burst pacing not yet implemented".to_string(),
+        ))
+    }
+
+    async fn tick(&self, _request: BurstTickRequest) -> ServiceResult<BurstTickResult> {
+        Err(ServiceError::NotImplemented(
+            "WASM mode burst pacing not yet implemented".to_string(),
+        ))
+    }
+
     async fn start(&self) -> ServiceResult<()> {
         Err(ServiceError::NotImplemented(
             "WASM mode runtime control not yet implemented".to_string(),
@@ -0,0 +1,587 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Typed request/response DTOs for the v2 API
+
+use feagi_services::traits::agent_service::AgentProperties;
+use feagi_services::types::{
+    BrainRegionInfo, BurstPacingMode, BurstTickRequest, BurstTickResult, CorticalAreaInfo,
+    CorticalXyzpData, GenomeInfo, MorphologyInfo, RuntimeStatus,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use super::error::{V2Error, V2Result};
+
+pub use crate::v1::{Coordinates3D, Dimensions3D};
+
+// ============================================================================
+// CORTICAL AREAS
+// ============================================================================
+
+/// Cortical area summary
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CorticalAreaV2 {
+    /// Base64 cortical ID
+    pub cortical_id: String,
+    /// Human-readable ASCII cortical ID
+    pub cortical_id_s: String,
+    pub cortical_idx: u32,
+    pub name: String,
+    /// "sensory", "motor", "memory", "custom" or "core"
+    pub cortical_type: String,
+    /// "IPU", "OPU", "CORE", "CUSTOM" or "MEMORY"
+    pub cortical_group: String,
+    pub dimensions: Dimensions3D,
+    pub position: Coordinates3D,
+    pub neuron_count: usize,
+    pub incoming_synapse_count: usize,
+    pub outgoing_synapse_count: usize,
+    pub visible: bool,
+    pub parent_region_id: Option<String>,
+}
+
+impl From<CorticalAreaInfo> for CorticalAreaV2 {
+    fn from(info: CorticalAreaInfo) -> Self {
+        Self {
+            cortical_id: info.cortical_id,
+            cortical_id_s: info.cortical_id_s,
+            cortical_idx: info.cortical_idx,
+            name: info.name,
+            cortical_type: info.cortical_type,
+            cortical_group: info.cortical_group,
+            dimensions: Dimensions3D {
+                x: info.dimensions.0 as u32,
+                y: info.dimensions.1 as u32,
+                z: info.dimensions.2 as u32,
+            },
+            position: Coordinates3D {
+                x: info.position.0,
+                y: info.position.1,
+                z: info.position.2,
+            },
+            neuron_count: info.neuron_count,
+            incoming_synapse_count: info.incoming_synapse_count,
+            outgoing_synapse_count: info.outgoing_synapse_count,
+            visible: info.visible,
+            parent_region_id: info.parent_region_id,
+        }
+    }
+}
+
+// ============================================================================
+// BRAIN REGIONS
+// ============================================================================
+
+/// Brain region summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BrainRegionV2 {
+    pub region_id: String,
+    pub name: String,
+    pub region_type: String,
+    pub parent_id: Option<String>,
+    /// Cortical IDs directly contained in this region
+    pub cortical_areas: Vec<String>,
+    /// Region IDs of direct children
+    pub child_regions: Vec<String>,
+}
+
+impl From<BrainRegionInfo> for BrainRegionV2 {
+    fn from(info: BrainRegionInfo) -> Self {
+        Self {
+            region_id: info.region_id,
+            name: info.name,
+            region_type: info.region_type,
+            parent_id: info.parent_id,
+            cortical_areas: info.cortical_areas,
+            child_regions: info.child_regions,
+        }
+    }
+}
+
+// ============================================================================
+// MORPHOLOGIES
+// ============================================================================
+
+/// Morphology definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MorphologyV2 {
+    pub morphology_id: String,
+    pub morphology_type: String,
+    pub class: String,
+    /// Type-specific parameters (vectors, patterns, function name, ...)
+    #[schema(value_type = Object)]
+    pub parameters: serde_json::Value,
+}
+
+impl MorphologyV2 {
+    pub fn from_info(morphology_id: String, info: MorphologyInfo) -> Self {
+        Self {
+            morphology_id,
+            morphology_type: info.morphology_type,
+            class: info.class,
+            parameters: info.parameters,
+        }
+    }
+}
+
+// ============================================================================
+// GENOME
+// ============================================================================
+
+/// Currently loaded genome
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GenomeV2 {
+    pub genome_id: String,
+    pub genome_title: String,
+    pub version: String,
+    pub cortical_area_count: usize,
+    pub brain_region_count: usize,
+    /// Simulation timestep in seconds
+    pub simulation_timestep: f64,
+    pub genome_num: Option<i32>,
+    /// Unix timestamp when the genome was loaded or created
+    pub genome_timestamp: Option<i64>,
+}
+
+impl From<GenomeInfo> for GenomeV2 {
+    fn from(info: GenomeInfo) -> Self {
+        Self {
+            genome_id: info.genome_id,
+            genome_title: info.genome_title,
+            version: info.version,
+            cortical_area_count: info.cortical_area_count,
+            brain_region_count: info.brain_region_count,
+            simulation_timestep: info.simulation_timestep,
+            genome_num: info.genome_num,
+            genome_timestamp: info.genome_timestamp,
+        }
+    }
+}
+
+/// FEAGI genome document
+///
+/// Accepts both blueprint layouts the genome loader understands: hierarchical (keyed by
+/// cortical ID) and flat (one key per gene). Other top-level fields are passed to the loader
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GenomeDocumentV2 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genome_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genome_title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub genome_description: Option<String>,
+    /// Genome format version ("2.x" or "3.0")
+    pub version: String,
+    /// Cortical area definitions
+    #[schema(value_type = Object)]
+    pub blueprint: serde_json::Map<String, serde_json::Value>,
+    /// Morphology definitions, keyed by morphology ID
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[schema(value_type = Object)]
+    pub neuron_morphologies: serde_json::Map<String, serde_json::Value>,
+    /// Brain region hierarchy, keyed by region ID
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    #[schema(value_type = Object)]
+    pub brain_regions: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub physiology: Option<serde_json::Value>,
+    /// Any other top-level genome fields
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Load genome request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LoadGenomeRequestV2 {
+    pub genome: GenomeDocumentV2,
+}
+
+// ============================================================================
+// BURST ENGINE
+// ============================================================================
+
+/// Burst engine run state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BurstEngineStateV2 {
+    Running,
+    Paused,
+    Stopped,
+    /// Stopped itself on an unrecoverable error (see `error`)
+    Error,
+}
+
+/// Burst engine status
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BurstEngineV2 {
+    pub state: BurstEngineStateV2,
+    /// Configured burst frequency (Hz)
+    pub frequency_hz: f64,
+    /// Bursts since start
+    pub burst_count: u64,
+    /// Measured burst rate (Hz)
+    pub current_rate_hz: f64,
+    /// Neurons fired in the last burst
+    pub last_burst_neuron_count: usize,
+    /// Average processing time per burst (ms)
+    pub avg_burst_time_ms: f64,
+    /// Why the burst engine stopped itself, if it did
+    pub error: Option<String>,
+}
+
+impl From<RuntimeStatus> for BurstEngineV2 {
+    fn from(status: RuntimeStatus) -> Self {
+        let state = match (status.is_running, status.is_paused) {
+            (_, true) => BurstEngineStateV2::Paused,
+            (true, false) => BurstEngineStateV2::Running,
+            (false, false) if status.error.is_some() => BurstEngineStateV2::Error,
+            (false, false) => BurstEngineStateV2::Stopped,
+        };
+        Self {
+            state,
+            frequency_hz: status.frequency_hz,
+            burst_count: status.burst_count,
+            current_rate_hz: status.current_rate_hz,
+            last_burst_neuron_count: status.last_burst_neuron_count,
+            avg_burst_time_ms: status.avg_burst_time_ms,
+            error: status.error,
+        }
+    }
+}
+
+/// Burst engine control command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BurstEngineCommandV2 {
+    Start,
+    Stop,
+    Pause,
+    Resume,
+}
+
+/// Burst pacing: wall clock or one burst per tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BurstPacingV2 {
+    RealTime,
+    Lockstep,
+}
+
+impl From<BurstPacingMode> for BurstPacingV2 {
+    fn from(mode: BurstPacingMode) -> Self {
+        match mode {
+            BurstPacingMode::RealTime => BurstPacingV2::RealTime,
+            BurstPacingMode::Lockstep => BurstPacingV2::Lockstep,
+        }
+    }
+}
+
+impl From<BurstPacingV2> for BurstPacingMode {
+    fn from(pacing: BurstPacingV2) -> Self {
+        match pacing {
+            BurstPacingV2::RealTime => BurstPacingMode::RealTime,
+            BurstPacingV2::Lockstep => BurstPacingMode::Lockstep,
+        }
+    }
+}
+
+/// Burst pacing setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BurstPacingSettingV2 {
+    pub pacing: BurstPacingV2,
+}
+
+/// XYZP neuron data for one cortical area (parallel arrays)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CorticalXyzpV2 {
+    /// Base64 cortical ID
+    pub cortical_id: String,
+    pub x: Vec<u32>,
+    pub y: Vec<u32>,
+    pub z: Vec<u32>,
+    pub p: Vec<f32>,
+}
+
+impl From<CorticalXyzpData> for CorticalXyzpV2 {
+    fn from(data: CorticalXyzpData) -> Self {
+        Self {
+            cortical_id: data.cortical_id,
+            x: data.x,
+            y: data.y,
+            z: data.z,
+            p: data.p,
+        }
+    }
+}
+
+impl From<CorticalXyzpV2> for CorticalXyzpData {
+    fn from(data: CorticalXyzpV2) -> Self {
+        Self {
+            cortical_id: data.cortical_id,
+            x: data.x,
+            y: data.y,
+            z: data.z,
+            p: data.p,
+        }
+    }
+}
+
+/// Lockstep tick: sensory input for one burst
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BurstTickRequestV2 {
+    /// Sensory data for this burst; empty uses the agents' sensory stream
+    #[serde(default)]
+    pub sensory: Vec<CorticalXyzpV2>,
+    /// Motor cortical IDs to return; omitted returns every brain output area
+    #[serde(default)]
+    pub motor_cortical_ids: Option<Vec<String>>,
+    /// How long to wait for the burst (ms, default 5000)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl From<BurstTickRequestV2> for BurstTickRequest {
+    fn from(request: BurstTickRequestV2) -> Self {
+        Self {
+            sensory: request.sensory.into_iter().map(Into::into).collect(),
+            motor_cortical_ids: request.motor_cortical_ids,
+            timeout_ms: request.timeout_ms,
+        }
+    }
+}
+
+/// Lockstep tick result: motor output of the burst
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BurstTickV2 {
+    /// Burst number of the burst this tick ran
+    pub burst: u64,
+    /// Neurons fired in the burst
+    pub fired_neuron_count: usize,
+    /// Fired neurons of the requested motor areas, sorted by cortical ID
+    pub motor: Vec<CorticalXyzpV2>,
+}
+
+impl From<BurstTickResult> for BurstTickV2 {
+    fn from(result: BurstTickResult) -> Self {
+        Self {
+            burst: result.burst,
+            fired_neuron_count: result.fired_neuron_count,
+            motor: result.motor.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// TRAINING
+// ============================================================================
+
+/// Reinforcement training status
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TrainingStatusV2 {
+    pub active: bool,
+    pub total_episodes: u64,
+    pub total_rewards: f64,
+    /// Current brain fitness score
+    pub brain_fitness: f64,
+}
+
+/// Reward or punishment signal
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReinforcementV2 {
+    /// Signal strength, from 0.0 to 1.0
+    pub intensity: f64,
+}
+
+impl ReinforcementV2 {
+    pub fn validate(&self) -> V2Result<()> {
+        if !(0.0..=1.0).contains(&self.intensity) {
+            return Err(V2Error::bad_request(format!(
+                "intensity must be between 0.0 and 1.0, got {}",
+                self.intensity
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Weights of the criteria that make up brain fitness
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FitnessCriteriaV2 {
+    /// Weight per criterion name
+    pub criteria: BTreeMap<String, f64>,
+}
+
+impl FitnessCriteriaV2 {
+    pub fn validate(&self) -> V2Result<()> {
+        match self
+            .criteria
+            .iter()
+            .find(|(_, weight)| !weight.is_finite() || **weight < 0.0)
+        {
+            Some((name, weight)) => Err(V2Error::bad_request(format!(
+                "weight of criterion '{}' must be a non-negative number, got {}",
+                name, weight
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+// ============================================================================
+// EVOLUTION
+// ============================================================================
+
+/// Evolution status
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EvolutionStatusV2 {
+    pub active: bool,
+    pub generation: u64,
+    pub population_size: usize,
+}
+
+// ============================================================================
+// SIMULATION
+// ============================================================================
+
+/// Stimulation simulation status
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SimulationStatusV2 {
+    pub active: bool,
+    pub stimulation_running: bool,
+    pub total_stimulations: u64,
+    pub active_scripts: usize,
+}
+
+// ============================================================================
+// AGENTS
+// ============================================================================
+
+/// Registered agent summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AgentV2 {
+    pub agent_id: String,
+    pub agent_type: String,
+    pub agent_version: String,
+    pub controller_version: String,
+    /// Transport the agent chose ("zmq", "websocket", "shm", ...)
+    pub chosen_transport: Option<String>,
+    /// Capability names declared at registration, sorted
+    pub capabilities: Vec<String>,
+}
+
+impl AgentV2 {
+    pub fn from_properties(agent_id: String, properties: AgentProperties) -> Self {
+        let mut capabilities: Vec<String> = properties.capabilities.into_keys().collect();
+        capabilities.sort();
+        Self {
+            agent_id,
+            agent_type: properties.agent_type,
+            agent_version: properties.agent_version,
+            controller_version: properties.controller_version,
+            chosen_transport: properties.chosen_transport,
+            capabilities,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_engine_state_mapping() {
+        let mut status = RuntimeStatus {
+            is_running: true,
+            is_paused: false,
+            frequency_hz: 30.0,
+            burst_count: 5,
+            current_rate_hz: 29.5,
+            last_burst_neuron_count: 12,
+            avg_burst_time_ms: 1.5,
+            error: None,
+        };
+        assert_eq!(
+            BurstEngineV2::from(status.clone()).state,
+            BurstEngineStateV2::Running
+        );
+        status.is_paused = true;
+        assert_eq!(
+            BurstEngineV2::from(status.clone()).state,
+            BurstEngineStateV2::Paused
+        );
+        status.is_running = false;
+        status.is_paused = false;
+        assert_eq!(
+            serde_json::to_value(BurstEngineV2::from(status.clone())).unwrap()["state"],
+            "stopped"
+        );
+        status.error = Some("Partition barrier failed".to_string());
+        assert_eq!(BurstEngineV2::from(status).state, BurstEngineStateV2::Error);
+    }
+
+    #[test]
+    fn test_genome_document_round_trip() {
+        let genome = serde_json::json!({
+            "genome_title": "Minimal",
+            "version": "2.1",
+            "blueprint": {"_power": {"cortical_name": "Power"}},
+            "evolution": {"generation": 3}
+        });
+        let request: LoadGenomeRequestV2 =
+            serde_json::from_value(serde_json::json!({ "genome": genome })).unwrap();
+        assert_eq!(request.genome.version, "2.1");
+        assert!(request.genome.neuron_morphologies.is_empty());
+        // Loading re-serializes the document, which must not lose or invent fields
+        assert_eq!(serde_json::to_value(&request.genome).unwrap(), genome);
+
+        assert!(serde_json::from_value::<LoadGenomeRequestV2>(
+            serde_json::json!({"genome": {"version": "2.1"}})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_training_request_validation() {
+        assert!(ReinforcementV2 { intensity: 0.5 }.validate().is_ok());
+        assert!(ReinforcementV2 { intensity: 1.5 }.validate().is_err());
+        assert!(ReinforcementV2 {
+            intensity: f64::NAN
+        }
+        .validate()
+        .is_err());
+
+        let mut criteria = FitnessCriteriaV2::default();
+        criteria.criteria.insert("speed".to_string(), 0.7);
+        assert!(criteria.validate().is_ok());
+        criteria.criteria.insert("accuracy".to_string(), -1.0);
+        assert!(criteria.validate().is_err());
+    }
+
+    #[test]
+    fn test_burst_tick_request_defaults() {
+        let request: BurstTickRequestV2 = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(request, BurstTickRequestV2::default());
+
+        let request: BurstTickRequestV2 = serde_json::from_value(serde_json::json!({
+            "sensory": [{"cortical_id": "aWQ=", "x": [1], "y": [2], "z": [0], "p": [0.5]}],
+            "timeout_ms": 250
+        }))
+        .unwrap();
+        let request = BurstTickRequest::from(request);
+        assert_eq!(request.sensory[0].x, vec![1]);
+        assert_eq!(request.timeout_ms, Some(250));
+        assert!(request.motor_cortical_ids.is_none());
+
+        let pacing: BurstPacingSettingV2 =
+            serde_json::from_value(serde_json::json!({"pacing": "lockstep"})).unwrap();
+        assert_eq!(
+            BurstPacingMode::from(pacing.pacing),
+            BurstPacingMode::Lockstep
+        );
+    }
+}
@@ -0,0 +1,463 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// v2 HTTP handlers
+//
+// Handlers are thin: call the service layer, convert to a v2 DTO, and map
+// every failure to `V2Error`. List endpoints are sorted by ID so pagination
+// is stable between requests.
+
+use axum::{
+    extract::State,
+    routing::{get, post},
+    Router,
+};
+use feagi_services::types::LoadGenomeParams;
+
+use super::dtos::{
+    AgentV2, BrainRegionV2, BurstEngineCommandV2, BurstEngineV2, BurstPacingSettingV2,
+    BurstTickRequestV2, BurstTickV2, CorticalAreaV2, EvolutionStatusV2, FitnessCriteriaV2,
+    GenomeV2, LoadGenomeRequestV2, MorphologyV2, ReinforcementV2, SimulationStatusV2,
+    TrainingStatusV2,
+};
+use super::error::{V2Error, V2Result};
+use super::extract::{Json, Path, Query};
+use super::pagination::{Page, PageQuery};
+use crate::transports::http::server::ApiState;
+
+/// Create the v2 router (nested under `/v2`)
+pub fn create_v2_router() -> Router<ApiState> {
+    Router::new()
+        .route("/cortical_areas", get(list_cortical_areas))
+        .route("/cortical_areas/:cortical_id", get(get_cortical_area))
+        .route("/brain_regions", get(list_brain_regions))
+        .route("/morphologies", get(list_morphologies))
+        .route("/genome", get(get_genome))
+        .route("/genome/load", post(load_genome))
+        .route("/burst_engine", get(get_burst_engine))
+        .route(
+            "/burst_engine/pacing",
+            get(get_burst_pacing).put(put_burst_pacing),
+        )
+        .route("/burst_engine/tick", post(tick_burst_engine))
+        .route("/burst_engine/:command", post(control_burst_engine))
+        .route("/agents", get(list_agents))
+        .route("/training", get(get_training))
+        .route("/training/reward", post(post_training_reward))
+        .route("/training/punishment", post(post_training_punishment))
+        .route(
+            "/training/fitness_criteria",
+            get(get_fitness_criteria).put(put_fitness_criteria),
+        )
+        .route("/evolution", get(get_evolution))
+        .route("/simulation", get(get_simulation))
+        .route("/simulation/reset", post(reset_simulation))
+}
+
+/// List cortical areas, sorted by cortical ID.
+#[utoipa::path(
+    get,
+    path = "/v2/cortical_areas",
+    tag = "v2",
+    params(PageQuery),
+    responses(
+        (status = 200, description = "Page of cortical areas", body = CorticalAreaPage),
+        (status = 400, description = "Invalid pagination", body = V2Error),
+        (status = 500, description = "Internal error", body = V2Error)
+    )
+)]
+pub async fn list_cortical_areas(
+    State(state): State<ApiState>,
+    Query(query): Query<PageQuery>,
+) -> V2Result<Json<Page<CorticalAreaV2>>> {
+    let mut areas: Vec<CorticalAreaV2> = state
+        .connectome_service
+        .list_cortical_areas()
+        .await?
+        .into_iter()
+        .map(CorticalAreaV2::from)
+        .collect();
+    areas.sort_by(|a, b| a.cortical_id.cmp(&b.cortical_id));
+    Ok(Json(Page::from_items(areas, &query)?))
+}
+
+/// Get one cortical area.
+#[utoipa::path(
+    get,
+    path = "/v2/cortical_areas/{cortical_id}",
+    tag = "v2",
+    params(("cortical_id" = String, Path, description = "Base64 cortical ID")),
+    responses(
+        (status = 200, description = "Cortical area", body = CorticalAreaV2),
+        (status = 404, description = "Cortical area not found", body = V2Error)
+    )
+)]
+pub async fn get_cortical_area(
+    State(state): State<ApiState>,
+    Path(cortical_id): Path<String>,
+) -> V2Result<Json<CorticalAreaV2>> {
+    let area = state
+        .connectome_service
+        .get_cortical_area(&cortical_id)
+        .await?;
+    Ok(Json(area.into()))
+}
+
+/// List brain regions, sorted by region ID.
+#[utoipa::path(
+    get,
+    path = "/v2/brain_regions",
+    tag = "v2",
+    params(PageQuery),
+    responses(
+        (status = 200, description = "Page of brain regions", body = BrainRegionPage),
+        (status = 400, description = "Invalid pagination", body = V2Error)
+    )
+)]
+pub async fn list_brain_regions(
+    State(state): State<ApiState>,
+    Query(query): Query<PageQuery>,
+) -> V2Result<Json<Page<BrainRegionV2>>> {
+    let mut regions: Vec<BrainRegionV2> = state
+        .connectome_service
+        .list_brain_regions()
+        .await?
+        .into_iter()
+        .map(BrainRegionV2::from)
+        .collect();
+    regions.sort_by(|a, b| a.region_id.cmp(&b.region_id));
+    Ok(Json(Page::from_items(regions, &query)?))
+}
+
+/// List morphologies, sorted by morphology ID.
+#[utoipa::path(
+    get,
+    path = "/v2/morphologies",
+    tag = "v2",
+    params(PageQuery),
+    responses(
+        (status = 200, description = "Page of morphologies", body = MorphologyPage),
+        (status = 400, description = "Invalid pagination", body = V2Error)
+    )
+)]
+pub async fn list_morphologies(
+    State(state): State<ApiState>,
+    Query(query): Query<PageQuery>,
+) -> V2Result<Json<Page<MorphologyV2>>> {
+    let mut morphologies: Vec<MorphologyV2> = state
+        .connectome_service
+        .get_morphologies()
+        .await?
+        .into_iter()
+        .map(|(id, info)| MorphologyV2::from_info(id, info))
+        .collect();
+    morphologies.sort_by(|a, b| a.morphology_id.cmp(&b.morphology_id));
+    Ok(Json(Page::from_items(morphologies, &query)?))
+}
+
+/// Get the currently loaded genome.
+#[utoipa::path(
+    get,
+    path = "/v2/genome",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Loaded genome", body = GenomeV2),
+        (status = 404, description = "No genome loaded", body = V2Error)
+    )
+)]
+pub async fn get_genome(State(state): State<ApiState>) -> V2Result<Json<GenomeV2>> {
+    let info = state.genome_service.get_genome_info().await?;
+    Ok(Json(info.into()))
+}
+
+/// Load a genome, replacing the current connectome.
+#[utoipa::path(
+    post,
+    path = "/v2/genome/load",
+    tag = "v2",
+    request_body = LoadGenomeRequestV2,
+    responses(
+        (status = 200, description = "Genome loaded", body = GenomeV2),
+        (status = 400, description = "Invalid genome", body = V2Error)
+    )
+)]
+pub async fn load_genome(
+    State(state): State<ApiState>,
+    Json(request): Json<LoadGenomeRequestV2>,
+) -> V2Result<Json<GenomeV2>> {
+    let json_str = serde_json::to_string(&request.genome)
+        .map_err(|e| V2Error::bad_request(format!("Invalid genome JSON: {}", e)))?;
+    let info = state
+        .genome_service
+        .load_genome(LoadGenomeParams { json_str })
+        .await?;
+    Ok(Json(info.into()))
+}
+
+/// Get burst engine status.
+#[utoipa::path(
+    get,
+    path = "/v2/burst_engine",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Burst engine status", body = BurstEngineV2)
+    )
+)]
+pub async fn get_burst_engine(State(state): State<ApiState>) -> V2Result<Json<BurstEngineV2>> {
+    let status = state.runtime_service.get_status().await?;
+    Ok(Json(status.into()))
+}
+
+/// Start, stop, pause or resume the burst engine.
+#[utoipa::path(
+    post,
+    path = "/v2/burst_engine/{command}",
+    tag = "v2",
+    params(("command" = BurstEngineCommandV2, Path, description = "start, stop, pause or resume")),
+    responses(
+        (status = 200, description = "Burst engine status after the command", body = BurstEngineV2),
+        (status = 409, description = "Command not valid in the current state", body = V2Error)
+    )
+)]
+pub async fn control_burst_engine(
+    State(state): State<ApiState>,
+    Path(command): Path<BurstEngineCommandV2>,
+) -> V2Result<Json<BurstEngineV2>> {
+    let runtime = &state.runtime_service;
+    match command {
+        BurstEngineCommandV2::Start => runtime.start().await?,
+        BurstEngineCommandV2::Stop => runtime.stop().await?,
+        BurstEngineCommandV2::Pause => runtime.pause().await?,
+        BurstEngineCommandV2::Resume => runtime.resume().await?,
+    }
+    let status = runtime.get_status().await?;
+    Ok(Json(status.into()))
+}
+
+/// Get burst pacing (wall clock or lockstep).
+#[utoipa::path(
+    get,
+    path = "/v2/burst_engine/pacing",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Current burst pacing", body = BurstPacingSettingV2)
+    )
+)]
+pub async fn get_burst_pacing(
+    State(state): State<ApiState>,
+) -> V2Result<Json<BurstPacingSettingV2>> {
+    let pacing = state.runtime_service.get_pacing().await?;
+    Ok(Json(BurstPacingSettingV2 {
+        pacing: pacing.into(),
+    }))
+}
+
+/// Switch between wall-clock pacing and lockstep (one burst per tick).
+#[utoipa::path(
+    put,
+    path = "/v2/burst_engine/pacing",
+    tag = "v2",
+    request_body = BurstPacingSettingV2,
+    responses(
+        (status = 200, description = "Burst pacing after the change", body = BurstPacingSettingV2),
+        (status = 501, description = "Pacing control not available", body = V2Error)
+    )
+)]
+pub async fn put_burst_pacing(
+    State(state): State<ApiState>,
+    Json(setting): Json<BurstPacingSettingV2>,
+) -> V2Result<Json<BurstPacingSettingV2>> {
+    state
+        .runtime_service
+        .set_pacing(setting.pacing.into())
+        .await?;
+    get_burst_pacing(State(state)).await
+}
+
+/// Run one lockstep burst with the given sensory input and return its motor output.
+#[utoipa::path(
+    post,
+    path = "/v2/burst_engine/tick",
+    tag = "v2",
+    request_body = BurstTickRequestV2,
+    responses(
+        (status = 200, description = "Motor output of the burst", body = BurstTickV2),
+        (status = 400, description = "Malformed sensory data", body = V2Error),
+        (status = 409, description = "Burst engine not running in lockstep pacing", body = V2Error),
+        (status = 500, description = "Burst failed or timed out", body = V2Error)
+    )
+)]
+pub async fn tick_burst_engine(
+    State(state): State<ApiState>,
+    Json(request): Json<BurstTickRequestV2>,
+) -> V2Result<Json<BurstTickV2>> {
+    let result = state.runtime_service.tick(request.into()).await?;
+    Ok(Json(result.into()))
+}
+
+/// List registered agents, sorted by agent ID.
+#[utoipa::path(
+    get,
+    path = "/v2/agents",
+    tag = "v2",
+    params(PageQuery),
+    responses(
+        (status = 200, description = "Page of agents", body = AgentPage),
+        (status = 503, description = "Agent service unavailable", body = V2Error)
+    )
+)]
+pub async fn list_agents(
+    State(state): State<ApiState>,
+    Query(query): Query<PageQuery>,
+) -> V2Result<Json<Page<AgentV2>>> {
+    let agent_service = state
+        .agent_service
+        .as_ref()
+        .ok_or_else(|| V2Error::unavailable("Agent service not available"))?;
+
+    let mut agent_ids = agent_service.list_agents().await?;
+    agent_ids.sort();
+    let (offset, limit) = query.resolve()?;
+    let total = agent_ids.len();
+
+    // Only fetch properties for the requested page
+    let mut items = Vec::new();
+    for agent_id in agent_ids.into_iter().skip(offset).take(limit) {
+        let properties = agent_service.get_agent_properties(&agent_id).await?;
+        items.push(AgentV2::from_properties(agent_id, properties));
+    }
+    let end = offset.saturating_add(items.len());
+    Ok(Json(Page {
+        items,
+        total,
+        offset,
+        limit,
+        next_offset: (end < total).then_some(end),
+    }))
+}
+
+/// Get reinforcement training status, statistics and brain fitness.
+#[utoipa::path(
+    get,
+    path = "/v2/training",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Training status", body = TrainingStatusV2)
+    )
+)]
+pub async fn get_training() -> V2Result<Json<TrainingStatusV2>> {
+    // No training runs in this build, so there is nothing to report yet
+    Ok(Json(TrainingStatusV2::default()))
+}
+
+/// Apply a reward signal.
+#[utoipa::path(
+    post,
+    path = "/v2/training/reward",
+    tag = "v2",
+    request_body = ReinforcementV2,
+    responses(
+        (status = 400, description = "Intensity out of range", body = V2Error),
+        (status = 501, description = "Reinforcement training not available", body = V2Error)
+    )
+)]
+pub async fn post_training_reward(
+    Json(signal): Json<ReinforcementV2>,
+) -> V2Result<Json<TrainingStatusV2>> {
+    signal.validate()?;
+    Err(V2Error::not_implemented(
+        "Reinforcement training is not available",
+    ))
+}
+
+/// Apply a punishment signal.
+#[utoipa::path(
+    post,
+    path = "/v2/training/punishment",
+    tag = "v2",
+    request_body = ReinforcementV2,
+    responses(
+        (status = 400, description = "Intensity out of range", body = V2Error),
+        (status = 501, description = "Reinforcement training not available", body = V2Error)
+    )
+)]
+pub async fn post_training_punishment(
+    Json(signal): Json<ReinforcementV2>,
+) -> V2Result<Json<TrainingStatusV2>> {
+    signal.validate()?;
+    Err(V2Error::not_implemented(
+        "Reinforcement training is not available",
+    ))
+}
+
+/// Get the criteria brain fitness is scored by.
+#[utoipa::path(
+    get,
+    path = "/v2/training/fitness_criteria",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Fitness criteria", body = FitnessCriteriaV2)
+    )
+)]
+pub async fn get_fitness_criteria() -> V2Result<Json<FitnessCriteriaV2>> {
+    Ok(Json(FitnessCriteriaV2::default()))
+}
+
+/// Replace the criteria brain fitness is scored by.
+#[utoipa::path(
+    put,
+    path = "/v2/training/fitness_criteria",
+    tag = "v2",
+    request_body = FitnessCriteriaV2,
+    responses(
+        (status = 400, description = "Invalid criterion weight", body = V2Error),
+        (status = 501, description = "Fitness scoring not available", body = V2Error)
+    )
+)]
+pub async fn put_fitness_criteria(
+    Json(criteria): Json<FitnessCriteriaV2>,
+) -> V2Result<Json<FitnessCriteriaV2>> {
+    criteria.validate()?;
+    Err(V2Error::not_implemented("Fitness scoring is not available"))
+}
+
+/// Get evolution status.
+#[utoipa::path(
+    get,
+    path = "/v2/evolution",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Evolution status", body = EvolutionStatusV2)
+    )
+)]
+pub async fn get_evolution() -> V2Result<Json<EvolutionStatusV2>> {
+    Ok(Json(EvolutionStatusV2::default()))
+}
+
+/// Get stimulation simulation status.
+#[utoipa::path(
+    get,
+    path = "/v2/simulation",
+    tag = "v2",
+    responses(
+        (status = 200, description = "Simulation status", body = SimulationStatusV2)
+    )
+)]
+pub async fn get_simulation() -> V2Result<Json<SimulationStatusV2>> {
+    Ok(Json(SimulationStatusV2::default()))
+}
+
+/// Reset the stimulation simulation to its initial state.
+#[utoipa::path(
+    post,
+    path = "/v2/simulation/reset",
+    tag = "v2",
+    responses(
+        (status = 501, description = "Stimulation simulation not available", body = V2Error)
+    )
+)]
+pub async fn reset_simulation() -> V2Result<Json<SimulationStatusV2>> {
+    Err(V2Error::not_implemented(
+        "Stimulation simulation is not available",
+    ))
+}
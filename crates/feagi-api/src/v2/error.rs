@@ -0,0 +1,243 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Error body shared by every v2 endpoint
+
+#[cfg(feature = "http")]
+use axum::{
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use feagi_services::traits::agent_service::AgentError;
+use feagi_services::ServiceError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::common::ApiError;
+
+/// Machine-readable error category (stable across releases)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum V2ErrorKind {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    Unavailable,
+    NotImplemented,
+    Internal,
+}
+
+impl V2ErrorKind {
+    /// HTTP status code for this kind
+    pub fn status(&self) -> u16 {
+        match self {
+            V2ErrorKind::BadRequest => 400,
+            V2ErrorKind::Unauthorized => 401,
+            V2ErrorKind::Forbidden => 403,
+            V2ErrorKind::NotFound => 404,
+            V2ErrorKind::Conflict => 409,
+            V2ErrorKind::PayloadTooLarge => 413,
+            V2ErrorKind::UnsupportedMediaType => 415,
+            V2ErrorKind::Unavailable => 503,
+            V2ErrorKind::NotImplemented => 501,
+            V2ErrorKind::Internal => 500,
+        }
+    }
+
+    fn from_status(status: u16) -> Self {
+        match status {
+            400 | 422 => V2ErrorKind::BadRequest,
+            401 => V2ErrorKind::Unauthorized,
+            403 => V2ErrorKind::Forbidden,
+            404 => V2ErrorKind::NotFound,
+            409 => V2ErrorKind::Conflict,
+            413 => V2ErrorKind::PayloadTooLarge,
+            415 => V2ErrorKind::UnsupportedMediaType,
+            501 => V2ErrorKind::NotImplemented,
+            503 => V2ErrorKind::Unavailable,
+            _ => V2ErrorKind::Internal,
+        }
+    }
+}
+
+/// Error response body returned by every v2 endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "status": 404,
+    "error": "not_found",
+    "message": "CorticalArea 'abc' not found"
+}))]
+pub struct V2Error {
+    /// HTTP status code (mirrors the response status)
+    pub status: u16,
+
+    /// Machine-readable error category
+    pub error: V2ErrorKind,
+
+    /// Human-readable message
+    pub message: String,
+
+    /// Optional extra context
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+pub type V2Result<T> = Result<T, V2Error>;
+
+impl V2Error {
+    pub fn new(kind: V2ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            status: kind.status(),
+            error: kind,
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(V2ErrorKind::BadRequest, message)
+    }
+
+    pub fn not_found(resource: &str, id: &str) -> Self {
+        Self::new(
+            V2ErrorKind::NotFound,
+            format!("{} '{}' not found", resource, id),
+        )
+    }
+
+    pub fn unavailable(message: impl Into<String>) -> Self {
+        Self::new(V2ErrorKind::Unavailable, message)
+    }
+
+    pub fn not_implemented(message: impl Into<String>) -> Self {
+        Self::new(V2ErrorKind::NotImplemented, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(V2ErrorKind::Internal, message)
+    }
+}
+
+impl From<ServiceError> for V2Error {
+    fn from(error: ServiceError) -> Self {
+        match error {
+            ServiceError::NotFound { resource, id } => V2Error::not_found(&resource, &id),
+            ServiceError::InvalidInput(msg) => V2Error::bad_request(msg),
+            ServiceError::AlreadyExists { resource, id } => V2Error::new(
+                V2ErrorKind::Conflict,
+                format!("{} '{}' already exists", resource, id),
+            ),
+            ServiceError::Forbidden(msg) => V2Error::new(V2ErrorKind::Forbidden, msg),
+            ServiceError::InvalidState(msg) => V2Error::new(V2ErrorKind::Conflict, msg),
+            ServiceError::NotImplemented(msg) => V2Error::new(V2ErrorKind::NotImplemented, msg),
+            ServiceError::Internal(msg)
+            | ServiceError::Backend(msg)
+            | ServiceError::StateError(msg) => V2Error::internal(msg),
+        }
+    }
+}
+
+impl From<AgentError> for V2Error {
+    fn from(error: AgentError) -> Self {
+        let kind = match error {
+            AgentError::NotFound(_) => V2ErrorKind::NotFound,
+            AgentError::ServiceUnavailable(_) => V2ErrorKind::Unavailable,
+            AgentError::InvalidData(_) => V2ErrorKind::BadRequest,
+            AgentError::RegistrationFailed(_) | AgentError::Internal(_) => V2ErrorKind::Internal,
+        };
+        V2Error::new(kind, error.to_string())
+    }
+}
+
+impl From<ApiError> for V2Error {
+    fn from(error: ApiError) -> Self {
+        let kind = V2ErrorKind::from_status(error.code);
+        Self {
+            status: kind.status(),
+            error: kind,
+            message: error.message,
+            details: error.details,
+        }
+    }
+}
+
+/// Error for a request axum could not extract, keeping the category of its status code
+#[cfg(feature = "http")]
+fn from_rejection(status: StatusCode, message: String) -> V2Error {
+    V2Error::new(V2ErrorKind::from_status(status.as_u16()), message)
+}
+
+#[cfg(feature = "http")]
+impl From<JsonRejection> for V2Error {
+    fn from(rejection: JsonRejection) -> Self {
+        from_rejection(rejection.status(), rejection.body_text())
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<QueryRejection> for V2Error {
+    fn from(rejection: QueryRejection) -> Self {
+        from_rejection(rejection.status(), rejection.body_text())
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<PathRejection> for V2Error {
+    fn from(rejection: PathRejection) -> Self {
+        from_rejection(rejection.status(), rejection.body_text())
+    }
+}
+
+#[cfg(feature = "http")]
+impl IntoResponse for V2Error {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, Json(self)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_error_mapping() {
+        let error: V2Error = ServiceError::NotFound {
+            resource: "CorticalArea".to_string(),
+            id: "abc".to_string(),
+        }
+        .into();
+        assert_eq!(error.status, 404);
+        assert_eq!(error.error, V2ErrorKind::NotFound);
+
+        let error: V2Error = ServiceError::InvalidState("not running".to_string()).into();
+        assert_eq!(error.status, 409);
+        assert_eq!(error.error, V2ErrorKind::Conflict);
+    }
+
+    #[test]
+    fn test_error_body_shape() {
+        let body = serde_json::to_value(V2Error::bad_request("limit must be positive")).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "status": 400,
+                "error": "bad_request",
+                "message": "limit must be positive"
+            })
+        );
+
+        let from_v1: V2Error = ApiError::not_implemented("todo").into();
+        assert_eq!(from_v1.error, V2ErrorKind::NotImplemented);
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Request extractors for v2 handlers
+//
+// Drop-in replacements for axum's `Json`, `Query` and `Path` that report
+// malformed requests with the `V2Error` body instead of axum's plain-text
+// rejections. `Json` is also the v2 response wrapper.
+
+use async_trait::async_trait;
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::error::V2Error;
+
+/// JSON request body / response body
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = V2Error;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::Json(value) = axum::Json::<T>::from_request(req, state).await?;
+        Ok(Json(value))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+/// Query string parameters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = V2Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Query(value) =
+            axum::extract::Query::<T>::from_request_parts(parts, state).await?;
+        Ok(Query(value))
+    }
+}
+
+/// Path parameters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Path<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = V2Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Path(value) =
+            axum::extract::Path::<T>::from_request_parts(parts, state).await?;
+        Ok(Path(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::error::V2ErrorKind;
+    use crate::v2::pagination::PageQuery;
+    use axum::body::Body;
+    use axum::routing::{get, post};
+    use axum::Router;
+    use http_body_util::BodyExt;
+    use serde::Deserialize;
+    use tower::ServiceExt;
+
+    #[derive(Deserialize)]
+    struct Payload {
+        #[allow(dead_code)]
+        value: u32,
+    }
+
+    fn router() -> Router {
+        Router::new()
+            .route("/body", post(|Json(_): Json<Payload>| async {}))
+            .route("/page", get(|Query(_): Query<PageQuery>| async {}))
+            .route("/items/:id", get(|Path(_): Path<u32>| async {}))
+    }
+
+    async fn error_of(request: Request) -> V2Error {
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status().as_u16();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let error: V2Error = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(error.status, status);
+        error
+    }
+
+    #[tokio::test]
+    async fn test_rejections_use_v2_error_body() {
+        let json_post = |content_type: &str, body: &'static str| {
+            Request::post("/body")
+                .header("content-type", content_type)
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let error = error_of(json_post("application/json", "{\"value\": \"x\"}")).await;
+        assert_eq!(error.error, V2ErrorKind::BadRequest);
+        let error = error_of(json_post("application/json", "{")).await;
+        assert_eq!(error.error, V2ErrorKind::BadRequest);
+        let error = error_of(json_post("text/plain", "{\"value\": 1}")).await;
+        assert_eq!(error.error, V2ErrorKind::UnsupportedMediaType);
+
+        let request = Request::get("/page?limit=abc").body(Body::empty()).unwrap();
+        assert_eq!(error_of(request).await.error, V2ErrorKind::BadRequest);
+
+        let request = Request::get("/items/abc").body(Body::empty()).unwrap();
+        assert_eq!(error_of(request).await.error, V2ErrorKind::BadRequest);
+    }
+}
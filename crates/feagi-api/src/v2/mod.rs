@@ -1,5 +1,27 @@
 // Copyright 2025 Neuraville Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-// API Version 2 - Future (placeholder)
-// This will be implemented later when we introduce breaking changes
+// API Version 2 - typed DTOs, consistent errors, pagination
+//
+// Served under `/v2/*` side-by-side with v1. Unlike v1 (which mirrors the
+// Python API and often exchanges `HashMap<String, Value>`), every v2 handler
+// takes and returns a concrete DTO, every failure uses the same `V2Error`
+// body, and list endpoints are paginated with `offset`/`limit`. The OpenAPI
+// document is generated from these types and served at
+// `/api-docs/v2/openapi.json`.
+
+pub mod dtos;
+#[cfg(feature = "http")]
+pub mod endpoints;
+pub mod error;
+#[cfg(feature = "http")]
+pub mod extract;
+#[cfg(feature = "http")]
+pub mod openapi;
+pub mod pagination;
+
+pub use dtos::*;
+#[cfg(feature = "http")]
+pub use endpoints::create_v2_router;
+pub use error::{V2Error, V2ErrorKind, V2Result};
+pub use pagination::{Page, PageQuery, DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
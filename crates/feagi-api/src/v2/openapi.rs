@@ -0,0 +1,128 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// OpenAPI document for the v2 API
+//
+// Generated from the v2 handler annotations and DTO types; served at
+// `/api-docs/v2/openapi.json`.
+
+use utoipa::OpenApi;
+
+use super::dtos::{
+    AgentV2, BrainRegionV2, BurstEngineCommandV2, BurstEngineStateV2, BurstEngineV2,
+    BurstPacingSettingV2, BurstPacingV2, BurstTickRequestV2, BurstTickV2, Coordinates3D,
+    CorticalAreaV2, CorticalXyzpV2, Dimensions3D, EvolutionStatusV2, FitnessCriteriaV2,
+    GenomeDocumentV2, GenomeV2, LoadGenomeRequestV2, MorphologyV2, ReinforcementV2,
+    SimulationStatusV2, TrainingStatusV2,
+};
+use super::endpoints;
+use super::error::{V2Error, V2ErrorKind};
+use super::pagination::{AgentPage, BrainRegionPage, CorticalAreaPage, MorphologyPage};
+
+/// OpenAPI documentation for the FEAGI v2 REST API
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "FEAGI REST API v2",
+        version = "2.0.0",
+        description = "Typed FEAGI REST API with paginated lists and uniform error bodies",
+        license(
+            name = "Apache-2.0",
+            url = "https://www.apache.org/licenses/LICENSE-2.0"
+        )
+    ),
+    servers(
+        (url = "http://localhost:8000", description = "Default FEAGI server"),
+        (url = "http://localhost:8080", description = "Alternative port")
+    ),
+    paths(
+        endpoints::list_cortical_areas,
+        endpoints::get_cortical_area,
+        endpoints::list_brain_regions,
+        endpoints::list_morphologies,
+        endpoints::get_genome,
+        endpoints::load_genome,
+        endpoints::get_burst_engine,
+        endpoints::control_burst_engine,
+        endpoints::get_burst_pacing,
+        endpoints::put_burst_pacing,
+        endpoints::tick_burst_engine,
+        endpoints::list_agents,
+        endpoints::get_training,
+        endpoints::post_training_reward,
+        endpoints::post_training_punishment,
+        endpoints::get_fitness_criteria,
+        endpoints::put_fitness_criteria,
+        endpoints::get_evolution,
+        endpoints::get_simulation,
+        endpoints::reset_simulation,
+    ),
+    components(
+        schemas(
+            V2Error,
+            V2ErrorKind,
+            Coordinates3D,
+            Dimensions3D,
+            CorticalAreaV2,
+            CorticalAreaPage,
+            BrainRegionV2,
+            BrainRegionPage,
+            MorphologyV2,
+            MorphologyPage,
+            GenomeV2,
+            GenomeDocumentV2,
+            LoadGenomeRequestV2,
+            BurstEngineStateV2,
+            BurstEngineV2,
+            BurstEngineCommandV2,
+            BurstPacingV2,
+            BurstPacingSettingV2,
+            CorticalXyzpV2,
+            BurstTickRequestV2,
+            BurstTickV2,
+            AgentV2,
+            AgentPage,
+            TrainingStatusV2,
+            ReinforcementV2,
+            FitnessCriteriaV2,
+            EvolutionStatusV2,
+            SimulationStatusV2,
+        )
+    ),
+    tags(
+        (name = "v2", description = "Typed v2 API")
+    )
+)]
+pub struct ApiDocV2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_v2_document_contents() {
+        let doc = serde_json::to_value(ApiDocV2::openapi()).unwrap();
+        let paths = doc["paths"].as_object().unwrap();
+        assert!(paths.contains_key("/v2/cortical_areas"));
+        assert!(paths.contains_key("/v2/burst_engine/{command}"));
+        assert!(paths.contains_key("/v2/burst_engine/tick"));
+        assert!(paths.contains_key("/v2/training/reward"));
+        assert!(paths.contains_key("/v2/evolution"));
+        assert!(paths.contains_key("/v2/simulation"));
+
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+        for name in [
+            "V2Error",
+            "CorticalAreaPage",
+            "AgentPage",
+            "GenomeV2",
+            "GenomeDocumentV2",
+            "ReinforcementV2",
+        ] {
+            assert!(schemas.contains_key(name), "missing schema {}", name);
+        }
+        let genome_required = serde_json::to_string(&schemas["GenomeDocumentV2"]).unwrap();
+        assert!(genome_required.contains(r#""required":["version","blueprint"]"#));
+        assert!(schemas["CorticalAreaPage"]["properties"]["next_offset"].is_object());
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Offset/limit pagination for v2 list endpoints
+
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::dtos::{AgentV2, BrainRegionV2, CorticalAreaV2, MorphologyV2};
+use super::error::{V2Error, V2Result};
+
+/// Page size used when the client does not pass `limit`
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+
+/// Largest accepted `limit`
+pub const MAX_PAGE_LIMIT: usize = 1000;
+
+/// Pagination query parameters
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageQuery {
+    /// Number of items to skip (default 0)
+    pub offset: Option<usize>,
+
+    /// Maximum number of items to return (default 100, max 1000)
+    pub limit: Option<usize>,
+}
+
+impl PageQuery {
+    /// Resolve defaults and validate bounds
+    pub fn resolve(&self) -> V2Result<(usize, usize)> {
+        let offset = self.offset.unwrap_or(0);
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if limit == 0 || limit > MAX_PAGE_LIMIT {
+            return Err(V2Error::bad_request(format!(
+                "limit must be between 1 and {}, got {}",
+                MAX_PAGE_LIMIT, limit
+            )));
+        }
+        Ok((offset, limit))
+    }
+}
+
+/// One page of a list result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[aliases(
+    CorticalAreaPage = Page<CorticalAreaV2>,
+    BrainRegionPage = Page<BrainRegionV2>,
+    MorphologyPage = Page<MorphologyV2>,
+    AgentPage = Page<AgentV2>
+)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+
+    /// Total number of items across all pages
+    pub total: usize,
+
+    /// Offset of the first item on this page
+    pub offset: usize,
+
+    /// Page size that was applied
+    pub limit: usize,
+
+    /// Offset to request for the next page (absent on the last page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+}
+
+impl<T> Page<T> {
+    /// Slice a fully materialized (and already ordered) list into a page
+    pub fn from_items(items: Vec<T>, query: &PageQuery) -> V2Result<Self> {
+        let (offset, limit) = query.resolve()?;
+        let total = items.len();
+        let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+        let end = offset.saturating_add(items.len());
+        Ok(Self {
+            items,
+            total,
+            offset,
+            limit,
+            next_offset: (end < total).then_some(end),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_slicing() {
+        let query = PageQuery {
+            offset: Some(2),
+            limit: Some(3),
+        };
+        let page = Page::from_items((0..10).collect(), &query).unwrap();
+        assert_eq!(page.items, vec![2, 3, 4]);
+        assert_eq!(page.total, 10);
+        assert_eq!(page.next_offset, Some(5));
+
+        let last = Page::from_items(
+            (0..10).collect::<Vec<_>>(),
+            &PageQuery {
+                offset: Some(8),
+                limit: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(last.items, vec![8, 9]);
+        assert_eq!(last.limit, DEFAULT_PAGE_LIMIT);
+        assert_eq!(last.next_offset, None);
+
+        let past_end = Page::from_items(
+            vec![1],
+            &PageQuery {
+                offset: Some(5),
+                limit: None,
+            },
+        )
+        .unwrap();
+        assert!(past_end.items.is_empty());
+        assert_eq!(past_end.next_offset, None);
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        for limit in [0, MAX_PAGE_LIMIT + 1] {
+            let query = PageQuery {
+                offset: None,
+                limit: Some(limit),
+            };
+            let error = Page::<u8>::from_items(vec![], &query).unwrap_err();
+            assert_eq!(error.status, 400);
+        }
+    }
+}
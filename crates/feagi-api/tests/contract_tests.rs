@@ -131,6 +131,7 @@ fn build_test_state() -> ApiState {
                 current_rate_hz: 0.0,
                 last_burst_neuron_count: 0,
                 avg_burst_time_ms: 0.0,
+                error: None,
             })
         }
         async fn set_frequency(&self, _frequency: f64) -> feagi_services::ServiceResult<()> {
@@ -138,6 +139,27 @@ fn build_test_state() -> ApiState {
                 "MockRuntimeService".to_string(),
             ))
         }
+        async fn get_pacing(
+            &self,
+        ) -> feagi_services::ServiceResult<feagi_services::BurstPacingMode> {
+            Ok(feagi_services::BurstPacingMode::RealTime)
+        }
+        async fn set_pacing(
+            &self,
+            _pacing: feagi_services::BurstPacingMode,
+        ) -> feagi_services::ServiceResult<()> {
+            Err(feagi_services::ServiceError::NotImplemented(
+                "MockRuntimeService".to_string(),
+            ))
+        }
+        async fn tick(
+            &self,
+            _request: feagi_services::BurstTickRequest,
+        ) -> feagi_services::ServiceResult<feagi_services::BurstTickResult> {
+            Err(feagi_services::ServiceError::NotImplemented(
+                "MockRuntimeService".to_string(),
+            ))
+        }
         async fn get_burst_count(&self) -> feagi_services::ServiceResult<u64> {
             Ok(0)
         }
@@ -0,0 +1,484 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Minimal HDF5 writer for SONATA export
+//!
+//! Writes only the subset of HDF5 that SONATA node/edge files need: groups,
+//! attributes and contiguous one-dimensional datasets of integers, doubles or
+//! fixed-length UTF-8 strings. Files use superblock version 2, version 2
+//! object headers and compact link storage, which HDF5 1.8 and later (h5py,
+//! libsonata, BMTK) read. Nothing is chunked, compressed or shared, and the
+//! whole tree is written in one pass, so no native HDF5 library is needed.
+//!
+//! Objects are written children-first: dataset data is followed by its
+//! object header, and a group header is written once all of its children
+//! have addresses. The superblock is patched in last.
+
+use std::io::{self, Seek, SeekFrom, Write};
+
+const SIGNATURE: &[u8; 8] = b"\x89HDF\r\n\x1a\n";
+const SUPERBLOCK_SIZE: u64 = 48;
+const UNDEFINED_ADDRESS: u64 = u64::MAX;
+
+const MSG_DATASPACE: u8 = 0x01;
+const MSG_LINK_INFO: u8 = 0x02;
+const MSG_DATATYPE: u8 = 0x03;
+const MSG_FILL_VALUE: u8 = 0x05;
+const MSG_LINK: u8 = 0x06;
+const MSG_LAYOUT: u8 = 0x08;
+const MSG_GROUP_INFO: u8 = 0x0A;
+const MSG_ATTRIBUTE: u8 = 0x0C;
+
+/// Values of a dataset or attribute
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Column {
+    U32(Vec<u32>),
+    U64(Vec<u64>),
+    I64(Vec<i64>),
+    F64(Vec<f64>),
+    Text(Vec<String>),
+}
+
+impl Column {
+    fn len(&self) -> usize {
+        match self {
+            Column::U32(v) => v.len(),
+            Column::U64(v) => v.len(),
+            Column::I64(v) => v.len(),
+            Column::F64(v) => v.len(),
+            Column::Text(v) => v.len(),
+        }
+    }
+
+    /// Longest string, at least 1 byte (HDF5 strings cannot be empty)
+    fn text_size(values: &[String]) -> usize {
+        values.iter().map(String::len).max().unwrap_or(0).max(1)
+    }
+
+    /// Encoded datatype message
+    fn datatype(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(20);
+        match self {
+            Column::U32(_) | Column::U64(_) | Column::I64(_) => {
+                let (size, signed) = match self {
+                    Column::U32(_) => (4u32, false),
+                    Column::U64(_) => (8, false),
+                    _ => (8, true),
+                };
+                // Version 1, fixed-point class; little-endian, bit 3 = signed
+                out.extend_from_slice(&[0x10, if signed { 0x08 } else { 0x00 }, 0, 0]);
+                out.extend_from_slice(&size.to_le_bytes());
+                out.extend_from_slice(&0u16.to_le_bytes());
+                out.extend_from_slice(&(size as u16 * 8).to_le_bytes());
+            }
+            Column::F64(_) => {
+                // Version 1, floating-point class; IEEE little-endian double
+                // with implied mantissa MSB and the sign at bit 63
+                out.extend_from_slice(&[0x11, 0x20, 63, 0]);
+                out.extend_from_slice(&8u32.to_le_bytes());
+                out.extend_from_slice(&0u16.to_le_bytes());
+                out.extend_from_slice(&64u16.to_le_bytes());
+                out.extend_from_slice(&[52, 11, 0, 52]);
+                out.extend_from_slice(&1023u32.to_le_bytes());
+            }
+            Column::Text(values) => {
+                // Version 1, string class; null padded, UTF-8
+                out.extend_from_slice(&[0x13, 0x11, 0, 0]);
+                out.extend_from_slice(&(Self::text_size(values) as u32).to_le_bytes());
+            }
+        }
+        out
+    }
+
+    /// Raw little-endian element data
+    fn raw(&self) -> Vec<u8> {
+        match self {
+            Column::U32(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Column::U64(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Column::I64(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Column::F64(v) => v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            Column::Text(v) => {
+                let size = Self::text_size(v);
+                let mut out = Vec::with_capacity(size * v.len());
+                for value in v {
+                    out.extend_from_slice(value.as_bytes());
+                    out.resize(out.len() + size - value.len(), 0);
+                }
+                out
+            }
+        }
+    }
+}
+
+/// An HDF5 attribute: a scalar or a one-dimensional array
+#[derive(Debug, Clone)]
+struct Attribute {
+    name: String,
+    value: Column,
+    scalar: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Dataset {
+    name: String,
+    data: Column,
+    attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone)]
+enum Child {
+    Group(Group),
+    Dataset(Dataset),
+}
+
+/// An HDF5 group; the root group of a file is `Group::default()`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Group {
+    name: String,
+    children: Vec<Child>,
+    attributes: Vec<Attribute>,
+}
+
+impl Group {
+    /// Get or create the child group `name`
+    pub(crate) fn group(&mut self, name: &str) -> &mut Group {
+        let index = self
+            .children
+            .iter()
+            .position(|c| matches!(c, Child::Group(g) if g.name == name))
+            .unwrap_or_else(|| {
+                self.children.push(Child::Group(Group {
+                    name: name.to_string(),
+                    ..Group::default()
+                }));
+                self.children.len() - 1
+            });
+        match &mut self.children[index] {
+            Child::Group(group) => group,
+            Child::Dataset(_) => unreachable!(),
+        }
+    }
+
+    /// Add a one-dimensional dataset
+    pub(crate) fn dataset(&mut self, name: &str, data: Column) {
+        self.children.push(Child::Dataset(Dataset {
+            name: name.to_string(),
+            data,
+            attributes: Vec::new(),
+        }));
+    }
+
+    /// Add a one-dimensional dataset with a scalar attribute
+    pub(crate) fn dataset_with_attribute(
+        &mut self,
+        name: &str,
+        data: Column,
+        attribute: &str,
+        value: Column,
+    ) {
+        self.children.push(Child::Dataset(Dataset {
+            name: name.to_string(),
+            data,
+            attributes: vec![Attribute {
+                name: attribute.to_string(),
+                value,
+                scalar: true,
+            }],
+        }));
+    }
+
+    /// Add a scalar attribute (`value` must hold exactly one element)
+    pub(crate) fn scalar_attribute(&mut self, name: &str, value: Column) {
+        debug_assert_eq!(value.len(), 1);
+        self.attributes.push(Attribute {
+            name: name.to_string(),
+            value,
+            scalar: true,
+        });
+    }
+
+    /// Add a one-dimensional array attribute
+    pub(crate) fn array_attribute(&mut self, name: &str, value: Column) {
+        self.attributes.push(Attribute {
+            name: name.to_string(),
+            value,
+            scalar: false,
+        });
+    }
+}
+
+/// Write `root` as a complete HDF5 file
+pub(crate) fn write_file<W: Write + Seek>(writer: W, root: &Group) -> io::Result<()> {
+    let mut file = FileWriter {
+        writer,
+        position: 0,
+    };
+    file.write_all(&[0u8; SUPERBLOCK_SIZE as usize])?;
+    let root_address = file.write_group(root)?;
+    let end_of_file = file.position;
+
+    let mut superblock = Vec::with_capacity(SUPERBLOCK_SIZE as usize);
+    superblock.extend_from_slice(SIGNATURE);
+    // Version 2, 8-byte offsets and lengths, no consistency flags
+    superblock.extend_from_slice(&[2, 8, 8, 0]);
+    superblock.extend_from_slice(&0u64.to_le_bytes());
+    superblock.extend_from_slice(&UNDEFINED_ADDRESS.to_le_bytes());
+    superblock.extend_from_slice(&end_of_file.to_le_bytes());
+    superblock.extend_from_slice(&root_address.to_le_bytes());
+    let checksum = lookup3(&superblock);
+    superblock.extend_from_slice(&checksum.to_le_bytes());
+
+    file.writer.seek(SeekFrom::Start(0))?;
+    file.writer.write_all(&superblock)?;
+    file.writer.seek(SeekFrom::Start(end_of_file))?;
+    file.writer.flush()
+}
+
+struct FileWriter<W> {
+    writer: W,
+    position: u64,
+}
+
+impl<W: Write> FileWriter<W> {
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn write_group(&mut self, group: &Group) -> io::Result<u64> {
+        let mut links = Vec::with_capacity(group.children.len());
+        for child in &group.children {
+            let (name, address) = match child {
+                Child::Group(g) => (&g.name, self.write_group(g)?),
+                Child::Dataset(d) => (&d.name, self.write_dataset(d)?),
+            };
+            links.push((name, address));
+        }
+
+        let mut header = ObjectHeader::default();
+        // Link info: no creation order, compact storage (no fractal heap or index)
+        let mut link_info = vec![0u8, 0];
+        link_info.extend_from_slice(&UNDEFINED_ADDRESS.to_le_bytes());
+        link_info.extend_from_slice(&UNDEFINED_ADDRESS.to_le_bytes());
+        header.message(MSG_LINK_INFO, &link_info)?;
+        header.message(MSG_GROUP_INFO, &[0, 0])?;
+        for (name, address) in links {
+            header.message(MSG_LINK, &encode_link(name, address)?)?;
+        }
+        for attribute in &group.attributes {
+            header.message(MSG_ATTRIBUTE, &encode_attribute(attribute)?)?;
+        }
+        self.write_header(header)
+    }
+
+    fn write_dataset(&mut self, dataset: &Dataset) -> io::Result<u64> {
+        let raw = dataset.data.raw();
+        let data_address = if raw.is_empty() {
+            UNDEFINED_ADDRESS
+        } else {
+            let address = self.position;
+            self.write_all(&raw)?;
+            address
+        };
+
+        let mut header = ObjectHeader::default();
+        header.message(MSG_DATASPACE, &encode_dataspace(&dataset.data, false))?;
+        header.message(MSG_DATATYPE, &dataset.data.datatype())?;
+        // Fill value version 3: late allocation, write fill value only if set
+        header.message(MSG_FILL_VALUE, &[3, 0x0A])?;
+        // Layout version 3, contiguous
+        let mut layout = vec![3u8, 1];
+        layout.extend_from_slice(&data_address.to_le_bytes());
+        layout.extend_from_slice(&(raw.len() as u64).to_le_bytes());
+        header.message(MSG_LAYOUT, &layout)?;
+        for attribute in &dataset.attributes {
+            header.message(MSG_ATTRIBUTE, &encode_attribute(attribute)?)?;
+        }
+        self.write_header(header)
+    }
+
+    fn write_header(&mut self, header: ObjectHeader) -> io::Result<u64> {
+        let address = self.position;
+        let mut bytes = Vec::with_capacity(header.messages.len() + 14);
+        bytes.extend_from_slice(b"OHDR");
+        // Version 2; flags = 4-byte chunk size, no times or attribute phase changes
+        bytes.extend_from_slice(&[2, 0x02]);
+        bytes.extend_from_slice(&(header.messages.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&header.messages);
+        let checksum = lookup3(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        self.write_all(&bytes)?;
+        Ok(address)
+    }
+}
+
+#[derive(Default)]
+struct ObjectHeader {
+    messages: Vec<u8>,
+}
+
+impl ObjectHeader {
+    fn message(&mut self, kind: u8, body: &[u8]) -> io::Result<()> {
+        let size = u16::try_from(body.len()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "HDF5 header message too large")
+        })?;
+        self.messages.push(kind);
+        self.messages.extend_from_slice(&size.to_le_bytes());
+        self.messages.push(0);
+        self.messages.extend_from_slice(body);
+        Ok(())
+    }
+}
+
+fn encode_dataspace(column: &Column, scalar: bool) -> Vec<u8> {
+    if scalar {
+        // Version 2, rank 0, scalar
+        vec![2, 0, 0, 0]
+    } else {
+        // Version 2, rank 1, no max dims, simple
+        let mut out = vec![2, 1, 0, 1];
+        out.extend_from_slice(&(column.len() as u64).to_le_bytes());
+        out
+    }
+}
+
+fn encode_link(name: &str, address: u64) -> io::Result<Vec<u8>> {
+    let length = u8::try_from(name.len()).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("HDF5 link name too long: {}", name),
+        )
+    })?;
+    // Version 1; flags = 1-byte name length, hard link, default charset
+    let mut out = vec![1u8, 0, length];
+    out.extend_from_slice(name.as_bytes());
+    out.extend_from_slice(&address.to_le_bytes());
+    Ok(out)
+}
+
+fn encode_attribute(attribute: &Attribute) -> io::Result<Vec<u8>> {
+    let datatype = attribute.value.datatype();
+    let dataspace = encode_dataspace(&attribute.value, attribute.scalar);
+    let name_size = u16::try_from(attribute.name.len() + 1)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "HDF5 attribute name too long"))?;
+    // Version 3, no shared components
+    let mut out = vec![3u8, 0];
+    out.extend_from_slice(&name_size.to_le_bytes());
+    out.extend_from_slice(&(datatype.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(dataspace.len() as u16).to_le_bytes());
+    // Name character set: UTF-8
+    out.push(1);
+    out.extend_from_slice(attribute.name.as_bytes());
+    out.push(0);
+    out.extend_from_slice(&datatype);
+    out.extend_from_slice(&dataspace);
+    out.extend_from_slice(&attribute.value.raw());
+    Ok(out)
+}
+
+/// Bob Jenkins' lookup3 `hashlittle` with a zero seed, the HDF5 metadata checksum
+fn lookup3(data: &[u8]) -> u32 {
+    fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+        *a = a.wrapping_sub(*c) ^ c.rotate_left(4);
+        *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a) ^ a.rotate_left(6);
+        *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b) ^ b.rotate_left(8);
+        *b = b.wrapping_add(*a);
+        *a = a.wrapping_sub(*c) ^ c.rotate_left(16);
+        *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a) ^ a.rotate_left(19);
+        *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b) ^ b.rotate_left(4);
+        *b = b.wrapping_add(*a);
+    }
+
+    fn word(bytes: &[u8]) -> u32 {
+        bytes
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc.wrapping_add((b as u32) << (8 * i)))
+    }
+
+    let initial = 0xdead_beefu32.wrapping_add(data.len() as u32);
+    let (mut a, mut b, mut c) = (initial, initial, initial);
+    let mut rest = data;
+    while rest.len() > 12 {
+        a = a.wrapping_add(word(&rest[0..4]));
+        b = b.wrapping_add(word(&rest[4..8]));
+        c = c.wrapping_add(word(&rest[8..12]));
+        mix(&mut a, &mut b, &mut c);
+        rest = &rest[12..];
+    }
+    if rest.is_empty() {
+        return c;
+    }
+    a = a.wrapping_add(word(&rest[..rest.len().min(4)]));
+    if rest.len() > 4 {
+        b = b.wrapping_add(word(&rest[4..rest.len().min(8)]));
+    }
+    if rest.len() > 8 {
+        c = c.wrapping_add(word(&rest[8..]));
+    }
+
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(14));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(11));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(25));
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(16));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(4));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(14));
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(24));
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup3_reference_values() {
+        assert_eq!(lookup3(b""), 0xdead_beef);
+        assert_eq!(lookup3(b"Four score and seven years ago"), 0x1777_0551);
+    }
+
+    #[test]
+    fn test_file_structure() {
+        let mut root = Group::default();
+        root.scalar_attribute("magic", Column::U32(vec![0x0A7A]));
+        root.group("nodes")
+            .group("pop")
+            .dataset("node_type_id", Column::U64(vec![0, 1]));
+
+        let mut cursor = io::Cursor::new(Vec::new());
+        write_file(&mut cursor, &root).unwrap();
+        let bytes = cursor.into_inner();
+
+        assert_eq!(&bytes[..8], SIGNATURE);
+        let end_of_file = u64::from_le_bytes(bytes[28..36].try_into().unwrap());
+        assert_eq!(end_of_file, bytes.len() as u64);
+        let checksum = u32::from_le_bytes(bytes[44..48].try_into().unwrap());
+        assert_eq!(checksum, lookup3(&bytes[..44]));
+
+        // Root header comes last and carries a valid checksum
+        let root_address = u64::from_le_bytes(bytes[36..44].try_into().unwrap()) as usize;
+        assert_eq!(&bytes[root_address..root_address + 4], b"OHDR");
+        let header = &bytes[root_address..bytes.len() - 4];
+        let stored = u32::from_le_bytes(bytes[bytes.len() - 4..].try_into().unwrap());
+        assert_eq!(stored, lookup3(header));
+    }
+
+    #[test]
+    fn test_text_column_is_null_padded() {
+        let column = Column::Text(vec!["ab".to_string(), "c".to_string(), String::new()]);
+        assert_eq!(column.raw(), b"abc\0\0\0".to_vec());
+        assert_eq!(Column::Text(vec![String::new()]).raw(), vec![0]);
+    }
+}
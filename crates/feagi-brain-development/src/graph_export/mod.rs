@@ -0,0 +1,857 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/*!
+Connectome graph export for external network analysis.
+
+Builds a graph view of the instantiated connectome held by a
+[`ConnectomeManager`] and writes it to formats understood by NetworkX,
+Gephi and simulator tool chains:
+
+- **GraphML** (`networkx.read_graphml`, Gephi)
+- **SONATA** HDF5 nodes/edges files (BMTK, NEST, NEURON tool chains), behind
+  the `sonata` feature
+- **CSV** node/edge tables (`pandas.read_csv`, Gephi spreadsheet import)
+
+Two granularities are supported:
+
+- [`GraphGranularity::Area`]: cortical areas are nodes, cortical mappings are
+  edges annotated with the morphologies used and the number of synapses the
+  mapping instantiated.
+- [`GraphGranularity::Neuron`]: neurons are nodes (with voxel coordinates and
+  cortical area), synapses are edges (with weight, psp and synapse type).
+
+Both can be restricted to one or more brain regions.
+
+## SONATA layout
+
+[`ConnectomeGraph::save_sonata`] writes one node and one edge population
+(`feagi_areas`/`feagi_mappings` or `feagi_neurons`/`feagi_synapses`). The
+HDF5 files are produced by a small built-in writer, so no native HDF5
+library is needed:
+
+```text
+<dir>/
+├── circuit_config.json
+├── nodes.h5         /nodes/<pop>/{node_id, node_type_id, node_group_id, node_group_index}
+│                    /nodes/<pop>/0/{feagi_id, <attributes>}
+├── node_types.csv   node_type_id pop_name model_type
+├── edges.h5         /edges/<pop>/{source_node_id, target_node_id, edge_type_id,
+│                                  edge_group_id, edge_group_index}
+│                    /edges/<pop>/0/{<attributes>}
+└── edge_types.csv   edge_type_id pop_name
+```
+
+The type tables are space-delimited, as the SONATA specification requires.
+
+## CSV layout
+
+[`ConnectomeGraph::save_csv_tables`] writes the same tables as comma-separated
+files:
+
+```text
+<dir>/
+├── nodes.csv        node_id,node_type_id,node_group_id,node_group_index,feagi_id,<attributes>
+├── node_types.csv   node_type_id,pop_name,model_type
+├── edges.csv        edge_id,source_node_id,target_node_id,edge_type_id,edge_group_id,edge_group_index,<attributes>
+└── edge_types.csv   edge_type_id,pop_name
+```
+
+Node ids in both layouts are contiguous, so the original FEAGI identifier of
+every node is kept in the `feagi_id` attribute.
+*/
+
+#[cfg(feature = "sonata")]
+mod hdf5;
+
+use crate::connectome_manager::ConnectomeManager;
+use crate::types::{BduError, BduResult};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Level of detail of an exported graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphGranularity {
+    /// Cortical areas as nodes, mappings as edges
+    #[default]
+    Area,
+    /// Neurons as nodes, synapses as edges
+    Neuron,
+}
+
+/// Options for [`build_connectome_graph`]
+#[derive(Debug, Clone, Default)]
+pub struct GraphExportOptions {
+    /// Level of detail
+    pub granularity: GraphGranularity,
+
+    /// Only export areas belonging to these brain regions (empty = whole brain)
+    pub region_ids: Vec<String>,
+
+    /// Also include areas of descendant regions of `region_ids`
+    pub include_subregions: bool,
+}
+
+/// Value of a node or edge attribute
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphAttribute {
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl GraphAttribute {
+    fn graphml_type(&self) -> &'static str {
+        match self {
+            GraphAttribute::Int(_) => "long",
+            GraphAttribute::Float(_) => "double",
+            GraphAttribute::Text(_) => "string",
+        }
+    }
+}
+
+impl fmt::Display for GraphAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphAttribute::Int(v) => write!(f, "{}", v),
+            GraphAttribute::Float(v) => write!(f, "{}", v),
+            GraphAttribute::Text(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// A graph node (cortical area or neuron)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphNode {
+    /// FEAGI identifier (cortical ID or neuron ID)
+    pub id: String,
+    /// Node type: "cortical_area", or the cortical ID for neurons
+    pub node_type: String,
+    /// Ordered attributes
+    pub attributes: Vec<(&'static str, GraphAttribute)>,
+}
+
+/// A graph edge (cortical mapping or synapse)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphEdge {
+    /// Source node identifier
+    pub source: String,
+    /// Target node identifier
+    pub target: String,
+    /// Edge type: "cortical_mapping", "excitatory" or "inhibitory"
+    pub edge_type: String,
+    /// Ordered attributes
+    pub attributes: Vec<(&'static str, GraphAttribute)>,
+}
+
+/// Graph view of a connectome
+#[derive(Debug, Clone, Default)]
+pub struct ConnectomeGraph {
+    pub granularity: GraphGranularity,
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Build a graph of the connectome
+///
+/// Synapse counts and neuron-level data are read from the NPU attached to the
+/// manager; without an NPU, area graphs report zero synapses and neuron graphs
+/// are empty.
+///
+/// # Errors
+///
+/// Returns `BduError::InvalidArea` if a requested region does not exist.
+pub fn build_connectome_graph(
+    manager: &ConnectomeManager,
+    options: &GraphExportOptions,
+) -> BduResult<ConnectomeGraph> {
+    let hierarchy = manager.get_brain_region_hierarchy();
+
+    // Resolve the set of exported areas, ordered by cortical index for stable output
+    let mut selected: Vec<(u32, CorticalID)> = if options.region_ids.is_empty() {
+        manager
+            .get_cortical_area_ids()
+            .into_iter()
+            .filter_map(|id| manager.get_cortical_idx(id).map(|idx| (idx, *id)))
+            .collect()
+    } else {
+        let mut ids = HashSet::new();
+        for region_id in &options.region_ids {
+            let region = hierarchy.get_region(region_id).ok_or_else(|| {
+                BduError::InvalidArea(format!("Brain region {} not found", region_id))
+            })?;
+            ids.extend(region.cortical_areas.iter().copied());
+            if options.include_subregions {
+                for descendant in hierarchy.get_all_descendants(region_id) {
+                    if let Some(region) = hierarchy.get_region(descendant) {
+                        ids.extend(region.cortical_areas.iter().copied());
+                    }
+                }
+            }
+        }
+        ids.into_iter()
+            .filter_map(|id| manager.get_cortical_idx(&id).map(|idx| (idx, id)))
+            .collect()
+    };
+    selected.sort_by_key(|(idx, _)| *idx);
+    let selected_idx: HashSet<u32> = selected.iter().map(|(idx, _)| *idx).collect();
+
+    let npu = manager.get_npu().and_then(|npu| npu.lock().ok());
+
+    match options.granularity {
+        GraphGranularity::Area => {
+            // Count instantiated synapses per (source area, target area)
+            let mut synapse_counts: HashMap<(u32, u32), u64> = HashMap::new();
+            if let Some(npu) = npu.as_ref() {
+                for &(src_idx, _) in &selected {
+                    for neuron_id in npu.get_neurons_in_cortical_area(src_idx) {
+                        for (target, _, _, _) in npu.get_outgoing_synapses(neuron_id) {
+                            let dst_idx = npu.get_neuron_cortical_area(target);
+                            if selected_idx.contains(&dst_idx) {
+                                *synapse_counts.entry((src_idx, dst_idx)).or_default() += 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            let mut graph = ConnectomeGraph {
+                granularity: GraphGranularity::Area,
+                ..Default::default()
+            };
+            for (idx, cortical_id) in &selected {
+                let Some(area) = manager.get_cortical_area(cortical_id) else {
+                    continue;
+                };
+                let region = hierarchy
+                    .find_region_containing_area(cortical_id)
+                    .unwrap_or_default();
+                let neuron_count = npu
+                    .as_ref()
+                    .map(|npu| npu.get_neurons_in_cortical_area(*idx).len())
+                    .unwrap_or(0);
+                graph.nodes.push(GraphNode {
+                    id: cortical_id.as_base_64(),
+                    node_type: "cortical_area".to_string(),
+                    attributes: vec![
+                        ("name", GraphAttribute::Text(area.name.clone())),
+                        ("cortical_idx", GraphAttribute::Int(*idx as i64)),
+                        ("region", GraphAttribute::Text(region)),
+                        ("width", GraphAttribute::Int(area.dimensions.width as i64)),
+                        ("height", GraphAttribute::Int(area.dimensions.height as i64)),
+                        ("depth", GraphAttribute::Int(area.dimensions.depth as i64)),
+                        ("x", GraphAttribute::Int(area.position.x as i64)),
+                        ("y", GraphAttribute::Int(area.position.y as i64)),
+                        ("z", GraphAttribute::Int(area.position.z as i64)),
+                        ("neuron_count", GraphAttribute::Int(neuron_count as i64)),
+                    ],
+                });
+
+                let Some(dst_map) = area
+                    .properties
+                    .get("cortical_mapping_dst")
+                    .and_then(|v| v.as_object())
+                else {
+                    continue;
+                };
+                let mut dsts: Vec<(u32, &String, &serde_json::Value)> = dst_map
+                    .iter()
+                    .filter_map(|(dst, rules)| {
+                        let dst_id = CorticalID::try_from_base_64(dst).ok()?;
+                        let dst_idx = manager.get_cortical_idx(&dst_id)?;
+                        selected_idx
+                            .contains(&dst_idx)
+                            .then_some((dst_idx, dst, rules))
+                    })
+                    .collect();
+                dsts.sort_by_key(|(dst_idx, _, _)| *dst_idx);
+
+                for (dst_idx, dst, rules) in dsts {
+                    let morphologies: Vec<&str> = rules
+                        .as_array()
+                        .map(|rules| {
+                            rules
+                                .iter()
+                                .filter_map(|rule| rule.get("morphology_id")?.as_str())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let synapse_count = synapse_counts.get(&(*idx, dst_idx)).copied().unwrap_or(0);
+                    graph.edges.push(GraphEdge {
+                        source: cortical_id.as_base_64(),
+                        target: dst.clone(),
+                        edge_type: "cortical_mapping".to_string(),
+                        attributes: vec![
+                            ("morphologies", GraphAttribute::Text(morphologies.join(","))),
+                            ("synapse_count", GraphAttribute::Int(synapse_count as i64)),
+                        ],
+                    });
+                }
+            }
+            Ok(graph)
+        }
+        GraphGranularity::Neuron => {
+            let mut graph = ConnectomeGraph {
+                granularity: GraphGranularity::Neuron,
+                ..Default::default()
+            };
+            let Some(npu) = npu.as_ref() else {
+                return Ok(graph);
+            };
+
+            let mut neurons: Vec<(u32, u32)> = Vec::new();
+            for (idx, cortical_id) in &selected {
+                let mut area_neurons = npu.get_neurons_in_cortical_area(*idx);
+                area_neurons.sort_unstable();
+                for neuron_id in area_neurons {
+                    let (x, y, z) = npu.get_neuron_coordinates(neuron_id).unwrap_or((0, 0, 0));
+                    graph.nodes.push(GraphNode {
+                        id: neuron_id.to_string(),
+                        node_type: cortical_id.as_base_64(),
+                        attributes: vec![
+                            (
+                                "cortical_area",
+                                GraphAttribute::Text(cortical_id.as_base_64()),
+                            ),
+                            ("cortical_idx", GraphAttribute::Int(*idx as i64)),
+                            ("x", GraphAttribute::Int(x as i64)),
+                            ("y", GraphAttribute::Int(y as i64)),
+                            ("z", GraphAttribute::Int(z as i64)),
+                        ],
+                    });
+                    neurons.push((neuron_id, *idx));
+                }
+            }
+
+            for (neuron_id, _) in &neurons {
+                for (target, weight, psp, synapse_type) in npu.get_outgoing_synapses(*neuron_id) {
+                    if !selected_idx.contains(&npu.get_neuron_cortical_area(target)) {
+                        continue;
+                    }
+                    graph.edges.push(GraphEdge {
+                        source: neuron_id.to_string(),
+                        target: target.to_string(),
+                        edge_type: if synapse_type == 0 {
+                            "excitatory".to_string()
+                        } else {
+                            "inhibitory".to_string()
+                        },
+                        attributes: vec![
+                            ("weight", GraphAttribute::Int(weight as i64)),
+                            ("psp", GraphAttribute::Int(psp as i64)),
+                            ("synapse_type", GraphAttribute::Int(synapse_type as i64)),
+                        ],
+                    });
+                }
+            }
+            Ok(graph)
+        }
+    }
+}
+
+impl ConnectomeGraph {
+    /// Write the graph as GraphML
+    pub fn write_graphml<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let node_keys = attribute_keys(self.nodes.iter().map(|n| &n.attributes));
+        let edge_keys = attribute_keys(self.edges.iter().map(|e| &e.attributes));
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+        )?;
+        writeln!(
+            writer,
+            r#"  <key id="n_type" for="node" attr.name="node_type" attr.type="string"/>"#
+        )?;
+        for (name, ty) in &node_keys {
+            writeln!(
+                writer,
+                r#"  <key id="n_{0}" for="node" attr.name="{0}" attr.type="{1}"/>"#,
+                name, ty
+            )?;
+        }
+        writeln!(
+            writer,
+            r#"  <key id="e_type" for="edge" attr.name="edge_type" attr.type="string"/>"#
+        )?;
+        for (name, ty) in &edge_keys {
+            writeln!(
+                writer,
+                r#"  <key id="e_{0}" for="edge" attr.name="{0}" attr.type="{1}"/>"#,
+                name, ty
+            )?;
+        }
+
+        writeln!(writer, r#"  <graph id="feagi" edgedefault="directed">"#)?;
+        for node in &self.nodes {
+            writeln!(writer, r#"    <node id="{}">"#, xml_escape(&node.id))?;
+            writeln!(
+                writer,
+                r#"      <data key="n_type">{}</data>"#,
+                xml_escape(&node.node_type)
+            )?;
+            for (name, value) in &node.attributes {
+                writeln!(
+                    writer,
+                    r#"      <data key="n_{}">{}</data>"#,
+                    name,
+                    xml_escape(&value.to_string())
+                )?;
+            }
+            writeln!(writer, "    </node>")?;
+        }
+        for edge in &self.edges {
+            writeln!(
+                writer,
+                r#"    <edge source="{}" target="{}">"#,
+                xml_escape(&edge.source),
+                xml_escape(&edge.target)
+            )?;
+            writeln!(
+                writer,
+                r#"      <data key="e_type">{}</data>"#,
+                xml_escape(&edge.edge_type)
+            )?;
+            for (name, value) in &edge.attributes {
+                writeln!(
+                    writer,
+                    r#"      <data key="e_{}">{}</data>"#,
+                    name,
+                    xml_escape(&value.to_string())
+                )?;
+            }
+            writeln!(writer, "    </edge>")?;
+        }
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")?;
+        Ok(())
+    }
+
+    /// Write the graph as a GraphML file
+    pub fn save_graphml<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_graphml(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Write the graph as a SONATA circuit into `dir`
+    #[cfg(feature = "sonata")]
+    pub fn save_sonata<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        use hdf5::Column;
+
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let (node_population, edge_population) = self.population_names();
+        let tables = self.tables();
+
+        let mut nodes = sonata_root();
+        let population = nodes.group("nodes").group(node_population);
+        let node_count = self.nodes.len() as u64;
+        population.dataset("node_id", Column::U64((0..node_count).collect()));
+        population.dataset(
+            "node_type_id",
+            Column::U64(
+                self.nodes
+                    .iter()
+                    .map(|n| tables.node_types[n.node_type.as_str()] as u64)
+                    .collect(),
+            ),
+        );
+        population.dataset("node_group_id", Column::U64(vec![0; self.nodes.len()]));
+        population.dataset("node_group_index", Column::U64((0..node_count).collect()));
+        let group = population.group("0");
+        group.dataset(
+            "feagi_id",
+            Column::Text(self.nodes.iter().map(|n| n.id.clone()).collect()),
+        );
+        let rows: Vec<_> = self.nodes.iter().map(|n| &n.attributes).collect();
+        add_sonata_attributes(group, &rows);
+        hdf5::write_file(BufWriter::new(File::create(dir.join("nodes.h5"))?), &nodes)?;
+
+        let mut edges = sonata_root();
+        let population = edges.group("edges").group(edge_population);
+        let edge_count = tables.edges.len() as u64;
+        for (name, column) in [
+            (
+                "source_node_id",
+                tables.edges.iter().map(|e| e.1 as u64).collect(),
+            ),
+            (
+                "target_node_id",
+                tables.edges.iter().map(|e| e.2 as u64).collect(),
+            ),
+        ] {
+            population.dataset_with_attribute(
+                name,
+                Column::U64(column),
+                "node_population",
+                Column::Text(vec![node_population.to_string()]),
+            );
+        }
+        population.dataset(
+            "edge_type_id",
+            Column::U64(
+                tables
+                    .edges
+                    .iter()
+                    .map(|(e, _, _)| tables.edge_types[e.edge_type.as_str()] as u64)
+                    .collect(),
+            ),
+        );
+        population.dataset("edge_group_id", Column::U64(vec![0; tables.edges.len()]));
+        population.dataset("edge_group_index", Column::U64((0..edge_count).collect()));
+        let rows: Vec<_> = tables.edges.iter().map(|(e, _, _)| &e.attributes).collect();
+        add_sonata_attributes(population.group("0"), &rows);
+        hdf5::write_file(BufWriter::new(File::create(dir.join("edges.h5"))?), &edges)?;
+
+        self.write_type_tables(dir, &tables, ' ')?;
+
+        let config = serde_json::json!({
+            "manifest": { "$NETWORK_DIR": "." },
+            "networks": {
+                "nodes": [{
+                    "nodes_file": "$NETWORK_DIR/nodes.h5",
+                    "node_types_file": "$NETWORK_DIR/node_types.csv",
+                    "populations": { node_population: { "type": "virtual" } },
+                }],
+                "edges": [{
+                    "edges_file": "$NETWORK_DIR/edges.h5",
+                    "edge_types_file": "$NETWORK_DIR/edge_types.csv",
+                    "populations": { edge_population: {} },
+                }],
+            },
+        });
+        std::fs::write(
+            dir.join("circuit_config.json"),
+            serde_json::to_string_pretty(&config)?,
+        )
+    }
+
+    /// Write the graph as CSV node/edge tables into `dir`
+    pub fn save_csv_tables<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let tables = self.tables();
+
+        self.write_type_tables(dir, &tables, ',')?;
+
+        let node_keys = attribute_keys(self.nodes.iter().map(|n| &n.attributes));
+        let mut nodes = BufWriter::new(File::create(dir.join("nodes.csv"))?);
+        write!(
+            nodes,
+            "node_id,node_type_id,node_group_id,node_group_index,feagi_id"
+        )?;
+        for (name, _) in &node_keys {
+            write!(nodes, ",{}", name)?;
+        }
+        writeln!(nodes)?;
+        for (i, node) in self.nodes.iter().enumerate() {
+            write!(
+                nodes,
+                "{},{},0,{},{}",
+                i,
+                tables.node_types[node.node_type.as_str()],
+                i,
+                csv_escape(&node.id)
+            )?;
+            write_csv_attributes(&mut nodes, &node_keys, &node.attributes)?;
+        }
+        nodes.flush()?;
+
+        let edge_keys = attribute_keys(tables.edges.iter().map(|(e, _, _)| &e.attributes));
+        let mut edges = BufWriter::new(File::create(dir.join("edges.csv"))?);
+        write!(
+            edges,
+            "edge_id,source_node_id,target_node_id,edge_type_id,edge_group_id,edge_group_index"
+        )?;
+        for (name, _) in &edge_keys {
+            write!(edges, ",{}", name)?;
+        }
+        writeln!(edges)?;
+        for (edge_id, (edge, source, target)) in tables.edges.iter().enumerate() {
+            write!(
+                edges,
+                "{},{},{},{},0,{}",
+                edge_id,
+                source,
+                target,
+                tables.edge_types[edge.edge_type.as_str()],
+                edge_id
+            )?;
+            write_csv_attributes(&mut edges, &edge_keys, &edge.attributes)?;
+        }
+        edges.flush()
+    }
+
+    /// Node/edge population names for the table exports
+    #[cfg_attr(not(feature = "sonata"), allow(dead_code))]
+    fn population_names(&self) -> (&'static str, &'static str) {
+        match self.granularity {
+            GraphGranularity::Area => ("feagi_areas", "feagi_mappings"),
+            GraphGranularity::Neuron => ("feagi_neurons", "feagi_synapses"),
+        }
+    }
+
+    /// Type numbering and contiguous edge endpoints shared by the table exports
+    fn tables(&self) -> Tables<'_> {
+        // Types are numbered in order of first appearance
+        let node_types = type_ids(self.nodes.iter().map(|n| n.node_type.as_str()));
+        let edge_types = type_ids(self.edges.iter().map(|e| e.edge_type.as_str()));
+        let node_ids: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), i))
+            .collect();
+        let edges = self
+            .edges
+            .iter()
+            .filter_map(|edge| {
+                let source = *node_ids.get(edge.source.as_str())?;
+                let target = *node_ids.get(edge.target.as_str())?;
+                Some((edge, source, target))
+            })
+            .collect();
+        Tables {
+            node_types,
+            edge_types,
+            edges,
+        }
+    }
+
+    fn write_type_tables(
+        &self,
+        dir: &Path,
+        tables: &Tables<'_>,
+        delimiter: char,
+    ) -> std::io::Result<()> {
+        let d = delimiter;
+        let mut types = BufWriter::new(File::create(dir.join("node_types.csv"))?);
+        writeln!(types, "node_type_id{d}pop_name{d}model_type")?;
+        for (name, id) in sorted_types(&tables.node_types) {
+            writeln!(types, "{}{d}{}{d}virtual", id, csv_escape(name))?;
+        }
+        types.flush()?;
+
+        let mut types = BufWriter::new(File::create(dir.join("edge_types.csv"))?);
+        writeln!(types, "edge_type_id{d}pop_name")?;
+        for (name, id) in sorted_types(&tables.edge_types) {
+            writeln!(types, "{}{d}{}", id, csv_escape(name))?;
+        }
+        types.flush()
+    }
+}
+
+/// Type numbering and the exported edges with contiguous endpoint ids
+struct Tables<'a> {
+    node_types: BTreeMap<&'a str, usize>,
+    edge_types: BTreeMap<&'a str, usize>,
+    /// Edges whose endpoints are both exported, with source and target ids
+    edges: Vec<(&'a GraphEdge, usize, usize)>,
+}
+
+/// Root group carrying the SONATA `magic` and `version` attributes
+#[cfg(feature = "sonata")]
+fn sonata_root() -> hdf5::Group {
+    let mut root = hdf5::Group::default();
+    root.scalar_attribute("magic", hdf5::Column::U32(vec![0x0A7A]));
+    root.array_attribute("version", hdf5::Column::U32(vec![0, 1]));
+    root
+}
+
+/// Add one dataset per attribute to a SONATA group; missing values are 0, NaN or ""
+#[cfg(feature = "sonata")]
+fn add_sonata_attributes(group: &mut hdf5::Group, rows: &[&Vec<(&'static str, GraphAttribute)>]) {
+    use hdf5::Column;
+
+    for (key, kind) in attribute_keys(rows.iter().copied()) {
+        let values = rows
+            .iter()
+            .map(|attrs| attrs.iter().find(|(name, _)| *name == key).map(|(_, v)| v));
+        let column = match kind {
+            "long" => Column::I64(
+                values
+                    .map(|v| match v {
+                        Some(GraphAttribute::Int(x)) => *x,
+                        Some(GraphAttribute::Float(x)) => *x as i64,
+                        _ => 0,
+                    })
+                    .collect(),
+            ),
+            "double" => Column::F64(
+                values
+                    .map(|v| match v {
+                        Some(GraphAttribute::Float(x)) => *x,
+                        Some(GraphAttribute::Int(x)) => *x as f64,
+                        _ => f64::NAN,
+                    })
+                    .collect(),
+            ),
+            _ => Column::Text(
+                values
+                    .map(|v| v.map(|v| v.to_string()).unwrap_or_default())
+                    .collect(),
+            ),
+        };
+        group.dataset(key, column);
+    }
+}
+
+/// Collect attribute names (in first-seen order) with their GraphML types
+fn attribute_keys<'a>(
+    attributes: impl Iterator<Item = &'a Vec<(&'static str, GraphAttribute)>>,
+) -> Vec<(&'static str, &'static str)> {
+    let mut keys: Vec<(&'static str, &'static str)> = Vec::new();
+    for attrs in attributes {
+        for (name, value) in attrs {
+            if !keys.iter().any(|(k, _)| k == name) {
+                keys.push((name, value.graphml_type()));
+            }
+        }
+    }
+    keys
+}
+
+fn type_ids<'a>(types: impl Iterator<Item = &'a str>) -> BTreeMap<&'a str, usize> {
+    let mut ids = BTreeMap::new();
+    for name in types {
+        let next = ids.len();
+        ids.entry(name).or_insert(next);
+    }
+    ids
+}
+
+fn sorted_types<'a>(types: &BTreeMap<&'a str, usize>) -> Vec<(&'a str, usize)> {
+    let mut sorted: Vec<(&str, usize)> = types.iter().map(|(k, v)| (*k, *v)).collect();
+    sorted.sort_by_key(|(_, id)| *id);
+    sorted
+}
+
+fn write_csv_attributes<W: Write>(
+    writer: &mut W,
+    keys: &[(&'static str, &'static str)],
+    attributes: &[(&'static str, GraphAttribute)],
+) -> std::io::Result<()> {
+    for (key, _) in keys {
+        match attributes.iter().find(|(name, _)| name == key) {
+            Some((_, value)) => write!(writer, ",{}", csv_escape(&value.to_string()))?,
+            None => write!(writer, ",")?,
+        }
+    }
+    writeln!(writer)
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> ConnectomeGraph {
+        ConnectomeGraph {
+            granularity: GraphGranularity::Area,
+            nodes: vec![
+                GraphNode {
+                    id: "a".to_string(),
+                    node_type: "cortical_area".to_string(),
+                    attributes: vec![("name", GraphAttribute::Text("V1 & <edge>".to_string()))],
+                },
+                GraphNode {
+                    id: "b".to_string(),
+                    node_type: "cortical_area".to_string(),
+                    attributes: vec![("name", GraphAttribute::Text("motor, left".to_string()))],
+                },
+            ],
+            edges: vec![GraphEdge {
+                source: "a".to_string(),
+                target: "b".to_string(),
+                edge_type: "cortical_mapping".to_string(),
+                attributes: vec![("synapse_count", GraphAttribute::Int(42))],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_graphml_output() {
+        let mut out = Vec::new();
+        sample_graph().write_graphml(&mut out).unwrap();
+        let xml = String::from_utf8(out).unwrap();
+
+        assert!(
+            xml.contains(r#"<key id="n_name" for="node" attr.name="name" attr.type="string"/>"#)
+        );
+        assert!(xml.contains(
+            r#"<key id="e_synapse_count" for="edge" attr.name="synapse_count" attr.type="long"/>"#
+        ));
+        assert!(xml.contains("V1 &amp; &lt;edge&gt;"));
+        assert!(xml.contains(r#"<edge source="a" target="b">"#));
+        assert!(xml.contains(r#"<data key="e_synapse_count">42</data>"#));
+    }
+
+    #[test]
+    fn test_csv_table_output() {
+        let dir = std::env::temp_dir().join(format!("feagi_graph_csv_{}", std::process::id()));
+        sample_graph().save_csv_tables(&dir).unwrap();
+
+        let nodes = std::fs::read_to_string(dir.join("nodes.csv")).unwrap();
+        let mut lines = nodes.lines();
+        assert_eq!(
+            lines.next(),
+            Some("node_id,node_type_id,node_group_id,node_group_index,feagi_id,name")
+        );
+        assert_eq!(lines.next(), Some("0,0,0,0,a,V1 & <edge>"));
+        assert_eq!(lines.next(), Some("1,0,0,1,b,\"motor, left\""));
+
+        let edges = std::fs::read_to_string(dir.join("edges.csv")).unwrap();
+        assert!(edges.lines().any(|l| l == "0,0,1,0,0,0,42"));
+
+        let types = std::fs::read_to_string(dir.join("node_types.csv")).unwrap();
+        assert_eq!(
+            types.lines().next(),
+            Some("node_type_id,pop_name,model_type")
+        );
+        assert_eq!(types.lines().nth(1), Some("0,cortical_area,virtual"));
+        let types = std::fs::read_to_string(dir.join("edge_types.csv")).unwrap();
+        assert_eq!(types.lines().nth(1), Some("0,cortical_mapping"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "sonata")]
+    #[test]
+    fn test_sonata_output() {
+        let dir = std::env::temp_dir().join(format!("feagi_sonata_{}", std::process::id()));
+        sample_graph().save_sonata(&dir).unwrap();
+
+        for file in ["nodes.h5", "edges.h5"] {
+            let bytes = std::fs::read(dir.join(file)).unwrap();
+            assert_eq!(&bytes[..4], b"\x89HDF");
+        }
+        let types = std::fs::read_to_string(dir.join("node_types.csv")).unwrap();
+        assert_eq!(types.lines().nth(1), Some("0 cortical_area virtual"));
+        let config = std::fs::read_to_string(dir.join("circuit_config.json")).unwrap();
+        assert!(config.contains("$NETWORK_DIR/nodes.h5"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
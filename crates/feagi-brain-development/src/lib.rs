@@ -37,6 +37,7 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub mod connectivity;
 pub mod connectome_manager;
 pub mod cortical_type_utils;
+pub mod graph_export;
 pub mod neuroembryogenesis;
 mod rng;
 pub mod spatial;
@@ -72,6 +73,11 @@ pub use feagi_evolutionary::{GenomeParser, GenomeSaver, ParsedGenome};
 // Re-export connectome manager
 pub use connectome_manager::{ConnectomeConfig, ConnectomeManager};
 
+// Re-export connectome graph export
+pub use graph_export::{
+    build_connectome_graph, ConnectomeGraph, GraphExportOptions, GraphGranularity,
+};
+
 // Re-export neuroembryogenesis
 pub use neuroembryogenesis::{DevelopmentProgress, DevelopmentStage, Neuroembryogenesis};
 
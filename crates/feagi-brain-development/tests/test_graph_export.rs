@@ -0,0 +1,200 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Graph Export Tests
+///
+/// Builds area- and neuron-level graphs from a small connectome and checks
+/// nodes, edges, synapse counts and region filtering.
+use feagi_brain_development::graph_export::{
+    build_connectome_graph, GraphAttribute, GraphExportOptions, GraphGranularity,
+};
+use feagi_brain_development::{
+    BrainRegion, ConnectomeManager, CorticalArea, CorticalID, RegionType,
+};
+use feagi_npu_burst_engine::{RustNPU, TracingMutex};
+use feagi_structures::genomic::brain_regions::RegionID;
+use feagi_structures::genomic::cortical_area::{
+    CorticalAreaDimensions, CorticalAreaType, CustomCorticalType,
+};
+use std::sync::Arc;
+
+fn create_test_manager() -> ConnectomeManager {
+    let runtime = feagi_npu_runtime::StdRuntime;
+    let backend = feagi_npu_burst_engine::backend::CPUBackend::new();
+    let npu = RustNPU::new(runtime, backend, 10_000, 100_000, 10).expect("Failed to create NPU");
+    let npu = Arc::new(TracingMutex::new(
+        feagi_npu_burst_engine::DynamicNPU::F32(npu),
+        "TestNPU",
+    ));
+    ConnectomeManager::new_for_testing_with_npu(npu)
+}
+
+fn add_area(manager: &mut ConnectomeManager, id: &[u8; 8], name: &str) -> CorticalID {
+    let cortical_id = CorticalID::try_from_bytes(id).unwrap();
+    let area = CorticalArea::new(
+        cortical_id,
+        0,
+        name.to_string(),
+        CorticalAreaDimensions::new(2, 1, 1).unwrap(),
+        (0, 0, 0).into(),
+        CorticalAreaType::Custom(CustomCorticalType::LeakyIntegrateFire),
+    )
+    .unwrap();
+    manager.add_cortical_area(area).unwrap();
+    cortical_id
+}
+
+fn add_neuron(manager: &mut ConnectomeManager, area: &CorticalID, x: u32) -> u64 {
+    manager
+        .add_neuron(
+            area,
+            x,
+            0,
+            0,
+            1.0,
+            f32::MAX,
+            0.1,
+            0.0,
+            0,
+            2,
+            1.0,
+            3,
+            5,
+            false,
+        )
+        .unwrap()
+}
+
+/// Two areas `cgraph_a` -> `cgraph_b`, two neurons each, three synapses a->b
+/// and one recurrent synapse inside b.
+fn build_connectome() -> (ConnectomeManager, CorticalID, CorticalID, Vec<u64>) {
+    let mut manager = create_test_manager();
+    let a = add_area(&mut manager, b"cgraph_a", "Area A");
+    let b = add_area(&mut manager, b"cgraph_b", "Area B");
+
+    let neurons = vec![
+        add_neuron(&mut manager, &a, 0),
+        add_neuron(&mut manager, &a, 1),
+        add_neuron(&mut manager, &b, 0),
+        add_neuron(&mut manager, &b, 1),
+    ];
+    manager
+        .create_synapse(neurons[0], neurons[2], 128, 200, 0)
+        .unwrap();
+    manager
+        .create_synapse(neurons[0], neurons[3], 64, 200, 0)
+        .unwrap();
+    manager
+        .create_synapse(neurons[1], neurons[3], 32, 100, 1)
+        .unwrap();
+    manager
+        .create_synapse(neurons[2], neurons[3], 16, 50, 0)
+        .unwrap();
+    manager
+        .get_npu()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .rebuild_synapse_index();
+
+    manager
+        .get_cortical_area_mut(&a)
+        .unwrap()
+        .properties
+        .insert(
+            "cortical_mapping_dst".to_string(),
+            serde_json::json!({ b.as_base_64(): [{ "morphology_id": "projector" }] }),
+        );
+
+    (manager, a, b, neurons)
+}
+
+#[test]
+fn test_area_graph() {
+    let (manager, a, b, _) = build_connectome();
+    let graph = build_connectome_graph(&manager, &GraphExportOptions::default()).unwrap();
+
+    assert_eq!(graph.granularity, GraphGranularity::Area);
+    assert_eq!(graph.nodes.len(), 2);
+    assert_eq!(graph.nodes[0].id, a.as_base_64());
+    assert!(graph.nodes[0]
+        .attributes
+        .contains(&("neuron_count", GraphAttribute::Int(2))));
+
+    assert_eq!(graph.edges.len(), 1);
+    let edge = &graph.edges[0];
+    assert_eq!(
+        (edge.source.as_str(), edge.target.as_str()),
+        (a.as_base_64().as_str(), b.as_base_64().as_str())
+    );
+    assert!(edge
+        .attributes
+        .contains(&("synapse_count", GraphAttribute::Int(3))));
+    assert!(edge.attributes.contains(&(
+        "morphologies",
+        GraphAttribute::Text("projector".to_string())
+    )));
+
+    let mut xml = Vec::new();
+    graph.write_graphml(&mut xml).unwrap();
+    assert!(String::from_utf8(xml).unwrap().contains("Area B"));
+}
+
+#[test]
+fn test_neuron_graph() {
+    let (manager, _, b, neurons) = build_connectome();
+    let options = GraphExportOptions {
+        granularity: GraphGranularity::Neuron,
+        ..Default::default()
+    };
+    let graph = build_connectome_graph(&manager, &options).unwrap();
+
+    assert_eq!(graph.nodes.len(), 4);
+    assert_eq!(graph.edges.len(), 4);
+
+    let inhibitory: Vec<_> = graph
+        .edges
+        .iter()
+        .filter(|e| e.edge_type == "inhibitory")
+        .collect();
+    assert_eq!(inhibitory.len(), 1);
+    assert_eq!(inhibitory[0].source, neurons[1].to_string());
+    assert!(inhibitory[0]
+        .attributes
+        .contains(&("weight", GraphAttribute::Int(32))));
+
+    let node = graph
+        .nodes
+        .iter()
+        .find(|n| n.id == neurons[3].to_string())
+        .unwrap();
+    assert_eq!(node.node_type, b.as_base_64());
+    assert!(node.attributes.contains(&("x", GraphAttribute::Int(1))));
+}
+
+#[test]
+fn test_region_filter() {
+    let (mut manager, _, b, _) = build_connectome();
+    let region_id = RegionID::new();
+    let region = BrainRegion::new(region_id, "Region B".to_string(), RegionType::Undefined)
+        .unwrap()
+        .with_areas([b]);
+    manager.add_brain_region(region, None).unwrap();
+
+    let options = GraphExportOptions {
+        granularity: GraphGranularity::Neuron,
+        region_ids: vec![region_id.to_string()],
+        include_subregions: true,
+    };
+    let graph = build_connectome_graph(&manager, &options).unwrap();
+
+    // Only area B neurons and the recurrent synapse inside B remain
+    assert_eq!(graph.nodes.len(), 2);
+    assert_eq!(graph.edges.len(), 1);
+
+    let missing = GraphExportOptions {
+        region_ids: vec!["no-such-region".to_string()],
+        ..Default::default()
+    };
+    assert!(build_connectome_graph(&manager, &missing).is_err());
+}
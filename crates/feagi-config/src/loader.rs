@@ -428,6 +428,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_burst_engine_threads() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("feagi_configuration.toml");
+
+        let mut file = File::create(&config_path).unwrap();
+        writeln!(file, "[burst_engine]").unwrap();
+        writeln!(file, "cpu_threads = 8").unwrap();
+        writeln!(file, "cpu_parallel_synapse_threshold = 50000").unwrap();
+
+        let config = load_config(Some(&config_path), None).unwrap();
+
+        assert_eq!(config.burst_engine.cpu_threads, 8);
+        assert_eq!(config.burst_engine.cpu_parallel_synapse_threshold, 50_000);
+        assert_eq!(FeagiConfig::default().burst_engine.cpu_threads, 0);
+    }
+
     #[test]
     fn test_environment_overrides() {
         let _env_lock = ENV_LOCK.lock().unwrap();
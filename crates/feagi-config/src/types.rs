@@ -29,6 +29,9 @@ pub struct FeagiConfig {
     pub ports: PortsConfig,
     pub zmq: ZmqConfig,
     pub websocket: WebSocketConfig,   // FEAGI 2.0: WebSocket transport
+    pub shm: SharedMemoryConfig,      // Same-host agents over shared memory rings
+    pub tcp: TcpTransportConfig,      // Length-prefixed frames over raw TCP
+    pub uds: UnixSocketConfig,        // Same-host agents over Unix domain sockets
     pub transports: TransportsConfig, // FEAGI 2.0: Multi-transport coordination
     pub timeouts: TimeoutsConfig,
     pub agents: AgentsConfig,
@@ -42,6 +45,7 @@ pub struct FeagiConfig {
     pub compression: CompressionConfig,
     pub memory_processing: MemoryProcessingConfig,
     pub snapshot: SnapshotConfig,
+    pub event_journal: EventJournalConfig, // Persistent log of state changes
 }
 
 /// System-level configuration
@@ -346,6 +350,90 @@ impl Default for WebSocketConfig {
     }
 }
 
+/// Shared memory transport settings for agents on the same host
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SharedMemoryConfig {
+    /// Directory the sensory, motor and visualization ring files are created in.
+    /// Defaults to the `/dev/shm` tmpfs on Linux and the temp directory elsewhere.
+    pub directory: String,
+    /// Distinguishes the ring files of FEAGI instances sharing `directory`; empty uses the
+    /// process ID.
+    pub instance_name: String,
+    /// Frames kept per ring before the oldest is overwritten.
+    pub slot_count: u32,
+    /// Largest frame a ring accepts, in bytes. Each ring takes `slot_count` times this.
+    pub max_frame_size: u32,
+}
+
+impl SharedMemoryConfig {
+    /// Instance part of the ring file names
+    pub fn resolved_instance_name(&self) -> String {
+        if self.instance_name.is_empty() {
+            std::process::id().to_string()
+        } else {
+            self.instance_name.clone()
+        }
+    }
+}
+
+impl Default for SharedMemoryConfig {
+    fn default() -> Self {
+        let directory = if cfg!(target_os = "linux") {
+            "/dev/shm".to_string()
+        } else {
+            std::env::temp_dir().to_string_lossy().into_owned()
+        };
+        Self {
+            directory,
+            instance_name: String::new(),
+            slot_count: 16,
+            max_frame_size: 1048576, // 1MB
+        }
+    }
+}
+
+/// Raw TCP transport settings for agents that cannot use libzmq
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TcpTransportConfig {
+    /// Host/IP to bind TCP transport endpoints to.
+    pub bind_host: String,
+    /// Host/IP advertised to clients for connecting to TCP transport endpoints.
+    pub advertised_host: String,
+    pub sensory_port: u16,
+    pub motor_port: u16,
+    pub visualization_port: u16,
+}
+
+impl Default for TcpTransportConfig {
+    fn default() -> Self {
+        Self {
+            bind_host: "127.0.0.1".to_string(),
+            advertised_host: "127.0.0.1".to_string(),
+            sensory_port: 9071,
+            motor_port: 9072,
+            visualization_port: 9070,
+        }
+    }
+}
+
+/// Unix domain socket transport settings for agents on the same host
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UnixSocketConfig {
+    /// Directory the sensory, motor and visualization sockets are created in.
+    pub directory: String,
+}
+
+impl Default for UnixSocketConfig {
+    fn default() -> Self {
+        Self {
+            directory: "/tmp".to_string(),
+        }
+    }
+}
+
 /// Multi-transport coordination settings (FEAGI 2.0)
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
@@ -548,6 +636,13 @@ pub struct BurstEngineConfig {
     pub memory_area_multiplier: f64,
     pub enable_preallocation: bool,
     pub enable_capacity_warnings: bool,
+    /// Worker threads for synaptic propagation and neural dynamics
+    /// (0 = shared rayon pool, 1 = serial, N = dedicated pool of N threads)
+    pub cpu_threads: usize,
+    /// Minimum synapses per propagation task, and fire candidates before neural
+    /// dynamics is partitioned across `cpu_threads` (0 = unset: rayon's own
+    /// splitting and serial neural dynamics)
+    pub cpu_parallel_synapse_threshold: usize,
     pub sleep: BurstEngineSleepConfig,
 }
 
@@ -560,6 +655,8 @@ impl Default for BurstEngineConfig {
             memory_area_multiplier: 2.0,
             enable_preallocation: true,
             enable_capacity_warnings: true,
+            cpu_threads: 0,
+            cpu_parallel_synapse_threshold: 0,
             sleep: BurstEngineSleepConfig::default(),
         }
     }
@@ -678,6 +775,9 @@ impl Default for VisualizationConfig {
 }
 
 /// Data compression settings
+///
+/// Agent stream compression is opt-in: it is only negotiated when `enabled` is set here and the
+/// agent advertises support for `algorithm` during registration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct CompressionConfig {
@@ -688,18 +788,25 @@ pub struct CompressionConfig {
     pub compress_visualization: bool,
     pub compress_motor: bool,
     pub compress_sensory: bool,
+    /// Keyframe interval of XYZP delta-coded motor and visualization streams, for agents that
+    /// advertise delta support (0 keeps sending full frames)
+    pub xyzp_delta_keyframe_interval: u32,
+    /// Largest size in bytes a compressed agent payload may inflate to; larger payloads are rejected
+    pub max_decompressed_size: usize,
 }
 
 impl Default for CompressionConfig {
     fn default() -> Self {
         Self {
-            enabled: true,
+            enabled: false,
             min_size_threshold: 100,
             enable_stats: true,
             algorithm: "lz4".to_string(),
             compress_visualization: true,
             compress_motor: true,
             compress_sensory: false,
+            xyzp_delta_keyframe_interval: 0,
+            max_decompressed_size: 16 * 1024 * 1024,
         }
     }
 }
@@ -746,6 +853,32 @@ impl Default for SleepManagerConfig {
     }
 }
 
+/// Persistent state event journal configuration
+///
+/// Off by default. When enabled, genome loads, cortical area and mapping changes, agent
+/// registrations and burst engine state changes are appended to JSON-lines
+/// files that `GET /v1/system/events` reads back. The journal rotates to a new
+/// file every `max_file_size_mb` and keeps the newest `max_files`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EventJournalConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub max_file_size_mb: u64, // Start a new segment file at this size
+    pub max_files: usize,      // Oldest segment files beyond this count are deleted (0 = keep all)
+}
+
+impl Default for EventJournalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: "output/events/state_events.jsonl".to_string(),
+            max_file_size_mb: 16,
+            max_files: 8,
+        }
+    }
+}
+
 /// Brain snapshot configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
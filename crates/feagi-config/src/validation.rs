@@ -274,6 +274,62 @@ fn validate_value_ranges(config: &FeagiConfig, errors: &mut Vec<ConfigValidation
         }
     }
 
+    // Stream compression algorithm must be one the agent transports can negotiate
+    match config.compression.algorithm.to_ascii_lowercase().as_str() {
+        "lz4" | "zstd" | "none" => {}
+        other => {
+            errors.push(ConfigValidationError::InvalidValue {
+                field: "compression.algorithm".to_string(),
+                reason: format!("must be 'lz4', 'zstd', or 'none' (got '{}')", other),
+            });
+        }
+    }
+
+    if config.compression.max_decompressed_size == 0 {
+        errors.push(ConfigValidationError::InvalidValue {
+            field: "compression.max_decompressed_size".to_string(),
+            reason: "must be positive".to_string(),
+        });
+    }
+
+    // Shared memory rings need room for at least one frame
+    if config.shm.slot_count == 0 {
+        errors.push(ConfigValidationError::InvalidValue {
+            field: "shm.slot_count".to_string(),
+            reason: "must be positive".to_string(),
+        });
+    }
+    if config.shm.instance_name.contains(['/', '\\']) {
+        errors.push(ConfigValidationError::InvalidValue {
+            field: "shm.instance_name".to_string(),
+            reason: "must not contain path separators".to_string(),
+        });
+    }
+    if config.shm.max_frame_size == 0 {
+        errors.push(ConfigValidationError::InvalidValue {
+            field: "shm.max_frame_size".to_string(),
+            reason: "must be positive".to_string(),
+        });
+    }
+
+    // TCP transport streams each need their own port
+    let tcp_ports = [
+        ("tcp.sensory_port", config.tcp.sensory_port),
+        ("tcp.motor_port", config.tcp.motor_port),
+        ("tcp.visualization_port", config.tcp.visualization_port),
+    ];
+    for (i, (first_name, first_port)) in tcp_ports.iter().enumerate() {
+        for (second_name, second_port) in &tcp_ports[i + 1..] {
+            if first_port == second_port {
+                errors.push(ConfigValidationError::PortConflict {
+                    port1: first_name.to_string(),
+                    port2: second_name.to_string(),
+                    port: *first_port,
+                });
+            }
+        }
+    }
+
     // Advertised hosts must be routable; wildcard bind addresses are not valid for discovery.
     validate_advertised_host("api.advertised_host", &config.api.advertised_host, errors);
     validate_advertised_host("zmq.advertised_host", &config.zmq.advertised_host, errors);
@@ -282,6 +338,7 @@ fn validate_value_ranges(config: &FeagiConfig, errors: &mut Vec<ConfigValidation
         &config.websocket.advertised_host,
         errors,
     );
+    validate_advertised_host("tcp.advertised_host", &config.tcp.advertised_host, errors);
     validate_advertised_host(
         "agent.advertised_host",
         &config.agent.advertised_host,
@@ -386,6 +443,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tcp_transport_port_conflict() {
+        let mut config = FeagiConfig::default();
+        config.tcp.motor_port = config.tcp.sensory_port;
+
+        let result = validate_config(&config);
+        assert!(matches!(
+            result,
+            Err(ConfigError::ValidationError(msg))
+                if msg.contains("tcp.sensory_port") && msg.contains("tcp.motor_port")
+        ));
+    }
+
+    #[test]
+    fn test_invalid_compression_algorithm() {
+        let mut config = FeagiConfig::default();
+        config.compression.algorithm = "ZSTD".to_string();
+        assert!(validate_config(&config).is_ok());
+
+        config.compression.algorithm = "brotli".to_string();
+        let result = validate_config(&config);
+        assert!(matches!(
+            result,
+            Err(ConfigError::ValidationError(msg)) if msg.contains("compression.algorithm")
+        ));
+    }
+
+    #[test]
+    fn test_shm_instance_name_stays_in_directory() {
+        let mut config = FeagiConfig::default();
+        config.shm.instance_name = "robot-a".to_string();
+        assert!(validate_config(&config).is_ok());
+        assert_eq!(config.shm.resolved_instance_name(), "robot-a");
+
+        config.shm.instance_name = "../robot-a".to_string();
+        let result = validate_config(&config);
+        assert!(matches!(
+            result,
+            Err(ConfigError::ValidationError(msg)) if msg.contains("shm.instance_name")
+        ));
+    }
+
     #[test]
     fn test_non_routable_advertised_host_is_rejected() {
         let mut config = FeagiConfig::default();
@@ -20,6 +20,8 @@ fn main() {
             "websocket-transport-std",
             cfg!(feature = "websocket-transport-std"),
         ),
+        ("tcp-transport", cfg!(feature = "tcp-transport")),
+        ("uds-transport", cfg!(feature = "uds-transport")),
         ("shm-transport", cfg!(feature = "shm-transport")),
         ("bluetooth-transport", cfg!(feature = "bluetooth-transport")),
     ];
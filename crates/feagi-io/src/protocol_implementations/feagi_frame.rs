@@ -0,0 +1,72 @@
+//! Lightweight inspection of FEAGI byte container frames shared by transport implementations.
+//!
+//! Transports only peek at the container header; full validation is left to the receiver.
+
+use crate::AgentID;
+use feagi_serialization::payload_compression::{
+    is_compressed_payload, COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT,
+};
+use feagi_serialization::{FeagiByteContainer, PayloadCompression};
+
+const MIN_FEAGI_FRAME_BYTES: usize = 12;
+const STRUCT_LOOKUP_BYTES_PER_ENTRY: usize = 4;
+
+/// Lightweight FEAGI frame sanity check used for latest-wins filtering.
+///
+/// This avoids selecting trailing noise frames that can appear during
+/// reconnect churn and would otherwise cause downstream decode drops.
+/// Compressed envelopes are accepted as long as their header is intact; the
+/// receiver inflates and validates them.
+pub(crate) fn is_plausible_feagi_frame(bytes: &[u8]) -> bool {
+    if is_compressed_payload(bytes) {
+        return bytes.len() > COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT
+            && matches!(
+                PayloadCompression::try_from(bytes[1]),
+                Ok(PayloadCompression::Lz4 | PayloadCompression::Zstd)
+            );
+    }
+    if bytes.len() < MIN_FEAGI_FRAME_BYTES {
+        return false;
+    }
+    // Byte 0: FEAGI binary structure version
+    if bytes[0] != FeagiByteContainer::CURRENT_FBS_VERSION {
+        return false;
+    }
+    // Byte 3: number of structures in container header
+    let structure_count = bytes[3] as usize;
+    let min_required =
+        MIN_FEAGI_FRAME_BYTES + structure_count.saturating_mul(STRUCT_LOOKUP_BYTES_PER_ENTRY);
+    bytes.len() >= min_required
+}
+
+/// For sensory channels, empty containers (struct_count=0) are valid protocol frames
+/// but should not eclipse meaningful sensory updates inside the same drain window.
+/// Agents only compress payloads above a size threshold, so compressed frames count as non-empty.
+pub(crate) fn has_non_empty_payload(bytes: &[u8]) -> bool {
+    if is_compressed_payload(bytes) {
+        return true;
+    }
+    bytes.get(3).copied().unwrap_or(0) > 0
+}
+
+/// Reads the agent ID a client stamped into the container header, if any.
+///
+/// The header of a compressed payload is not readable without inflating it, so none is returned.
+pub(crate) fn try_extract_agent_id_from_payload(payload: &[u8]) -> Option<AgentID> {
+    if is_compressed_payload(payload) {
+        return None;
+    }
+    let start = FeagiByteContainer::GLOBAL_BYTE_HEADER_BYTE_COUNT;
+    let end = start + FeagiByteContainer::AGENT_ID_BYTE_COUNT;
+    if payload.len() < end {
+        return None;
+    }
+
+    let mut id_bytes = [0u8; AgentID::NUMBER_BYTES];
+    id_bytes.copy_from_slice(&payload[start..end]);
+    let parsed_id = AgentID::new(id_bytes);
+    if parsed_id.is_blank() {
+        return None;
+    }
+    Some(parsed_id)
+}
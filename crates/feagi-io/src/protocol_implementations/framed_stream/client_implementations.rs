@@ -0,0 +1,472 @@
+//! Framed stream client implementations using the poll-based trait design.
+//!
+//! Connections are established synchronously in `request_connect()` and then put in
+//! non-blocking mode, like the WebSocket clients.
+
+use crate::protocol_implementations::framed_stream::framing::FramedConnection;
+use crate::protocol_implementations::framed_stream::FramedStreamTransport;
+use crate::traits_and_enums::client::{
+    FeagiClient, FeagiClientPusher, FeagiClientPusherProperties, FeagiClientRequester,
+    FeagiClientRequesterProperties, FeagiClientSubscriber, FeagiClientSubscriberProperties,
+};
+use crate::traits_and_enums::shared::{FeagiEndpointState, TransportProtocolEndpoint};
+use crate::FeagiNetworkError;
+
+/// Moves the next complete frame of a connection into `receive_buffer`.
+///
+/// Returns the error state to enter if the connection failed or was closed.
+fn try_receive_frame<S: std::io::Read + std::io::Write>(
+    connection: &mut FramedConnection<S>,
+    receive_buffer: &mut Vec<u8>,
+) -> Result<bool, FeagiNetworkError> {
+    let still_open = connection.fill_read_buffer()?;
+    if connection.take_frame_into(receive_buffer)? {
+        return Ok(true);
+    }
+    if !still_open {
+        return Err(FeagiNetworkError::ReceiveFailed(
+            "Connection closed".to_string(),
+        ));
+    }
+    Ok(false)
+}
+
+// ============================================================================
+// Subscriber
+// ============================================================================
+
+//region Subscriber Properties
+
+/// Configuration properties for creating a framed stream subscriber client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedClientSubscriberProperties<T: FramedStreamTransport> {
+    server_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientSubscriberProperties<T> {
+    /// Creates new subscriber properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: T::parse_address(server_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientSubscriberProperties
+    for FeagiFramedClientSubscriberProperties<T>
+{
+    fn as_boxed_client_subscriber(&self) -> Box<dyn FeagiClientSubscriber> {
+        Box::new(FeagiFramedClientSubscriber::<T> {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            connection: None,
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+//endregion
+
+//region Subscriber Implementation
+
+/// A framed stream client that subscribes to data from a publisher server.
+pub struct FeagiFramedClientSubscriber<T: FramedStreamTransport> {
+    server_address: T::Address,
+    current_state: FeagiEndpointState,
+    connection: Option<FramedConnection<T::Stream>>,
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientSubscriber<T> {
+    fn close(&mut self) {
+        self.connection = None;
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClient for FeagiFramedClientSubscriber<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && !self.has_data {
+            if let Some(connection) = &mut self.connection {
+                match try_receive_frame(connection, &mut self.receive_buffer) {
+                    Ok(true) => {
+                        self.has_data = true;
+                        self.current_state = FeagiEndpointState::ActiveHasData;
+                    }
+                    Ok(false) => {}
+                    Err(e) => self.current_state = FeagiEndpointState::Errored(e),
+                }
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let stream = T::connect(&self.server_address)?;
+                self.connection = Some(FramedConnection::new(stream));
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientSubscriber for FeagiFramedClientSubscriber<T> {
+    fn consume_retrieved_data(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(self.receive_buffer.as_slice())
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no data available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_subscriber_properties(&self) -> Box<dyn FeagiClientSubscriberProperties> {
+        Box::new(FeagiFramedClientSubscriberProperties::<T> {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Pusher
+// ============================================================================
+
+//region Pusher Properties
+
+/// Configuration properties for creating a framed stream pusher client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedClientPusherProperties<T: FramedStreamTransport> {
+    server_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientPusherProperties<T> {
+    /// Creates new pusher properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: T::parse_address(server_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientPusherProperties
+    for FeagiFramedClientPusherProperties<T>
+{
+    fn as_boxed_client_pusher(&self) -> Box<dyn FeagiClientPusher> {
+        Box::new(FeagiFramedClientPusher::<T> {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            connection: None,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+//endregion
+
+//region Pusher Implementation
+
+/// A framed stream client that pushes data to a server.
+pub struct FeagiFramedClientPusher<T: FramedStreamTransport> {
+    server_address: T::Address,
+    current_state: FeagiEndpointState,
+    connection: Option<FramedConnection<T::Stream>>,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientPusher<T> {
+    fn close(&mut self) {
+        self.connection = None;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClient for FeagiFramedClientPusher<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        // Pusher doesn't receive data, but keeps partially sent frames moving
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) {
+            if let Some(connection) = &mut self.connection {
+                if let Err(e) = connection.flush_pending_writes() {
+                    self.current_state = FeagiEndpointState::Errored(e);
+                }
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let stream = T::connect(&self.server_address)?;
+                self.connection = Some(FramedConnection::new(stream));
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientPusher for FeagiFramedClientPusher<T> {
+    fn publish_data(&mut self, data: &[u8]) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                let connection = self
+                    .connection
+                    .as_mut()
+                    .ok_or_else(|| FeagiNetworkError::SendFailed("Not connected".to_string()))?;
+                connection.send_frame(data)
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot publish: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_pusher_properties(&self) -> Box<dyn FeagiClientPusherProperties> {
+        Box::new(FeagiFramedClientPusherProperties::<T> {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Requester
+// ============================================================================
+
+//region Requester Properties
+
+/// Configuration properties for creating a framed stream requester client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedClientRequesterProperties<T: FramedStreamTransport> {
+    server_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientRequesterProperties<T> {
+    /// Creates new requester properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: T::parse_address(server_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientRequesterProperties
+    for FeagiFramedClientRequesterProperties<T>
+{
+    fn as_boxed_client_requester(&self) -> Box<dyn FeagiClientRequester> {
+        Box::new(FeagiFramedClientRequester::<T> {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            connection: None,
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+//endregion
+
+//region Requester Implementation
+
+/// A framed stream client that sends requests and receives responses.
+pub struct FeagiFramedClientRequester<T: FramedStreamTransport> {
+    server_address: T::Address,
+    current_state: FeagiEndpointState,
+    connection: Option<FramedConnection<T::Stream>>,
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedClientRequester<T> {
+    fn close(&mut self) {
+        self.connection = None;
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClient for FeagiFramedClientRequester<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && !self.has_data {
+            if let Some(connection) = &mut self.connection {
+                let received = connection
+                    .flush_pending_writes()
+                    .and_then(|_| try_receive_frame(connection, &mut self.receive_buffer));
+                match received {
+                    Ok(true) => {
+                        self.has_data = true;
+                        self.current_state = FeagiEndpointState::ActiveHasData;
+                    }
+                    Ok(false) => {}
+                    Err(e) => self.current_state = FeagiEndpointState::Errored(e),
+                }
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let stream = T::connect(&self.server_address)?;
+                self.connection = Some(FramedConnection::new(stream));
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.server_address)
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiClientRequester for FeagiFramedClientRequester<T> {
+    fn publish_request(&mut self, request: &[u8]) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                let connection = self
+                    .connection
+                    .as_mut()
+                    .ok_or_else(|| FeagiNetworkError::SendFailed("Not connected".to_string()))?;
+                connection.send_frame(request)
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot send request: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn consume_retrieved_response(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(self.receive_buffer.as_slice())
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no response available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_requester_properties(&self) -> Box<dyn FeagiClientRequesterProperties> {
+        Box::new(FeagiFramedClientRequesterProperties::<T> {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
@@ -0,0 +1,226 @@
+//! Non-blocking length-prefixed frame reading and writing over a byte stream.
+
+use std::io::{ErrorKind, Read, Write};
+
+use crate::FeagiNetworkError;
+
+/// Bytes of the little endian u32 length preceding every frame
+const FRAME_LENGTH_PREFIX_BYTE_COUNT: usize = 4;
+
+/// Largest frame accepted from a peer; anything larger is treated as a corrupt stream.
+///
+/// Peers are not authenticated at the transport level, so this also bounds what a single
+/// connection can make the server buffer.
+pub const MAX_FRAME_BYTE_COUNT: usize = 16 * 1024 * 1024;
+
+/// Bytes read from the socket per read call
+const READ_CHUNK_BYTE_COUNT: usize = 64 * 1024;
+
+/// Unconsumed received bytes buffered per connection; always room for one complete frame
+const MAX_BUFFERED_READ_BYTE_COUNT: usize = FRAME_LENGTH_PREFIX_BYTE_COUNT + MAX_FRAME_BYTE_COUNT;
+
+/// Unsent bytes allowed to pile up before a peer is considered stalled
+const MAX_PENDING_WRITE_BYTE_COUNT: usize = 64 * 1024 * 1024;
+
+/// A non-blocking stream with buffers for partially read and partially written frames.
+pub(crate) struct FramedConnection<S: Read + Write> {
+    stream: S,
+    read_buffer: Vec<u8>,
+    /// Start of the bytes in `read_buffer` not yet handed out as frames
+    read_offset: usize,
+    write_buffer: Vec<u8>,
+    /// Start of the bytes in `write_buffer` not yet accepted by the socket
+    write_offset: usize,
+}
+
+impl<S: Read + Write> FramedConnection<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        Self {
+            stream,
+            read_buffer: Vec::new(),
+            read_offset: 0,
+            write_buffer: Vec::new(),
+            write_offset: 0,
+        }
+    }
+
+    /// Pulls what the socket has ready into the read buffer, stopping once
+    /// [`MAX_BUFFERED_READ_BYTE_COUNT`] unconsumed bytes are held. The rest stays in the
+    /// socket until frames were taken with [`Self::take_frame_into`].
+    ///
+    /// Returns false once the peer closed the connection. Frames received before the
+    /// close can still be taken with [`Self::take_frame_into`].
+    pub(crate) fn fill_read_buffer(&mut self) -> Result<bool, FeagiNetworkError> {
+        loop {
+            let filled = self.read_buffer.len();
+            let unconsumed = filled - self.read_offset;
+            if unconsumed >= MAX_BUFFERED_READ_BYTE_COUNT {
+                return Ok(true);
+            }
+            let chunk = READ_CHUNK_BYTE_COUNT.min(MAX_BUFFERED_READ_BYTE_COUNT - unconsumed);
+            self.read_buffer.resize(filled + chunk, 0);
+            let read_result = self.stream.read(&mut self.read_buffer[filled..]);
+            match read_result {
+                Ok(0) => {
+                    self.read_buffer.truncate(filled);
+                    return Ok(false);
+                }
+                Ok(number_bytes) => {
+                    self.read_buffer.truncate(filled + number_bytes);
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                    self.read_buffer.truncate(filled);
+                    return Ok(true);
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {
+                    self.read_buffer.truncate(filled);
+                }
+                Err(e) => {
+                    self.read_buffer.truncate(filled);
+                    return Err(FeagiNetworkError::ReceiveFailed(e.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Moves the next complete frame into `destination`, replacing its contents.
+    ///
+    /// Returns false if no complete frame has been received yet.
+    pub(crate) fn take_frame_into(
+        &mut self,
+        destination: &mut Vec<u8>,
+    ) -> Result<bool, FeagiNetworkError> {
+        let available = &self.read_buffer[self.read_offset..];
+        if available.len() < FRAME_LENGTH_PREFIX_BYTE_COUNT {
+            return Ok(false);
+        }
+        let frame_length =
+            u32::from_le_bytes([available[0], available[1], available[2], available[3]]) as usize;
+        if frame_length > MAX_FRAME_BYTE_COUNT {
+            return Err(FeagiNetworkError::ReceiveFailed(format!(
+                "Peer announced a frame of {} bytes, over the limit of {}",
+                frame_length, MAX_FRAME_BYTE_COUNT
+            )));
+        }
+        if available.len() < FRAME_LENGTH_PREFIX_BYTE_COUNT + frame_length {
+            return Ok(false);
+        }
+
+        destination.clear();
+        destination.extend_from_slice(
+            &available
+                [FRAME_LENGTH_PREFIX_BYTE_COUNT..FRAME_LENGTH_PREFIX_BYTE_COUNT + frame_length],
+        );
+        self.read_offset += FRAME_LENGTH_PREFIX_BYTE_COUNT + frame_length;
+
+        // Compact so the buffer does not grow with the lifetime of the connection
+        if self.read_offset == self.read_buffer.len() {
+            self.read_buffer.clear();
+            self.read_offset = 0;
+        } else if self.read_offset > self.read_buffer.len() / 2 {
+            self.read_buffer.drain(..self.read_offset);
+            self.read_offset = 0;
+        }
+        Ok(true)
+    }
+
+    /// Returns true if bytes of an earlier frame are still waiting for the socket.
+    pub(crate) fn has_pending_writes(&self) -> bool {
+        self.write_offset < self.write_buffer.len()
+    }
+
+    /// Queues a frame and writes as much of the outgoing buffer as the socket accepts.
+    pub(crate) fn send_frame(&mut self, payload: &[u8]) -> Result<(), FeagiNetworkError> {
+        if payload.len() > MAX_FRAME_BYTE_COUNT {
+            return Err(FeagiNetworkError::SendFailed(format!(
+                "Frame of {} bytes exceeds the limit of {}",
+                payload.len(),
+                MAX_FRAME_BYTE_COUNT
+            )));
+        }
+        let pending = self.write_buffer.len() - self.write_offset;
+        if pending + FRAME_LENGTH_PREFIX_BYTE_COUNT + payload.len() > MAX_PENDING_WRITE_BYTE_COUNT {
+            return Err(FeagiNetworkError::SendFailed(format!(
+                "Peer is not reading; {} bytes are still unsent",
+                pending
+            )));
+        }
+
+        self.write_buffer
+            .extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.write_buffer.extend_from_slice(payload);
+        self.flush_pending_writes()
+    }
+
+    /// Writes queued bytes until the socket would block.
+    pub(crate) fn flush_pending_writes(&mut self) -> Result<(), FeagiNetworkError> {
+        while self.write_offset < self.write_buffer.len() {
+            match self.stream.write(&self.write_buffer[self.write_offset..]) {
+                Ok(0) => {
+                    return Err(FeagiNetworkError::SendFailed(
+                        "Connection closed".to_string(),
+                    ));
+                }
+                Ok(number_bytes) => self.write_offset += number_bytes,
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(FeagiNetworkError::SendFailed(e.to_string())),
+            }
+        }
+        self.write_buffer.clear();
+        self.write_offset = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A peer that always has another frame ready and never closes
+    struct FloodingStream {
+        frame: Vec<u8>,
+        position: usize,
+    }
+
+    impl Read for FloodingStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            for byte in buf.iter_mut() {
+                *byte = self.frame[self.position];
+                self.position = (self.position + 1) % self.frame.len();
+            }
+            Ok(buf.len())
+        }
+    }
+
+    impl Write for FloodingStream {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fill_read_buffer_is_bounded_for_flooding_peer() {
+        let payload = [7u8; 1000];
+        let mut frame = (payload.len() as u32).to_le_bytes().to_vec();
+        frame.extend_from_slice(&payload);
+        let mut connection = FramedConnection::new(FloodingStream { frame, position: 0 });
+
+        assert!(connection.fill_read_buffer().unwrap());
+        assert!(connection.read_buffer.len() <= MAX_BUFFERED_READ_BYTE_COUNT);
+
+        let mut received = Vec::new();
+        assert!(connection.take_frame_into(&mut received).unwrap());
+        assert_eq!(received, payload);
+
+        // Taking frames makes room for more without exceeding the bound
+        assert!(connection.fill_read_buffer().unwrap());
+        assert!(
+            connection.read_buffer.len() - connection.read_offset <= MAX_BUFFERED_READ_BYTE_COUNT
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Length-prefixed framing over plain byte stream sockets.
+//!
+//! This module provides the FEAGI networking traits on top of any connection oriented
+//! byte stream (TCP, Unix domain sockets). Every message is sent as a frame:
+//!
+//! - Payload length (u32, little endian)
+//! - Payload bytes
+//!
+//! All sockets are put in non-blocking mode and every operation checks for `WouldBlock`,
+//! making the implementations compatible with any async runtime or synchronous usage.
+//! Concrete transports only describe how to bind, accept and connect through
+//! [`FramedStreamTransport`]; see the `tcp` and `unix_socket` modules for the public types.
+//!
+//! # Socket Patterns
+//!
+//! | Server | Client | Pattern |
+//! |--------|--------|---------|
+//! | [`FeagiFramedServerPublisher`] | [`FeagiFramedClientSubscriber`] | Pub/Sub (broadcast) |
+//! | [`FeagiFramedServerPuller`] | [`FeagiFramedClientPusher`] | Push/Pull (pipeline) |
+//! | [`FeagiFramedServerRouter`] | [`FeagiFramedClientRequester`] | Router/Dealer (req/rep) |
+
+use std::fmt::Debug;
+use std::io::{Read, Write};
+
+use crate::traits_and_enums::shared::{TransportProtocolEndpoint, TransportProtocolImplementation};
+use crate::FeagiNetworkError;
+
+mod framing;
+
+#[cfg(feature = "feagi-server")]
+mod server_implementations;
+
+#[cfg(feature = "feagi-client")]
+mod client_implementations;
+
+pub use framing::MAX_FRAME_BYTE_COUNT;
+
+#[cfg(feature = "feagi-server")]
+// Server implementations and properties
+pub use server_implementations::{
+    FeagiFramedServerPublisher, FeagiFramedServerPublisherProperties, FeagiFramedServerPuller,
+    FeagiFramedServerPullerProperties, FeagiFramedServerRouter, FeagiFramedServerRouterProperties,
+};
+
+#[cfg(feature = "feagi-client")]
+// Client implementations and properties
+pub use client_implementations::{
+    FeagiFramedClientPusher, FeagiFramedClientPusherProperties, FeagiFramedClientRequester,
+    FeagiFramedClientRequesterProperties, FeagiFramedClientSubscriber,
+    FeagiFramedClientSubscriberProperties,
+};
+
+/// Describes a connection oriented byte stream transport the framed endpoints can run on.
+///
+/// Implementations are stateless markers; all state lives in the endpoints.
+pub trait FramedStreamTransport: Debug + Clone + PartialEq + Send + Sync + 'static {
+    /// Validated address of a bind point or server
+    type Address: Debug + Clone + PartialEq + Send + Sync + 'static;
+    /// Listening socket of a server
+    type Listener: Send + 'static;
+    /// Connected socket, in non-blocking mode
+    type Stream: Read + Write + Send + 'static;
+
+    /// Protocol reported by servers using this transport
+    const PROTOCOL: TransportProtocolImplementation;
+
+    /// Parses and validates a user supplied address.
+    fn parse_address(address: &str) -> Result<Self::Address, FeagiNetworkError>;
+
+    /// Wraps an address in the matching endpoint variant.
+    fn to_endpoint(address: &Self::Address) -> TransportProtocolEndpoint;
+
+    /// Binds a non-blocking listener to the given address.
+    fn bind(address: &Self::Address) -> Result<Self::Listener, FeagiNetworkError>;
+
+    /// Accepts a pending connection, returning `WouldBlock` if there is none.
+    ///
+    /// The returned stream must already be in non-blocking mode.
+    fn accept(listener: &Self::Listener) -> std::io::Result<Self::Stream>;
+
+    /// Connects to a server, returning a stream in non-blocking mode.
+    fn connect(address: &Self::Address) -> Result<Self::Stream, FeagiNetworkError>;
+
+    /// Releases any resources left behind by a listener once it is closed.
+    fn release(_address: &Self::Address) {}
+}
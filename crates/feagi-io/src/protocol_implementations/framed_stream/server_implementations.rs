@@ -0,0 +1,705 @@
+//! Framed stream server implementations using the poll-based trait design.
+//!
+//! Listeners and connections are non-blocking; `poll()` accepts new clients and
+//! moves any complete frames out of the socket buffers.
+
+use std::io::ErrorKind;
+
+use crate::protocol_implementations::feagi_frame::{
+    has_non_empty_payload, is_plausible_feagi_frame, try_extract_agent_id_from_payload,
+};
+use crate::protocol_implementations::framed_stream::framing::FramedConnection;
+use crate::protocol_implementations::framed_stream::FramedStreamTransport;
+use crate::traits_and_enums::server::{
+    FeagiServer, FeagiServerPublisher, FeagiServerPublisherProperties, FeagiServerPuller,
+    FeagiServerPullerProperties, FeagiServerRouter, FeagiServerRouterProperties,
+};
+use crate::traits_and_enums::shared::{
+    FeagiEndpointState, TransportProtocolEndpoint, TransportProtocolImplementation,
+};
+use crate::{AgentID, FeagiNetworkError};
+
+/// Accept any pending connections (non-blocking).
+fn accept_pending_streams<T: FramedStreamTransport>(
+    listener: &Option<T::Listener>,
+) -> Vec<T::Stream> {
+    let mut accepted = Vec::new();
+    let listener = match listener {
+        Some(l) => l,
+        None => return accepted,
+    };
+
+    loop {
+        match T::accept(listener) {
+            Ok(stream) => accepted.push(stream),
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => break,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        }
+    }
+    accepted
+}
+
+// ============================================================================
+// Publisher
+// ============================================================================
+
+//region Publisher Properties
+
+/// Configuration properties for creating a framed stream publisher server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedServerPublisherProperties<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerPublisherProperties<T> {
+    /// Creates new publisher properties with explicit local/remote endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_bind_address` - The address the server binds to.
+    /// * `remote_bind_address` - The address agents are told to connect to.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: T::parse_address(local_bind_address)?,
+            remote_bind_address: T::parse_address(remote_bind_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerPublisherProperties
+    for FeagiFramedServerPublisherProperties<T>
+{
+    fn as_boxed_server_publisher(&self) -> Box<dyn FeagiServerPublisher> {
+        Box::new(FeagiFramedServerPublisher::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            listener: None,
+            clients: Vec::new(),
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+//endregion
+
+//region Publisher Implementation
+
+/// A framed stream server that broadcasts data to all connected clients.
+///
+/// A client that has not yet taken the previous frame off its socket skips newer
+/// frames instead of stalling the others.
+pub struct FeagiFramedServerPublisher<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+    current_state: FeagiEndpointState,
+    listener: Option<T::Listener>,
+    clients: Vec<FramedConnection<T::Stream>>,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerPublisher<T> {
+    /// Get the number of connected clients.
+    pub fn client_count(&self) -> usize {
+        self.clients.len()
+    }
+
+    fn close_all(&mut self) {
+        self.clients.clear();
+        if self.listener.take().is_some() {
+            T::release(&self.local_bind_address);
+        }
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServer for FeagiFramedServerPublisher<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) {
+            for stream in accept_pending_streams::<T>(&self.listener) {
+                self.clients.push(FramedConnection::new(stream));
+            }
+            // Keep partially sent frames moving even when nothing new is published
+            self.clients
+                .retain_mut(|client| client.flush_pending_writes().is_ok());
+        }
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                self.listener = Some(T::bind(&self.local_bind_address)?);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerPublisher for FeagiFramedServerPublisher<T> {
+    fn publish_data(&mut self, data: &[u8]) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                // Send to all clients, dropping the ones whose connection failed
+                self.clients.retain_mut(|client| {
+                    if client.flush_pending_writes().is_err() {
+                        return false;
+                    }
+                    if client.has_pending_writes() {
+                        // Slow subscriber - skip this frame rather than queue stale data
+                        return true;
+                    }
+                    client.send_frame(data).is_ok()
+                });
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot publish: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_publisher_properties(&self) -> Box<dyn FeagiServerPublisherProperties> {
+        Box::new(FeagiFramedServerPublisherProperties::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Puller
+// ============================================================================
+
+//region Puller Properties
+
+/// Configuration properties for creating a framed stream puller server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedServerPullerProperties<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerPullerProperties<T> {
+    /// Creates new puller properties with explicit local/remote endpoints.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: T::parse_address(local_bind_address)?,
+            remote_bind_address: T::parse_address(remote_bind_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerPullerProperties
+    for FeagiFramedServerPullerProperties<T>
+{
+    fn as_boxed_server_puller(&self) -> Box<dyn FeagiServerPuller> {
+        Box::new(FeagiFramedServerPuller::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            listener: None,
+            clients: Vec::new(),
+            frame_buffer: Vec::new(),
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+//endregion
+
+//region Puller Implementation
+
+/// A framed stream server that receives pushed data from clients.
+///
+/// Like the ZMQ puller, each poll drains everything queued and keeps only the latest
+/// non-empty FEAGI frame, so a reconnecting agent never replays a stale backlog.
+pub struct FeagiFramedServerPuller<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+    current_state: FeagiEndpointState,
+    listener: Option<T::Listener>,
+    clients: Vec<FramedConnection<T::Stream>>,
+    /// Scratch buffer frames are read into while draining
+    frame_buffer: Vec<u8>,
+    /// Latest usable frame, handed out by `consume_retrieved_data`
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerPuller<T> {
+    /// Drain all currently-queued frames of every client and keep only the latest payload.
+    fn try_receive_latest(&mut self) -> bool {
+        let mut has_latest_non_empty_valid = false;
+        let frame_buffer = &mut self.frame_buffer;
+        let receive_buffer = &mut self.receive_buffer;
+
+        self.clients.retain_mut(|client| {
+            let still_open = match client.fill_read_buffer() {
+                Ok(still_open) => still_open,
+                Err(_) => return false,
+            };
+            loop {
+                match client.take_frame_into(frame_buffer) {
+                    Ok(true) => {
+                        if is_plausible_feagi_frame(frame_buffer)
+                            && has_non_empty_payload(frame_buffer)
+                        {
+                            std::mem::swap(frame_buffer, receive_buffer);
+                            has_latest_non_empty_valid = true;
+                        }
+                    }
+                    Ok(false) => break,
+                    // Corrupt framing cannot be resynchronized; drop the client
+                    Err(_) => return false,
+                }
+            }
+            still_open
+        });
+
+        has_latest_non_empty_valid
+    }
+
+    fn close_all(&mut self) {
+        self.clients.clear();
+        if self.listener.take().is_some() {
+            T::release(&self.local_bind_address);
+        }
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServer for FeagiFramedServerPuller<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && !self.has_data {
+            for stream in accept_pending_streams::<T>(&self.listener) {
+                self.clients.push(FramedConnection::new(stream));
+            }
+
+            if self.try_receive_latest() {
+                self.has_data = true;
+                self.current_state = FeagiEndpointState::ActiveHasData;
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                self.listener = Some(T::bind(&self.local_bind_address)?);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerPuller for FeagiFramedServerPuller<T> {
+    fn consume_retrieved_data(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(self.receive_buffer.as_slice())
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no data available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_puller_properties(&self) -> Box<dyn FeagiServerPullerProperties> {
+        Box::new(FeagiFramedServerPullerProperties::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Router
+// ============================================================================
+
+//region Router Properties
+
+/// Configuration properties for creating a framed stream router server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiFramedServerRouterProperties<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerRouterProperties<T> {
+    /// Creates new router properties with explicit local/remote endpoints.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: T::parse_address(local_bind_address)?,
+            remote_bind_address: T::parse_address(remote_bind_address)?,
+        })
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerRouterProperties
+    for FeagiFramedServerRouterProperties<T>
+{
+    fn as_boxed_server_router(&self) -> Box<dyn FeagiServerRouter> {
+        Box::new(FeagiFramedServerRouter::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            listener: None,
+            clients: Vec::new(),
+            next_client_index: 0,
+            receive_buffer: Vec::new(),
+            current_session: None,
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+//endregion
+
+//region Router Implementation
+
+/// A connected router client and the session its responses are routed by.
+struct RouterClient<S: std::io::Read + std::io::Write> {
+    connection: FramedConnection<S>,
+    session_id: AgentID,
+}
+
+/// A framed stream server that handles request-response communication with multiple clients.
+///
+/// Every connection starts with a random session ID, which follows the agent ID
+/// found in the header of the requests it sends.
+pub struct FeagiFramedServerRouter<T: FramedStreamTransport> {
+    local_bind_address: T::Address,
+    remote_bind_address: T::Address,
+    current_state: FeagiEndpointState,
+    listener: Option<T::Listener>,
+    clients: Vec<RouterClient<T::Stream>>,
+    /// Client to check first on the next poll, so busy clients cannot starve the others
+    next_client_index: usize,
+    /// Buffer for received request
+    receive_buffer: Vec<u8>,
+    /// Session ID of the client that sent the current request
+    current_session: Option<AgentID>,
+}
+
+impl<T: FramedStreamTransport> FeagiFramedServerRouter<T> {
+    fn accept_pending_connections(&mut self) {
+        for stream in accept_pending_streams::<T>(&self.listener) {
+            self.clients.push(RouterClient {
+                connection: FramedConnection::new(stream),
+                session_id: AgentID::new_random(),
+            });
+        }
+    }
+
+    fn align_session_with_payload_agent_id(&mut self, client_index: usize) {
+        let Some(payload_agent_id) = try_extract_agent_id_from_payload(&self.receive_buffer) else {
+            return;
+        };
+        if self.clients[client_index].session_id == payload_agent_id {
+            return;
+        }
+        // A reconnecting agent takes its session over from the stale connection
+        for client in self.clients.iter_mut() {
+            if client.session_id == payload_agent_id {
+                client.session_id = AgentID::new_random();
+            }
+        }
+        self.clients[client_index].session_id = payload_agent_id;
+    }
+
+    /// Takes one complete request from the next client that has one.
+    fn try_receive(&mut self) -> bool {
+        let client_count = self.clients.len();
+        let mut received_from: Option<usize> = None;
+        let mut failed_indices = Vec::new();
+
+        for offset in 0..client_count {
+            let i = (self.next_client_index + offset) % client_count;
+            let client = &mut self.clients[i];
+            if client.connection.flush_pending_writes().is_err() {
+                failed_indices.push(i);
+                continue;
+            }
+            let still_open = match client.connection.fill_read_buffer() {
+                Ok(still_open) => still_open,
+                Err(_) => {
+                    failed_indices.push(i);
+                    continue;
+                }
+            };
+            match client.connection.take_frame_into(&mut self.receive_buffer) {
+                Ok(true) => {
+                    received_from = Some(i);
+                    break;
+                }
+                Ok(false) if still_open => {}
+                _ => failed_indices.push(i),
+            }
+        }
+
+        if let Some(i) = received_from {
+            self.align_session_with_payload_agent_id(i);
+            self.current_session = Some(self.clients[i].session_id);
+            self.next_client_index = i + 1;
+        }
+
+        // Remove failed clients (in reverse order to preserve indices)
+        failed_indices.sort_unstable();
+        for i in failed_indices.into_iter().rev() {
+            self.clients.remove(i);
+        }
+
+        received_from.is_some()
+    }
+
+    fn close_all(&mut self) {
+        self.clients.clear();
+        if self.listener.take().is_some() {
+            T::release(&self.local_bind_address);
+        }
+        self.current_session = None;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServer for FeagiFramedServerRouter<T> {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) {
+            self.accept_pending_connections();
+
+            if self.try_receive() {
+                self.current_state = FeagiEndpointState::ActiveHasData;
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                self.listener = Some(T::bind(&self.local_bind_address)?);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.local_bind_address)
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        T::to_endpoint(&self.remote_bind_address)
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        T::PROTOCOL
+    }
+}
+
+impl<T: FramedStreamTransport> FeagiServerRouter for FeagiFramedServerRouter<T> {
+    fn consume_retrieved_request(&mut self) -> Result<(AgentID, &[u8]), FeagiNetworkError> {
+        match (&self.current_state, self.current_session) {
+            (FeagiEndpointState::ActiveHasData, Some(session_id)) => {
+                self.current_session = None;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok((session_id, self.receive_buffer.as_slice()))
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no request available".to_string(),
+            )),
+        }
+    }
+
+    fn publish_response(
+        &mut self,
+        session_id: AgentID,
+        message: &[u8],
+    ) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                let client = self
+                    .clients
+                    .iter_mut()
+                    .find(|client| client.session_id == session_id)
+                    .ok_or_else(|| {
+                        FeagiNetworkError::SendFailed(format!("Unknown session: {:?}", session_id))
+                    })?;
+                client.connection.send_frame(message)
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot send response: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_router_properties(&self) -> Box<dyn FeagiServerRouterProperties> {
+        Box::new(FeagiFramedServerRouterProperties::<T> {
+            local_bind_address: self.local_bind_address.clone(),
+            remote_bind_address: self.remote_bind_address.clone(),
+        })
+    }
+}
+
+//endregion
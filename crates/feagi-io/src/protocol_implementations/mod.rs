@@ -7,3 +7,25 @@ pub mod websocket;
 
 #[cfg(feature = "zmq-transport")]
 pub mod zmq;
+
+#[cfg(any(feature = "tcp-transport", all(feature = "uds-transport", unix)))]
+pub mod framed_stream;
+
+#[cfg(feature = "tcp-transport")]
+pub mod tcp;
+
+#[cfg(all(feature = "uds-transport", unix))]
+pub mod unix_socket;
+
+#[cfg(feature = "shm-transport")]
+pub mod shared_memory;
+
+#[cfg(any(
+    feature = "zmq-transport",
+    feature = "websocket-transport-std",
+    feature = "tcp-transport",
+    all(feature = "uds-transport", unix),
+    feature = "shm-transport"
+))]
+#[allow(dead_code)] // Not every transport uses every helper
+pub(crate) mod feagi_frame;
@@ -0,0 +1,478 @@
+//! Shared memory client implementations using the poll-based trait design.
+//!
+//! `request_connect()` maps the rings of a running server and fails if there are none.
+//! A client notices its server stopping through the ring's open flag and reports it as
+//! an error on the next poll.
+
+use crate::protocol_implementations::shared_memory::ring::ShmRing;
+use crate::protocol_implementations::shared_memory::{
+    SharedMemoryUrl, ROUTER_REQUESTS_SUFFIX, ROUTER_RESPONSES_SUFFIX,
+};
+use crate::traits_and_enums::client::{
+    FeagiClient, FeagiClientPusher, FeagiClientPusherProperties, FeagiClientRequester,
+    FeagiClientRequesterProperties, FeagiClientSubscriber, FeagiClientSubscriberProperties,
+};
+use crate::traits_and_enums::shared::{FeagiEndpointState, TransportProtocolEndpoint};
+use crate::{AgentID, FeagiNetworkError};
+
+fn server_closed_error() -> FeagiNetworkError {
+    FeagiNetworkError::ReceiveFailed("Shared memory server closed".to_string())
+}
+
+// ============================================================================
+// Subscriber
+// ============================================================================
+
+//region Subscriber Properties
+
+/// Configuration properties for creating a shared memory subscriber client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryClientSubscriberProperties {
+    server_address: SharedMemoryUrl,
+}
+
+impl FeagiSharedMemoryClientSubscriberProperties {
+    /// Creates new subscriber properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: SharedMemoryUrl::new(server_address)?,
+        })
+    }
+}
+
+impl FeagiClientSubscriberProperties for FeagiSharedMemoryClientSubscriberProperties {
+    fn as_boxed_client_subscriber(&self) -> Box<dyn FeagiClientSubscriber> {
+        Box::new(FeagiSharedMemoryClientSubscriber {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            ring: None,
+            cursor: 0,
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+//endregion
+
+//region Subscriber Implementation
+
+/// A shared memory client that reads every frame a publisher writes after it connected.
+pub struct FeagiSharedMemoryClientSubscriber {
+    server_address: SharedMemoryUrl,
+    current_state: FeagiEndpointState,
+    ring: Option<ShmRing>,
+    /// Sequence number of the next frame to read
+    cursor: u64,
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl FeagiSharedMemoryClientSubscriber {
+    fn close(&mut self) {
+        self.ring = None;
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiClient for FeagiSharedMemoryClientSubscriber {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && !self.has_data {
+            if let Some(ring) = &self.ring {
+                if ring.read_frame_into(&mut self.cursor, &mut self.receive_buffer) {
+                    self.has_data = true;
+                    self.current_state = FeagiEndpointState::ActiveHasData;
+                } else if !ring.is_open() {
+                    self.current_state = FeagiEndpointState::Errored(server_closed_error());
+                }
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let ring = ShmRing::open(self.server_address.path())?;
+                self.cursor = ring.next_sequence();
+                self.ring = Some(ring);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+impl FeagiClientSubscriber for FeagiSharedMemoryClientSubscriber {
+    fn consume_retrieved_data(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(self.receive_buffer.as_slice())
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no data available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_subscriber_properties(&self) -> Box<dyn FeagiClientSubscriberProperties> {
+        Box::new(FeagiSharedMemoryClientSubscriberProperties {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Pusher
+// ============================================================================
+
+//region Pusher Properties
+
+/// Configuration properties for creating a shared memory pusher client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryClientPusherProperties {
+    server_address: SharedMemoryUrl,
+}
+
+impl FeagiSharedMemoryClientPusherProperties {
+    /// Creates new pusher properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: SharedMemoryUrl::new(server_address)?,
+        })
+    }
+}
+
+impl FeagiClientPusherProperties for FeagiSharedMemoryClientPusherProperties {
+    fn as_boxed_client_pusher(&self) -> Box<dyn FeagiClientPusher> {
+        Box::new(FeagiSharedMemoryClientPusher {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            ring: None,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+//endregion
+
+//region Pusher Implementation
+
+/// A shared memory client that writes frames straight into a puller's ring.
+pub struct FeagiSharedMemoryClientPusher {
+    server_address: SharedMemoryUrl,
+    current_state: FeagiEndpointState,
+    ring: Option<ShmRing>,
+}
+
+impl FeagiSharedMemoryClientPusher {
+    fn close(&mut self) {
+        self.ring = None;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiClient for FeagiSharedMemoryClientPusher {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        // Pusher doesn't receive data, but still notices the server going away
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) {
+            if let Some(ring) = &self.ring {
+                if !ring.is_open() {
+                    self.current_state = FeagiEndpointState::Errored(server_closed_error());
+                }
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                self.ring = Some(ShmRing::open(self.server_address.path())?);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+impl FeagiClientPusher for FeagiSharedMemoryClientPusher {
+    fn publish_data(&mut self, data: &[u8]) -> Result<(), FeagiNetworkError> {
+        match (&self.current_state, &self.ring) {
+            (FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData, Some(ring)) => {
+                ring.write_frame(&[data])
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot publish: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_pusher_properties(&self) -> Box<dyn FeagiClientPusherProperties> {
+        Box::new(FeagiSharedMemoryClientPusherProperties {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Requester
+// ============================================================================
+
+//region Requester Properties
+
+/// Configuration properties for creating a shared memory requester client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryClientRequesterProperties {
+    server_address: SharedMemoryUrl,
+}
+
+impl FeagiSharedMemoryClientRequesterProperties {
+    /// Creates new requester properties with the given server address.
+    pub fn new(server_address: &str) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            server_address: SharedMemoryUrl::new(server_address)?,
+        })
+    }
+}
+
+impl FeagiClientRequesterProperties for FeagiSharedMemoryClientRequesterProperties {
+    fn as_boxed_client_requester(&self) -> Box<dyn FeagiClientRequester> {
+        Box::new(FeagiSharedMemoryClientRequester {
+            server_address: self.server_address.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            request_ring: None,
+            response_ring: None,
+            mailbox: AgentID::new_random(),
+            cursor: 0,
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+//endregion
+
+//region Requester Implementation
+
+/// A shared memory client that sends requests and receives responses.
+///
+/// Requests are tagged with a mailbox ID that is new for every connection; responses
+/// for other mailboxes in the shared response ring are skipped.
+pub struct FeagiSharedMemoryClientRequester {
+    server_address: SharedMemoryUrl,
+    current_state: FeagiEndpointState,
+    request_ring: Option<ShmRing>,
+    response_ring: Option<ShmRing>,
+    mailbox: AgentID,
+    /// Sequence number of the next response to read
+    cursor: u64,
+    /// Buffer for received response, including the mailbox prefix
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl FeagiSharedMemoryClientRequester {
+    /// Moves the next response addressed to this client into the receive buffer.
+    fn try_receive(&mut self) -> bool {
+        let Some(ring) = &self.response_ring else {
+            return false;
+        };
+        while ring.read_frame_into(&mut self.cursor, &mut self.receive_buffer) {
+            if self.receive_buffer.starts_with(self.mailbox.bytes()) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn close(&mut self) {
+        self.request_ring = None;
+        self.response_ring = None;
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiClient for FeagiSharedMemoryClientRequester {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && !self.has_data {
+            if self.try_receive() {
+                self.has_data = true;
+                self.current_state = FeagiEndpointState::ActiveHasData;
+            } else if self
+                .response_ring
+                .as_ref()
+                .is_some_and(|ring| !ring.is_open())
+            {
+                self.current_state = FeagiEndpointState::Errored(server_closed_error());
+            }
+        }
+        &self.current_state
+    }
+
+    fn request_connect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let request_ring =
+                    ShmRing::open(&self.server_address.path_with_suffix(ROUTER_REQUESTS_SUFFIX))?;
+                let response_ring = ShmRing::open(
+                    &self
+                        .server_address
+                        .path_with_suffix(ROUTER_RESPONSES_SUFFIX),
+                )?;
+                self.mailbox = AgentID::new_random();
+                self.cursor = response_ring.next_sequence();
+                self.request_ring = Some(request_ring);
+                self.response_ring = Some(response_ring);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot connect: client is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_disconnect(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot disconnect: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: client is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_endpoint_target(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.server_address.clone())
+    }
+}
+
+impl FeagiClientRequester for FeagiSharedMemoryClientRequester {
+    fn publish_request(&mut self, request: &[u8]) -> Result<(), FeagiNetworkError> {
+        match (&self.current_state, &self.request_ring) {
+            (FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData, Some(ring)) => {
+                ring.write_frame(&[self.mailbox.bytes(), request])
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot send request: client is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn consume_retrieved_response(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(&self.receive_buffer[AgentID::NUMBER_BYTES..])
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no response available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_requester_properties(&self) -> Box<dyn FeagiClientRequesterProperties> {
+        Box::new(FeagiSharedMemoryClientRequesterProperties {
+            server_address: self.server_address.clone(),
+        })
+    }
+}
+
+//endregion
@@ -0,0 +1,70 @@
+//! Shared memory transport implementations.
+//!
+//! This module provides the FEAGI networking traits over memory mapped ring files, for
+//! agents running on the same host as FEAGI. Frames are copied once into the ring by the
+//! writer and once out of it by each reader; no socket or kernel buffer is involved.
+//! Point the endpoints at a tmpfs path (such as `/dev/shm` on Linux) to keep the rings
+//! out of the page cache writeback.
+//!
+//! Rings are lock-free: any number of writers claim slots with an atomic counter and
+//! every reader follows the ring at its own pace, skipping frames it fell a full ring
+//! behind on. A router uses two rings, `<path>.requests` and `<path>.responses`.
+//!
+//! # Socket Patterns
+//!
+//! | Server | Client | Pattern |
+//! |--------|--------|---------|
+//! | [`FeagiSharedMemoryServerPublisher`] | [`FeagiSharedMemoryClientSubscriber`] | Pub/Sub (broadcast) |
+//! | [`FeagiSharedMemoryServerPuller`] | [`FeagiSharedMemoryClientPusher`] | Push/Pull (pipeline) |
+//! | [`FeagiSharedMemoryServerRouter`] | [`FeagiSharedMemoryClientRequester`] | Router/Dealer (req/rep) |
+//!
+//! # Creating Instances
+//!
+//! All server and client instances are created through their Properties types:
+//!
+//! ```ignore
+//! // Server example
+//! let props = FeagiSharedMemoryServerPublisherProperties::new(
+//!     "shm:///dev/shm/feagi_viz",
+//!     "shm:///dev/shm/feagi_viz",
+//! )?;
+//! let mut server = props.as_boxed_server_publisher();
+//!
+//! // Client example
+//! let props = FeagiSharedMemoryClientSubscriberProperties::new("shm:///dev/shm/feagi_viz")?;
+//! let mut client = props.as_boxed_client_subscriber();
+//! ```
+
+mod ring;
+mod shared;
+
+#[cfg(feature = "feagi-server")]
+mod server_implementations;
+
+#[cfg(feature = "feagi-client")]
+mod client_implementations;
+
+pub use ring::{DEFAULT_MAX_FRAME_BYTE_COUNT, DEFAULT_SLOT_COUNT};
+pub use shared::SharedMemoryUrl;
+
+#[cfg(feature = "feagi-server")]
+// Server implementations and properties
+pub use server_implementations::{
+    FeagiSharedMemoryServerPublisher, FeagiSharedMemoryServerPublisherProperties,
+    FeagiSharedMemoryServerPuller, FeagiSharedMemoryServerPullerProperties,
+    FeagiSharedMemoryServerRouter, FeagiSharedMemoryServerRouterProperties,
+};
+
+#[cfg(feature = "feagi-client")]
+// Client implementations and properties
+pub use client_implementations::{
+    FeagiSharedMemoryClientPusher, FeagiSharedMemoryClientPusherProperties,
+    FeagiSharedMemoryClientRequester, FeagiSharedMemoryClientRequesterProperties,
+    FeagiSharedMemoryClientSubscriber, FeagiSharedMemoryClientSubscriberProperties,
+};
+
+/// Suffix of the ring a router reads requests from
+const ROUTER_REQUESTS_SUFFIX: &str = ".requests";
+
+/// Suffix of the ring a router writes responses to
+const ROUTER_RESPONSES_SUFFIX: &str = ".responses";
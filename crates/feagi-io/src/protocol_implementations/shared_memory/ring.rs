@@ -0,0 +1,558 @@
+//! Lock-free ring of fixed size frame slots in a memory mapped file.
+//!
+//! # Layout
+//!
+//! Both sides of a ring run on the same host, so integers use the host byte order.
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 8 | Magic `FEAGIRNG` |
+//! | 8 | 4 | Version |
+//! | 12 | 4 | Slot count |
+//! | 16 | 4 | Largest frame a slot holds, in bytes |
+//! | 20 | 4 | Open flag, set while the creating server is running |
+//! | 24 | 8 | Next sequence number to be claimed by a writer |
+//! | 32 | 4 | Process ID of the creating server |
+//! | 36 | 28 | Reserved |
+//!
+//! Each slot is a 16 byte header (commit stamp u64, frame length u32, reserved u32)
+//! followed by the frame bytes, padded to a multiple of 8 bytes.
+//!
+//! Writers claim a sequence number with an atomic increment, so any number of processes
+//! can write at once. A slot's stamp is `2 * sequence + 1` while its frame is being
+//! written and `2 * sequence + 2` once it is complete. Readers keep their own cursor,
+//! compare the stamp before and after copying a frame out, and skip frames that were
+//! overwritten in between, so a slow reader never holds up a writer.
+//!
+//! Writers a lap apart map to the same slot, so a writer also claims the slot itself by
+//! swapping in its stamp. A writer whose slot was already claimed by a later sequence
+//! drops its frame, which readers would skip anyway, and a writer that finds an earlier
+//! frame still being written waits briefly for it and otherwise fails the send, so two
+//! frames are never written into one slot at once.
+//!
+//! Ring files are created with owner-only permissions and never replace a ring whose
+//! server is still running. A ring left behind by a server that died without removing it
+//! is reclaimed by the next server created on the same path; clients still mapping the
+//! old ring keep the removed file until they reconnect. Server processes are identified
+//! by PID, so servers sharing ring paths must share a PID namespace.
+
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{fence, AtomicU32, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use memmap2::MmapMut;
+
+use crate::FeagiNetworkError;
+
+const RING_MAGIC: &[u8; 8] = b"FEAGIRNG";
+const RING_VERSION: u32 = 1;
+const HEADER_BYTE_COUNT: usize = 64;
+const SLOT_HEADER_BYTE_COUNT: usize = 16;
+
+const VERSION_OFFSET: usize = 8;
+const SLOT_COUNT_OFFSET: usize = 12;
+const MAX_FRAME_BYTE_COUNT_OFFSET: usize = 16;
+const OPEN_FLAG_OFFSET: usize = 20;
+const NEXT_SEQUENCE_OFFSET: usize = 24;
+const OWNER_PID_OFFSET: usize = 32;
+
+/// Slot offset of the frame length, following the commit stamp
+const SLOT_FRAME_LENGTH_OFFSET: usize = 8;
+
+/// How long a writer waits for the writer a lap behind it to finish the same slot
+const SLOT_CLAIM_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Number of slots of a ring created without an explicit size
+pub const DEFAULT_SLOT_COUNT: u32 = 16;
+
+/// Largest frame a ring created without an explicit size accepts
+pub const DEFAULT_MAX_FRAME_BYTE_COUNT: u32 = 1024 * 1024;
+
+/// A ring mapped into this process, either created by a server or opened by a client.
+pub(crate) struct ShmRing {
+    path: PathBuf,
+    /// Keeps the mapping alive; all access goes through `base`
+    _mmap: MmapMut,
+    base: *mut u8,
+    slot_count: u64,
+    max_frame_byte_count: usize,
+    slot_stride: usize,
+}
+
+// SAFETY: The mapping is owned by the ring and is only touched through the atomic header
+// fields and the stamp protocol, which already has to tolerate writers in other processes.
+unsafe impl Send for ShmRing {}
+
+impl ShmRing {
+    /// Creates a fresh ring file at `path`, readable and writable by the owner only, and
+    /// marks it open.
+    ///
+    /// Fails if anything already exists at `path`, so a ring served by another process is
+    /// never taken over, unless it is a ring whose server process is no longer running.
+    pub(crate) fn create(
+        path: &Path,
+        slot_count: u32,
+        max_frame_byte_count: u32,
+    ) -> Result<Self, FeagiNetworkError> {
+        if slot_count == 0 || max_frame_byte_count == 0 {
+            return Err(FeagiNetworkError::InvalidSocketProperties(
+                "Shared memory ring needs at least one slot of at least one byte".to_string(),
+            ));
+        }
+        let bind_error = |e: std::io::Error| {
+            FeagiNetworkError::CannotBind(format!(
+                "Cannot create shared memory ring '{}': {}",
+                path.display(),
+                e
+            ))
+        };
+
+        let file = match create_ring_file(path) {
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists && Self::is_stale(path) => {
+                std::fs::remove_file(path).map_err(|e| {
+                    FeagiNetworkError::CannotBind(format!(
+                        "Cannot remove stale shared memory ring '{}': {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                create_ring_file(path)
+            }
+            result => result,
+        }
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::AlreadyExists {
+                FeagiNetworkError::CannotBind(format!(
+                    "Shared memory ring '{}' already exists; another server may be using it",
+                    path.display()
+                ))
+            } else {
+                bind_error(e)
+            }
+        })?;
+        let total_byte_count = HEADER_BYTE_COUNT
+            + slot_count as usize * slot_stride_for(max_frame_byte_count as usize);
+        file.set_len(total_byte_count as u64).map_err(bind_error)?;
+        // SAFETY: The file was just created by us and is sized before mapping.
+        let mut mmap = unsafe { MmapMut::map_mut(&file) }.map_err(bind_error)?;
+
+        mmap[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&RING_VERSION.to_ne_bytes());
+        mmap[SLOT_COUNT_OFFSET..SLOT_COUNT_OFFSET + 4].copy_from_slice(&slot_count.to_ne_bytes());
+        mmap[MAX_FRAME_BYTE_COUNT_OFFSET..MAX_FRAME_BYTE_COUNT_OFFSET + 4]
+            .copy_from_slice(&max_frame_byte_count.to_ne_bytes());
+        mmap[OWNER_PID_OFFSET..OWNER_PID_OFFSET + 4]
+            .copy_from_slice(&std::process::id().to_ne_bytes());
+        mmap[..RING_MAGIC.len()].copy_from_slice(RING_MAGIC);
+
+        let ring = Self::from_mapping(path, mmap).map_err(FeagiNetworkError::CannotBind)?;
+        ring.open_flag().store(1, Ordering::Release);
+        Ok(ring)
+    }
+
+    /// Maps an existing ring created by a running server.
+    pub(crate) fn open(path: &Path) -> Result<Self, FeagiNetworkError> {
+        let connect_error = |e: std::io::Error| {
+            FeagiNetworkError::CannotConnect(format!(
+                "Cannot open shared memory ring '{}': {}",
+                path.display(),
+                e
+            ))
+        };
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .map_err(connect_error)?;
+        // SAFETY: The layout is validated before any field is trusted.
+        let mmap = unsafe { MmapMut::map_mut(&file) }.map_err(connect_error)?;
+
+        let ring = Self::from_mapping(path, mmap).map_err(FeagiNetworkError::CannotConnect)?;
+        if !ring.is_open() || !ring.owner_is_running() {
+            return Err(FeagiNetworkError::CannotConnect(format!(
+                "Shared memory ring '{}' is not served",
+                path.display()
+            )));
+        }
+        Ok(ring)
+    }
+
+    /// Returns true if `path` holds a ring whose server process is no longer running.
+    ///
+    /// Anything that is not a valid ring is left alone.
+    fn is_stale(path: &Path) -> bool {
+        let Ok(file) = OpenOptions::new().read(true).write(true).open(path) else {
+            return false;
+        };
+        // SAFETY: The layout is validated before any field is trusted.
+        let Ok(mmap) = (unsafe { MmapMut::map_mut(&file) }) else {
+            return false;
+        };
+        Self::from_mapping(path, mmap).is_ok_and(|ring| !ring.owner_is_running())
+    }
+
+    fn from_mapping(path: &Path, mut mmap: MmapMut) -> Result<Self, String> {
+        if mmap.len() < HEADER_BYTE_COUNT {
+            return Err(format!(
+                "Shared memory ring '{}' is too small: {} bytes",
+                path.display(),
+                mmap.len()
+            ));
+        }
+        if &mmap[..RING_MAGIC.len()] != RING_MAGIC {
+            return Err(format!(
+                "'{}' is not a FEAGI shared memory ring",
+                path.display()
+            ));
+        }
+        let version = read_u32(&mmap, VERSION_OFFSET);
+        if version != RING_VERSION {
+            return Err(format!(
+                "Shared memory ring '{}' has version {}, expected {}",
+                path.display(),
+                version,
+                RING_VERSION
+            ));
+        }
+
+        let slot_count = read_u32(&mmap, SLOT_COUNT_OFFSET) as usize;
+        let max_frame_byte_count = read_u32(&mmap, MAX_FRAME_BYTE_COUNT_OFFSET) as usize;
+        let slot_stride = slot_stride_for(max_frame_byte_count);
+        if slot_count == 0 || mmap.len() < HEADER_BYTE_COUNT + slot_count * slot_stride {
+            return Err(format!(
+                "Shared memory ring '{}' is truncated",
+                path.display()
+            ));
+        }
+
+        let base = mmap.as_mut_ptr();
+        Ok(Self {
+            path: path.to_path_buf(),
+            _mmap: mmap,
+            base,
+            slot_count: slot_count as u64,
+            max_frame_byte_count,
+            slot_stride,
+        })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns true while the server that created the ring has not closed it.
+    pub(crate) fn is_open(&self) -> bool {
+        self.open_flag().load(Ordering::Acquire) != 0
+    }
+
+    /// Returns false once the process that created the ring has exited.
+    fn owner_is_running(&self) -> bool {
+        process_is_running(self.owner_pid().load(Ordering::Acquire))
+    }
+
+    /// Tells every client mapping the ring that the server is gone.
+    pub(crate) fn close(&self) {
+        self.open_flag().store(0, Ordering::Release);
+    }
+
+    /// Sequence number the next written frame will get; a reader starting here only sees new frames.
+    pub(crate) fn next_sequence(&self) -> u64 {
+        self.next_sequence_counter().load(Ordering::Acquire)
+    }
+
+    /// Writes the concatenation of `parts` as one frame.
+    pub(crate) fn write_frame(&self, parts: &[&[u8]]) -> Result<(), FeagiNetworkError> {
+        let frame_byte_count: usize = parts.iter().map(|part| part.len()).sum();
+        if frame_byte_count > self.max_frame_byte_count {
+            return Err(FeagiNetworkError::SendFailed(format!(
+                "Frame of {} bytes exceeds the shared memory slot size of {}",
+                frame_byte_count, self.max_frame_byte_count
+            )));
+        }
+        if !self.is_open() {
+            return Err(FeagiNetworkError::SendFailed(
+                "Shared memory ring was closed by the server".to_string(),
+            ));
+        }
+
+        let sequence = self.next_sequence_counter().fetch_add(1, Ordering::AcqRel);
+        let slot_offset = self.slot_offset(sequence);
+        let stamp = self.atomic_u64(slot_offset);
+        let writing_stamp = 2 * sequence + 1;
+        let claim_deadline = Instant::now() + SLOT_CLAIM_TIMEOUT;
+        let mut current_stamp = stamp.load(Ordering::Acquire);
+        loop {
+            if current_stamp >= writing_stamp {
+                // A writer a lap ahead already owns the slot, so readers skip this frame anyway
+                return Ok(());
+            }
+            if current_stamp % 2 == 1 {
+                // The writer a lap behind is still copying its frame into this slot
+                if Instant::now() >= claim_deadline {
+                    return Err(FeagiNetworkError::SendFailed(
+                        "Shared memory slot is still being written by a stalled writer".to_string(),
+                    ));
+                }
+                std::thread::yield_now();
+                current_stamp = stamp.load(Ordering::Acquire);
+                continue;
+            }
+            match stamp.compare_exchange_weak(
+                current_stamp,
+                writing_stamp,
+                Ordering::Relaxed,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current_stamp = actual,
+            }
+        }
+        fence(Ordering::Release);
+
+        // SAFETY: The slot lies inside the mapping and holds `max_frame_byte_count` bytes,
+        // which was checked above. The claimed stamp keeps other writers out of the slot, and
+        // concurrent readers detect the overlap by the stamp.
+        unsafe {
+            let length_bytes = (frame_byte_count as u32).to_ne_bytes();
+            std::ptr::copy_nonoverlapping(
+                length_bytes.as_ptr(),
+                self.base.add(slot_offset + SLOT_FRAME_LENGTH_OFFSET),
+                length_bytes.len(),
+            );
+            let mut destination = self.base.add(slot_offset + SLOT_HEADER_BYTE_COUNT);
+            for part in parts {
+                std::ptr::copy_nonoverlapping(part.as_ptr(), destination, part.len());
+                destination = destination.add(part.len());
+            }
+        }
+
+        stamp.store(2 * sequence + 2, Ordering::Release);
+        Ok(())
+    }
+
+    /// Copies the frame at `cursor` into `destination` and advances the cursor.
+    ///
+    /// Frames that were overwritten before they could be read are skipped. Returns false
+    /// once the cursor reached a frame that is not completely written yet.
+    pub(crate) fn read_frame_into(&self, cursor: &mut u64, destination: &mut Vec<u8>) -> bool {
+        loop {
+            let next_sequence = self.next_sequence();
+            if *cursor >= next_sequence {
+                return false;
+            }
+            // Anything more than one lap behind has already been overwritten
+            if next_sequence - *cursor > self.slot_count {
+                *cursor = next_sequence - self.slot_count;
+            }
+
+            let slot_offset = self.slot_offset(*cursor);
+            let stamp = self.atomic_u64(slot_offset);
+            let committed_stamp = 2 * *cursor + 2;
+            let stamp_before = stamp.load(Ordering::Acquire);
+            if stamp_before < committed_stamp {
+                // Not written yet; a writer that died here is skipped after a lap
+                return false;
+            }
+            if stamp_before > committed_stamp {
+                *cursor += 1;
+                continue;
+            }
+
+            // SAFETY: The slot lies inside the mapping; the length is bounds checked before
+            // use and the copy is discarded below if a writer touched the slot meanwhile.
+            let frame_byte_count = unsafe {
+                std::ptr::read_volatile(
+                    self.base.add(slot_offset + SLOT_FRAME_LENGTH_OFFSET) as *const u32
+                )
+            } as usize;
+            *cursor += 1;
+            if frame_byte_count > self.max_frame_byte_count {
+                continue;
+            }
+            destination.clear();
+            destination.extend_from_slice(unsafe {
+                std::slice::from_raw_parts(
+                    self.base.add(slot_offset + SLOT_HEADER_BYTE_COUNT),
+                    frame_byte_count,
+                )
+            });
+
+            fence(Ordering::Acquire);
+            if stamp.load(Ordering::Relaxed) == stamp_before {
+                return true;
+            }
+        }
+    }
+
+    fn slot_offset(&self, sequence: u64) -> usize {
+        HEADER_BYTE_COUNT + (sequence % self.slot_count) as usize * self.slot_stride
+    }
+
+    fn open_flag(&self) -> &AtomicU32 {
+        // SAFETY: In bounds of the validated header and 4 byte aligned in the page aligned mapping.
+        unsafe { &*(self.base.add(OPEN_FLAG_OFFSET) as *const AtomicU32) }
+    }
+
+    fn owner_pid(&self) -> &AtomicU32 {
+        // SAFETY: In bounds of the validated header and 4 byte aligned in the page aligned mapping.
+        unsafe { &*(self.base.add(OWNER_PID_OFFSET) as *const AtomicU32) }
+    }
+
+    fn next_sequence_counter(&self) -> &AtomicU64 {
+        self.atomic_u64(NEXT_SEQUENCE_OFFSET)
+    }
+
+    fn atomic_u64(&self, offset: usize) -> &AtomicU64 {
+        // SAFETY: Callers pass header or slot offsets, which are in bounds of the validated
+        // mapping and 8 byte aligned since the mapping is page aligned.
+        unsafe { &*(self.base.add(offset) as *const AtomicU64) }
+    }
+}
+
+/// Creates a new ring file readable and writable by the owner only.
+fn create_ring_file(path: &Path) -> std::io::Result<std::fs::File> {
+    let mut options = OpenOptions::new();
+    options.read(true).write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+/// Returns true unless process `pid` is known to have exited. PID 0 marks an unknown owner.
+#[cfg(unix)]
+fn process_is_running(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return true;
+    };
+    if pid == 0 {
+        return true;
+    }
+    // SAFETY: Signal 0 only checks that the process exists; nothing is delivered.
+    if unsafe { libc::kill(pid, 0) } == 0 {
+        return true;
+    }
+    // EPERM means the process exists but belongs to another user
+    std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Without a liveness check every owner counts as running, so rings are never reclaimed.
+#[cfg(not(unix))]
+fn process_is_running(_pid: u32) -> bool {
+    true
+}
+
+/// Bytes a slot occupies, including its header and padding.
+fn slot_stride_for(max_frame_byte_count: usize) -> usize {
+    (SLOT_HEADER_BYTE_COUNT + max_frame_byte_count).div_ceil(8) * 8
+}
+
+fn read_u32(mmap: &MmapMut, offset: usize) -> u32 {
+    u32::from_ne_bytes([
+        mmap[offset],
+        mmap[offset + 1],
+        mmap[offset + 2],
+        mmap[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh_ring_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "feagi_io_ring_{}_{}.ring",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn read_all(ring: &ShmRing) -> Vec<Vec<u8>> {
+        let mut cursor = 0;
+        let mut frame = Vec::new();
+        let mut frames = Vec::new();
+        while ring.read_frame_into(&mut cursor, &mut frame) {
+            frames.push(frame.clone());
+        }
+        frames
+    }
+
+    #[test]
+    fn test_writers_a_lap_apart_never_share_a_slot() {
+        let path = fresh_ring_path("lap");
+        let ring = ShmRing::create(&path, 2, 16).unwrap();
+
+        // Sequence 0 is claimed by a writer that stalls in the middle of its frame
+        let stalled = ring.next_sequence_counter().fetch_add(1, Ordering::AcqRel);
+        ring.atomic_u64(ring.slot_offset(stalled))
+            .store(2 * stalled + 1, Ordering::Release);
+        ring.write_frame(&[b"one"]).unwrap();
+        // Sequence 2 lands on the stalled writer's slot and must not write over it
+        assert!(ring.write_frame(&[b"two"]).is_err());
+
+        // Sequence 3 finds its slot already claimed by sequence 5 and leaves it alone
+        let claimed_by_later = 2 * 5 + 2;
+        ring.atomic_u64(ring.slot_offset(3))
+            .store(claimed_by_later, Ordering::Release);
+        ring.write_frame(&[b"three"]).unwrap();
+        assert_eq!(
+            ring.atomic_u64(ring.slot_offset(3)).load(Ordering::Acquire),
+            claimed_by_later
+        );
+
+        ring.close();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_create_is_private_and_never_replaces_a_ring() {
+        let path = fresh_ring_path("create");
+        let ring = ShmRing::create(&path, 2, 16).unwrap();
+        ring.write_frame(&[b"kept"]).unwrap();
+
+        assert!(matches!(
+            ShmRing::create(&path, 2, 16),
+            Err(FeagiNetworkError::CannotBind(_))
+        ));
+        assert_eq!(read_all(&ShmRing::open(&path).unwrap()), vec![b"kept"]);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        ring.close();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_reclaims_a_ring_left_by_a_dead_server() {
+        let path = fresh_ring_path("stale");
+        let stale = ShmRing::create(&path, 2, 16).unwrap();
+        stale.write_frame(&[b"lost"]).unwrap();
+
+        // The server dies without closing or removing its ring
+        let mut exited = std::process::Command::new("true").spawn().unwrap();
+        exited.wait().unwrap();
+        stale.owner_pid().store(exited.id(), Ordering::Release);
+        drop(stale);
+        assert!(path.exists());
+        assert!(matches!(
+            ShmRing::open(&path),
+            Err(FeagiNetworkError::CannotConnect(_))
+        ));
+
+        let ring = ShmRing::create(&path, 2, 16).unwrap();
+        assert_eq!(ring.owner_pid().load(Ordering::Acquire), std::process::id());
+        assert!(read_all(&ShmRing::open(&path).unwrap()).is_empty());
+
+        ring.close();
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,667 @@
+//! Shared memory server implementations using the poll-based trait design.
+//!
+//! A server creates its ring files in `request_start()` and unlinks them again when it
+//! stops. There are no connections to accept; clients simply map the rings.
+
+use std::collections::HashMap;
+
+use crate::protocol_implementations::feagi_frame::{
+    has_non_empty_payload, is_plausible_feagi_frame, try_extract_agent_id_from_payload,
+};
+use crate::protocol_implementations::shared_memory::ring::{
+    ShmRing, DEFAULT_MAX_FRAME_BYTE_COUNT, DEFAULT_SLOT_COUNT,
+};
+use crate::protocol_implementations::shared_memory::{
+    SharedMemoryUrl, ROUTER_REQUESTS_SUFFIX, ROUTER_RESPONSES_SUFFIX,
+};
+use crate::traits_and_enums::server::{
+    FeagiServer, FeagiServerPublisher, FeagiServerPublisherProperties, FeagiServerPuller,
+    FeagiServerPullerProperties, FeagiServerRouter, FeagiServerRouterProperties,
+};
+use crate::traits_and_enums::shared::{
+    FeagiEndpointState, TransportProtocolEndpoint, TransportProtocolImplementation,
+};
+use crate::{AgentID, FeagiNetworkError};
+
+/// Marks a ring closed for its clients and removes its file.
+fn release_ring(ring: Option<ShmRing>) {
+    if let Some(ring) = ring {
+        ring.close();
+        // Nothing to recover on failure; a leftover file makes the next start fail loudly
+        let _ = std::fs::remove_file(ring.path());
+    }
+}
+
+// ============================================================================
+// Publisher
+// ============================================================================
+
+//region Publisher Properties
+
+/// Configuration properties for creating a shared memory publisher server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryServerPublisherProperties {
+    local_bind_address: SharedMemoryUrl,
+    remote_bind_address: SharedMemoryUrl,
+    slot_count: u32,
+    max_frame_byte_count: u32,
+}
+
+impl FeagiSharedMemoryServerPublisherProperties {
+    /// Creates new publisher properties with explicit local/remote endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_bind_address` - The ring file the server creates.
+    /// * `remote_bind_address` - The ring file agents are told to map.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Self::new_with_ring_size(
+            local_bind_address,
+            remote_bind_address,
+            DEFAULT_SLOT_COUNT,
+            DEFAULT_MAX_FRAME_BYTE_COUNT,
+        )
+    }
+
+    /// Creates new publisher properties with a custom ring size.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot_count` - Frames kept in the ring before the oldest is overwritten.
+    /// * `max_frame_byte_count` - Largest frame that can be published.
+    pub fn new_with_ring_size(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+        slot_count: u32,
+        max_frame_byte_count: u32,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: SharedMemoryUrl::new(local_bind_address)?,
+            remote_bind_address: SharedMemoryUrl::new(remote_bind_address)?,
+            slot_count,
+            max_frame_byte_count,
+        })
+    }
+}
+
+impl FeagiServerPublisherProperties for FeagiSharedMemoryServerPublisherProperties {
+    fn as_boxed_server_publisher(&self) -> Box<dyn FeagiServerPublisher> {
+        Box::new(FeagiSharedMemoryServerPublisher {
+            properties: self.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            ring: None,
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.local_bind_address.clone())
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.remote_bind_address.clone())
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+//endregion
+
+//region Publisher Implementation
+
+/// A shared memory server that broadcasts data to every mapped subscriber.
+///
+/// Publishing never waits for subscribers; one that falls more than a ring behind
+/// skips ahead to the oldest frame still in the ring.
+pub struct FeagiSharedMemoryServerPublisher {
+    properties: FeagiSharedMemoryServerPublisherProperties,
+    current_state: FeagiEndpointState,
+    ring: Option<ShmRing>,
+}
+
+impl FeagiSharedMemoryServerPublisher {
+    fn close_all(&mut self) {
+        release_ring(self.ring.take());
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiServer for FeagiSharedMemoryServerPublisher {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        // Subscribers map the ring themselves, so there is nothing to service
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                self.ring = Some(ShmRing::create(
+                    self.properties.local_bind_address.path(),
+                    self.properties.slot_count,
+                    self.properties.max_frame_byte_count,
+                )?);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        self.properties.get_bind_point()
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        self.properties.get_agent_endpoint()
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+impl FeagiServerPublisher for FeagiSharedMemoryServerPublisher {
+    fn publish_data(&mut self, data: &[u8]) -> Result<(), FeagiNetworkError> {
+        match (&self.current_state, &self.ring) {
+            (FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData, Some(ring)) => {
+                ring.write_frame(&[data])
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot publish: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_publisher_properties(&self) -> Box<dyn FeagiServerPublisherProperties> {
+        Box::new(self.properties.clone())
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Puller
+// ============================================================================
+
+//region Puller Properties
+
+/// Configuration properties for creating a shared memory puller server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryServerPullerProperties {
+    local_bind_address: SharedMemoryUrl,
+    remote_bind_address: SharedMemoryUrl,
+    slot_count: u32,
+    max_frame_byte_count: u32,
+}
+
+impl FeagiSharedMemoryServerPullerProperties {
+    /// Creates new puller properties with explicit local/remote endpoints.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Self::new_with_ring_size(
+            local_bind_address,
+            remote_bind_address,
+            DEFAULT_SLOT_COUNT,
+            DEFAULT_MAX_FRAME_BYTE_COUNT,
+        )
+    }
+
+    /// Creates new puller properties with a custom ring size.
+    pub fn new_with_ring_size(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+        slot_count: u32,
+        max_frame_byte_count: u32,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: SharedMemoryUrl::new(local_bind_address)?,
+            remote_bind_address: SharedMemoryUrl::new(remote_bind_address)?,
+            slot_count,
+            max_frame_byte_count,
+        })
+    }
+}
+
+impl FeagiServerPullerProperties for FeagiSharedMemoryServerPullerProperties {
+    fn as_boxed_server_puller(&self) -> Box<dyn FeagiServerPuller> {
+        Box::new(FeagiSharedMemoryServerPuller {
+            properties: self.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            ring: None,
+            cursor: 0,
+            frame_buffer: Vec::new(),
+            receive_buffer: Vec::new(),
+            has_data: false,
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.local_bind_address.clone())
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.remote_bind_address.clone())
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+//endregion
+
+//region Puller Implementation
+
+/// A shared memory server that receives frames pushed by any number of clients.
+///
+/// Like the ZMQ puller, each poll drains everything written since the last one and
+/// keeps only the latest non-empty FEAGI frame.
+pub struct FeagiSharedMemoryServerPuller {
+    properties: FeagiSharedMemoryServerPullerProperties,
+    current_state: FeagiEndpointState,
+    ring: Option<ShmRing>,
+    /// Sequence number of the next frame to read
+    cursor: u64,
+    /// Scratch buffer frames are read into while draining
+    frame_buffer: Vec<u8>,
+    /// Latest usable frame, handed out by `consume_retrieved_data`
+    receive_buffer: Vec<u8>,
+    has_data: bool,
+}
+
+impl FeagiSharedMemoryServerPuller {
+    /// Drain all frames written since the last poll and keep only the latest payload.
+    fn try_receive_latest(&mut self) -> bool {
+        let Some(ring) = &self.ring else {
+            return false;
+        };
+        let mut has_latest_non_empty_valid = false;
+        while ring.read_frame_into(&mut self.cursor, &mut self.frame_buffer) {
+            if is_plausible_feagi_frame(&self.frame_buffer)
+                && has_non_empty_payload(&self.frame_buffer)
+            {
+                std::mem::swap(&mut self.frame_buffer, &mut self.receive_buffer);
+                has_latest_non_empty_valid = true;
+            }
+        }
+        has_latest_non_empty_valid
+    }
+
+    fn close_all(&mut self) {
+        release_ring(self.ring.take());
+        self.has_data = false;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiServer for FeagiSharedMemoryServerPuller {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting)
+            && !self.has_data
+            && self.try_receive_latest()
+        {
+            self.has_data = true;
+            self.current_state = FeagiEndpointState::ActiveHasData;
+        }
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let ring = ShmRing::create(
+                    self.properties.local_bind_address.path(),
+                    self.properties.slot_count,
+                    self.properties.max_frame_byte_count,
+                )?;
+                self.cursor = ring.next_sequence();
+                self.ring = Some(ring);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        self.properties.get_bind_point()
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        self.properties.get_agent_endpoint()
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+impl FeagiServerPuller for FeagiSharedMemoryServerPuller {
+    fn consume_retrieved_data(&mut self) -> Result<&[u8], FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveHasData if self.has_data => {
+                self.has_data = false;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(self.receive_buffer.as_slice())
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no data available".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_puller_properties(&self) -> Box<dyn FeagiServerPullerProperties> {
+        Box::new(self.properties.clone())
+    }
+}
+
+//endregion
+
+// ============================================================================
+// Router
+// ============================================================================
+
+//region Router Properties
+
+/// Configuration properties for creating a shared memory router server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeagiSharedMemoryServerRouterProperties {
+    local_bind_address: SharedMemoryUrl,
+    remote_bind_address: SharedMemoryUrl,
+    slot_count: u32,
+    max_frame_byte_count: u32,
+}
+
+impl FeagiSharedMemoryServerRouterProperties {
+    /// Creates new router properties with explicit local/remote endpoints.
+    ///
+    /// The router creates two ring files next to the given path, one for requests
+    /// and one for responses.
+    pub fn new(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+    ) -> Result<Self, FeagiNetworkError> {
+        Self::new_with_ring_size(
+            local_bind_address,
+            remote_bind_address,
+            DEFAULT_SLOT_COUNT,
+            DEFAULT_MAX_FRAME_BYTE_COUNT,
+        )
+    }
+
+    /// Creates new router properties with a custom size for both rings.
+    pub fn new_with_ring_size(
+        local_bind_address: &str,
+        remote_bind_address: &str,
+        slot_count: u32,
+        max_frame_byte_count: u32,
+    ) -> Result<Self, FeagiNetworkError> {
+        Ok(Self {
+            local_bind_address: SharedMemoryUrl::new(local_bind_address)?,
+            remote_bind_address: SharedMemoryUrl::new(remote_bind_address)?,
+            slot_count,
+            max_frame_byte_count,
+        })
+    }
+}
+
+impl FeagiServerRouterProperties for FeagiSharedMemoryServerRouterProperties {
+    fn as_boxed_server_router(&self) -> Box<dyn FeagiServerRouter> {
+        Box::new(FeagiSharedMemoryServerRouter {
+            properties: self.clone(),
+            current_state: FeagiEndpointState::Inactive,
+            request_ring: None,
+            response_ring: None,
+            cursor: 0,
+            receive_buffer: Vec::new(),
+            current_session: None,
+            mailboxes: HashMap::new(),
+        })
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.local_bind_address.clone())
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::SharedMemory(self.remote_bind_address.clone())
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+//endregion
+
+//region Router Implementation
+
+/// A shared memory server that handles request-response communication with multiple clients.
+///
+/// Every requester tags its requests with a random mailbox ID, and picks the responses
+/// tagged with it out of the shared response ring. Sessions follow the agent ID found in
+/// the request header, falling back to the mailbox ID for requests without one.
+pub struct FeagiSharedMemoryServerRouter {
+    properties: FeagiSharedMemoryServerRouterProperties,
+    current_state: FeagiEndpointState,
+    request_ring: Option<ShmRing>,
+    response_ring: Option<ShmRing>,
+    /// Sequence number of the next request to read
+    cursor: u64,
+    /// Buffer for received request, including the mailbox prefix
+    receive_buffer: Vec<u8>,
+    /// Session ID of the client that sent the current request
+    current_session: Option<AgentID>,
+    /// Mailbox the responses of each session are tagged with
+    mailboxes: HashMap<AgentID, AgentID>,
+}
+
+impl FeagiSharedMemoryServerRouter {
+    /// Takes the next well formed request off the request ring.
+    fn try_receive(&mut self) -> bool {
+        let Some(ring) = &self.request_ring else {
+            return false;
+        };
+        while ring.read_frame_into(&mut self.cursor, &mut self.receive_buffer) {
+            if self.receive_buffer.len() < AgentID::NUMBER_BYTES {
+                continue;
+            }
+            let mut mailbox_bytes = [0u8; AgentID::NUMBER_BYTES];
+            mailbox_bytes.copy_from_slice(&self.receive_buffer[..AgentID::NUMBER_BYTES]);
+            let mailbox = AgentID::new(mailbox_bytes);
+            let session_id =
+                try_extract_agent_id_from_payload(&self.receive_buffer[AgentID::NUMBER_BYTES..])
+                    .unwrap_or(mailbox);
+            // A reconnecting agent brings a new mailbox along
+            self.mailboxes.insert(session_id, mailbox);
+            self.current_session = Some(session_id);
+            return true;
+        }
+        false
+    }
+
+    fn close_all(&mut self) {
+        release_ring(self.request_ring.take());
+        release_ring(self.response_ring.take());
+        self.mailboxes.clear();
+        self.current_session = None;
+        self.current_state = FeagiEndpointState::Inactive;
+    }
+}
+
+impl FeagiServer for FeagiSharedMemoryServerRouter {
+    fn poll(&mut self) -> &FeagiEndpointState {
+        if matches!(self.current_state, FeagiEndpointState::ActiveWaiting) && self.try_receive() {
+            self.current_state = FeagiEndpointState::ActiveHasData;
+        }
+        &self.current_state
+    }
+
+    fn request_start(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Inactive => {
+                let address = &self.properties.local_bind_address;
+                let request_ring = ShmRing::create(
+                    &address.path_with_suffix(ROUTER_REQUESTS_SUFFIX),
+                    self.properties.slot_count,
+                    self.properties.max_frame_byte_count,
+                )?;
+                let response_ring = match ShmRing::create(
+                    &address.path_with_suffix(ROUTER_RESPONSES_SUFFIX),
+                    self.properties.slot_count,
+                    self.properties.max_frame_byte_count,
+                ) {
+                    Ok(ring) => ring,
+                    Err(e) => {
+                        release_ring(Some(request_ring));
+                        return Err(e);
+                    }
+                };
+                self.cursor = request_ring.next_sequence();
+                self.request_ring = Some(request_ring);
+                self.response_ring = Some(response_ring);
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot start: server is not in Inactive state".to_string(),
+            )),
+        }
+    }
+
+    fn request_stop(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot stop: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn confirm_error_and_close(&mut self) -> Result<(), FeagiNetworkError> {
+        match &self.current_state {
+            FeagiEndpointState::Errored(_) => {
+                self.close_all();
+                Ok(())
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Cannot confirm error: server is not in Errored state".to_string(),
+            )),
+        }
+    }
+
+    fn get_bind_point(&self) -> TransportProtocolEndpoint {
+        self.properties.get_bind_point()
+    }
+
+    fn get_agent_endpoint(&self) -> TransportProtocolEndpoint {
+        self.properties.get_agent_endpoint()
+    }
+
+    fn get_protocol(&self) -> TransportProtocolImplementation {
+        TransportProtocolImplementation::SharedMemory
+    }
+}
+
+impl FeagiServerRouter for FeagiSharedMemoryServerRouter {
+    fn consume_retrieved_request(&mut self) -> Result<(AgentID, &[u8]), FeagiNetworkError> {
+        match (&self.current_state, self.current_session) {
+            (FeagiEndpointState::ActiveHasData, Some(session_id)) => {
+                self.current_session = None;
+                self.current_state = FeagiEndpointState::ActiveWaiting;
+                Ok((session_id, &self.receive_buffer[AgentID::NUMBER_BYTES..]))
+            }
+            _ => Err(FeagiNetworkError::ReceiveFailed(
+                "Cannot consume: no request available".to_string(),
+            )),
+        }
+    }
+
+    fn publish_response(
+        &mut self,
+        session_id: AgentID,
+        message: &[u8],
+    ) -> Result<(), FeagiNetworkError> {
+        match (&self.current_state, &self.response_ring) {
+            (
+                FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData,
+                Some(response_ring),
+            ) => {
+                let mailbox = self.mailboxes.get(&session_id).ok_or_else(|| {
+                    FeagiNetworkError::SendFailed(format!("Unknown session: {:?}", session_id))
+                })?;
+                response_ring.write_frame(&[mailbox.bytes(), message])
+            }
+            _ => Err(FeagiNetworkError::SendFailed(
+                "Cannot send response: server is not in Active state".to_string(),
+            )),
+        }
+    }
+
+    fn as_boxed_router_properties(&self) -> Box<dyn FeagiServerRouterProperties> {
+        Box::new(self.properties.clone())
+    }
+}
+
+//endregion
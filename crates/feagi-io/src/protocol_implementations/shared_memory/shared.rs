@@ -0,0 +1,76 @@
+//! Shared utilities for shared memory implementations.
+
+use std::path::{Path, PathBuf};
+
+use crate::FeagiNetworkError;
+use serde::{Deserialize, Serialize};
+
+/// URL endpoint struct for shared memory endpoints with validation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedMemoryUrl {
+    url: String,
+}
+
+impl SharedMemoryUrl {
+    /// Creates a new SharedMemoryUrl after validating the format.
+    ///
+    /// The URL will be normalized to include the `shm://` scheme if not present.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The ring file URL or path (e.g., "shm:///dev/shm/feagi_sensory", "/dev/shm/feagi_motor").
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is empty or points to a directory.
+    pub fn new(url: &str) -> Result<Self, FeagiNetworkError> {
+        let normalized = if url.starts_with("shm://") {
+            url.to_string()
+        } else {
+            format!("shm://{}", url)
+        };
+        validate_shared_memory_url(&normalized)?;
+        Ok(SharedMemoryUrl { url: normalized })
+    }
+
+    /// Returns the URL as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the filesystem path of the ring file.
+    pub fn path(&self) -> &Path {
+        Path::new(self.url.strip_prefix("shm://").unwrap_or(&self.url))
+    }
+
+    /// Returns the path of a companion ring file, named by appending `suffix` to this path.
+    pub(crate) fn path_with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut path = self.path().as_os_str().to_owned();
+        path.push(suffix);
+        PathBuf::from(path)
+    }
+}
+
+impl std::fmt::Display for SharedMemoryUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+/// Validates a shared memory URL format (`shm://path`).
+fn validate_shared_memory_url(url: &str) -> Result<(), FeagiNetworkError> {
+    let path = url.strip_prefix("shm://").unwrap_or(url);
+    if path.is_empty() {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid shared memory URL '{}': empty path after shm://",
+            url
+        )));
+    }
+    if path.ends_with('/') || Path::new(path).is_dir() {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid shared memory URL '{}': path must name a file, not a directory",
+            url
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! Raw TCP transport implementations.
+//!
+//! This module provides the FEAGI networking traits over plain `std::net` TCP sockets
+//! with length-prefixed framing, for environments where libzmq is not available.
+//! All sockets are non-blocking and have Nagle's algorithm disabled for low latency.
+//!
+//! # Socket Patterns
+//!
+//! | Server | Client | Pattern |
+//! |--------|--------|---------|
+//! | [`FeagiTcpServerPublisher`] | [`FeagiTcpClientSubscriber`] | Pub/Sub (broadcast) |
+//! | [`FeagiTcpServerPuller`] | [`FeagiTcpClientPusher`] | Push/Pull (pipeline) |
+//! | [`FeagiTcpServerRouter`] | [`FeagiTcpClientRequester`] | Router/Dealer (req/rep) |
+//!
+//! # Creating Instances
+//!
+//! All server and client instances are created through their Properties types:
+//!
+//! ```ignore
+//! // Server example
+//! let props = FeagiTcpServerPublisherProperties::new("tcp://*:9050", "tcp://feagi:9050")?;
+//! let mut server = props.as_boxed_server_publisher();
+//!
+//! // Client example
+//! let props = FeagiTcpClientSubscriberProperties::new("tcp://feagi:9050")?;
+//! let mut client = props.as_boxed_client_subscriber();
+//! ```
+
+use std::net::{TcpListener, TcpStream};
+
+use crate::protocol_implementations::framed_stream::FramedStreamTransport;
+use crate::traits_and_enums::shared::{TransportProtocolEndpoint, TransportProtocolImplementation};
+use crate::FeagiNetworkError;
+
+mod shared;
+
+pub use shared::TcpUrl;
+
+/// Framed stream transport over TCP sockets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TcpTransport;
+
+impl FramedStreamTransport for TcpTransport {
+    type Address = TcpUrl;
+    type Listener = TcpListener;
+    type Stream = TcpStream;
+
+    const PROTOCOL: TransportProtocolImplementation = TransportProtocolImplementation::Tcp;
+
+    fn parse_address(address: &str) -> Result<Self::Address, FeagiNetworkError> {
+        TcpUrl::new(address)
+    }
+
+    fn to_endpoint(address: &Self::Address) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::Tcp(address.clone())
+    }
+
+    fn bind(address: &Self::Address) -> Result<Self::Listener, FeagiNetworkError> {
+        let listener = TcpListener::bind(address.host_port())
+            .map_err(|e| FeagiNetworkError::CannotBind(e.to_string()))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| FeagiNetworkError::CannotBind(e.to_string()))?;
+        Ok(listener)
+    }
+
+    fn accept(listener: &Self::Listener) -> std::io::Result<Self::Stream> {
+        let (stream, _addr) = listener.accept()?;
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    }
+
+    fn connect(address: &Self::Address) -> Result<Self::Stream, FeagiNetworkError> {
+        let stream = TcpStream::connect(address.host_port())
+            .map_err(|e| FeagiNetworkError::CannotConnect(e.to_string()))?;
+        stream
+            .set_nonblocking(true)
+            .map_err(|e| FeagiNetworkError::CannotConnect(e.to_string()))?;
+        stream
+            .set_nodelay(true)
+            .map_err(|e| FeagiNetworkError::CannotConnect(e.to_string()))?;
+        Ok(stream)
+    }
+}
+
+#[cfg(feature = "feagi-server")]
+use crate::protocol_implementations::framed_stream::{
+    FeagiFramedServerPublisher, FeagiFramedServerPublisherProperties, FeagiFramedServerPuller,
+    FeagiFramedServerPullerProperties, FeagiFramedServerRouter, FeagiFramedServerRouterProperties,
+};
+
+#[cfg(feature = "feagi-client")]
+use crate::protocol_implementations::framed_stream::{
+    FeagiFramedClientPusher, FeagiFramedClientPusherProperties, FeagiFramedClientRequester,
+    FeagiFramedClientRequesterProperties, FeagiFramedClientSubscriber,
+    FeagiFramedClientSubscriberProperties,
+};
+
+// Server implementations and properties
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerPublisher = FeagiFramedServerPublisher<TcpTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerPublisherProperties = FeagiFramedServerPublisherProperties<TcpTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerPuller = FeagiFramedServerPuller<TcpTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerPullerProperties = FeagiFramedServerPullerProperties<TcpTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerRouter = FeagiFramedServerRouter<TcpTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiTcpServerRouterProperties = FeagiFramedServerRouterProperties<TcpTransport>;
+
+// Client implementations and properties
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientSubscriber = FeagiFramedClientSubscriber<TcpTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientSubscriberProperties = FeagiFramedClientSubscriberProperties<TcpTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientPusher = FeagiFramedClientPusher<TcpTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientPusherProperties = FeagiFramedClientPusherProperties<TcpTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientRequester = FeagiFramedClientRequester<TcpTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiTcpClientRequesterProperties = FeagiFramedClientRequesterProperties<TcpTransport>;
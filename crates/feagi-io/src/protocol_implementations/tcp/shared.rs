@@ -0,0 +1,81 @@
+//! Shared utilities for raw TCP implementations.
+
+use crate::FeagiNetworkError;
+use serde::{Deserialize, Serialize};
+
+/// URL endpoint struct for raw TCP endpoints with validation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcpUrl {
+    url: String,
+}
+
+impl TcpUrl {
+    /// Creates a new TcpUrl after validating the format.
+    ///
+    /// The URL will be normalized to include the `tcp://` scheme if not present.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The TCP URL (e.g., "tcp://127.0.0.1:9050", "tcp://*:9050", "localhost:9050").
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL format is invalid.
+    pub fn new(url: &str) -> Result<Self, FeagiNetworkError> {
+        let normalized = if url.starts_with("tcp://") {
+            url.to_string()
+        } else {
+            format!("tcp://{}", url)
+        };
+        validate_tcp_url(&normalized)?;
+        Ok(TcpUrl { url: normalized })
+    }
+
+    /// Returns the URL as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the `host:port` portion suitable for binding or connecting.
+    ///
+    /// A `*` host (bind to all interfaces, as in ZMQ URLs) becomes `0.0.0.0`.
+    pub fn host_port(&self) -> String {
+        let host_port = self.url.strip_prefix("tcp://").unwrap_or(&self.url);
+        match host_port.strip_prefix("*:") {
+            Some(port) => format!("0.0.0.0:{}", port),
+            None => host_port.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for TcpUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+/// Validates a raw TCP URL format (`tcp://host:port`).
+fn validate_tcp_url(url: &str) -> Result<(), FeagiNetworkError> {
+    let host_port = url.strip_prefix("tcp://").unwrap_or(url);
+    let (host, port) = host_port.rsplit_once(':').ok_or_else(|| {
+        FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid TCP URL '{}': missing port (expected host:port)",
+            url
+        ))
+    })?;
+
+    if host.is_empty() {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid TCP URL '{}': empty host",
+            url
+        )));
+    }
+    if port.parse::<u16>().is_err() {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid TCP URL '{}': port '{}' is not a number between 0 and 65535",
+            url, port
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Unix domain socket transport implementations.
+//!
+//! This module provides the FEAGI networking traits over `std::os::unix` stream sockets
+//! with length-prefixed framing, for agents running on the same host as FEAGI.
+//! All sockets are non-blocking. A server removes a stale socket file left behind by a
+//! previous run when binding, and removes its own socket file when stopped.
+//!
+//! # Socket Patterns
+//!
+//! | Server | Client | Pattern |
+//! |--------|--------|---------|
+//! | [`FeagiUnixSocketServerPublisher`] | [`FeagiUnixSocketClientSubscriber`] | Pub/Sub (broadcast) |
+//! | [`FeagiUnixSocketServerPuller`] | [`FeagiUnixSocketClientPusher`] | Push/Pull (pipeline) |
+//! | [`FeagiUnixSocketServerRouter`] | [`FeagiUnixSocketClientRequester`] | Router/Dealer (req/rep) |
+//!
+//! # Creating Instances
+//!
+//! All server and client instances are created through their Properties types:
+//!
+//! ```ignore
+//! // Server example
+//! let props = FeagiUnixSocketServerPublisherProperties::new(
+//!     "unix:///tmp/feagi_viz.sock",
+//!     "unix:///tmp/feagi_viz.sock",
+//! )?;
+//! let mut server = props.as_boxed_server_publisher();
+//!
+//! // Client example
+//! let props = FeagiUnixSocketClientSubscriberProperties::new("unix:///tmp/feagi_viz.sock")?;
+//! let mut client = props.as_boxed_client_subscriber();
+//! ```
+
+use std::os::unix::net::{UnixListener, UnixStream};
+
+use crate::protocol_implementations::framed_stream::FramedStreamTransport;
+use crate::traits_and_enums::shared::{TransportProtocolEndpoint, TransportProtocolImplementation};
+use crate::FeagiNetworkError;
+
+mod shared;
+
+pub use shared::UnixSocketUrl;
+
+/// Framed stream transport over Unix domain sockets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnixSocketTransport;
+
+impl FramedStreamTransport for UnixSocketTransport {
+    type Address = UnixSocketUrl;
+    type Listener = UnixListener;
+    type Stream = UnixStream;
+
+    const PROTOCOL: TransportProtocolImplementation = TransportProtocolImplementation::UnixSocket;
+
+    fn parse_address(address: &str) -> Result<Self::Address, FeagiNetworkError> {
+        UnixSocketUrl::new(address)
+    }
+
+    fn to_endpoint(address: &Self::Address) -> TransportProtocolEndpoint {
+        TransportProtocolEndpoint::UnixSocket(address.clone())
+    }
+
+    fn bind(address: &Self::Address) -> Result<Self::Listener, FeagiNetworkError> {
+        let path = address.path();
+        if path.exists() {
+            // A live server still accepts connections; anything else is a leftover file
+            if UnixStream::connect(path).is_ok() {
+                return Err(FeagiNetworkError::CannotBind(format!(
+                    "Unix socket '{}' is already in use",
+                    path.display()
+                )));
+            }
+            std::fs::remove_file(path).map_err(|e| {
+                FeagiNetworkError::CannotBind(format!(
+                    "Cannot remove stale Unix socket '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        }
+
+        let listener =
+            UnixListener::bind(path).map_err(|e| FeagiNetworkError::CannotBind(e.to_string()))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| FeagiNetworkError::CannotBind(e.to_string()))?;
+        Ok(listener)
+    }
+
+    fn accept(listener: &Self::Listener) -> std::io::Result<Self::Stream> {
+        let (stream, _addr) = listener.accept()?;
+        stream.set_nonblocking(true)?;
+        Ok(stream)
+    }
+
+    fn connect(address: &Self::Address) -> Result<Self::Stream, FeagiNetworkError> {
+        let stream = UnixStream::connect(address.path())
+            .map_err(|e| FeagiNetworkError::CannotConnect(e.to_string()))?;
+        stream
+            .set_nonblocking(true)
+            .map_err(|e| FeagiNetworkError::CannotConnect(e.to_string()))?;
+        Ok(stream)
+    }
+
+    fn release(address: &Self::Address) {
+        // Nothing to recover on failure; the next bind cleans up a stale file
+        let _ = std::fs::remove_file(address.path());
+    }
+}
+
+#[cfg(feature = "feagi-server")]
+use crate::protocol_implementations::framed_stream::{
+    FeagiFramedServerPublisher, FeagiFramedServerPublisherProperties, FeagiFramedServerPuller,
+    FeagiFramedServerPullerProperties, FeagiFramedServerRouter, FeagiFramedServerRouterProperties,
+};
+
+#[cfg(feature = "feagi-client")]
+use crate::protocol_implementations::framed_stream::{
+    FeagiFramedClientPusher, FeagiFramedClientPusherProperties, FeagiFramedClientRequester,
+    FeagiFramedClientRequesterProperties, FeagiFramedClientSubscriber,
+    FeagiFramedClientSubscriberProperties,
+};
+
+// Server implementations and properties
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerPublisher = FeagiFramedServerPublisher<UnixSocketTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerPublisherProperties =
+    FeagiFramedServerPublisherProperties<UnixSocketTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerPuller = FeagiFramedServerPuller<UnixSocketTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerPullerProperties =
+    FeagiFramedServerPullerProperties<UnixSocketTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerRouter = FeagiFramedServerRouter<UnixSocketTransport>;
+#[cfg(feature = "feagi-server")]
+pub type FeagiUnixSocketServerRouterProperties =
+    FeagiFramedServerRouterProperties<UnixSocketTransport>;
+
+// Client implementations and properties
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientSubscriber = FeagiFramedClientSubscriber<UnixSocketTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientSubscriberProperties =
+    FeagiFramedClientSubscriberProperties<UnixSocketTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientPusher = FeagiFramedClientPusher<UnixSocketTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientPusherProperties =
+    FeagiFramedClientPusherProperties<UnixSocketTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientRequester = FeagiFramedClientRequester<UnixSocketTransport>;
+#[cfg(feature = "feagi-client")]
+pub type FeagiUnixSocketClientRequesterProperties =
+    FeagiFramedClientRequesterProperties<UnixSocketTransport>;
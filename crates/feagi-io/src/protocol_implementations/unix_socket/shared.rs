@@ -0,0 +1,72 @@
+//! Shared utilities for Unix domain socket implementations.
+
+use std::path::Path;
+
+use crate::FeagiNetworkError;
+use serde::{Deserialize, Serialize};
+
+/// Longest socket path the platform socket address can hold (`sun_path`, minus the terminator)
+const MAX_SOCKET_PATH_BYTES: usize = 107;
+
+/// URL endpoint struct for Unix domain socket endpoints with validation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnixSocketUrl {
+    url: String,
+}
+
+impl UnixSocketUrl {
+    /// Creates a new UnixSocketUrl after validating the format.
+    ///
+    /// The URL will be normalized to include the `unix://` scheme if not present.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The socket URL or path (e.g., "unix:///tmp/feagi_sensory.sock", "/tmp/feagi.sock").
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is empty or too long for a socket address.
+    pub fn new(url: &str) -> Result<Self, FeagiNetworkError> {
+        let normalized = if url.starts_with("unix://") {
+            url.to_string()
+        } else {
+            format!("unix://{}", url)
+        };
+        validate_unix_socket_url(&normalized)?;
+        Ok(UnixSocketUrl { url: normalized })
+    }
+
+    /// Returns the URL as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the filesystem path of the socket.
+    pub fn path(&self) -> &Path {
+        Path::new(self.url.strip_prefix("unix://").unwrap_or(&self.url))
+    }
+}
+
+impl std::fmt::Display for UnixSocketUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+/// Validates a Unix domain socket URL format (`unix://path`).
+fn validate_unix_socket_url(url: &str) -> Result<(), FeagiNetworkError> {
+    let path = url.strip_prefix("unix://").unwrap_or(url);
+    if path.is_empty() {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid Unix socket URL '{}': empty path after unix://",
+            url
+        )));
+    }
+    if path.len() > MAX_SOCKET_PATH_BYTES {
+        return Err(FeagiNetworkError::InvalidSocketProperties(format!(
+            "Invalid Unix socket URL '{}': path is longer than {} bytes",
+            url, MAX_SOCKET_PATH_BYTES
+        )));
+    }
+    Ok(())
+}
@@ -12,6 +12,7 @@ use tungstenite::handshake::HandshakeError;
 use tungstenite::handshake::MidHandshake;
 use tungstenite::{accept, Message, WebSocket};
 
+use crate::protocol_implementations::feagi_frame::try_extract_agent_id_from_payload;
 use crate::protocol_implementations::websocket::shared::WebSocketUrl;
 use crate::traits_and_enums::server::{
     FeagiServer, FeagiServerPublisher, FeagiServerPublisherProperties, FeagiServerPuller,
@@ -21,7 +22,6 @@ use crate::traits_and_enums::shared::{
     FeagiEndpointState, TransportProtocolEndpoint, TransportProtocolImplementation,
 };
 use crate::{AgentID, FeagiNetworkError};
-use feagi_serialization::FeagiByteContainer;
 
 /// Type alias for WebSocket over TcpStream
 type WsStream = WebSocket<TcpStream>;
@@ -692,22 +692,6 @@ pub struct FeagiWebSocketServerRouter {
 }
 
 impl FeagiWebSocketServerRouter {
-    fn try_extract_agent_id_from_payload(payload: &[u8]) -> Option<AgentID> {
-        let start = FeagiByteContainer::GLOBAL_BYTE_HEADER_BYTE_COUNT;
-        let end = start + FeagiByteContainer::AGENT_ID_BYTE_COUNT;
-        if payload.len() < end {
-            return None;
-        }
-
-        let mut id_bytes = [0u8; AgentID::NUMBER_BYTES];
-        id_bytes.copy_from_slice(&payload[start..end]);
-        let parsed_id = AgentID::new(id_bytes);
-        if parsed_id.is_blank() {
-            return None;
-        }
-        Some(parsed_id)
-    }
-
     fn remap_client_session(&mut self, client_index: usize, new_session_id: AgentID) {
         let previous_session = self.index_to_session.insert(client_index, new_session_id);
         if let Some(old_session_id) = previous_session {
@@ -722,7 +706,7 @@ impl FeagiWebSocketServerRouter {
     }
 
     fn align_session_with_payload_agent_id(&mut self, client_index: usize, payload: &[u8]) {
-        if let Some(payload_agent_id) = Self::try_extract_agent_id_from_payload(payload) {
+        if let Some(payload_agent_id) = try_extract_agent_id_from_payload(payload) {
             self.remap_client_session(client_index, payload_agent_id);
         }
     }
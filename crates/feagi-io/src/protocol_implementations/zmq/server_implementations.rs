@@ -12,9 +12,11 @@
 use std::collections::HashMap;
 use std::env;
 
-use feagi_serialization::FeagiByteContainer;
 use zmq::{Context, Message, Socket};
 
+use crate::protocol_implementations::feagi_frame::{
+    has_non_empty_payload, is_plausible_feagi_frame,
+};
 use crate::protocol_implementations::zmq::shared::ZmqUrl;
 use crate::traits_and_enums::server::{
     FeagiServer, FeagiServerPublisher, FeagiServerPublisherProperties, FeagiServerPuller,
@@ -398,34 +400,6 @@ pub struct FeagiZmqServerPuller {
 }
 
 impl FeagiZmqServerPuller {
-    const MIN_FEAGI_FRAME_BYTES: usize = 12;
-    const STRUCT_LOOKUP_BYTES_PER_ENTRY: usize = 4;
-
-    /// Lightweight FEAGI frame sanity check used for latest-wins filtering.
-    ///
-    /// This avoids selecting trailing noise frames that can appear during
-    /// reconnect churn and would otherwise cause downstream decode drops.
-    fn is_plausible_feagi_frame(bytes: &[u8]) -> bool {
-        if bytes.len() < Self::MIN_FEAGI_FRAME_BYTES {
-            return false;
-        }
-        // Byte 0: FEAGI binary structure version
-        if bytes[0] != FeagiByteContainer::CURRENT_FBS_VERSION {
-            return false;
-        }
-        // Byte 3: number of structures in container header
-        let structure_count = bytes[3] as usize;
-        let min_required = Self::MIN_FEAGI_FRAME_BYTES
-            + structure_count.saturating_mul(Self::STRUCT_LOOKUP_BYTES_PER_ENTRY);
-        bytes.len() >= min_required
-    }
-
-    fn has_non_empty_payload(bytes: &[u8]) -> bool {
-        // For sensory channels, empty containers (struct_count=0) are valid protocol frames
-        // but should not eclipse meaningful sensory updates inside the same drain window.
-        bytes.get(3).copied().unwrap_or(0) > 0
-    }
-
     /// Drain all currently-queued frames and keep only the latest payload.
     ///
     /// This avoids replaying stale sensory backlog after reconnect/restart.
@@ -435,9 +409,7 @@ impl FeagiZmqServerPuller {
         let mut has_latest_non_empty_valid = false;
 
         self.socket.recv(&mut self.recv_msg, zmq::DONTWAIT)?;
-        if Self::is_plausible_feagi_frame(&self.recv_msg)
-            && Self::has_non_empty_payload(&self.recv_msg)
-        {
+        if is_plausible_feagi_frame(&self.recv_msg) && has_non_empty_payload(&self.recv_msg) {
             std::mem::swap(&mut self.recv_msg, &mut self.latest_non_empty_valid_msg);
             has_latest_non_empty_valid = true;
         }
@@ -445,8 +417,8 @@ impl FeagiZmqServerPuller {
         loop {
             match self.socket.recv(&mut self.recv_msg, zmq::DONTWAIT) {
                 Ok(()) => {
-                    if Self::is_plausible_feagi_frame(&self.recv_msg)
-                        && Self::has_non_empty_payload(&self.recv_msg)
+                    if is_plausible_feagi_frame(&self.recv_msg)
+                        && has_non_empty_payload(&self.recv_msg)
                     {
                         std::mem::swap(&mut self.recv_msg, &mut self.latest_non_empty_valid_msg);
                         has_latest_non_empty_valid = true;
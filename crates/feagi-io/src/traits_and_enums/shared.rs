@@ -3,6 +3,12 @@
 use crate::FeagiNetworkError;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "shm-transport")]
+use crate::protocol_implementations::shared_memory::SharedMemoryUrl;
+#[cfg(feature = "tcp-transport")]
+use crate::protocol_implementations::tcp::TcpUrl;
+#[cfg(all(feature = "uds-transport", unix))]
+use crate::protocol_implementations::unix_socket::UnixSocketUrl;
 #[cfg(any(
     feature = "websocket-transport-std",
     feature = "websocket-transport-wasm"
@@ -12,6 +18,21 @@ use crate::protocol_implementations::websocket::WebSocketUrl;
 use crate::protocol_implementations::zmq::ZmqUrl;
 
 // Client properties imports
+#[cfg(feature = "shm-transport")]
+use crate::protocol_implementations::shared_memory::{
+    FeagiSharedMemoryClientPusherProperties, FeagiSharedMemoryClientRequesterProperties,
+    FeagiSharedMemoryClientSubscriberProperties,
+};
+#[cfg(feature = "tcp-transport")]
+use crate::protocol_implementations::tcp::{
+    FeagiTcpClientPusherProperties, FeagiTcpClientRequesterProperties,
+    FeagiTcpClientSubscriberProperties,
+};
+#[cfg(all(feature = "uds-transport", unix))]
+use crate::protocol_implementations::unix_socket::{
+    FeagiUnixSocketClientPusherProperties, FeagiUnixSocketClientRequesterProperties,
+    FeagiUnixSocketClientSubscriberProperties,
+};
 #[cfg(feature = "websocket-transport-std")]
 use crate::protocol_implementations::websocket::websocket_std::{
     FeagiWebSocketClientPusherProperties, FeagiWebSocketClientRequesterProperties,
@@ -24,6 +45,21 @@ use crate::protocol_implementations::zmq::{
 };
 
 // Server properties imports
+#[cfg(feature = "shm-transport")]
+use crate::protocol_implementations::shared_memory::{
+    FeagiSharedMemoryServerPublisherProperties, FeagiSharedMemoryServerPullerProperties,
+    FeagiSharedMemoryServerRouterProperties,
+};
+#[cfg(feature = "tcp-transport")]
+use crate::protocol_implementations::tcp::{
+    FeagiTcpServerPublisherProperties, FeagiTcpServerPullerProperties,
+    FeagiTcpServerRouterProperties,
+};
+#[cfg(all(feature = "uds-transport", unix))]
+use crate::protocol_implementations::unix_socket::{
+    FeagiUnixSocketServerPublisherProperties, FeagiUnixSocketServerPullerProperties,
+    FeagiUnixSocketServerRouterProperties,
+};
 #[cfg(feature = "websocket-transport-std")]
 use crate::protocol_implementations::websocket::websocket_std::{
     FeagiWebSocketServerPublisherProperties, FeagiWebSocketServerPullerProperties,
@@ -128,8 +164,14 @@ pub enum TransportProtocolImplementation {
     Zmq,
     BluetoothSerial,
     SharedMemory,
+    Tcp,
+    UnixSocket,
 }
 
+/// Address of an endpoint on one of the transports compiled into this build.
+///
+/// Every implemented [`TransportProtocolImplementation`] has a variant, except
+/// `BluetoothSerial`, which has no transport implementation yet.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransportProtocolEndpoint {
     #[cfg(any(
@@ -139,14 +181,35 @@ pub enum TransportProtocolEndpoint {
     WebSocket(WebSocketUrl),
 
     #[cfg(feature = "zmq-transport")]
-    Zmq(ZmqUrl), // TODO other implementations
+    Zmq(ZmqUrl),
+
+    #[cfg(feature = "tcp-transport")]
+    Tcp(TcpUrl),
+
+    #[cfg(all(feature = "uds-transport", unix))]
+    UnixSocket(UnixSocketUrl),
+    #[cfg(feature = "shm-transport")]
+    SharedMemory(SharedMemoryUrl),
 }
 
 impl From<TransportProtocolEndpoint> for TransportProtocolImplementation {
     fn from(t: TransportProtocolEndpoint) -> Self {
         match t {
+            #[cfg(feature = "zmq-transport")]
             TransportProtocolEndpoint::Zmq(_) => TransportProtocolImplementation::Zmq,
+            #[cfg(any(
+                feature = "websocket-transport-std",
+                feature = "websocket-transport-wasm"
+            ))]
             TransportProtocolEndpoint::WebSocket(_) => TransportProtocolImplementation::WebSocket,
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(_) => TransportProtocolImplementation::Tcp,
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(_) => TransportProtocolImplementation::UnixSocket,
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(_) => {
+                TransportProtocolImplementation::SharedMemory
+            }
         }
     }
 }
@@ -177,6 +240,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => {
                 Box::new(FeagiZmqClientSubscriberProperties::new(endpoint.as_str()).unwrap())
             }
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => {
+                Box::new(FeagiTcpClientSubscriberProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => {
+                Box::new(FeagiUnixSocketClientSubscriberProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => Box::new(
+                FeagiSharedMemoryClientSubscriberProperties::new(endpoint.as_str()).unwrap(),
+            ),
         }
     }
 
@@ -209,6 +284,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => Ok(Box::new(
                 FeagiZmqClientSubscriberProperties::new(endpoint.as_str())?,
             )),
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => Ok(Box::new(
+                FeagiTcpClientSubscriberProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => Ok(Box::new(
+                FeagiUnixSocketClientSubscriberProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => Ok(Box::new(
+                FeagiSharedMemoryClientSubscriberProperties::new(endpoint.as_str())?,
+            )),
         }
     }
 
@@ -231,6 +318,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => {
                 Box::new(FeagiZmqClientPusherProperties::new(endpoint.as_str()).unwrap())
             }
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => {
+                Box::new(FeagiTcpClientPusherProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => {
+                Box::new(FeagiUnixSocketClientPusherProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => {
+                Box::new(FeagiSharedMemoryClientPusherProperties::new(endpoint.as_str()).unwrap())
+            }
         }
     }
 
@@ -263,6 +362,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => Ok(Box::new(
                 FeagiZmqClientPusherProperties::new(endpoint.as_str())?,
             )),
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => Ok(Box::new(
+                FeagiTcpClientPusherProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => Ok(Box::new(
+                FeagiUnixSocketClientPusherProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => Ok(Box::new(
+                FeagiSharedMemoryClientPusherProperties::new(endpoint.as_str())?,
+            )),
         }
     }
 
@@ -287,6 +398,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => {
                 Box::new(FeagiZmqClientRequesterProperties::new(endpoint.as_str()).unwrap())
             }
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => {
+                Box::new(FeagiTcpClientRequesterProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => {
+                Box::new(FeagiUnixSocketClientRequesterProperties::new(endpoint.as_str()).unwrap())
+            }
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => Box::new(
+                FeagiSharedMemoryClientRequesterProperties::new(endpoint.as_str()).unwrap(),
+            ),
         }
     }
 
@@ -319,6 +442,18 @@ impl TransportProtocolEndpoint {
             TransportProtocolEndpoint::Zmq(endpoint) => Ok(Box::new(
                 FeagiZmqClientRequesterProperties::new(endpoint.as_str())?,
             )),
+            #[cfg(feature = "tcp-transport")]
+            TransportProtocolEndpoint::Tcp(endpoint) => Ok(Box::new(
+                FeagiTcpClientRequesterProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(all(feature = "uds-transport", unix))]
+            TransportProtocolEndpoint::UnixSocket(endpoint) => Ok(Box::new(
+                FeagiUnixSocketClientRequesterProperties::new(endpoint.as_str())?,
+            )),
+            #[cfg(feature = "shm-transport")]
+            TransportProtocolEndpoint::SharedMemory(endpoint) => Ok(Box::new(
+                FeagiSharedMemoryClientRequesterProperties::new(endpoint.as_str())?,
+            )),
         }
     }
 
@@ -363,6 +498,42 @@ pub fn create_default_boxed_server_publisher_properties(
                 "Server bind and Agent remote cannot use different protocols!".to_string(),
             )),
         },
+        #[cfg(feature = "tcp-transport")]
+        TransportProtocolEndpoint::Tcp(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::Tcp(agent_remote) => {
+                Ok(Box::new(FeagiTcpServerPublisherProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(all(feature = "uds-transport", unix))]
+        TransportProtocolEndpoint::UnixSocket(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::UnixSocket(agent_remote) => {
+                Ok(Box::new(FeagiUnixSocketServerPublisherProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(feature = "shm-transport")]
+        TransportProtocolEndpoint::SharedMemory(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::SharedMemory(agent_remote) => {
+                Ok(Box::new(FeagiSharedMemoryServerPublisherProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
     }
 }
 
@@ -395,6 +566,39 @@ pub fn create_default_boxed_server_puller_properties(
                 "Server bind and Agent remote cannot use different protocols!".to_string(),
             )),
         },
+        #[cfg(feature = "tcp-transport")]
+        TransportProtocolEndpoint::Tcp(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::Tcp(agent_remote) => Ok(Box::new(
+                FeagiTcpServerPullerProperties::new(server_bind.as_str(), agent_remote.as_str())?,
+            )),
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(all(feature = "uds-transport", unix))]
+        TransportProtocolEndpoint::UnixSocket(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::UnixSocket(agent_remote) => {
+                Ok(Box::new(FeagiUnixSocketServerPullerProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(feature = "shm-transport")]
+        TransportProtocolEndpoint::SharedMemory(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::SharedMemory(agent_remote) => {
+                Ok(Box::new(FeagiSharedMemoryServerPullerProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
     }
 }
 
@@ -427,5 +631,38 @@ pub fn create_default_boxed_server_router_properties(
                 "Server bind and Agent remote cannot use different protocols!".to_string(),
             )),
         },
+        #[cfg(feature = "tcp-transport")]
+        TransportProtocolEndpoint::Tcp(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::Tcp(agent_remote) => Ok(Box::new(
+                FeagiTcpServerRouterProperties::new(server_bind.as_str(), agent_remote.as_str())?,
+            )),
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(all(feature = "uds-transport", unix))]
+        TransportProtocolEndpoint::UnixSocket(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::UnixSocket(agent_remote) => {
+                Ok(Box::new(FeagiUnixSocketServerRouterProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
+        #[cfg(feature = "shm-transport")]
+        TransportProtocolEndpoint::SharedMemory(server_bind) => match agent_remote {
+            TransportProtocolEndpoint::SharedMemory(agent_remote) => {
+                Ok(Box::new(FeagiSharedMemoryServerRouterProperties::new(
+                    server_bind.as_str(),
+                    agent_remote.as_str(),
+                )?))
+            }
+            _ => Err(FeagiNetworkError::InvalidSocketProperties(
+                "Server bind and Agent remote cannot use different protocols!".to_string(),
+            )),
+        },
     }
 }
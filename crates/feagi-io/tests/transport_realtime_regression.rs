@@ -3,7 +3,12 @@
 //! These tests run real client/server sockets (no mocks) to guard against
 //! startup backlog replay and malformed-frame interference regressions.
 
-#![cfg(feature = "zmq-transport")]
+#![cfg(any(
+    feature = "zmq-transport",
+    feature = "tcp-transport",
+    all(feature = "uds-transport", unix),
+    feature = "shm-transport"
+))]
 #![allow(clippy::manual_is_multiple_of)]
 
 use std::env;
@@ -11,13 +16,38 @@ use std::net::TcpListener;
 use std::thread;
 use std::time::{Duration, Instant};
 
+#[cfg(feature = "shm-transport")]
+use feagi_io::protocol_implementations::shared_memory::{
+    FeagiSharedMemoryClientPusherProperties, FeagiSharedMemoryClientRequesterProperties,
+    FeagiSharedMemoryClientSubscriberProperties, FeagiSharedMemoryServerPublisherProperties,
+    FeagiSharedMemoryServerPullerProperties, FeagiSharedMemoryServerRouterProperties,
+    SharedMemoryUrl,
+};
+#[cfg(feature = "tcp-transport")]
+use feagi_io::protocol_implementations::tcp::{
+    FeagiTcpClientPusherProperties, FeagiTcpClientRequesterProperties,
+    FeagiTcpClientSubscriberProperties, FeagiTcpServerPublisherProperties,
+    FeagiTcpServerPullerProperties, FeagiTcpServerRouterProperties, TcpUrl,
+};
+#[cfg(all(feature = "uds-transport", unix))]
+use feagi_io::protocol_implementations::unix_socket::{
+    FeagiUnixSocketClientPusherProperties, FeagiUnixSocketClientRequesterProperties,
+    FeagiUnixSocketClientSubscriberProperties, FeagiUnixSocketServerPublisherProperties,
+    FeagiUnixSocketServerPullerProperties, FeagiUnixSocketServerRouterProperties,
+};
+#[cfg(feature = "zmq-transport")]
 use feagi_io::protocol_implementations::zmq::{
     FeagiZmqClientPusherProperties, FeagiZmqServerPullerProperties,
 };
-use feagi_io::traits_and_enums::client::FeagiClientPusherProperties;
-use feagi_io::traits_and_enums::server::FeagiServerPullerProperties;
+use feagi_io::traits_and_enums::client::{
+    FeagiClientPusherProperties, FeagiClientRequesterProperties, FeagiClientSubscriberProperties,
+};
+use feagi_io::traits_and_enums::server::{
+    FeagiServerPublisherProperties, FeagiServerPullerProperties, FeagiServerRouterProperties,
+};
 use feagi_io::traits_and_enums::shared::FeagiEndpointState;
-use feagi_serialization::FeagiByteContainer;
+use feagi_io::AgentID;
+use feagi_serialization::{FeagiByteContainer, PayloadCompression, PayloadCompressor};
 use feagi_structures::FeagiJSON;
 
 fn reserve_free_tcp_port() -> u16 {
@@ -78,6 +108,7 @@ fn read_soak_secs_from_env_or_default(default_secs: u64) -> u64 {
     }
 }
 
+#[cfg(feature = "zmq-transport")]
 #[test]
 fn zmq_puller_keeps_latest_valid_frame_in_burst_with_noise() {
     let port = reserve_free_tcp_port();
@@ -139,6 +170,7 @@ fn zmq_puller_keeps_latest_valid_frame_in_burst_with_noise() {
     );
 }
 
+#[cfg(feature = "zmq-transport")]
 #[test]
 fn zmq_stream_stays_responsive_and_fresh_under_sustained_noise() {
     let port = reserve_free_tcp_port();
@@ -261,6 +293,7 @@ fn zmq_stream_stays_responsive_and_fresh_under_sustained_noise() {
     );
 }
 
+#[cfg(feature = "zmq-transport")]
 #[test]
 fn zmq_puller_prefers_non_empty_sensory_frame_over_empty_container_in_same_drain() {
     let port = reserve_free_tcp_port();
@@ -320,6 +353,7 @@ fn zmq_puller_prefers_non_empty_sensory_frame_over_empty_container_in_same_drain
     );
 }
 
+#[cfg(feature = "zmq-transport")]
 #[test]
 fn zmq_stream_soak_detects_blackout_or_degradation_windows() {
     // Keep this in standard test flow. Allow override for deeper local soak runs.
@@ -451,3 +485,609 @@ fn zmq_stream_soak_detects_blackout_or_degradation_windows() {
         freshness_delta
     );
 }
+
+// ============================================================================
+// Framed stream and shared memory transports
+// ============================================================================
+
+fn make_frame_from_agent(agent_id: AgentID, counter: u16) -> Vec<u8> {
+    let mut container = FeagiByteContainer::new_empty();
+    let payload = FeagiJSON::from_json_value(serde_json::json!({
+        "kind": "request",
+        "counter": counter
+    }));
+    container
+        .overwrite_byte_data_with_single_struct_data(&payload, counter)
+        .expect("Failed to build non-empty FEAGI frame");
+    container
+        .set_agent_identifier(agent_id)
+        .expect("Failed to stamp agent ID");
+    container.get_byte_ref().to_vec()
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+fn unique_socket_path(name: &str) -> String {
+    let path = env::temp_dir().join(format!("feagi_io_{}_{}.sock", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path.to_string_lossy().into_owned()
+}
+
+#[cfg(feature = "shm-transport")]
+fn unique_ring_path(name: &str) -> String {
+    let path = env::temp_dir().join(format!("feagi_io_{}_{}.ring", name, std::process::id()));
+    path.to_string_lossy().into_owned()
+}
+
+fn assert_framed_puller_keeps_latest_non_empty_frame(
+    server_props: &dyn FeagiServerPullerProperties,
+    client_props: &dyn FeagiClientPusherProperties,
+) {
+    let mut server = server_props.as_boxed_server_puller();
+    server
+        .request_start()
+        .expect("Failed to start server puller");
+    let mut client = client_props.as_boxed_client_pusher();
+    client
+        .request_connect()
+        .expect("Failed to request client connect");
+
+    for marker in 0u8..80u8 {
+        client
+            .publish_data(&make_valid_frame_with_marker(marker))
+            .expect("Failed to push valid frame");
+        // Interleave malformed short frames and empty containers to emulate transport noise.
+        client
+            .publish_data(&[0xAA, 0xBB, marker])
+            .expect("Failed to push noise frame");
+        client
+            .publish_data(&make_empty_valid_container())
+            .expect("Failed to push empty frame");
+    }
+    // Let the whole burst reach the server socket so a single drain sees all of it
+    thread::sleep(Duration::from_millis(50));
+
+    wait_until(Duration::from_secs(2), || {
+        matches!(server.poll(), FeagiEndpointState::ActiveHasData)
+    });
+    let consumed = server
+        .consume_retrieved_data()
+        .expect("Server failed to consume retrieved data");
+    assert!(consumed.len() > 12, "Expected a non-empty FEAGI frame");
+    assert_eq!(consumed[1], 79, "Expected the latest valid marker");
+
+    // The backlog was drained, so nothing stale is replayed
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(server.poll(), &FeagiEndpointState::ActiveWaiting);
+
+    server.request_stop().expect("Failed to stop server puller");
+}
+
+fn assert_framed_publisher_broadcasts_in_order(
+    server_props: &dyn FeagiServerPublisherProperties,
+    client_props: &dyn FeagiClientSubscriberProperties,
+) {
+    let mut server = server_props.as_boxed_server_publisher();
+    server
+        .request_start()
+        .expect("Failed to start server publisher");
+    let mut subscribers = [
+        client_props.as_boxed_client_subscriber(),
+        client_props.as_boxed_client_subscriber(),
+    ];
+    for subscriber in subscribers.iter_mut() {
+        subscriber
+            .request_connect()
+            .expect("Failed to request subscriber connect");
+    }
+    // Accept both subscribers before broadcasting
+    thread::sleep(Duration::from_millis(20));
+    server.poll();
+
+    for counter in 0u16..5u16 {
+        server
+            .publish_data(&make_valid_frame_with_counter(counter))
+            .expect("Failed to publish frame");
+    }
+
+    for subscriber in subscribers.iter_mut() {
+        for expected in 0u16..5u16 {
+            wait_until(Duration::from_secs(2), || {
+                matches!(subscriber.poll(), FeagiEndpointState::ActiveHasData)
+            });
+            let data = subscriber
+                .consume_retrieved_data()
+                .expect("Subscriber failed to consume data");
+            assert_eq!(counter_from_frame(data), expected);
+        }
+    }
+
+    // A subscriber that went away is dropped without disturbing the publisher
+    subscribers[0]
+        .request_disconnect()
+        .expect("Failed to disconnect subscriber");
+    for counter in 5u16..10u16 {
+        server
+            .publish_data(&make_valid_frame_with_counter(counter))
+            .expect("Publishing must survive a departed subscriber");
+    }
+    wait_until(Duration::from_secs(2), || {
+        matches!(subscribers[1].poll(), FeagiEndpointState::ActiveHasData)
+    });
+    let data = subscribers[1]
+        .consume_retrieved_data()
+        .expect("Remaining subscriber failed to consume data");
+    assert_eq!(counter_from_frame(data), 5);
+
+    server
+        .request_stop()
+        .expect("Failed to stop server publisher");
+}
+
+fn assert_framed_router_routes_responses_by_agent(
+    server_props: &dyn FeagiServerRouterProperties,
+    client_props: &dyn FeagiClientRequesterProperties,
+) {
+    let mut server = server_props.as_boxed_server_router();
+    server
+        .request_start()
+        .expect("Failed to start server router");
+
+    let agents = [AgentID::new([1; 8]), AgentID::new([2; 8])];
+    let mut requesters = [
+        client_props.as_boxed_client_requester(),
+        client_props.as_boxed_client_requester(),
+    ];
+    for (i, requester) in requesters.iter_mut().enumerate() {
+        requester
+            .request_connect()
+            .expect("Failed to request requester connect");
+        requester
+            .publish_request(&make_frame_from_agent(agents[i], i as u16))
+            .expect("Failed to send request");
+    }
+
+    // Echo every request back with the counter shifted, addressed by session
+    for _ in 0..agents.len() {
+        wait_until(Duration::from_secs(2), || {
+            matches!(server.poll(), FeagiEndpointState::ActiveHasData)
+        });
+        let (session_id, request) = server
+            .consume_retrieved_request()
+            .expect("Router failed to consume request");
+        assert!(
+            agents.contains(&session_id),
+            "Session should follow the agent ID in the request header"
+        );
+        let counter = counter_from_frame(request);
+        server
+            .publish_response(
+                session_id,
+                &make_frame_from_agent(session_id, counter + 100),
+            )
+            .expect("Failed to send response");
+    }
+
+    for (i, requester) in requesters.iter_mut().enumerate() {
+        wait_until(Duration::from_secs(2), || {
+            matches!(requester.poll(), FeagiEndpointState::ActiveHasData)
+        });
+        let response = requester
+            .consume_retrieved_response()
+            .expect("Requester failed to consume response");
+        assert_eq!(counter_from_frame(response), i as u16 + 100);
+    }
+
+    server.request_stop().expect("Failed to stop server router");
+}
+
+#[cfg(feature = "tcp-transport")]
+#[test]
+fn tcp_puller_keeps_latest_valid_frame_in_burst_with_noise() {
+    let endpoint = format!("tcp://127.0.0.1:{}", reserve_free_tcp_port());
+    assert_framed_puller_keeps_latest_non_empty_frame(
+        &FeagiTcpServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiTcpClientPusherProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "tcp-transport")]
+#[test]
+fn tcp_publisher_broadcasts_to_all_subscribers_in_order() {
+    let endpoint = format!("tcp://127.0.0.1:{}", reserve_free_tcp_port());
+    assert_framed_publisher_broadcasts_in_order(
+        &FeagiTcpServerPublisherProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiTcpClientSubscriberProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "tcp-transport")]
+#[test]
+fn tcp_router_routes_responses_to_requesting_agent() {
+    let endpoint = format!("tcp://127.0.0.1:{}", reserve_free_tcp_port());
+    assert_framed_router_routes_responses_by_agent(
+        &FeagiTcpServerRouterProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiTcpClientRequesterProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "tcp-transport")]
+#[test]
+fn tcp_endpoint_creates_matching_properties() {
+    use feagi_io::traits_and_enums::shared::{
+        create_default_boxed_server_puller_properties, TransportProtocolEndpoint,
+        TransportProtocolImplementation,
+    };
+
+    let port = reserve_free_tcp_port();
+    let bind = TransportProtocolEndpoint::Tcp(TcpUrl::new(&format!("*:{port}")).unwrap());
+    let remote =
+        TransportProtocolEndpoint::Tcp(TcpUrl::new(&format!("tcp://127.0.0.1:{port}")).unwrap());
+
+    let server_props = create_default_boxed_server_puller_properties(bind, remote.clone())
+        .expect("Failed to create TCP puller properties from endpoints");
+    assert_eq!(
+        server_props.get_protocol(),
+        TransportProtocolImplementation::Tcp
+    );
+    assert_eq!(server_props.get_agent_endpoint(), remote);
+
+    let client_props = remote
+        .try_create_boxed_client_pusher_properties()
+        .expect("Failed to create TCP pusher properties from endpoint");
+    assert_eq!(client_props.get_endpoint_target(), remote);
+    assert_framed_puller_keeps_latest_non_empty_frame(server_props.as_ref(), client_props.as_ref());
+
+    assert!(TcpUrl::new("tcp://127.0.0.1").is_err());
+    assert!(TcpUrl::new("tcp://127.0.0.1:notaport").is_err());
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+#[test]
+fn uds_puller_keeps_latest_valid_frame_in_burst_with_noise() {
+    let endpoint = format!("unix://{}", unique_socket_path("puller"));
+    assert_framed_puller_keeps_latest_non_empty_frame(
+        &FeagiUnixSocketServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiUnixSocketClientPusherProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+#[test]
+fn uds_publisher_broadcasts_to_all_subscribers_in_order() {
+    let endpoint = unique_socket_path("publisher");
+    assert_framed_publisher_broadcasts_in_order(
+        &FeagiUnixSocketServerPublisherProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiUnixSocketClientSubscriberProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+#[test]
+fn uds_router_routes_responses_to_requesting_agent() {
+    let endpoint = unique_socket_path("router");
+    assert_framed_router_routes_responses_by_agent(
+        &FeagiUnixSocketServerRouterProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiUnixSocketClientRequesterProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+#[test]
+fn uds_server_replaces_stale_socket_file_and_cleans_up_on_stop() {
+    let path = unique_socket_path("stale");
+    // A socket file left behind by a crashed server
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    assert!(std::path::Path::new(&path).exists());
+
+    let server_props = FeagiUnixSocketServerPullerProperties::new(&path, &path).unwrap();
+    let mut server = server_props.as_boxed_server_puller();
+    server
+        .request_start()
+        .expect("Server should replace a stale socket file");
+
+    // A second server must not steal the socket of a live one
+    let mut second = server_props.as_boxed_server_puller();
+    assert!(second.request_start().is_err());
+
+    server.request_stop().expect("Failed to stop server puller");
+    assert!(!std::path::Path::new(&path).exists());
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_puller_keeps_latest_valid_frame_in_burst_with_noise() {
+    let endpoint = format!("shm://{}", unique_ring_path("puller"));
+    assert_framed_puller_keeps_latest_non_empty_frame(
+        &FeagiSharedMemoryServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiSharedMemoryClientPusherProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_publisher_broadcasts_to_all_subscribers_in_order() {
+    let endpoint = unique_ring_path("publisher");
+    assert_framed_publisher_broadcasts_in_order(
+        &FeagiSharedMemoryServerPublisherProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiSharedMemoryClientSubscriberProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_router_routes_responses_to_requesting_agent() {
+    let endpoint = unique_ring_path("router");
+    assert_framed_router_routes_responses_by_agent(
+        &FeagiSharedMemoryServerRouterProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiSharedMemoryClientRequesterProperties::new(&endpoint).unwrap(),
+    );
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_endpoint_creates_matching_properties() {
+    use feagi_io::traits_and_enums::shared::{
+        create_default_boxed_server_puller_properties, TransportProtocolEndpoint,
+        TransportProtocolImplementation,
+    };
+
+    let endpoint = TransportProtocolEndpoint::SharedMemory(
+        SharedMemoryUrl::new(&unique_ring_path("endpoint")).unwrap(),
+    );
+    let server_props =
+        create_default_boxed_server_puller_properties(endpoint.clone(), endpoint.clone())
+            .expect("Failed to create shared memory puller properties from endpoints");
+    assert_eq!(
+        server_props.get_protocol(),
+        TransportProtocolImplementation::SharedMemory
+    );
+    assert_eq!(server_props.get_agent_endpoint(), endpoint);
+
+    let client_props = endpoint
+        .try_create_boxed_client_pusher_properties()
+        .expect("Failed to create shared memory pusher properties from endpoint");
+    assert_eq!(client_props.get_endpoint_target(), endpoint);
+    assert_framed_puller_keeps_latest_non_empty_frame(server_props.as_ref(), client_props.as_ref());
+
+    assert!(SharedMemoryUrl::new("shm://").is_err());
+    assert!(SharedMemoryUrl::new(&env::temp_dir().to_string_lossy()).is_err());
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_clients_see_server_stop_and_ring_is_removed() {
+    let path = unique_ring_path("stop");
+    let server_props =
+        FeagiSharedMemoryServerPublisherProperties::new_with_ring_size(&path, &path, 4, 1024)
+            .unwrap();
+    let client_props = FeagiSharedMemoryClientSubscriberProperties::new(&path).unwrap();
+
+    // Nothing to map before the server runs
+    let mut subscriber = client_props.as_boxed_client_subscriber();
+    assert!(subscriber.request_connect().is_err());
+
+    let mut server = server_props.as_boxed_server_publisher();
+    server
+        .request_start()
+        .expect("Failed to start server publisher");
+    subscriber
+        .request_connect()
+        .expect("Failed to map the publisher ring");
+
+    // Frames over the slot size are refused instead of truncated
+    assert!(server.publish_data(&[0u8; 2048]).is_err());
+
+    // A subscriber that fell more than a ring behind resumes at the oldest frame kept
+    for counter in 0u16..10u16 {
+        server
+            .publish_data(&make_valid_frame_with_counter(counter))
+            .expect("Failed to publish frame");
+    }
+    assert_eq!(subscriber.poll(), &FeagiEndpointState::ActiveHasData);
+    let data = subscriber
+        .consume_retrieved_data()
+        .expect("Subscriber failed to consume data");
+    assert_eq!(counter_from_frame(data), 6);
+
+    server
+        .request_stop()
+        .expect("Failed to stop server publisher");
+    assert!(!std::path::Path::new(&path).exists());
+
+    // Frames already in the ring are still delivered before the close is reported
+    for _ in 7u16..10u16 {
+        assert_eq!(subscriber.poll(), &FeagiEndpointState::ActiveHasData);
+        subscriber.consume_retrieved_data().unwrap();
+    }
+    assert!(matches!(subscriber.poll(), FeagiEndpointState::Errored(_)));
+    subscriber
+        .confirm_error_and_close()
+        .expect("Failed to acknowledge closed server");
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_router_receives_every_request_from_concurrent_writers() {
+    const WRITER_COUNT: u8 = 4;
+    const REQUESTS_PER_WRITER: u16 = 200;
+
+    let path = unique_ring_path("concurrent");
+    // Large enough that no request is overwritten before it is read
+    let server_props =
+        FeagiSharedMemoryServerRouterProperties::new_with_ring_size(&path, &path, 1024, 4096)
+            .unwrap();
+    let mut server = server_props.as_boxed_server_router();
+    server
+        .request_start()
+        .expect("Failed to start server router");
+
+    let writers: Vec<_> = (1..=WRITER_COUNT)
+        .map(|writer| {
+            let client_props = FeagiSharedMemoryClientRequesterProperties::new(&path).unwrap();
+            thread::spawn(move || {
+                let mut requester = client_props.as_boxed_client_requester();
+                requester
+                    .request_connect()
+                    .expect("Failed to request requester connect");
+                let agent_id = AgentID::new([writer; 8]);
+                for counter in 0..REQUESTS_PER_WRITER {
+                    requester
+                        .publish_request(&make_frame_from_agent(agent_id, counter))
+                        .expect("Failed to send request");
+                }
+            })
+        })
+        .collect();
+
+    let mut next_expected = [0u16; WRITER_COUNT as usize];
+    let total = WRITER_COUNT as usize * REQUESTS_PER_WRITER as usize;
+    let mut received = 0usize;
+    let start = Instant::now();
+    while received < total && start.elapsed() < Duration::from_secs(10) {
+        if !matches!(server.poll(), FeagiEndpointState::ActiveHasData) {
+            thread::yield_now();
+            continue;
+        }
+        let (session_id, request) = server
+            .consume_retrieved_request()
+            .expect("Router failed to consume request");
+        let writer = session_id.bytes()[0] as usize - 1;
+        assert_eq!(session_id, AgentID::new([writer as u8 + 1; 8]));
+        assert_eq!(
+            counter_from_frame(request),
+            next_expected[writer],
+            "Requests of one writer must arrive complete and in order"
+        );
+        next_expected[writer] += 1;
+        received += 1;
+    }
+    for writer in writers {
+        writer.join().expect("Writer thread panicked");
+    }
+
+    assert_eq!(received, total, "Every request should have been received");
+    server.request_stop().expect("Failed to stop server router");
+}
+
+fn make_compressible_frame_with_counter(counter: u16) -> Vec<u8> {
+    let mut container = FeagiByteContainer::new_empty();
+    let payload = FeagiJSON::from_json_value(serde_json::json!({
+        "kind": "sensory",
+        "counter": counter,
+        "channels": vec![0u8; 512]
+    }));
+    container
+        .overwrite_byte_data_with_single_struct_data(&payload, counter)
+        .expect("Failed to build compressible FEAGI frame");
+    container.get_byte_ref().to_vec()
+}
+
+/// Negotiated sensory compression wraps frames in an envelope that does not start with the
+/// container version; pullers must still deliver it so the receiver can inflate it.
+fn assert_puller_delivers_compressed_sensory_frame(
+    server_props: &dyn FeagiServerPullerProperties,
+    client_props: &dyn FeagiClientPusherProperties,
+    compression: PayloadCompression,
+) {
+    let mut server = server_props.as_boxed_server_puller();
+    server
+        .request_start()
+        .expect("Failed to start server puller");
+    let mut client = client_props.as_boxed_client_pusher();
+    client
+        .request_connect()
+        .expect("Failed to request client connect");
+    wait_until(Duration::from_secs(2), || {
+        matches!(
+            client.poll(),
+            FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData
+        )
+    });
+    thread::sleep(Duration::from_millis(50));
+
+    let raw = make_compressible_frame_with_counter(0x0C7C);
+    let mut compressor = PayloadCompressor::new(compression, 100);
+    let compressed = compressor
+        .compress(&raw)
+        .expect("Failed to compress sensory frame")
+        .to_vec();
+    assert!(
+        compressed.len() < raw.len(),
+        "Expected the frame to be sent compressed"
+    );
+    client
+        .publish_data(&compressed)
+        .expect("Failed to push compressed frame");
+    // Trailing noise must not eclipse the compressed frame
+    client
+        .publish_data(&[0xAA, 0xBB, 0xCC])
+        .expect("Failed to push noise frame");
+    client
+        .publish_data(&make_empty_valid_container())
+        .expect("Failed to push empty frame");
+
+    wait_until(Duration::from_secs(2), || {
+        matches!(server.poll(), FeagiEndpointState::ActiveHasData)
+    });
+    let consumed = server
+        .consume_retrieved_data()
+        .expect("Server failed to consume retrieved data")
+        .to_vec();
+    assert_eq!(
+        consumed, compressed,
+        "Expected the compressed frame as sent"
+    );
+
+    let mut received = FeagiByteContainer::new_empty();
+    let arrived_with = received
+        .try_write_possibly_compressed_data_by_copy_and_verify(&consumed)
+        .expect("Compressed sensory frame did not decode");
+    assert_eq!(arrived_with, compression);
+    assert_eq!(received.get_byte_ref(), raw.as_slice());
+
+    server.request_stop().expect("Failed to stop server puller");
+}
+
+#[cfg(feature = "zmq-transport")]
+#[test]
+fn zmq_puller_delivers_compressed_sensory_frames() {
+    let endpoint = format!("tcp://127.0.0.1:{}", reserve_free_tcp_port());
+    assert_puller_delivers_compressed_sensory_frame(
+        &FeagiZmqServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiZmqClientPusherProperties::new(&endpoint).unwrap(),
+        PayloadCompression::Lz4,
+    );
+}
+
+#[cfg(feature = "tcp-transport")]
+#[test]
+fn tcp_puller_delivers_compressed_sensory_frames() {
+    let endpoint = format!("tcp://127.0.0.1:{}", reserve_free_tcp_port());
+    assert_puller_delivers_compressed_sensory_frame(
+        &FeagiTcpServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiTcpClientPusherProperties::new(&endpoint).unwrap(),
+        PayloadCompression::Zstd,
+    );
+}
+
+#[cfg(feature = "shm-transport")]
+#[test]
+fn shm_puller_delivers_compressed_sensory_frames() {
+    let endpoint = format!("shm://{}", unique_ring_path("compressed_puller"));
+    assert_puller_delivers_compressed_sensory_frame(
+        &FeagiSharedMemoryServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiSharedMemoryClientPusherProperties::new(&endpoint).unwrap(),
+        PayloadCompression::Lz4,
+    );
+}
+
+#[cfg(all(feature = "uds-transport", unix))]
+#[test]
+fn uds_puller_delivers_compressed_sensory_frames() {
+    let endpoint = format!("unix://{}", unique_socket_path("compressed_puller"));
+    assert_puller_delivers_compressed_sensory_frame(
+        &FeagiUnixSocketServerPullerProperties::new(&endpoint, &endpoint).unwrap(),
+        &FeagiUnixSocketClientPusherProperties::new(&endpoint).unwrap(),
+        PayloadCompression::Zstd,
+    );
+}
@@ -0,0 +1,189 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Burst Parallelism Benchmarks
+//!
+//! Purpose:
+//! - Pick `BackendConfig::cpu_parallel_synapse_threshold` for the target hardware.
+//! - Neural dynamics: serial vs partitioned across pool threads, by fire candidate count.
+//! - Synaptic propagation: rayon's own splitting (threshold unset) vs a minimum
+//!   task length, by synapse count.
+//!
+//! Notes:
+//! - The partitioned pool uses every available core (at least 2).
+//! - Results depend on the core count; run on the target hardware.
+
+use std::time::Duration;
+
+use ahash::AHashMap;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use feagi_npu_burst_engine::backend::BackendConfig;
+use feagi_npu_burst_engine::neural_dynamics::process_neural_dynamics_partitioned;
+use feagi_npu_burst_engine::parallelism::BurstParallelism;
+use feagi_npu_burst_engine::synaptic_propagation::SynapticPropagationEngine;
+use feagi_npu_neural::synapse::SynapseType;
+use feagi_npu_neural::types::{FireCandidateList, NeuronId};
+use feagi_npu_runtime::std_impl::{NeuronArray, SynapseArray};
+use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+fn pool_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1)
+        .max(2)
+}
+
+fn parallelism(cpu_threads: usize, cpu_parallel_synapse_threshold: usize) -> BurstParallelism {
+    BurstParallelism::from_config(&BackendConfig {
+        cpu_threads,
+        cpu_parallel_synapse_threshold,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+fn create_neurons(neuron_count: usize) -> NeuronArray<f32> {
+    let mut neuron_array = NeuronArray::new(neuron_count);
+    for i in 0..neuron_count {
+        neuron_array.membrane_potentials.push(0.0);
+        neuron_array.thresholds.push(10.0 + (i % 5) as f32);
+        neuron_array.leak_coefficients.push(0.1);
+        neuron_array.resting_potentials.push(0.0);
+        neuron_array.neuron_types.push(0);
+        neuron_array.refractory_periods.push(0);
+        neuron_array.refractory_countdowns.push(0);
+        neuron_array.excitabilities.push(1.0);
+        neuron_array.consecutive_fire_counts.push(0);
+        neuron_array.consecutive_fire_limits.push(u16::MAX);
+        neuron_array.snooze_periods.push(0);
+        neuron_array.mp_charge_accumulation.push(true);
+        neuron_array.cortical_areas.push(0);
+        neuron_array.coordinates.extend_from_slice(&[0, 0, 0]);
+        neuron_array.valid_mask.push(true);
+    }
+    neuron_array.count = neuron_count;
+    neuron_array
+}
+
+fn bench_neural_dynamics(c: &mut Criterion) {
+    let mut group = c.benchmark_group("burst_parallelism_dynamics");
+
+    let neuron_count = 1_000_000;
+    let mut neuron_array = create_neurons(neuron_count);
+    let partitioned = parallelism(pool_threads(), 1);
+
+    for candidate_count in [256, 1_024, 4_096, 16_384, 65_536, 262_144] {
+        // Spread candidates over the ID space, as a burst touching many areas would
+        let stride = neuron_count / candidate_count;
+        let mut fcl = FireCandidateList::new();
+        for i in 0..candidate_count {
+            fcl.add_candidate(NeuronId((i * stride) as u32), 6.0);
+        }
+
+        group.throughput(Throughput::Elements(candidate_count as u64));
+        for (name, parallelism) in [("serial", None), ("partitioned", Some(&partitioned))] {
+            group.bench_with_input(
+                BenchmarkId::new(name, candidate_count),
+                &candidate_count,
+                |b, _| {
+                    let mut burst = 0;
+                    b.iter(|| {
+                        burst += 1;
+                        black_box(
+                            process_neural_dynamics_partitioned(
+                                black_box(&fcl),
+                                None,
+                                &mut neuron_array,
+                                burst,
+                                parallelism,
+                            )
+                            .unwrap(),
+                        )
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_synaptic_propagation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("burst_parallelism_propagation");
+
+    let neuron_count = 100_000;
+    let synapses_per_neuron = 50;
+    let mut synapse_array = SynapseArray::new(neuron_count * synapses_per_neuron);
+    for source in 0..neuron_count {
+        for offset in 0..synapses_per_neuron {
+            synapse_array.add_synapse_simple(
+                source as u32,
+                ((source + offset * 97 + 1) % neuron_count) as u32,
+                128,
+                200,
+                SynapseType::Excitatory,
+            );
+        }
+    }
+
+    let cortical_id = CoreCorticalType::Power.to_cortical_id();
+    let neuron_mapping: AHashMap<NeuronId, _> = (0..neuron_count)
+        .map(|i| (NeuronId(i as u32), cortical_id))
+        .collect();
+    let neuron_mps = AHashMap::new();
+
+    let configs = [
+        ("unset", parallelism(pool_threads(), 0)),
+        ("min_len_100000", parallelism(pool_threads(), 100_000)),
+    ];
+
+    for fired_count in [100, 1_000, 10_000] {
+        let stride = neuron_count / fired_count;
+        let fired: Vec<NeuronId> = (0..fired_count)
+            .map(|i| NeuronId((i * stride) as u32))
+            .collect();
+        let synapse_count = fired_count * synapses_per_neuron;
+
+        group.throughput(Throughput::Elements(synapse_count as u64));
+        for (name, config) in &configs {
+            let mut engine = SynapticPropagationEngine::new();
+            engine.build_synapse_index(&synapse_array);
+            engine.set_neuron_mapping(neuron_mapping.clone());
+            engine.set_parallelism(config.clone());
+
+            group.bench_with_input(
+                BenchmarkId::new(*name, synapse_count),
+                &synapse_count,
+                |b, _| {
+                    b.iter(|| {
+                        black_box(
+                            engine
+                                .propagate(
+                                    black_box(&fired),
+                                    black_box(&synapse_array),
+                                    &neuron_mps,
+                                )
+                                .unwrap(),
+                        )
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn criterion_config() -> Criterion {
+    Criterion::default()
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2))
+        .sample_size(20)
+}
+
+criterion_group! {
+    name = burst_parallelism;
+    config = criterion_config();
+    targets = bench_neural_dynamics, bench_synaptic_propagation
+}
+criterion_main!(burst_parallelism);
@@ -0,0 +1,169 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Synapse Storage Comparison
+//!
+//! Compares propagation over the hash-indexed `SynapseArray` against the
+//! compressed-sparse-row `CsrSynapseArray`, plus the cost of merging the CSR
+//! insert/remove buffers.
+//!
+//! Run with: `cargo bench -p feagi-npu-burst-engine --bench synapse_storage_comparison`
+
+use std::time::Duration;
+
+use ahash::AHashMap;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use feagi_npu_neural::synapse::{compute_synaptic_contribution, SynapseType};
+use feagi_npu_runtime::std_impl::{CsrSynapseArray, SynapseArray};
+use feagi_npu_runtime::SynapseStorage;
+
+/// Deterministic neighborhood connectivity, inserted in target-major order so
+/// the hash-indexed array is not accidentally source-sorted.
+fn create_synapses(neuron_count: usize, synapses_per_neuron: usize) -> SynapseArray {
+    let mut synapse_array = SynapseArray::new(neuron_count * synapses_per_neuron);
+    for offset in 0..synapses_per_neuron {
+        for source in 0..neuron_count {
+            let target = (source * 7 + offset + 1) % neuron_count;
+            let synapse_type = if offset % 4 == 0 {
+                SynapseType::Inhibitory
+            } else {
+                SynapseType::Excitatory
+            };
+            synapse_array.add_synapse_simple(source as u32, target as u32, 128, 200, synapse_type);
+        }
+    }
+    synapse_array
+}
+
+fn generate_fired_neurons(neuron_count: usize, firing_rate: f32) -> Vec<u32> {
+    let fire_count = ((neuron_count as f32 * firing_rate) as usize).max(1);
+    (0..fire_count)
+        .map(|i| (i * (neuron_count / fire_count)) as u32)
+        .collect()
+}
+
+/// Sequential walk of the `SynapseArray` source index (hash lookup per fired neuron)
+fn propagate_hash_index(synapse_array: &SynapseArray, fired: &[u32]) -> AHashMap<u32, f32> {
+    let mut result = AHashMap::new();
+    for source in fired {
+        let Some(indices) = synapse_array.source_index.get(source) else {
+            continue;
+        };
+        for &idx in indices {
+            if !synapse_array.valid_mask[idx] {
+                continue;
+            }
+            let synapse_type = if synapse_array.types[idx] == 0 {
+                SynapseType::Excitatory
+            } else {
+                SynapseType::Inhibitory
+            };
+            *result
+                .entry(synapse_array.target_neurons[idx])
+                .or_insert(0.0) += compute_synaptic_contribution(
+                synapse_array.weights[idx],
+                synapse_array.postsynaptic_potentials[idx],
+                synapse_type,
+            );
+        }
+    }
+    result
+}
+
+fn bench_propagation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("synapse_storage_propagation");
+    group.sample_size(20);
+    group.warm_up_time(Duration::from_millis(500));
+    group.measurement_time(Duration::from_secs(2));
+
+    for &(neuron_count, synapses_per_neuron) in &[(10_000, 50), (100_000, 50)] {
+        let synapse_array = create_synapses(neuron_count, synapses_per_neuron);
+        let csr = CsrSynapseArray::from_storage(&synapse_array);
+
+        for &firing_rate in &[0.01f32, 0.1] {
+            let fired = generate_fired_neurons(neuron_count, firing_rate);
+            let label = format!(
+                "{}k_{}syn_{}pct",
+                neuron_count / 1000,
+                synapses_per_neuron,
+                (firing_rate * 100.0) as u32
+            );
+            group.throughput(Throughput::Elements(
+                (fired.len() * synapses_per_neuron) as u64,
+            ));
+
+            group.bench_with_input(
+                BenchmarkId::new("hash_index", &label),
+                &fired,
+                |b, fired| {
+                    b.iter(|| black_box(propagate_hash_index(&synapse_array, black_box(fired))));
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new("hash_index_parallel", &label),
+                &fired,
+                |b, fired| {
+                    b.iter(|| black_box(synapse_array.propagate_parallel(black_box(fired))));
+                },
+            );
+
+            group.bench_with_input(BenchmarkId::new("csr", &label), &fired, |b, fired| {
+                b.iter(|| black_box(csr.propagate(black_box(fired))));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_csr_maintenance(c: &mut Criterion) {
+    let mut group = c.benchmark_group("csr_maintenance");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_millis(500));
+    group.measurement_time(Duration::from_secs(2));
+
+    let neuron_count = 100_000;
+    let synapse_array = create_synapses(neuron_count, 50);
+    let csr = CsrSynapseArray::from_storage(&synapse_array);
+    let fired = generate_fired_neurons(neuron_count, 0.01);
+
+    group.bench_function("build_from_storage_100k_50syn", |b| {
+        b.iter(|| black_box(CsrSynapseArray::from_storage(black_box(&synapse_array))));
+    });
+
+    // 5% churn: buffered inserts + removals, then propagate before/after merge
+    let mut churned = CsrSynapseArray::from_storage(&synapse_array);
+    for i in 0..(neuron_count / 4) {
+        let source = (i * 13 % neuron_count) as u32;
+        churned
+            .add_synapse(source, (i % neuron_count) as u32, 64, 200, 0)
+            .unwrap();
+        churned.remove_synapse(i * 17 % csr.count()).unwrap();
+    }
+
+    group.bench_function("propagate_with_pending_5pct", |b| {
+        b.iter(|| black_box(churned.propagate(black_box(&fired))));
+    });
+
+    group.bench_function("merge_pending_5pct", |b| {
+        b.iter_batched(
+            || {
+                let mut array = CsrSynapseArray::from_storage(&synapse_array);
+                for i in 0..(neuron_count / 4) {
+                    array
+                        .add_synapse((i * 13 % neuron_count) as u32, i as u32, 64, 200, 0)
+                        .unwrap();
+                }
+                array
+            },
+            |mut array| black_box(array.merge_pending()),
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_propagation, bench_csr_maintenance);
+criterion_main!(benches);
@@ -12,6 +12,9 @@
 //!
 //! SIMD-optimized CPU backend using existing neural_dynamics and synaptic_propagation modules.
 //! This wraps the current high-performance Rust implementation.
+//!
+//! `RustNPU::process_burst` does not go through this backend; its thread pool and
+//! partitioning live in [`crate::parallelism`].
 
 use super::ComputeBackend;
 use crate::neural_dynamics;
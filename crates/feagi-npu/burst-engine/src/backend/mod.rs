@@ -255,6 +255,16 @@ pub struct BackendConfig {
     /// Force CUDA GPU even if CPU/WGPU would be better (for testing)
     #[cfg(feature = "cuda")]
     pub force_cuda: bool,
+
+    /// Worker threads for burst propagation and neural dynamics
+    /// (default: 0 = rayon's global pool, 1 = serial, N = dedicated pool of N threads)
+    pub cpu_threads: usize,
+
+    /// Minimum synapses per propagation task, and fire candidates before neural
+    /// dynamics is partitioned (default: 0 = unset, which leaves propagation to
+    /// rayon's own splitting and neural dynamics serial). Measure with
+    /// `benches/burst_parallelism.rs` before setting it.
+    pub cpu_parallel_synapse_threshold: usize,
 }
 
 impl Default for BackendConfig {
@@ -278,15 +288,20 @@ impl Default for BackendConfig {
             force_gpu: false,
             #[cfg(feature = "cuda")]
             force_cuda: false,
+
+            // Share rayon's global pool and its own work splitting
+            cpu_threads: 0,
+            cpu_parallel_synapse_threshold: 0,
         }
     }
 }
 
-/// GPU configuration from application config (TOML)
+/// GPU and CPU thread configuration from application config (TOML)
 ///
-/// This struct provides a simplified interface for GPU configuration
+/// This struct provides a simplified interface for backend configuration
 /// that can be passed from the application layer (feagi/feagi-inference-engine)
 /// to the burst engine without creating tight coupling to feagi-config.
+/// The CPU thread settings come from feagi-config's `[burst_engine]` section.
 #[derive(Debug, Clone)]
 pub struct GpuConfig {
     /// Enable GPU processing globally
@@ -300,6 +315,12 @@ pub struct GpuConfig {
 
     /// Fraction of GPU memory to use (0.0-1.0)
     pub gpu_memory_fraction: f64,
+
+    /// Worker threads for the CPU burst phases (see `BackendConfig::cpu_threads`)
+    pub cpu_threads: usize,
+
+    /// See `BackendConfig::cpu_parallel_synapse_threshold` (0 = unset)
+    pub cpu_parallel_synapse_threshold: usize,
 }
 
 impl Default for GpuConfig {
@@ -309,6 +330,8 @@ impl Default for GpuConfig {
             hybrid_enabled: true,
             gpu_threshold: 1_000_000,
             gpu_memory_fraction: 0.8,
+            cpu_threads: 0,
+            cpu_parallel_synapse_threshold: 0,
         }
     }
 }
@@ -345,6 +368,8 @@ impl GpuConfig {
             gpu_synapse_threshold: self.gpu_threshold,
             force_cpu: !self.use_gpu,
             force_gpu: self.use_gpu && !self.hybrid_enabled,
+            cpu_threads: self.cpu_threads,
+            cpu_parallel_synapse_threshold: self.cpu_parallel_synapse_threshold,
             ..Default::default()
         };
 
@@ -19,11 +19,14 @@
 //! - Power neurons injected every burst
 //! - Sensory neurons injected by separate threads directly into FCL
 
+use crate::distributed::{DistributedError, PartitionCoordinator, PartitionPlan, SpikeExchange};
+use crate::lockstep::{BurstPacing, TickGate, TickHandle, TickOutput, TickRequest};
 use crate::parameter_update_queue::ParameterUpdateQueue;
 use crate::sensory::AgentManager;
 use crate::update_sim_timestep_from_hz;
 #[cfg(feature = "std")]
 use crate::{tracing_mutex::TracingMutex, DynamicNPU};
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
 use feagi_npu_neural::types::NeuronId;
 use parking_lot::RwLock as ParkingLotRwLock;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -34,7 +37,7 @@ use std::time::{Duration, Instant};
 type FireQueueSample = ahash::AHashMap<u32, (Vec<u32>, Vec<u32>, Vec<u32>, Vec<u32>, Vec<f32>)>;
 
 /// Decoded sensory data: list of (cortical ID, XYZP list per cortical area)
-type SensoryXyzpDecoded = Vec<(
+pub type SensoryXyzpDecoded = Vec<(
     feagi_structures::genomic::cortical_area::CorticalID,
     Vec<(u32, u32, u32, f32)>,
 )>;
@@ -105,6 +108,13 @@ pub struct BurstLoopRunner {
     frequency_hz: Arc<Mutex<f64>>,
     /// Running flag (atomic for thread-safe stop)
     running: Arc<AtomicBool>,
+    /// Burst pacing and pending lockstep ticks (shared with burst thread)
+    tick_gate: Arc<TickGate>,
+    /// Distributed NPU coordinator (None = the NPU holds the whole brain)
+    /// Lock order: partition_coordinator -> npu
+    partition_coordinator: Arc<Mutex<Option<PartitionCoordinator>>>,
+    /// Why the burst loop stopped itself (None while healthy; cleared by `start`)
+    fault: Arc<Mutex<Option<String>>>,
     /// Thread handle (for graceful shutdown)
     thread_handle: Option<thread::JoinHandle<()>>,
     /// Sensory agent manager (per-agent injection threads - SHM-based agents)
@@ -350,6 +360,9 @@ impl BurstLoopRunner {
             npu,
             frequency_hz: Arc::new(Mutex::new(frequency_hz)), // Shared with burst thread for dynamic updates
             running: Arc::new(AtomicBool::new(false)),
+            tick_gate: Arc::new(TickGate::default()),
+            partition_coordinator: Arc::new(Mutex::new(None)),
+            fault: Arc::new(Mutex::new(None)),
             thread_handle: None,
             sensory_manager: Arc::new(Mutex::new(sensory_manager)),
             sensory_intake: None, // Can be set later via set_sensory_intake()
@@ -569,6 +582,73 @@ impl BurstLoopRunner {
         info!("[BURST-RUNNER] Frequency set to {:.2} Hz", frequency_hz);
     }
 
+    /// Switch between wall-clock and tick-driven pacing (can be called while running)
+    pub fn set_pacing(&self, pacing: BurstPacing) {
+        self.tick_gate.set_pacing(pacing);
+        info!("[BURST-RUNNER] Pacing set to {:?}", pacing);
+    }
+
+    /// Get current burst pacing
+    pub fn get_pacing(&self) -> BurstPacing {
+        self.tick_gate.pacing()
+    }
+
+    /// Handle for issuing lockstep ticks from other threads
+    pub fn tick_handle(&self) -> TickHandle {
+        TickHandle::new(self.tick_gate.clone(), self.running.clone())
+    }
+
+    /// Run this NPU as the coordinator (partition 0) of a distributed NPU
+    ///
+    /// `snapshot` must describe the brain currently loaded in the NPU. Neurons
+    /// of other partitions are removed from the NPU, and from then on every
+    /// burst waits for the workers' spikes and sends its own to them. Can be
+    /// called while the loop is running; takes effect at the next burst.
+    /// If the exchange fails, the loop stops and reports a [`fault`](Self::fault)
+    /// instead of running on without the workers.
+    pub fn attach_partition_coordinator(
+        &self,
+        snapshot: &ConnectomeSnapshot,
+        plan: &PartitionPlan,
+        exchange: Box<dyn SpikeExchange>,
+    ) -> Result<(), DistributedError> {
+        // Lock order: partition_coordinator -> npu (same as the burst loop)
+        let mut slot = self.partition_coordinator.lock().unwrap();
+        let coordinator = {
+            let mut npu = self.npu.lock().unwrap();
+            match &mut *npu {
+                DynamicNPU::F32(npu) => PartitionCoordinator::attach(npu, snapshot, plan, exchange),
+                DynamicNPU::INT8(npu) => {
+                    PartitionCoordinator::attach(npu, snapshot, plan, exchange)
+                }
+            }?
+        };
+        info!(
+            "[BURST-RUNNER] Coordinating distributed NPU: {} local neurons, {} remote synapses, peers {:?}",
+            coordinator.neuron_count(),
+            coordinator.remote_synapse_count(),
+            coordinator.peers()
+        );
+        *slot = Some(coordinator);
+        Ok(())
+    }
+
+    /// Stop exchanging spikes with worker partitions
+    ///
+    /// The NPU keeps only partition 0's neurons; reload the connectome to run
+    /// the whole brain locally again.
+    pub fn detach_partition_coordinator(&self) -> Option<PartitionCoordinator> {
+        self.partition_coordinator.lock().unwrap().take()
+    }
+
+    /// Run exactly one burst in lockstep pacing and return its motor output
+    ///
+    /// Blocks until the burst thread has processed the tick or `timeout` elapses.
+    /// Requires a running loop with [`BurstPacing::Lockstep`].
+    pub fn tick(&self, request: TickRequest, timeout: Duration) -> Result<TickOutput, String> {
+        self.tick_handle().tick(request, timeout)
+    }
+
     /// Start the burst loop in a background thread
     ///
     /// 🦀 Power neurons are read from RustNPU internally - 100% Rust!
@@ -581,11 +661,15 @@ impl BurstLoopRunner {
         info!("[BURST-RUNNER] Starting burst loop at {:.2} Hz (power neurons auto-discovered from cortical_idx=1)",
                  current_freq);
 
+        *self.fault.lock().unwrap() = None;
         self.running.store(true, Ordering::Release);
 
         let npu = self.npu.clone();
         let frequency = self.frequency_hz.clone(); // Clone Arc for thread
         let running = self.running.clone();
+        let tick_gate = self.tick_gate.clone();
+        let partition_coordinator = self.partition_coordinator.clone();
+        let fault = self.fault.clone();
         let viz_writer = self.viz_shm_writer.clone();
         let motor_writer = self.motor_shm_writer.clone();
         let viz_publisher = self.viz_publisher.clone(); // Direct Rust-to-Rust trait reference (NO PYTHON CALLBACKS!)
@@ -614,6 +698,9 @@ impl BurstLoopRunner {
                         npu,
                         frequency,
                         running,
+                        tick_gate,
+                        partition_coordinator,
+                        fault,
                         viz_writer,
                         motor_writer,
                         viz_publisher,
@@ -698,6 +785,11 @@ impl BurstLoopRunner {
         self.running.load(Ordering::Acquire)
     }
 
+    /// Why the burst loop stopped itself, if it did (cleared by `start`)
+    pub fn fault(&self) -> Option<String> {
+        self.fault.lock().unwrap().clone()
+    }
+
     /// Get current burst count (lock-free atomic read)
     ///
     /// Reads from cached value that's updated by the burst loop.
@@ -956,7 +1048,7 @@ fn aggregate_into_visualization_chunks(
 /// This eliminates ~1 MB allocation per burst @ 10 Hz = ~10 MB/sec saved
 ///
 /// Filter by cortical_id strings (e.g., "omot00"), matching sensory stream pattern
-fn encode_fire_data_to_xyzp(
+pub(crate) fn encode_fire_data_to_xyzp(
     fire_data: RawFireQueueSnapshot,
     cortical_id_filter: Option<&ahash::AHashSet<String>>,
 ) -> Result<Vec<u8>, String> {
@@ -1182,6 +1274,70 @@ fn decode_sensory_bytes(bytes: &[u8]) -> Result<SensoryXyzpDecoded, String> {
     Ok(out)
 }
 
+/// Sensory data carried by a lockstep tick (decoded XYZP plus an optional byte container)
+fn decode_tick_sensory(request: &TickRequest) -> Result<SensoryXyzpDecoded, String> {
+    let mut decoded = request.sensory_xyzp.clone();
+    if let Some(bytes) = &request.sensory_bytes {
+        decoded.extend(decode_sensory_bytes(bytes)?);
+    }
+    Ok(decoded)
+}
+
+/// Fired neurons of the motor areas a lockstep tick asked for.
+///
+/// Without an explicit list every brain output (OPU) area is returned.
+fn tick_motor_snapshot(
+    fire_data: Option<&FireQueueSample>,
+    cortical_id_mappings: &ahash::AHashMap<u32, String>,
+    motor_cortical_ids: Option<&ahash::AHashSet<String>>,
+) -> RawFireQueueSnapshot {
+    use feagi_structures::genomic::cortical_area::{CorticalAreaType, CorticalID};
+
+    let mut motor = RawFireQueueSnapshot::new();
+    let Some(fire_data) = fire_data else {
+        return motor;
+    };
+    for (area_id, (neuron_ids, coords_x, coords_y, coords_z, potentials)) in fire_data {
+        let Some(cortical_id) = cortical_id_mappings.get(area_id) else {
+            continue;
+        };
+        let requested = match motor_cortical_ids {
+            Some(ids) => ids.contains(cortical_id),
+            None => CorticalID::try_from_base_64(cortical_id)
+                .and_then(|id| id.as_cortical_type())
+                .is_ok_and(|t| matches!(t, CorticalAreaType::BrainOutput(_))),
+        };
+        if requested && !neuron_ids.is_empty() {
+            motor.insert(
+                *area_id,
+                RawFireQueueData {
+                    cortical_area_idx: *area_id,
+                    cortical_id: cortical_id.clone(),
+                    neuron_ids: neuron_ids.clone(),
+                    coords_x: coords_x.clone(),
+                    coords_y: coords_y.clone(),
+                    coords_z: coords_z.clone(),
+                    potentials: potentials.clone(),
+                },
+            );
+        }
+    }
+    motor
+}
+
+/// Stop the burst loop after an unrecoverable error
+///
+/// The reason is kept for `BurstLoopRunner::fault` and the burst engine is
+/// put in the `Error` state, so the API reports it instead of a healthy stop.
+fn stop_on_fault(running: &AtomicBool, fault: &Mutex<Option<String>>, message: &str) {
+    error!("[BURST-LOOP] ❌ {}; stopping burst loop", message);
+    *fault.lock().unwrap() = Some(message.to_string());
+    running.store(false, Ordering::Release);
+    feagi_state_manager::StateManager::instance()
+        .read()
+        .set_burst_engine_state(feagi_state_manager::BurstEngineState::Error);
+}
+
 /// Main burst processing loop (runs in dedicated thread)
 ///
 /// This is the HOT PATH - zero Python involvement!
@@ -1192,6 +1348,9 @@ fn burst_loop(
     npu: Arc<TracingMutex<DynamicNPU>>,
     frequency_hz: Arc<Mutex<f64>>, // Shared frequency - can be updated while running
     running: Arc<AtomicBool>,
+    tick_gate: Arc<TickGate>, // Lockstep pacing: one burst per tick
+    partition_coordinator: Arc<Mutex<Option<PartitionCoordinator>>>, // Distributed NPU spike exchange
+    fault: Arc<Mutex<Option<String>>>, // Set when the loop stops itself on an unrecoverable error
     viz_shm_writer: Arc<Mutex<Option<crate::viz_shm_writer::VizSHMWriter>>>,
     motor_shm_writer: Arc<Mutex<Option<crate::motor_shm_writer::MotorSHMWriter>>>,
     viz_publisher: Option<Arc<dyn VisualizationPublisher>>, // Trait object for visualization (NO PYTHON CALLBACKS!)
@@ -1236,6 +1395,16 @@ fn burst_loop(
     let mut missing_motor_agent_logged: ahash::AHashSet<String> = ahash::AHashSet::new();
 
     while running.load(Ordering::Acquire) {
+        // Lockstep pacing: the next burst starts when a tick arrives, not on the clock
+        let tick = if tick_gate.pacing() == BurstPacing::Lockstep {
+            match tick_gate.next_tick(&running) {
+                Some(tick) => Some(tick),
+                None => continue, // Stopped, or switched back to real time
+            }
+        } else {
+            None
+        };
+
         let iteration_start = Instant::now();
         let burst_start = Instant::now();
         // Keep simulation timestep snapshot aligned with runtime frequency.
@@ -1256,7 +1425,8 @@ fn burst_loop(
                 let gap = iteration_start.duration_since(last);
                 // Only warn for extreme gaps (>10 seconds) that might indicate system issues
                 // Normal batch processing (e.g., MRI data) can have multi-second gaps
-                if gap.as_millis() > 10000 {
+                // Lockstep bursts legitimately idle until the next tick
+                if tick.is_none() && gap.as_millis() > 10000 {
                     warn!(
                         "[BURST-LOOP] ⚠️ Extremely large gap between bursts: {:.2}ms - burst {} (this may indicate system issues)",
                         gap.as_secs_f64() * 1000.0,
@@ -1290,8 +1460,22 @@ fn burst_loop(
             break;
         }
 
+        // A lockstep tick may carry the sensory data for its burst
+        let tick_sensory = match tick.as_ref() {
+            Some((seq, request)) if request.has_sensory() => match decode_tick_sensory(request) {
+                Ok(decoded) => Some(decoded),
+                Err(e) => {
+                    warn!("[BURST-LOOP] Rejected tick {}: {}", seq, e);
+                    tick_gate.complete(*seq, Err(e));
+                    continue;
+                }
+            },
+            _ => None,
+        };
+
         // Poll transport-agnostic sensory intake (feagi-io) before acquiring NPU lock
-        let sensory_xyzp: Option<SensoryXyzpDecoded> = sensory_intake.as_ref().and_then(|intake| {
+        let sensory_xyzp: Option<SensoryXyzpDecoded> = tick_sensory.or_else(|| {
+            let intake = sensory_intake.as_ref()?;
             let mut guard = intake.lock().ok()?;
             let bytes = guard.poll_sensory_data().ok().flatten()?;
             match decode_sensory_bytes(&bytes) {
@@ -1320,7 +1504,7 @@ fn burst_loop(
                 let gap = lock_start.duration_since(last);
                 // Only warn for extreme gaps (>30 seconds) that might indicate deadlock
                 // Batch processing (e.g., medical imaging) can hold lock for several seconds legitimately
-                if gap.as_millis() > 30000 {
+                if tick.is_none() && gap.as_millis() > 30000 {
                     warn!(
                         "[NPU-LOCK] Burst {}: Extreme gap since last release: {:.2}ms (possible deadlock or system issue)",
                         burst_num,
@@ -1339,6 +1523,24 @@ fn burst_loop(
 
         let mut last_process_duration: Option<std::time::Duration> = None;
         let mut last_burst_stats: Option<(usize, usize, usize, usize, usize)> = None;
+        let mut last_burst_error: Option<String> = None;
+
+        // Distributed NPU: wait for the workers' spikes from the previous burst
+        // before taking the NPU lock, so the barrier never blocks NPU readers.
+        let mut partition_guard = partition_coordinator.lock().unwrap();
+        let mut remote_potentials: Vec<(NeuronId, f32)> = Vec::new();
+        if let Some(coordinator) = partition_guard.as_mut() {
+            match coordinator.begin_burst() {
+                Ok(staged) => remote_potentials = staged,
+                Err(e) => {
+                    // Running on without the workers would silently simulate part of the brain
+                    let message = format!("Partition barrier failed: {}", e);
+                    stop_on_fault(&running, &fault, &message);
+                    last_burst_error = Some(message);
+                }
+            }
+        }
+        let mut partition_fired: Vec<u32> = Vec::new();
 
         // Track lock acquisition time outside block scope for diagnostics
         let lock_acquired = {
@@ -1639,6 +1841,9 @@ fn burst_loop(
                         npu_lock.inject_sensory_xyzp_by_id(cortical_id, xyzp);
                     }
                 }
+                if !remote_potentials.is_empty() {
+                    npu_lock.inject_sensory_with_potentials(&remote_potentials);
+                }
 
                 let process_start = Instant::now();
                 debug!("[BURST-TIMING] Starting process_burst()...");
@@ -1670,6 +1875,9 @@ fn burst_loop(
                         }
 
                         total_neurons_fired += result.neuron_count;
+                        if partition_guard.is_some() {
+                            partition_fired = result.fired_neurons.iter().map(|id| id.0).collect();
+                        }
                         // Update cached burst count for lock-free reads
                         let current_burst = npu_lock.get_burst_count();
                         cached_burst_count
@@ -1717,6 +1925,7 @@ fn burst_loop(
                             timestamp, e
                         );
                         burst_after = npu_lock.get_burst_count();
+                        last_burst_error = Some(e.to_string());
                         false // Continue despite error
                     }
                     Err(panic_payload) => {
@@ -1787,12 +1996,46 @@ fn burst_loop(
             );
         }
 
+        // Send this burst's spikes on; a failed burst sends none so the
+        // workers' clocks keep advancing with ours.
+        if !should_exit {
+            if let Some(coordinator) = partition_guard.as_mut() {
+                if let Err(e) = coordinator.end_burst(&partition_fired) {
+                    let message = format!("Sending spikes to partitions failed: {}", e);
+                    stop_on_fault(&running, &fault, &message);
+                    last_burst_error = Some(message);
+                }
+            }
+        }
+        drop(partition_guard);
+
         if let Some(ref callback) = post_burst_callback {
             callback(burst_after);
         } else if !POST_BURST_MISSING_LOGGED.swap(true, Ordering::Relaxed) {
             tracing::debug!("[BURST-LOOP] Post-burst callback not configured");
         }
 
+        // Hand the burst's motor output back to the caller that ticked it
+        if let Some((seq, request)) = tick.as_ref() {
+            let output = match last_burst_error.take() {
+                Some(e) => Err(format!("Burst processing failed: {}", e)),
+                None => {
+                    let fire_data = cached_fire_queue.lock().unwrap().clone();
+                    let mappings = cached_cortical_id_mappings.lock().unwrap().clone();
+                    Ok(TickOutput {
+                        burst: burst_after,
+                        fired_neuron_count: last_burst_stats.map(|s| s.0).unwrap_or(0),
+                        motor: tick_motor_snapshot(
+                            fire_data.as_deref(),
+                            &mappings,
+                            request.motor_cortical_ids.as_ref(),
+                        ),
+                    })
+                }
+            };
+            tick_gate.complete(*seq, output);
+        }
+
         // Exit if shutdown was requested
         if should_exit || !running.load(Ordering::Relaxed) {
             break;
@@ -2609,6 +2852,11 @@ fn burst_loop(
             *last_end = Some(Instant::now());
         }
 
+        // Lockstep bursts are paced by ticks, not by the burst frequency
+        if tick.is_some() {
+            continue;
+        }
+
         // Adaptive sleep (RTOS-friendly timing)
         // Strategy: <5Hz = chunked sleep, 5-100Hz = hybrid, >100Hz = busy-wait
         // CRITICAL: Break sleep into chunks to allow responsive shutdown
@@ -2837,6 +3085,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lockstep_ticks_drive_bursts_and_return_motor_output() {
+        struct NoViz;
+        impl VisualizationPublisher for NoViz {
+            fn publish_raw_fire_queue_for_agent(
+                &self,
+                _agent_id: &str,
+                _fire_data: RawFireQueueSnapshot,
+            ) -> Result<(), String> {
+                Ok(())
+            }
+        }
+
+        struct NoMotor;
+        impl MotorPublisher for NoMotor {
+            fn publish_motor(&self, _agent_id: &str, _data: &[u8]) -> Result<(), String> {
+                Ok(())
+            }
+        }
+
+        use feagi_npu_neural::types::{SynapseType, SynapticPsp, SynapticWeight};
+        use feagi_npu_runtime::StdRuntime;
+        use feagi_structures::genomic::cortical_area::descriptors::CorticalUnitIndex;
+        use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+            FrameChangeHandling, PercentageNeuronPositioning,
+        };
+        use feagi_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
+
+        let sensory_id = SensoryCorticalUnit::get_cortical_ids_array_for_infrared_with_parameters(
+            FrameChangeHandling::Absolute,
+            PercentageNeuronPositioning::Linear,
+            CorticalUnitIndex::from(0u8),
+        )[0];
+        let motor_id =
+            MotorCorticalUnit::get_cortical_ids_array_for_text_english_output_with_parameters(
+                FrameChangeHandling::Absolute,
+                CorticalUnitIndex::from(0u8),
+            )[0];
+
+        // Sensory neuron -> motor neuron: the motor area fires one burst after the input
+        let mut rust_npu =
+            <crate::RustNPU<StdRuntime, f32, crate::backend::CPUBackend>>::new_cpu_only(
+                100, 1000, 10,
+            );
+        rust_npu.register_cortical_area(3, sensory_id.as_base_64());
+        rust_npu.register_cortical_area(4, motor_id.as_base_64());
+        let add = |npu: &mut crate::RustNPU<StdRuntime, f32, crate::backend::CPUBackend>,
+                   area: u32| {
+            npu.add_neuron(
+                1.0,
+                f32::MAX,
+                0.0,
+                0.0,
+                0,
+                0,
+                1.0,
+                0,
+                0,
+                true,
+                area,
+                0,
+                0,
+                0,
+            )
+            .unwrap()
+        };
+        let sensory = add(&mut rust_npu, 3);
+        let motor = add(&mut rust_npu, 4);
+        rust_npu
+            .add_synapse(
+                sensory,
+                motor,
+                SynapticWeight(255),
+                SynapticPsp(255),
+                SynapseType::Excitatory,
+            )
+            .unwrap();
+        rust_npu.rebuild_synapse_index();
+
+        let npu = Arc::new(TracingMutex::new(DynamicNPU::F32(rust_npu), "TestNPU"));
+        // 1 Hz: anything faster than one burst per second proves ticks, not the clock, pace it
+        let mut runner = BurstLoopRunner::new::<NoViz, NoMotor>(npu, None, None, 1.0);
+        runner.refresh_cortical_id_mappings(ahash::AHashMap::from_iter([
+            (3, sensory_id.as_base_64()),
+            (4, motor_id.as_base_64()),
+        ]));
+        assert!(runner
+            .tick(TickRequest::default(), Duration::from_millis(10))
+            .is_err());
+
+        runner.set_pacing(BurstPacing::Lockstep);
+        runner.start().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(runner.get_burst_count(), 0, "no burst without a tick");
+
+        let timeout = Duration::from_secs(5);
+        let started = Instant::now();
+        let first = runner
+            .tick(
+                TickRequest::default().with_sensory_xyzp(sensory_id, vec![(0, 0, 0, 128.0)]),
+                timeout,
+            )
+            .unwrap();
+        assert_eq!(first.burst, 1);
+        assert_eq!(first.fired_neuron_count, 1);
+        assert!(first.motor.is_empty());
+
+        let second = runner.tick(TickRequest::default(), timeout).unwrap();
+        assert_eq!(second.burst, 2);
+        let motor_area = second.motor.get(&4).expect("motor area fired");
+        assert_eq!(motor_area.neuron_ids, vec![motor.0]);
+        assert!(!second.motor_xyzp_bytes().unwrap().is_empty());
+
+        // An explicit motor filter excludes unlisted areas
+        runner
+            .tick(
+                TickRequest::default().with_sensory_xyzp(sensory_id, vec![(0, 0, 0, 128.0)]),
+                timeout,
+            )
+            .unwrap();
+        let fourth = runner
+            .tick(
+                TickRequest::default()
+                    .with_motor_cortical_ids(ahash::AHashSet::from_iter(["none".to_string()])),
+                timeout,
+            )
+            .unwrap();
+        assert_eq!(fourth.burst, 4);
+        assert_eq!(fourth.fired_neuron_count, 1);
+        assert!(fourth.motor.is_empty());
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        runner.set_pacing(BurstPacing::RealTime);
+        assert!(runner.tick(TickRequest::default(), timeout).is_err());
+        runner.stop();
+    }
+
     #[test]
     fn test_visualization_rate_validation() {
         struct NoViz;
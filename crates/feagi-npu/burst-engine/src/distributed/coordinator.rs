@@ -0,0 +1,139 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Partition 0 of a distributed NPU, run by the burst loop on its own NPU.
+
+use std::time::Duration;
+
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
+use feagi_npu_neural::types::{NeuralValue, NeuronId};
+use feagi_npu_runtime::Runtime;
+
+use super::link::{owners, PartitionLink};
+use super::{DistributedError, PartitionId, PartitionPlan, SpikeExchange};
+use crate::backend::ComputeBackend;
+use crate::npu::RustNPU;
+
+/// Coordinator side of a distributed NPU.
+///
+/// Unlike a [`PartitionWorker`](super::PartitionWorker), the coordinator does
+/// not own an NPU. [`attach`](Self::attach) trims an NPU that already holds the
+/// whole brain down to partition 0, and the
+/// [`BurstLoopRunner`](crate::BurstLoopRunner) then trades spikes with the
+/// workers around each burst. Neuron IDs in that NPU are the snapshot's global
+/// IDs, so core areas, sensory injection and motor output keep working
+/// unchanged.
+pub struct PartitionCoordinator {
+    link: PartitionLink,
+    exchange: Box<dyn SpikeExchange>,
+    neuron_count: usize,
+}
+
+impl PartitionCoordinator {
+    /// Trim `npu` to partition 0 of `plan` and connect it to the workers.
+    ///
+    /// `snapshot` must describe the brain currently loaded in `npu` (for
+    /// example its own [`RustNPU::export_connectome`]); every neuron of the
+    /// snapshot is expected at the same ID in the NPU. Neurons of other
+    /// partitions are deleted, together with every synapse that leaves or
+    /// enters them.
+    pub fn attach<
+        R: Runtime,
+        T: NeuralValue,
+        B: ComputeBackend<T, R::NeuronStorage<T>, R::SynapseStorage>,
+    >(
+        npu: &mut RustNPU<R, T, B>,
+        snapshot: &ConnectomeSnapshot,
+        plan: &PartitionPlan,
+        exchange: Box<dyn SpikeExchange>,
+    ) -> Result<Self, DistributedError> {
+        if exchange.partition() != 0 {
+            return Err(DistributedError::NotCoordinator(exchange.partition()));
+        }
+
+        let owner_of = owners(snapshot, plan)?;
+        let mut local = Vec::new();
+        let mut foreign = Vec::new();
+        for (global, owner) in owner_of.iter().enumerate() {
+            let Some(owner) = owner else {
+                continue;
+            };
+            let id = global as u32;
+            if !npu.is_neuron_valid(id)
+                || npu.get_neuron_cortical_area(id) != snapshot.neurons.cortical_areas[global]
+            {
+                return Err(DistributedError::SnapshotMismatch(format!(
+                    "neuron {} of the snapshot is not loaded in the NPU",
+                    global
+                )));
+            }
+            if *owner == 0 {
+                local.push(NeuronId(id));
+            } else {
+                foreign.push(NeuronId(id));
+            }
+        }
+
+        // Outgoing synapses are pruned through the synapse index, which must
+        // cover every synapse loaded so far.
+        npu.rebuild_synapse_index();
+        npu.remove_synapses_from_sources_to_targets(local.clone(), foreign.clone());
+        npu.remove_synapses_from_sources(foreign.clone());
+        for neuron in &foreign {
+            npu.delete_neuron(neuron.0);
+        }
+        npu.rebuild_synapse_index();
+
+        let link = PartitionLink::build(snapshot, plan, 0, &owner_of, |global| global);
+        Ok(Self {
+            link,
+            exchange,
+            neuron_count: local.len(),
+        })
+    }
+
+    /// Set how long a burst waits for worker spikes before failing
+    pub fn with_barrier_timeout(mut self, barrier_timeout: Duration) -> Self {
+        self.link.set_barrier_timeout(barrier_timeout);
+        self
+    }
+
+    /// Worker partitions the coordinator exchanges spikes with
+    pub fn peers(&self) -> &[PartitionId] {
+        self.link.peers()
+    }
+
+    /// Bursts run in lockstep with the workers since attaching
+    pub fn burst(&self) -> u64 {
+        self.link.step()
+    }
+
+    /// Number of neurons kept on the coordinator
+    pub fn neuron_count(&self) -> usize {
+        self.neuron_count
+    }
+
+    /// Number of synapses that arrive from worker partitions
+    pub fn remote_synapse_count(&self) -> usize {
+        self.link.remote_synapse_count()
+    }
+
+    /// Stop the spike exchange
+    pub fn shutdown(mut self) -> Result<(), DistributedError> {
+        self.exchange.stop()
+    }
+
+    /// Wait for the workers' spikes from the previous burst.
+    ///
+    /// Returns the potentials to stage on the NPU before processing the burst.
+    pub(crate) fn begin_burst(&mut self) -> Result<Vec<(NeuronId, f32)>, DistributedError> {
+        self.link
+            .begin_burst(self.exchange.as_mut())
+            .map(|(staged, _)| staged)
+    }
+
+    /// Send the neurons that fired this burst to the workers
+    pub(crate) fn end_burst(&mut self, fired_neurons: &[u32]) -> Result<u64, DistributedError> {
+        self.link.end_burst(self.exchange.as_mut(), fired_neurons)
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-partition spike batches and the transports that carry them.
+
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+
+use super::{DistributedError, PartitionId};
+
+/// Neurons of one partition that fired in one burst and have synapses onto a peer.
+///
+/// Neuron IDs are *global* (connectome snapshot) IDs. A partition sends one
+/// batch to every peer each burst, even an empty one, because peers wait for
+/// it before starting the next burst.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpikeBatch {
+    /// Burst in which the neurons fired
+    pub burst: u64,
+    /// Partition that produced the spikes
+    pub source: PartitionId,
+    /// Global IDs of the fired neurons
+    pub neurons: Vec<u32>,
+}
+
+impl SpikeBatch {
+    /// Wire magic (`FSPK`)
+    pub const MAGIC: [u8; 4] = *b"FSPK";
+    /// Header size: magic(4) + burst(8) + source(4) + count(4)
+    pub const HEADER_LEN: usize = 20;
+
+    /// Encode as little-endian bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::HEADER_LEN + self.neurons.len() * 4);
+        bytes.extend_from_slice(&Self::MAGIC);
+        bytes.extend_from_slice(&self.burst.to_le_bytes());
+        bytes.extend_from_slice(&self.source.to_le_bytes());
+        bytes.extend_from_slice(&(self.neurons.len() as u32).to_le_bytes());
+        for neuron in &self.neurons {
+            bytes.extend_from_slice(&neuron.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode bytes produced by [`SpikeBatch::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DistributedError> {
+        if bytes.len() < Self::HEADER_LEN {
+            return Err(DistributedError::MalformedBatch(format!(
+                "{} bytes is shorter than the {}-byte header",
+                bytes.len(),
+                Self::HEADER_LEN
+            )));
+        }
+        if bytes[0..4] != Self::MAGIC {
+            return Err(DistributedError::MalformedBatch("bad magic".to_string()));
+        }
+        let burst = u64::from_le_bytes(bytes[4..12].try_into().unwrap());
+        let source = u32::from_le_bytes(bytes[12..16].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[16..20].try_into().unwrap()) as usize;
+        let body = &bytes[Self::HEADER_LEN..];
+        if body.len() != count * 4 {
+            return Err(DistributedError::MalformedBatch(format!(
+                "header declares {} neurons but body holds {} bytes",
+                count,
+                body.len()
+            )));
+        }
+        let neurons = body
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        Ok(Self {
+            burst,
+            source,
+            neurons,
+        })
+    }
+}
+
+/// Transport used by a partition to trade spike batches with its peers.
+///
+/// Implementations must deliver every batch exactly once and keep batches from
+/// the same sender in order. They do not need to order batches from different
+/// senders.
+pub trait SpikeExchange: Send {
+    /// Partition this endpoint belongs to
+    fn partition(&self) -> PartitionId;
+
+    /// Send a batch to one peer
+    fn send(&mut self, peer: PartitionId, batch: &SpikeBatch) -> Result<(), DistributedError>;
+
+    /// Take the next received batch, if any (never blocks)
+    fn try_recv(&mut self) -> Result<Option<SpikeBatch>, DistributedError>;
+
+    /// Release transport resources once the partition leaves the run
+    fn stop(&mut self) -> Result<(), DistributedError> {
+        Ok(())
+    }
+}
+
+/// In-process exchange for partitions running as threads of one process
+pub struct ChannelSpikeExchange {
+    partition: PartitionId,
+    peers: Vec<Option<Sender<SpikeBatch>>>,
+    inbox: Receiver<SpikeBatch>,
+}
+
+impl ChannelSpikeExchange {
+    /// Create fully connected endpoints for `partition_count` partitions.
+    ///
+    /// Endpoint `i` of the returned vector belongs to partition `i`.
+    pub fn mesh(partition_count: u32) -> Vec<Self> {
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            (0..partition_count).map(|_| channel()).unzip();
+
+        receivers
+            .into_iter()
+            .enumerate()
+            .map(|(partition, inbox)| Self {
+                partition: partition as PartitionId,
+                peers: senders
+                    .iter()
+                    .enumerate()
+                    .map(|(peer, sender)| (peer != partition).then(|| sender.clone()))
+                    .collect(),
+                inbox,
+            })
+            .collect()
+    }
+}
+
+impl SpikeExchange for ChannelSpikeExchange {
+    fn partition(&self) -> PartitionId {
+        self.partition
+    }
+
+    fn send(&mut self, peer: PartitionId, batch: &SpikeBatch) -> Result<(), DistributedError> {
+        let sender = self
+            .peers
+            .get(peer as usize)
+            .and_then(Option::as_ref)
+            .ok_or(DistributedError::UnknownPeer(peer))?;
+        // A peer that already shut down has no use for further spikes. One that
+        // dies mid-run is caught by the barrier of the partitions waiting on it.
+        let _ = sender.send(batch.clone());
+        Ok(())
+    }
+
+    fn try_recv(&mut self) -> Result<Option<SpikeBatch>, DistributedError> {
+        match self.inbox.try_recv() {
+            Ok(batch) => Ok(Some(batch)),
+            Err(TryRecvError::Empty) => Ok(None),
+            // Our own sender clones live in the peers; disconnection means all peers are gone
+            Err(TryRecvError::Disconnected) => Err(DistributedError::Transport(
+                "all peer partitions hung up".to_string(),
+            )),
+        }
+    }
+}
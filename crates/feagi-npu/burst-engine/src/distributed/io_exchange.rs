@@ -0,0 +1,234 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Spike exchange over `feagi-io` transports (separate processes or machines).
+//!
+//! Each partition binds one router server and connects one requester client to
+//! every peer's router. The requester/router pair is used one-way: batches
+//! are sent as requests and no reply is expected. Unlike the sensory puller,
+//! the router delivers every message, which the burst barrier relies on.
+//!
+//! Shared-memory rings drop frames only when a writer laps a reader. The burst
+//! barrier keeps every peer at most one burst ahead, so at most two batches per
+//! peer are in flight and [`IoSpikeExchange::shared_memory`] sizes the rings
+//! well above that.
+
+use std::time::{Duration, Instant};
+
+use ahash::AHashMap;
+use feagi_io::protocol_implementations::shared_memory::{
+    FeagiSharedMemoryClientRequesterProperties, FeagiSharedMemoryServerRouterProperties,
+    DEFAULT_SLOT_COUNT,
+};
+use feagi_io::protocol_implementations::zmq::{
+    FeagiZmqClientRequesterProperties, FeagiZmqServerRouterProperties,
+};
+use feagi_io::traits_and_enums::client::{FeagiClientRequester, FeagiClientRequesterProperties};
+use feagi_io::traits_and_enums::server::{FeagiServerRouter, FeagiServerRouterProperties};
+use feagi_io::traits_and_enums::shared::FeagiEndpointState;
+use feagi_io::AgentID;
+
+use super::{DistributedError, PartitionId, SpikeBatch, SpikeExchange};
+
+/// [`SpikeExchange`] over a `feagi-io` router plus one requester per peer
+pub struct IoSpikeExchange {
+    partition: PartitionId,
+    router: Box<dyn FeagiServerRouter>,
+    peers: AHashMap<PartitionId, Box<dyn FeagiClientRequester>>,
+    send_timeout: Duration,
+}
+
+impl IoSpikeExchange {
+    /// Wrap already-created transport endpoints
+    pub fn new(
+        partition: PartitionId,
+        router: Box<dyn FeagiServerRouter>,
+        peers: Vec<(PartitionId, Box<dyn FeagiClientRequester>)>,
+    ) -> Self {
+        Self {
+            partition,
+            router,
+            peers: peers.into_iter().collect(),
+            send_timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// ZMQ exchange where partition `i` binds `endpoints[i]` and connects to the others.
+    ///
+    /// Endpoints are ZMQ URLs such as `tcp://127.0.0.1:7100` or `ipc:///tmp/feagi-p0`.
+    pub fn zmq(partition: PartitionId, endpoints: &[String]) -> Result<Self, DistributedError> {
+        let own =
+            endpoints
+                .get(partition as usize)
+                .ok_or(DistributedError::PartitionOutOfRange {
+                    partition,
+                    partition_count: endpoints.len() as u32,
+                })?;
+        let router = FeagiZmqServerRouterProperties::new(own, own)
+            .map_err(|e| DistributedError::Transport(e.to_string()))?
+            .as_boxed_server_router();
+
+        let mut peers = Vec::with_capacity(endpoints.len().saturating_sub(1));
+        for (peer, endpoint) in endpoints.iter().enumerate() {
+            if peer == partition as usize {
+                continue;
+            }
+            let requester = FeagiZmqClientRequesterProperties::new(endpoint)
+                .map_err(|e| DistributedError::Transport(e.to_string()))?
+                .as_boxed_client_requester();
+            peers.push((peer as PartitionId, requester));
+        }
+
+        Ok(Self::new(partition, router, peers))
+    }
+
+    /// Shared-memory exchange where partition `i` creates the rings at `paths[i]`.
+    ///
+    /// For partitions on the same host. Paths name ring files, preferably on a
+    /// tmpfs such as `/dev/shm`; the router adds `.requests`/`.responses`
+    /// suffixes. `max_batch_neurons` bounds the neurons one batch can carry,
+    /// typically the largest number of neurons a partition owns.
+    pub fn shared_memory(
+        partition: PartitionId,
+        paths: &[String],
+        max_batch_neurons: u32,
+    ) -> Result<Self, DistributedError> {
+        let own = paths
+            .get(partition as usize)
+            .ok_or(DistributedError::PartitionOutOfRange {
+                partition,
+                partition_count: paths.len() as u32,
+            })?;
+        // Every peer writes into our request ring and may run one burst ahead
+        let slot_count = DEFAULT_SLOT_COUNT.max(4 * paths.len() as u32);
+        let max_frame_byte_count = (SpikeBatch::HEADER_LEN
+            + AgentID::NUMBER_BYTES
+            + 4 * max_batch_neurons as usize) as u32;
+        let router = FeagiSharedMemoryServerRouterProperties::new_with_ring_size(
+            own,
+            own,
+            slot_count,
+            max_frame_byte_count,
+        )
+        .map_err(|e| DistributedError::Transport(e.to_string()))?
+        .as_boxed_server_router();
+
+        let mut peers = Vec::with_capacity(paths.len().saturating_sub(1));
+        for (peer, path) in paths.iter().enumerate() {
+            if peer == partition as usize {
+                continue;
+            }
+            let requester = FeagiSharedMemoryClientRequesterProperties::new(path)
+                .map_err(|e| DistributedError::Transport(e.to_string()))?
+                .as_boxed_client_requester();
+            peers.push((peer as PartitionId, requester));
+        }
+
+        Ok(Self::new(partition, router, peers))
+    }
+
+    /// Set how long [`SpikeExchange::send`] retries while a peer's transport would block
+    pub fn with_send_timeout(mut self, send_timeout: Duration) -> Self {
+        self.send_timeout = send_timeout;
+        self
+    }
+
+    /// Bind the router and connect to every peer, waiting until all are active.
+    ///
+    /// Connecting is retried until `timeout`, since peers started at the same
+    /// time may not have bound their routers yet.
+    pub fn start(&mut self, timeout: Duration) -> Result<(), DistributedError> {
+        self.router
+            .request_start()
+            .map_err(|e| DistributedError::Transport(e.to_string()))?;
+
+        let deadline = Instant::now() + timeout;
+        let mut last_error = None;
+        loop {
+            let mut ready = is_active(self.router.poll())?;
+            for requester in self.peers.values_mut() {
+                if matches!(requester.poll(), FeagiEndpointState::Inactive) {
+                    if let Err(e) = requester.request_connect() {
+                        last_error = Some(e.to_string());
+                    }
+                }
+                ready &= is_active(requester.poll())?;
+            }
+            if ready {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(DistributedError::Transport(format!(
+                    "partition {} transports did not become active{}",
+                    self.partition,
+                    last_error.map(|e| format!(": {}", e)).unwrap_or_default()
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
+
+fn is_active(state: &FeagiEndpointState) -> Result<bool, DistributedError> {
+    match state {
+        FeagiEndpointState::ActiveWaiting | FeagiEndpointState::ActiveHasData => Ok(true),
+        FeagiEndpointState::Errored(e) => Err(DistributedError::Transport(e.to_string())),
+        FeagiEndpointState::Inactive | FeagiEndpointState::Pending => Ok(false),
+    }
+}
+
+impl SpikeExchange for IoSpikeExchange {
+    fn partition(&self) -> PartitionId {
+        self.partition
+    }
+
+    fn send(&mut self, peer: PartitionId, batch: &SpikeBatch) -> Result<(), DistributedError> {
+        let requester = self
+            .peers
+            .get_mut(&peer)
+            .ok_or(DistributedError::UnknownPeer(peer))?;
+        let bytes = batch.to_bytes();
+
+        // Non-blocking transports report a full queue as a send failure; retry
+        // until the peer drains it rather than dropping spikes.
+        let deadline = Instant::now() + self.send_timeout;
+        loop {
+            match requester.publish_request(&bytes) {
+                Ok(()) => return Ok(()),
+                Err(e) if Instant::now() >= deadline => {
+                    return Err(DistributedError::Transport(format!(
+                        "send to partition {} failed: {}",
+                        peer, e
+                    )))
+                }
+                Err(_) => std::thread::sleep(Duration::from_micros(100)),
+            }
+        }
+    }
+
+    fn try_recv(&mut self) -> Result<Option<SpikeBatch>, DistributedError> {
+        match self.router.poll() {
+            FeagiEndpointState::ActiveHasData => {
+                let (_, payload) = self
+                    .router
+                    .consume_retrieved_request()
+                    .map_err(|e| DistributedError::Transport(e.to_string()))?;
+                SpikeBatch::from_bytes(payload).map(Some)
+            }
+            FeagiEndpointState::Errored(e) => Err(DistributedError::Transport(e.to_string())),
+            _ => Ok(None),
+        }
+    }
+
+    /// Disconnect from peers and unbind the router
+    fn stop(&mut self) -> Result<(), DistributedError> {
+        for requester in self.peers.values_mut() {
+            requester
+                .request_disconnect()
+                .map_err(|e| DistributedError::Transport(e.to_string()))?;
+        }
+        self.router
+            .request_stop()
+            .map_err(|e| DistributedError::Transport(e.to_string()))
+    }
+}
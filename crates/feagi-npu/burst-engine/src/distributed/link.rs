@@ -0,0 +1,275 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-partition wiring and burst barrier shared by workers and the coordinator.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use ahash::AHashMap;
+use feagi_npu_neural::synapse::{compute_synaptic_contribution, SynapseType};
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
+use feagi_npu_neural::types::NeuronId;
+
+use super::{DistributedError, PartitionId, PartitionPlan, SpikeBatch, SpikeExchange};
+
+/// Owning partition of every neuron in `snapshot` (`None` for deleted neurons)
+pub(crate) fn owners(
+    snapshot: &ConnectomeSnapshot,
+    plan: &PartitionPlan,
+) -> Result<Vec<Option<PartitionId>>, DistributedError> {
+    let neurons = &snapshot.neurons;
+    (0..neurons.count)
+        .map(|global| {
+            if !neurons.valid_mask[global] {
+                return Ok(None);
+            }
+            let cortical_idx = neurons.cortical_areas[global];
+            plan.partition_of(cortical_idx)
+                .map(Some)
+                .ok_or(DistributedError::UnassignedArea { cortical_idx })
+        })
+        .collect()
+}
+
+/// Where a valid snapshot synapse lives relative to one partition
+pub(crate) enum SynapsePlacement {
+    /// Source and target are both owned by the partition
+    Local,
+    /// Only the target is owned; the source fires on another partition
+    Incoming,
+    /// Only the source is owned; the target belongs to the given peer
+    Outgoing(PartitionId),
+    /// Neither end is owned
+    Foreign,
+}
+
+/// Classify a snapshot synapse for `partition` (`None` if an end is deleted)
+pub(crate) fn placement(
+    owner_of: &[Option<PartitionId>],
+    partition: PartitionId,
+    source: u32,
+    target: u32,
+) -> Option<SynapsePlacement> {
+    let source_owner = owner_of.get(source as usize).copied().flatten()?;
+    let target_owner = owner_of.get(target as usize).copied().flatten()?;
+    Some(
+        match (source_owner == partition, target_owner == partition) {
+            (true, true) => SynapsePlacement::Local,
+            (false, true) => SynapsePlacement::Incoming,
+            (true, false) => SynapsePlacement::Outgoing(target_owner),
+            (false, false) => SynapsePlacement::Foreign,
+        },
+    )
+}
+
+/// Type of the synapse at `idx` in the snapshot
+pub(crate) fn synapse_type(snapshot: &ConnectomeSnapshot, idx: usize) -> SynapseType {
+    if snapshot.synapses.types[idx] == 0 {
+        SynapseType::Excitatory
+    } else {
+        SynapseType::Inhibitory
+    }
+}
+
+/// Spike routing and lockstep barrier of one partition.
+///
+/// Burst numbers on the wire count lockstep steps taken by this link, not the
+/// NPU's own burst counter, so an NPU that already ran bursts before joining
+/// (the coordinator's) stays aligned with freshly built workers.
+pub(crate) struct PartitionLink {
+    partition: PartitionId,
+    peers: Vec<PartitionId>,
+    /// Remote source (global) → local targets with precomputed contribution
+    remote_inputs: AHashMap<u32, Vec<(NeuronId, f32)>>,
+    /// Local source (global ID) → peers that own at least one of its targets
+    routes: AHashMap<u32, Vec<PartitionId>>,
+    /// Batches that arrived ahead of the barrier, by burst
+    inbox: BTreeMap<u64, Vec<SpikeBatch>>,
+    barrier_timeout: Duration,
+    /// Lockstep bursts completed
+    step: u64,
+}
+
+impl PartitionLink {
+    /// Wire `partition` for every cross-partition synapse of `snapshot`.
+    ///
+    /// `local_of` maps an owned global neuron ID to its ID in the partition's NPU.
+    pub(crate) fn build(
+        snapshot: &ConnectomeSnapshot,
+        plan: &PartitionPlan,
+        partition: PartitionId,
+        owner_of: &[Option<PartitionId>],
+        local_of: impl Fn(u32) -> u32,
+    ) -> Self {
+        let synapses = &snapshot.synapses;
+        let mut remote_inputs: AHashMap<u32, Vec<(NeuronId, f32)>> = AHashMap::new();
+        let mut routes: AHashMap<u32, Vec<PartitionId>> = AHashMap::new();
+        for idx in 0..synapses.count {
+            if !synapses.valid_mask[idx] {
+                continue;
+            }
+            let source = synapses.source_neurons[idx];
+            let target = synapses.target_neurons[idx];
+            match placement(owner_of, partition, source, target) {
+                Some(SynapsePlacement::Incoming) => {
+                    let contribution = compute_synaptic_contribution(
+                        synapses.weights[idx],
+                        synapses.postsynaptic_potentials[idx],
+                        synapse_type(snapshot, idx),
+                    );
+                    remote_inputs
+                        .entry(source)
+                        .or_default()
+                        .push((NeuronId(local_of(target)), contribution));
+                }
+                Some(SynapsePlacement::Outgoing(peer)) => {
+                    let peers = routes.entry(source).or_default();
+                    if !peers.contains(&peer) {
+                        peers.push(peer);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            partition,
+            peers: plan.peers_of(partition),
+            remote_inputs,
+            routes,
+            inbox: BTreeMap::new(),
+            barrier_timeout: Duration::from_secs(10),
+            step: 0,
+        }
+    }
+
+    pub(crate) fn set_barrier_timeout(&mut self, barrier_timeout: Duration) {
+        self.barrier_timeout = barrier_timeout;
+    }
+
+    pub(crate) fn partition(&self) -> PartitionId {
+        self.partition
+    }
+
+    pub(crate) fn peers(&self) -> &[PartitionId] {
+        &self.peers
+    }
+
+    pub(crate) fn step(&self) -> u64 {
+        self.step
+    }
+
+    pub(crate) fn remote_synapse_count(&self) -> usize {
+        self.remote_inputs.values().map(Vec::len).sum()
+    }
+
+    /// Wait for every peer's spikes from the previous burst.
+    ///
+    /// Returns the summed contributions to stage on local neurons and the
+    /// number of remote spikes received.
+    pub(crate) fn begin_burst(
+        &mut self,
+        exchange: &mut dyn SpikeExchange,
+    ) -> Result<(Vec<(NeuronId, f32)>, usize), DistributedError> {
+        if self.step == 0 || self.peers.is_empty() {
+            return Ok((Vec::new(), 0));
+        }
+
+        let batches = self.await_peers(exchange, self.step)?;
+        let mut remote_spikes = 0;
+        let mut potentials: AHashMap<NeuronId, f32> = AHashMap::new();
+        for batch in &batches {
+            remote_spikes += batch.neurons.len();
+            for source in &batch.neurons {
+                for &(target, contribution) in self.remote_inputs.get(source).into_iter().flatten()
+                {
+                    *potentials.entry(target).or_insert(0.0) += contribution;
+                }
+            }
+        }
+        Ok((potentials.into_iter().collect(), remote_spikes))
+    }
+
+    /// Send this burst's spikes (global IDs) to every peer and advance the clock.
+    ///
+    /// Returns the burst number the spikes were sent for.
+    pub(crate) fn end_burst(
+        &mut self,
+        exchange: &mut dyn SpikeExchange,
+        fired_neurons: &[u32],
+    ) -> Result<u64, DistributedError> {
+        self.step += 1;
+
+        let mut outgoing: AHashMap<PartitionId, Vec<u32>> = AHashMap::new();
+        for &neuron in fired_neurons {
+            for &peer in self.routes.get(&neuron).into_iter().flatten() {
+                outgoing.entry(peer).or_default().push(neuron);
+            }
+        }
+        for &peer in &self.peers {
+            let batch = SpikeBatch {
+                burst: self.step,
+                source: self.partition,
+                neurons: outgoing.remove(&peer).unwrap_or_default(),
+            };
+            exchange.send(peer, &batch)?;
+        }
+        Ok(self.step)
+    }
+
+    /// Block until every peer's batch for `burst` is in, buffering early arrivals
+    fn await_peers(
+        &mut self,
+        exchange: &mut dyn SpikeExchange,
+        burst: u64,
+    ) -> Result<Vec<SpikeBatch>, DistributedError> {
+        let deadline = Instant::now() + self.barrier_timeout;
+        loop {
+            if self.inbox.get(&burst).map_or(0, Vec::len) == self.peers.len() {
+                return Ok(self.inbox.remove(&burst).unwrap_or_default());
+            }
+
+            match exchange.try_recv()? {
+                Some(batch) => self.accept(batch, burst)?,
+                None if Instant::now() >= deadline => {
+                    let arrived = self.inbox.get(&burst);
+                    let missing = self
+                        .peers
+                        .iter()
+                        .copied()
+                        .filter(|&peer| {
+                            !arrived.is_some_and(|batches| batches.iter().any(|b| b.source == peer))
+                        })
+                        .collect();
+                    return Err(DistributedError::BarrierTimeout { burst, missing });
+                }
+                None => std::thread::sleep(Duration::from_micros(50)),
+            }
+        }
+    }
+
+    fn accept(&mut self, batch: SpikeBatch, expected: u64) -> Result<(), DistributedError> {
+        if !self.peers.contains(&batch.source) {
+            return Err(DistributedError::UnknownPeer(batch.source));
+        }
+        // A peer can be at most one burst ahead: it cannot finish burst N+2
+        // without our spikes from burst N+1.
+        if batch.burst < expected || batch.burst > expected + 1 {
+            return Err(DistributedError::ClockSkew {
+                source_partition: batch.source,
+                expected,
+                received: batch.burst,
+            });
+        }
+        let pending = self.inbox.entry(batch.burst).or_default();
+        if pending.iter().any(|b| b.source == batch.source) {
+            return Err(DistributedError::MalformedBatch(format!(
+                "duplicate batch from partition {} for burst {}",
+                batch.source, batch.burst
+            )));
+        }
+        pending.push(batch);
+        Ok(())
+    }
+}
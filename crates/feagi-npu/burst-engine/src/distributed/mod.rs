@@ -0,0 +1,120 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Distributed NPU (partitioned burst engine)
+//!
+//! Runs one brain across several burst-engine workers by assigning whole
+//! cortical areas to partitions.
+//!
+//! ## Architecture
+//! ```text
+//!  ┌──────────────────────┐   SpikeBatch(burst N)   ┌──────────────────────┐
+//!  │ PartitionWorker #0   │ ──────────────────────▶ │ PartitionWorker #1   │
+//!  │  RustNPU (areas A,B) │ ◀────────────────────── │  RustNPU (areas C,D) │
+//!  │  + remote synapses   │   SpikeBatch(burst N)   │  + remote synapses   │
+//!  └──────────────────────┘                         └──────────────────────┘
+//! ```
+//!
+//! - Each worker owns a [`RustNPU`](crate::RustNPU) holding its own neurons and
+//!   every synapse whose source and target are both local.
+//! - Synapses whose target is local but whose source lives on another partition
+//!   are kept by the worker as *remote inputs*. After burst N every worker sends
+//!   each peer the fired neurons that peer listens to; before burst N+1 the peer
+//!   turns them into staged potentials. This is the same t+1 timing as local
+//!   propagation, so partitioned output matches a single NPU.
+//! - A burst does not start until every peer's batch for the previous burst has
+//!   arrived. This barrier is the shared burst clock, and a worker that falls
+//!   out of step surfaces as [`DistributedError::ClockSkew`].
+//!
+//! Partition 0 is the coordinator: it holds the core areas and runs inside the
+//! regular [`BurstLoopRunner`](crate::BurstLoopRunner) as a
+//! [`PartitionCoordinator`], attached with
+//! `BurstLoopRunner::attach_partition_coordinator`. Sensory input, motor output
+//! and visualization therefore keep flowing through the runner, while the other
+//! partitions run as [`PartitionWorker`]s that call [`PartitionWorker::step`]
+//! in a loop.
+//!
+//! Spikes travel through a [`SpikeExchange`]. [`ChannelSpikeExchange`] connects
+//! partitions running as threads in one process. With the `distributed`
+//! feature, `IoSpikeExchange` connects separate processes over `feagi-io`
+//! router/requester transports: ZMQ for partitions on other machines, or
+//! shared-memory rings for partitions on the same host.
+//!
+//! ## Limitations
+//! Cross-partition contributions use the static synapse PSP
+//! (`weight × psp × sign`). Areas that enable `mp_driven_psp` or disable PSP
+//! uniform distribution need the source neuron's state, which is not shipped
+//! between partitions. Keep such projections inside one partition.
+
+mod coordinator;
+mod exchange;
+#[cfg(feature = "distributed")]
+mod io_exchange;
+mod link;
+mod plan;
+mod worker;
+
+pub use coordinator::*;
+pub use exchange::*;
+#[cfg(feature = "distributed")]
+pub use io_exchange::*;
+pub use plan::*;
+pub use worker::*;
+
+/// Errors raised by partition planning, spike exchange and partition workers
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DistributedError {
+    #[error("partition count must be > 0")]
+    InvalidPartitionCount,
+
+    #[error("partition {partition} is out of range (partition_count={partition_count})")]
+    PartitionOutOfRange {
+        partition: PartitionId,
+        partition_count: u32,
+    },
+
+    #[error("core area {cortical_idx} must stay on the coordinator partition 0, not {partition}")]
+    CoreAreaOffCoordinator {
+        cortical_idx: u32,
+        partition: PartitionId,
+    },
+
+    #[error("cortical area {cortical_idx} is not assigned to any partition")]
+    UnassignedArea { cortical_idx: u32 },
+
+    #[error("cortical area {cortical_idx} has no registered name in the connectome")]
+    MissingAreaName { cortical_idx: u32 },
+
+    #[error("partition {0} cannot coordinate; the coordinator is partition 0")]
+    NotCoordinator(PartitionId),
+
+    #[error("connectome snapshot does not match the NPU: {0}")]
+    SnapshotMismatch(String),
+
+    #[error("NPU error: {0}")]
+    Npu(String),
+
+    #[error("malformed spike batch: {0}")]
+    MalformedBatch(String),
+
+    #[error("unknown peer partition {0}")]
+    UnknownPeer(PartitionId),
+
+    #[error(
+        "burst clock skew: partition {source_partition} sent burst {received}, expected {expected}"
+    )]
+    ClockSkew {
+        source_partition: PartitionId,
+        expected: u64,
+        received: u64,
+    },
+
+    #[error("timed out waiting for burst {burst} spikes from partitions {missing:?}")]
+    BarrierTimeout {
+        burst: u64,
+        missing: Vec<PartitionId>,
+    },
+
+    #[error("transport error: {0}")]
+    Transport(String),
+}
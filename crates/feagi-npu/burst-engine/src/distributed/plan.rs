@@ -0,0 +1,156 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cortical area → partition assignment.
+
+use std::collections::BTreeMap;
+
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
+
+use super::DistributedError;
+
+/// Index of a partition (burst-engine worker) in a distributed NPU
+pub type PartitionId = u32;
+
+/// Highest cortical index of the core areas (death, power, fatigue).
+///
+/// The NPU creates core neurons with deterministic IDs when these areas are
+/// registered, so they always live on partition 0 (the coordinator).
+pub const MAX_CORE_CORTICAL_IDX: u32 = 2;
+
+/// Assignment of cortical areas to partitions.
+///
+/// Every process of a distributed NPU must use the same plan. Plans built
+/// with [`PartitionPlan::balanced`] are deterministic for a given snapshot, so
+/// each process can compute its own copy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionPlan {
+    partition_count: u32,
+    assignments: BTreeMap<u32, PartitionId>,
+}
+
+impl PartitionPlan {
+    /// Create an empty plan for `partition_count` partitions
+    pub fn new(partition_count: u32) -> Result<Self, DistributedError> {
+        if partition_count == 0 {
+            return Err(DistributedError::InvalidPartitionCount);
+        }
+        Ok(Self {
+            partition_count,
+            assignments: BTreeMap::new(),
+        })
+    }
+
+    /// Plan that keeps every cortical area of `snapshot` on partition 0
+    pub fn single(snapshot: &ConnectomeSnapshot) -> Self {
+        let assignments = snapshot
+            .cortical_area_names
+            .keys()
+            .map(|&cortical_idx| (cortical_idx, 0))
+            .collect();
+        Self {
+            partition_count: 1,
+            assignments,
+        }
+    }
+
+    /// Spread the areas of `snapshot` over `partition_count` partitions by neuron count.
+    ///
+    /// Areas are placed largest-first on the least loaded partition (ties go to
+    /// the lowest partition ID). Core areas are pinned to partition 0 and count
+    /// towards its load.
+    pub fn balanced(
+        snapshot: &ConnectomeSnapshot,
+        partition_count: u32,
+    ) -> Result<Self, DistributedError> {
+        let mut plan = Self::new(partition_count)?;
+
+        let mut neuron_counts: BTreeMap<u32, usize> = snapshot
+            .cortical_area_names
+            .keys()
+            .map(|&cortical_idx| (cortical_idx, 0))
+            .collect();
+        let neurons = &snapshot.neurons;
+        for idx in 0..neurons.count {
+            if neurons.valid_mask[idx] {
+                *neuron_counts
+                    .entry(neurons.cortical_areas[idx])
+                    .or_insert(0) += 1;
+            }
+        }
+
+        let mut loads = vec![0usize; partition_count as usize];
+        let mut regular: Vec<(u32, usize)> = Vec::with_capacity(neuron_counts.len());
+        for (&cortical_idx, &count) in &neuron_counts {
+            if cortical_idx <= MAX_CORE_CORTICAL_IDX {
+                plan.assignments.insert(cortical_idx, 0);
+                loads[0] += count;
+            } else {
+                regular.push((cortical_idx, count));
+            }
+        }
+
+        // Largest first; BTreeMap order already breaks ties by cortical index
+        regular.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        for (cortical_idx, count) in regular {
+            let (partition, _) = loads
+                .iter()
+                .enumerate()
+                .min_by_key(|&(partition, &load)| (load, partition))
+                .expect("partition_count > 0");
+            loads[partition] += count;
+            plan.assignments
+                .insert(cortical_idx, partition as PartitionId);
+        }
+
+        Ok(plan)
+    }
+
+    /// Assign a cortical area to a partition (replaces any earlier assignment)
+    pub fn assign(
+        &mut self,
+        cortical_idx: u32,
+        partition: PartitionId,
+    ) -> Result<(), DistributedError> {
+        if partition >= self.partition_count {
+            return Err(DistributedError::PartitionOutOfRange {
+                partition,
+                partition_count: self.partition_count,
+            });
+        }
+        if cortical_idx <= MAX_CORE_CORTICAL_IDX && partition != 0 {
+            return Err(DistributedError::CoreAreaOffCoordinator {
+                cortical_idx,
+                partition,
+            });
+        }
+        self.assignments.insert(cortical_idx, partition);
+        Ok(())
+    }
+
+    /// Number of partitions in this plan
+    pub fn partition_count(&self) -> u32 {
+        self.partition_count
+    }
+
+    /// Partition that owns a cortical area
+    pub fn partition_of(&self, cortical_idx: u32) -> Option<PartitionId> {
+        self.assignments.get(&cortical_idx).copied()
+    }
+
+    /// Cortical areas owned by a partition, in ascending order
+    pub fn areas_of(&self, partition: PartitionId) -> Vec<u32> {
+        self.assignments
+            .iter()
+            .filter(|(_, &owner)| owner == partition)
+            .map(|(&cortical_idx, _)| cortical_idx)
+            .collect()
+    }
+
+    /// Every partition except `partition`, in ascending order
+    pub fn peers_of(&self, partition: PartitionId) -> Vec<PartitionId> {
+        (0..self.partition_count)
+            .filter(|&peer| peer != partition)
+            .collect()
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Burst-engine worker that runs one partition of a distributed NPU.
+
+use std::time::Duration;
+
+use ahash::AHashMap;
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
+use feagi_npu_neural::types::{NeuralValue, NeuronId, SynapticPsp, SynapticWeight};
+use feagi_npu_runtime::Runtime;
+
+use super::link::{owners, placement, synapse_type, PartitionLink, SynapsePlacement};
+use super::{DistributedError, PartitionId, PartitionPlan, SpikeExchange, MAX_CORE_CORTICAL_IDX};
+use crate::backend::ComputeBackend;
+use crate::npu::RustNPU;
+
+/// Outcome of one burst on one partition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionBurst {
+    /// Burst number (identical on every partition)
+    pub burst: u64,
+    /// Global IDs of the local neurons that fired, ascending
+    pub fired_neurons: Vec<u32>,
+    /// Spikes received from peers for the previous burst
+    pub remote_spikes: usize,
+}
+
+/// One partition of a distributed NPU.
+///
+/// Wraps a [`RustNPU`] that holds only the neurons of the partition's cortical
+/// areas. Neuron IDs inside that NPU are local; everything this type exposes
+/// uses the global IDs of the source [`ConnectomeSnapshot`].
+pub struct PartitionWorker<
+    R: Runtime,
+    T: NeuralValue,
+    B: ComputeBackend<T, R::NeuronStorage<T>, R::SynapseStorage>,
+> {
+    npu: RustNPU<R, T, B>,
+    link: PartitionLink,
+    global_to_local: AHashMap<u32, u32>,
+    local_to_global: AHashMap<u32, u32>,
+}
+
+impl<R: Runtime, T: NeuralValue, B: ComputeBackend<T, R::NeuronStorage<T>, R::SynapseStorage>>
+    PartitionWorker<R, T, B>
+{
+    /// Load this partition's share of `snapshot` into an empty `npu`.
+    ///
+    /// Neuron parameters and membrane potentials are restored from the snapshot.
+    pub fn build(
+        mut npu: RustNPU<R, T, B>,
+        snapshot: &ConnectomeSnapshot,
+        plan: &PartitionPlan,
+        partition: PartitionId,
+    ) -> Result<Self, DistributedError> {
+        if partition >= plan.partition_count() {
+            return Err(DistributedError::PartitionOutOfRange {
+                partition,
+                partition_count: plan.partition_count(),
+            });
+        }
+
+        // Register owned areas in ascending order so core areas (0, 1, 2) get
+        // their deterministic neurons exactly as in a single NPU.
+        for cortical_idx in plan.areas_of(partition) {
+            let name = snapshot
+                .cortical_area_names
+                .get(&cortical_idx)
+                .ok_or(DistributedError::MissingAreaName { cortical_idx })?;
+            npu.register_cortical_area(cortical_idx, name.clone());
+        }
+
+        let neurons = &snapshot.neurons;
+        let owner_of = owners(snapshot, plan)?;
+        let mut global_to_local = AHashMap::new();
+        let mut local_to_global = AHashMap::new();
+        let mut restored_ids = Vec::new();
+        let mut restored_potentials = Vec::new();
+        for (global, &owner) in owner_of.iter().enumerate() {
+            if owner != Some(partition) {
+                continue;
+            }
+            let cortical_idx = neurons.cortical_areas[global];
+
+            let local = if cortical_idx <= MAX_CORE_CORTICAL_IDX
+                && global as u32 == cortical_idx
+                && npu.is_neuron_valid(cortical_idx)
+            {
+                // Created by register_cortical_area
+                cortical_idx
+            } else {
+                let coords = &neurons.coordinates[global * 3..global * 3 + 3];
+                npu.add_neuron(
+                    T::from_f32(neurons.thresholds[global]),
+                    T::from_f32(neurons.threshold_limits[global]),
+                    neurons.leak_coefficients[global],
+                    T::from_f32(neurons.resting_potentials[global]),
+                    neurons.neuron_types[global],
+                    neurons.refractory_periods[global],
+                    neurons.excitabilities[global],
+                    neurons.consecutive_fire_limits[global],
+                    neurons.snooze_periods[global],
+                    neurons.mp_charge_accumulation[global],
+                    cortical_idx,
+                    coords[0],
+                    coords[1],
+                    coords[2],
+                )
+                .map_err(|e| DistributedError::Npu(e.to_string()))?
+                .0
+            };
+            global_to_local.insert(global as u32, local);
+            local_to_global.insert(local, global as u32);
+            restored_ids.push(local);
+            restored_potentials.push(neurons.membrane_potentials[global]);
+        }
+        npu.batch_update_membrane_potential(&restored_ids, &restored_potentials);
+
+        let synapses = &snapshot.synapses;
+        for idx in 0..synapses.count {
+            if !synapses.valid_mask[idx] {
+                continue;
+            }
+            let source = synapses.source_neurons[idx];
+            let target = synapses.target_neurons[idx];
+            if let Some(SynapsePlacement::Local) = placement(&owner_of, partition, source, target) {
+                npu.add_synapse(
+                    NeuronId(global_to_local[&source]),
+                    NeuronId(global_to_local[&target]),
+                    SynapticWeight(synapses.weights[idx]),
+                    SynapticPsp(synapses.postsynaptic_potentials[idx]),
+                    synapse_type(snapshot, idx),
+                )
+                .map_err(|e| DistributedError::Npu(e.to_string()))?;
+            }
+        }
+        npu.rebuild_synapse_index();
+        npu.set_power_amount(snapshot.power_amount);
+
+        let link = PartitionLink::build(snapshot, plan, partition, &owner_of, |global| {
+            global_to_local[&global]
+        });
+        Ok(Self {
+            npu,
+            link,
+            global_to_local,
+            local_to_global,
+        })
+    }
+
+    /// Set how long a burst waits for peer spikes before failing
+    pub fn with_barrier_timeout(mut self, barrier_timeout: Duration) -> Self {
+        self.link.set_barrier_timeout(barrier_timeout);
+        self
+    }
+
+    /// Partition this worker runs
+    pub fn partition(&self) -> PartitionId {
+        self.link.partition()
+    }
+
+    /// Partitions this worker exchanges spikes with
+    pub fn peers(&self) -> &[PartitionId] {
+        self.link.peers()
+    }
+
+    /// Underlying NPU (local neuron IDs)
+    pub fn npu(&self) -> &RustNPU<R, T, B> {
+        &self.npu
+    }
+
+    /// Number of neurons owned by this partition
+    pub fn neuron_count(&self) -> usize {
+        self.global_to_local.len()
+    }
+
+    /// Number of synapses that arrive from other partitions
+    pub fn remote_synapse_count(&self) -> usize {
+        self.link.remote_synapse_count()
+    }
+
+    /// Local NPU ID of a global neuron, if this partition owns it
+    pub fn local_id(&self, global: u32) -> Option<u32> {
+        self.global_to_local.get(&global).copied()
+    }
+
+    /// Global ID of a local NPU neuron
+    pub fn global_id(&self, local: u32) -> Option<u32> {
+        self.local_to_global.get(&local).copied()
+    }
+
+    /// Stage sensory potentials for the next burst, addressed by global neuron ID.
+    ///
+    /// Neurons owned by other partitions are skipped, so every partition can be
+    /// handed the same sensory frame. Returns the number of staged neurons.
+    pub fn inject_sensory_with_potentials(&mut self, neurons: &[(u32, f32)]) -> usize {
+        let staged: Vec<(NeuronId, f32)> = neurons
+            .iter()
+            .filter_map(|&(global, potential)| {
+                self.local_id(global)
+                    .map(|local| (NeuronId(local), potential))
+            })
+            .collect();
+        if !staged.is_empty() {
+            self.npu.inject_sensory_with_potentials(&staged);
+        }
+        staged.len()
+    }
+
+    /// Run one burst in lockstep with the peers.
+    ///
+    /// Waits for every peer's spikes from the previous burst, stages their
+    /// contributions, processes the burst and sends this burst's spikes on.
+    pub fn step(
+        &mut self,
+        exchange: &mut dyn SpikeExchange,
+    ) -> Result<PartitionBurst, DistributedError> {
+        let (staged, remote_spikes) = self.link.begin_burst(exchange)?;
+        if !staged.is_empty() {
+            self.npu.inject_sensory_with_potentials(&staged);
+        }
+
+        let result = self
+            .npu
+            .process_burst()
+            .map_err(|e| DistributedError::Npu(e.to_string()))?;
+
+        let mut fired_neurons: Vec<u32> = result
+            .fired_neurons
+            .iter()
+            .filter_map(|id| self.local_to_global.get(&id.0).copied())
+            .collect();
+        fired_neurons.sort_unstable();
+
+        let burst = self.link.end_burst(exchange, &fired_neurons)?;
+        Ok(PartitionBurst {
+            burst,
+            fired_neurons,
+            remote_spikes,
+        })
+    }
+}
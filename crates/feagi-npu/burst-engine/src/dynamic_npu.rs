@@ -77,12 +77,32 @@ where
         synapse_capacity: usize,
         fire_ledger_window: usize,
     ) -> Result<Self> {
-        let npu = RustNPU::new(
+        Self::new_f32_with_backend_config(
             runtime,
             backend,
             neuron_capacity,
             synapse_capacity,
             fire_ledger_window,
+            &crate::backend::BackendConfig::default(),
+        )
+    }
+
+    /// Create new f32 NPU whose burst phases use the threads configured in `backend_config`
+    pub fn new_f32_with_backend_config(
+        runtime: R,
+        backend: B,
+        neuron_capacity: usize,
+        synapse_capacity: usize,
+        fire_ledger_window: usize,
+        backend_config: &crate::backend::BackendConfig,
+    ) -> Result<Self> {
+        let npu = RustNPU::new_with_backend_config(
+            runtime,
+            backend,
+            neuron_capacity,
+            synapse_capacity,
+            fire_ledger_window,
+            backend_config,
         )?;
         Ok(DynamicNPUGeneric::F32(npu))
     }
@@ -95,12 +115,32 @@ where
         synapse_capacity: usize,
         fire_ledger_window: usize,
     ) -> Result<Self> {
-        let npu = RustNPU::new(
+        Self::new_int8_with_backend_config(
+            runtime,
+            backend,
+            neuron_capacity,
+            synapse_capacity,
+            fire_ledger_window,
+            &crate::backend::BackendConfig::default(),
+        )
+    }
+
+    /// Create new INT8 NPU whose burst phases use the threads configured in `backend_config`
+    pub fn new_int8_with_backend_config(
+        runtime: R,
+        backend: B,
+        neuron_capacity: usize,
+        synapse_capacity: usize,
+        fire_ledger_window: usize,
+        backend_config: &crate::backend::BackendConfig,
+    ) -> Result<Self> {
+        let npu = RustNPU::new_with_backend_config(
             runtime,
             backend,
             neuron_capacity,
             synapse_capacity,
             fire_ledger_window,
+            backend_config,
         )?;
         Ok(DynamicNPUGeneric::INT8(npu))
     }
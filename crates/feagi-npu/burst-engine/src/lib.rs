@@ -87,9 +87,13 @@ pub mod backend;
 #[cfg(feature = "std")]
 pub mod burst_loop_runner;
 pub use burst_loop_runner::SensoryIntake;
+#[cfg(feature = "std")]
+pub mod distributed; // Cortical areas partitioned across burst-engine workers
 pub mod fire_ledger;
 pub mod fire_structures;
 pub mod fq_sampler;
+#[cfg(feature = "std")]
+pub mod lockstep; // Tick-driven (simulated-time) burst pacing
 pub mod motor_shm_writer;
 pub mod neural_dynamics;
 #[cfg(feature = "std")]
@@ -97,6 +101,7 @@ pub mod tracing_mutex;
 // Neuron models moved to feagi-neural::models (Phase 2b)
 pub mod dynamic_npu;
 pub mod npu;
+pub mod parallelism; // Thread pool and partitioning for the burst phases
 pub mod parameter_update_queue;
 pub mod sensory; // Rust sensory injection system
                  // Disabled - uses DynamicNPU
@@ -109,6 +114,8 @@ pub use backend::*;
 pub use burst_loop_runner::*;
 #[cfg(feature = "std")]
 pub use dynamic_npu::DynamicNPU;
+#[cfg(feature = "std")]
+pub use lockstep::{BurstPacing, TickOutput, TickRequest};
 /// Conditional NPU mutex: TracingMutex if feature enabled, else wrapper around std::sync::Mutex
 /// This allows zero-overhead when lock tracing is disabled
 #[cfg(feature = "std")]
@@ -121,6 +128,7 @@ pub use fq_sampler::*;
 pub use neural_dynamics::*;
 // Neuron models now in feagi-neural::models
 pub use npu::*;
+pub use parallelism::BurstParallelism;
 pub use parameter_update_queue::{ParameterUpdate, ParameterUpdateQueue};
 pub use sensory::*;
 // pub use sleep::*;
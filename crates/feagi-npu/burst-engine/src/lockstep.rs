@@ -0,0 +1,283 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lockstep (simulated-time) pacing for the burst loop.
+//!
+//! In [`BurstPacing::RealTime`] the burst loop is paced by the wall clock
+//! (`set_frequency`). In [`BurstPacing::Lockstep`] it runs exactly one burst per
+//! [`BurstLoopRunner::tick`](crate::BurstLoopRunner::tick): the caller supplies
+//! the sensory data for that step, the burst runs as soon as the tick arrives,
+//! and the motor output of that burst is handed back to the caller. Bursts are
+//! therefore reproducible and run as fast as the caller ticks, which may be
+//! faster or slower than real time.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use ahash::AHashSet;
+use feagi_structures::genomic::cortical_area::CorticalID;
+
+use crate::burst_loop_runner::{RawFireQueueSnapshot, SensoryXyzpDecoded};
+
+/// How the burst loop decides when to run the next burst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BurstPacing {
+    /// Wall-clock pacing at the configured burst frequency
+    #[default]
+    RealTime,
+    /// One burst per explicit tick
+    Lockstep,
+}
+
+/// Input for one lockstep burst
+#[derive(Debug, Clone, Default)]
+pub struct TickRequest {
+    /// Serialized `FeagiByteContainer` with sensory XYZP data for this burst
+    pub sensory_bytes: Option<Vec<u8>>,
+    /// Already-decoded sensory XYZP data for this burst, per cortical area
+    pub sensory_xyzp: SensoryXyzpDecoded,
+    /// Motor cortical IDs (base64) to return; `None` returns every brain output area
+    pub motor_cortical_ids: Option<AHashSet<String>>,
+}
+
+impl TickRequest {
+    /// Apply a serialized `FeagiByteContainer` to this burst
+    pub fn with_sensory_bytes(mut self, bytes: Vec<u8>) -> Self {
+        self.sensory_bytes = Some(bytes);
+        self
+    }
+
+    /// Add decoded XYZP data for one sensory cortical area
+    pub fn with_sensory_xyzp(
+        mut self,
+        cortical_id: CorticalID,
+        xyzp: Vec<(u32, u32, u32, f32)>,
+    ) -> Self {
+        self.sensory_xyzp.push((cortical_id, xyzp));
+        self
+    }
+
+    /// Only return motor output for these cortical IDs (base64)
+    pub fn with_motor_cortical_ids(mut self, cortical_ids: AHashSet<String>) -> Self {
+        self.motor_cortical_ids = Some(cortical_ids);
+        self
+    }
+
+    /// Whether the request carries sensory data.
+    ///
+    /// Ticks without sensory data fall back to the runner's sensory intake.
+    pub fn has_sensory(&self) -> bool {
+        self.sensory_bytes.is_some() || !self.sensory_xyzp.is_empty()
+    }
+}
+
+/// Result of one lockstep burst
+#[derive(Debug, Clone, Default)]
+pub struct TickOutput {
+    /// Burst number of the burst this tick ran
+    pub burst: u64,
+    /// Number of neurons that fired in the burst
+    pub fired_neuron_count: usize,
+    /// Fired neurons of the requested motor areas, keyed by cortical_idx
+    pub motor: RawFireQueueSnapshot,
+}
+
+impl TickOutput {
+    /// Motor output serialized as XYZP bytes, the format motor publishers send to agents
+    pub fn motor_xyzp_bytes(&self) -> Result<Vec<u8>, String> {
+        crate::burst_loop_runner::encode_fire_data_to_xyzp(self.motor.clone(), None)
+    }
+}
+
+/// Cloneable handle for ticking a runner without holding on to it.
+///
+/// A tick blocks until its burst finishes; callers that keep the runner behind
+/// a lock take a handle first so start/stop are not held up meanwhile.
+#[derive(Clone)]
+pub struct TickHandle {
+    gate: Arc<TickGate>,
+    running: Arc<AtomicBool>,
+}
+
+impl TickHandle {
+    pub(crate) fn new(gate: Arc<TickGate>, running: Arc<AtomicBool>) -> Self {
+        Self { gate, running }
+    }
+
+    /// Current burst pacing
+    pub fn pacing(&self) -> BurstPacing {
+        self.gate.pacing()
+    }
+
+    /// Run one lockstep burst and wait up to `timeout` for its output
+    pub fn tick(&self, request: TickRequest, timeout: Duration) -> Result<TickOutput, String> {
+        if !self.running.load(Ordering::Acquire) {
+            return Err("Burst loop is not running".to_string());
+        }
+        if self.gate.pacing() != BurstPacing::Lockstep {
+            return Err("Burst loop is not in lockstep pacing".to_string());
+        }
+        self.gate.tick(&self.running, request, timeout)
+    }
+}
+
+/// Hand-off between [`BurstLoopRunner::tick`](crate::BurstLoopRunner::tick)
+/// callers and the burst thread
+#[derive(Default)]
+pub(crate) struct TickGate {
+    lockstep: AtomicBool,
+    state: Mutex<TickState>,
+    changed: Condvar,
+    /// Serializes callers so each one gets the output of its own burst
+    in_flight: Mutex<()>,
+}
+
+#[derive(Default)]
+struct TickState {
+    pending: Option<(u64, TickRequest)>,
+    requested: u64,
+    completed: u64,
+    output: Option<Result<TickOutput, String>>,
+}
+
+/// Longest the burst thread blocks before re-checking the running flag
+const WAIT_SLICE: Duration = Duration::from_millis(50);
+
+impl TickGate {
+    pub(crate) fn pacing(&self) -> BurstPacing {
+        if self.lockstep.load(Ordering::Acquire) {
+            BurstPacing::Lockstep
+        } else {
+            BurstPacing::RealTime
+        }
+    }
+
+    pub(crate) fn set_pacing(&self, pacing: BurstPacing) {
+        self.lockstep
+            .store(pacing == BurstPacing::Lockstep, Ordering::Release);
+        // Wake a burst thread waiting for a tick so it switches back to the clock
+        self.changed.notify_all();
+    }
+
+    /// Queue a tick and wait for the burst thread to finish it
+    pub(crate) fn tick(
+        &self,
+        running: &AtomicBool,
+        request: TickRequest,
+        timeout: Duration,
+    ) -> Result<TickOutput, String> {
+        let _in_flight = self.in_flight.lock().unwrap();
+        let deadline = Instant::now() + timeout;
+
+        let mut state = self.state.lock().unwrap();
+        state.requested += 1;
+        let seq = state.requested;
+        state.pending = Some((seq, request));
+        state.output = None;
+        self.changed.notify_all();
+
+        while state.completed < seq {
+            let now = Instant::now();
+            if !running.load(Ordering::Acquire) || now >= deadline {
+                let picked_up = state.pending.take().is_none();
+                return Err(if !running.load(Ordering::Acquire) {
+                    "Burst loop stopped before the tick completed".to_string()
+                } else if picked_up {
+                    format!("Tick {} did not complete within {:?}", seq, timeout)
+                } else {
+                    format!("Tick {} was not picked up within {:?}", seq, timeout)
+                });
+            }
+            let wait = (deadline - now).min(WAIT_SLICE);
+            state = self.changed.wait_timeout(state, wait).unwrap().0;
+        }
+        state
+            .output
+            .take()
+            .unwrap_or_else(|| Err("Tick output missing".to_string()))
+    }
+
+    /// Block the burst thread until a tick arrives.
+    ///
+    /// Returns `None` when the loop stops or pacing switches back to real time.
+    pub(crate) fn next_tick(&self, running: &AtomicBool) -> Option<(u64, TickRequest)> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if !running.load(Ordering::Acquire) || self.pacing() != BurstPacing::Lockstep {
+                return None;
+            }
+            if let Some(tick) = state.pending.take() {
+                return Some(tick);
+            }
+            state = self.changed.wait_timeout(state, WAIT_SLICE).unwrap().0;
+        }
+    }
+
+    /// Publish the result of tick `seq` to its caller
+    pub(crate) fn complete(&self, seq: u64, output: Result<TickOutput, String>) {
+        let mut state = self.state.lock().unwrap();
+        state.completed = seq;
+        state.output = Some(output);
+        self.changed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+    #[test]
+    fn test_tick_round_trip_and_timeouts() {
+        let gate = Arc::new(TickGate::default());
+        let running = Arc::new(AtomicBool::new(true));
+
+        // Real-time pacing never hands out ticks
+        assert_eq!(gate.pacing(), BurstPacing::RealTime);
+        assert!(gate.next_tick(&running).is_none());
+
+        // Nobody picks the tick up: the caller times out and the tick is withdrawn
+        gate.set_pacing(BurstPacing::Lockstep);
+        let err = gate
+            .tick(&running, TickRequest::default(), Duration::from_millis(20))
+            .unwrap_err();
+        assert!(err.contains("not picked up"), "{}", err);
+
+        let worker = {
+            let (gate, running) = (gate.clone(), running.clone());
+            std::thread::spawn(move || {
+                let mut served = 0;
+                while let Some((seq, request)) = gate.next_tick(&running) {
+                    served += 1;
+                    gate.complete(
+                        seq,
+                        Ok(TickOutput {
+                            burst: seq,
+                            fired_neuron_count: request.sensory_xyzp.len(),
+                            ..Default::default()
+                        }),
+                    );
+                }
+                served
+            })
+        };
+
+        for expected in 2..5 {
+            let request = TickRequest::default().with_sensory_xyzp(
+                CoreCorticalType::Power.to_cortical_id(),
+                vec![(0, 0, 0, 1.0)],
+            );
+            assert!(request.has_sensory());
+            let output = gate
+                .tick(&running, request, Duration::from_secs(5))
+                .unwrap();
+            assert_eq!(output.burst, expected);
+            assert_eq!(output.fired_neuron_count, 1);
+        }
+
+        // Switching back to real time releases the waiting burst thread
+        gate.set_pacing(BurstPacing::RealTime);
+        assert_eq!(worker.join().unwrap(), 3);
+    }
+}
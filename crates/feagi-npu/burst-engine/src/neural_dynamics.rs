@@ -21,6 +21,7 @@
 //! 3. **Cache-friendly**: Sequential access patterns, no pointer chasing
 
 use crate::fire_structures::{FireQueue, FiringNeuron};
+use crate::parallelism::{neuron_partition, BurstParallelism};
 use feagi_npu_neural::types::*;
 use feagi_npu_runtime::NeuronStorage;
 use std::sync::OnceLock;
@@ -77,6 +78,8 @@ pub struct DynamicsResult {
     pub neurons_processed: usize,
     pub neurons_fired: usize,
     pub neurons_in_refractory: usize,
+    /// Threads that evaluated candidates (1 unless the burst was partitioned)
+    pub worker_threads: usize,
 }
 
 /// Process neural dynamics for all candidate neurons
@@ -94,6 +97,29 @@ pub fn process_neural_dynamics<T: NeuralValue>(
     memory_candidate_cortical_idx: Option<&ahash::AHashMap<u32, u32>>,
     neuron_array: &mut impl NeuronStorage<Value = T>,
     burst_count: u64,
+) -> Result<DynamicsResult> {
+    process_neural_dynamics_partitioned(
+        fcl,
+        memory_candidate_cortical_idx,
+        neuron_array,
+        burst_count,
+        None,
+    )
+}
+
+/// Process neural dynamics, partitioning large candidate lists across threads
+///
+/// When `parallelism` allows it (see [`BurstParallelism::should_partition`]),
+/// candidates are split by neuron ID into one partition per pool thread. Each
+/// thread evaluates its partition against the shared neuron storage, then the
+/// updates are written back and the fire queue is built in candidate order,
+/// so the result matches the serial path exactly.
+pub fn process_neural_dynamics_partitioned<T: NeuralValue>(
+    fcl: &FireCandidateList,
+    memory_candidate_cortical_idx: Option<&ahash::AHashMap<u32, u32>>,
+    neuron_array: &mut impl NeuronStorage<Value = T>,
+    burst_count: u64,
+    parallelism: Option<&BurstParallelism>,
 ) -> Result<DynamicsResult> {
     let profile_enabled = tracing::enabled!(tracing::Level::DEBUG);
     let dynamics_start = profile_enabled.then(std::time::Instant::now);
@@ -107,6 +133,7 @@ pub fn process_neural_dynamics<T: NeuralValue>(
             neurons_processed: 0,
             neurons_fired: 0,
             neurons_in_refractory: 0,
+            worker_threads: 1,
         });
     }
 
@@ -117,7 +144,22 @@ pub fn process_neural_dynamics<T: NeuralValue>(
     // NOTE: Keep in sync with `feagi-npu/plasticity/src/neuron_id_manager.rs`.
     const MEMORY_NEURON_ID_START: u32 = 50_000_000;
 
+    let mut worker_threads = 1;
     let (fired_neurons, refractory_count): (Vec<_>, usize) = fcl.with_cached(|candidates| {
+            if let Some(parallelism) =
+                parallelism.filter(|parallelism| parallelism.should_partition(candidates.len()))
+            {
+                let (fired, refractory, threads) = process_candidates_partitioned(
+                    candidates,
+                    memory_candidate_cortical_idx,
+                    neuron_array,
+                    burst_count,
+                    parallelism,
+                );
+                worker_threads = threads;
+                return (fired, refractory);
+            }
+
             // For large candidate counts, use batch processing for better cache locality
             // Threshold: 10k candidates (lowered from 50k based on profiling data)
             // Profiling showed sequential path taking 2.67μs per candidate, making SIMD batching
@@ -247,9 +289,108 @@ pub fn process_neural_dynamics<T: NeuralValue>(
         neurons_processed: candidate_count,
         neurons_fired: fired_neurons.len(),
         neurons_in_refractory: refractory_count,
+        worker_threads,
     })
 }
 
+/// Evaluate candidates one partition per pool thread, then apply the updates
+///
+/// Returns (fired neurons in candidate order, refractory count, distinct worker threads).
+fn process_candidates_partitioned<T: NeuralValue>(
+    candidates: &[(NeuronId, f32)],
+    memory_candidate_cortical_idx: Option<&ahash::AHashMap<u32, u32>>,
+    neuron_array: &mut impl NeuronStorage<Value = T>,
+    burst_count: u64,
+    parallelism: &BurstParallelism,
+) -> (Vec<FiringNeuron>, usize, usize) {
+    const MEMORY_NEURON_ID_START: u32 = 50_000_000;
+
+    let partitions = parallelism.threads();
+    let storage = &*neuron_array;
+    let evaluated = parallelism.broadcast(|partition| {
+        let mut updates = Vec::new();
+        let mut refractory = 0;
+        for (position, &(neuron_id, candidate_potential)) in candidates.iter().enumerate() {
+            if neuron_id.0 >= MEMORY_NEURON_ID_START
+                || neuron_partition(neuron_id.0, partitions) != partition
+            {
+                continue;
+            }
+            let update = evaluate_neuron(
+                neuron_id,
+                T::from_f32(candidate_potential),
+                storage,
+                burst_count,
+            );
+            let idx = neuron_id.0 as usize;
+            let countdown = match &update {
+                Some(update) => update.refractory_countdown,
+                None if idx < storage.count() => storage.refractory_countdowns()[idx],
+                None => 0,
+            };
+            if countdown > 0 {
+                refractory += 1;
+            }
+            if let Some(update) = update {
+                updates.push((position, update));
+            }
+        }
+        (updates, refractory, std::thread::current().id())
+    });
+
+    // Memory neurons are force-fired and do not use the regular neuron storage array.
+    let mut fired: Vec<(usize, FiringNeuron)> = Vec::new();
+    let mut memory_missing_meta = 0usize;
+    for (position, &(neuron_id, candidate_potential)) in candidates.iter().enumerate() {
+        if neuron_id.0 < MEMORY_NEURON_ID_START {
+            continue;
+        }
+        match memory_candidate_cortical_idx.and_then(|m| m.get(&neuron_id.0).copied()) {
+            Some(cortical_idx) => fired.push((
+                position,
+                FiringNeuron {
+                    neuron_id,
+                    membrane_potential: candidate_potential,
+                    cortical_idx,
+                    x: 0,
+                    y: 0,
+                    z: 0,
+                },
+            )),
+            None => memory_missing_meta += 1,
+        }
+    }
+    if !fired.is_empty() || memory_missing_meta > 0 {
+        tracing::debug!(
+            "[PHASE2] Memory candidates: fired={} missing_meta={}",
+            fired.len(),
+            memory_missing_meta
+        );
+    }
+
+    let mut refractory = 0;
+    let mut threads = Vec::with_capacity(evaluated.len());
+    for (updates, partition_refractory, thread) in evaluated {
+        for (position, update) in updates {
+            apply_neuron_update(neuron_array, &update);
+            if let Some(neuron) = update.fired {
+                fired.push((position, neuron));
+            }
+        }
+        refractory += partition_refractory;
+        if !threads.contains(&thread) {
+            threads.push(thread);
+        }
+    }
+
+    fired.sort_unstable_by_key(|&(position, _)| position);
+    (
+        fired.into_iter().map(|(_, neuron)| neuron).collect(),
+        refractory,
+        threads.len(),
+    )
+}
+
 /// Process candidates with SIMD batch processing (gather/scatter pattern)
 ///
 /// For large candidate counts, this function:
@@ -559,6 +700,44 @@ fn process_single_neuron<T: NeuralValue>(
     neuron_array: &mut impl NeuronStorage<Value = T>,
     burst_count: u64,
 ) -> Option<FiringNeuron> {
+    let update = evaluate_neuron(neuron_id, candidate_potential, neuron_array, burst_count)?;
+    apply_neuron_update(neuron_array, &update);
+    update.fired
+}
+
+/// New state of one neuron after a burst, computed without mutating storage
+///
+/// Splitting evaluation from the write-back lets the partitioned path evaluate
+/// candidates on several threads against a shared `&NeuronStorage` and apply
+/// the updates afterwards.
+struct NeuronUpdate<T: NeuralValue> {
+    idx: usize,
+    membrane_potential: T,
+    refractory_countdown: u16,
+    consecutive_fire_count: u16,
+    fired: Option<FiringNeuron>,
+}
+
+#[inline(always)]
+fn apply_neuron_update<T: NeuralValue>(
+    neuron_array: &mut impl NeuronStorage<Value = T>,
+    update: &NeuronUpdate<T>,
+) {
+    neuron_array.membrane_potentials_mut()[update.idx] = update.membrane_potential;
+    neuron_array.refractory_countdowns_mut()[update.idx] = update.refractory_countdown;
+    neuron_array.consecutive_fire_counts_mut()[update.idx] = update.consecutive_fire_count;
+}
+
+/// Evaluate a single neuron's dynamics
+///
+/// Returns None if the neuron doesn't exist, otherwise its new state
+#[inline(always)]
+fn evaluate_neuron<T: NeuralValue>(
+    neuron_id: NeuronId,
+    candidate_potential: T,
+    neuron_array: &impl NeuronStorage<Value = T>,
+    burst_count: u64,
+) -> Option<NeuronUpdate<T>> {
     // neuron_id == array index (direct access, no HashMap needed!)
     let idx = neuron_id.0 as usize;
 
@@ -580,29 +759,29 @@ fn process_single_neuron<T: NeuralValue>(
     let cortical_idx = neuron_array.cortical_areas()[idx];
     let mp_acc = neuron_array.mp_charge_accumulation()[idx];
 
-    // CRITICAL DEBUG: Log entry for neuron 16438 (disabled to reduce spam)
-    // if neuron_id.0 == 16438 {
-    //     println!("[RUST-16438] Burst {}: START - countdown={}, count={}/{}, potential={:.6}, threshold={:.6}, candidate={:.6}",
-    //              burst_count,
-    //              neuron_array.refractory_countdowns_mut()[idx],
-    //              neuron_array.consecutive_fire_counts()[idx],
-    //              neuron_array.consecutive_fire_limits()[idx],
-    //              neuron_array.membrane_potentials()[idx],
-    //              neuron_array.thresholds()[idx],
-    //              candidate_potential);
-    // }
+    let mut update = NeuronUpdate {
+        idx,
+        membrane_potential: neuron_array.membrane_potentials()[idx],
+        refractory_countdown: neuron_array.refractory_countdowns()[idx],
+        consecutive_fire_count: neuron_array.consecutive_fire_counts()[idx],
+        fired: None,
+    };
+
+    // Semantics: 0 disables the limiter (unlimited).
+    // Internally, most paths use u16::MAX as the unlimited encoding; support both.
+    let consecutive_fire_limit_raw = neuron_array.consecutive_fire_limits()[idx];
+    let consecutive_fire_limit = if consecutive_fire_limit_raw == 0 {
+        u16::MAX
+    } else {
+        consecutive_fire_limit_raw
+    };
 
     // 1. Handle unified refractory period (normal + extended)
     // CRITICAL: Decrement countdown, but BLOCK THIS ENTIRE BURST
     // Semantics: refractory_period=1 → fire, block 1 burst, fire
     // When countdown=1, this burst is blocked, then countdown becomes 0 for next burst
-    // IMPORTANT: avoid mixing *_mut() borrows with immutable borrows in logging.
-    let refractory_countdown = neuron_array.refractory_countdowns()[idx];
-    if refractory_countdown > 0 {
+    if update.refractory_countdown > 0 {
         if allow_trace {
-            let mp = neuron_array.membrane_potentials()[idx].to_f32();
-            let thr = neuron_array.thresholds()[idx].to_f32();
-            let leak = neuron_array.leak_coefficients()[idx];
             trace!(
                 target: "feagi-npu-trace",
                 "[DYN] burst={} neuron={} area={} mp_acc={} REFRACTORY countdown={} candidate={:.6} mp={:.6} thr={:.6} leak={:.6}",
@@ -610,51 +789,35 @@ fn process_single_neuron<T: NeuralValue>(
                 neuron_id.0,
                 cortical_idx,
                 mp_acc,
-                refractory_countdown,
+                update.refractory_countdown,
                 candidate_potential.to_f32(),
-                mp,
-                thr,
-                leak
+                update.membrane_potential.to_f32(),
+                neuron_array.thresholds()[idx].to_f32(),
+                neuron_array.leak_coefficients()[idx]
             );
         }
-        let _old_countdown = neuron_array.refractory_countdowns_mut()[idx];
 
         // Decrement countdown for next burst
-        neuron_array.refractory_countdowns_mut()[idx] -= 1;
-        let new_countdown = neuron_array.refractory_countdowns_mut()[idx];
+        update.refractory_countdown -= 1;
 
         // Check if extended refractory just expired → reset consecutive fire count
         // This happens AFTER this burst is blocked, ready for next burst
-        let consecutive_fire_limit_raw = neuron_array.consecutive_fire_limits()[idx];
-        let consecutive_fire_limit = if consecutive_fire_limit_raw == 0 {
-            u16::MAX
-        } else {
-            consecutive_fire_limit_raw
-        };
-        if new_countdown == 0
-            && consecutive_fire_limit_raw != 0
+        if update.refractory_countdown == 0
             && consecutive_fire_limit != u16::MAX
-            && neuron_array.consecutive_fire_counts()[idx] >= consecutive_fire_limit
+            && update.consecutive_fire_count >= consecutive_fire_limit
         {
             // Reset happens when countdown expires (Option A logic)
-            let _old_count = neuron_array.consecutive_fire_counts()[idx];
-            neuron_array.consecutive_fire_counts_mut()[idx] = 0;
-            // if neuron_id.0 == 16438 {
-            //     println!("[RUST-16438] → BLOCKED by refrac (countdown {} → {}), count reset {} → 0",
-            //              old_countdown, new_countdown, old_count);
-            // }
-        } // else if neuron_id.0 == 16438 {
-          // println!("[RUST-16438] → BLOCKED by refrac (countdown {} → {})", old_countdown, new_countdown);
-          // }
+            update.consecutive_fire_count = 0;
+        }
 
         // BLOCK THIS BURST - neuron cannot fire
-        return None;
+        return Some(update);
     }
 
     // 2. Add candidate potential (matches Python: add BEFORE checking threshold)
-    let old_potential = neuron_array.membrane_potentials()[idx];
+    let old_potential = update.membrane_potential;
     let current_potential = old_potential.saturating_add(candidate_potential);
-    neuron_array.membrane_potentials_mut()[idx] = current_potential;
+    update.membrane_potential = current_potential;
 
     // 3. Check threshold (matches Python: "Check firing conditions BEFORE decay")
     let threshold = neuron_array.thresholds()[idx];
@@ -688,32 +851,20 @@ fn process_single_neuron<T: NeuralValue>(
             );
         }
         // 5. Check consecutive fire limit (matches Python SIMD implementation)
-        // SIMD-friendly encoding: consecutive_fire_limit == u16::MAX means unlimited
-        // Semantics: 0 disables the limiter (unlimited). Support both 0 and u16::MAX as unlimited.
-        let consecutive_fire_limit_raw = neuron_array.consecutive_fire_limits()[idx];
-        let consecutive_fire_limit = if consecutive_fire_limit_raw == 0 {
-            u16::MAX
-        } else {
-            consecutive_fire_limit_raw
-        };
-        let consecutive_fire_count = neuron_array.consecutive_fire_counts()[idx];
-
         // SIMD-friendly: uniform comparison (no branching for "no limit" case)
         // If consecutive_fire_limit == u16::MAX, count will always be < MAX
-        let consecutive_fire_constraint = consecutive_fire_count < consecutive_fire_limit;
-
-        if !consecutive_fire_constraint {
+        if update.consecutive_fire_count >= consecutive_fire_limit {
             // Neuron exceeded consecutive fire limit - prevent firing
             // CRITICAL: Reset count to 0 (matches Python SIMD: all non-firing neurons get count reset)
             // This allows the neuron to fire again after being blocked for one burst
-            neuron_array.consecutive_fire_counts_mut()[idx] = 0;
+            update.consecutive_fire_count = 0;
 
             // CRITICAL FIX: Don't apply leak when blocked by consecutive fire limit
             // The neuron will enter refractory period, and leak will be applied during refractory
             // Applying leak here causes the neuron to lose potential and need extra time to re-fire
             // This was causing the "gap of 3 instead of 2" bug
 
-            return None;
+            return Some(update);
         }
 
         // 6. Apply probabilistic excitability
@@ -744,55 +895,36 @@ fn process_single_neuron<T: NeuralValue>(
                     current_potential.to_f32(),
                     threshold.to_f32(),
                     neuron_array.refractory_periods()[idx],
-                    neuron_array.consecutive_fire_counts()[idx],
+                    update.consecutive_fire_count,
                     neuron_array.consecutive_fire_limits()[idx],
                     neuron_array.snooze_periods()[idx]
                 );
             }
             // Reset membrane potential
-            neuron_array.membrane_potentials_mut()[idx] = T::zero();
+            update.membrane_potential = T::zero();
 
             // Increment consecutive fire count (saturating to prevent overflow)
-            let old_count = neuron_array.consecutive_fire_counts()[idx];
-            neuron_array.consecutive_fire_counts_mut()[idx] = old_count.saturating_add(1);
-            let new_count = neuron_array.consecutive_fire_counts()[idx];
+            update.consecutive_fire_count = update.consecutive_fire_count.saturating_add(1);
 
             // Apply refractory period (additive if hit consecutive fire limit)
             // SEMANTICS: refractory_period=N means "skip N bursts between fires"
             // e.g., refrac=1 → fire, skip 1, fire → pattern: 1_1_1_
             //       refrac=2 → fire, skip 2, fire → pattern: 1__1__
             let refractory_period = neuron_array.refractory_periods()[idx];
-            let consecutive_fire_limit_raw = neuron_array.consecutive_fire_limits()[idx];
-            let consecutive_fire_limit = if consecutive_fire_limit_raw == 0 {
-                u16::MAX
-            } else {
-                consecutive_fire_limit_raw
-            };
-
-            if consecutive_fire_limit_raw != 0
-                && consecutive_fire_limit != u16::MAX
-                && new_count >= consecutive_fire_limit
+            if consecutive_fire_limit != u16::MAX
+                && update.consecutive_fire_count >= consecutive_fire_limit
             {
                 // Hit burst limit → ADDITIVE extended refractory
                 // countdown = refrac + snooze (total bursts to skip)
                 // e.g., refrac=1, snooze=2, cfc_limit=3 → 1_1_1___1_1_1___
-                let snooze_period = neuron_array.snooze_periods()[idx];
-                let countdown = refractory_period.saturating_add(snooze_period);
-                neuron_array.refractory_countdowns_mut()[idx] = countdown;
-                // if neuron_id.0 == 16438 {
-                //     println!("[RUST-16438] → FIRED! count {} → {} (HIT LIMIT), extended refrac={}+{}={}",
-                //              new_count-1, new_count, refractory_period, snooze_period, countdown);
-                // }
                 // Note: consecutive_fire_count will be reset when countdown expires
+                let snooze_period = neuron_array.snooze_periods()[idx];
+                update.refractory_countdown = refractory_period.saturating_add(snooze_period);
             } else {
                 // Normal fire → normal refractory only
                 // countdown = refrac (bursts to skip)
                 // e.g., refrac=1 → countdown=1 → fire, 1 blocked, fire
-                neuron_array.refractory_countdowns_mut()[idx] = refractory_period;
-                // if neuron_id.0 == 16438 {
-                //     println!("[RUST-16438] → FIRED! count {} → {}, refrac={}",
-                //              new_count-1, new_count, refractory_period);
-                // }
+                update.refractory_countdown = refractory_period;
             }
 
             // Get neuron coordinates
@@ -803,14 +935,15 @@ fn process_single_neuron<T: NeuralValue>(
                 neuron_array.coordinates()[coord_idx + 2],
             );
 
-            return Some(FiringNeuron {
+            update.fired = Some(FiringNeuron {
                 neuron_id,
                 membrane_potential: current_potential.to_f32(),
-                cortical_idx: neuron_array.cortical_areas()[idx], // Use cortical_idx directly - no conversion needed
+                cortical_idx, // Use cortical_idx directly - no conversion needed
                 x,
                 y,
                 z,
             });
+            return Some(update);
         }
     }
 
@@ -818,17 +951,13 @@ fn process_single_neuron<T: NeuralValue>(
     // (matches Python: "Apply membrane decay to remaining neurons (leak behavior)")
 
     // Reset consecutive fire count (matches Python SIMD implementation)
-    let consecutive_fire_limit = neuron_array.consecutive_fire_limits()[idx];
-    if consecutive_fire_limit != 0 && consecutive_fire_limit != u16::MAX {
-        neuron_array.consecutive_fire_counts_mut()[idx] = 0;
+    if consecutive_fire_limit != u16::MAX {
+        update.consecutive_fire_count = 0;
     }
 
     // Apply LIF leak (using platform-agnostic function from feagi-neural)
     let leak_coefficient = neuron_array.leak_coefficients()[idx];
-    apply_leak(
-        &mut neuron_array.membrane_potentials_mut()[idx],
-        leak_coefficient,
-    );
+    apply_leak(&mut update.membrane_potential, leak_coefficient);
 
     if allow_trace {
         trace!(
@@ -841,13 +970,13 @@ fn process_single_neuron<T: NeuralValue>(
             old_potential.to_f32(),
             candidate_potential.to_f32(),
             current_potential.to_f32(),
-            neuron_array.membrane_potentials()[idx].to_f32(),
+            update.membrane_potential.to_f32(),
             threshold.to_f32(),
             leak_coefficient
         );
     }
 
-    None
+    Some(update)
 }
 
 // REMOVED: process_neural_dynamics_simd - dead code with fallback
@@ -102,6 +102,8 @@ pub struct BurstResult {
     pub synaptic_injections: usize,
     pub neurons_processed: usize,
     pub neurons_in_refractory: usize,
+    /// Threads that evaluated neural dynamics (1 unless the burst was partitioned)
+    pub worker_threads: usize,
 }
 
 /// Complete Rust Neural Processing Unit with Fine-Grained Locking
@@ -260,6 +262,34 @@ impl<
         synapse_capacity: usize,
         fire_ledger_window: usize,
     ) -> Result<Self> {
+        Self::new_with_backend_config(
+            runtime,
+            backend,
+            neuron_capacity,
+            synapse_capacity,
+            fire_ledger_window,
+            &crate::backend::BackendConfig::default(),
+        )
+    }
+
+    /// Create a new Rust NPU whose burst phases use the threads configured in `backend_config`
+    ///
+    /// `BackendConfig::cpu_threads` and `BackendConfig::cpu_parallel_synapse_threshold`
+    /// select the thread pool used for synaptic propagation and the partitioning
+    /// of neural dynamics (see [`crate::parallelism`]).
+    pub fn new_with_backend_config(
+        runtime: R,
+        backend: B,
+        neuron_capacity: usize,
+        synapse_capacity: usize,
+        fire_ledger_window: usize,
+        backend_config: &crate::backend::BackendConfig,
+    ) -> Result<Self> {
+        let mut propagation_engine = SynapticPropagationEngine::new();
+        propagation_engine.set_parallelism(crate::parallelism::BurstParallelism::from_config(
+            backend_config,
+        )?);
+
         // Create storage using runtime
         let neuron_storage = runtime
             .create_neuron_storage(neuron_capacity)
@@ -289,7 +319,7 @@ impl<
                 pending_replay_injections: Vec::new(),
             }),
             area_id_to_name: std::sync::RwLock::new(AHashMap::new()),
-            propagation_engine: std::sync::RwLock::new(SynapticPropagationEngine::new()),
+            propagation_engine: std::sync::RwLock::new(propagation_engine),
             stdp_mappings: std::sync::RwLock::new(AHashMap::new()),
             stdp_mapping_index: std::sync::RwLock::new(AHashMap::new()),
             backend: std::sync::Mutex::new(backend),
@@ -1124,7 +1154,7 @@ impl<
     /// deterministically without destroying other outgoing synapses from the same source area.
     ///
     /// Implementation detail:
-    /// - Uses the propagation engine's `synapse_index` to iterate only synapses reachable from
+    /// - Uses the propagation engine's synapse lookup to iterate only synapses reachable from
     ///   the given source neurons.
     /// - Marks matching synapses invalid via `valid_mask` (no compaction).
     ///
@@ -1146,10 +1176,8 @@ impl<
 
         let mut removed = 0usize;
         for source in sources {
-            let Some(indices) = prop_engine.synapse_index.get(&source) else {
-                continue;
-            };
-            for &syn_idx in indices {
+            let indices = prop_engine.outgoing_synapses(source, &*synapse_storage);
+            for &syn_idx in indices.iter() {
                 if syn_idx >= synapse_storage.count() {
                     continue;
                 }
@@ -1745,11 +1773,12 @@ impl<
 
         // Phase 2: Neural Dynamics (membrane potential updates, threshold checks, firing)
         let phase2_start = std::time::Instant::now();
-        let dynamics_result = process_neural_dynamics(
+        let dynamics_result = process_neural_dynamics_partitioned(
             &fire_structures.fire_candidate_list,
             Some(&fire_structures.memory_candidate_cortical_idx),
             &mut *neuron_storage,
             burst_count,
+            Some(propagation_engine.parallelism()),
         )?;
         let phase2_duration = phase2_start.elapsed();
 
@@ -1843,6 +1872,11 @@ impl<
 
         // Build result
         let fired_neurons = fire_structures.current_fire_queue.get_all_neuron_ids();
+        let idle = fired_neurons.is_empty() && previous_fq.is_empty();
+        drop(fire_structures);
+
+        // Phase 7: Fold buffered synapse changes into storage (idle bursts, or when the buffer is large)
+        self.compact_synapse_storage(idle);
 
         Ok(BurstResult {
             neuron_count: fired_neurons.len(),
@@ -1853,6 +1887,7 @@ impl<
             synaptic_injections: injection_result.synaptic_injections,
             neurons_processed: dynamics_result.neurons_processed,
             neurons_in_refractory: dynamics_result.neurons_in_refractory,
+            worker_threads: dynamics_result.worker_threads,
         })
     }
 
@@ -2482,6 +2517,14 @@ impl<
             refractory_periods: neuron_storage.refractory_periods().to_vec(),
             refractory_countdowns: neuron_storage.refractory_countdowns().to_vec(),
             excitabilities: neuron_storage.excitabilities().to_vec(),
+            threshold_limits: neuron_storage
+                .threshold_limits()
+                .iter()
+                .map(|&v| v.to_f32())
+                .collect(),
+            consecutive_fire_limits: neuron_storage.consecutive_fire_limits().to_vec(),
+            snooze_periods: neuron_storage.snooze_periods().to_vec(),
+            mp_charge_accumulation: neuron_storage.mp_charge_accumulation().to_vec(),
             cortical_areas: neuron_storage.cortical_areas().to_vec(),
             coordinates: neuron_storage.coordinates().to_vec(),
             valid_mask: neuron_storage.valid_mask().to_vec(),
@@ -2526,6 +2569,96 @@ impl<
         }
     }
 
+    /// Restore the neurons of a connectome snapshot at their original IDs
+    ///
+    /// Intended for an empty NPU whose synapse storage already references the
+    /// snapshot's neuron IDs, such as one running on a memory-mapped synapse
+    /// image. Deleted neurons are recreated as invalid placeholders so later
+    /// IDs do not shift. Cortical areas, membrane potentials, burst count and
+    /// power amount are restored too; the synapse index is rebuilt.
+    #[cfg(feature = "connectome-io")]
+    pub fn restore_neurons(
+        &mut self,
+        snapshot: &feagi_npu_neural::types::connectome::ConnectomeSnapshot,
+    ) -> Result<()> {
+        let neurons = &snapshot.neurons;
+        if neurons.coordinates.len() < neurons.count * 3 {
+            return Err(FeagiError::ComputationError(format!(
+                "Snapshot has {} coordinates for {} neurons",
+                neurons.coordinates.len(),
+                neurons.count
+            )));
+        }
+
+        // Ascending order gives core areas (0, 1, 2) their deterministic neurons
+        let mut area_ids: Vec<u32> = snapshot.cortical_area_names.keys().copied().collect();
+        area_ids.sort_unstable();
+        for &area_id in &area_ids {
+            self.register_cortical_area(area_id, snapshot.cortical_area_names[&area_id].clone());
+        }
+
+        let mut restored_ids = Vec::new();
+        let mut restored_potentials = Vec::new();
+        for global in 0..neurons.count {
+            let id = global as u32;
+            let cortical_idx = neurons.cortical_areas[global];
+            let valid = neurons.valid_mask[global];
+
+            if global < self.neuron_storage.read().unwrap().count() {
+                // Created by register_cortical_area
+                if !valid || self.get_neuron_cortical_area(id) != cortical_idx {
+                    return Err(FeagiError::ComputationError(format!(
+                        "Core neuron {} does not match the snapshot",
+                        id
+                    )));
+                }
+            } else {
+                // Placeholders still need a registered area
+                let area = if valid || snapshot.cortical_area_names.contains_key(&cortical_idx) {
+                    cortical_idx
+                } else {
+                    area_ids.first().copied().unwrap_or(cortical_idx)
+                };
+                let coords = &neurons.coordinates[global * 3..global * 3 + 3];
+                let restored = self.add_neuron(
+                    T::from_f32(neurons.thresholds[global]),
+                    T::from_f32(neurons.threshold_limits[global]),
+                    neurons.leak_coefficients[global],
+                    T::from_f32(neurons.resting_potentials[global]),
+                    neurons.neuron_types[global],
+                    neurons.refractory_periods[global],
+                    neurons.excitabilities[global],
+                    neurons.consecutive_fire_limits[global],
+                    neurons.snooze_periods[global],
+                    neurons.mp_charge_accumulation[global],
+                    area,
+                    coords[0],
+                    coords[1],
+                    coords[2],
+                )?;
+                if restored.0 != id {
+                    return Err(FeagiError::ComputationError(format!(
+                        "Neuron {} was restored as {}; the NPU must start empty",
+                        id, restored.0
+                    )));
+                }
+                if !valid {
+                    self.delete_neuron(id);
+                    continue;
+                }
+            }
+            restored_ids.push(id);
+            restored_potentials.push(neurons.membrane_potentials[global]);
+        }
+        self.batch_update_membrane_potential(&restored_ids, &restored_potentials);
+
+        self.rebuild_synapse_index();
+        self.burst_count
+            .store(snapshot.burst_count, std::sync::atomic::Ordering::Release);
+        self.set_power_amount(snapshot.power_amount);
+        Ok(())
+    }
+
     // TODO: import_connectome needs refactoring for trait-based storage
     // Currently commented out - requires bulk load API in Storage traits
     // See issue: Direct field assignment doesn't work with trait-based storage
@@ -3351,21 +3484,20 @@ impl<
         let mut updated = 0usize;
         for source_id in source_neuron_ids {
             let src = NeuronId(source_id);
-            if let Some(indices) = prop_engine.synapse_index.get(&src) {
-                for &syn_idx in indices {
-                    // IMPORTANT: Avoid simultaneous mutable + immutable borrows from synapse_storage.
-                    // Take a short-lived immutable borrow to check validity, then (if valid) mutate PSP.
-                    let is_valid = {
-                        let valid = synapse_storage.valid_mask();
-                        syn_idx < valid.len() && valid[syn_idx]
-                    };
+            let indices = prop_engine.outgoing_synapses(src, &*synapse_storage);
+            for &syn_idx in indices.iter() {
+                // IMPORTANT: Avoid simultaneous mutable + immutable borrows from synapse_storage.
+                // Take a short-lived immutable borrow to check validity, then (if valid) mutate PSP.
+                let is_valid = {
+                    let valid = synapse_storage.valid_mask();
+                    syn_idx < valid.len() && valid[syn_idx]
+                };
 
-                    if is_valid {
-                        let psps = synapse_storage.postsynaptic_potentials_mut();
-                        // syn_idx bounds already checked against valid.len(); lengths match by contract.
-                        psps[syn_idx] = postsynaptic_potential;
-                        updated += 1;
-                    }
+                if is_valid {
+                    let psps = synapse_storage.postsynaptic_potentials_mut();
+                    // syn_idx bounds already checked against valid.len(); lengths match by contract.
+                    psps[syn_idx] = postsynaptic_potential;
+                    updated += 1;
                 }
             }
         }
@@ -3527,14 +3659,15 @@ impl<
 
         // Look up synapse indices for this source neuron
         let prop_engine = self.propagation_engine.read().unwrap();
-        let synapse_indices = match prop_engine.synapse_index.get(&source) {
-            Some(indices) => indices,
-            None => return Vec::new(), // No synapses from this neuron
-        };
+        let synapse_indices =
+            prop_engine.outgoing_synapses(source, &*self.synapse_storage.read().unwrap());
+        if synapse_indices.is_empty() {
+            return Vec::new(); // No synapses from this neuron
+        }
 
         // Collect all valid synapses with full properties
         let mut outgoing = Vec::new();
-        for &syn_idx in synapse_indices {
+        for &syn_idx in synapse_indices.iter() {
             if syn_idx < self.synapse_storage.read().unwrap().count()
                 && self.synapse_storage.read().unwrap().valid_mask()[syn_idx]
             {
@@ -3596,6 +3729,31 @@ impl<
         self.rebuild_stdp_mapping_index();
     }
 
+    /// Merge buffered synapse inserts/removals if the storage asks for it
+    ///
+    /// Called at the end of every burst with `idle` set when nothing fired in
+    /// this burst or the previous one. Compaction renumbers synapses, so the
+    /// propagation and STDP indices are rebuilt when it changes anything.
+    ///
+    /// Returns true if the storage was compacted.
+    pub fn compact_synapse_storage(&self, idle: bool) -> bool {
+        if !self.synapse_storage.read().unwrap().wants_compaction(idle) {
+            return false;
+        }
+
+        let mut synapse_storage = self.synapse_storage.write().unwrap();
+        if !synapse_storage.compact() {
+            return false;
+        }
+        let mut prop_engine = self.propagation_engine.write().unwrap();
+        prop_engine.build_synapse_index(&*synapse_storage);
+        drop(prop_engine);
+        drop(synapse_storage);
+
+        self.rebuild_stdp_mapping_index();
+        true
+    }
+
     /// Register or update STDP parameters for a plastic cortical mapping A→B.
     pub fn register_stdp_mapping(
         &mut self,
@@ -3902,8 +4060,9 @@ impl<
                 for src_neuron in activity.src_all.iter() {
                     let source = NeuronId(src_neuron);
                     let mut existing_targets = AHashSet::new();
-                    if let Some(indices) = prop_engine.synapse_index.get(&source) {
-                        for &syn_idx in indices {
+                    {
+                        let indices = prop_engine.outgoing_synapses(source, &*synapse_storage);
+                        for &syn_idx in indices.iter() {
                             if syn_idx >= synapse_storage.count()
                                 || !synapse_storage.valid_mask()[syn_idx]
                             {
@@ -0,0 +1,201 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Burst Parallelism
+//!
+//! Thread pool and work split shared by the two CPU-heavy burst phases:
+//!
+//! - **Synaptic propagation** runs its rayon pipeline inside the pool. Unless
+//!   a threshold is configured, rayon splits the work as it always has.
+//! - **Neural dynamics**, once a threshold is configured, partitions the fire
+//!   candidate list by neuron ID (block-cyclic ranges of [`TARGET_BLOCK_SIZE`]
+//!   IDs) and evaluates one partition per pool thread. Each neuron's update
+//!   depends only on its own state, so the result is identical to the serial
+//!   path.
+//!
+//! Built from [`BackendConfig::cpu_threads`] and
+//! [`BackendConfig::cpu_parallel_synapse_threshold`] when the NPU is constructed.
+//!
+//! This lives beside `synaptic_propagation` and `neural_dynamics` rather than in
+//! `backend::cpu` because `RustNPU::process_burst` calls those two modules
+//! directly; `CPUBackend` is only reached through the `ComputeBackend` trait and
+//! is not on the burst path.
+//!
+//! With the threshold unset both phases behave as before partitioning existed.
+//! `benches/burst_parallelism.rs` measures both phases across candidate and
+//! synapse counts; run it on the target hardware before setting a threshold.
+
+use crate::backend::BackendConfig;
+use feagi_npu_neural::types::{FeagiError, Result};
+use std::sync::Arc;
+
+/// Neuron IDs per partition range; ranges are dealt round-robin to partitions
+pub const TARGET_BLOCK_SIZE: u32 = 1024;
+
+/// Thread pool and work split for the burst phases
+#[derive(Clone)]
+pub struct BurstParallelism {
+    /// Dedicated pool (None = rayon's global pool)
+    pool: Option<Arc<rayon::ThreadPool>>,
+    /// Minimum synapses per propagation task (1 = rayon's own splitting)
+    min_task_len: usize,
+    /// Fire candidates before neural dynamics is partitioned (usize::MAX = never)
+    min_partition_candidates: usize,
+}
+
+impl BurstParallelism {
+    /// Build from `BackendConfig::cpu_threads` (0 = global pool, 1 = serial,
+    /// N = dedicated pool of N threads) and `cpu_parallel_synapse_threshold`
+    /// (0 = unset)
+    pub fn from_config(config: &BackendConfig) -> Result<Self> {
+        let pool = match config.cpu_threads {
+            0 => None,
+            threads => Some(Arc::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .thread_name(|i| format!("feagi-burst-{}", i))
+                    .build()
+                    .map_err(|e| {
+                        FeagiError::ComputationError(format!(
+                            "Failed to build burst thread pool: {}",
+                            e
+                        ))
+                    })?,
+            )),
+        };
+
+        let (min_task_len, min_partition_candidates) = match config.cpu_parallel_synapse_threshold {
+            0 => (1, usize::MAX),
+            threshold => (threshold, threshold),
+        };
+
+        Ok(Self {
+            pool,
+            min_task_len,
+            min_partition_candidates,
+        })
+    }
+
+    /// Worker threads available to a burst (1 = serial)
+    pub fn threads(&self) -> usize {
+        self.pool
+            .as_ref()
+            .map_or_else(rayon::current_num_threads, |pool| {
+                pool.current_num_threads()
+            })
+    }
+
+    /// Minimum synapses per propagation task
+    ///
+    /// 1 unless a threshold is configured, which leaves rayon's splitting as is.
+    pub fn min_task_len(&self) -> usize {
+        self.min_task_len
+    }
+
+    /// Whether `candidates` fire candidates are enough to partition neural dynamics
+    pub fn should_partition(&self, candidates: usize) -> bool {
+        self.threads() > 1 && candidates >= self.min_partition_candidates
+    }
+
+    /// Run `op` inside the pool, so its rayon iterators use the pool's threads
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    /// Run `op` once on every pool thread, passing the thread's partition index
+    ///
+    /// Results are returned in partition order.
+    pub fn broadcast<OP, R>(&self, op: OP) -> Vec<R>
+    where
+        OP: Fn(usize) -> R + Sync,
+        R: Send,
+    {
+        match &self.pool {
+            Some(pool) => pool.broadcast(|ctx| op(ctx.index())),
+            None => rayon::broadcast(|ctx| op(ctx.index())),
+        }
+    }
+}
+
+impl Default for BurstParallelism {
+    /// Global pool with rayon's own splitting and serial neural dynamics
+    fn default() -> Self {
+        Self {
+            pool: None,
+            min_task_len: 1,
+            min_partition_candidates: usize::MAX,
+        }
+    }
+}
+
+impl std::fmt::Debug for BurstParallelism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BurstParallelism")
+            .field("threads", &self.threads())
+            .field("dedicated_pool", &self.pool.is_some())
+            .field("min_task_len", &self.min_task_len)
+            .field("min_partition_candidates", &self.min_partition_candidates)
+            .finish()
+    }
+}
+
+/// Partition owning a neuron (block-cyclic over neuron ID ranges)
+#[inline]
+pub fn neuron_partition(neuron_id: u32, partitions: usize) -> usize {
+    (neuron_id / TARGET_BLOCK_SIZE) as usize % partitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config_thread_counts() {
+        let serial = BurstParallelism::from_config(&BackendConfig {
+            cpu_threads: 1,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(serial.threads(), 1);
+        assert!(!serial.should_partition(usize::MAX));
+
+        let parallel = BurstParallelism::from_config(&BackendConfig {
+            cpu_threads: 3,
+            cpu_parallel_synapse_threshold: 10,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(parallel.threads(), 3);
+        assert_eq!(parallel.min_task_len(), 10);
+        assert!(!parallel.should_partition(9));
+        assert!(parallel.should_partition(10));
+        assert_eq!(parallel.broadcast(|partition| partition), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_unset_threshold_keeps_serial_paths() {
+        let parallel = BurstParallelism::from_config(&BackendConfig {
+            cpu_threads: 2,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(parallel.threads(), 2);
+        assert_eq!(parallel.min_task_len(), 1);
+        assert!(!parallel.should_partition(usize::MAX - 1));
+    }
+
+    #[test]
+    fn test_neuron_partition_is_block_cyclic() {
+        assert_eq!(neuron_partition(0, 4), 0);
+        assert_eq!(neuron_partition(TARGET_BLOCK_SIZE - 1, 4), 0);
+        assert_eq!(neuron_partition(TARGET_BLOCK_SIZE, 4), 1);
+        assert_eq!(neuron_partition(TARGET_BLOCK_SIZE * 4, 4), 0);
+    }
+}
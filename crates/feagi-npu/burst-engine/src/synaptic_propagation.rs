@@ -38,11 +38,13 @@
 //! - Python: ~165ms for 12K neurons
 //! - Rust Target: <3ms (50-100x speedup)
 
+use crate::parallelism::BurstParallelism;
 use ahash::AHashMap;
 use feagi_npu_neural::types::*;
 use feagi_npu_runtime::SynapseStorage;
 use feagi_structures::genomic::cortical_area::CorticalID;
 use rayon::prelude::*;
+use std::borrow::Cow;
 use std::sync::OnceLock;
 
 // Use platform-agnostic synaptic algorithms (now in feagi-neural)
@@ -105,6 +107,8 @@ pub type PropagationResult = AHashMap<CorticalID, Vec<(NeuronId, SynapticContrib
 pub struct SynapticPropagationEngine {
     /// Pre-built index: source neuron → synapse indices
     pub synapse_index: SynapseIndex,
+    /// Outgoing synapses are looked up in the synapse storage instead of `synapse_index`
+    storage_indexed: bool,
     /// Neuron → Cortical Area mapping
     pub neuron_to_area: AHashMap<NeuronId, CorticalID>,
     /// Cortical Area → mp_driven_psp flag mapping
@@ -113,6 +117,8 @@ pub struct SynapticPropagationEngine {
     /// When false: PSP is divided among all outgoing synapses
     /// When true: Full PSP value is applied to each synapse
     pub area_psp_uniform_distribution: AHashMap<CorticalID, bool>,
+    /// Thread pool and minimum task size for propagation
+    parallelism: BurstParallelism,
     /// Performance stats
     total_propagations: u64,
     total_synapses_processed: u64,
@@ -142,15 +148,27 @@ impl SynapticPropagationEngine {
     pub fn new() -> Self {
         Self {
             synapse_index: AHashMap::new(),
+            storage_indexed: false,
             neuron_to_area: AHashMap::new(),
             area_mp_driven_psp: AHashMap::new(),
             area_psp_uniform_distribution: AHashMap::new(),
+            parallelism: BurstParallelism::default(),
             total_propagations: 0,
             total_synapses_processed: 0,
             last_profile: None,
         }
     }
 
+    /// Run propagation on `parallelism`'s pool with its minimum task size
+    pub fn set_parallelism(&mut self, parallelism: BurstParallelism) {
+        self.parallelism = parallelism;
+    }
+
+    /// Thread pool and minimum task size used for propagation
+    pub fn parallelism(&self) -> &BurstParallelism {
+        &self.parallelism
+    }
+
     /// Returns the most recent propagation profile, if any.
     ///
     /// This is intended for performance diagnostics and is populated on each `propagate()` call.
@@ -162,8 +180,15 @@ impl SynapticPropagationEngine {
     /// This should be called once during initialization or when connectome changes
     ///
     /// ZERO-COPY: Works directly with StdSynapseArray without allocating intermediate structures
+    ///
+    /// Storages that look up sources on their own (e.g. memory-mapped synapse
+    /// images) are not indexed; their rows are queried during propagation.
     pub fn build_synapse_index<S: SynapseStorage>(&mut self, synapse_storage: &S) {
         self.synapse_index.clear();
+        self.storage_indexed = synapse_storage.indexes_sources();
+        if self.storage_indexed {
+            return;
+        }
 
         for i in 0..synapse_storage.count() {
             if synapse_storage.valid_mask()[i] {
@@ -173,6 +198,24 @@ impl SynapticPropagationEngine {
         }
     }
 
+    /// Indices of the synapses originating at `source`
+    ///
+    /// Served by the synapse storage when it indexes sources itself, otherwise
+    /// by the pre-built index.
+    pub fn outgoing_synapses<'a, S: SynapseStorage>(
+        &'a self,
+        source: NeuronId,
+        synapse_storage: &S,
+    ) -> Cow<'a, [usize]> {
+        if self.storage_indexed {
+            Cow::Owned(synapse_storage.outgoing_synapses(source.0))
+        } else {
+            self.synapse_index
+                .get(&source)
+                .map_or(Cow::Borrowed(&[][..]), |indices| Cow::Borrowed(indices))
+        }
+    }
+
     /// Set the neuron-to-cortical-area mapping
     pub fn set_neuron_mapping(&mut self, mapping: AHashMap<NeuronId, CorticalID>) {
         self.neuron_to_area = mapping;
@@ -226,6 +269,24 @@ impl SynapticPropagationEngine {
         fired_neurons: &[NeuronId],
         synapse_storage: &impl SynapseStorage,
         neuron_membrane_potentials: &AHashMap<NeuronId, u8>,
+    ) -> Result<PropagationResult> {
+        let parallelism = self.parallelism.clone();
+        parallelism.install(|| {
+            self.propagate_in_pool(
+                fired_neurons,
+                synapse_storage,
+                neuron_membrane_potentials,
+                parallelism.min_task_len(),
+            )
+        })
+    }
+
+    fn propagate_in_pool(
+        &mut self,
+        fired_neurons: &[NeuronId],
+        synapse_storage: &impl SynapseStorage,
+        neuron_membrane_potentials: &AHashMap<NeuronId, u8>,
+        min_task_len: usize,
     ) -> Result<PropagationResult> {
         let profile_enabled = tracing::enabled!(tracing::Level::DEBUG);
         let trace_cfg = synapse_trace_cfg();
@@ -250,12 +311,19 @@ impl SynapticPropagationEngine {
 
         // PHASE 1: GATHER - Collect all synapse indices for fired neurons (parallel)
         let gather_start = profile_enabled.then(std::time::Instant::now);
-        let synapse_indices: Vec<usize> = fired_neurons
-            .par_iter()
-            .filter_map(|&neuron_id| self.synapse_index.get(&neuron_id))
-            .flatten()
-            .copied()
-            .collect();
+        let synapse_indices: Vec<usize> = if self.storage_indexed {
+            fired_neurons
+                .par_iter()
+                .flat_map_iter(|&neuron_id| synapse_storage.outgoing_synapses(neuron_id.0))
+                .collect()
+        } else {
+            fired_neurons
+                .par_iter()
+                .filter_map(|&neuron_id| self.synapse_index.get(&neuron_id))
+                .flatten()
+                .copied()
+                .collect()
+        };
         let gather_ms = gather_start
             .map(|start| start.elapsed().as_secs_f64() * 1000.0)
             .unwrap_or(0.0);
@@ -289,6 +357,7 @@ impl SynapticPropagationEngine {
             let compute_start = profile_enabled.then(std::time::Instant::now);
             let contributions: Vec<(NeuronId, CorticalID, SynapticContribution)> = synapse_indices
                 .par_iter()
+                .with_min_len(min_task_len)
                 .filter_map(|&syn_idx| {
                     let target_neuron = NeuronId(synapse_storage.target_neurons()[syn_idx]);
                     let cortical_area = *self.neuron_to_area.get(&target_neuron)?;
@@ -317,6 +386,7 @@ impl SynapticPropagationEngine {
             let group_start = profile_enabled.then(std::time::Instant::now);
             let result: PropagationResult = contributions
                 .into_par_iter()
+                .with_min_len(min_task_len)
                 .fold(
                     AHashMap::<CorticalID, Vec<(NeuronId, SynapticContribution)>>::new,
                     |mut acc, (target_neuron, cortical_area, contribution)| {
@@ -366,6 +436,7 @@ impl SynapticPropagationEngine {
         let metadata_start = profile_enabled.then(std::time::Instant::now);
         let source_metadata: AHashMap<NeuronId, SourceNeuronMetadata> = synapse_indices
             .par_iter()
+            .with_min_len(min_task_len)
             .map(|&syn_idx| NeuronId(synapse_storage.source_neurons()[syn_idx]))
             .fold(
                 AHashMap::<NeuronId, (Option<CorticalID>, usize)>::new,
@@ -418,6 +489,7 @@ impl SynapticPropagationEngine {
         let compute_start = profile_enabled.then(std::time::Instant::now);
         let contributions: Vec<(NeuronId, CorticalID, SynapticContribution)> = synapse_indices
             .par_iter()
+            .with_min_len(min_task_len)
             .filter_map(|&syn_idx| {
                 // Skip invalid synapses (already filtered by build_synapse_index, but double-check)
                 if !synapse_storage.valid_mask()[syn_idx] {
@@ -520,6 +592,7 @@ impl SynapticPropagationEngine {
         let group_start = profile_enabled.then(std::time::Instant::now);
         let result: PropagationResult = contributions
             .into_par_iter()
+            .with_min_len(min_task_len)
             .fold(
                 AHashMap::<CorticalID, Vec<(NeuronId, SynapticContribution)>>::new,
                 |mut acc, (target_neuron, cortical_area, contribution)| {
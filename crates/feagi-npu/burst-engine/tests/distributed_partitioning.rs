@@ -0,0 +1,679 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the distributed (partitioned) NPU
+//!
+//! A reference brain is built in one NPU, exported as a connectome snapshot,
+//! and re-run split over several partitions. Every burst the union of the
+//! partitions' fired neurons must equal the reference NPU's fired neurons.
+//!
+//! Partitions run as worker threads, as a coordinator inside a
+//! `BurstLoopRunner`, and (with the `distributed` feature) as separate worker
+//! processes. Worker processes are this test binary re-run with
+//! `FEAGI_DISTRIBUTED_TEST_WORKER` set, which turns `distributed_worker_process`
+//! from a no-op into a partition worker.
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use feagi_npu_burst_engine::backend::CPUBackend;
+use feagi_npu_burst_engine::distributed::{
+    ChannelSpikeExchange, DistributedError, PartitionCoordinator, PartitionPlan, PartitionWorker,
+    SpikeBatch, SpikeExchange,
+};
+use feagi_npu_burst_engine::{
+    BurstLoopRunner, BurstPacing, DynamicNPU, MotorPublisher, RawFireQueueSnapshot, RustNPU,
+    TickRequest, TracingMutex, VisualizationPublisher,
+};
+use feagi_npu_neural::types::connectome::ConnectomeSnapshot;
+use feagi_npu_neural::types::*;
+use feagi_npu_runtime::StdRuntime;
+use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+const REGULAR_AREAS: [u32; 4] = [10, 11, 12, 13];
+const NEURONS_PER_AREA: u32 = 16;
+const BURSTS: u64 = 60;
+
+type TestNpu = RustNPU<StdRuntime, f32, CPUBackend>;
+
+/// Small deterministic generator so the network is identical on every run
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+
+    fn range(&mut self, low: u32, high: u32) -> u32 {
+        low + self.next() % (high - low)
+    }
+}
+
+fn empty_npu() -> TestNpu {
+    RustNPU::new(StdRuntime, CPUBackend::new(), 1000, 10000, 20).unwrap()
+}
+
+/// Core areas plus four regular areas with dense cross-area wiring
+fn build_reference() -> TestNpu {
+    let mut npu = empty_npu();
+    let name = CoreCorticalType::Death.to_cortical_id().as_base_64();
+    npu.register_cortical_area(0, name.clone());
+    npu.register_cortical_area(1, CoreCorticalType::Power.to_cortical_id().as_base_64());
+    npu.register_cortical_area(2, CoreCorticalType::Fatigue.to_cortical_id().as_base_64());
+    for area in REGULAR_AREAS {
+        npu.register_cortical_area(area, name.clone());
+    }
+
+    let mut rng = Lcg(0x5eed);
+    let mut ids = Vec::new();
+    for area in REGULAR_AREAS {
+        for x in 0..NEURONS_PER_AREA {
+            // Vary every parameter so partitions must copy them, not default them
+            let threshold = rng.range(2000, 6000) as f32;
+            let threshold_limit = if rng.range(0, 4) == 0 {
+                threshold * 3.0
+            } else {
+                f32::MAX
+            };
+            let consecutive_fire_limit = if rng.range(0, 3) == 0 {
+                rng.range(1, 4) as u16
+            } else {
+                u16::MAX
+            };
+            let id = npu
+                .add_neuron(
+                    threshold,
+                    threshold_limit,
+                    rng.range(0, 30) as f32 / 100.0,
+                    0.0,
+                    0,
+                    rng.range(0, 3) as u16,
+                    1.0,
+                    consecutive_fire_limit,
+                    rng.range(0, 3) as u16,
+                    rng.range(0, 4) != 0,
+                    area,
+                    x,
+                    0,
+                    0,
+                )
+                .unwrap();
+            ids.push(id.0);
+        }
+    }
+
+    for &source in &ids {
+        for _ in 0..6 {
+            let target = ids[rng.range(0, ids.len() as u32) as usize];
+            let synapse_type = if rng.range(0, 5) == 0 {
+                SynapseType::Inhibitory
+            } else {
+                SynapseType::Excitatory
+            };
+            npu.add_synapse(
+                NeuronId(source),
+                NeuronId(target),
+                SynapticWeight(rng.range(20, 80) as u8),
+                SynapticPsp(rng.range(20, 80) as u8),
+                synapse_type,
+            )
+            .unwrap();
+        }
+    }
+    // Power neuron drives part of the first area
+    for &target in &ids[0..4] {
+        npu.add_synapse(
+            NeuronId(1),
+            NeuronId(target),
+            SynapticWeight(60),
+            SynapticPsp(60),
+            SynapseType::Excitatory,
+        )
+        .unwrap();
+    }
+    npu.rebuild_synapse_index();
+    npu
+}
+
+/// Sensory frame for a burst, addressed by global neuron ID
+fn sensory_frame(burst: u64) -> Vec<(u32, f32)> {
+    let mut rng = Lcg(burst);
+    let first = 3; // after the three core neurons
+    (0..3)
+        .map(|_| (first + rng.range(0, NEURONS_PER_AREA), 5000.0))
+        .collect()
+}
+
+/// No sensory input; the power neuron alone drives the network
+fn no_sensory(_burst: u64) -> Vec<(u32, f32)> {
+    Vec::new()
+}
+
+fn run_reference(npu: &mut TestNpu, sensory: fn(u64) -> Vec<(u32, f32)>) -> Vec<Vec<u32>> {
+    (1..=BURSTS)
+        .map(|burst| {
+            let frame: Vec<(NeuronId, f32)> = sensory(burst)
+                .into_iter()
+                .map(|(id, potential)| (NeuronId(id), potential))
+                .collect();
+            npu.inject_sensory_with_potentials(&frame);
+            let mut fired: Vec<u32> = npu
+                .process_burst()
+                .unwrap()
+                .fired_neurons
+                .iter()
+                .map(|id| id.0)
+                .collect();
+            fired.sort_unstable();
+            fired
+        })
+        .collect()
+}
+
+/// Run one partition for all bursts, returning (fired per burst, remote spikes received)
+fn run_partition(
+    snapshot: &ConnectomeSnapshot,
+    plan: &PartitionPlan,
+    exchange: &mut dyn SpikeExchange,
+    sensory: fn(u64) -> Vec<(u32, f32)>,
+) -> (Vec<Vec<u32>>, usize) {
+    let mut worker =
+        PartitionWorker::build(empty_npu(), snapshot, plan, exchange.partition()).unwrap();
+    let mut fired = Vec::new();
+    let mut remote_spikes = 0;
+    for burst in 1..=BURSTS {
+        worker.inject_sensory_with_potentials(&sensory(burst));
+        let result = worker.step(exchange).unwrap();
+        assert_eq!(result.burst, burst);
+        remote_spikes += result.remote_spikes;
+        fired.push(result.fired_neurons);
+    }
+    (fired, remote_spikes)
+}
+
+fn merge(per_partition: Vec<(Vec<Vec<u32>>, usize)>) -> (Vec<Vec<u32>>, usize) {
+    let mut merged = vec![Vec::new(); BURSTS as usize];
+    let mut remote_spikes = 0;
+    for (fired, remote) in per_partition {
+        remote_spikes += remote;
+        for (burst, neurons) in fired.into_iter().enumerate() {
+            merged[burst].extend(neurons);
+        }
+    }
+    for neurons in &mut merged {
+        neurons.sort_unstable();
+    }
+    (merged, remote_spikes)
+}
+
+/// Number of regular areas with fired neurons
+fn active_area_count(fired: &[Vec<u32>]) -> usize {
+    fired
+        .iter()
+        .flatten()
+        .filter(|&&id| id > 2)
+        .map(|&id| (id - 3) / NEURONS_PER_AREA)
+        .collect::<std::collections::BTreeSet<u32>>()
+        .len()
+}
+
+fn assert_matches_reference(reference: &[Vec<u32>], partitioned: &[Vec<u32>]) {
+    for (burst, (expected, actual)) in reference.iter().zip(partitioned).enumerate() {
+        assert_eq!(
+            expected,
+            actual,
+            "fired neurons differ at burst {}",
+            burst + 1
+        );
+    }
+}
+
+#[test]
+fn test_partitioned_threads_match_single_npu() {
+    let mut reference_npu = build_reference();
+    let snapshot = Arc::new(reference_npu.export_connectome());
+    let reference = run_reference(&mut reference_npu, sensory_frame);
+
+    // The network must actually be active across areas for the comparison to mean anything
+    assert!(active_area_count(&reference) > 2, "too little activity");
+
+    let plan = Arc::new(PartitionPlan::balanced(&snapshot, 3).unwrap());
+    for partition in 0..3 {
+        assert!(!plan.areas_of(partition).is_empty());
+    }
+
+    let handles: Vec<_> = ChannelSpikeExchange::mesh(3)
+        .into_iter()
+        .map(|mut exchange| {
+            let snapshot = Arc::clone(&snapshot);
+            let plan = Arc::clone(&plan);
+            thread::spawn(move || run_partition(&snapshot, &plan, &mut exchange, sensory_frame))
+        })
+        .collect();
+    let (partitioned, remote_spikes) =
+        merge(handles.into_iter().map(|h| h.join().unwrap()).collect());
+
+    assert!(remote_spikes > 0, "no spikes crossed partitions");
+    assert_matches_reference(&reference, &partitioned);
+}
+
+#[test]
+fn test_single_partition_plan_matches_single_npu() {
+    let mut reference_npu = build_reference();
+    let snapshot = reference_npu.export_connectome();
+    let reference = run_reference(&mut reference_npu, sensory_frame);
+
+    let plan = PartitionPlan::single(&snapshot);
+    let mut exchange = ChannelSpikeExchange::mesh(1).pop().unwrap();
+    let (partitioned, remote_spikes) =
+        run_partition(&snapshot, &plan, &mut exchange, sensory_frame);
+
+    assert_eq!(remote_spikes, 0);
+    assert_matches_reference(&reference, &partitioned);
+}
+
+#[test]
+fn test_partition_plan_rules() {
+    let snapshot = build_reference().export_connectome();
+
+    let plan = PartitionPlan::balanced(&snapshot, 2).unwrap();
+    assert_eq!(plan, PartitionPlan::balanced(&snapshot, 2).unwrap());
+    for core in 0..=2 {
+        assert_eq!(plan.partition_of(core), Some(0));
+    }
+    assert_eq!(plan.peers_of(1), vec![0]);
+
+    let mut manual = PartitionPlan::new(2).unwrap();
+    assert_eq!(
+        manual.assign(1, 1),
+        Err(DistributedError::CoreAreaOffCoordinator {
+            cortical_idx: 1,
+            partition: 1
+        })
+    );
+    assert!(matches!(
+        manual.assign(10, 2),
+        Err(DistributedError::PartitionOutOfRange { .. })
+    ));
+    assert_eq!(
+        PartitionPlan::new(0),
+        Err(DistributedError::InvalidPartitionCount)
+    );
+
+    // Areas missing from the plan are rejected when a worker loads the snapshot
+    manual.assign(0, 0).unwrap();
+    assert!(matches!(
+        PartitionWorker::build(empty_npu(), &snapshot, &manual, 0),
+        Err(DistributedError::UnassignedArea { .. })
+    ));
+}
+
+#[test]
+fn test_spike_batch_wire_format_and_clock_skew() {
+    let batch = SpikeBatch {
+        burst: 42,
+        source: 3,
+        neurons: vec![7, 9, 1_000_000],
+    };
+    let bytes = batch.to_bytes();
+    assert_eq!(bytes.len(), SpikeBatch::HEADER_LEN + 12);
+    assert_eq!(SpikeBatch::from_bytes(&bytes).unwrap(), batch);
+    assert!(SpikeBatch::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(SpikeBatch::from_bytes(b"nope").is_err());
+
+    // A peer replaying an old burst breaks the lockstep clock
+    let snapshot = build_reference().export_connectome();
+    let plan = PartitionPlan::balanced(&snapshot, 2).unwrap();
+    let mut exchanges = ChannelSpikeExchange::mesh(2);
+    let mut peer = exchanges.pop().unwrap();
+    let mut own = exchanges.pop().unwrap();
+    let mut worker = PartitionWorker::build(empty_npu(), &snapshot, &plan, 0).unwrap();
+
+    worker.step(&mut own).unwrap();
+    let stale = SpikeBatch {
+        burst: 0,
+        source: 1,
+        neurons: Vec::new(),
+    };
+    peer.send(0, &stale).unwrap();
+    assert_eq!(
+        worker.step(&mut own),
+        Err(DistributedError::ClockSkew {
+            source_partition: 1,
+            expected: 1,
+            received: 0
+        })
+    );
+}
+
+struct NoViz;
+impl VisualizationPublisher for NoViz {
+    fn publish_raw_fire_queue_for_agent(
+        &self,
+        _agent_id: &str,
+        _fire_data: RawFireQueueSnapshot,
+    ) -> std::result::Result<(), String> {
+        Ok(())
+    }
+}
+
+struct NoMotor;
+impl MotorPublisher for NoMotor {
+    fn publish_motor(&self, _agent_id: &str, _data: &[u8]) -> std::result::Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Run partition 0 as the coordinator of a lockstep burst loop.
+///
+/// Every area is reported as motor output, so each tick returns the neurons
+/// the coordinator fired in that burst. The detached coordinator is returned
+/// still connected, so the workers can deliver their last batch.
+fn run_coordinator(
+    snapshot: &ConnectomeSnapshot,
+    plan: &PartitionPlan,
+    exchange: Box<dyn SpikeExchange>,
+) -> (Vec<Vec<u32>>, PartitionCoordinator) {
+    let npu = Arc::new(TracingMutex::new(
+        DynamicNPU::F32(build_reference()),
+        "CoordinatorNPU",
+    ));
+    let mut runner = BurstLoopRunner::new::<NoViz, NoMotor>(npu, None, None, 1.0);
+    let names: ahash::AHashMap<u32, String> = snapshot
+        .cortical_area_names
+        .keys()
+        .map(|&idx| (idx, format!("area{}", idx)))
+        .collect();
+    let motor_ids: ahash::AHashSet<String> = names.values().cloned().collect();
+    runner.refresh_cortical_id_mappings(names);
+    runner.set_pacing(BurstPacing::Lockstep);
+    runner.start().unwrap();
+    runner
+        .attach_partition_coordinator(snapshot, plan, exchange)
+        .unwrap();
+
+    let fired = (1..=BURSTS)
+        .map(|burst| {
+            let output = runner
+                .tick(
+                    TickRequest::default().with_motor_cortical_ids(motor_ids.clone()),
+                    Duration::from_secs(20),
+                )
+                .unwrap();
+            assert_eq!(output.burst, burst);
+            let mut neurons: Vec<u32> = output
+                .motor
+                .values()
+                .flat_map(|area| area.neuron_ids.iter().copied())
+                .collect();
+            neurons.sort_unstable();
+            neurons
+        })
+        .collect();
+
+    let coordinator = runner
+        .detach_partition_coordinator()
+        .expect("coordinator still attached");
+    runner.stop();
+    (fired, coordinator)
+}
+
+#[test]
+fn test_burst_loop_coordinator_with_worker_threads_matches_single_npu() {
+    let mut reference_npu = build_reference();
+    let snapshot = Arc::new(reference_npu.export_connectome());
+    let reference = run_reference(&mut reference_npu, no_sensory);
+    assert!(active_area_count(&reference) > 2, "too little activity");
+
+    let plan = Arc::new(PartitionPlan::balanced(&snapshot, 3).unwrap());
+    let mut exchanges = ChannelSpikeExchange::mesh(3).into_iter();
+    let coordinator_exchange = exchanges.next().unwrap();
+    let workers: Vec<_> = exchanges
+        .map(|mut exchange| {
+            let snapshot = Arc::clone(&snapshot);
+            let plan = Arc::clone(&plan);
+            thread::spawn(move || run_partition(&snapshot, &plan, &mut exchange, no_sensory))
+        })
+        .collect();
+
+    let (coordinator_fired, coordinator) =
+        run_coordinator(&snapshot, &plan, Box::new(coordinator_exchange));
+    let mut per_partition: Vec<_> = workers.into_iter().map(|h| h.join().unwrap()).collect();
+    coordinator.shutdown().unwrap();
+    per_partition.push((coordinator_fired, 0));
+    let (partitioned, remote_spikes) = merge(per_partition);
+
+    assert!(remote_spikes > 0, "no spikes crossed partitions");
+    assert_matches_reference(&reference, &partitioned);
+}
+
+#[test]
+fn test_coordinator_rejects_worker_partition() {
+    let mut npu = build_reference();
+    let snapshot = npu.export_connectome();
+    let plan = PartitionPlan::balanced(&snapshot, 2).unwrap();
+    let exchange = ChannelSpikeExchange::mesh(2).pop().unwrap();
+    assert!(matches!(
+        PartitionCoordinator::attach(&mut npu, &snapshot, &plan, Box::new(exchange)),
+        Err(DistributedError::NotCoordinator(1))
+    ));
+}
+
+#[test]
+fn test_burst_loop_stops_with_fault_when_partition_exchange_fails() {
+    let reference = build_reference();
+    let snapshot = reference.export_connectome();
+    let plan = PartitionPlan::balanced(&snapshot, 2).unwrap();
+    let mut exchanges = ChannelSpikeExchange::mesh(2);
+    drop(exchanges.pop()); // the worker is gone
+    let coordinator_exchange = exchanges.pop().unwrap();
+
+    let npu = Arc::new(TracingMutex::new(
+        DynamicNPU::F32(build_reference()),
+        "CoordinatorNPU",
+    ));
+    let mut runner = BurstLoopRunner::new::<NoViz, NoMotor>(npu, None, None, 1.0);
+    runner.set_pacing(BurstPacing::Lockstep);
+    runner.start().unwrap();
+    runner
+        .attach_partition_coordinator(&snapshot, &plan, Box::new(coordinator_exchange))
+        .unwrap();
+
+    // The burst fails instead of simulating partition 0 alone, and the loop stops
+    let failed = (0..2).find_map(|_| {
+        runner
+            .tick(TickRequest::default(), Duration::from_secs(5))
+            .err()
+    });
+    assert!(failed.is_some(), "bursts kept running without the worker");
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while runner.is_running() && std::time::Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!runner.is_running());
+    assert!(runner
+        .fault()
+        .is_some_and(|fault| fault.contains("partition")));
+    runner.stop();
+}
+
+/// Set in worker processes to `<transport> <partition> <endpoint>,<endpoint>,...`
+#[cfg(feature = "distributed")]
+const WORKER_ENV: &str = "FEAGI_DISTRIBUTED_TEST_WORKER";
+
+#[cfg(feature = "distributed")]
+fn io_exchange(
+    transport: &str,
+    partition: u32,
+    endpoints: &[String],
+    snapshot: &ConnectomeSnapshot,
+) -> feagi_npu_burst_engine::distributed::IoSpikeExchange {
+    use feagi_npu_burst_engine::distributed::IoSpikeExchange;
+
+    let mut exchange = match transport {
+        "zmq" => IoSpikeExchange::zmq(partition, endpoints).unwrap(),
+        "shm" => {
+            IoSpikeExchange::shared_memory(partition, endpoints, snapshot.neurons.count as u32)
+                .unwrap()
+        }
+        other => panic!("unknown transport {}", other),
+    };
+    exchange.start(Duration::from_secs(20)).unwrap();
+    exchange
+}
+
+/// Partition worker body of the multi-process tests; a no-op in normal runs
+#[cfg(feature = "distributed")]
+#[test]
+fn distributed_worker_process() {
+    use std::io::Read;
+
+    let Ok(spec) = std::env::var(WORKER_ENV) else {
+        return;
+    };
+    let mut parts = spec.split(' ');
+    let transport = parts.next().unwrap();
+    let partition: u32 = parts.next().unwrap().parse().unwrap();
+    let endpoints: Vec<String> = parts.next().unwrap().split(',').map(String::from).collect();
+
+    let snapshot = build_reference().export_connectome();
+    let plan = PartitionPlan::balanced(&snapshot, endpoints.len() as u32).unwrap();
+    let mut exchange = io_exchange(transport, partition, &endpoints, &snapshot);
+    let (fired, remote_spikes) = run_partition(&snapshot, &plan, &mut exchange, no_sensory);
+    for (burst, neurons) in fired.iter().enumerate() {
+        let neurons: Vec<String> = neurons.iter().map(u32::to_string).collect();
+        println!("FIRED {} {}", burst + 1, neurons.join(","));
+    }
+    println!("REMOTE {}", remote_spikes);
+    println!("DONE");
+
+    // Stay connected until the coordinator has taken our last batch
+    let mut rest = Vec::new();
+    std::io::stdin().read_to_end(&mut rest).unwrap();
+    exchange.stop().unwrap();
+}
+
+/// Run the coordinator in this process and partitions 1.. as worker processes
+#[cfg(feature = "distributed")]
+fn run_multi_process(transport: &str, endpoints: Vec<String>) {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+    use std::sync::Mutex;
+
+    let mut reference_npu = build_reference();
+    let snapshot = reference_npu.export_connectome();
+    let reference = run_reference(&mut reference_npu, no_sensory);
+    let plan = PartitionPlan::balanced(&snapshot, endpoints.len() as u32).unwrap();
+
+    let mut children = Vec::new();
+    let mut readers = Vec::new();
+    for partition in 1..endpoints.len() {
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "distributed_worker_process",
+                "--exact",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(
+                WORKER_ENV,
+                format!("{} {} {}", transport, partition, endpoints.join(",")),
+            )
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        let output = Arc::new(Mutex::new((vec![Vec::new(); BURSTS as usize], 0usize)));
+        let sink = Arc::clone(&output);
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                let mut words = line.split(' ');
+                match words.next() {
+                    Some("FIRED") => {
+                        let burst: usize = words.next().unwrap().parse().unwrap();
+                        sink.lock().unwrap().0[burst - 1] = words
+                            .next()
+                            .unwrap_or("")
+                            .split(',')
+                            .filter(|id| !id.is_empty())
+                            .map(|id| id.parse().unwrap())
+                            .collect();
+                    }
+                    Some("REMOTE") => {
+                        sink.lock().unwrap().1 = words.next().unwrap().parse().unwrap();
+                    }
+                    Some("DONE") => {
+                        let _ = done_tx.send(());
+                    }
+                    _ => {}
+                }
+            }
+        });
+        children.push(child);
+        readers.push((done_rx, output));
+    }
+
+    let exchange = io_exchange(transport, 0, &endpoints, &snapshot);
+    let (coordinator_fired, coordinator) = run_coordinator(&snapshot, &plan, Box::new(exchange));
+
+    let mut per_partition = vec![(coordinator_fired, 0)];
+    for (done, output) in readers {
+        done.recv_timeout(Duration::from_secs(60))
+            .expect("worker process did not finish");
+        per_partition.push(output.lock().unwrap().clone());
+    }
+    coordinator.shutdown().unwrap();
+    for mut child in children {
+        drop(child.stdin.take());
+        assert!(child.wait().unwrap().success());
+    }
+    let (partitioned, remote_spikes) = merge(per_partition);
+
+    assert!(remote_spikes > 0, "no spikes crossed partitions");
+    assert_matches_reference(&reference, &partitioned);
+}
+
+#[cfg(feature = "distributed")]
+#[test]
+fn test_worker_processes_over_zmq_match_single_npu() {
+    let endpoints: Vec<String> = (0..3)
+        .map(|p| {
+            format!(
+                "ipc:///tmp/feagi-distributed-test-{}-{}",
+                std::process::id(),
+                p
+            )
+        })
+        .collect();
+    run_multi_process("zmq", endpoints.clone());
+    // ZMQ leaves ipc socket files behind
+    for endpoint in &endpoints {
+        let _ = std::fs::remove_file(endpoint.trim_start_matches("ipc://"));
+    }
+}
+
+#[cfg(feature = "distributed")]
+#[test]
+fn test_worker_processes_over_shared_memory_match_single_npu() {
+    let endpoints = (0..3)
+        .map(|p| {
+            std::env::temp_dir()
+                .join(format!(
+                    "feagi-distributed-test-{}-{}",
+                    std::process::id(),
+                    p
+                ))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    run_multi_process("shm", endpoints);
+}
@@ -24,6 +24,7 @@ fn test_gpu_config_disabled() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     let (backend_type, backend_config) = config.to_backend_selection();
@@ -40,6 +41,7 @@ fn test_gpu_config_hybrid_mode() {
         hybrid_enabled: true,
         gpu_threshold: 500_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     let (backend_type, backend_config) = config.to_backend_selection();
@@ -58,6 +60,7 @@ fn test_gpu_config_always_on() {
         hybrid_enabled: false,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     let (backend_type, backend_config) = config.to_backend_selection();
@@ -84,6 +87,7 @@ fn test_gpu_config_custom_threshold() {
         hybrid_enabled: true,
         gpu_threshold: 5_000_000,
         gpu_memory_fraction: 0.5,
+        ..Default::default()
     };
 
     let (_, backend_config) = config.to_backend_selection();
@@ -100,6 +104,7 @@ fn test_gpu_config_serialization() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     // Verify values match TOML defaults
@@ -116,6 +121,7 @@ fn test_backend_selection_small_genome() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     let (_, backend_config) = config.to_backend_selection();
@@ -137,6 +143,7 @@ fn test_backend_selection_large_genome() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.8,
+        ..Default::default()
     };
 
     let (_, backend_config) = config.to_backend_selection();
@@ -176,6 +183,7 @@ fn test_gpu_memory_fraction_range() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 0.5,
+        ..Default::default()
     };
     assert!(config.gpu_memory_fraction >= 0.0 && config.gpu_memory_fraction <= 1.0);
 
@@ -184,6 +192,7 @@ fn test_gpu_memory_fraction_range() {
         hybrid_enabled: true,
         gpu_threshold: 1_000_000,
         gpu_memory_fraction: 1.0,
+        ..Default::default()
     };
     assert!(config2.gpu_memory_fraction >= 0.0 && config2.gpu_memory_fraction <= 1.0);
 }
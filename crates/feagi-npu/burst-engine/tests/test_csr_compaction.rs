@@ -0,0 +1,104 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # CSR Compaction Tests
+//!
+//! A `RustNPU` on `CsrRuntime` must merge buffered synapse changes on idle
+//! bursts and keep propagating through the merged rows.
+
+use feagi_npu_burst_engine::backend::CPUBackend;
+use feagi_npu_burst_engine::RustNPU;
+use feagi_npu_neural::{NeuronId, SynapseType, SynapticPsp, SynapticWeight};
+use feagi_npu_runtime::CsrRuntime;
+use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+const AREA: u32 = 10;
+
+fn build_npu() -> RustNPU<CsrRuntime, f32, CPUBackend> {
+    let mut npu = RustNPU::new(CsrRuntime::new(), CPUBackend::new(), 16, 16, 20).unwrap();
+    npu.register_cortical_area(AREA, CoreCorticalType::Death.to_cortical_id().as_base_64());
+    for i in 0..4 {
+        npu.add_neuron(
+            1.0,      // threshold
+            f32::MAX, // threshold limit
+            0.0,      // leak coefficient
+            0.0,      // resting potential
+            0,        // neuron type
+            0,        // refractory period
+            1.0,      // excitability
+            u16::MAX, // consecutive fire limit
+            0,        // snooze period
+            true,     // mp_charge_accumulation
+            AREA,
+            i,
+            0,
+            0,
+        )
+        .unwrap();
+    }
+    npu
+}
+
+fn add_synapse(npu: &mut RustNPU<CsrRuntime, f32, CPUBackend>, source: u32, target: u32) {
+    npu.add_synapse(
+        NeuronId(source),
+        NeuronId(target),
+        SynapticWeight(255),
+        SynapticPsp(255),
+        SynapseType::Excitatory,
+    )
+    .unwrap();
+}
+
+fn fire(npu: &mut RustNPU<CsrRuntime, f32, CPUBackend>, neuron: u32) -> Vec<u32> {
+    npu.inject_sensory_with_potentials(&[(NeuronId(neuron), 10.0)]);
+    npu.process_burst().unwrap();
+    let mut fired: Vec<u32> = npu
+        .process_burst()
+        .unwrap()
+        .fired_neurons
+        .iter()
+        .map(|id| id.0)
+        .collect();
+    fired.sort_unstable();
+    fired
+}
+
+#[test]
+fn test_idle_burst_merges_buffered_synapses() {
+    let mut npu = build_npu();
+    add_synapse(&mut npu, 0, 1);
+    add_synapse(&mut npu, 0, 2);
+    add_synapse(&mut npu, 2, 3);
+    npu.rebuild_synapse_index();
+
+    // Small buffers are only merged when idle
+    assert!(!npu.compact_synapse_storage(false));
+
+    // Buffered rows propagate before any merge
+    assert_eq!(fire(&mut npu, 0), vec![1, 2]);
+
+    // 2 -> 3 fires next, then two quiet bursts make the NPU idle and merge the buffer
+    for _ in 0..3 {
+        npu.process_burst().unwrap();
+    }
+    assert!(!npu.compact_synapse_storage(true));
+
+    assert!(npu.remove_synapse(NeuronId(0), NeuronId(2)));
+    add_synapse(&mut npu, 1, 3);
+    npu.rebuild_synapse_index();
+    assert!(npu.compact_synapse_storage(true));
+    assert_eq!(npu.get_synapse_count(), 3);
+
+    let mut targets: Vec<u32> = npu
+        .get_outgoing_synapses(0)
+        .iter()
+        .map(|&(target, ..)| target)
+        .collect();
+    targets.sort_unstable();
+    assert_eq!(targets, vec![1]);
+
+    npu.process_burst().unwrap();
+    assert_eq!(fire(&mut npu, 1), vec![3]);
+    assert_eq!(fire(&mut npu, 0), vec![1]);
+}
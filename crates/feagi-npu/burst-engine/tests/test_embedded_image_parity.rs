@@ -0,0 +1,126 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Embedded Image Parity Tests
+//!
+//! A connectome exported from a `RustNPU`, compiled into an embedded image and
+//! loaded into the fixed-size embedded arrays must fire exactly like the NPU
+//! it came from, including threshold limits, consecutive fire limits with
+//! snooze, probabilistic excitability and non-accumulating neurons.
+
+use feagi_npu_burst_engine::backend::CPUBackend;
+use feagi_npu_burst_engine::RustNPU;
+use feagi_npu_neural::{NeuronId, SynapseType, SynapticPsp, SynapticWeight};
+use feagi_npu_runtime::embedded_impl::image::compile_image;
+use feagi_npu_runtime::embedded_impl::{EmbeddedImage, NeuronArray, SynapseArray};
+use feagi_npu_runtime::StdRuntime;
+use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+const NEURONS: usize = 64;
+const SYNAPSES: usize = 256;
+const AREA: u32 = 10;
+
+/// Recurrent network exercising every per-neuron parameter, with a deleted neuron
+fn build_npu() -> RustNPU<StdRuntime, f32, CPUBackend> {
+    let mut npu = RustNPU::new(StdRuntime, CPUBackend::new(), NEURONS, SYNAPSES, 20).unwrap();
+    npu.register_cortical_area(AREA, CoreCorticalType::Death.to_cortical_id().as_base_64());
+
+    let ids: Vec<u32> = (0..40u32)
+        .map(|i| {
+            npu.add_neuron(
+                40.0 + (i % 7) as f32 * 10.0,              // threshold
+                if i % 5 == 1 { 150.0 } else { f32::MAX }, // threshold limit
+                0.2 + (i % 3) as f32 * 0.2,                // leak coefficient
+                0.0,                                       // resting potential
+                0,                                         // neuron type
+                (i % 3) as u16,                            // refractory period
+                if i % 4 == 2 { 0.6 } else { 1.0 },        // excitability
+                if i % 6 == 3 { 2 } else { u16::MAX },     // consecutive fire limit
+                if i % 6 == 3 { 3 } else { 0 },            // snooze period
+                i % 8 != 7,                                // mp_charge_accumulation
+                AREA,
+                i,
+                0,
+                0,
+            )
+            .unwrap()
+            .0
+        })
+        .collect();
+
+    for i in 0..200usize {
+        let (source, target) = ((i * 7) % ids.len(), (i * 11 + 3) % ids.len());
+        let synapse_type = if i % 6 == 0 {
+            SynapseType::Inhibitory
+        } else {
+            SynapseType::Excitatory
+        };
+        npu.add_synapse(
+            NeuronId(ids[source]),
+            NeuronId(ids[target]),
+            SynapticWeight((i % 9 + 1) as u8),
+            SynapticPsp(4),
+            synapse_type,
+        )
+        .unwrap();
+    }
+
+    // A deleted neuron leaves a gap; IDs after it must not shift
+    npu.delete_neuron(ids[5]);
+    npu.remove_synapses_from_sources(vec![NeuronId(ids[5])]);
+    npu.rebuild_synapse_index();
+    npu
+}
+
+/// Sensory drive for a burst: a few neurons get input on a fixed schedule,
+/// neuron 3 (consecutive fire limit 2, snooze 3) is driven every burst
+#[allow(clippy::manual_is_multiple_of)] // u64::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+fn drive(burst: u64) -> Vec<(NeuronId, f32)> {
+    (0..4u32)
+        .filter(|&neuron| neuron == 3 || (burst + neuron as u64) % 3 == 0)
+        .map(|neuron| (NeuronId(neuron), 80.0))
+        .collect()
+}
+
+#[test]
+fn test_embedded_image_fires_like_rust_npu() {
+    const BURSTS: u64 = 60;
+    let mut npu = build_npu();
+    let blob = compile_image(&npu.export_connectome(), NEURONS, SYNAPSES).unwrap();
+
+    let image = EmbeddedImage::parse(&blob).unwrap();
+    let mut mcu_neurons = NeuronArray::<f32, NEURONS>::new();
+    let mut mcu_synapses = SynapseArray::<SYNAPSES>::new();
+    image
+        .load_into(&mut mcu_neurons, &mut mcu_synapses)
+        .unwrap();
+
+    let mut mcu_fired = [false; NEURONS];
+    let mut mcu_candidates = [false; NEURONS];
+    let mut mcu_contributions = [0.0f32; NEURONS];
+    let mut total_fired = 0;
+
+    for burst in 1..=BURSTS {
+        let frame = drive(burst);
+
+        npu.inject_sensory_with_potentials(&frame);
+        let result = npu.process_burst().unwrap();
+        assert_eq!(result.burst, burst);
+        let mut npu_fired: Vec<u32> = result.fired_neurons.iter().map(|id| id.0).collect();
+        npu_fired.sort_unstable();
+
+        mcu_synapses.propagate_candidates(&mcu_fired, &mut mcu_contributions, &mut mcu_candidates);
+        for &(neuron, potential) in &frame {
+            mcu_candidates[neuron.0 as usize] = true;
+            mcu_contributions[neuron.0 as usize] += potential;
+        }
+        mcu_neurons.process_candidates(&mcu_candidates, &mcu_contributions, &mut mcu_fired, burst);
+        let mcu_fired_ids: Vec<u32> = (0..NEURONS as u32)
+            .filter(|&i| mcu_fired[i as usize])
+            .collect();
+
+        assert_eq!(mcu_fired_ids, npu_fired, "burst {}", burst);
+        total_fired += npu_fired.len();
+    }
+    assert!(total_fired > BURSTS as usize, "network should be active");
+}
@@ -0,0 +1,157 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Partitioned Burst Tests
+//!
+//! A `RustNPU` built with `BackendConfig::cpu_threads > 1` must run neural
+//! dynamics on several threads, and fire and update its neurons exactly like a
+//! serial NPU.
+
+use feagi_npu_burst_engine::backend::{BackendConfig, CPUBackend, GpuConfig};
+use feagi_npu_burst_engine::RustNPU;
+use feagi_npu_neural::{NeuronId, SynapseType, SynapticPsp, SynapticWeight};
+use feagi_npu_runtime::StdRuntime;
+use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+const NEURONS: u32 = 5_000;
+const AREA: u32 = 10;
+
+fn build_npu(cpu_threads: usize) -> RustNPU<StdRuntime, f32, CPUBackend> {
+    build_npu_with_config(&BackendConfig {
+        cpu_threads,
+        cpu_parallel_synapse_threshold: 1,
+        ..Default::default()
+    })
+}
+
+fn build_npu_with_config(config: &BackendConfig) -> RustNPU<StdRuntime, f32, CPUBackend> {
+    let mut npu = RustNPU::new_with_backend_config(
+        StdRuntime,
+        CPUBackend::new(),
+        NEURONS as usize,
+        NEURONS as usize * 4,
+        20,
+        config,
+    )
+    .unwrap();
+    npu.register_cortical_area(AREA, CoreCorticalType::Death.to_cortical_id().as_base_64());
+
+    for i in 0..NEURONS {
+        npu.add_neuron(
+            30.0 + (i % 5) as f32 * 10.0,             // threshold
+            if i % 7 == 1 { 90.0 } else { f32::MAX }, // threshold limit
+            0.1 + (i % 3) as f32 * 0.2,               // leak coefficient
+            0.0,                                      // resting potential
+            0,                                        // neuron type
+            (i % 3) as u16,                           // refractory period
+            if i % 4 == 2 { 0.5 } else { 1.0 },       // excitability
+            if i % 6 == 3 { 2 } else { u16::MAX },    // consecutive fire limit
+            if i % 6 == 3 { 2 } else { 0 },           // snooze period
+            i % 8 != 7,                               // mp_charge_accumulation
+            AREA,
+            i % 100,
+            i / 100,
+            0,
+        )
+        .unwrap();
+    }
+    for i in 0..NEURONS * 3 {
+        let (source, target) = ((i * 7) % NEURONS, (i * 13 + 5) % NEURONS);
+        let synapse_type = if i % 5 == 0 {
+            SynapseType::Inhibitory
+        } else {
+            SynapseType::Excitatory
+        };
+        npu.add_synapse(
+            NeuronId(source),
+            NeuronId(target),
+            SynapticWeight((i % 9 + 1) as u8),
+            SynapticPsp(3),
+            synapse_type,
+        )
+        .unwrap();
+    }
+    npu.rebuild_synapse_index();
+    npu
+}
+
+/// Every fired neuron of the current fire queue with its area, coordinates and
+/// potential bits, in neuron ID order
+///
+/// Within an area the queue follows the FCL's hash order, which differs per NPU
+/// whether or not the burst is partitioned.
+fn fire_queue(npu: &RustNPU<StdRuntime, f32, CPUBackend>) -> Vec<(u32, u32, u32, u32, u32, u32)> {
+    let mut fired: Vec<_> = npu
+        .get_current_fire_queue()
+        .into_iter()
+        .flat_map(|(area, (ids, xs, ys, zs, potentials))| {
+            (0..ids.len())
+                .map(|i| (ids[i], area, xs[i], ys[i], zs[i], potentials[i].to_bits()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    fired.sort_unstable();
+    fired
+}
+
+#[test]
+fn test_partitioned_burst_runs_on_multiple_threads_and_matches_serial() {
+    let mut serial = build_npu(1);
+    let mut partitioned = build_npu(4);
+
+    let mut partitioned_threads = 0;
+    for burst in 0..20u32 {
+        let frame: Vec<(NeuronId, f32)> = (0..NEURONS)
+            .filter(|neuron| (neuron + burst) % 11 == 0)
+            .map(|neuron| (NeuronId(neuron), 50.0))
+            .collect();
+
+        serial.inject_sensory_with_potentials(&frame);
+        partitioned.inject_sensory_with_potentials(&frame);
+        let serial_result = serial.process_burst().unwrap();
+        let partitioned_result = partitioned.process_burst().unwrap();
+
+        assert_eq!(serial_result.worker_threads, 1);
+        assert_eq!(
+            fire_queue(&partitioned),
+            fire_queue(&serial),
+            "burst {}",
+            burst
+        );
+        assert_eq!(partitioned_result.neuron_count, serial_result.neuron_count);
+        assert_eq!(
+            partitioned_result.neurons_in_refractory,
+            serial_result.neurons_in_refractory
+        );
+        for neuron in 0..NEURONS {
+            assert_eq!(
+                partitioned.get_neuron_state(NeuronId(neuron)),
+                serial.get_neuron_state(NeuronId(neuron)),
+                "neuron {} after burst {}",
+                neuron,
+                burst
+            );
+        }
+        partitioned_threads = partitioned_threads.max(partitioned_result.worker_threads);
+    }
+    assert_eq!(partitioned_threads, 4);
+}
+
+#[test]
+fn test_application_config_threads_reach_the_npu() {
+    // The application fills these from feagi-config's [burst_engine] section
+    let (_, backend_config) = GpuConfig {
+        use_gpu: false,
+        cpu_threads: 4,
+        cpu_parallel_synapse_threshold: 1,
+        ..Default::default()
+    }
+    .to_backend_selection();
+    let mut npu = build_npu_with_config(&backend_config);
+
+    let frame: Vec<(NeuronId, f32)> = (0..NEURONS)
+        .map(|neuron| (NeuronId(neuron), 50.0))
+        .collect();
+    npu.inject_sensory_with_potentials(&frame);
+    assert_eq!(npu.process_burst().unwrap().worker_threads, 4);
+}
@@ -57,6 +57,18 @@ pub struct SerializableNeuronArray {
     /// Excitability multipliers (f32)
     pub excitabilities: Vec<f32>,
 
+    /// Threshold limits (f32, `f32::MAX` = no limit)
+    pub threshold_limits: Vec<f32>,
+
+    /// Consecutive fire limits (u16, `u16::MAX` = no limit)
+    pub consecutive_fire_limits: Vec<u16>,
+
+    /// Snooze periods after hitting the consecutive fire limit (u16)
+    pub snooze_periods: Vec<u16>,
+
+    /// Whether membrane potential accumulates across bursts (bool)
+    pub mp_charge_accumulation: Vec<bool>,
+
     /// Cortical area IDs (u32)
     pub cortical_areas: Vec<u32>,
 
@@ -82,6 +94,10 @@ impl SerializableNeuronArray {
             refractory_periods: std::vec::from_elem(0, capacity),
             refractory_countdowns: std::vec::from_elem(0, capacity),
             excitabilities: std::vec::from_elem(1.0, capacity),
+            threshold_limits: std::vec::from_elem(f32::MAX, capacity),
+            consecutive_fire_limits: std::vec::from_elem(u16::MAX, capacity),
+            snooze_periods: std::vec::from_elem(0, capacity),
+            mp_charge_accumulation: std::vec::from_elem(true, capacity),
             cortical_areas: std::vec::from_elem(0, capacity),
             coordinates: std::vec::from_elem(0, capacity * 3), // x, y, z for each neuron
             valid_mask: std::vec::from_elem(false, capacity),
@@ -0,0 +1,616 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Static connectome images for the embedded runtime
+//!
+//! A brain trained on a server is compiled (host side, `std` feature) into a
+//! compact binary image with [`compile_image`]. The image is meant to live in
+//! flash, e.g. via `include_bytes!`, and is copied into the fixed-size
+//! embedded arrays at boot by [`EmbeddedImage::load_into`], which is `no_std`
+//! and allocation-free:
+//!
+//! ```ignore
+//! static BRAIN: &[u8] = include_bytes!("brain.femb");
+//!
+//! let image = EmbeddedImage::parse(BRAIN)?;
+//! let mut neurons = NeuronArray::<f32, 1000>::new();
+//! let mut synapses = SynapseArray::<5000>::new();
+//! image.load_into(&mut neurons, &mut synapses)?;
+//! ```
+//!
+//! ## Format (little-endian)
+//!
+//! ```text
+//! [Header, 24 bytes]
+//!   magic "FEMB" | version u32 | neuron_count u32 | synapse_count u32
+//!   reserved u32 | checksum u32 (FNV-1a over everything after the header)
+//! [Neurons, 56 bytes each]
+//!   threshold f32 | leak f32 | resting f32 | membrane_potential f32 | excitability f32
+//!   neuron_type i32 | cortical_area u32 | x u32 | y u32 | z u32
+//!   refractory_period u16 | refractory_countdown u16 | valid u8 | mp_charge_accumulation u8
+//!   pad u8 × 2 | threshold_limit f32 | consecutive_fire_limit u16 | snooze_period u16
+//! [Synapses, 12 bytes each]
+//!   source u32 | target u32 | weight u8 | psp u8 | type u8 | pad u8
+//! ```
+//!
+//! Neuron IDs are positions in the neuron table, so they match the IDs of the
+//! source connectome (gaps are kept as invalid neurons). Only valid synapses
+//! are stored.
+
+use crate::traits::{NeuronStorage, RuntimeError, SynapseStorage};
+use core::fmt;
+use feagi_npu_neural::types::NeuralValue;
+
+/// Embedded image magic bytes
+pub const IMAGE_MAGIC: &[u8; 4] = b"FEMB";
+
+/// Embedded image format version
+pub const IMAGE_VERSION: u32 = 2;
+
+/// Size of the image header
+pub const HEADER_LEN: usize = 24;
+
+/// Size of one neuron record
+pub const NEURON_RECORD_LEN: usize = 56;
+
+/// Size of one synapse record
+pub const SYNAPSE_RECORD_LEN: usize = 12;
+
+/// Embedded image errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// Data does not start with [`IMAGE_MAGIC`]
+    InvalidMagic,
+    /// Image was written by an unsupported format version
+    UnsupportedVersion(u32),
+    /// Data is shorter than the header declares
+    Truncated,
+    /// Body checksum does not match the header
+    ChecksumMismatch,
+    /// Image does not fit the target storage
+    CapacityExceeded {
+        /// What overflowed ("neurons" or "synapses")
+        what: &'static str,
+        /// Required slots
+        requested: usize,
+        /// Available slots
+        available: usize,
+    },
+    /// Synapse references a neuron outside the neuron table
+    DanglingSynapse {
+        /// Synapse position in the source
+        index: usize,
+    },
+    /// Target storage must be empty so neuron IDs line up
+    StorageNotEmpty,
+    /// Target storage rejected a neuron or synapse
+    Storage(RuntimeError),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::InvalidMagic => write!(f, "Invalid embedded image magic"),
+            ImageError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported embedded image version {}", version)
+            }
+            ImageError::Truncated => write!(f, "Embedded image is truncated"),
+            ImageError::ChecksumMismatch => write!(f, "Embedded image checksum mismatch"),
+            ImageError::CapacityExceeded {
+                what,
+                requested,
+                available,
+            } => write!(
+                f,
+                "Image needs {} {} but only {} are available",
+                requested, what, available
+            ),
+            ImageError::DanglingSynapse { index } => {
+                write!(f, "Synapse {} references a neuron outside the image", index)
+            }
+            ImageError::StorageNotEmpty => write!(f, "Target storage must be empty"),
+            ImageError::Storage(e) => write!(f, "Storage error: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ImageError {}
+
+impl From<RuntimeError> for ImageError {
+    fn from(e: RuntimeError) -> Self {
+        ImageError::Storage(e)
+    }
+}
+
+/// One neuron of an image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageNeuron {
+    /// Firing threshold
+    pub threshold: f32,
+    /// Leak coefficient
+    pub leak_coefficient: f32,
+    /// Resting potential
+    pub resting_potential: f32,
+    /// Membrane potential at compile time
+    pub membrane_potential: f32,
+    /// Excitability multiplier
+    pub excitability: f32,
+    /// Neuron type
+    pub neuron_type: i32,
+    /// Cortical area index
+    pub cortical_area: u32,
+    /// Coordinates within the cortical area
+    pub coordinates: (u32, u32, u32),
+    /// Refractory period (bursts)
+    pub refractory_period: u16,
+    /// Refractory countdown at compile time
+    pub refractory_countdown: u16,
+    /// Whether the slot holds a neuron
+    pub valid: bool,
+    /// Whether membrane potential accumulates across bursts
+    pub mp_charge_accumulation: bool,
+    /// Upper bound of the firing window
+    pub threshold_limit: f32,
+    /// Consecutive fires before snoozing
+    pub consecutive_fire_limit: u16,
+    /// Extra refractory bursts after hitting the consecutive fire limit
+    pub snooze_period: u16,
+}
+
+/// One synapse of an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageSynapse {
+    /// Source neuron ID
+    pub source: u32,
+    /// Target neuron ID
+    pub target: u32,
+    /// Synaptic weight
+    pub weight: u8,
+    /// Postsynaptic potential
+    pub psp: u8,
+    /// Synapse type (0=excitatory, 1=inhibitory)
+    pub synapse_type: u8,
+}
+
+fn fnv1a(data: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for &byte in data {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn read_f32(bytes: &[u8], at: usize) -> f32 {
+    f32::from_bits(read_u32(bytes, at))
+}
+
+/// Validated view over an embedded image (zero-copy; typically in flash)
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedImage<'a> {
+    bytes: &'a [u8],
+    neuron_count: usize,
+    synapse_count: usize,
+}
+
+impl<'a> EmbeddedImage<'a> {
+    /// Validate an image (header, length and checksum)
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ImageError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(ImageError::Truncated);
+        }
+        if &bytes[0..4] != IMAGE_MAGIC {
+            return Err(ImageError::InvalidMagic);
+        }
+        let version = read_u32(bytes, 4);
+        if version != IMAGE_VERSION {
+            return Err(ImageError::UnsupportedVersion(version));
+        }
+        let neuron_count = read_u32(bytes, 8) as usize;
+        let synapse_count = read_u32(bytes, 12) as usize;
+        // Counts too large to address cannot be backed by the data either
+        let expected_len = neuron_count
+            .checked_mul(NEURON_RECORD_LEN)
+            .zip(synapse_count.checked_mul(SYNAPSE_RECORD_LEN))
+            .and_then(|(neuron_len, synapse_len)| {
+                HEADER_LEN.checked_add(neuron_len)?.checked_add(synapse_len)
+            })
+            .ok_or(ImageError::Truncated)?;
+        if bytes.len() < expected_len {
+            return Err(ImageError::Truncated);
+        }
+        let bytes = &bytes[..expected_len];
+        if fnv1a(&bytes[HEADER_LEN..]) != read_u32(bytes, 20) {
+            return Err(ImageError::ChecksumMismatch);
+        }
+        Ok(Self {
+            bytes,
+            neuron_count,
+            synapse_count,
+        })
+    }
+
+    /// Neuron slots in the image (including invalid gaps)
+    pub fn neuron_count(&self) -> usize {
+        self.neuron_count
+    }
+
+    /// Synapses in the image
+    pub fn synapse_count(&self) -> usize {
+        self.synapse_count
+    }
+
+    /// Image size in bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the image holds no neurons and no synapses
+    pub fn is_empty(&self) -> bool {
+        self.neuron_count == 0 && self.synapse_count == 0
+    }
+
+    /// Decode neuron `idx` (panics if out of range)
+    pub fn neuron(&self, idx: usize) -> ImageNeuron {
+        assert!(idx < self.neuron_count, "neuron index out of range");
+        let b = &self.bytes[HEADER_LEN + idx * NEURON_RECORD_LEN..];
+        ImageNeuron {
+            threshold: read_f32(b, 0),
+            leak_coefficient: read_f32(b, 4),
+            resting_potential: read_f32(b, 8),
+            membrane_potential: read_f32(b, 12),
+            excitability: read_f32(b, 16),
+            neuron_type: read_u32(b, 20) as i32,
+            cortical_area: read_u32(b, 24),
+            coordinates: (read_u32(b, 28), read_u32(b, 32), read_u32(b, 36)),
+            refractory_period: read_u16(b, 40),
+            refractory_countdown: read_u16(b, 42),
+            valid: b[44] != 0,
+            mp_charge_accumulation: b[45] != 0,
+            threshold_limit: read_f32(b, 48),
+            consecutive_fire_limit: read_u16(b, 52),
+            snooze_period: read_u16(b, 54),
+        }
+    }
+
+    /// Decode synapse `idx` (panics if out of range)
+    pub fn synapse(&self, idx: usize) -> ImageSynapse {
+        assert!(idx < self.synapse_count, "synapse index out of range");
+        let start = HEADER_LEN + self.neuron_count * NEURON_RECORD_LEN;
+        let b = &self.bytes[start + idx * SYNAPSE_RECORD_LEN..];
+        ImageSynapse {
+            source: read_u32(b, 0),
+            target: read_u32(b, 4),
+            weight: b[8],
+            psp: b[9],
+            synapse_type: b[10],
+        }
+    }
+
+    /// Load the image into empty neuron and synapse storage
+    ///
+    /// Capacity and synapse endpoints are checked up front, so nothing is
+    /// written if the image does not fit or a synapse references a neuron
+    /// outside the image.
+    pub fn load_into<N, S>(&self, neurons: &mut N, synapses: &mut S) -> Result<(), ImageError>
+    where
+        N: NeuronStorage,
+        S: SynapseStorage,
+    {
+        if neurons.count() != 0 || synapses.count() != 0 {
+            return Err(ImageError::StorageNotEmpty);
+        }
+        if self.neuron_count > neurons.capacity() {
+            return Err(ImageError::CapacityExceeded {
+                what: "neurons",
+                requested: self.neuron_count,
+                available: neurons.capacity(),
+            });
+        }
+        if self.synapse_count > synapses.capacity() {
+            return Err(ImageError::CapacityExceeded {
+                what: "synapses",
+                requested: self.synapse_count,
+                available: synapses.capacity(),
+            });
+        }
+        for idx in 0..self.synapse_count {
+            let synapse = self.synapse(idx);
+            if synapse.source as usize >= self.neuron_count
+                || synapse.target as usize >= self.neuron_count
+            {
+                return Err(ImageError::DanglingSynapse { index: idx });
+            }
+        }
+
+        for idx in 0..self.neuron_count {
+            let neuron = self.neuron(idx);
+            let (x, y, z) = neuron.coordinates;
+            neurons.add_neuron(
+                N::Value::from_f32(neuron.threshold),
+                N::Value::from_f32(neuron.threshold_limit),
+                neuron.leak_coefficient,
+                N::Value::from_f32(neuron.resting_potential),
+                neuron.neuron_type,
+                neuron.refractory_period,
+                neuron.excitability,
+                neuron.consecutive_fire_limit,
+                neuron.snooze_period,
+                neuron.mp_charge_accumulation,
+                neuron.cortical_area,
+                x,
+                y,
+                z,
+            )?;
+            neurons.membrane_potentials_mut()[idx] = N::Value::from_f32(neuron.membrane_potential);
+            neurons.refractory_countdowns_mut()[idx] = neuron.refractory_countdown;
+            neurons.valid_mask_mut()[idx] = neuron.valid;
+        }
+
+        for idx in 0..self.synapse_count {
+            let synapse = self.synapse(idx);
+            synapses.add_synapse(
+                synapse.source,
+                synapse.target,
+                synapse.weight,
+                synapse.psp,
+                synapse.synapse_type,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Compile a connectome snapshot into an embedded image
+///
+/// Fails if the image would not fit `max_neurons` / `max_synapses` (the
+/// const capacities of the target's `NeuronArray` / `SynapseArray`) or if a
+/// valid synapse references a neuron outside the snapshot.
+#[cfg(feature = "std")]
+pub fn compile_image(
+    snapshot: &feagi_npu_neural::types::connectome::ConnectomeSnapshot,
+    max_neurons: usize,
+    max_synapses: usize,
+) -> Result<std::vec::Vec<u8>, ImageError> {
+    let neurons = &snapshot.neurons;
+    let synapses = &snapshot.synapses;
+
+    // Trailing invalid slots are dropped; interior gaps keep IDs stable
+    let neuron_count = neurons
+        .valid_mask
+        .iter()
+        .rposition(|&valid| valid)
+        .map_or(0, |last| last + 1);
+    let synapse_indices: std::vec::Vec<usize> = (0..synapses.valid_mask.len())
+        .filter(|&i| synapses.valid_mask[i])
+        .collect();
+
+    if neuron_count > max_neurons {
+        return Err(ImageError::CapacityExceeded {
+            what: "neurons",
+            requested: neuron_count,
+            available: max_neurons,
+        });
+    }
+    if synapse_indices.len() > max_synapses {
+        return Err(ImageError::CapacityExceeded {
+            what: "synapses",
+            requested: synapse_indices.len(),
+            available: max_synapses,
+        });
+    }
+
+    let mut out = std::vec::Vec::with_capacity(
+        HEADER_LEN + neuron_count * NEURON_RECORD_LEN + synapse_indices.len() * SYNAPSE_RECORD_LEN,
+    );
+    out.extend_from_slice(IMAGE_MAGIC);
+    out.extend_from_slice(&IMAGE_VERSION.to_le_bytes());
+    out.extend_from_slice(&(neuron_count as u32).to_le_bytes());
+    out.extend_from_slice(&(synapse_indices.len() as u32).to_le_bytes());
+    out.extend_from_slice(&0u32.to_le_bytes()); // reserved
+    out.extend_from_slice(&0u32.to_le_bytes()); // checksum, patched below
+
+    for i in 0..neuron_count {
+        let coordinate = |axis: usize| neurons.coordinates.get(i * 3 + axis).copied();
+        out.extend_from_slice(&neurons.thresholds[i].to_le_bytes());
+        out.extend_from_slice(&neurons.leak_coefficients[i].to_le_bytes());
+        out.extend_from_slice(&neurons.resting_potentials[i].to_le_bytes());
+        out.extend_from_slice(&neurons.membrane_potentials[i].to_le_bytes());
+        out.extend_from_slice(&neurons.excitabilities[i].to_le_bytes());
+        out.extend_from_slice(&neurons.neuron_types[i].to_le_bytes());
+        out.extend_from_slice(&neurons.cortical_areas[i].to_le_bytes());
+        for axis in 0..3 {
+            out.extend_from_slice(&coordinate(axis).unwrap_or(0).to_le_bytes());
+        }
+        out.extend_from_slice(&neurons.refractory_periods[i].to_le_bytes());
+        out.extend_from_slice(&neurons.refractory_countdowns[i].to_le_bytes());
+        out.extend_from_slice(&[
+            neurons.valid_mask[i] as u8,
+            neurons.mp_charge_accumulation[i] as u8,
+            0,
+            0,
+        ]);
+        out.extend_from_slice(&neurons.threshold_limits[i].to_le_bytes());
+        out.extend_from_slice(&neurons.consecutive_fire_limits[i].to_le_bytes());
+        out.extend_from_slice(&neurons.snooze_periods[i].to_le_bytes());
+    }
+
+    for &i in &synapse_indices {
+        let (source, target) = (synapses.source_neurons[i], synapses.target_neurons[i]);
+        if source as usize >= neuron_count || target as usize >= neuron_count {
+            return Err(ImageError::DanglingSynapse { index: i });
+        }
+        out.extend_from_slice(&source.to_le_bytes());
+        out.extend_from_slice(&target.to_le_bytes());
+        out.extend_from_slice(&[
+            synapses.weights[i],
+            synapses.postsynaptic_potentials[i],
+            synapses.types[i],
+            0,
+        ]);
+    }
+
+    let checksum = fnv1a(&out[HEADER_LEN..]);
+    out[20..24].copy_from_slice(&checksum.to_le_bytes());
+    Ok(out)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::embedded_impl::{NeuronArray as EmbeddedNeurons, SynapseArray as EmbeddedSynapses};
+    use feagi_npu_neural::types::connectome::{
+        ConnectomeMetadata, ConnectomeSnapshot, SerializableNeuronArray, SerializableSynapseArray,
+    };
+
+    const NEURONS: usize = 64;
+    const SYNAPSES: usize = 256;
+
+    /// Small recurrent network with a gap (neuron 5 is invalid)
+    fn trained_snapshot() -> ConnectomeSnapshot {
+        let mut neurons = SerializableNeuronArray::new(48);
+        for i in 0..40 {
+            neurons.thresholds[i] = 40.0 + (i % 7) as f32 * 10.0;
+            neurons.leak_coefficients[i] = 0.5 + (i % 3) as f32 * 0.1;
+            neurons.refractory_periods[i] = (i % 3) as u16;
+            neurons.membrane_potentials[i] = (i % 5) as f32 * 4.0;
+            neurons.cortical_areas[i] = (i / 10) as u32;
+            neurons.coordinates[i * 3] = i as u32;
+            neurons.valid_mask[i] = i != 5;
+        }
+        neurons.count = 39;
+
+        let mut synapses = SerializableSynapseArray::default();
+        for i in 0..200u32 {
+            let (source, target) = ((i * 7) % 40, (i * 11 + 3) % 40);
+            synapses.source_neurons.push(source);
+            synapses.target_neurons.push(target);
+            synapses.weights.push((i % 9 + 1) as u8);
+            synapses.postsynaptic_potentials.push(4);
+            synapses.types.push(u8::from(i % 6 == 0));
+            synapses.valid_mask.push(i % 17 != 0);
+        }
+        synapses.count = 200;
+        synapses.capacity = 200;
+
+        ConnectomeSnapshot {
+            version: 2,
+            neurons,
+            synapses,
+            cortical_area_names: ahash::AHashMap::new(),
+            burst_count: 0,
+            power_amount: 1.0,
+            fire_ledger_window: 20,
+            metadata: ConnectomeMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn test_image_round_trips_neuron_parameters() {
+        let mut snapshot = trained_snapshot();
+        snapshot.neurons.threshold_limits[3] = 90.0;
+        snapshot.neurons.consecutive_fire_limits[3] = 2;
+        snapshot.neurons.snooze_periods[3] = 4;
+        snapshot.neurons.mp_charge_accumulation[3] = false;
+        snapshot.neurons.excitabilities[3] = 0.5;
+        let blob = compile_image(&snapshot, NEURONS, SYNAPSES).unwrap();
+
+        let image = EmbeddedImage::parse(&blob).unwrap();
+        assert_eq!(image.neuron_count(), 40);
+        assert_eq!(image.synapse_count(), 188);
+        let mut neurons = EmbeddedNeurons::<f32, NEURONS>::new();
+        let mut synapses = EmbeddedSynapses::<SYNAPSES>::new();
+        image.load_into(&mut neurons, &mut synapses).unwrap();
+
+        let n = &snapshot.neurons;
+        for i in 0..40 {
+            assert_eq!(neurons.thresholds[i], n.thresholds[i]);
+            assert_eq!(neurons.threshold_limits[i], n.threshold_limits[i]);
+            assert_eq!(
+                neurons.consecutive_fire_limits[i],
+                n.consecutive_fire_limits[i]
+            );
+            assert_eq!(neurons.snooze_periods[i], n.snooze_periods[i]);
+            assert_eq!(
+                neurons.mp_charge_accumulation[i],
+                n.mp_charge_accumulation[i]
+            );
+            assert_eq!(neurons.excitabilities[i], n.excitabilities[i]);
+            assert_eq!(neurons.membrane_potentials[i], n.membrane_potentials[i]);
+            assert_eq!(neurons.valid_mask[i], n.valid_mask[i]);
+        }
+        assert_eq!(neurons.threshold_limits[0], f32::MAX);
+        assert_eq!(synapses.count, 188);
+    }
+
+    #[test]
+    fn test_image_validation() {
+        let snapshot = trained_snapshot();
+        assert!(matches!(
+            compile_image(&snapshot, 10, SYNAPSES),
+            Err(ImageError::CapacityExceeded {
+                what: "neurons",
+                ..
+            })
+        ));
+
+        let mut blob = compile_image(&snapshot, NEURONS, SYNAPSES).unwrap();
+        let mut small = EmbeddedSynapses::<16>::new();
+        let mut neurons = EmbeddedNeurons::<f32, NEURONS>::new();
+        assert!(matches!(
+            EmbeddedImage::parse(&blob)
+                .unwrap()
+                .load_into(&mut neurons, &mut small),
+            Err(ImageError::CapacityExceeded {
+                what: "synapses",
+                ..
+            })
+        ));
+        assert_eq!(neurons.count(), 0);
+
+        // A checksum-valid image whose synapse points past the neuron table
+        let mut dangling = blob.clone();
+        let target_at = HEADER_LEN + 40 * NEURON_RECORD_LEN + 4;
+        dangling[target_at..target_at + 4].copy_from_slice(&40u32.to_le_bytes());
+        let checksum = fnv1a(&dangling[HEADER_LEN..]);
+        dangling[20..24].copy_from_slice(&checksum.to_le_bytes());
+        let mut neurons = EmbeddedNeurons::<f32, NEURONS>::new();
+        let mut synapses = EmbeddedSynapses::<SYNAPSES>::new();
+        assert_eq!(
+            EmbeddedImage::parse(&dangling)
+                .unwrap()
+                .load_into(&mut neurons, &mut synapses),
+            Err(ImageError::DanglingSynapse { index: 0 })
+        );
+        assert_eq!(neurons.count(), 0);
+
+        // Counts that overflow the image length are rejected, not wrapped
+        let mut huge = blob.clone();
+        huge[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        huge[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            EmbeddedImage::parse(&huge).unwrap_err(),
+            ImageError::Truncated
+        );
+
+        let last = blob.len() - 1;
+        blob[last] ^= 0xFF;
+        assert_eq!(
+            EmbeddedImage::parse(&blob).unwrap_err(),
+            ImageError::ChecksumMismatch
+        );
+        assert_eq!(
+            EmbeddedImage::parse(&blob[..HEADER_LEN + 1]).unwrap_err(),
+            ImageError::Truncated
+        );
+    }
+}
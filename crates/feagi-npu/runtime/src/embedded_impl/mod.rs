@@ -26,10 +26,12 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod image;
 pub mod neuron_array;
 pub mod runtime;
 pub mod synapse_array;
 
+pub use image::{EmbeddedImage, ImageError};
 pub use neuron_array::NeuronArray;
 pub use runtime::EmbeddedRuntime;
 pub use synapse_array::SynapseArray;
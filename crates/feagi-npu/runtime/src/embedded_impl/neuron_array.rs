@@ -14,7 +14,7 @@
 
 use crate::traits::{NeuronStorage, Result, RuntimeError};
 use feagi_npu_neural::types::NeuralValue;
-use feagi_npu_neural::{is_refractory, update_neuron_lif};
+use feagi_npu_neural::{apply_leak, excitability_random, is_refractory, update_neuron_lif};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 extern crate alloc;
@@ -165,6 +165,7 @@ impl<T: NeuralValue, const N: usize> NeuronArray<T, N> {
     ///
     /// # Example
     /// ```
+    /// # use feagi_npu_runtime::embedded::NeuronArray;
     /// let mut neurons = NeuronArray::<f32, 100>::new();
     /// let inputs = [1.5; 100];
     /// let mut fired = [false; 100];
@@ -210,6 +211,115 @@ impl<T: NeuralValue, const N: usize> NeuronArray<T, N> {
         fired_count
     }
 
+    /// Process burst with the host NPU's firing rules
+    ///
+    /// Unlike [`process_burst`](Self::process_burst), only neurons flagged in
+    /// `candidates` are updated, and the full per-neuron parameters apply:
+    /// firing window (threshold..=threshold limit), consecutive fire limit
+    /// with snooze, probabilistic excitability and non-accumulating membrane
+    /// potentials. Given the same connectome and inputs, the fired neurons
+    /// match `RustNPU` burst for burst.
+    ///
+    /// # Arguments
+    /// * `candidates` - Fire candidate list (see `SynapseArray::propagate_candidates`)
+    /// * `candidate_potentials` - Input currents for each candidate
+    /// * `fired_mask` - Output: which neurons fired (caller-allocated)
+    /// * `burst_count` - Current burst number (seeds excitability)
+    pub fn process_candidates(
+        &mut self,
+        candidates: &[bool; N],
+        candidate_potentials: &[T; N],
+        fired_mask: &mut [bool; N],
+        burst_count: u64,
+    ) -> usize {
+        let mut fired_count = 0;
+
+        for idx in 0..self.count {
+            fired_mask[idx] = false;
+
+            if !self.valid_mask[idx] {
+                continue;
+            }
+
+            // Non-accumulating neurons start every burst from zero
+            if !self.mp_charge_accumulation[idx] {
+                self.membrane_potentials[idx] = T::zero();
+            }
+
+            if !candidates[idx] {
+                continue;
+            }
+
+            // 0 and MAX both mean unlimited
+            let fire_limit = match self.consecutive_fire_limits[idx] {
+                0 => u16::MAX,
+                limit => limit,
+            };
+
+            // Refractory blocks this burst; the fire count resets once a snooze ends
+            if self.refractory_countdowns[idx] > 0 {
+                self.refractory_countdowns[idx] -= 1;
+                if self.refractory_countdowns[idx] == 0
+                    && fire_limit != u16::MAX
+                    && self.consecutive_fire_counts[idx] >= fire_limit
+                {
+                    self.consecutive_fire_counts[idx] = 0;
+                }
+                continue;
+            }
+
+            let potential = self.membrane_potentials[idx].saturating_add(candidate_potentials[idx]);
+            self.membrane_potentials[idx] = potential;
+
+            let threshold_limit = self.threshold_limits[idx];
+            let in_window = potential.ge(self.thresholds[idx])
+                && (!potential.ge(threshold_limit)
+                    || potential.to_f32() == threshold_limit.to_f32());
+
+            if in_window {
+                if self.consecutive_fire_counts[idx] >= fire_limit {
+                    // Blocked by the limit: reset the count, keep the potential
+                    self.consecutive_fire_counts[idx] = 0;
+                    continue;
+                }
+
+                let excitability = self.excitabilities[idx];
+                let fires = if excitability >= 0.999 {
+                    true
+                } else if excitability <= 0.0 {
+                    false
+                } else {
+                    excitability_random(idx as u32, burst_count) < excitability
+                };
+
+                if fires {
+                    self.membrane_potentials[idx] = T::zero();
+                    let count = self.consecutive_fire_counts[idx].saturating_add(1);
+                    self.consecutive_fire_counts[idx] = count;
+                    self.refractory_countdowns[idx] =
+                        if fire_limit != u16::MAX && count >= fire_limit {
+                            self.refractory_periods[idx].saturating_add(self.snooze_periods[idx])
+                        } else {
+                            self.refractory_periods[idx]
+                        };
+                    fired_mask[idx] = true;
+                    fired_count += 1;
+                    continue;
+                }
+            }
+
+            if fire_limit != u16::MAX {
+                self.consecutive_fire_counts[idx] = 0;
+            }
+            apply_leak(
+                &mut self.membrane_potentials[idx],
+                self.leak_coefficients[idx],
+            );
+        }
+
+        fired_count
+    }
+
     /// Get memory footprint in bytes
     pub const fn memory_footprint() -> usize {
         core::mem::size_of::<Self>()
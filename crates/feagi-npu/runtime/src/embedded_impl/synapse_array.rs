@@ -95,12 +95,45 @@ impl<const N: usize> SynapseArray<N> {
             *contrib = 0.0;
         }
 
+        self.for_each_contribution(fired_mask, |target, contribution| {
+            contributions[target] += contribution;
+        });
+    }
+
+    /// Propagate activity and record which neurons became fire candidates
+    ///
+    /// Like [`propagate`](Self::propagate), but also marks every target of a
+    /// fired synapse in `candidates`, even when its contributions cancel out.
+    /// This is the fire candidate list the host NPU builds, and the input
+    /// expected by [`NeuronArray::process_candidates`](super::NeuronArray::process_candidates).
+    pub fn propagate_candidates<const MAX_NEURONS: usize>(
+        &self,
+        fired_mask: &[bool; MAX_NEURONS],
+        contributions: &mut [f32; MAX_NEURONS],
+        candidates: &mut [bool; MAX_NEURONS],
+    ) {
+        for (contrib, candidate) in contributions.iter_mut().zip(candidates.iter_mut()) {
+            *contrib = 0.0;
+            *candidate = false;
+        }
+
+        self.for_each_contribution(fired_mask, |target, contribution| {
+            contributions[target] += contribution;
+            candidates[target] = true;
+        });
+    }
+
+    fn for_each_contribution<const MAX_NEURONS: usize>(
+        &self,
+        fired_mask: &[bool; MAX_NEURONS],
+        mut apply: impl FnMut(usize, f32),
+    ) {
         // Process each synapse
         for idx in 0..self.count {
             let source = self.source_neurons[idx] as usize;
 
-            // Skip if source didn't fire
-            if source >= MAX_NEURONS || !fired_mask[source] {
+            // Skip removed synapses and sources that didn't fire
+            if !self.valid_mask[idx] || source >= MAX_NEURONS || !fired_mask[source] {
                 continue;
             }
 
@@ -122,7 +155,7 @@ impl<const N: usize> SynapseArray<N> {
                 synapse_type,
             );
 
-            contributions[target] += contribution;
+            apply(target, contribution);
         }
     }
 
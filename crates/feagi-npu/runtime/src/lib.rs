@@ -88,7 +88,13 @@ pub mod std_impl;
 
 // Re-export std module contents for convenience (backward compatibility)
 #[cfg(feature = "std")]
-pub use std_impl::{NeuronArray as StdNeuronArray, StdRuntime, SynapseArray as StdSynapseArray};
+pub use std_impl::{
+    CsrRuntime, CsrSynapseArray, NeuronArray as StdNeuronArray, StdRuntime,
+    SynapseArray as StdSynapseArray,
+};
+
+#[cfg(feature = "mmap")]
+pub use std_impl::{MmapRuntime, MmapSynapseArray};
 
 // Embedded implementation (behind "embedded" feature)
 #[cfg(feature = "embedded")]
@@ -0,0 +1,532 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compressed-sparse-row synapse array
+//!
+//! Synapses are kept sorted by source neuron so all outgoing synapses of a
+//! neuron occupy one contiguous range. Propagation looks up each fired neuron's
+//! row with a binary search over the (compressed) row keys and then walks a
+//! slice, so its cost scales with the number of fired synapses rather than
+//! with per-synapse hash lookups.
+//!
+//! Structural changes are buffered instead of re-sorting on every call:
+//!
+//! - **Inserts** are appended after the sorted region and tracked in a small
+//!   per-source index until the next merge.
+//! - **Removals** only clear the valid flag; the slot is reclaimed on merge.
+//!
+//! [`CsrSynapseArray::merge_pending`] folds both buffers back into the sorted
+//! layout. The burst engine calls it through [`SynapseStorage::compact`] on
+//! idle bursts, or once [`CsrSynapseArray::needs_merge`] reports true, and
+//! rebuilds its indices afterwards since merging renumbers synapses.
+//!
+//! Rows are exposed through [`SynapseStorage::outgoing_synapses`], so the
+//! engine does not build a separate source index over this storage.
+
+use crate::traits::{Result, RuntimeError, SynapseStorage};
+use ahash::AHashMap;
+use feagi_npu_neural::synapse::{compute_synaptic_contribution, SynapseType};
+use std::format;
+use std::ops::Range;
+use std::vec::Vec;
+
+/// Pending inserts/removals (as a fraction of merged synapses) that trigger `needs_merge`
+pub const DEFAULT_MERGE_THRESHOLD: f32 = 0.05;
+
+/// Minimum pending changes before `needs_merge` reports true
+const MIN_PENDING_FOR_MERGE: usize = 1024;
+
+/// Synapse array in compressed-sparse-row layout keyed by source neuron
+pub struct CsrSynapseArray {
+    count: usize,
+    source_neurons: Vec<u32>,
+    target_neurons: Vec<u32>,
+    weights: Vec<u8>,
+    postsynaptic_potentials: Vec<u8>,
+    types: Vec<u8>,
+    valid_mask: Vec<bool>,
+
+    /// Sorted distinct source neurons of the merged region
+    row_sources: Vec<u32>,
+    /// `row_offsets[i]..row_offsets[i + 1]` holds the synapses of `row_sources[i]`
+    row_offsets: Vec<usize>,
+    /// Synapses `[0, merged_len)` are sorted; `[merged_len, count)` is the insert buffer
+    merged_len: usize,
+    /// Source → insert-buffer indices
+    pending_index: AHashMap<u32, Vec<usize>>,
+    valid_count: usize,
+    /// The valid mask was handed out mutably; `valid_count` must be recounted
+    mask_dirty: bool,
+    merge_threshold: f32,
+}
+
+impl CsrSynapseArray {
+    /// Create an empty array with initial capacity
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            count: 0,
+            source_neurons: Vec::with_capacity(capacity),
+            target_neurons: Vec::with_capacity(capacity),
+            weights: Vec::with_capacity(capacity),
+            postsynaptic_potentials: Vec::with_capacity(capacity),
+            types: Vec::with_capacity(capacity),
+            valid_mask: Vec::with_capacity(capacity),
+            row_sources: Vec::new(),
+            row_offsets: std::vec![0],
+            merged_len: 0,
+            pending_index: AHashMap::new(),
+            valid_count: 0,
+            mask_dirty: false,
+            merge_threshold: DEFAULT_MERGE_THRESHOLD,
+        }
+    }
+
+    /// Build a merged CSR array from any synapse storage (invalid synapses are dropped)
+    pub fn from_storage<S: SynapseStorage>(storage: &S) -> Self {
+        let mut array = Self::new(storage.valid_count());
+        for (idx, &valid) in storage.valid_mask().iter().enumerate() {
+            if valid {
+                array.push(
+                    storage.source_neurons()[idx],
+                    storage.target_neurons()[idx],
+                    storage.weights()[idx],
+                    storage.postsynaptic_potentials()[idx],
+                    storage.types()[idx],
+                );
+            }
+        }
+        array.merge_pending();
+        array
+    }
+
+    /// Set the pending-change fraction at which `needs_merge` reports true
+    pub fn with_merge_threshold(mut self, threshold: f32) -> Self {
+        self.merge_threshold = threshold;
+        self
+    }
+
+    /// Synapses in the insert buffer
+    pub fn pending_inserts(&self) -> usize {
+        self.count - self.merged_len
+    }
+
+    /// Synapses removed but not yet reclaimed
+    pub fn pending_removals(&self) -> usize {
+        self.count - self.current_valid_count()
+    }
+
+    /// Whether buffered changes are large enough that a merge is worthwhile
+    pub fn needs_merge(&self) -> bool {
+        let pending = self.pending_inserts() + self.pending_removals();
+        pending >= MIN_PENDING_FOR_MERGE
+            && pending as f32 >= self.merged_len as f32 * self.merge_threshold
+    }
+
+    /// Fold the insert and removal buffers into the sorted layout
+    ///
+    /// Synapses keep their relative order within a source row (merged synapses
+    /// first, then buffered inserts in insertion order). Returns the number of
+    /// removed synapses that were reclaimed.
+    ///
+    /// Synapse indices change; rebuild any external index afterwards.
+    pub fn merge_pending(&mut self) -> usize {
+        self.sync_valid_count();
+        let reclaimed = self.count - self.valid_count;
+
+        let mut order: Vec<usize> = (0..self.count).filter(|&i| self.valid_mask[i]).collect();
+        // Stable: preserves merged-before-pending and insertion order per row
+        order.sort_by_key(|&i| self.source_neurons[i]);
+
+        self.source_neurons = order.iter().map(|&i| self.source_neurons[i]).collect();
+        self.target_neurons = order.iter().map(|&i| self.target_neurons[i]).collect();
+        self.weights = order.iter().map(|&i| self.weights[i]).collect();
+        self.postsynaptic_potentials = order
+            .iter()
+            .map(|&i| self.postsynaptic_potentials[i])
+            .collect();
+        self.types = order.iter().map(|&i| self.types[i]).collect();
+        self.valid_mask = std::vec![true; order.len()];
+
+        self.count = order.len();
+        self.merged_len = self.count;
+        self.valid_count = self.count;
+        self.pending_index.clear();
+
+        self.row_sources.clear();
+        self.row_offsets.clear();
+        self.row_offsets.push(0);
+        for (idx, &source) in self.source_neurons.iter().enumerate() {
+            if self.row_sources.last() != Some(&source) {
+                if !self.row_sources.is_empty() {
+                    self.row_offsets.push(idx);
+                }
+                self.row_sources.push(source);
+            }
+        }
+        if !self.row_sources.is_empty() {
+            self.row_offsets.push(self.count);
+        }
+
+        reclaimed
+    }
+
+    /// Merge only if [`needs_merge`](Self::needs_merge) reports true
+    pub fn merge_if_needed(&mut self) -> Option<usize> {
+        self.needs_merge().then(|| self.merge_pending())
+    }
+
+    /// Index range of the merged synapses originating at `source`
+    ///
+    /// Does not include buffered inserts; see [`outgoing`](Self::outgoing).
+    pub fn merged_row(&self, source: u32) -> Range<usize> {
+        match self.row_sources.binary_search(&source) {
+            Ok(row) => self.row_offsets[row]..self.row_offsets[row + 1],
+            Err(_) => 0..0,
+        }
+    }
+
+    /// Indices of all valid synapses originating at `source`
+    pub fn outgoing(&self, source: u32) -> impl Iterator<Item = usize> + '_ {
+        let pending = self
+            .pending_index
+            .get(&source)
+            .map(|indices| indices.as_slice())
+            .unwrap_or(&[]);
+        self.merged_row(source)
+            .chain(pending.iter().copied())
+            .filter(move |&idx| self.valid_mask[idx])
+    }
+
+    /// Event-driven propagation: only the rows of fired neurons are visited
+    ///
+    /// Returns target neuron → accumulated contribution. Contributions are
+    /// summed in fired-neuron order, then row order.
+    pub fn propagate(&self, fired_neurons: &[u32]) -> AHashMap<u32, f32> {
+        let mut result = AHashMap::new();
+        for &source in fired_neurons {
+            for idx in self.outgoing(source) {
+                *result.entry(self.target_neurons[idx]).or_insert(0.0) += self.contribution(idx);
+            }
+        }
+        result
+    }
+
+    fn contribution(&self, idx: usize) -> f32 {
+        let synapse_type = if self.types[idx] == 0 {
+            SynapseType::Excitatory
+        } else {
+            SynapseType::Inhibitory
+        };
+        compute_synaptic_contribution(
+            self.weights[idx],
+            self.postsynaptic_potentials[idx],
+            synapse_type,
+        )
+    }
+
+    /// Valid synapses, recounting if the mask was written directly
+    fn current_valid_count(&self) -> usize {
+        if self.mask_dirty {
+            self.valid_mask[..self.count]
+                .iter()
+                .filter(|&&valid| valid)
+                .count()
+        } else {
+            self.valid_count
+        }
+    }
+
+    /// Re-establish `valid_count` after direct writes through `valid_mask_mut`
+    fn sync_valid_count(&mut self) {
+        if self.mask_dirty {
+            self.valid_count = self.current_valid_count();
+            self.mask_dirty = false;
+        }
+    }
+
+    fn push(&mut self, source: u32, target: u32, weight: u8, psp: u8, synapse_type: u8) -> usize {
+        self.sync_valid_count();
+        let idx = self.count;
+        self.source_neurons.push(source);
+        self.target_neurons.push(target);
+        self.weights.push(weight);
+        self.postsynaptic_potentials.push(psp);
+        self.types.push(synapse_type);
+        self.valid_mask.push(true);
+        self.pending_index.entry(source).or_default().push(idx);
+        self.count += 1;
+        self.valid_count += 1;
+        idx
+    }
+
+    fn invalidate(&mut self, idx: usize) -> bool {
+        self.sync_valid_count();
+        if !self.valid_mask[idx] {
+            return false;
+        }
+        self.valid_mask[idx] = false;
+        self.valid_count -= 1;
+        true
+    }
+
+    fn check_index(&self, idx: usize) -> Result<()> {
+        if idx >= self.count {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Synapse index {} out of bounds (count: {})",
+                idx, self.count
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl SynapseStorage for CsrSynapseArray {
+    fn source_neurons(&self) -> &[u32] {
+        &self.source_neurons[..self.count]
+    }
+
+    fn target_neurons(&self) -> &[u32] {
+        &self.target_neurons[..self.count]
+    }
+
+    fn weights(&self) -> &[u8] {
+        &self.weights[..self.count]
+    }
+
+    fn postsynaptic_potentials(&self) -> &[u8] {
+        &self.postsynaptic_potentials[..self.count]
+    }
+
+    fn types(&self) -> &[u8] {
+        &self.types[..self.count]
+    }
+
+    fn valid_mask(&self) -> &[bool] {
+        &self.valid_mask[..self.count]
+    }
+
+    fn weights_mut(&mut self) -> &mut [u8] {
+        let count = self.count;
+        &mut self.weights[..count]
+    }
+
+    fn postsynaptic_potentials_mut(&mut self) -> &mut [u8] {
+        let count = self.count;
+        &mut self.postsynaptic_potentials[..count]
+    }
+
+    /// Direct mask writes are picked up by the next count or merge; prefer `remove_synapse`
+    fn valid_mask_mut(&mut self) -> &mut [bool] {
+        self.mask_dirty = true;
+        let count = self.count;
+        &mut self.valid_mask[..count]
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn capacity(&self) -> usize {
+        self.source_neurons.capacity()
+    }
+
+    fn add_synapse(
+        &mut self,
+        source: u32,
+        target: u32,
+        weight: u8,
+        psp: u8,
+        synapse_type: u8,
+    ) -> Result<usize> {
+        Ok(self.push(source, target, weight, psp, synapse_type))
+    }
+
+    fn add_synapses_batch(
+        &mut self,
+        sources: &[u32],
+        targets: &[u32],
+        weights: &[u8],
+        psps: &[u8],
+        types: &[u8],
+    ) -> Result<()> {
+        let batch_size = sources.len();
+        if [targets.len(), weights.len(), psps.len(), types.len()]
+            .iter()
+            .any(|&len| len != batch_size)
+        {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Batch arrays must have equal length (sources: {})",
+                batch_size
+            )));
+        }
+        for i in 0..batch_size {
+            self.push(sources[i], targets[i], weights[i], psps[i], types[i]);
+        }
+        Ok(())
+    }
+
+    fn remove_synapse(&mut self, idx: usize) -> Result<()> {
+        self.check_index(idx)?;
+        self.invalidate(idx);
+        Ok(())
+    }
+
+    fn remove_synapses_from_sources(&mut self, source_neurons: &[u32]) -> Result<usize> {
+        let mut removed = 0;
+        for &source in source_neurons {
+            let indices: Vec<usize> = self.outgoing(source).collect();
+            for idx in indices {
+                if self.invalidate(idx) {
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    fn remove_synapses_between(&mut self, source: u32, target: u32) -> Result<usize> {
+        let indices: Vec<usize> = self
+            .outgoing(source)
+            .filter(|&idx| self.target_neurons[idx] == target)
+            .collect();
+        let mut removed = 0;
+        for idx in indices {
+            if self.invalidate(idx) {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn update_weight(&mut self, idx: usize, new_weight: u8) -> Result<()> {
+        self.check_index(idx)?;
+        if !self.valid_mask[idx] {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Synapse {} is not valid",
+                idx
+            )));
+        }
+        self.weights[idx] = new_weight;
+        Ok(())
+    }
+
+    fn valid_count(&self) -> usize {
+        self.current_valid_count()
+    }
+
+    /// Rows are looked up by binary search, so no in-RAM index is needed
+    fn indexes_sources(&self) -> bool {
+        true
+    }
+
+    fn outgoing_synapses(&self, source: u32) -> Vec<usize> {
+        self.outgoing(source).collect()
+    }
+
+    fn wants_compaction(&self, idle: bool) -> bool {
+        if idle {
+            self.pending_inserts() + self.pending_removals() > 0
+        } else {
+            self.needs_merge()
+        }
+    }
+
+    fn compact(&mut self) -> bool {
+        let pending = self.pending_inserts() + self.pending_removals();
+        self.merge_pending();
+        pending > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std_impl::SynapseArray;
+
+    #[test]
+    fn test_rows_and_buffers() {
+        let mut array = CsrSynapseArray::new(8);
+        array.add_synapse(5, 50, 10, 1, 0).unwrap();
+        array.add_synapse(1, 10, 20, 1, 0).unwrap();
+        array.add_synapse(5, 51, 30, 1, 1).unwrap();
+        array.merge_pending();
+
+        assert_eq!(array.source_neurons(), &[1, 5, 5]);
+        assert_eq!(array.merged_row(5), 1..3);
+        assert_eq!(array.merged_row(2), 0..0);
+
+        // Buffered insert is visible before merge
+        let idx = array.add_synapse(1, 11, 40, 1, 0).unwrap();
+        assert_eq!(array.pending_inserts(), 1);
+        assert_eq!(array.outgoing(1).collect::<Vec<_>>(), std::vec![0, idx]);
+
+        // Removal is visible before merge and reclaimed by it
+        assert_eq!(array.remove_synapses_between(5, 50).unwrap(), 1);
+        assert_eq!(array.valid_count(), 3);
+        assert_eq!(array.merge_pending(), 1);
+        assert_eq!(array.count(), 3);
+        assert_eq!(array.target_neurons(), &[10, 11, 51]);
+        assert_eq!(array.pending_inserts() + array.pending_removals(), 0);
+    }
+
+    #[test]
+    fn test_valid_mask_writes_keep_count_in_sync() {
+        let mut array = CsrSynapseArray::new(8);
+        for target in 0..4 {
+            array.add_synapse(2, target, 10, 1, 0).unwrap();
+        }
+        array.merge_pending();
+
+        array.valid_mask_mut()[1] = false;
+        assert_eq!(array.valid_count(), 3);
+        assert_eq!(array.pending_removals(), 1);
+        assert_eq!(array.outgoing_synapses(2), std::vec![0, 2, 3]);
+        assert!(array.wants_compaction(true));
+
+        // Removal after a direct write does not double count
+        assert_eq!(array.remove_synapses_between(2, 1).unwrap(), 0);
+        assert_eq!(array.remove_synapses_between(2, 3).unwrap(), 1);
+        assert_eq!(array.valid_count(), 2);
+
+        assert!(array.compact());
+        assert_eq!(array.count(), 2);
+        assert_eq!(array.target_neurons(), &[0, 2]);
+        assert!(!array.compact());
+    }
+
+    #[test]
+    fn test_propagation_matches_synapse_array() {
+        let mut reference = SynapseArray::new(64);
+        let mut csr = CsrSynapseArray::new(64);
+        for i in 0..64u32 {
+            let (source, target, weight) = (i % 7, (i * 13) % 20, (i * 3) as u8);
+            let synapse_type = if i % 5 == 0 {
+                SynapseType::Inhibitory
+            } else {
+                SynapseType::Excitatory
+            };
+            reference.add_synapse_simple(source, target, weight, 9, synapse_type);
+            csr.add_synapse(source, target, weight, 9, synapse_type as u8)
+                .unwrap();
+            if i == 31 {
+                csr.merge_pending();
+            }
+        }
+        reference.remove_synapses_from_sources(&[3]).unwrap();
+        csr.remove_synapses_from_sources(&[3]).unwrap();
+
+        let fired = [0, 3, 4, 6];
+        let mut expected: Vec<(u32, f32)> = reference
+            .propagate_parallel(&[0, 4, 6])
+            .into_iter()
+            .collect();
+        let mut actual: Vec<(u32, f32)> = csr.propagate(&fired).into_iter().collect();
+        expected.sort_by_key(|&(target, _)| target);
+        actual.sort_by_key(|&(target, _)| target);
+        assert_eq!(actual, expected);
+
+        assert!(!csr.needs_merge());
+        let rebuilt = CsrSynapseArray::from_storage(&reference);
+        assert_eq!(rebuilt.valid_count(), csr.valid_count());
+    }
+}
@@ -0,0 +1,1036 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Memory-mapped synapse array
+//!
+//! Serves synapses straight from a *synapse image* on disk instead of loading
+//! them into RAM, so connectomes larger than physical memory can still run.
+//! Only the pages that propagation actually touches are faulted in, and the
+//! OS is free to drop clean pages again under memory pressure.
+//!
+//! ## Image layout
+//!
+//! All integers are little-endian. Sections start on `align` boundaries
+//! (relative to the start of the image), so `u32`/`u64` columns can be
+//! borrowed directly from the mapping. Writers use the [`page_size`] of the
+//! machine as `align`, so every section starts on its own page:
+//!
+//! ```text
+//! [Header, IMAGE_HEADER_LEN bytes]
+//!   magic "FSYN" | version u32 | count u64 | capacity u64 | row_count u64 | align u64
+//!   offsets u64 × 8 (sources, targets, weights, psps, types, valid, row_sources, row_offsets)
+//! [sources u32 × capacity] [targets u32 × capacity]
+//! [weights u8 × capacity] [psps u8 × capacity] [types u8 × capacity] [valid u8 × capacity]
+//! [row_sources u32 × row_count] [row_offsets u64 × (row_count + 1)]
+//! ```
+//!
+//! Synapses `[0, count)` are sorted by source neuron (CSR layout, see
+//! [`CsrSynapseArray`](super::CsrSynapseArray)); slots `[count, capacity)` are
+//! reserved headroom for synapses added at runtime.
+//!
+//! ## Mutation
+//!
+//! The file is mapped copy-on-write: weight updates, removals and inserts touch
+//! private copies of the affected pages and are never written back. Persist a
+//! modified connectome by saving a new snapshot.
+//!
+//! ## Hot rows
+//!
+//! Propagation keeps the decoded `(target, contribution)` list of recently
+//! fired source neurons in a bounded LRU cache, so the hot part of the network
+//! is served from RAM while the cold remainder stays on disk.
+
+use crate::traits::{Result, RuntimeError, SynapseStorage};
+use ahash::AHashMap;
+use feagi_npu_neural::synapse::{compute_synaptic_contribution, SynapseType};
+use memmap2::{MmapMut, MmapOptions};
+use std::fs::File;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::vec::Vec;
+use std::{format, mem, string::ToString};
+
+/// Synapse image magic bytes
+pub const IMAGE_MAGIC: &[u8; 4] = b"FSYN";
+
+/// Synapse image format version
+pub const IMAGE_VERSION: u32 = 1;
+
+/// Required alignment of the image start within its file
+pub const IMAGE_OFFSET_ALIGN: u64 = 8;
+
+/// Size of the fixed image header
+pub const IMAGE_HEADER_LEN: usize = 4 + 4 + 8 + 8 + 8 + 8 + 8 * SECTION_COUNT;
+
+/// Default byte budget of the hot-row cache (64 MiB)
+pub const DEFAULT_HOT_CACHE_BYTES: usize = 64 * 1024 * 1024;
+
+/// Bookkeeping bytes charged per cached row on top of its entries
+const CACHED_ROW_OVERHEAD: usize = 48;
+
+const SECTION_COUNT: usize = 8;
+const SOURCES: usize = 0;
+const TARGETS: usize = 1;
+const WEIGHTS: usize = 2;
+const PSPS: usize = 3;
+const TYPES: usize = 4;
+const VALID: usize = 5;
+const ROW_SOURCES: usize = 6;
+const ROW_OFFSETS: usize = 7;
+
+/// Memory page size of this machine
+///
+/// Queried once from the OS. Windows reports the 64 KiB allocation
+/// granularity instead, since that is what file mappings are aligned to.
+pub fn page_size() -> u64 {
+    static PAGE_SIZE: OnceLock<u64> = OnceLock::new();
+    *PAGE_SIZE.get_or_init(query_page_size)
+}
+
+#[cfg(unix)]
+fn query_page_size() -> u64 {
+    // SAFETY: sysconf has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 {
+        size as u64
+    } else {
+        4096
+    }
+}
+
+#[cfg(not(unix))]
+fn query_page_size() -> u64 {
+    64 * 1024
+}
+
+/// Round `value` up to a multiple of `align`
+pub fn align_up(value: u64, align: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
+/// Byte length of each section for the given sizes (None on overflow)
+fn section_lens(capacity: u64, row_count: u64) -> Option<[u64; SECTION_COUNT]> {
+    let words = capacity.checked_mul(4)?;
+    Some([
+        words,
+        words,
+        capacity,
+        capacity,
+        capacity,
+        capacity,
+        row_count.checked_mul(4)?,
+        row_count.checked_add(1)?.checked_mul(8)?,
+    ])
+}
+
+/// Section offsets (relative to the image start) for the given sizes (None on overflow)
+fn section_offsets(capacity: u64, row_count: u64, align: u64) -> Option<[u64; SECTION_COUNT]> {
+    let lens = section_lens(capacity, row_count)?;
+    let mut offsets = [0u64; SECTION_COUNT];
+    let mut cursor = align_up(IMAGE_HEADER_LEN as u64, align);
+    for (offset, len) in offsets.iter_mut().zip(lens) {
+        *offset = cursor;
+        cursor = align_up(cursor.checked_add(len)?, align);
+    }
+    Some(offsets)
+}
+
+/// Borrowed synapse columns to write into an image
+///
+/// All slices must have the same length.
+#[derive(Debug, Clone, Copy)]
+pub struct SynapseColumns<'a> {
+    /// Source neuron IDs
+    pub source_neurons: &'a [u32],
+    /// Target neuron IDs
+    pub target_neurons: &'a [u32],
+    /// Synaptic weights
+    pub weights: &'a [u8],
+    /// Postsynaptic potentials
+    pub postsynaptic_potentials: &'a [u8],
+    /// Synapse types (0=excitatory, 1=inhibitory)
+    pub types: &'a [u8],
+    /// Valid synapse mask
+    pub valid_mask: &'a [bool],
+}
+
+impl<'a> SynapseColumns<'a> {
+    /// Borrow the columns of any synapse storage
+    pub fn from_storage<S: SynapseStorage>(storage: &'a S) -> Self {
+        Self {
+            source_neurons: storage.source_neurons(),
+            target_neurons: storage.target_neurons(),
+            weights: storage.weights(),
+            postsynaptic_potentials: storage.postsynaptic_potentials(),
+            types: storage.types(),
+            valid_mask: storage.valid_mask(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.source_neurons.len()
+    }
+}
+
+/// Write a synapse image
+///
+/// Invalid synapses are dropped and the rest are sorted by source neuron
+/// (stable), so synapse indices are renumbered. The image reserves
+/// `max(capacity, valid synapses)` slots. Sections are aligned to the
+/// [`page_size`]; the writer should be positioned on a page boundary of the
+/// target file so that they are page-aligned in the file too (an
+/// [`IMAGE_OFFSET_ALIGN`] boundary is enough for the image to be mappable).
+///
+/// Returns the number of bytes written.
+pub fn write_synapse_image<W: Write>(
+    writer: &mut W,
+    columns: &SynapseColumns<'_>,
+    capacity: usize,
+) -> io::Result<u64> {
+    let len = columns.len();
+    if [
+        columns.target_neurons.len(),
+        columns.weights.len(),
+        columns.postsynaptic_potentials.len(),
+        columns.types.len(),
+        columns.valid_mask.len(),
+    ]
+    .iter()
+    .any(|&other| other != len)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "synapse columns must have equal length",
+        ));
+    }
+
+    let mut order: Vec<usize> = (0..len).filter(|&i| columns.valid_mask[i]).collect();
+    order.sort_by_key(|&i| columns.source_neurons[i]);
+    let count = order.len();
+    let capacity = capacity.max(count);
+
+    let mut row_sources: Vec<u32> = Vec::new();
+    let mut row_offsets: Vec<u64> = std::vec![0];
+    for (position, &idx) in order.iter().enumerate() {
+        let source = columns.source_neurons[idx];
+        if row_sources.last() != Some(&source) {
+            if !row_sources.is_empty() {
+                row_offsets.push(position as u64);
+            }
+            row_sources.push(source);
+        }
+    }
+    if !row_sources.is_empty() {
+        row_offsets.push(count as u64);
+    }
+
+    let align = page_size();
+    let offsets = section_offsets(capacity as u64, row_sources.len() as u64, align)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "synapse image too large"))?;
+    let padding = capacity - count;
+
+    let mut header = Vec::with_capacity(IMAGE_HEADER_LEN);
+    header.extend_from_slice(IMAGE_MAGIC);
+    header.extend_from_slice(&IMAGE_VERSION.to_le_bytes());
+    header.extend_from_slice(&(count as u64).to_le_bytes());
+    header.extend_from_slice(&(capacity as u64).to_le_bytes());
+    header.extend_from_slice(&(row_sources.len() as u64).to_le_bytes());
+    header.extend_from_slice(&align.to_le_bytes());
+    for offset in offsets {
+        header.extend_from_slice(&offset.to_le_bytes());
+    }
+
+    let mut out = SectionWriter {
+        writer,
+        written: 0,
+        buffer: Vec::with_capacity(64 * 1024),
+    };
+    out.write(&header)?;
+
+    out.pad_to(offsets[SOURCES])?;
+    for &idx in &order {
+        out.write(&columns.source_neurons[idx].to_le_bytes())?;
+    }
+    out.zeros(padding * 4)?;
+
+    out.pad_to(offsets[TARGETS])?;
+    for &idx in &order {
+        out.write(&columns.target_neurons[idx].to_le_bytes())?;
+    }
+    out.zeros(padding * 4)?;
+
+    for (section, column) in [
+        (WEIGHTS, columns.weights),
+        (PSPS, columns.postsynaptic_potentials),
+        (TYPES, columns.types),
+    ] {
+        out.pad_to(offsets[section])?;
+        for &idx in &order {
+            out.write(&[column[idx]])?;
+        }
+        out.zeros(padding)?;
+    }
+
+    out.pad_to(offsets[VALID])?;
+    out.fill(1, count)?;
+    out.zeros(padding)?;
+
+    out.pad_to(offsets[ROW_SOURCES])?;
+    for source in &row_sources {
+        out.write(&source.to_le_bytes())?;
+    }
+
+    out.pad_to(offsets[ROW_OFFSETS])?;
+    for offset in &row_offsets {
+        out.write(&offset.to_le_bytes())?;
+    }
+
+    out.finish()
+}
+
+/// Buffered writer that tracks the image-relative position
+struct SectionWriter<'w, W: Write> {
+    writer: &'w mut W,
+    written: u64,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> SectionWriter<'_, W> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.buffer.extend_from_slice(bytes);
+        self.written += bytes.len() as u64;
+        if self.buffer.len() >= 64 * 1024 {
+            self.writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    fn fill(&mut self, byte: u8, len: usize) -> io::Result<()> {
+        let chunk = [byte; 4096];
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = remaining.min(chunk.len());
+            self.write(&chunk[..n])?;
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    fn zeros(&mut self, len: usize) -> io::Result<()> {
+        self.fill(0, len)
+    }
+
+    fn pad_to(&mut self, offset: u64) -> io::Result<()> {
+        self.zeros((offset - self.written) as usize)
+    }
+
+    fn finish(mut self) -> io::Result<u64> {
+        self.writer.write_all(&self.buffer)?;
+        self.buffer.clear();
+        Ok(self.written)
+    }
+}
+
+/// Hot-row cache statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HotCacheStats {
+    /// Rows served from the cache
+    pub hits: u64,
+    /// Rows decoded from the mapping
+    pub misses: u64,
+    /// Rows currently cached
+    pub cached_rows: usize,
+    /// Bytes currently charged against the budget
+    pub cached_bytes: usize,
+}
+
+struct CachedRow {
+    entries: Vec<(u32, f32)>,
+    last_used: u64,
+}
+
+/// Bounded LRU cache of decoded source rows
+struct HotRowCache {
+    budget_bytes: usize,
+    rows: AHashMap<u32, CachedRow>,
+    tick: u64,
+    stats: HotCacheStats,
+}
+
+impl HotRowCache {
+    fn new(budget_bytes: usize) -> Self {
+        Self {
+            budget_bytes,
+            rows: AHashMap::new(),
+            tick: 0,
+            stats: HotCacheStats::default(),
+        }
+    }
+
+    fn row_bytes(entries: usize) -> usize {
+        entries * mem::size_of::<(u32, f32)>() + CACHED_ROW_OVERHEAD
+    }
+
+    fn get(&mut self, source: u32) -> Option<&[(u32, f32)]> {
+        self.tick += 1;
+        let tick = self.tick;
+        match self.rows.get_mut(&source) {
+            Some(row) => {
+                row.last_used = tick;
+                self.stats.hits += 1;
+                Some(&row.entries)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, source: u32, entries: Vec<(u32, f32)>) {
+        let bytes = Self::row_bytes(entries.len());
+        if bytes > self.budget_bytes {
+            return;
+        }
+        if self.stats.cached_bytes + bytes > self.budget_bytes {
+            // Evict the least recently used rows down to 3/4 of the budget so
+            // eviction cost is amortised over many inserts
+            self.evict_to((self.budget_bytes / 4 * 3).min(self.budget_bytes - bytes));
+        }
+        self.stats.cached_bytes += bytes;
+        let row = CachedRow {
+            entries,
+            last_used: self.tick,
+        };
+        if let Some(old) = self.rows.insert(source, row) {
+            self.stats.cached_bytes -= Self::row_bytes(old.entries.len());
+        }
+        self.stats.cached_rows = self.rows.len();
+    }
+
+    fn evict_to(&mut self, target_bytes: usize) {
+        let mut by_age: Vec<(u64, u32)> = self
+            .rows
+            .iter()
+            .map(|(&source, row)| (row.last_used, source))
+            .collect();
+        by_age.sort_unstable();
+        for (_, source) in by_age {
+            if self.stats.cached_bytes <= target_bytes {
+                break;
+            }
+            self.invalidate(source);
+        }
+    }
+
+    fn invalidate(&mut self, source: u32) {
+        if let Some(row) = self.rows.remove(&source) {
+            self.stats.cached_bytes -= Self::row_bytes(row.entries.len());
+            self.stats.cached_rows = self.rows.len();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.rows.clear();
+        self.stats.cached_bytes = 0;
+        self.stats.cached_rows = 0;
+    }
+}
+
+/// Synapse storage backed by a copy-on-write memory map of a synapse image
+pub struct MmapSynapseArray {
+    map: MmapMut,
+    offsets: [usize; SECTION_COUNT],
+    count: usize,
+    capacity: usize,
+    row_count: usize,
+    /// Synapses `[0, merged_len)` come from the image; the rest were added at runtime
+    merged_len: usize,
+    /// Source → indices of synapses added at runtime
+    pending_index: AHashMap<u32, Vec<usize>>,
+    valid_count: usize,
+    hot_rows: Mutex<HotRowCache>,
+}
+
+impl MmapSynapseArray {
+    /// Map a standalone synapse image file
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_at(path, 0)
+    }
+
+    /// Map a synapse image embedded at `offset` bytes into a file
+    ///
+    /// `offset` must be a multiple of [`IMAGE_OFFSET_ALIGN`]. Opening reads the
+    /// header, row table and valid mask; all other sections are faulted in
+    /// on demand.
+    #[allow(clippy::manual_is_multiple_of)] // u64::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+    pub fn open_at<P: AsRef<Path>>(path: P, offset: u64) -> Result<Self> {
+        if cfg!(target_endian = "big") {
+            return Err(RuntimeError::PlatformNotSupported(
+                "synapse images are little-endian".to_string(),
+            ));
+        }
+        if offset % IMAGE_OFFSET_ALIGN != 0 {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Synapse image offset {} is not aligned to {} bytes",
+                offset, IMAGE_OFFSET_ALIGN
+            )));
+        }
+
+        let file = File::open(path.as_ref())
+            .map_err(|e| RuntimeError::StorageError(format!("Failed to open image: {}", e)))?;
+        // SAFETY: the mapping is private (copy-on-write), so our writes never
+        // reach the file. As with any file mapping, the file must not be
+        // truncated or modified by other processes while it is mapped.
+        let map = unsafe { MmapOptions::new().offset(offset).map_copy(&file) }
+            .map_err(|e| RuntimeError::StorageError(format!("Failed to map image: {}", e)))?;
+
+        let mut array = Self::from_map(map)?;
+        array.valid_count = array.count_valid()?;
+        Ok(array)
+    }
+
+    #[allow(clippy::manual_is_multiple_of)] // u64::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+    fn from_map(map: MmapMut) -> Result<Self> {
+        let corrupt =
+            |what: &str| RuntimeError::StorageError(format!("Corrupt synapse image: {}", what));
+        if map.len() < IMAGE_HEADER_LEN {
+            return Err(corrupt("truncated header"));
+        }
+        if &map[0..4] != IMAGE_MAGIC {
+            return Err(corrupt("bad magic"));
+        }
+        let read_u64 = |at: usize| u64::from_le_bytes(map[at..at + 8].try_into().unwrap());
+        let version = u32::from_le_bytes(map[4..8].try_into().unwrap());
+        if version != IMAGE_VERSION {
+            return Err(RuntimeError::StorageError(format!(
+                "Unsupported synapse image version {} (expected {})",
+                version, IMAGE_VERSION
+            )));
+        }
+        let count = read_u64(8);
+        let capacity = read_u64(16);
+        let row_count = read_u64(24);
+        let align = read_u64(32);
+        if count > capacity {
+            return Err(corrupt("count exceeds capacity"));
+        }
+        if !align.is_power_of_two() || align < IMAGE_OFFSET_ALIGN {
+            return Err(corrupt("invalid section alignment"));
+        }
+
+        // Sizes come from an untrusted header: every product and end offset is
+        // checked, so a crafted header cannot wrap past the bounds check below.
+        let lens =
+            section_lens(capacity, row_count).ok_or_else(|| corrupt("section size overflow"))?;
+        let mut offsets = [0usize; SECTION_COUNT];
+        for (section, offset) in offsets.iter_mut().enumerate() {
+            let start = read_u64(40 + section * 8);
+            let end = start
+                .checked_add(lens[section])
+                .ok_or_else(|| corrupt("section out of bounds"))?;
+            if start % align != 0 || end > map.len() as u64 {
+                return Err(corrupt("section out of bounds"));
+            }
+            *offset = start as usize;
+        }
+        // Every section ends inside the mapping, so all sizes fit in usize
+        let to_usize = |value: u64| usize::try_from(value).map_err(|_| corrupt("size overflow"));
+        let (count, capacity, row_count) =
+            (to_usize(count)?, to_usize(capacity)?, to_usize(row_count)?);
+
+        let array = Self {
+            map,
+            offsets,
+            count,
+            capacity,
+            row_count,
+            merged_len: count,
+            pending_index: AHashMap::new(),
+            valid_count: 0,
+            hot_rows: Mutex::new(HotRowCache::new(DEFAULT_HOT_CACHE_BYTES)),
+        };
+
+        let row_offsets = array.row_offsets();
+        if row_offsets[0] != 0
+            || row_offsets[row_count] != count as u64
+            || row_offsets.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(corrupt("invalid row table"));
+        }
+        Ok(array)
+    }
+
+    /// Count valid synapses, rejecting mask bytes other than 0/1
+    fn count_valid(&self) -> Result<usize> {
+        let start = self.offsets[VALID];
+        let mask = &self.map[start..start + self.capacity];
+        if mask.iter().any(|&byte| byte > 1) {
+            return Err(RuntimeError::StorageError(
+                "Corrupt synapse image: invalid valid-mask byte".to_string(),
+            ));
+        }
+        Ok(mask[..self.count].iter().filter(|&&byte| byte == 1).count())
+    }
+
+    /// Set the hot-row cache budget in bytes (0 disables caching)
+    pub fn with_hot_cache_budget(self, budget_bytes: usize) -> Self {
+        *self.hot_rows.lock().unwrap() = HotRowCache::new(budget_bytes);
+        self
+    }
+
+    /// Hot-row cache statistics
+    pub fn hot_cache_stats(&self) -> HotCacheStats {
+        self.hot_rows.lock().unwrap().stats
+    }
+
+    /// Synapses added since the image was opened
+    pub fn pending_inserts(&self) -> usize {
+        self.count - self.merged_len
+    }
+
+    /// Index range of the image synapses originating at `source`
+    pub fn merged_row(&self, source: u32) -> Range<usize> {
+        match self.row_sources().binary_search(&source) {
+            Ok(row) => {
+                let offsets = self.row_offsets();
+                offsets[row] as usize..offsets[row + 1] as usize
+            }
+            Err(_) => 0..0,
+        }
+    }
+
+    /// Indices of all valid synapses originating at `source`
+    pub fn outgoing(&self, source: u32) -> impl Iterator<Item = usize> + '_ {
+        let pending = self
+            .pending_index
+            .get(&source)
+            .map(|indices| indices.as_slice())
+            .unwrap_or(&[]);
+        let valid = self.valid_mask();
+        self.merged_row(source)
+            .chain(pending.iter().copied())
+            .filter(move |&idx| valid[idx])
+    }
+
+    /// Event-driven propagation: only the rows of fired neurons are visited
+    ///
+    /// Returns target neuron → accumulated contribution, summed in the same
+    /// order as [`CsrSynapseArray::propagate`](super::CsrSynapseArray::propagate).
+    /// Image rows are served from the hot-row cache when possible.
+    pub fn propagate(&self, fired_neurons: &[u32]) -> AHashMap<u32, f32> {
+        let mut result = AHashMap::new();
+        let mut hot_rows = self.hot_rows.lock().unwrap();
+        for &source in fired_neurons {
+            let row = self.merged_row(source);
+            if !row.is_empty() {
+                if let Some(entries) = hot_rows.get(source) {
+                    for &(target, contribution) in entries {
+                        *result.entry(target).or_insert(0.0) += contribution;
+                    }
+                } else {
+                    let valid = self.valid_mask();
+                    let targets = self.target_neurons();
+                    let entries: Vec<(u32, f32)> = row
+                        .filter(|&idx| valid[idx])
+                        .map(|idx| (targets[idx], self.contribution(idx)))
+                        .collect();
+                    for &(target, contribution) in &entries {
+                        *result.entry(target).or_insert(0.0) += contribution;
+                    }
+                    hot_rows.insert(source, entries);
+                }
+            }
+            if let Some(pending) = self.pending_index.get(&source) {
+                for &idx in pending {
+                    if self.valid_mask()[idx] {
+                        *result.entry(self.target_neurons()[idx]).or_insert(0.0) +=
+                            self.contribution(idx);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn contribution(&self, idx: usize) -> f32 {
+        let synapse_type = if self.types()[idx] == 0 {
+            SynapseType::Excitatory
+        } else {
+            SynapseType::Inhibitory
+        };
+        compute_synaptic_contribution(
+            self.weights()[idx],
+            self.postsynaptic_potentials()[idx],
+            synapse_type,
+        )
+    }
+
+    fn section<T>(&self, section: usize, len: usize) -> &[T] {
+        // SAFETY: `from_map` checked that the section lies inside the mapping
+        // and starts on a boundary of at least IMAGE_OFFSET_ALIGN bytes. The
+        // image itself starts IMAGE_OFFSET_ALIGN-aligned (memmap2 only shifts
+        // the pointer within a page), so the section is aligned for T. Every bit pattern is a valid u32/u64/u8, and the
+        // valid-mask section is checked to hold only 0/1 before it is exposed
+        // as bool.
+        unsafe {
+            std::slice::from_raw_parts(
+                self.map.as_ptr().add(self.offsets[section]) as *const T,
+                len,
+            )
+        }
+    }
+
+    fn section_mut<T>(&mut self, section: usize, len: usize) -> &mut [T] {
+        // SAFETY: see `section`; `&mut self` guarantees exclusive access.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.map.as_mut_ptr().add(self.offsets[section]) as *mut T,
+                len,
+            )
+        }
+    }
+
+    fn row_sources(&self) -> &[u32] {
+        self.section(ROW_SOURCES, self.row_count)
+    }
+
+    fn row_offsets(&self) -> &[u64] {
+        self.section(ROW_OFFSETS, self.row_count + 1)
+    }
+
+    /// Drop the cached row of the synapse at `idx` (image synapses only)
+    fn invalidate_row_of(&mut self, idx: usize) {
+        if idx < self.merged_len {
+            let source = self.source_neurons()[idx];
+            self.hot_rows.get_mut().unwrap().invalidate(source);
+        }
+    }
+
+    fn invalidate(&mut self, idx: usize) -> bool {
+        if !self.valid_mask()[idx] {
+            return false;
+        }
+        self.invalidate_row_of(idx);
+        let count = self.count;
+        self.section_mut::<bool>(VALID, count)[idx] = false;
+        self.valid_count -= 1;
+        true
+    }
+
+    fn check_index(&self, idx: usize) -> Result<()> {
+        if idx >= self.count {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Synapse index {} out of bounds (count: {})",
+                idx, self.count
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl SynapseStorage for MmapSynapseArray {
+    fn source_neurons(&self) -> &[u32] {
+        self.section(SOURCES, self.count)
+    }
+
+    fn target_neurons(&self) -> &[u32] {
+        self.section(TARGETS, self.count)
+    }
+
+    fn weights(&self) -> &[u8] {
+        self.section(WEIGHTS, self.count)
+    }
+
+    fn postsynaptic_potentials(&self) -> &[u8] {
+        self.section(PSPS, self.count)
+    }
+
+    fn types(&self) -> &[u8] {
+        self.section(TYPES, self.count)
+    }
+
+    fn valid_mask(&self) -> &[bool] {
+        self.section(VALID, self.count)
+    }
+
+    /// Clears the hot-row cache, since any weight may change
+    fn weights_mut(&mut self) -> &mut [u8] {
+        self.hot_rows.get_mut().unwrap().clear();
+        let count = self.count;
+        self.section_mut(WEIGHTS, count)
+    }
+
+    /// Clears the hot-row cache, since any PSP may change
+    fn postsynaptic_potentials_mut(&mut self) -> &mut [u8] {
+        self.hot_rows.get_mut().unwrap().clear();
+        let count = self.count;
+        self.section_mut(PSPS, count)
+    }
+
+    /// Direct mask access bypasses removal bookkeeping; prefer `remove_synapse`
+    fn valid_mask_mut(&mut self) -> &mut [bool] {
+        self.hot_rows.get_mut().unwrap().clear();
+        let count = self.count;
+        self.section_mut(VALID, count)
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn add_synapse(
+        &mut self,
+        source: u32,
+        target: u32,
+        weight: u8,
+        psp: u8,
+        synapse_type: u8,
+    ) -> Result<usize> {
+        if self.count >= self.capacity {
+            return Err(RuntimeError::CapacityExceeded {
+                requested: self.count + 1,
+                available: self.capacity,
+            });
+        }
+        let idx = self.count;
+        let capacity = self.capacity;
+        self.section_mut::<u32>(SOURCES, capacity)[idx] = source;
+        self.section_mut::<u32>(TARGETS, capacity)[idx] = target;
+        self.section_mut::<u8>(WEIGHTS, capacity)[idx] = weight;
+        self.section_mut::<u8>(PSPS, capacity)[idx] = psp;
+        self.section_mut::<u8>(TYPES, capacity)[idx] = synapse_type;
+        self.section_mut::<bool>(VALID, capacity)[idx] = true;
+        self.pending_index.entry(source).or_default().push(idx);
+        self.count += 1;
+        self.valid_count += 1;
+        Ok(idx)
+    }
+
+    fn add_synapses_batch(
+        &mut self,
+        sources: &[u32],
+        targets: &[u32],
+        weights: &[u8],
+        psps: &[u8],
+        types: &[u8],
+    ) -> Result<()> {
+        let batch_size = sources.len();
+        if [targets.len(), weights.len(), psps.len(), types.len()]
+            .iter()
+            .any(|&len| len != batch_size)
+        {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Batch arrays must have equal length (sources: {})",
+                batch_size
+            )));
+        }
+        if self.count + batch_size > self.capacity {
+            return Err(RuntimeError::CapacityExceeded {
+                requested: self.count + batch_size,
+                available: self.capacity,
+            });
+        }
+        for i in 0..batch_size {
+            self.add_synapse(sources[i], targets[i], weights[i], psps[i], types[i])?;
+        }
+        Ok(())
+    }
+
+    fn remove_synapse(&mut self, idx: usize) -> Result<()> {
+        self.check_index(idx)?;
+        self.invalidate(idx);
+        Ok(())
+    }
+
+    fn remove_synapses_from_sources(&mut self, source_neurons: &[u32]) -> Result<usize> {
+        let mut removed = 0;
+        for &source in source_neurons {
+            let indices: Vec<usize> = self.outgoing(source).collect();
+            for idx in indices {
+                if self.invalidate(idx) {
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    fn remove_synapses_between(&mut self, source: u32, target: u32) -> Result<usize> {
+        let targets = self.target_neurons();
+        let indices: Vec<usize> = self
+            .outgoing(source)
+            .filter(|&idx| targets[idx] == target)
+            .collect();
+        let mut removed = 0;
+        for idx in indices {
+            if self.invalidate(idx) {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn update_weight(&mut self, idx: usize, new_weight: u8) -> Result<()> {
+        self.check_index(idx)?;
+        if !self.valid_mask()[idx] {
+            return Err(RuntimeError::InvalidParameters(format!(
+                "Synapse {} is not valid",
+                idx
+            )));
+        }
+        self.invalidate_row_of(idx);
+        let count = self.count;
+        self.section_mut::<u8>(WEIGHTS, count)[idx] = new_weight;
+        Ok(())
+    }
+
+    fn valid_count(&self) -> usize {
+        self.valid_count
+    }
+
+    /// Rows come from the image's row table, so no in-RAM index is needed
+    fn indexes_sources(&self) -> bool {
+        true
+    }
+
+    fn outgoing_synapses(&self, source: u32) -> Vec<usize> {
+        self.outgoing(source).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std_impl::{CsrSynapseArray, SynapseArray};
+    use tempfile::NamedTempFile;
+
+    fn reference_synapses() -> SynapseArray {
+        let mut reference = SynapseArray::new(64);
+        for i in 0..64u32 {
+            let synapse_type = if i % 5 == 0 {
+                SynapseType::Inhibitory
+            } else {
+                SynapseType::Excitatory
+            };
+            reference.add_synapse_simple(i % 7, (i * 13) % 20, (i * 3) as u8, 9, synapse_type);
+        }
+        reference.remove_synapses_from_sources(&[3]).unwrap();
+        reference
+    }
+
+    fn write_image(reference: &SynapseArray, capacity: usize, offset: u64) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(&std::vec![0xAB; offset as usize]).unwrap();
+        let columns = SynapseColumns::from_storage(reference);
+        write_synapse_image(&mut file, &columns, capacity).unwrap();
+        file.flush().unwrap();
+        file
+    }
+
+    #[test]
+    fn test_mapped_propagation_matches_csr() {
+        let reference = reference_synapses();
+        let file = write_image(&reference, 0, page_size());
+        let mapped = MmapSynapseArray::open_at(file.path(), page_size()).unwrap();
+        let csr = CsrSynapseArray::from_storage(&reference);
+
+        assert_eq!(mapped.count(), csr.count());
+        assert_eq!(mapped.valid_count(), reference.valid_count());
+        assert_eq!(mapped.source_neurons(), csr.source_neurons());
+        assert_eq!(mapped.target_neurons(), csr.target_neurons());
+        assert_eq!(mapped.merged_row(5), csr.merged_row(5));
+
+        let fired = [0, 3, 4, 6];
+        let mut expected: Vec<(u32, f32)> = csr.propagate(&fired).into_iter().collect();
+        expected.sort_by_key(|&(target, _)| target);
+        for pass in 0..2 {
+            let mut actual: Vec<(u32, f32)> = mapped.propagate(&fired).into_iter().collect();
+            actual.sort_by_key(|&(target, _)| target);
+            assert_eq!(actual, expected, "pass {}", pass);
+        }
+        let stats = mapped.hot_cache_stats();
+        assert_eq!((stats.misses, stats.hits), (3, 3));
+        assert_eq!(stats.cached_rows, 3);
+    }
+
+    #[test]
+    fn test_mutations_stay_private_and_invalidate_cache() {
+        let reference = reference_synapses();
+        let file = write_image(&reference, 128, 0);
+        let on_disk = std::fs::read(file.path()).unwrap();
+
+        let mut mapped = MmapSynapseArray::open(file.path()).unwrap();
+        assert_eq!(mapped.capacity(), 128);
+        let before = mapped.propagate(&[1]);
+
+        let idx = mapped.merged_row(1).start;
+        let target = mapped.target_neurons()[idx];
+        mapped.update_weight(idx, 255).unwrap();
+        assert_eq!(mapped.hot_cache_stats().cached_rows, 0);
+        assert_ne!(mapped.propagate(&[1])[&target], before[&target]);
+
+        let added = mapped.add_synapse(42, 7, 100, 1, 0).unwrap();
+        assert_eq!(mapped.pending_inserts(), 1);
+        assert_eq!(mapped.outgoing(42).collect::<Vec<_>>(), std::vec![added]);
+        assert!(mapped.propagate(&[42]).contains_key(&7));
+        assert_eq!(mapped.remove_synapses_between(42, 7).unwrap(), 1);
+        assert!(mapped.propagate(&[42]).is_empty());
+
+        drop(mapped);
+        assert_eq!(std::fs::read(file.path()).unwrap(), on_disk);
+    }
+
+    #[test]
+    fn test_capacity_and_cache_budget() {
+        let reference = reference_synapses();
+        let file = write_image(&reference, 0, 0);
+        let mut mapped = MmapSynapseArray::open(file.path())
+            .unwrap()
+            .with_hot_cache_budget(0);
+        assert!(matches!(
+            mapped.add_synapse(1, 2, 3, 4, 0),
+            Err(RuntimeError::CapacityExceeded { .. })
+        ));
+        mapped.propagate(&[0, 0]);
+        assert_eq!(mapped.hot_cache_stats().cached_rows, 0);
+        assert_eq!(mapped.hot_cache_stats().misses, 2);
+
+        assert!(MmapSynapseArray::open_at(file.path(), 1).is_err());
+        let garbage = NamedTempFile::new().unwrap();
+        std::fs::write(garbage.path(), std::vec![0u8; IMAGE_HEADER_LEN]).unwrap();
+        assert!(MmapSynapseArray::open(garbage.path()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_overflowing_header_sizes() {
+        let reference = reference_synapses();
+        let file = write_image(&reference, 0, 0);
+        let image = std::fs::read(file.path()).unwrap();
+        let patched = |at: usize, value: u64| {
+            let mut bytes = image.clone();
+            bytes[at..at + 8].copy_from_slice(&value.to_le_bytes());
+            let out = NamedTempFile::new().unwrap();
+            std::fs::write(out.path(), bytes).unwrap();
+            out
+        };
+
+        // capacity * 4 wraps to a small length
+        let wrapped_capacity = patched(16, (1u64 << 62) + 1);
+        assert!(MmapSynapseArray::open(wrapped_capacity.path()).is_err());
+        // (row_count + 1) * 8 wraps
+        let wrapped_rows = patched(24, u64::MAX);
+        assert!(MmapSynapseArray::open(wrapped_rows.path()).is_err());
+        // section start + length wraps past the end of the mapping
+        let align = u64::from_le_bytes(image[32..40].try_into().unwrap());
+        let wrapped_start = patched(40, u64::MAX - align + 1);
+        assert!(MmapSynapseArray::open(wrapped_start.path()).is_err());
+    }
+}
@@ -17,12 +17,20 @@
 //!
 //! This module is only available when the `std` feature is enabled.
 
+pub mod csr_synapse_array;
+#[cfg(feature = "mmap")]
+pub mod mmap_synapse_array;
 pub mod neuron_array;
 pub mod runtime;
 pub mod synapse_array;
 
+pub use csr_synapse_array::CsrSynapseArray;
+#[cfg(feature = "mmap")]
+pub use mmap_synapse_array::MmapSynapseArray;
 pub use neuron_array::NeuronArray;
-pub use runtime::StdRuntime;
+#[cfg(feature = "mmap")]
+pub use runtime::MmapRuntime;
+pub use runtime::{CsrRuntime, StdRuntime};
 pub use synapse_array::SynapseArray;
 
 // Re-export for backward compatibility
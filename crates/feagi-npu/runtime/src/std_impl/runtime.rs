@@ -10,7 +10,7 @@
 
 //! Standard runtime implementation for desktop/server platforms
 
-use crate::std_impl::{NeuronArray, SynapseArray};
+use crate::std_impl::{CsrSynapseArray, NeuronArray, SynapseArray};
 use crate::traits::{NeuralValue, Result, Runtime};
 
 /// Standard runtime for desktop/server (Vec-based, dynamic allocation)
@@ -62,6 +62,122 @@ impl Runtime for StdRuntime {
     }
 }
 
+/// Standard runtime with compressed-sparse-row synapse storage
+///
+/// Same neuron storage as [`StdRuntime`], but synapses are stored in a
+/// [`CsrSynapseArray`] for event-driven propagation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsrRuntime;
+
+impl CsrRuntime {
+    /// Create a new CSR runtime
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Runtime for CsrRuntime {
+    type NeuronStorage<T: NeuralValue> = NeuronArray<T>;
+    type SynapseStorage = CsrSynapseArray;
+
+    fn create_neuron_storage<T: NeuralValue>(
+        &self,
+        capacity: usize,
+    ) -> Result<Self::NeuronStorage<T>> {
+        Ok(NeuronArray::new(capacity))
+    }
+
+    fn create_synapse_storage(&self, capacity: usize) -> Result<Self::SynapseStorage> {
+        Ok(CsrSynapseArray::new(capacity))
+    }
+
+    fn supports_parallel(&self) -> bool {
+        true
+    }
+
+    fn memory_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn platform_name(&self) -> &'static str {
+        "Standard (CSR synapses)"
+    }
+}
+
+/// Standard runtime whose synapses are served from a memory-mapped synapse image
+///
+/// Neurons are stored as in [`StdRuntime`]. Every synapse storage created by
+/// this runtime maps the same image (copy-on-write), so a saved connectome can
+/// be run without loading its synapses into RAM. The image fixes the synapse
+/// capacity; the capacity requested from `create_synapse_storage` is ignored.
+#[cfg(feature = "mmap")]
+#[derive(Debug, Clone)]
+pub struct MmapRuntime {
+    image_path: std::path::PathBuf,
+    image_offset: u64,
+    hot_cache_bytes: usize,
+}
+
+#[cfg(feature = "mmap")]
+impl MmapRuntime {
+    /// Runtime over a synapse image stored at `image_offset` bytes into `image_path`
+    pub fn new<P: Into<std::path::PathBuf>>(image_path: P, image_offset: u64) -> Self {
+        Self {
+            image_path: image_path.into(),
+            image_offset,
+            hot_cache_bytes: crate::std_impl::mmap_synapse_array::DEFAULT_HOT_CACHE_BYTES,
+        }
+    }
+
+    /// Set the hot-row cache budget of created synapse storages
+    pub fn with_hot_cache_budget(mut self, budget_bytes: usize) -> Self {
+        self.hot_cache_bytes = budget_bytes;
+        self
+    }
+
+    /// Path of the mapped file
+    pub fn image_path(&self) -> &std::path::Path {
+        &self.image_path
+    }
+
+    /// Offset of the synapse image within the mapped file
+    pub fn image_offset(&self) -> u64 {
+        self.image_offset
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Runtime for MmapRuntime {
+    type NeuronStorage<T: NeuralValue> = NeuronArray<T>;
+    type SynapseStorage = crate::std_impl::MmapSynapseArray;
+
+    fn create_neuron_storage<T: NeuralValue>(
+        &self,
+        capacity: usize,
+    ) -> Result<Self::NeuronStorage<T>> {
+        Ok(NeuronArray::new(capacity))
+    }
+
+    fn create_synapse_storage(&self, _capacity: usize) -> Result<Self::SynapseStorage> {
+        Ok(
+            crate::std_impl::MmapSynapseArray::open_at(&self.image_path, self.image_offset)?
+                .with_hot_cache_budget(self.hot_cache_bytes),
+        )
+    }
+
+    fn supports_parallel(&self) -> bool {
+        true
+    }
+
+    fn memory_limit(&self) -> Option<usize> {
+        None
+    }
+
+    fn platform_name(&self) -> &'static str {
+        "Standard (memory-mapped synapses)"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,6 +385,49 @@ pub trait SynapseStorage: Send + Sync {
 
     /// Get count of valid (non-deleted) synapses
     fn valid_count(&self) -> usize;
+
+    // === Source Lookup ===
+
+    /// Whether the storage looks up synapses by source neuron on its own
+    ///
+    /// When true, the burst engine does not build an in-RAM source index and
+    /// calls [`outgoing_synapses`](Self::outgoing_synapses) instead.
+    fn indexes_sources(&self) -> bool {
+        false
+    }
+
+    /// Indices of the valid synapses originating at `source`
+    ///
+    /// The default scans every synapse; storages that return true from
+    /// [`indexes_sources`](Self::indexes_sources) override it.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn outgoing_synapses(&self, source: u32) -> Vec<usize> {
+        let valid = self.valid_mask();
+        self.source_neurons()
+            .iter()
+            .enumerate()
+            .filter(|&(idx, &neuron)| neuron == source && valid[idx])
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    // === Buffered Changes ===
+
+    /// Whether buffered structural changes should be folded in now
+    ///
+    /// `idle` is true when no activity is in flight (nothing fired in the
+    /// last two bursts), so even small buffers are worth merging.
+    fn wants_compaction(&self, _idle: bool) -> bool {
+        false
+    }
+
+    /// Fold buffered inserts/removals into the storage layout
+    ///
+    /// Returns true if synapse indices changed, in which case any index built
+    /// over this storage must be rebuilt.
+    fn compact(&mut self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
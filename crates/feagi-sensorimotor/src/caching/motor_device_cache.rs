@@ -6,7 +6,7 @@ use crate::data_types::*;
 use crate::neuron_voxel_coding::xyzp::decoders::*;
 use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPDecoder;
 use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
-use feagi_serialization::FeagiByteContainer;
+use feagi_serialization::{FeagiByteContainer, XYZPDeltaDecoder};
 use feagi_structures::genomic::cortical_area::descriptors::{
     CorticalChannelCount, CorticalChannelIndex, CorticalUnitIndex, NeuronDepth,
 };
@@ -18,7 +18,7 @@ use feagi_structures::genomic::MotorCorticalUnit;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::{motor_cortical_units, FeagiDataError, FeagiSignalIndex};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::time::Instant;
 
@@ -57,17 +57,26 @@ macro_rules! motor_unit_functions {
     (@generate_similar_functions
         $cortical_type_key_name:ident,
         $wrapped_data_type:ident
+    ) => {
+        motor_unit_functions!(@generate_similar_functions $cortical_type_key_name, $wrapped_data_type, $wrapped_data_type);
+    };
+
+    // Same as above, for units whose default stages turn the decoded type into a different one
+    (@generate_similar_functions
+        $cortical_type_key_name:ident,
+        $decoded_data_type:ident,
+        $processed_data_type:ident
     ) => {
         ::paste::paste! {
             pub fn [<$cortical_type_key_name:snake _read_preprocessed_cache_value>](
                 &self,
                 unit: CorticalUnitIndex,
                 channel: CorticalChannelIndex,
-            ) -> Result< $wrapped_data_type, FeagiDataError> {
+            ) -> Result< $decoded_data_type, FeagiDataError> {
 
                 const MOTOR_TYPE: MotorCorticalUnit = MotorCorticalUnit::$cortical_type_key_name;
                 let wrapped = self.try_read_preprocessed_cached_value(MOTOR_TYPE, unit, channel)?;
-                let val: $wrapped_data_type = wrapped.try_into()?;
+                let val: $decoded_data_type = wrapped.try_into()?;
                 Ok(val)
             }
 
@@ -75,11 +84,11 @@ macro_rules! motor_unit_functions {
                 &self,
                 unit: CorticalUnitIndex,
                 channel: CorticalChannelIndex,
-            ) -> Result< $wrapped_data_type, FeagiDataError> {
+            ) -> Result< $processed_data_type, FeagiDataError> {
 
                 const MOTOR_TYPE: MotorCorticalUnit = MotorCorticalUnit::$cortical_type_key_name;
                 let wrapped = self.try_read_postprocessed_cached_value(MOTOR_TYPE, unit, channel)?;
-                let val: $wrapped_data_type = wrapped.try_into()?;
+                let val: $processed_data_type = wrapped.try_into()?;
                 Ok(val)
             }
 
@@ -343,6 +352,59 @@ macro_rules! motor_unit_functions {
         motor_unit_functions!(@generate_similar_functions $motor_unit, SignedPercentage);
     };
 
+    // Arm for TextEnglishOutput, a MiscData unit whose bitplanes are collected as received text tokens
+    (@generate_functions
+        TextEnglishOutput,
+        MiscData
+    ) => {
+        pub fn text_english_output_register(
+            &mut self,
+            unit: CorticalUnitIndex,
+            number_channels: CorticalChannelCount,
+            token_depth: NeuronDepth
+            ) -> Result<(), FeagiDataError>
+        {
+            if *token_depth > u32::BITS {
+                return Err(FeagiDataError::BadParameters(format!("Text tokens cannot be deeper than {} bitplanes, got {}!", u32::BITS, token_depth)));
+            }
+            let frame_change_handling = FrameChangeHandling::Absolute; // Only absolute is allowed for text
+            let misc_data_dimensions = MiscDataDimensions::new(1, 1, *token_depth)?;
+            let cortical_id: CorticalID = MotorCorticalUnit::get_cortical_ids_array_for_text_english_output_with_parameters(frame_change_handling, unit)[0];
+            let decoder: Box<dyn NeuronVoxelXYZPDecoder + Sync + Send> = MiscDataNeuronVoxelXYZPDecoder::new_box(cortical_id, misc_data_dimensions, number_channels)?;
+
+            let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                "frame_change_handling": frame_change_handling
+            }).as_object().unwrap().clone();
+
+            let initial_val: WrappedIOData = WrappedIOType::MiscData(Some(misc_data_dimensions)).create_blank_data_of_type()?;
+            self.register(MotorCorticalUnit::TextEnglishOutput, unit, decoder, io_props, number_channels, initial_val)?;
+            Ok(())
+        }
+
+        /// Returns (and forgets) the tokens received on a channel since the last read, in order.
+        pub fn text_english_output_read_tokens(
+            &mut self,
+            unit: CorticalUnitIndex,
+            channel: CorticalChannelIndex
+        ) -> Result<Vec<TextToken>, FeagiDataError>
+        {
+            self.verify_existence(MotorCorticalUnit::TextEnglishOutput, unit, channel)?;
+            Ok(self.received_text_tokens.remove(&(unit, channel)).map(Vec::from).unwrap_or_default())
+        }
+
+        pub fn text_english_output_get_received_token_count(
+            &self,
+            unit: CorticalUnitIndex,
+            channel: CorticalChannelIndex
+        ) -> Result<usize, FeagiDataError>
+        {
+            self.verify_existence(MotorCorticalUnit::TextEnglishOutput, unit, channel)?;
+            Ok(self.received_text_tokens.get(&(unit, channel)).map_or(0, |tokens| tokens.len()))
+        }
+
+        motor_unit_functions!(@generate_similar_functions TextEnglishOutput, MiscData);
+    };
+
     // Arm for WrappedIOType::MiscData
     (@generate_functions
         $motor_unit:ident,
@@ -373,6 +435,49 @@ macro_rules! motor_unit_functions {
         motor_unit_functions!(@generate_similar_functions $motor_unit, MiscData);
     };
 
+    // Arm for WrappedIOType::AudioFrame
+    (@generate_functions
+        $motor_unit:ident,
+        AudioFrame
+    ) => {
+        ::paste::paste! {
+            #[allow(clippy::too_many_arguments)]
+            pub fn [<$motor_unit:snake _register>](
+                &mut self,
+                unit: CorticalUnitIndex,
+                number_channels: CorticalChannelCount,
+                frame_change_handling: FrameChangeHandling,
+                audio_properties: AudioFrameProperties,
+                number_tone_bands: u32,
+                amplitude_depth: NeuronDepth,
+                min_frequency_hz: f32,
+                max_frequency_hz: f32,
+                ) -> Result<(), FeagiDataError>
+            {
+                // The decoder outputs band amplitudes, so we add the stage synthesizing them into audio by default
+                processing::ToneSynthesizer::new(audio_properties, number_tone_bands, min_frequency_hz, max_frequency_hz)?; // Validate before creating any stages
+                let cortical_id: CorticalID = MotorCorticalUnit::[<get_cortical_ids_array_for_ $motor_unit:snake _with_parameters>](frame_change_handling, unit)[0];
+                let decoder: Box<dyn NeuronVoxelXYZPDecoder + Sync + Send> = ToneBandsNeuronVoxelXYZPDecoder::new_box(cortical_id, number_tone_bands, amplitude_depth, number_channels)?;
+
+                let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                    "frame_change_handling": frame_change_handling
+                }).as_object().unwrap().clone();
+
+                let band_amplitude_dimensions = MiscDataDimensions::new(1, number_tone_bands, 1)?;
+                let initial_val: WrappedIOData = WrappedIOType::MiscData(Some(band_amplitude_dimensions)).create_blank_data_of_type()?;
+                self.register(MotorCorticalUnit::$motor_unit, unit, decoder, io_props, number_channels, initial_val)?;
+
+                for channel_index in 0..*number_channels {
+                    let synthesizer_stage = PipelineStageProperties::new_audio_tone_synthesizer(audio_properties, number_tone_bands, min_frequency_hz, max_frequency_hz);
+                    self.[<$motor_unit:snake _replace_all_stages>](unit, channel_index.into(), vec![synthesizer_stage])?;
+                }
+                Ok(())
+            }
+        }
+
+        motor_unit_functions!(@generate_similar_functions $motor_unit, MiscData, AudioFrame);
+    };
+
     // Arm for WrappedIOType::ImageFrame
     (@generate_functions
         $motor_unit:ident,
@@ -455,7 +560,9 @@ pub struct MotorDeviceCache {
         HashMap<(MotorCorticalUnit, CorticalUnitIndex), MotorCorticalUnitCache>,
     neuron_data: CorticalMappedXYZPNeuronVoxels,
     byte_data: FeagiByteContainer,
+    delta_decoder: XYZPDeltaDecoder,
     previous_burst: Instant,
+    received_text_tokens: HashMap<(CorticalUnitIndex, CorticalChannelIndex), VecDeque<TextToken>>,
     #[allow(dead_code)]
     is_active: bool,
 }
@@ -480,7 +587,9 @@ impl MotorDeviceCache {
             motor_cortical_unit_caches: HashMap::new(),
             neuron_data: CorticalMappedXYZPNeuronVoxels::new(),
             byte_data: FeagiByteContainer::new_empty(),
+            delta_decoder: XYZPDeltaDecoder::new(),
             previous_burst: Instant::now(),
+            received_text_tokens: HashMap::new(),
             is_active: false,
         }
     }
@@ -508,7 +617,9 @@ impl MotorDeviceCache {
         self.motor_cortical_unit_caches.clear();
         self.neuron_data = CorticalMappedXYZPNeuronVoxels::new();
         self.byte_data = FeagiByteContainer::new_empty();
+        self.delta_decoder.reset();
         self.previous_burst = Instant::now();
+        self.received_text_tokens.clear();
     }
 
     pub fn verify_existence(
@@ -538,10 +649,11 @@ impl MotorDeviceCache {
         &self.neuron_data
     }
 
-    // Returns true if data was retrieved
+    // Returns true if data was retrieved. Accepts full and delta-coded XYZP motor frames; deltas
+    // are dropped until the next keyframe if one was missed
     pub fn try_decode_bytes_to_neural_data(&mut self) -> Result<bool, FeagiDataError> {
-        self.byte_data
-            .try_update_struct_from_first_found_struct_of_type(&mut self.neuron_data)
+        self.delta_decoder
+            .try_apply_from_container(&self.byte_data, &mut self.neuron_data)
     }
 
     pub fn try_decode_neural_data_into_cache(
@@ -554,6 +666,22 @@ impl MotorDeviceCache {
                 time_of_decode,
             )?;
         }
+        self.collect_received_text_tokens(time_of_decode)?;
+        Ok(())
+    }
+
+    /// Lets time based motor pipeline stages, such as the watchdog of a
+    /// [`MotorSafetyLimiter`](crate::data_pipeline::PipelineStageProperties::MotorSafetyLimiter),
+    /// react to no new motor data arriving, running callbacks on any channels that change.
+    ///
+    /// This already happens for every channel without fresh data whenever motor data is
+    /// decoded, but that stops if FEAGI stops sending altogether. The agent client calls this on
+    /// every tick; call it periodically from the robot's control loop when driving the cache
+    /// directly, so watchdogs still trip then.
+    pub fn tick_motor_pipelines(&mut self, time_of_tick: Instant) -> Result<(), FeagiDataError> {
+        for motor_channel_stream_cache in self.motor_cortical_unit_caches.values_mut() {
+            motor_channel_stream_cache.try_process_idle_and_do_callbacks(time_of_tick)?;
+        }
         Ok(())
     }
 
@@ -798,6 +926,38 @@ impl MotorDeviceCache {
 
     //region Hashmap Interactions
 
+    /// Stores the token of every text channel that received bitplanes in this decode.
+    fn collect_received_text_tokens(
+        &mut self,
+        time_of_decode: Instant,
+    ) -> Result<(), FeagiDataError> {
+        for ((motor_type, unit_index), motor_stream_caches) in
+            self.motor_cortical_unit_caches.iter()
+        {
+            if *motor_type != MotorCorticalUnit::TextEnglishOutput {
+                continue;
+            }
+            for channel in 0..*motor_stream_caches.number_of_channels() {
+                let channel_index = CorticalChannelIndex::from(channel);
+                if motor_stream_caches.try_get_channel_last_processed_instant(channel_index)?
+                    != time_of_decode
+                {
+                    continue; // Nothing received, a gap
+                }
+                let token_bitplanes: &MiscData = motor_stream_caches
+                    .get_preprocessed_motor_value(channel_index)?
+                    .try_into()?;
+                if let Some(token_id) = decode_token_id_from_misc_data(token_bitplanes)? {
+                    self.received_text_tokens
+                        .entry((*unit_index, channel_index))
+                        .or_default()
+                        .push_back(TextToken::new_unchecked(token_id));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn try_get_motor_channel_stream_caches(
         &self,
         motor_type: MotorCorticalUnit,
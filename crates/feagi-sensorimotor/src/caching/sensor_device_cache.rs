@@ -1,13 +1,16 @@
 use crate::configuration::jsonable::JSONInputOutputDefinition;
 use crate::data_pipeline::per_channel_stream_caches::SensoryCorticalUnitCache;
-use crate::data_pipeline::{PipelineStageProperties, PipelineStagePropertyIndex};
+use crate::data_pipeline::{
+    PipelineStageProperties, PipelineStagePropertyIndex, SensoryEncodingSettings,
+};
 use crate::data_types::descriptors::PercentageChannelDimensionality;
 use crate::data_types::descriptors::{
-    ImageFrameProperties, MiscDataDimensions, SegmentedImageFrameProperties,
+    AudioFrameProperties, ImageFrameProperties, ImageXYResolution, MiscDataDimensions,
+    SegmentedImageFrameProperties,
 };
 use crate::data_types::{
-    GazeProperties, ImageFrame, MiscData, Percentage, Percentage3D, SegmentedImageFrame,
-    SignedPercentage4D,
+    encode_token_id_to_misc_data, EventStream, GazeProperties, ImageFrame, MiscData, Percentage,
+    Percentage3D, SegmentedImageFrame, SignedPercentage4D, TextToken,
 };
 use crate::neuron_voxel_coding::xyzp::encoders::*;
 use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPEncoder;
@@ -24,7 +27,7 @@ use feagi_structures::genomic::SensoryCorticalUnit;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::{sensor_cortical_units, FeagiDataError, FeagiSignal};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::time::Instant;
 // InputOutputDefinition is used in commented-out code (lines 622, 645)
@@ -179,6 +182,26 @@ macro_rules! sensor_unit_functions {
                 self.try_removing_all_stages(SENSOR_UNIT_TYPE, unit, channel_index)?;
                 Ok(())
             }
+
+            pub fn [<$cortical_type_key_name:snake _get_encoding_settings>](
+                &mut self,
+                unit: CorticalUnitIndex
+            ) -> Result<SensoryEncodingSettings, FeagiDataError>
+            {
+                const SENSOR_UNIT_TYPE: SensoryCorticalUnit = SensoryCorticalUnit::$cortical_type_key_name;
+                self.try_get_encoding_settings(SENSOR_UNIT_TYPE, unit)
+            }
+
+            pub fn [<$cortical_type_key_name:snake _set_encoding_settings>](
+                &mut self,
+                unit: CorticalUnitIndex,
+                encoding_settings: SensoryEncodingSettings
+            ) -> Result<(), FeagiDataError>
+            {
+                const SENSOR_UNIT_TYPE: SensoryCorticalUnit = SensoryCorticalUnit::$cortical_type_key_name;
+                self.try_set_encoding_settings(SENSOR_UNIT_TYPE, unit, encoding_settings)?;
+                Ok(())
+            }
         }
     };
     //endregion
@@ -367,6 +390,78 @@ macro_rules! sensor_unit_functions {
         sensor_unit_functions!(@generate_similar_functions $sensory_unit, SegmentedImageFrame);
     };
 
+    // Arm for TextEnglishInput, a MiscData unit that sends queued text tokens as bitplanes, one per burst
+    (@generate_functions
+        TextEnglishInput,
+        MiscData
+    ) => {
+        pub fn text_english_input_register(
+            &mut self,
+            unit: CorticalUnitIndex,
+            number_channels: CorticalChannelCount,
+            token_depth: NeuronDepth
+            ) -> Result<(), FeagiDataError>
+        {
+            if *token_depth > u32::BITS {
+                return Err(FeagiDataError::BadParameters(format!("Text tokens cannot be deeper than {} bitplanes, got {}!", u32::BITS, token_depth)));
+            }
+            let frame_change_handling = FrameChangeHandling::Absolute; // Only absolute is allowed for text
+            let misc_data_dimensions = MiscDataDimensions::new(1, 1, *token_depth)?;
+            let cortical_id: CorticalID = SensoryCorticalUnit::get_cortical_ids_array_for_text_english_input_with_parameters(frame_change_handling, unit)[0];
+            let encoder: Box<dyn NeuronVoxelXYZPEncoder + Sync + Send> = MiscDataNeuronVoxelXYZPEncoder::new_box(cortical_id, misc_data_dimensions, number_channels)?;
+
+            let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                "frame_change_handling": frame_change_handling
+            }).as_object().unwrap().clone();
+
+            let initial_val: WrappedIOData = WrappedIOType::MiscData(Some(misc_data_dimensions)).create_blank_data_of_type()?;
+            self.register(SensoryCorticalUnit::TextEnglishInput, unit, encoder, io_props, number_channels, initial_val)?;
+            Ok(())
+        }
+
+        /// Queues tokens to be sent on a channel, one per burst. Bursts without a queued token
+        /// send nothing, which FEAGI reads as a gap.
+        pub fn text_english_input_queue_tokens(
+            &mut self,
+            unit: CorticalUnitIndex,
+            channel: CorticalChannelIndex,
+            tokens: &[TextToken]
+        ) -> Result<(), FeagiDataError>
+        {
+            let token_depth = self.try_get_text_token_depth(unit, channel)?;
+            for token in tokens {
+                if token_depth < u32::BITS && token.token_id() >= (1u32 << token_depth) - 1 {
+                    return Err(FeagiDataError::BadParameters(format!("Token id {} cannot be sent with a token depth of {}!", token.token_id(), token_depth)));
+                }
+            }
+            self.queued_text_tokens.entry((unit, channel)).or_default().extend(tokens.iter().copied());
+            Ok(())
+        }
+
+        pub fn text_english_input_get_queued_token_count(
+            &self,
+            unit: CorticalUnitIndex,
+            channel: CorticalChannelIndex
+        ) -> Result<usize, FeagiDataError>
+        {
+            self.verify_existence(SensoryCorticalUnit::TextEnglishInput, unit, channel)?;
+            Ok(self.queued_text_tokens.get(&(unit, channel)).map_or(0, |queue| queue.len()))
+        }
+
+        pub fn text_english_input_clear_queued_tokens(
+            &mut self,
+            unit: CorticalUnitIndex,
+            channel: CorticalChannelIndex
+        ) -> Result<(), FeagiDataError>
+        {
+            self.verify_existence(SensoryCorticalUnit::TextEnglishInput, unit, channel)?;
+            self.queued_text_tokens.remove(&(unit, channel));
+            Ok(())
+        }
+
+        sensor_unit_functions!(@generate_similar_functions TextEnglishInput, MiscData);
+    };
+
     // Arm for WrappedIOType::MiscData
     (@generate_functions
         $sensory_unit:ident,
@@ -398,6 +493,100 @@ macro_rules! sensor_unit_functions {
     };
 
 
+    // Arm for WrappedIOType::AudioFrame
+    (@generate_functions
+        $sensory_unit:ident,
+        AudioFrame
+    ) => {
+        ::paste::paste! {
+            #[allow(clippy::too_many_arguments)]
+            pub fn [<$sensory_unit:snake _register>](
+                &mut self,
+                unit: CorticalUnitIndex,
+                number_channels: CorticalChannelCount,
+                frame_change_handling: FrameChangeHandling,
+                audio_properties: AudioFrameProperties,
+                fft_window_size: u32,
+                number_mel_bands: u32,
+                intensity_depth: NeuronDepth,
+                ) -> Result<(), FeagiDataError>
+            {
+                // Like segmented vision, the encoder works on processed data (a mel spectrogram), so we add the stages turning raw audio into it by default
+                const DYNAMIC_RANGE_DB: f32 = 60.0;
+                let hop_size: u32 = (fft_window_size / 2).max(1); // 50% window overlap
+                let spectrogram_stage = PipelineStageProperties::new_audio_spectrogram(audio_properties, fft_window_size, hop_size);
+                let spectrogram_dimensions = match spectrogram_stage.get_output_data_type() {
+                    WrappedIOType::MiscData(Some(dimensions)) => dimensions,
+                    _ => return Err(FeagiDataError::InternalError("Audio spectrogram stage does not output misc data!".into())),
+                };
+                let mel_stage = PipelineStageProperties::new_audio_mel_filterbank(
+                    spectrogram_dimensions,
+                    audio_properties.get_sample_rate_hz(),
+                    number_mel_bands,
+                    0.0,
+                    audio_properties.get_sample_rate_hz() as f32 / 2.0,
+                    DYNAMIC_RANGE_DB,
+                );
+                let mel_dimensions = MiscDataDimensions::new(spectrogram_dimensions.width, number_mel_bands, 1)?;
+
+                let cortical_id: CorticalID = SensoryCorticalUnit::[<get_cortical_ids_array_for_ $sensory_unit:snake _with_parameters>](frame_change_handling, unit)[0];
+                let encoder: Box<dyn NeuronVoxelXYZPEncoder + Sync + Send> = AudioSpectrogramNeuronVoxelXYZPEncoder::new_box(cortical_id, mel_dimensions, intensity_depth, number_channels)?;
+
+                let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                    "frame_change_handling": frame_change_handling
+                }).as_object().unwrap().clone();
+
+                let initial_val: WrappedIOData = WrappedIOType::MiscData(Some(mel_dimensions)).create_blank_data_of_type()?;
+                self.register(SensoryCorticalUnit::$sensory_unit, unit, encoder, io_props, number_channels, initial_val)?;
+
+                for channel_index in 0..*number_channels {
+                    let audio_pipeline = vec![spectrogram_stage.clone(), mel_stage.clone()];
+                    self.[<$sensory_unit:snake _replace_all_stages>](unit, channel_index.into(), audio_pipeline)?;
+                }
+                Ok(())
+            }
+        }
+
+        sensor_unit_functions!(@generate_similar_functions $sensory_unit, MiscData);
+    };
+
+    // Arm for LogPolarVision, which is an ImageFrame unit that resamples incoming images with a log-polar stage by default
+    (@generate_functions
+        LogPolarVision,
+        ImageFrame
+    ) => {
+        pub fn log_polar_vision_register(
+            &mut self,
+            unit: CorticalUnitIndex,
+            number_channels: CorticalChannelCount,
+            frame_change_handling: FrameChangeHandling,
+            input_image_properties: ImageFrameProperties,
+            log_polar_image_properties: ImageFrameProperties,
+            initial_gaze: GazeProperties
+            ) -> Result<(), FeagiDataError>
+        {
+            let cortical_id: CorticalID = SensoryCorticalUnit::get_cortical_ids_array_for_log_polar_vision_with_parameters(frame_change_handling, unit)[0];
+            let encoder: Box<dyn NeuronVoxelXYZPEncoder + Sync + Send> = CartesianPlaneNeuronVoxelXYZPEncoder::new_box(cortical_id, &log_polar_image_properties, number_channels)?;
+
+            let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                "frame_change_handling": frame_change_handling
+            }).as_object().unwrap().clone();
+
+            let initial_val: WrappedIOData = WrappedIOType::ImageFrame(Some(log_polar_image_properties)).create_blank_data_of_type()?;
+            self.register(SensoryCorticalUnit::LogPolarVision, unit, encoder, io_props, number_channels, initial_val)?;
+
+            let stage_properties = PipelineStageProperties::new_image_log_polar_transformer(input_image_properties, log_polar_image_properties, initial_gaze);
+
+            for channel_index in 0..*number_channels {
+                let log_polar_pipeline = vec![stage_properties.clone()];
+                self.log_polar_vision_replace_all_stages(unit, channel_index.into(), log_polar_pipeline)?;
+            }
+            Ok(())
+        }
+
+        sensor_unit_functions!(@generate_similar_functions LogPolarVision, ImageFrame);
+    };
+
     // Arm for WrappedIOType::ImageFrame
     (@generate_functions
         $sensory_unit:ident,
@@ -427,6 +616,36 @@ macro_rules! sensor_unit_functions {
 
         sensor_unit_functions!(@generate_similar_functions $sensory_unit, ImageFrame);
     };
+
+    // Arm for WrappedIOType::EventStream
+    (@generate_functions
+        $sensory_unit:ident,
+        EventStream
+    ) => {
+        ::paste::paste! {
+            pub fn [<$sensory_unit:snake _register>](
+                &mut self,
+                unit: CorticalUnitIndex,
+                number_channels: CorticalChannelCount,
+                frame_change_handling: FrameChangeHandling,
+                sensor_resolution: ImageXYResolution,
+                ) -> Result<(), FeagiDataError>
+            {
+                let cortical_id: CorticalID = SensoryCorticalUnit::[<get_cortical_ids_array_for_ $sensory_unit:snake _with_parameters>](frame_change_handling, unit)[0];
+                let encoder: Box<dyn NeuronVoxelXYZPEncoder + Sync + Send> = EventStreamNeuronVoxelXYZPEncoder::new_box(cortical_id, sensor_resolution, number_channels)?;
+
+                let io_props: serde_json::Map<String, serde_json::Value> = json!({
+                    "frame_change_handling": frame_change_handling
+                }).as_object().unwrap().clone();
+
+                let initial_val: WrappedIOData = WrappedIOType::EventStream(Some(sensor_resolution)).create_blank_data_of_type()?;
+                self.register(SensoryCorticalUnit::$sensory_unit, unit, encoder, io_props, number_channels, initial_val)?;
+                Ok(())
+            }
+        }
+
+        sensor_unit_functions!(@generate_similar_functions $sensory_unit, EventStream);
+    };
 }
 
 pub struct SensorDeviceCache {
@@ -435,6 +654,7 @@ pub struct SensorDeviceCache {
     neuron_data: CorticalMappedXYZPNeuronVoxels,
     byte_data: FeagiByteContainer,
     previous_burst: Instant,
+    queued_text_tokens: HashMap<(CorticalUnitIndex, CorticalChannelIndex), VecDeque<TextToken>>,
     neurons_encoded_signal: FeagiSignal<CorticalMappedXYZPNeuronVoxels>,
     bytes_encoded_signal: FeagiSignal<FeagiByteContainer>,
 }
@@ -467,6 +687,7 @@ impl SensorDeviceCache {
             neuron_data: CorticalMappedXYZPNeuronVoxels::new(),
             byte_data: FeagiByteContainer::new_empty(),
             previous_burst: Instant::now(),
+            queued_text_tokens: HashMap::new(),
             neurons_encoded_signal: FeagiSignal::new(),
             bytes_encoded_signal: FeagiSignal::new(),
         }
@@ -478,6 +699,7 @@ impl SensorDeviceCache {
         self.neuron_data = CorticalMappedXYZPNeuronVoxels::new();
         self.byte_data = FeagiByteContainer::new_empty();
         self.previous_burst = Instant::now();
+        self.queued_text_tokens.clear();
         self.neurons_encoded_signal = FeagiSignal::new();
         self.bytes_encoded_signal = FeagiSignal::new();
     }
@@ -540,17 +762,20 @@ impl SensorDeviceCache {
         // Clear neuron data before encoding
         self.neuron_data.clear_neurons_only();
 
+        self.write_next_queued_text_tokens(time_of_burst)?;
+
         let previous_burst = self.previous_burst;
 
         // TODO see if we can parallelize this to work on multiple cortical areas at once
         // Iterate over all registered sensor stream caches and encode them
-        // CRITICAL: Pass previous_burst (not time_of_burst) so encoder can check if channels were updated since last encoding
+        // CRITICAL: Pass previous_burst so only channels updated since the last encoding are sent
         for ((_sensor_type, _unit_index), stream_cache) in
             self.sensor_cortical_unit_caches.iter_mut()
         {
             stream_cache.update_neuron_data_with_recently_updated_cached_sensor_data(
                 &mut self.neuron_data,
                 previous_burst,
+                time_of_burst,
             )?;
         }
 
@@ -678,6 +903,55 @@ impl SensorDeviceCache {
 
     //region Data
 
+    /// Writes the next queued token of each text channel, to be encoded this burst.
+    fn write_next_queued_text_tokens(
+        &mut self,
+        time_of_burst: Instant,
+    ) -> Result<(), FeagiDataError> {
+        let channels: Vec<(CorticalUnitIndex, CorticalChannelIndex)> =
+            self.queued_text_tokens.keys().copied().collect();
+        for (unit, channel) in channels {
+            let Some(token) = self
+                .queued_text_tokens
+                .get_mut(&(unit, channel))
+                .and_then(|queue| queue.pop_front())
+            else {
+                continue;
+            };
+            if self.queued_text_tokens[&(unit, channel)].is_empty() {
+                self.queued_text_tokens.remove(&(unit, channel));
+            }
+            let token_depth = self.try_get_text_token_depth(unit, channel)?;
+            let token_bitplanes = encode_token_id_to_misc_data(token.token_id(), token_depth)?;
+            self.try_update_value(
+                SensoryCorticalUnit::TextEnglishInput,
+                unit,
+                channel,
+                token_bitplanes.into(),
+                time_of_burst,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn try_get_text_token_depth(
+        &self,
+        unit_index: CorticalUnitIndex,
+        channel_index: CorticalChannelIndex,
+    ) -> Result<u32, FeagiDataError> {
+        let sensor_stream_caches = self.try_get_sensory_channel_stream_caches(
+            SensoryCorticalUnit::TextEnglishInput,
+            unit_index,
+        )?;
+        match sensor_stream_caches.get_input_type_for_channel(channel_index)? {
+            WrappedIOType::MiscData(Some(dimensions)) => Ok(dimensions.depth),
+            other => Err(FeagiDataError::InternalError(format!(
+                "Text input channel expects {} instead of token bitplanes!",
+                other
+            ))),
+        }
+    }
+
     fn try_update_value(
         &mut self,
         sensor_type: SensoryCorticalUnit,
@@ -750,6 +1024,28 @@ impl SensorDeviceCache {
         Ok(())
     }
 
+    fn try_get_encoding_settings(
+        &self,
+        sensor_type: SensoryCorticalUnit,
+        unit_index: CorticalUnitIndex,
+    ) -> Result<SensoryEncodingSettings, FeagiDataError> {
+        let sensor_stream_caches =
+            self.try_get_sensory_channel_stream_caches(sensor_type, unit_index)?;
+        Ok(sensor_stream_caches.get_encoding_settings())
+    }
+
+    fn try_set_encoding_settings(
+        &mut self,
+        sensor_type: SensoryCorticalUnit,
+        unit_index: CorticalUnitIndex,
+        encoding_settings: SensoryEncodingSettings,
+    ) -> Result<(), FeagiDataError> {
+        let sensor_stream_caches =
+            self.try_get_sensory_channel_stream_caches_mut(sensor_type, unit_index)?;
+        sensor_stream_caches.set_encoding_settings(encoding_settings);
+        Ok(())
+    }
+
     //endregion
 
     //region Stages
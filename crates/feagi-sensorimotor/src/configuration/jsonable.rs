@@ -1,20 +1,22 @@
-use crate::data_pipeline::PipelineStageProperties;
+use crate::data_pipeline::{PipelineStageProperties, SensoryEncodingSettings};
 use crate::data_types::descriptors::{
-    ImageFrameProperties, MiscDataDimensions, PercentageChannelDimensionality,
+    ImageFrameProperties, ImageXYResolution, MiscDataDimensions, PercentageChannelDimensionality,
     SegmentedImageFrameProperties,
 };
 use crate::data_types::{
-    GazeProperties, ImageFilteringSettings, ImageFrame, MiscData, Percentage, Percentage2D,
-    Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage, SignedPercentage2D,
-    SignedPercentage3D, SignedPercentage4D,
+    EventStream, GazeProperties, ImageFilteringSettings, ImageFrame, MiscData, Percentage,
+    Percentage2D, Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage,
+    SignedPercentage2D, SignedPercentage3D, SignedPercentage4D,
 };
 use crate::feedbacks::FeedbackRegistrar;
 use crate::neuron_voxel_coding::xyzp::decoders::{
     GazePropertiesNeuronVoxelXYZPDecoder, ImageFilteringSettingsNeuronVoxelXYZPDecoder,
     MiscDataNeuronVoxelXYZPDecoder, PercentageNeuronVoxelXYZPDecoder,
+    ToneBandsNeuronVoxelXYZPDecoder,
 };
 use crate::neuron_voxel_coding::xyzp::encoders::{
-    BooleanNeuronVoxelXYZPEncoder, CartesianPlaneNeuronVoxelXYZPEncoder,
+    AudioSpectrogramNeuronVoxelXYZPEncoder, BooleanNeuronVoxelXYZPEncoder,
+    CartesianPlaneNeuronVoxelXYZPEncoder, EventStreamNeuronVoxelXYZPEncoder,
     MiscDataNeuronVoxelXYZPEncoder, PercentageNeuronVoxelXYZPEncoder,
     SegmentedImageFrameNeuronVoxelXYZPEncoder,
 };
@@ -151,6 +153,8 @@ pub struct JSONUnitDefinition {
     pub(crate) cortical_unit_index: CorticalUnitIndex,
     pub(crate) io_configuration_flags: serde_json::Map<String, serde_json::Value>, // Due to the diversity contained here, this MUST be a generic dictionary
     pub(crate) device_grouping: Vec<JSONDeviceGrouping>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) encoding_settings: Option<SensoryEncodingSettings>, // Sensory units only
 }
 
 impl JSONUnitDefinition {
@@ -205,6 +209,8 @@ pub enum JSONEncoderProperties {
         PercentageChannelDimensionality,
     ),
     SegmentedImageFrame(SegmentedImageFrameProperties),
+    AudioSpectrogram(MiscDataDimensions, NeuronDepth), // spectrogram dimensions, intensity z depth
+    EventStream(ImageXYResolution),
 }
 
 impl JSONEncoderProperties {
@@ -284,6 +290,31 @@ impl JSONEncoderProperties {
                     number_channels,
                 )
             }
+            JSONEncoderProperties::AudioSpectrogram(spectrogram_dimensions, intensity_depth) => {
+                if cortical_ids.len() != 1 {
+                    return Err(FeagiDataError::InternalError(
+                        "Expected one cortical id!".to_string(),
+                    ));
+                }
+                AudioSpectrogramNeuronVoxelXYZPEncoder::new_box(
+                    *cortical_ids.first().unwrap(),
+                    *spectrogram_dimensions,
+                    *intensity_depth,
+                    number_channels,
+                )
+            }
+            JSONEncoderProperties::EventStream(resolution) => {
+                if cortical_ids.len() != 1 {
+                    return Err(FeagiDataError::InternalError(
+                        "Expected one cortical id!".to_string(),
+                    ));
+                }
+                EventStreamNeuronVoxelXYZPEncoder::new_box(
+                    *cortical_ids.first().unwrap(),
+                    *resolution,
+                    number_channels,
+                )
+            }
         }
     }
 
@@ -348,6 +379,14 @@ impl JSONEncoderProperties {
                     )?,
                 ))
             }
+            JSONEncoderProperties::AudioSpectrogram(spectrogram_dimensions, _intensity_depth) => {
+                Ok(WrappedIOData::MiscData(MiscData::new(
+                    spectrogram_dimensions,
+                )?))
+            }
+            JSONEncoderProperties::EventStream(resolution) => {
+                Ok(WrappedIOData::EventStream(EventStream::new(*resolution)))
+            }
         }
     }
 }
@@ -369,6 +408,7 @@ pub enum JSONDecoderProperties {
         NeuronDepth,
         PercentageNeuronPositioning,
     ), // brightness z depth, contrast z depth, diff z depth
+    ToneBands(u32, NeuronDepth), // number of tone bands, amplitude z depth
 }
 
 impl JSONDecoderProperties {
@@ -463,6 +503,19 @@ impl JSONDecoderProperties {
                     *percentage_neuron_positioning,
                 )
             }
+            JSONDecoderProperties::ToneBands(number_tone_bands, amplitude_depth) => {
+                if cortical_ids.len() != 1 {
+                    return Err(FeagiDataError::InternalError(
+                        "Expected one cortical id!".to_string(),
+                    ));
+                }
+                ToneBandsNeuronVoxelXYZPDecoder::new_box(
+                    *cortical_ids.first().unwrap(),
+                    *number_tone_bands,
+                    *amplitude_depth,
+                    number_channels,
+                )
+            }
         }
     }
 
@@ -534,6 +587,11 @@ impl JSONDecoderProperties {
             ) => Ok(WrappedIOData::ImageFilteringSettings(
                 ImageFilteringSettings::default(),
             )),
+            JSONDecoderProperties::ToneBands(number_tone_bands, _amplitude_depth) => {
+                Ok(WrappedIOData::MiscData(MiscData::new(
+                    &MiscDataDimensions::new(1, *number_tone_bands, 1)?,
+                )?))
+            }
         }
     }
 }
@@ -1,8 +1,13 @@
 use crate::caching::MotorDeviceCache;
 use crate::caching::SensorDeviceCache;
 use crate::configuration::jsonable::JSONInputOutputDefinition;
+use crate::data_types::{TextTokenDecoder, TextTokenizer};
 use crate::feedbacks::{FeedBackRegistration, FeedbackRegistrar, FeedbackRegistrationTargets};
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelIndex, CorticalUnitIndex,
+};
 use feagi_structures::FeagiDataError;
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::{Arc, Mutex, MutexGuard};
 
@@ -23,6 +28,8 @@ pub struct ConnectorCache {
     sensor_cache: Arc<Mutex<SensorDeviceCache>>,
     motor_cache: Arc<Mutex<MotorDeviceCache>>,
     feedback_registrar: FeedbackRegistrar,
+    text_tokenizer: Option<TextTokenizer>,
+    text_decoders: HashMap<(CorticalUnitIndex, CorticalChannelIndex), TextTokenDecoder>,
 }
 
 impl Default for ConnectorCache {
@@ -37,6 +44,8 @@ impl ConnectorCache {
             sensor_cache: Arc::new(Mutex::new(SensorDeviceCache::new())),
             motor_cache: Arc::new(Mutex::new(MotorDeviceCache::new())),
             feedback_registrar: FeedbackRegistrar::new(),
+            text_tokenizer: None,
+            text_decoders: HashMap::new(),
         }
     }
 
@@ -102,8 +111,69 @@ impl ConnectorCache {
         // imported motor/sensor caches.
         self.feedback_registrar
             .reload_all_from_self(self.get_sensor_cache_ref(), self.get_motor_cache_ref())?;
+        self.text_decoders.clear();
         Ok(())
     }
+
+    //region Text
+
+    /// Sets the vocabulary used to send and receive text, replacing any previous one.
+    pub fn set_text_tokenizer(&mut self, text_tokenizer: TextTokenizer) {
+        self.text_tokenizer = Some(text_tokenizer);
+        self.text_decoders.clear();
+    }
+
+    pub fn get_text_tokenizer(&self) -> Option<&TextTokenizer> {
+        self.text_tokenizer.as_ref()
+    }
+
+    /// Tokenizes text and queues it on a registered text input channel, to be sent one token
+    /// per burst.
+    pub fn write_text(
+        &self,
+        unit: CorticalUnitIndex,
+        channel: CorticalChannelIndex,
+        text: &str,
+    ) -> Result<(), FeagiDataError> {
+        let tokens = self.try_get_text_tokenizer()?.encode(text)?;
+        self.get_sensor_cache()
+            .text_english_input_queue_tokens(unit, channel, &tokens)
+    }
+
+    /// Returns the text received on a registered text output channel since the last read.
+    pub fn read_text(
+        &mut self,
+        unit: CorticalUnitIndex,
+        channel: CorticalChannelIndex,
+    ) -> Result<String, FeagiDataError> {
+        let tokens = self
+            .get_motor_cache()
+            .text_english_output_read_tokens(unit, channel)?;
+        let text_tokenizer = self
+            .text_tokenizer
+            .as_ref()
+            .ok_or_else(Self::no_text_tokenizer_error)?;
+        let text_decoder = self.text_decoders.entry((unit, channel)).or_default();
+        let mut text = String::new();
+        for token in tokens {
+            text.push_str(&text_decoder.decode_token(text_tokenizer, token)?);
+        }
+        Ok(text)
+    }
+
+    fn try_get_text_tokenizer(&self) -> Result<&TextTokenizer, FeagiDataError> {
+        self.text_tokenizer
+            .as_ref()
+            .ok_or_else(Self::no_text_tokenizer_error)
+    }
+
+    fn no_text_tokenizer_error() -> FeagiDataError {
+        FeagiDataError::BadParameters(
+            "No text tokenizer is set! Call set_text_tokenizer first.".into(),
+        )
+    }
+
+    //endregion
 }
 
 impl fmt::Display for ConnectorCache {
@@ -1,7 +1,139 @@
+use crate::data_types::{Percentage, SignedPercentage};
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
 use feagi_structures::define_index;
+use feagi_structures::FeagiDataError;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 
 define_index!(
     PipelineStagePropertyIndex,
     u32,
     "Index for a stage / stage property within a pipeline."
 );
+
+/// The scalar type a signal conditioning stage reads and writes.
+///
+/// Scalar stages do their math on plain floats, and use this to convert from and to the
+/// wrapped data flowing through the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScalarSignalType {
+    /// [`Percentage`], from 0 to 1
+    Percentage,
+    /// [`SignedPercentage`], from -1 to 1
+    SignedPercentage,
+}
+
+impl ScalarSignalType {
+    pub fn get_wrapped_io_type(&self) -> WrappedIOType {
+        match self {
+            ScalarSignalType::Percentage => WrappedIOType::Percentage,
+            ScalarSignalType::SignedPercentage => WrappedIOType::SignedPercentage,
+        }
+    }
+
+    /// Returns the range of values the signal can hold.
+    pub fn get_value_range(&self) -> RangeInclusive<f32> {
+        match self {
+            ScalarSignalType::Percentage => 0.0..=1.0,
+            ScalarSignalType::SignedPercentage => -1.0..=1.0,
+        }
+    }
+
+    /// Returns an error if the given range is reversed or does not fit within this signal.
+    pub fn verify_range_within_signal(
+        &self,
+        range: &RangeInclusive<f32>,
+        range_name: &str,
+    ) -> Result<(), FeagiDataError> {
+        let signal_range = self.get_value_range();
+        if !(range.start() <= range.end()
+            && signal_range.contains(range.start())
+            && signal_range.contains(range.end()))
+        {
+            return Err(FeagiDataError::BadParameters(format!(
+                "{} {:?} must be an increasing range within {:?} for {:?} signals!",
+                range_name, range, signal_range, self
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads the signal value out of wrapped data of this type.
+    pub fn read_value(&self, data: &WrappedIOData) -> Result<f32, FeagiDataError> {
+        match (self, data) {
+            (ScalarSignalType::Percentage, WrappedIOData::Percentage(value)) => {
+                Ok(value.get_as_0_1())
+            }
+            (ScalarSignalType::SignedPercentage, WrappedIOData::SignedPercentage(value)) => {
+                Ok(value.get_as_m1_1())
+            }
+            _ => Err(FeagiDataError::BadParameters(format!(
+                "Expected {:?} data but got {}!",
+                self,
+                WrappedIOType::from(data)
+            ))),
+        }
+    }
+
+    /// Creates wrapped data of this type, clamping the value into the signal's range.
+    pub fn create_wrapped_value(&self, value: f32) -> WrappedIOData {
+        let range = self.get_value_range();
+        let value = value.clamp(*range.start(), *range.end());
+        match self {
+            ScalarSignalType::Percentage => {
+                WrappedIOData::Percentage(Percentage::new_from_0_1_unchecked(value))
+            }
+            ScalarSignalType::SignedPercentage => {
+                WrappedIOData::SignedPercentage(SignedPercentage::new_from_m1_1_unchecked(value))
+            }
+        }
+    }
+}
+
+/// Which updated channels of a sensory unit are encoded to neurons each burst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum SensoryEncodingMode {
+    /// Channels written to since the previous burst are encoded
+    #[default]
+    Updated,
+    /// Channels written to since the previous burst are encoded only if their processed value
+    /// differs from the one last encoded
+    ChangeOnly,
+}
+
+/// Controls which channels of a sensory unit are encoded to neurons each burst.
+///
+/// Channels that are not encoded send no neurons at all, so FEAGI only sees fresh data. An
+/// optional keep-alive re-encodes the latest value of channels that have not been encoded for
+/// a while, for devices that only report when something changes. Channels never written to
+/// are not kept alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct SensoryEncodingSettings {
+    mode: SensoryEncodingMode,
+    keep_alive_ms: Option<u32>,
+}
+
+impl SensoryEncodingSettings {
+    pub fn new(
+        mode: SensoryEncodingMode,
+        keep_alive_ms: Option<u32>,
+    ) -> Result<Self, FeagiDataError> {
+        if keep_alive_ms == Some(0) {
+            return Err(FeagiDataError::BadParameters(
+                "Keep alive interval must be greater than 0 milliseconds!".into(),
+            ));
+        }
+        Ok(SensoryEncodingSettings {
+            mode,
+            keep_alive_ms,
+        })
+    }
+
+    pub fn get_mode(&self) -> SensoryEncodingMode {
+        self.mode
+    }
+
+    pub fn get_keep_alive_ms(&self) -> Option<u32> {
+        self.keep_alive_ms
+    }
+}
@@ -13,6 +13,8 @@ mod pipeline_stage_conversions;
 mod pipeline_stage_properties;
 pub mod stages;
 
-pub use descriptors::PipelineStagePropertyIndex;
+pub use descriptors::{
+    PipelineStagePropertyIndex, ScalarSignalType, SensoryEncodingMode, SensoryEncodingSettings,
+};
 pub(crate) use pipeline_stage_conversions::stage_properties_to_stages;
 pub use pipeline_stage_properties::PipelineStageProperties;
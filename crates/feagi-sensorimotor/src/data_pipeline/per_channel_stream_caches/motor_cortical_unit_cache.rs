@@ -89,8 +89,11 @@ impl MotorCorticalUnitCache {
                 .get_mut(index)
                 .unwrap();
 
-            pipeline_runner
-                .try_update_all_stage_properties(device_group.pipeline_stages.clone())?;
+            // Use replace_all_stages when importing from JSON since we're setting up a new pipeline
+            // This works even when pipeline is empty (0 stages) unlike try_update_all_stage_properties
+            if !device_group.pipeline_stages.is_empty() {
+                pipeline_runner.try_replace_all_stages(device_group.pipeline_stages.clone())?;
+            }
             pipeline_runner.set_channel_friendly_name(device_group.friendly_name.clone());
             pipeline_runner.set_channel_index_override(device_group.channel_index_override);
             pipeline_runner.set_json_device_properties(device_group.device_properties.clone());
@@ -109,6 +112,7 @@ impl MotorCorticalUnitCache {
             cortical_unit_index,
             io_configuration_flags: self.io_configuration_flags.clone(),
             device_grouping: self.get_all_device_grouping(),
+            encoding_settings: None,
         };
         (json_unit_definition, encoder_properties)
     }
@@ -367,17 +371,38 @@ impl MotorCorticalUnitCache {
             )?; // Only writes to cache, does not process
         self.pipeline_runners
             .par_iter_mut()
-            .zip(&self.has_channel_been_updated)
+            .zip(self.has_channel_been_updated.par_iter_mut())
             .try_for_each(|(pipeline_runner, has_channel_been_updated)| {
                 if *has_channel_been_updated {
                     _ = pipeline_runner.process_cached_decoded_motor_value(time_of_decode)?;
                     // Don't do call backs here, we want everything to be done first
+                } else {
+                    // Channels without fresh data still give time based stages (watchdogs) a go
+                    *has_channel_been_updated = pipeline_runner.process_idle(time_of_decode)?;
                 }
                 Ok(())
             })?;
         Ok(())
     }
 
+    /// Runs time based stages (such as watchdogs) on all channels without new neuron data,
+    /// and runs callbacks on the channels whose values changed as a result.
+    pub(crate) fn try_process_idle_and_do_callbacks(
+        &mut self,
+        time_of_check: Instant,
+    ) -> Result<(), FeagiDataError> {
+        self.pipeline_runners
+            .par_iter_mut()
+            .zip(self.has_channel_been_updated.par_iter_mut())
+            .try_for_each(|(pipeline_runner, has_channel_been_updated)| {
+                *has_channel_been_updated = pipeline_runner.process_idle(time_of_check)?;
+                Ok::<(), FeagiDataError>(())
+            })?;
+        self.try_run_callbacks_on_changed_channels()?;
+        self.has_channel_been_updated.fill(false);
+        Ok(())
+    }
+
     //region Internal
 
     #[inline]
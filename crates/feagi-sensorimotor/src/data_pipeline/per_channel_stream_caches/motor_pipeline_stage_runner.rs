@@ -143,6 +143,27 @@ impl MotorPipelineStageRunner {
         Ok(self.get_postprocessed_motor_value()) // Return the output from the last processor
     }
 
+    /// Lets time based stages react to no new decoded value arriving. Once a stage changes its
+    /// output, the stages after it process that output as usual. Returns true if the
+    /// postprocessed value changed.
+    pub fn process_idle(&mut self, time_of_check: Instant) -> Result<bool, FeagiDataError> {
+        let mut has_changed = false;
+        for i in 0..self.pipeline_stages.len() {
+            if has_changed {
+                let (left, right) = self.pipeline_stages.split_at_mut(i);
+                let previous_output = left[i - 1].get_most_recent_output();
+                right[0].process_new_input(previous_output, time_of_check)?;
+            } else {
+                has_changed = self.pipeline_stages[i].process_idle(time_of_check)?;
+            }
+        }
+
+        if has_changed {
+            self.last_instant_data_processed = time_of_check;
+        }
+        Ok(has_changed)
+    }
+
     //endregion
 
     //region Pipeline Stages (delegating to trait)
@@ -194,7 +194,7 @@ pub(crate) trait PipelineStageRunner {
             self.get_direction(),
         )?;
         self.get_stages_mut_internal()[*replacing_at_index as usize] =
-            new_pipeline_stage_properties.create_stage();
+            new_pipeline_stage_properties.create_stage()?;
         Ok(())
     }
 
@@ -4,7 +4,10 @@ use crate::configuration::jsonable::{
 use crate::data_pipeline::per_channel_stream_caches::{
     PipelineStageRunner, SensoryPipelineStageRunner,
 };
-use crate::data_pipeline::{PipelineStageProperties, PipelineStagePropertyIndex};
+use crate::data_pipeline::{
+    PipelineStageProperties, PipelineStagePropertyIndex, SensoryEncodingMode,
+    SensoryEncodingSettings,
+};
 use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPEncoder;
 use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
 use feagi_structures::genomic::cortical_area::descriptors::{
@@ -13,7 +16,7 @@ use feagi_structures::genomic::cortical_area::descriptors::{
 use feagi_structures::genomic::SensoryCorticalUnit;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::FeagiDataError;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Manages multiple sensory data streams with independent processing pipelines per channel.
 ///
@@ -25,6 +28,8 @@ use std::time::Instant;
 /// - `neuron_encoder`: Encoder that converts processed data into neuron voxel representations
 /// - `pipeline_runners`: Collection of pipeline runners, one per cortical channel
 /// - `last_update_time`: Timestamp of the most recent update across all channels
+/// - `encoding_settings`: Which channels are encoded each burst
+/// - `last_encoded`: Per channel record of the last encoding, to support change-only and keep-alive
 
 #[derive(Debug)]
 pub(crate) struct SensoryCorticalUnitCache {
@@ -33,6 +38,15 @@ pub(crate) struct SensoryCorticalUnitCache {
     pipeline_runners: Vec<SensoryPipelineStageRunner>,
     last_update_time: Instant,
     device_friendly_name: Option<String>,
+    encoding_settings: SensoryEncodingSettings,
+    last_encoded: Vec<LastEncodedChannel>,
+}
+
+/// What was last encoded for a single channel.
+#[derive(Debug, Default)]
+struct LastEncodedChannel {
+    instant: Option<Instant>,
+    value: Option<WrappedIOData>, // Only kept in change-only mode
 }
 
 impl SensoryCorticalUnitCache {
@@ -54,6 +68,10 @@ impl SensoryCorticalUnitCache {
             pipeline_runners,
             last_update_time: Instant::now(),
             device_friendly_name: None,
+            encoding_settings: SensoryEncodingSettings::default(),
+            last_encoded: std::iter::repeat_with(LastEncodedChannel::default)
+                .take(*number_channels as usize)
+                .collect(),
         })
     }
 
@@ -84,6 +102,13 @@ impl SensoryCorticalUnitCache {
 
         let _ =
             sensory_cortical_unit_cache.set_friendly_name(unit_definition.friendly_name.clone());
+        if let Some(encoding_settings) = unit_definition.encoding_settings {
+            // Rebuild to validate the deserialized settings
+            sensory_cortical_unit_cache.set_encoding_settings(SensoryEncodingSettings::new(
+                encoding_settings.get_mode(),
+                encoding_settings.get_keep_alive_ms(),
+            )?);
+        }
 
         // Update all the channels
         for (index, device_group) in unit_definition.device_grouping.iter().enumerate() {
@@ -115,6 +140,11 @@ impl SensoryCorticalUnitCache {
             cortical_unit_index,
             io_configuration_flags: self.io_configuration_flags.clone(),
             device_grouping: self.get_all_device_grouping(),
+            encoding_settings: if self.encoding_settings == SensoryEncodingSettings::default() {
+                None
+            } else {
+                Some(self.encoding_settings)
+            },
         };
         (json_unit_definition, encoder_properties)
     }
@@ -401,16 +431,32 @@ impl SensoryCorticalUnitCache {
         Ok(())
     }
 
+    pub fn get_encoding_settings(&self) -> SensoryEncodingSettings {
+        self.encoding_settings
+    }
+
+    /// Sets which channels are encoded each burst. Forgets what was last encoded, so every
+    /// channel is treated as never encoded before.
+    pub fn set_encoding_settings(&mut self, encoding_settings: SensoryEncodingSettings) {
+        self.encoding_settings = encoding_settings;
+        self.last_encoded
+            .iter_mut()
+            .for_each(|last_encoded| *last_encoded = LastEncodedChannel::default());
+    }
+
     //endregion
 
     /// Encodes recently updated sensor data into neuron voxel representations.
     ///
-    /// Uses the configured neuron encoder to convert processed pipeline data from all
-    /// channels into neuron voxel data. This updates the provided neuron data structure
-    /// with encoded representations of the sensor inputs.
+    /// Uses the configured neuron encoder to convert processed pipeline data into neuron voxel
+    /// data. Only channels that were updated since the previous burst are encoded (and in
+    /// change-only mode, only if their value changed since it was last encoded). If a keep-alive
+    /// is set, channels that were written at least once and not encoded for that long are
+    /// encoded again regardless. All other channels send no neurons.
     ///
     /// # Arguments
     /// * `neuron_data` - Neuron voxel data structure to update (should be cleared beforehand)
+    /// * `time_of_previous_burst` - Timestamp of the previous encoding burst
     /// * `time_of_burst` - Timestamp for this encoding burst
     ///
     /// # Returns
@@ -422,17 +468,36 @@ impl SensoryCorticalUnitCache {
     pub(crate) fn update_neuron_data_with_recently_updated_cached_sensor_data(
         &mut self,
         neuron_data: &mut CorticalMappedXYZPNeuronVoxels,
+        time_of_previous_burst: Instant,
         time_of_burst: Instant,
     ) -> Result<(), FeagiDataError> {
-        // TODO We need a new trait method to just have all channels cleared if not up to date
+        let channels_to_encode = self.get_channels_to_encode(time_of_previous_burst, time_of_burst);
+        if !channels_to_encode.contains(&true) {
+            return Ok(()); // Nothing to send this burst
+        }
+
         // Note: We expect neuron data to be cleared before this step
         self.neuron_encoder
             .write_neuron_data_multi_channel_from_processed_cache(
                 &self.pipeline_runners,
-                time_of_burst,
+                &channels_to_encode,
                 neuron_data,
             )?;
 
+        let keep_value = self.encoding_settings.get_mode() == SensoryEncodingMode::ChangeOnly;
+        for ((last_encoded, pipeline_runner), _) in self
+            .last_encoded
+            .iter_mut()
+            .zip(self.pipeline_runners.iter())
+            .zip(channels_to_encode.iter())
+            .filter(|(_, encoded)| **encoded)
+        {
+            last_encoded.instant = Some(time_of_burst);
+            if keep_value {
+                last_encoded.value = Some(pipeline_runner.get_postprocessed_sensor_value().clone());
+            }
+        }
+
         Ok(())
     }
 
@@ -484,6 +549,48 @@ impl SensoryCorticalUnitCache {
         }
     }
 
+    /// Decides per channel whether it is encoded this burst, following the encoding settings.
+    fn get_channels_to_encode(
+        &self,
+        time_of_previous_burst: Instant,
+        time_of_burst: Instant,
+    ) -> Vec<bool> {
+        let keep_alive = self
+            .encoding_settings
+            .get_keep_alive_ms()
+            .map(|keep_alive_ms| Duration::from_millis(keep_alive_ms as u64));
+        let change_only = self.encoding_settings.get_mode() == SensoryEncodingMode::ChangeOnly;
+
+        self.pipeline_runners
+            .iter()
+            .zip(self.last_encoded.iter())
+            .map(|(pipeline_runner, last_encoded)| {
+                // Keep-alive only resends values that were actually written
+                if let Some(keep_alive) =
+                    keep_alive.filter(|_| pipeline_runner.has_processed_data())
+                {
+                    let keep_alive_due = match last_encoded.instant {
+                        Some(last_instant) => {
+                            time_of_burst.saturating_duration_since(last_instant) >= keep_alive
+                        }
+                        None => true,
+                    };
+                    if keep_alive_due {
+                        return true;
+                    }
+                }
+                if pipeline_runner.get_last_processed_instant() <= time_of_previous_burst {
+                    return false; // Not updated since the previous burst (which includes its own writes)
+                }
+                if change_only {
+                    return last_encoded.value.as_ref()
+                        != Some(pipeline_runner.get_postprocessed_sensor_value());
+                }
+                true
+            })
+            .collect()
+    }
+
     #[allow(dead_code)]
     fn get_encoder_json_properties(&self) -> Result<JSONEncoderProperties, FeagiDataError> {
         Ok(self.neuron_encoder.get_as_properties())
@@ -12,6 +12,7 @@ use std::time::Instant;
 pub struct SensoryPipelineStageRunner {
     expected_processed_sensor_type: WrappedIOType, // The type expected to be output by the stage runner
     last_instant_data_processed: Instant,
+    has_processed_data: bool, // False until a value was written and processed
     pipeline_stages: Vec<Box<dyn PipelineStage>>,
     preprocessed_cached_value: WrappedIOData,
     channel_friendly_name: Option<String>,
@@ -78,6 +79,7 @@ impl SensoryPipelineStageRunner {
         Ok(SensoryPipelineStageRunner {
             expected_processed_sensor_type: type_to_be_outputted,
             last_instant_data_processed: Instant::now(),
+            has_processed_data: false,
             pipeline_stages: Vec::new(),
             preprocessed_cached_value: initial_sensory_cached_value,
             channel_friendly_name: None,
@@ -141,6 +143,11 @@ impl SensoryPipelineStageRunner {
             .get_most_recent_output()
     }
 
+    /// Returns true once a written value has been processed, as opposed to the initial cached value.
+    pub fn has_processed_data(&self) -> bool {
+        self.has_processed_data
+    }
+
     /// Processes the currently cached value through the pipeline stages (if available), then returns a reference to the result
     pub fn process_cached_sensor_value(
         &mut self,
@@ -152,6 +159,7 @@ impl SensoryPipelineStageRunner {
             // Without this, `last_instant_data_processed` stays at its initialization time,
             // causing updates to be skipped and stale scratch-space to be re-emitted.
             self.last_instant_data_processed = time_of_update;
+            self.has_processed_data = true;
             return Ok(&self.preprocessed_cached_value);
         }
 
@@ -167,6 +175,7 @@ impl SensoryPipelineStageRunner {
         }
 
         self.last_instant_data_processed = time_of_update;
+        self.has_processed_data = true;
         Ok(self.get_postprocessed_sensor_value()) // Return the output from the last processor
     }
 
@@ -53,6 +53,15 @@ pub trait PipelineStage: fmt::Display + Debug + Sync + Send + Any {
         time_of_input: Instant,
     ) -> Result<&WrappedIOData, FeagiDataError>;
 
+    /// Lets the stage react to time passing without any new input.
+    ///
+    /// Called on channels that were not updated, so time based stages (such as watchdogs) can
+    /// change their output. Returns true if the most recent output changed, in which case the
+    /// following stages are given it as new input. Most stages do nothing here.
+    fn process_idle(&mut self, _time_of_check: Instant) -> Result<bool, FeagiDataError> {
+        Ok(false)
+    }
+
     fn clone_box(&self) -> Box<dyn PipelineStage>;
 
     /// Provide access to `Any` trait for downcasting
@@ -10,7 +10,7 @@ pub(crate) fn stage_properties_to_stages(
     let mut output: Vec<Box<dyn PipelineStage>> =
         Vec::with_capacity(pipeline_stage_properties.len());
     for properties in pipeline_stage_properties.iter() {
-        output.push(properties.create_stage());
+        output.push(properties.create_stage()?);
     }
     Ok(output)
 }
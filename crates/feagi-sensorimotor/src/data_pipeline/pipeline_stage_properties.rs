@@ -4,10 +4,18 @@
 //! Properties are serializable and can be dynamically updated at runtime.
 
 use crate::data_pipeline::stages::{
-    ImageFrameProcessorStage, ImageFrameQuickDiffStage, ImageFrameSegmentatorStage,
-    ImagePixelValueCountThresholdStage,
+    AudioMelFilterbankStage, AudioSpectrogramStage, AudioToneSynthesizerStage,
+    ImageColorBlobMaskStage, ImageEdgeDetectorStage, ImageFrameProcessorStage,
+    ImageFrameQuickDiffStage, ImageFrameSegmentatorStage, ImageLogPolarTransformerStage,
+    ImageMotionDetectorStage, ImagePixelValueCountThresholdStage, MotorSafetyLimiterStage,
+    ScalarClampStage, ScalarDeadbandStage, ScalarExponentialAverageStage, ScalarKalmanFilterStage,
+    ScalarLinearScaleStage, ScalarRateLimitStage, ScalarRollingAverageStage,
 };
-use crate::data_types::descriptors::{ImageFrameProperties, SegmentedImageFrameProperties};
+use crate::data_pipeline::ScalarSignalType;
+use crate::data_types::descriptors::{
+    AudioFrameProperties, ImageFrameProperties, MiscDataDimensions, SegmentedImageFrameProperties,
+};
+use crate::data_types::processing::{feature_map_properties, EdgeDetectionMethod, MotionDirection};
 use crate::data_types::{GazeProperties, ImageFrameProcessor, ImageFrameSegmentator, Percentage};
 use crate::wrapped_io_data::WrappedIOType;
 use std::ops::RangeInclusive;
@@ -36,7 +44,7 @@ use std::ops::RangeInclusive;
 ///         } => {
 ///             input_type: WrappedIOType::ImageFrame(Some(*transformer_definition.get_input_image_properties())),
 ///             output_type: WrappedIOType::ImageFrame(Some(transformer_definition.get_output_image_properties())),
-///             create_stage: ImageFrameProcessorStage::new_box(transformer_definition.clone()).unwrap(),
+///             create_stage: ImageFrameProcessorStage::new_box(transformer_definition.clone())?,
 ///             display: ("ImageFrameProcessor(transformer: {:?})", transformer_definition),
 ///         },
 ///
@@ -101,12 +109,15 @@ macro_rules! define_pipeline_stage_properties_enum {
             }
 
             /// Creates the corresponding pipeline stage from these properties.
+            ///
+            /// Returns an error if the properties are not valid for the stage, such as
+            /// properties deserialized from an agent registration.
             #[allow(unused_variables)]
-            pub fn create_stage(&self) -> Box<dyn $crate::data_pipeline::pipeline_stage::PipelineStage> {
+            pub fn create_stage(&self) -> Result<Box<dyn $crate::data_pipeline::pipeline_stage::PipelineStage>, feagi_structures::FeagiDataError> {
                 match self {
                     $(
                         Self::$variant_name { $($field_name),* } => {
-                            $create_stage_expr
+                            Ok($create_stage_expr)
                         }
                     ),*
                 }
@@ -157,7 +168,7 @@ define_pipeline_stage_properties_enum! {
         } => {
             input_type: WrappedIOType::ImageFrame(Some(*transformer_definition.get_input_image_properties())),
             output_type: WrappedIOType::ImageFrame(Some(transformer_definition.get_output_image_properties())),
-            create_stage: ImageFrameProcessorStage::new_box(transformer_definition.clone()).unwrap(),
+            create_stage: ImageFrameProcessorStage::new_box(transformer_definition.clone())?,
             display: ("ImageFrameProcessor(transformer: {:?})", transformer_definition),
         },
 
@@ -172,11 +183,28 @@ define_pipeline_stage_properties_enum! {
             create_stage: ImageFrameSegmentatorStage::new_box(
                 *input_image_properties,
                 *output_image_properties,
-                ImageFrameSegmentator::new(*input_image_properties, *output_image_properties, *segmentation_gaze).unwrap()
-            ).unwrap(),
+                ImageFrameSegmentator::new(*input_image_properties, *output_image_properties, *segmentation_gaze)?
+            )?,
             display: ("ImageFrameSegmentator(input: {:?}, output: {:?}, gaze: {:?})", input_image_properties, output_image_properties, segmentation_gaze),
         },
 
+        /// Properties for ImageLogPolarTransformerStage that resamples an image into a log-polar
+        /// map, with wedges along X and rings along Y, around a fovea placed by the gaze.
+        ImageLogPolarTransformer {
+            input_image_properties: ImageFrameProperties,
+            output_image_properties: ImageFrameProperties,
+            foveation_gaze: GazeProperties,
+        } => {
+            input_type: WrappedIOType::ImageFrame(Some(*input_image_properties)),
+            output_type: WrappedIOType::ImageFrame(Some(*output_image_properties)),
+            create_stage: ImageLogPolarTransformerStage::new_box(
+                *input_image_properties,
+                *output_image_properties,
+                *foveation_gaze,
+            )?,
+            display: ("ImageLogPolarTransformer(input: {:?}, output: {:?}, gaze: {:?})", input_image_properties, output_image_properties, foveation_gaze),
+        },
+
         /// Properties for ImageFrameQuickDiffStage that configures quick difference detection
         /// between consecutive image frames.
         ImageQuickDiff {
@@ -190,7 +218,7 @@ define_pipeline_stage_properties_enum! {
                 *image_properties,
                 per_pixel_allowed_range.clone(),
                 acceptable_amount_of_activity_in_image.clone()
-            ).unwrap(),
+            )?,
             display: ("ImageQuickDiff(pixel_range: {:?}, activity: {:?}, image: {:?})", per_pixel_allowed_range, acceptable_amount_of_activity_in_image, image_properties),
         },
 
@@ -206,8 +234,240 @@ define_pipeline_stage_properties_enum! {
                 *input_definition,
                 inclusive_pixel_range.clone(),
                 acceptable_amount_of_activity_in_image.clone(),
-            ).unwrap(),
+            )?,
             display: ("ImagePixelValueCountThreshold(input: {:?}, pixel_range: {:?}, activity: {:?})", input_definition, inclusive_pixel_range, acceptable_amount_of_activity_in_image),
         },
+
+        /// Properties for ImageEdgeDetectorStage that outputs a grayscale Sobel or Canny edge map
+        /// of an image.
+        ImageEdgeDetector {
+            input_image_properties: ImageFrameProperties,
+            edge_detection_method: EdgeDetectionMethod,
+        } => {
+            input_type: WrappedIOType::ImageFrame(Some(*input_image_properties)),
+            output_type: WrappedIOType::ImageFrame(Some(feature_map_properties(input_image_properties))),
+            create_stage: ImageEdgeDetectorStage::new_box(
+                *input_image_properties,
+                *edge_detection_method,
+            )?,
+            display: ("ImageEdgeDetector(input: {:?}, method: {:?})", input_image_properties, edge_detection_method),
+        },
+
+        /// Properties for ImageMotionDetectorStage that outputs a grayscale map of the motion
+        /// between consecutive images, in any or a single direction.
+        ImageMotionDetector {
+            input_image_properties: ImageFrameProperties,
+            difference_threshold: u8,
+            direction: MotionDirection,
+        } => {
+            input_type: WrappedIOType::ImageFrame(Some(*input_image_properties)),
+            output_type: WrappedIOType::ImageFrame(Some(feature_map_properties(input_image_properties))),
+            create_stage: ImageMotionDetectorStage::new_box(
+                *input_image_properties,
+                *difference_threshold,
+                *direction,
+            )?,
+            display: ("ImageMotionDetector(input: {:?}, difference_threshold: {:?}, direction: {:?})", input_image_properties, difference_threshold, direction),
+        },
+
+        /// Properties for ImageColorBlobMaskStage that outputs a grayscale mask of the blobs of an
+        /// image within a range of HSV colors. Hue is in degrees, and wraps around if the range
+        /// start is larger than its end.
+        ImageColorBlobMask {
+            input_image_properties: ImageFrameProperties,
+            hue_range: RangeInclusive<f32>,
+            saturation_range: RangeInclusive<Percentage>,
+            value_range: RangeInclusive<Percentage>,
+            minimum_blob_area: u32,
+        } => {
+            input_type: WrappedIOType::ImageFrame(Some(*input_image_properties)),
+            output_type: WrappedIOType::ImageFrame(Some(feature_map_properties(input_image_properties))),
+            create_stage: ImageColorBlobMaskStage::new_box(
+                *input_image_properties,
+                hue_range.clone(),
+                saturation_range.clone(),
+                value_range.clone(),
+                *minimum_blob_area,
+            )?,
+            display: ("ImageColorBlobMask(input: {:?}, hue: {:?}, saturation: {:?}, value: {:?}, minimum_blob_area: {:?})", input_image_properties, hue_range, saturation_range, value_range, minimum_blob_area),
+        },
+
+        /// Properties for AudioSpectrogramStage that computes a short-time FFT magnitude spectrogram
+        /// (time along x, frequency bins along y) of an audio frame.
+        AudioSpectrogram {
+            input_audio_properties: AudioFrameProperties,
+            window_size: u32,
+            hop_size: u32,
+        } => {
+            input_type: WrappedIOType::AudioFrame(Some(*input_audio_properties)),
+            // Invalid properties have no output dimensions, and fail in create_stage instead
+            output_type: WrappedIOType::MiscData(
+                crate::data_types::processing::ShortTimeFourierTransform::new(*input_audio_properties, *window_size, *hop_size)
+                    .ok()
+                    .map(|transform| transform.get_output_dimensions())
+            ),
+            create_stage: AudioSpectrogramStage::new_box(
+                *input_audio_properties,
+                *window_size,
+                *hop_size,
+            )?,
+            display: ("AudioSpectrogram(input: {:?}, window_size: {:?}, hop_size: {:?})", input_audio_properties, window_size, hop_size),
+        },
+
+        /// Properties for AudioMelFilterbankStage that pools a spectrogram into log compressed mel bands
+        AudioMelFilterbank {
+            input_spectrogram_dimensions: MiscDataDimensions,
+            sample_rate_hz: u32,
+            number_mel_bands: u32,
+            min_frequency_hz: f32,
+            max_frequency_hz: f32,
+            dynamic_range_db: f32,
+        } => {
+            input_type: WrappedIOType::MiscData(Some(*input_spectrogram_dimensions)),
+            output_type: WrappedIOType::MiscData(
+                MiscDataDimensions::new(input_spectrogram_dimensions.width, *number_mel_bands, 1).ok()
+            ),
+            create_stage: AudioMelFilterbankStage::new_box(
+                *input_spectrogram_dimensions,
+                *sample_rate_hz,
+                *number_mel_bands,
+                *min_frequency_hz,
+                *max_frequency_hz,
+                *dynamic_range_db,
+            )?,
+            display: ("AudioMelFilterbank(input: {:?}, sample_rate: {:?}, bands: {:?}, range: {:?}-{:?} Hz, dynamic_range: {:?} dB)", input_spectrogram_dimensions, sample_rate_hz, number_mel_bands, min_frequency_hz, max_frequency_hz, dynamic_range_db),
+        },
+
+        /// Properties for AudioToneSynthesizerStage that renders per band tone amplitudes into audio
+        AudioToneSynthesizer {
+            output_audio_properties: AudioFrameProperties,
+            number_tone_bands: u32,
+            min_frequency_hz: f32,
+            max_frequency_hz: f32,
+        } => {
+            input_type: WrappedIOType::MiscData(
+                MiscDataDimensions::new(1, *number_tone_bands, 1).ok()
+            ),
+            output_type: WrappedIOType::AudioFrame(Some(*output_audio_properties)),
+            create_stage: AudioToneSynthesizerStage::new_box(
+                *output_audio_properties,
+                *number_tone_bands,
+                *min_frequency_hz,
+                *max_frequency_hz,
+            )?,
+            display: ("AudioToneSynthesizer(output: {:?}, bands: {:?}, range: {:?}-{:?} Hz)", output_audio_properties, number_tone_bands, min_frequency_hz, max_frequency_hz),
+        },
+
+        /// Properties for ScalarLinearScaleStage that maps a scalar from an input range onto an
+        /// output range, optionally converting between percentage types
+        ScalarLinearScale {
+            input_signal_type: ScalarSignalType,
+            input_range: RangeInclusive<f32>,
+            output_signal_type: ScalarSignalType,
+            output_range: RangeInclusive<f32>,
+        } => {
+            input_type: input_signal_type.get_wrapped_io_type(),
+            output_type: output_signal_type.get_wrapped_io_type(),
+            create_stage: ScalarLinearScaleStage::new_box(
+                *input_signal_type,
+                input_range.clone(),
+                *output_signal_type,
+                output_range.clone(),
+            )?,
+            display: ("ScalarLinearScale(input: {:?} {:?}, output: {:?} {:?})", input_signal_type, input_range, output_signal_type, output_range),
+        },
+
+        /// Properties for ScalarClampStage that clamps a scalar into an allowed range
+        ScalarClamp {
+            signal_type: ScalarSignalType,
+            allowed_range: RangeInclusive<f32>,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarClampStage::new_box(*signal_type, allowed_range.clone())?,
+            display: ("ScalarClamp(signal: {:?}, allowed_range: {:?})", signal_type, allowed_range),
+        },
+
+        /// Properties for ScalarDeadbandStage that zeroes out scalars smaller than the deadband
+        ScalarDeadband {
+            signal_type: ScalarSignalType,
+            deadband: f32,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarDeadbandStage::new_box(*signal_type, *deadband)?,
+            display: ("ScalarDeadband(signal: {:?}, deadband: {:?})", signal_type, deadband),
+        },
+
+        /// Properties for ScalarRollingAverageStage that averages the last few scalars
+        ScalarRollingAverage {
+            signal_type: ScalarSignalType,
+            window_length: u32,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarRollingAverageStage::new_box(*signal_type, *window_length)?,
+            display: ("ScalarRollingAverage(signal: {:?}, window_length: {:?})", signal_type, window_length),
+        },
+
+        /// Properties for ScalarExponentialAverageStage that smooths scalars with an exponential
+        /// moving average
+        ScalarExponentialAverage {
+            signal_type: ScalarSignalType,
+            smoothing_factor: f32,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarExponentialAverageStage::new_box(*signal_type, *smoothing_factor)?,
+            display: ("ScalarExponentialAverage(signal: {:?}, smoothing_factor: {:?})", signal_type, smoothing_factor),
+        },
+
+        /// Properties for ScalarRateLimitStage that limits how fast a scalar may change
+        ScalarRateLimit {
+            signal_type: ScalarSignalType,
+            max_change_per_second: f32,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarRateLimitStage::new_box(*signal_type, *max_change_per_second)?,
+            display: ("ScalarRateLimit(signal: {:?}, max_change_per_second: {:?})", signal_type, max_change_per_second),
+        },
+
+        /// Properties for ScalarKalmanFilterStage that filters scalar noise with a 1D Kalman filter
+        ScalarKalmanFilter {
+            signal_type: ScalarSignalType,
+            process_noise: f32,
+            measurement_noise: f32,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: ScalarKalmanFilterStage::new_box(*signal_type, *process_noise, *measurement_noise)?,
+            display: ("ScalarKalmanFilter(signal: {:?}, process_noise: {:?}, measurement_noise: {:?})", signal_type, process_noise, measurement_noise),
+        },
+
+        /// Properties for MotorSafetyLimiterStage that keeps motor commands within position,
+        /// velocity and acceleration limits, with a watchdog and a latching emergency stop
+        MotorSafetyLimiter {
+            signal_type: ScalarSignalType,
+            position_range: RangeInclusive<f32>,
+            max_velocity_per_second: Option<f32>,
+            max_acceleration_per_second_squared: Option<f32>,
+            watchdog_timeout_ms: Option<u32>,
+            safe_value: f32,
+            emergency_stop: bool,
+        } => {
+            input_type: signal_type.get_wrapped_io_type(),
+            output_type: signal_type.get_wrapped_io_type(),
+            create_stage: MotorSafetyLimiterStage::new_box(
+                *signal_type,
+                position_range.clone(),
+                *max_velocity_per_second,
+                *max_acceleration_per_second_squared,
+                *watchdog_timeout_ms,
+                *safe_value,
+                *emergency_stop,
+            )?,
+            display: ("MotorSafetyLimiter(signal: {:?}, position_range: {:?}, max_velocity: {:?}, max_acceleration: {:?}, watchdog_timeout_ms: {:?}, safe_value: {:?}, emergency_stop: {:?})", signal_type, position_range, max_velocity_per_second, max_acceleration_per_second_squared, watchdog_timeout_ms, safe_value, emergency_stop),
+        },
     }
 }
@@ -0,0 +1,172 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::MiscDataDimensions;
+use crate::data_types::processing::MelFilterbank;
+use crate::data_types::MiscData;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Pools a linear frequency spectrogram (as output by
+/// [`AudioSpectrogramStage`](super::AudioSpectrogramStage)) into log compressed mel bands.
+#[derive(Debug, Clone)]
+pub struct AudioMelFilterbankStage {
+    input_spectrogram_dimensions: MiscDataDimensions,
+    sample_rate_hz: u32,
+    number_mel_bands: u32,
+    min_frequency_hz: f32,
+    max_frequency_hz: f32,
+    dynamic_range_db: f32,
+    filterbank: MelFilterbank,
+    cached: WrappedIOData,
+}
+
+impl Display for AudioMelFilterbankStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "AudioMelFilterbankStage(input: {}, bands: {}, range: {}-{} Hz, dynamic_range: {} dB)",
+            self.input_spectrogram_dimensions,
+            self.number_mel_bands,
+            self.min_frequency_hz,
+            self.max_frequency_hz,
+            self.dynamic_range_db
+        )
+    }
+}
+
+impl PipelineStage for AudioMelFilterbankStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.input_spectrogram_dimensions))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.filterbank.get_output_dimensions()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &MiscData = value.try_into()?;
+        let write_to: &mut MiscData = (&mut self.cached).try_into()?;
+
+        self.filterbank.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::AudioMelFilterbank {
+            input_spectrogram_dimensions: self.input_spectrogram_dimensions,
+            sample_rate_hz: self.sample_rate_hz,
+            number_mel_bands: self.number_mel_bands,
+            min_frequency_hz: self.min_frequency_hz,
+            max_frequency_hz: self.max_frequency_hz,
+            dynamic_range_db: self.dynamic_range_db,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::AudioMelFilterbank {
+                input_spectrogram_dimensions,
+                sample_rate_hz,
+                number_mel_bands,
+                min_frequency_hz,
+                max_frequency_hz,
+                dynamic_range_db,
+            } => {
+                if input_spectrogram_dimensions != self.input_spectrogram_dimensions
+                    || number_mel_bands != self.number_mel_bands
+                {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the dimensions of an existing AudioMelFilterbankStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.filterbank = MelFilterbank::new(
+                    input_spectrogram_dimensions,
+                    sample_rate_hz,
+                    number_mel_bands,
+                    min_frequency_hz,
+                    max_frequency_hz,
+                    dynamic_range_db,
+                )?;
+                self.sample_rate_hz = sample_rate_hz;
+                self.min_frequency_hz = min_frequency_hz;
+                self.max_frequency_hz = max_frequency_hz;
+                self.dynamic_range_db = dynamic_range_db;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for AudioMelFilterbankStage".into(),
+            )),
+        }
+    }
+}
+
+impl AudioMelFilterbankStage {
+    pub fn new(
+        input_spectrogram_dimensions: MiscDataDimensions,
+        sample_rate_hz: u32,
+        number_mel_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+        dynamic_range_db: f32,
+    ) -> Result<Self, FeagiDataError> {
+        let filterbank = MelFilterbank::new(
+            input_spectrogram_dimensions,
+            sample_rate_hz,
+            number_mel_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+            dynamic_range_db,
+        )?;
+        let cached: MiscData = MiscData::new(&filterbank.get_output_dimensions())?;
+        Ok(AudioMelFilterbankStage {
+            input_spectrogram_dimensions,
+            sample_rate_hz,
+            number_mel_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+            dynamic_range_db,
+            filterbank,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_spectrogram_dimensions: MiscDataDimensions,
+        sample_rate_hz: u32,
+        number_mel_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+        dynamic_range_db: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(AudioMelFilterbankStage::new(
+            input_spectrogram_dimensions,
+            sample_rate_hz,
+            number_mel_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+            dynamic_range_db,
+        )?))
+    }
+}
@@ -0,0 +1,126 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::AudioFrameProperties;
+use crate::data_types::processing::ShortTimeFourierTransform;
+use crate::data_types::{AudioFrame, MiscData};
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Turns an audio frame into a linear frequency magnitude spectrogram (time along x,
+/// frequency bins along y) with a short-time Fourier transform.
+#[derive(Debug, Clone)]
+pub struct AudioSpectrogramStage {
+    transform: ShortTimeFourierTransform,
+    cached: WrappedIOData,
+}
+
+impl Display for AudioSpectrogramStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "AudioSpectrogramStage(input: {}, window_size: {}, hop_size: {})",
+            self.transform.get_input_properties(),
+            self.transform.get_window_size(),
+            self.transform.get_hop_size()
+        )
+    }
+}
+
+impl PipelineStage for AudioSpectrogramStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::AudioFrame(Some(*self.transform.get_input_properties()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.transform.get_output_dimensions()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &AudioFrame = value.try_into()?;
+        let write_to: &mut MiscData = (&mut self.cached).try_into()?;
+
+        self.transform.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::AudioSpectrogram {
+            input_audio_properties: *self.transform.get_input_properties(),
+            window_size: self.transform.get_window_size(),
+            hop_size: self.transform.get_hop_size(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::AudioSpectrogram {
+                input_audio_properties,
+                window_size,
+                hop_size,
+            } => {
+                let transform =
+                    ShortTimeFourierTransform::new(input_audio_properties, window_size, hop_size)?;
+                if transform.get_output_dimensions() != self.transform.get_output_dimensions() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the spectrogram dimensions of an existing AudioSpectrogramStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.transform = transform;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for AudioSpectrogramStage".into(),
+            )),
+        }
+    }
+}
+
+impl AudioSpectrogramStage {
+    pub fn new(
+        input_audio_properties: AudioFrameProperties,
+        window_size: u32,
+        hop_size: u32,
+    ) -> Result<Self, FeagiDataError> {
+        let transform =
+            ShortTimeFourierTransform::new(input_audio_properties, window_size, hop_size)?;
+        let cached: MiscData = MiscData::new(&transform.get_output_dimensions())?;
+        Ok(AudioSpectrogramStage {
+            transform,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_audio_properties: AudioFrameProperties,
+        window_size: u32,
+        hop_size: u32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(AudioSpectrogramStage::new(
+            input_audio_properties,
+            window_size,
+            hop_size,
+        )?))
+    }
+}
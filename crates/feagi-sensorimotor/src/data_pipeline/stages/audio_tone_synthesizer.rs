@@ -0,0 +1,141 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::AudioFrameProperties;
+use crate::data_types::processing::ToneSynthesizer;
+use crate::data_types::{AudioFrame, MiscData};
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Synthesizes an audio frame from per band tone amplitudes (as decoded from a speaker cortical
+/// area), with phase continuity from one frame to the next.
+#[derive(Debug, Clone)]
+pub struct AudioToneSynthesizerStage {
+    synthesizer: ToneSynthesizer,
+    cached: WrappedIOData,
+}
+
+impl Display for AudioToneSynthesizerStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "AudioToneSynthesizerStage(output: {}, bands: {}, range: {}-{} Hz)",
+            self.synthesizer.get_output_properties(),
+            self.synthesizer.get_number_tone_bands(),
+            self.synthesizer.get_min_frequency_hz(),
+            self.synthesizer.get_max_frequency_hz()
+        )
+    }
+}
+
+impl PipelineStage for AudioToneSynthesizerStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.synthesizer.get_input_dimensions()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::AudioFrame(Some(*self.synthesizer.get_output_properties()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &MiscData = value.try_into()?;
+        let write_to: &mut AudioFrame = (&mut self.cached).try_into()?;
+
+        self.synthesizer.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::AudioToneSynthesizer {
+            output_audio_properties: *self.synthesizer.get_output_properties(),
+            number_tone_bands: self.synthesizer.get_number_tone_bands(),
+            min_frequency_hz: self.synthesizer.get_min_frequency_hz(),
+            max_frequency_hz: self.synthesizer.get_max_frequency_hz(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::AudioToneSynthesizer {
+                output_audio_properties,
+                number_tone_bands,
+                min_frequency_hz,
+                max_frequency_hz,
+            } => {
+                if output_audio_properties != *self.synthesizer.get_output_properties()
+                    || number_tone_bands != self.synthesizer.get_number_tone_bands()
+                {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the audio format or band count of an existing AudioToneSynthesizerStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.synthesizer = ToneSynthesizer::new(
+                    output_audio_properties,
+                    number_tone_bands,
+                    min_frequency_hz,
+                    max_frequency_hz,
+                )?;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for AudioToneSynthesizerStage".into(),
+            )),
+        }
+    }
+}
+
+impl AudioToneSynthesizerStage {
+    pub fn new(
+        output_audio_properties: AudioFrameProperties,
+        number_tone_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+    ) -> Result<Self, FeagiDataError> {
+        let synthesizer = ToneSynthesizer::new(
+            output_audio_properties,
+            number_tone_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+        )?;
+        let cached: AudioFrame = AudioFrame::new(&output_audio_properties)?;
+        Ok(AudioToneSynthesizerStage {
+            synthesizer,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        output_audio_properties: AudioFrameProperties,
+        number_tone_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(AudioToneSynthesizerStage::new(
+            output_audio_properties,
+            number_tone_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+        )?))
+    }
+}
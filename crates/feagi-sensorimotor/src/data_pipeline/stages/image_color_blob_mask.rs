@@ -0,0 +1,143 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::ImageFrameProperties;
+use crate::data_types::processing::ImageColorBlobMasker;
+use crate::data_types::{ImageFrame, Percentage};
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::time::Instant;
+
+/// Turns an RGB(A) image into a grayscale mask of the blobs within a range of HSV colors.
+#[derive(Debug, Clone)]
+pub struct ImageColorBlobMaskStage {
+    masker: ImageColorBlobMasker,
+    cached: WrappedIOData,
+}
+
+impl Display for ImageColorBlobMaskStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ImageColorBlobMaskStage(input: {}, hue: {:?}, saturation: {:?}, value: {:?}, minimum_blob_area: {})",
+            self.masker.get_input_properties(),
+            self.masker.get_hue_range(),
+            self.masker.get_saturation_range(),
+            self.masker.get_value_range(),
+            self.masker.get_minimum_blob_area()
+        )
+    }
+}
+
+impl PipelineStage for ImageColorBlobMaskStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(*self.masker.get_input_properties()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(self.masker.get_output_properties()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &ImageFrame = value.try_into()?;
+        let write_to: &mut ImageFrame = (&mut self.cached).try_into()?;
+
+        self.masker.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ImageColorBlobMask {
+            input_image_properties: *self.masker.get_input_properties(),
+            hue_range: self.masker.get_hue_range().clone(),
+            saturation_range: self.masker.get_saturation_range().clone(),
+            value_range: self.masker.get_value_range().clone(),
+            minimum_blob_area: self.masker.get_minimum_blob_area(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ImageColorBlobMask {
+                input_image_properties,
+                hue_range,
+                saturation_range,
+                value_range,
+                minimum_blob_area,
+            } => {
+                if input_image_properties != *self.masker.get_input_properties() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the input image properties of an existing ImageColorBlobMaskStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.masker
+                    .set_color_ranges(hue_range, saturation_range, value_range)?;
+                self.masker.set_minimum_blob_area(minimum_blob_area);
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ImageColorBlobMaskStage".into(),
+            )),
+        }
+    }
+}
+
+impl ImageColorBlobMaskStage {
+    pub fn new(
+        input_image_properties: ImageFrameProperties,
+        hue_range: RangeInclusive<f32>,
+        saturation_range: RangeInclusive<Percentage>,
+        value_range: RangeInclusive<Percentage>,
+        minimum_blob_area: u32,
+    ) -> Result<Self, FeagiDataError> {
+        let masker = ImageColorBlobMasker::new(
+            input_image_properties,
+            hue_range,
+            saturation_range,
+            value_range,
+            minimum_blob_area,
+        )?;
+        let cached = ImageFrame::new_from_image_frame_properties(&masker.get_output_properties())?;
+        Ok(ImageColorBlobMaskStage {
+            masker,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_image_properties: ImageFrameProperties,
+        hue_range: RangeInclusive<f32>,
+        saturation_range: RangeInclusive<Percentage>,
+        value_range: RangeInclusive<Percentage>,
+        minimum_blob_area: u32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ImageColorBlobMaskStage::new(
+            input_image_properties,
+            hue_range,
+            saturation_range,
+            value_range,
+            minimum_blob_area,
+        )?))
+    }
+}
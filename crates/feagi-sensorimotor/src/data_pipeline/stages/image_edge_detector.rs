@@ -0,0 +1,116 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::ImageFrameProperties;
+use crate::data_types::processing::{EdgeDetectionMethod, ImageEdgeDetector};
+use crate::data_types::ImageFrame;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Turns an image into a grayscale edge map with Sobel or Canny edge detection.
+#[derive(Debug, Clone)]
+pub struct ImageEdgeDetectorStage {
+    detector: ImageEdgeDetector,
+    cached: WrappedIOData,
+}
+
+impl Display for ImageEdgeDetectorStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ImageEdgeDetectorStage(input: {}, method: {:?})",
+            self.detector.get_input_properties(),
+            self.detector.get_method()
+        )
+    }
+}
+
+impl PipelineStage for ImageEdgeDetectorStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(*self.detector.get_input_properties()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(self.detector.get_output_properties()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &ImageFrame = value.try_into()?;
+        let write_to: &mut ImageFrame = (&mut self.cached).try_into()?;
+
+        self.detector.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ImageEdgeDetector {
+            input_image_properties: *self.detector.get_input_properties(),
+            edge_detection_method: *self.detector.get_method(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ImageEdgeDetector {
+                input_image_properties,
+                edge_detection_method,
+            } => {
+                if input_image_properties != *self.detector.get_input_properties() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the input image properties of an existing ImageEdgeDetectorStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.detector.set_method(edge_detection_method)
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ImageEdgeDetectorStage".into(),
+            )),
+        }
+    }
+}
+
+impl ImageEdgeDetectorStage {
+    pub fn new(
+        input_image_properties: ImageFrameProperties,
+        edge_detection_method: EdgeDetectionMethod,
+    ) -> Result<Self, FeagiDataError> {
+        let detector = ImageEdgeDetector::new(input_image_properties, edge_detection_method)?;
+        let cached =
+            ImageFrame::new_from_image_frame_properties(&detector.get_output_properties())?;
+        Ok(ImageEdgeDetectorStage {
+            detector,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_image_properties: ImageFrameProperties,
+        edge_detection_method: EdgeDetectionMethod,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ImageEdgeDetectorStage::new(
+            input_image_properties,
+            edge_detection_method,
+        )?))
+    }
+}
@@ -0,0 +1,127 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::ImageFrameProperties;
+use crate::data_types::processing::ImageLogPolarTransformer;
+use crate::data_types::{GazeProperties, ImageFrame};
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Resamples an image into a log-polar map around a fovea that can be moved with gaze.
+#[derive(Debug, Clone)]
+pub struct ImageLogPolarTransformerStage {
+    transformer: ImageLogPolarTransformer,
+    cached: WrappedIOData,
+}
+
+impl Display for ImageLogPolarTransformerStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ImageLogPolarTransformerStage(input: {}, output: {})",
+            self.transformer.get_input_properties(),
+            self.transformer.get_output_properties()
+        )
+    }
+}
+
+impl PipelineStage for ImageLogPolarTransformerStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(*self.transformer.get_input_properties()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(*self.transformer.get_output_properties()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &ImageFrame = value.try_into()?;
+        let write_to: &mut ImageFrame = (&mut self.cached).try_into()?;
+
+        self.transformer.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ImageLogPolarTransformer {
+            input_image_properties: *self.transformer.get_input_properties(),
+            output_image_properties: *self.transformer.get_output_properties(),
+            foveation_gaze: self.transformer.get_used_gaze(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ImageLogPolarTransformer {
+                input_image_properties,
+                output_image_properties,
+                foveation_gaze,
+            } => {
+                if input_image_properties != *self.transformer.get_input_properties()
+                    || output_image_properties != *self.transformer.get_output_properties()
+                {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the image properties of an existing ImageLogPolarTransformerStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.transformer.update_gaze(&foveation_gaze);
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ImageLogPolarTransformerStage".into(),
+            )),
+        }
+    }
+}
+
+impl ImageLogPolarTransformerStage {
+    pub fn new(
+        input_image_properties: ImageFrameProperties,
+        output_image_properties: ImageFrameProperties,
+        foveation_gaze: GazeProperties,
+    ) -> Result<Self, FeagiDataError> {
+        let transformer = ImageLogPolarTransformer::new(
+            input_image_properties,
+            output_image_properties,
+            foveation_gaze,
+        )?;
+        let cached = ImageFrame::new_from_image_frame_properties(&output_image_properties)?;
+        Ok(ImageLogPolarTransformerStage {
+            transformer,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_image_properties: ImageFrameProperties,
+        output_image_properties: ImageFrameProperties,
+        foveation_gaze: GazeProperties,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ImageLogPolarTransformerStage::new(
+            input_image_properties,
+            output_image_properties,
+            foveation_gaze,
+        )?))
+    }
+}
@@ -0,0 +1,125 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_types::descriptors::ImageFrameProperties;
+use crate::data_types::processing::{ImageMotionDetector, MotionDirection};
+use crate::data_types::ImageFrame;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Turns consecutive images into a grayscale map of motion, optionally in one direction only.
+#[derive(Debug, Clone)]
+pub struct ImageMotionDetectorStage {
+    detector: ImageMotionDetector,
+    cached: WrappedIOData,
+}
+
+impl Display for ImageMotionDetectorStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ImageMotionDetectorStage(input: {}, difference_threshold: {}, direction: {:?})",
+            self.detector.get_input_properties(),
+            self.detector.get_difference_threshold(),
+            self.detector.get_direction()
+        )
+    }
+}
+
+impl PipelineStage for ImageMotionDetectorStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(*self.detector.get_input_properties()))
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        WrappedIOType::ImageFrame(Some(self.detector.get_output_properties()))
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let read_from: &ImageFrame = value.try_into()?;
+        let write_to: &mut ImageFrame = (&mut self.cached).try_into()?;
+
+        self.detector.process(read_from, write_to)?;
+        Ok(self.get_most_recent_output())
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ImageMotionDetector {
+            input_image_properties: *self.detector.get_input_properties(),
+            difference_threshold: self.detector.get_difference_threshold(),
+            direction: self.detector.get_direction(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ImageMotionDetector {
+                input_image_properties,
+                difference_threshold,
+                direction,
+            } => {
+                if input_image_properties != *self.detector.get_input_properties() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the input image properties of an existing ImageMotionDetectorStage! Replace the stage instead.".into(),
+                    ));
+                }
+                self.detector.set_difference_threshold(difference_threshold);
+                self.detector.set_direction(direction);
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ImageMotionDetectorStage".into(),
+            )),
+        }
+    }
+}
+
+impl ImageMotionDetectorStage {
+    pub fn new(
+        input_image_properties: ImageFrameProperties,
+        difference_threshold: u8,
+        direction: MotionDirection,
+    ) -> Result<Self, FeagiDataError> {
+        let detector =
+            ImageMotionDetector::new(input_image_properties, difference_threshold, direction);
+        let cached =
+            ImageFrame::new_from_image_frame_properties(&detector.get_output_properties())?;
+        Ok(ImageMotionDetectorStage {
+            detector,
+            cached: cached.into(),
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_image_properties: ImageFrameProperties,
+        difference_threshold: u8,
+        direction: MotionDirection,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ImageMotionDetectorStage::new(
+            input_image_properties,
+            difference_threshold,
+            direction,
+        )?))
+    }
+}
@@ -3,12 +3,42 @@
 //! Provides actual processing stages that transform data flowing through
 //! the FEAGI connector pipeline. Each stage has corresponding properties
 //! defined in [`crate::data_pipeline::stage_properties`].
+mod audio_mel_filterbank;
+mod audio_spectrogram;
+mod audio_tone_synthesizer;
+mod image_color_blob_mask;
+mod image_edge_detector;
 mod image_frame_processor;
+mod image_log_polar_transformer;
+mod image_motion_detector;
 mod image_pixel_value_count_threshold;
 mod image_quick_diff;
 mod image_segmentor;
+mod motor_safety_limiter;
+mod scalar_clamp;
+mod scalar_deadband;
+mod scalar_exponential_average;
+mod scalar_kalman_filter;
+mod scalar_linear_scale;
+mod scalar_rate_limit;
+mod scalar_rolling_average;
 
+pub use audio_mel_filterbank::AudioMelFilterbankStage;
+pub use audio_spectrogram::AudioSpectrogramStage;
+pub use audio_tone_synthesizer::AudioToneSynthesizerStage;
+pub use image_color_blob_mask::ImageColorBlobMaskStage;
+pub use image_edge_detector::ImageEdgeDetectorStage;
 pub use image_frame_processor::ImageFrameProcessorStage;
+pub use image_log_polar_transformer::ImageLogPolarTransformerStage;
+pub use image_motion_detector::ImageMotionDetectorStage;
 pub use image_pixel_value_count_threshold::ImagePixelValueCountThresholdStage;
 pub use image_quick_diff::ImageFrameQuickDiffStage;
 pub use image_segmentor::ImageFrameSegmentatorStage;
+pub use motor_safety_limiter::MotorSafetyLimiterStage;
+pub use scalar_clamp::ScalarClampStage;
+pub use scalar_deadband::ScalarDeadbandStage;
+pub use scalar_exponential_average::ScalarExponentialAverageStage;
+pub use scalar_kalman_filter::ScalarKalmanFilterStage;
+pub use scalar_linear_scale::ScalarLinearScaleStage;
+pub use scalar_rate_limit::ScalarRateLimitStage;
+pub use scalar_rolling_average::ScalarRollingAverageStage;
@@ -0,0 +1,343 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+/// Keeps decoded motor commands within what the actuator can safely do.
+///
+/// - Commands are clamped to `position_range`.
+/// - If given, the output moves toward the command no faster than `max_velocity_per_second`,
+///   and speeds up / slows down no faster than `max_acceleration_per_second_squared`. The
+///   output keeps ramping toward the last command as time passes, even without new commands.
+/// - If given, the output falls back to `safe_value` once no new command has arrived for
+///   `watchdog_timeout_ms`, and stays there until commands resume.
+/// - While `emergency_stop` is set, the output is held at `safe_value` and commands are ignored.
+///   The stop is latched: it stays engaged until the stage properties are updated to release it.
+///
+/// Falling back to `safe_value` happens immediately, without the velocity and acceleration
+/// limits, so it should be a value the actuator can always jump to (such as zero speed). After
+/// falling back, the output ramps from `safe_value` toward new commands.
+///
+/// Ramping and the watchdog rely on time passing: motor channels without new data are checked
+/// whenever motor data is decoded, and on every tick of the agent client, which calls
+/// `MotorDeviceCache::tick_motor_pipelines`.
+#[derive(Debug, Clone)]
+pub struct MotorSafetyLimiterStage {
+    signal_type: ScalarSignalType,
+    position_range: RangeInclusive<f32>,
+    max_velocity_per_second: Option<f32>,
+    max_acceleration_per_second_squared: Option<f32>,
+    watchdog_timeout_ms: Option<u32>,
+    safe_value: f32,
+    emergency_stop: bool,
+    position: f32,
+    velocity: f32,
+    target: Option<f32>,             // None while falling back to the safe value
+    last_step_time: Option<Instant>, // None until the first step after falling back
+    last_input_time: Option<Instant>,
+    has_unreported_change: bool,
+    cached: WrappedIOData,
+}
+
+impl Display for MotorSafetyLimiterStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "MotorSafetyLimiterStage(signal: {:?}, position_range: {:?}, max_velocity: {:?}, max_acceleration: {:?}, watchdog_timeout_ms: {:?}, safe_value: {}, emergency_stop: {})",
+            self.signal_type,
+            self.position_range,
+            self.max_velocity_per_second,
+            self.max_acceleration_per_second_squared,
+            self.watchdog_timeout_ms,
+            self.safe_value,
+            self.emergency_stop
+        )
+    }
+}
+
+impl PipelineStage for MotorSafetyLimiterStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let command = self.signal_type.read_value(value)?;
+        self.has_unreported_change = false;
+        if self.emergency_stop {
+            return Ok(&self.cached);
+        }
+
+        self.last_input_time = Some(time_of_input);
+        self.target = Some(command.clamp(*self.position_range.start(), *self.position_range.end()));
+        self.step_toward_target(time_of_input);
+        Ok(&self.cached)
+    }
+
+    fn process_idle(&mut self, time_of_check: Instant) -> Result<bool, FeagiDataError> {
+        let mut has_changed = std::mem::take(&mut self.has_unreported_change);
+        if self.emergency_stop || self.has_watchdog_expired(time_of_check) {
+            has_changed |= self.fall_back_to_safe_value();
+        } else {
+            has_changed |= self.step_toward_target(time_of_check);
+        }
+        Ok(has_changed)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::MotorSafetyLimiter {
+            signal_type: self.signal_type,
+            position_range: self.position_range.clone(),
+            max_velocity_per_second: self.max_velocity_per_second,
+            max_acceleration_per_second_squared: self.max_acceleration_per_second_squared,
+            watchdog_timeout_ms: self.watchdog_timeout_ms,
+            safe_value: self.safe_value,
+            emergency_stop: self.emergency_stop,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::MotorSafetyLimiter {
+                signal_type,
+                position_range,
+                max_velocity_per_second,
+                max_acceleration_per_second_squared,
+                watchdog_timeout_ms,
+                safe_value,
+                emergency_stop,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing MotorSafetyLimiterStage! Replace the stage instead.".into(),
+                    ));
+                }
+                verify_limits(
+                    signal_type,
+                    &position_range,
+                    max_velocity_per_second,
+                    max_acceleration_per_second_squared,
+                    watchdog_timeout_ms,
+                    safe_value,
+                )?;
+                self.target = self
+                    .target
+                    .map(|target| target.clamp(*position_range.start(), *position_range.end()));
+                self.position_range = position_range;
+                self.max_velocity_per_second = max_velocity_per_second;
+                self.max_acceleration_per_second_squared = max_acceleration_per_second_squared;
+                self.watchdog_timeout_ms = watchdog_timeout_ms;
+                self.safe_value = safe_value;
+                self.emergency_stop = emergency_stop;
+                if emergency_stop {
+                    // Stop right away rather than waiting for the next check, and report the
+                    // change at the next check so callbacks still see it
+                    self.has_unreported_change |= self.fall_back_to_safe_value();
+                }
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for MotorSafetyLimiterStage".into(),
+            )),
+        }
+    }
+}
+
+impl MotorSafetyLimiterStage {
+    pub fn new(
+        signal_type: ScalarSignalType,
+        position_range: RangeInclusive<f32>,
+        max_velocity_per_second: Option<f32>,
+        max_acceleration_per_second_squared: Option<f32>,
+        watchdog_timeout_ms: Option<u32>,
+        safe_value: f32,
+        emergency_stop: bool,
+    ) -> Result<Self, FeagiDataError> {
+        verify_limits(
+            signal_type,
+            &position_range,
+            max_velocity_per_second,
+            max_acceleration_per_second_squared,
+            watchdog_timeout_ms,
+            safe_value,
+        )?;
+        Ok(MotorSafetyLimiterStage {
+            signal_type,
+            position_range,
+            max_velocity_per_second,
+            max_acceleration_per_second_squared,
+            watchdog_timeout_ms,
+            safe_value,
+            emergency_stop,
+            position: safe_value,
+            velocity: 0.0,
+            target: None,
+            last_step_time: None,
+            last_input_time: None,
+            has_unreported_change: false,
+            cached: signal_type.create_wrapped_value(safe_value),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        position_range: RangeInclusive<f32>,
+        max_velocity_per_second: Option<f32>,
+        max_acceleration_per_second_squared: Option<f32>,
+        watchdog_timeout_ms: Option<u32>,
+        safe_value: f32,
+        emergency_stop: bool,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(MotorSafetyLimiterStage::new(
+            signal_type,
+            position_range,
+            max_velocity_per_second,
+            max_acceleration_per_second_squared,
+            watchdog_timeout_ms,
+            safe_value,
+            emergency_stop,
+        )?))
+    }
+
+    fn has_watchdog_expired(&self, time_of_check: Instant) -> bool {
+        match (self.watchdog_timeout_ms, self.last_input_time) {
+            (Some(timeout_ms), Some(last_input_time)) => {
+                time_of_check.saturating_duration_since(last_input_time)
+                    >= Duration::from_millis(timeout_ms as u64)
+            }
+            _ => false,
+        }
+    }
+
+    /// Jumps to the safe value and forgets the current command. Returns true if the output changed.
+    fn fall_back_to_safe_value(&mut self) -> bool {
+        let has_changed = self.position != self.safe_value;
+        self.position = self.safe_value;
+        self.velocity = 0.0;
+        self.target = None;
+        self.last_step_time = None;
+        self.last_input_time = None;
+        self.cached = self.signal_type.create_wrapped_value(self.position);
+        has_changed
+    }
+
+    /// Moves the output toward the current command within the velocity and acceleration
+    /// limits. Returns true if the output changed.
+    fn step_toward_target(&mut self, time_of_step: Instant) -> bool {
+        let target = match self.target {
+            Some(target) => target,
+            None => return false,
+        };
+        let previous_position = self.position;
+
+        if self.max_velocity_per_second.is_none()
+            && self.max_acceleration_per_second_squared.is_none()
+        {
+            self.position = target;
+        } else if let Some(last_step_time) = self.last_step_time {
+            let elapsed_seconds = time_of_step
+                .saturating_duration_since(last_step_time)
+                .as_secs_f32();
+            if elapsed_seconds == 0.0 {
+                return false;
+            }
+            self.position = self.limited_position(target, elapsed_seconds);
+            self.velocity = (self.position - previous_position) / elapsed_seconds;
+        }
+        // Without a previous step there is no elapsed time to move in, so only start the clock
+        self.last_step_time = Some(time_of_step);
+
+        if self.position == previous_position {
+            self.velocity = 0.0;
+            return false;
+        }
+        self.cached = self.signal_type.create_wrapped_value(self.position);
+        true
+    }
+
+    fn limited_position(&self, target: f32, elapsed_seconds: f32) -> f32 {
+        let distance = target - self.position;
+        let mut speed = distance.abs() / elapsed_seconds;
+        if let Some(max_velocity) = self.max_velocity_per_second {
+            speed = speed.min(max_velocity);
+        }
+        let mut velocity = distance.signum() * speed;
+        if let Some(max_acceleration) = self.max_acceleration_per_second_squared {
+            // Never go faster than can still be braked to a stop at the target
+            let braking_speed = (2.0 * max_acceleration * distance.abs()).sqrt();
+            velocity = distance.signum() * speed.min(braking_speed);
+            let max_change = max_acceleration * elapsed_seconds;
+            velocity = velocity.clamp(self.velocity - max_change, self.velocity + max_change);
+        }
+
+        let position = self.position + velocity * elapsed_seconds;
+        if (target - position) * distance < 0.0 {
+            return target; // Overshot
+        }
+        position.clamp(*self.position_range.start(), *self.position_range.end())
+    }
+}
+
+fn verify_limits(
+    signal_type: ScalarSignalType,
+    position_range: &RangeInclusive<f32>,
+    max_velocity_per_second: Option<f32>,
+    max_acceleration_per_second_squared: Option<f32>,
+    watchdog_timeout_ms: Option<u32>,
+    safe_value: f32,
+) -> Result<(), FeagiDataError> {
+    signal_type.verify_range_within_signal(position_range, "Position range")?;
+    if !position_range.contains(&safe_value) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Safe value {} must be within the position range {:?}!",
+            safe_value, position_range
+        )));
+    }
+    for (limit, name) in [
+        (max_velocity_per_second, "Max velocity"),
+        (max_acceleration_per_second_squared, "Max acceleration"),
+    ] {
+        if let Some(limit) = limit {
+            if !(limit > 0.0 && limit.is_finite()) {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "{} must be a positive number, got {}!",
+                    name, limit
+                )));
+            }
+        }
+    }
+    if watchdog_timeout_ms == Some(0) {
+        return Err(FeagiDataError::BadParameters(
+            "Watchdog timeout cannot be 0 ms!".into(),
+        ));
+    }
+    Ok(())
+}
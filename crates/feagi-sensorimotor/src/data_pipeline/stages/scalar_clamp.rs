@@ -0,0 +1,114 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::time::Instant;
+
+/// Clamps a scalar into an allowed range.
+#[derive(Debug, Clone)]
+pub struct ScalarClampStage {
+    signal_type: ScalarSignalType,
+    allowed_range: RangeInclusive<f32>,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarClampStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarClampStage(signal: {:?}, allowed_range: {:?})",
+            self.signal_type, self.allowed_range
+        )
+    }
+}
+
+impl PipelineStage for ScalarClampStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self.signal_type.read_value(value)?;
+        self.cached = self.signal_type.create_wrapped_value(
+            input.clamp(*self.allowed_range.start(), *self.allowed_range.end()),
+        );
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarClamp {
+            signal_type: self.signal_type,
+            allowed_range: self.allowed_range.clone(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarClamp {
+                signal_type,
+                allowed_range,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarClampStage! Replace the stage instead.".into(),
+                    ));
+                }
+                signal_type.verify_range_within_signal(&allowed_range, "Allowed range")?;
+                self.allowed_range = allowed_range;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarClampStage"
+                    .into(),
+            )),
+        }
+    }
+}
+
+impl ScalarClampStage {
+    pub fn new(
+        signal_type: ScalarSignalType,
+        allowed_range: RangeInclusive<f32>,
+    ) -> Result<Self, FeagiDataError> {
+        signal_type.verify_range_within_signal(&allowed_range, "Allowed range")?;
+        Ok(ScalarClampStage {
+            signal_type,
+            cached: signal_type.create_wrapped_value(*allowed_range.start()),
+            allowed_range,
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        allowed_range: RangeInclusive<f32>,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarClampStage::new(signal_type, allowed_range)?))
+    }
+}
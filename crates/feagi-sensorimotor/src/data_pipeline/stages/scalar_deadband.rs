@@ -0,0 +1,128 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Zeroes out small values, such as the drift of a joystick at rest.
+///
+/// Values whose magnitude is below `deadband` become 0. Values beyond it are rescaled so the
+/// output still ramps smoothly from 0 at the edge of the deadband up to full scale, instead of
+/// jumping straight to `deadband`.
+#[derive(Debug, Clone)]
+pub struct ScalarDeadbandStage {
+    signal_type: ScalarSignalType,
+    deadband: f32,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarDeadbandStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarDeadbandStage(signal: {:?}, deadband: {})",
+            self.signal_type, self.deadband
+        )
+    }
+}
+
+impl PipelineStage for ScalarDeadbandStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self.signal_type.read_value(value)?;
+        let magnitude = input.abs();
+        let output = if magnitude < self.deadband {
+            0.0
+        } else {
+            input.signum() * (magnitude - self.deadband) / (1.0 - self.deadband)
+        };
+        self.cached = self.signal_type.create_wrapped_value(output);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarDeadband {
+            signal_type: self.signal_type,
+            deadband: self.deadband,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarDeadband {
+                signal_type,
+                deadband,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarDeadbandStage! Replace the stage instead.".into(),
+                    ));
+                }
+                verify_deadband(deadband)?;
+                self.deadband = deadband;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarDeadbandStage"
+                    .into(),
+            )),
+        }
+    }
+}
+
+impl ScalarDeadbandStage {
+    pub fn new(signal_type: ScalarSignalType, deadband: f32) -> Result<Self, FeagiDataError> {
+        verify_deadband(deadband)?;
+        Ok(ScalarDeadbandStage {
+            signal_type,
+            deadband,
+            cached: signal_type.create_wrapped_value(0.0),
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        deadband: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarDeadbandStage::new(signal_type, deadband)?))
+    }
+}
+
+fn verify_deadband(deadband: f32) -> Result<(), FeagiDataError> {
+    if !(0.0..1.0).contains(&deadband) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Deadband must be at least 0 and less than 1, got {}!",
+            deadband
+        )));
+    }
+    Ok(())
+}
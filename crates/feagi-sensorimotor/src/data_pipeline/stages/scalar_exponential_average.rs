@@ -0,0 +1,134 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Smooths a scalar with an exponential moving average.
+///
+/// Each output moves `smoothing_factor` of the way from the previous output toward the new
+/// input, so 1.0 passes inputs through unchanged and values near 0 smooth heavily. The first
+/// input is passed through as is.
+#[derive(Debug, Clone)]
+pub struct ScalarExponentialAverageStage {
+    signal_type: ScalarSignalType,
+    smoothing_factor: f32,
+    average: Option<f32>,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarExponentialAverageStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarExponentialAverageStage(signal: {:?}, smoothing_factor: {})",
+            self.signal_type, self.smoothing_factor
+        )
+    }
+}
+
+impl PipelineStage for ScalarExponentialAverageStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self.signal_type.read_value(value)?;
+        let average = match self.average {
+            Some(previous) => previous + self.smoothing_factor * (input - previous),
+            None => input,
+        };
+        self.average = Some(average);
+        self.cached = self.signal_type.create_wrapped_value(average);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarExponentialAverage {
+            signal_type: self.signal_type,
+            smoothing_factor: self.smoothing_factor,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarExponentialAverage {
+                signal_type,
+                smoothing_factor,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarExponentialAverageStage! Replace the stage instead.".into(),
+                    ));
+                }
+                verify_smoothing_factor(smoothing_factor)?;
+                self.smoothing_factor = smoothing_factor;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarExponentialAverageStage".into(),
+            )),
+        }
+    }
+}
+
+impl ScalarExponentialAverageStage {
+    pub fn new(
+        signal_type: ScalarSignalType,
+        smoothing_factor: f32,
+    ) -> Result<Self, FeagiDataError> {
+        verify_smoothing_factor(smoothing_factor)?;
+        Ok(ScalarExponentialAverageStage {
+            signal_type,
+            smoothing_factor,
+            average: None,
+            cached: signal_type.create_wrapped_value(0.0),
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        smoothing_factor: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarExponentialAverageStage::new(
+            signal_type,
+            smoothing_factor,
+        )?))
+    }
+}
+
+fn verify_smoothing_factor(smoothing_factor: f32) -> Result<(), FeagiDataError> {
+    if !(smoothing_factor > 0.0 && smoothing_factor <= 1.0) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Smoothing factor must be greater than 0 and at most 1, got {}!",
+            smoothing_factor
+        )));
+    }
+    Ok(())
+}
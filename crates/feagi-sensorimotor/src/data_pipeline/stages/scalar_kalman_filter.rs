@@ -0,0 +1,159 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Filters noise from a scalar with a one dimensional Kalman filter.
+///
+/// The signal is modeled as a random walk: `process_noise` is the variance of how much the
+/// true value may drift between inputs, and `measurement_noise` is the variance of the sensor
+/// noise. A higher ratio of measurement to process noise smooths more but reacts slower.
+/// The first input initializes the estimate.
+#[derive(Debug, Clone)]
+pub struct ScalarKalmanFilterStage {
+    signal_type: ScalarSignalType,
+    process_noise: f32,
+    measurement_noise: f32,
+    estimate: Option<(f32, f32)>, // Estimated value and its error variance
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarKalmanFilterStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarKalmanFilterStage(signal: {:?}, process_noise: {}, measurement_noise: {})",
+            self.signal_type, self.process_noise, self.measurement_noise
+        )
+    }
+}
+
+impl PipelineStage for ScalarKalmanFilterStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let measurement = self.signal_type.read_value(value)?;
+        let (estimate, variance) = match self.estimate {
+            Some((estimate, variance)) => {
+                let predicted_variance = variance + self.process_noise;
+                let gain = predicted_variance / (predicted_variance + self.measurement_noise);
+                (
+                    estimate + gain * (measurement - estimate),
+                    (1.0 - gain) * predicted_variance,
+                )
+            }
+            None => (measurement, self.measurement_noise),
+        };
+        self.estimate = Some((estimate, variance));
+        self.cached = self.signal_type.create_wrapped_value(estimate);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarKalmanFilter {
+            signal_type: self.signal_type,
+            process_noise: self.process_noise,
+            measurement_noise: self.measurement_noise,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarKalmanFilter {
+                signal_type,
+                process_noise,
+                measurement_noise,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarKalmanFilterStage! Replace the stage instead.".into(),
+                    ));
+                }
+                verify_noise_variances(process_noise, measurement_noise)?;
+                self.process_noise = process_noise;
+                self.measurement_noise = measurement_noise;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarKalmanFilterStage".into(),
+            )),
+        }
+    }
+}
+
+impl ScalarKalmanFilterStage {
+    pub fn new(
+        signal_type: ScalarSignalType,
+        process_noise: f32,
+        measurement_noise: f32,
+    ) -> Result<Self, FeagiDataError> {
+        verify_noise_variances(process_noise, measurement_noise)?;
+        Ok(ScalarKalmanFilterStage {
+            signal_type,
+            process_noise,
+            measurement_noise,
+            estimate: None,
+            cached: signal_type.create_wrapped_value(0.0),
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        process_noise: f32,
+        measurement_noise: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarKalmanFilterStage::new(
+            signal_type,
+            process_noise,
+            measurement_noise,
+        )?))
+    }
+}
+
+fn verify_noise_variances(
+    process_noise: f32,
+    measurement_noise: f32,
+) -> Result<(), FeagiDataError> {
+    if !(process_noise >= 0.0 && process_noise.is_finite()) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Kalman filter process noise must be zero or positive, got {}!",
+            process_noise
+        )));
+    }
+    if !(measurement_noise > 0.0 && measurement_noise.is_finite()) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Kalman filter measurement noise must be positive, got {}!",
+            measurement_noise
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::time::Instant;
+
+/// Linearly maps a scalar from an input range onto an output range, optionally converting
+/// between [`ScalarSignalType`]s. Inputs outside the input range are clamped to it first.
+///
+/// For example, mapping a [`ScalarSignalType::SignedPercentage`] input range of -0.5..=0.5 onto a
+/// [`ScalarSignalType::Percentage`] output range of 0..=1 turns a joystick that only ever
+/// travels halfway into a full 0 to 1 throttle.
+#[derive(Debug, Clone)]
+pub struct ScalarLinearScaleStage {
+    input_signal_type: ScalarSignalType,
+    input_range: RangeInclusive<f32>,
+    output_signal_type: ScalarSignalType,
+    output_range: RangeInclusive<f32>,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarLinearScaleStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarLinearScaleStage(input: {:?} {:?}, output: {:?} {:?})",
+            self.input_signal_type, self.input_range, self.output_signal_type, self.output_range
+        )
+    }
+}
+
+impl PipelineStage for ScalarLinearScaleStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.input_signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.output_signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self
+            .input_signal_type
+            .read_value(value)?
+            .clamp(*self.input_range.start(), *self.input_range.end());
+        let position = (input - self.input_range.start())
+            / (self.input_range.end() - self.input_range.start());
+        let output = self.output_range.start()
+            + position * (self.output_range.end() - self.output_range.start());
+        self.cached = self.output_signal_type.create_wrapped_value(output);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarLinearScale {
+            input_signal_type: self.input_signal_type,
+            input_range: self.input_range.clone(),
+            output_signal_type: self.output_signal_type,
+            output_range: self.output_range.clone(),
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarLinearScale {
+                input_signal_type,
+                input_range,
+                output_signal_type,
+                output_range,
+            } => {
+                if input_signal_type != self.input_signal_type
+                    || output_signal_type != self.output_signal_type
+                {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal types of an existing ScalarLinearScaleStage! Replace the stage instead.".into(),
+                    ));
+                }
+                *self = ScalarLinearScaleStage::new(
+                    input_signal_type,
+                    input_range,
+                    output_signal_type,
+                    output_range,
+                )?;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarLinearScaleStage".into(),
+            )),
+        }
+    }
+}
+
+impl ScalarLinearScaleStage {
+    pub fn new(
+        input_signal_type: ScalarSignalType,
+        input_range: RangeInclusive<f32>,
+        output_signal_type: ScalarSignalType,
+        output_range: RangeInclusive<f32>,
+    ) -> Result<Self, FeagiDataError> {
+        input_signal_type.verify_range_within_signal(&input_range, "Input range")?;
+        output_signal_type.verify_range_within_signal(&output_range, "Output range")?;
+        if input_range.start() == input_range.end() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Input range {:?} cannot be a single value!",
+                input_range
+            )));
+        }
+        Ok(ScalarLinearScaleStage {
+            input_signal_type,
+            input_range,
+            output_signal_type,
+            cached: output_signal_type.create_wrapped_value(*output_range.start()),
+            output_range,
+        })
+    }
+
+    pub(crate) fn new_box(
+        input_signal_type: ScalarSignalType,
+        input_range: RangeInclusive<f32>,
+        output_signal_type: ScalarSignalType,
+        output_range: RangeInclusive<f32>,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarLinearScaleStage::new(
+            input_signal_type,
+            input_range,
+            output_signal_type,
+            output_range,
+        )?))
+    }
+}
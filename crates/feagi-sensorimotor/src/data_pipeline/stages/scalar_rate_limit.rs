@@ -0,0 +1,140 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Limits how fast a scalar may change, based on the time between inputs.
+///
+/// Each output moves toward the new input by at most `max_change_per_second` times the seconds
+/// elapsed since the previous input. The first input is passed through as is.
+#[derive(Debug, Clone)]
+pub struct ScalarRateLimitStage {
+    signal_type: ScalarSignalType,
+    max_change_per_second: f32,
+    previous: Option<(f32, Instant)>,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarRateLimitStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarRateLimitStage(signal: {:?}, max_change_per_second: {})",
+            self.signal_type, self.max_change_per_second
+        )
+    }
+}
+
+impl PipelineStage for ScalarRateLimitStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self.signal_type.read_value(value)?;
+        let output = match self.previous {
+            Some((previous_output, previous_time)) => {
+                let elapsed_seconds = time_of_input
+                    .saturating_duration_since(previous_time)
+                    .as_secs_f32();
+                let max_change = self.max_change_per_second * elapsed_seconds;
+                previous_output + (input - previous_output).clamp(-max_change, max_change)
+            }
+            None => input,
+        };
+        self.previous = Some((output, time_of_input));
+        self.cached = self.signal_type.create_wrapped_value(output);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarRateLimit {
+            signal_type: self.signal_type,
+            max_change_per_second: self.max_change_per_second,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarRateLimit {
+                signal_type,
+                max_change_per_second,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarRateLimitStage! Replace the stage instead.".into(),
+                    ));
+                }
+                verify_max_change_per_second(max_change_per_second)?;
+                self.max_change_per_second = max_change_per_second;
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarRateLimitStage"
+                    .into(),
+            )),
+        }
+    }
+}
+
+impl ScalarRateLimitStage {
+    pub fn new(
+        signal_type: ScalarSignalType,
+        max_change_per_second: f32,
+    ) -> Result<Self, FeagiDataError> {
+        verify_max_change_per_second(max_change_per_second)?;
+        Ok(ScalarRateLimitStage {
+            signal_type,
+            max_change_per_second,
+            previous: None,
+            cached: signal_type.create_wrapped_value(0.0),
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        max_change_per_second: f32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarRateLimitStage::new(
+            signal_type,
+            max_change_per_second,
+        )?))
+    }
+}
+
+fn verify_max_change_per_second(max_change_per_second: f32) -> Result<(), FeagiDataError> {
+    if !(max_change_per_second > 0.0 && max_change_per_second.is_finite()) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Max change per second must be a positive number, got {}!",
+            max_change_per_second
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::data_pipeline::pipeline_stage::PipelineStage;
+use crate::data_pipeline::pipeline_stage_properties::PipelineStageProperties;
+use crate::data_pipeline::ScalarSignalType;
+use crate::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Outputs the mean of the last `window_length` scalars.
+///
+/// Until the window has filled up, the mean is taken over the values received so far.
+#[derive(Debug, Clone)]
+pub struct ScalarRollingAverageStage {
+    signal_type: ScalarSignalType,
+    window: Vec<f32>, // Circular buffer, only the first `number_filled` values are valid
+    next_index: usize,
+    number_filled: usize,
+    cached: WrappedIOData,
+}
+
+impl Display for ScalarRollingAverageStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarRollingAverageStage(signal: {:?}, window_length: {})",
+            self.signal_type,
+            self.window.len()
+        )
+    }
+}
+
+impl PipelineStage for ScalarRollingAverageStage {
+    fn get_input_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_output_data_type(&self) -> WrappedIOType {
+        self.signal_type.get_wrapped_io_type()
+    }
+
+    fn get_most_recent_output(&self) -> &WrappedIOData {
+        &self.cached
+    }
+
+    fn process_new_input(
+        &mut self,
+        value: &WrappedIOData,
+        _time_of_input: Instant,
+    ) -> Result<&WrappedIOData, FeagiDataError> {
+        let input = self.signal_type.read_value(value)?;
+        self.window[self.next_index] = input;
+        self.next_index = (self.next_index + 1) % self.window.len();
+        self.number_filled = (self.number_filled + 1).min(self.window.len());
+
+        let average =
+            self.window[..self.number_filled].iter().sum::<f32>() / self.number_filled as f32;
+        self.cached = self.signal_type.create_wrapped_value(average);
+        Ok(&self.cached)
+    }
+
+    fn clone_box(&self) -> Box<dyn PipelineStage> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_properties(&self) -> PipelineStageProperties {
+        PipelineStageProperties::ScalarRollingAverage {
+            signal_type: self.signal_type,
+            window_length: self.window.len() as u32,
+        }
+    }
+
+    fn load_properties(
+        &mut self,
+        properties: PipelineStageProperties,
+    ) -> Result<(), FeagiDataError> {
+        match properties {
+            PipelineStageProperties::ScalarRollingAverage {
+                signal_type,
+                window_length,
+            } => {
+                if signal_type != self.signal_type {
+                    return Err(FeagiDataError::BadParameters(
+                        "Cannot change the signal type of an existing ScalarRollingAverageStage! Replace the stage instead.".into(),
+                    ));
+                }
+                if window_length as usize != self.window.len() {
+                    // The old samples do not fit the new window, so start averaging afresh
+                    *self = ScalarRollingAverageStage::new(signal_type, window_length)?;
+                }
+                Ok(())
+            }
+            _ => Err(FeagiDataError::BadParameters(
+                "load_properties called with incompatible properties type for ScalarRollingAverageStage".into(),
+            )),
+        }
+    }
+}
+
+impl ScalarRollingAverageStage {
+    pub fn new(signal_type: ScalarSignalType, window_length: u32) -> Result<Self, FeagiDataError> {
+        if window_length == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Rolling average window length cannot be 0!".into(),
+            ));
+        }
+        Ok(ScalarRollingAverageStage {
+            signal_type,
+            window: vec![0.0; window_length as usize],
+            next_index: 0,
+            number_filled: 0,
+            cached: signal_type.create_wrapped_value(0.0),
+        })
+    }
+
+    pub(crate) fn new_box(
+        signal_type: ScalarSignalType,
+        window_length: u32,
+    ) -> Result<Box<dyn PipelineStage + 'static>, FeagiDataError> {
+        Ok(Box::new(ScalarRollingAverageStage::new(
+            signal_type,
+            window_length,
+        )?))
+    }
+}
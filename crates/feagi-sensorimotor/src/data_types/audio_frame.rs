@@ -0,0 +1,120 @@
+use super::descriptors::AudioFrameProperties;
+use feagi_structures::FeagiDataError;
+
+/// A fixed size block of mono PCM audio.
+///
+/// Samples are stored as `f32` in the range [-1.0, 1.0], the convention used by most audio
+/// backends. Integer PCM can be converted in with [`AudioFrame::new_from_i16_samples`].
+///
+/// # Example
+/// ```
+/// use feagi_sensorimotor::data_types::{AudioFrame, descriptors::AudioFrameProperties};
+///
+/// let properties = AudioFrameProperties::new(16000, 512).unwrap();
+/// let audio = AudioFrame::new(&properties).unwrap();
+/// assert_eq!(audio.get_samples().len(), 512);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioFrame {
+    sample_rate_hz: u32,
+    samples: Vec<f32>,
+}
+
+impl AudioFrame {
+    //region Common Constructors
+
+    /// Creates a new silent audio frame.
+    pub fn new(properties: &AudioFrameProperties) -> Result<AudioFrame, FeagiDataError> {
+        Ok(AudioFrame {
+            sample_rate_hz: properties.get_sample_rate_hz(),
+            samples: vec![0.0; properties.get_number_samples() as usize],
+        })
+    }
+
+    /// Creates an audio frame from existing samples.
+    ///
+    /// Samples outside [-1.0, 1.0] are clamped. Returns an error if there are no samples or
+    /// the sample rate is zero.
+    pub fn new_from_samples(
+        sample_rate_hz: u32,
+        samples: Vec<f32>,
+    ) -> Result<AudioFrame, FeagiDataError> {
+        AudioFrameProperties::new(sample_rate_hz, samples.len() as u32)?;
+        let mut audio = AudioFrame {
+            sample_rate_hz,
+            samples,
+        };
+        audio.clamp_samples();
+        Ok(audio)
+    }
+
+    /// Creates an audio frame from signed 16 bit PCM samples.
+    pub fn new_from_i16_samples(
+        sample_rate_hz: u32,
+        samples: &[i16],
+    ) -> Result<AudioFrame, FeagiDataError> {
+        let samples: Vec<f32> = samples
+            .iter()
+            .map(|sample| *sample as f32 / -(i16::MIN as f32))
+            .collect();
+        AudioFrame::new_from_samples(sample_rate_hz, samples)
+    }
+
+    //endregion
+
+    //region Get Properties
+
+    pub fn get_audio_frame_properties(&self) -> AudioFrameProperties {
+        AudioFrameProperties::new(self.sample_rate_hz, self.samples.len() as u32).unwrap()
+    }
+
+    pub fn get_sample_rate_hz(&self) -> u32 {
+        self.sample_rate_hz
+    }
+
+    /// Returns the length of this frame in seconds.
+    pub fn get_duration_seconds(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate_hz as f32
+    }
+
+    //endregion
+
+    pub fn get_samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// Returns the samples for in place writing. The number of samples cannot be changed.
+    pub fn get_samples_mut(&mut self) -> &mut [f32] {
+        &mut self.samples
+    }
+
+    /// Overwrites the samples of this frame, clamping them to [-1.0, 1.0].
+    pub fn update_samples(&mut self, samples: &[f32]) -> Result<(), FeagiDataError> {
+        if samples.len() != self.samples.len() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected {} audio samples but got {}!",
+                self.samples.len(),
+                samples.len()
+            )));
+        }
+        self.samples.copy_from_slice(samples);
+        self.clamp_samples();
+        Ok(())
+    }
+
+    pub fn blank_data(&mut self) {
+        self.samples.fill(0.0);
+    }
+
+    fn clamp_samples(&mut self) {
+        for sample in self.samples.iter_mut() {
+            *sample = sample.clamp(-1.0, 1.0);
+        }
+    }
+}
+
+impl std::fmt::Display for AudioFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "AudioFrame({})", self.get_audio_frame_properties())
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This module provides data structures and enums for describing dat properties
 
-use super::{ImageFrame, SegmentedImageFrame};
+use super::{AudioFrame, ImageFrame, SegmentedImageFrame};
 use feagi_structures::genomic::cortical_area::descriptors::CorticalChannelDimensions;
 // NeuronDepth is used in macro expansion
 use feagi_structures::FeagiDataError;
@@ -159,7 +159,7 @@ impl Display for ColorSpace {
 ///
 /// This enum defines the possible color channel configurations for an image:
 /// - GrayScale: Single channel (grayscale, or red)
-/// - RG: Two channels (red, green)
+/// - RG: Two channels (grayscale, alpha), matching grayscale images with transparency
 /// - RGB: Three channels (red, green, blue)
 /// - RGBA: Four channels (red, green, blue, alpha)
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -170,6 +170,17 @@ pub enum ColorChannelLayout {
     RGBA = 4,
 }
 
+impl ColorChannelLayout {
+    /// Returns the index of the alpha channel, if this layout has one.
+    pub fn get_alpha_channel_index(&self) -> Option<usize> {
+        match self {
+            ColorChannelLayout::RG => Some(1),
+            ColorChannelLayout::RGBA => Some(3),
+            _ => None,
+        }
+    }
+}
+
 impl Display for ColorChannelLayout {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -540,4 +551,90 @@ define_xyz_dimensions!(MiscDataDimensions, u32, "MiscDataDimensions", 0, "The di
 define_xyz_mapping!(MiscDataDimensions, ImageXYZDimensions);
 define_xyz_mapping!(MiscDataDimensions, CorticalChannelDimensions);
 
+/// The neuron axis a tensor axis is laid along when converting between tensors and MiscData.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MiscDataAxis {
+    /// Along X, with index 0 at X = 0
+    X,
+    /// Along Y, with index 0 at the bottom (Y = 0) as in FEAGI
+    Y,
+    /// Along Y, with index 0 at the top as with image rows and matrices
+    YTopDown,
+    /// Along Z, with index 0 at Z = 0
+    Z,
+}
+
+//endregion
+
+//region Audio
+
+/// Describes a fixed size block of mono PCM audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct AudioFrameProperties {
+    sample_rate_hz: u32,
+    number_samples: u32,
+}
+
+impl AudioFrameProperties {
+    /// Creates a new AudioFrameProperties instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate_hz` - Number of samples per second
+    /// * `number_samples` - Number of samples held by each frame
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either value is zero.
+    pub fn new(sample_rate_hz: u32, number_samples: u32) -> Result<Self, FeagiDataError> {
+        if sample_rate_hz == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Audio sample rate cannot be zero!".into(),
+            ));
+        }
+        if number_samples == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Audio frames cannot hold zero samples!".into(),
+            ));
+        }
+        Ok(AudioFrameProperties {
+            sample_rate_hz,
+            number_samples,
+        })
+    }
+
+    pub fn get_sample_rate_hz(&self) -> u32 {
+        self.sample_rate_hz
+    }
+
+    pub fn get_number_samples(&self) -> u32 {
+        self.number_samples
+    }
+
+    /// Verifies that an audio frame matches these properties.
+    pub fn verify_audio_frame_matches_properties(
+        &self,
+        audio_frame: &AudioFrame,
+    ) -> Result<(), FeagiDataError> {
+        if *self != audio_frame.get_audio_frame_properties() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected audio frame of {} but got {}!",
+                self,
+                audio_frame.get_audio_frame_properties()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Display for AudioFrameProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "AudioFrameProperties({} Hz, {} samples)",
+            self.sample_rate_hz, self.number_samples
+        )
+    }
+}
+
 //endregion
@@ -0,0 +1,306 @@
+use super::descriptors::ImageXYResolution;
+use feagi_structures::FeagiDataError;
+use serde::{Deserialize, Serialize};
+
+/// Brightness change direction of an event camera event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventPolarity {
+    /// The pixel got brighter
+    On,
+    /// The pixel got darker
+    Off,
+}
+
+/// A single event camera (DVS) event.
+///
+/// Coordinates follow the image convention: (0,0) is the top left, +y goes downward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DvsEvent {
+    pub x: u32,
+    pub y: u32,
+    pub polarity: EventPolarity,
+    pub timestamp_us: u64,
+}
+
+impl DvsEvent {
+    pub fn new(x: u32, y: u32, polarity: EventPolarity, timestamp_us: u64) -> Self {
+        DvsEvent {
+            x,
+            y,
+            polarity,
+            timestamp_us,
+        }
+    }
+}
+
+/// Bit layout of the 32 bit event addresses in an AEDAT 2.0 file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Aedat2AddressFormat {
+    /// DVS128: x in bits 1-7, y in bits 8-14, polarity in bit 0 (0 is ON)
+    Dvs128,
+    /// DAVIS240 / DAVIS346: x in bits 12-21, y in bits 22-30, polarity in bit 11 (1 is ON).
+    /// Frame (APS) and IMU samples, which have bit 31 set, are skipped.
+    Davis,
+}
+
+/// A batch of event camera events from a sensor of a given resolution.
+///
+/// Event cameras report per pixel brightness changes as they happen instead of full frames.
+/// Each batch should hold the events that arrived since the previous one was sent, which the
+/// event camera encoder then bins into a single burst.
+///
+/// # Example
+/// ```
+/// use feagi_sensorimotor::data_types::{DvsEvent, EventPolarity, EventStream};
+/// use feagi_sensorimotor::data_types::descriptors::ImageXYResolution;
+///
+/// let mut events = EventStream::new(ImageXYResolution::new(128, 128).unwrap());
+/// events.push_event(DvsEvent::new(3, 4, EventPolarity::On, 10)).unwrap();
+/// assert_eq!(events.len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventStream {
+    resolution: ImageXYResolution,
+    events: Vec<DvsEvent>,
+}
+
+impl EventStream {
+    //region Common Constructors
+
+    /// Creates a new empty event stream.
+    pub fn new(resolution: ImageXYResolution) -> EventStream {
+        EventStream {
+            resolution,
+            events: Vec::new(),
+        }
+    }
+
+    /// Creates an event stream from existing events.
+    ///
+    /// Returns an error if any event lies outside the resolution.
+    pub fn new_from_events(
+        resolution: ImageXYResolution,
+        events: Vec<DvsEvent>,
+    ) -> Result<EventStream, FeagiDataError> {
+        for event in events.iter() {
+            verify_event_in_resolution(event, &resolution)?;
+        }
+        Ok(EventStream { resolution, events })
+    }
+
+    //endregion
+
+    //region File Constructors
+
+    /// Reads the events of an AEDAT 2.0 file (as recorded by jAER and the DV software).
+    ///
+    /// The '#' header lines are skipped, then each event is read as a big endian 32 bit
+    /// address followed by a big endian 32 bit microsecond timestamp.
+    pub fn new_from_aedat2_bytes(
+        bytes: &[u8],
+        resolution: ImageXYResolution,
+        address_format: Aedat2AddressFormat,
+    ) -> Result<EventStream, FeagiDataError> {
+        let data = skip_header_lines(bytes, b'#', None);
+        #[allow(clippy::manual_is_multiple_of)]
+        // usize::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+        if data.len() % 8 != 0 {
+            return Err(FeagiDataError::DeserializationError(format!(
+                "AEDAT 2.0 event data must be a multiple of 8 bytes, got {}!",
+                data.len()
+            )));
+        }
+
+        let mut events: Vec<DvsEvent> = Vec::with_capacity(data.len() / 8);
+        for chunk in data.chunks_exact(8) {
+            let address = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let timestamp_us = u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as u64;
+            let event = match address_format {
+                Aedat2AddressFormat::Dvs128 => DvsEvent::new(
+                    (address >> 1) & 0x7F,
+                    (address >> 8) & 0x7F,
+                    if address & 1 == 0 {
+                        EventPolarity::On
+                    } else {
+                        EventPolarity::Off
+                    },
+                    timestamp_us,
+                ),
+                Aedat2AddressFormat::Davis => {
+                    if address >> 31 == 1 {
+                        continue; // APS or IMU sample
+                    }
+                    DvsEvent::new(
+                        (address >> 12) & 0x3FF,
+                        (address >> 22) & 0x1FF,
+                        if (address >> 11) & 1 == 1 {
+                            EventPolarity::On
+                        } else {
+                            EventPolarity::Off
+                        },
+                        timestamp_us,
+                    )
+                }
+            };
+            verify_event_in_resolution(&event, &resolution)?;
+            events.push(event);
+        }
+        Ok(EventStream { resolution, events })
+    }
+
+    /// Reads the events of a Prophesee EVT 2.0 RAW file.
+    ///
+    /// The '%' header lines (up to and including a "% end" line, if any) are skipped, then the
+    /// data is read as little endian 32 bit words. Only change detection (ON / OFF) and time
+    /// high words are used, other words such as external triggers are skipped.
+    pub fn new_from_evt2_raw_bytes(
+        bytes: &[u8],
+        resolution: ImageXYResolution,
+    ) -> Result<EventStream, FeagiDataError> {
+        const CD_OFF: u32 = 0x0;
+        const CD_ON: u32 = 0x1;
+        const EV_TIME_HIGH: u32 = 0x8;
+
+        let data = skip_header_lines(bytes, b'%', Some(b"% end"));
+        #[allow(clippy::manual_is_multiple_of)]
+        // usize::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+        if data.len() % 4 != 0 {
+            return Err(FeagiDataError::DeserializationError(format!(
+                "EVT 2.0 event data must be a multiple of 4 bytes, got {}!",
+                data.len()
+            )));
+        }
+
+        let mut events: Vec<DvsEvent> = Vec::with_capacity(data.len() / 4);
+        let mut time_high: u64 = 0;
+        for chunk in data.chunks_exact(4) {
+            let word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let polarity = match word >> 28 {
+                CD_OFF => EventPolarity::Off,
+                CD_ON => EventPolarity::On,
+                EV_TIME_HIGH => {
+                    time_high = (word & 0x0FFF_FFFF) as u64;
+                    continue;
+                }
+                _ => continue,
+            };
+            let event = DvsEvent::new(
+                (word >> 11) & 0x7FF,
+                word & 0x7FF,
+                polarity,
+                (time_high << 6) | ((word >> 22) & 0x3F) as u64,
+            );
+            verify_event_in_resolution(&event, &resolution)?;
+            events.push(event);
+        }
+        Ok(EventStream { resolution, events })
+    }
+
+    //endregion
+
+    //region Get Properties
+
+    pub fn get_resolution(&self) -> ImageXYResolution {
+        self.resolution
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    //endregion
+
+    //region Events
+
+    pub fn get_events(&self) -> &[DvsEvent] {
+        &self.events
+    }
+
+    /// Adds an event to the stream. Returns an error if it lies outside the resolution.
+    pub fn push_event(&mut self, event: DvsEvent) -> Result<(), FeagiDataError> {
+        verify_event_in_resolution(&event, &self.resolution)?;
+        self.events.push(event);
+        Ok(())
+    }
+
+    /// Removes all events.
+    pub fn blank_data(&mut self) {
+        self.events.clear();
+    }
+
+    /// Splits the stream into consecutive windows of `window_us` microseconds, starting at the
+    /// first event's timestamp. Useful for replaying a recording one burst at a time. Windows
+    /// without events are kept so playback timing is preserved.
+    pub fn split_by_time_window(&self, window_us: u64) -> Result<Vec<EventStream>, FeagiDataError> {
+        if window_us == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Event stream time window cannot be zero!".into(),
+            ));
+        }
+        let mut windows: Vec<EventStream> = Vec::new();
+        let start_us = match self.events.iter().map(|event| event.timestamp_us).min() {
+            Some(start_us) => start_us,
+            None => return Ok(windows),
+        };
+        for event in self.events.iter() {
+            let window_index = ((event.timestamp_us - start_us) / window_us) as usize;
+            while windows.len() <= window_index {
+                windows.push(EventStream::new(self.resolution));
+            }
+            windows[window_index].events.push(*event);
+        }
+        Ok(windows)
+    }
+
+    //endregion
+}
+
+impl std::fmt::Display for EventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "EventStream(<{}, {}>, {} events)",
+            self.resolution.width,
+            self.resolution.height,
+            self.events.len()
+        )
+    }
+}
+
+fn verify_event_in_resolution(
+    event: &DvsEvent,
+    resolution: &ImageXYResolution,
+) -> Result<(), FeagiDataError> {
+    if event.x >= resolution.width || event.y >= resolution.height {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Event at ({}, {}) is outside the sensor resolution of <{}, {}>!",
+            event.x, event.y, resolution.width, resolution.height
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the data following the text header lines that start with the given marker. If
+/// given, a header line equal to `end_line` ends the header early, as binary data could
+/// start with the marker byte.
+fn skip_header_lines<'a>(bytes: &'a [u8], marker: u8, end_line: Option<&[u8]>) -> &'a [u8] {
+    let mut position = 0;
+    while position < bytes.len() && bytes[position] == marker {
+        let line_end = match bytes[position..].iter().position(|byte| *byte == b'\n') {
+            Some(line_end) => position + line_end,
+            None => return &[],
+        };
+        let line = bytes[position..line_end]
+            .strip_suffix(b"\r")
+            .unwrap_or(&bytes[position..line_end]);
+        position = line_end + 1;
+        if end_line == Some(line) {
+            break;
+        }
+    }
+    &bytes[position..]
+}
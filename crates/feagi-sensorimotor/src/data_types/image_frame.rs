@@ -6,7 +6,7 @@ use feagi_structures::genomic::cortical_area::descriptors::CorticalChannelIndex;
 use feagi_structures::neuron_voxels::xyzp::NeuronVoxelXYZPArrays;
 use feagi_structures::FeagiDataError;
 use image::{DynamicImage, GenericImageView};
-use ndarray::{Array3, ArrayView3, ArrayViewMut3, Zip};
+use ndarray::{Array3, ArrayView3, ArrayViewMut3, Axis, Zip};
 
 // Named constants for sRGB / linear conversions
 const SRGB_THRESHOLD: f32 = 0.04045;
@@ -24,7 +24,7 @@ const LINEAR_MIDPOINT: f32 = 0.5;
 /// Stores pixel data as a 3D array with height, width, and channel dimensions.
 /// Supports RGB/RGBA formats and different color spaces (sRGB, Linear, Gamma).
 /// Can import/export various image formats and convert between color spaces.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ImageFrame {
     pixels: Array3<u8>, // MemoryOrderLayout::HeightsWidthsChannels
     channel_layout: ColorChannelLayout,
@@ -259,11 +259,11 @@ impl ImageFrame {
         self.pixels.shape()[0] * self.pixels.shape()[1] * self.pixels.shape()[2]
     }
 
-    /// Returns the 3D dimensions (height, width, channels) of the image.
+    /// Returns the 3D dimensions (width, height, channels) of the image.
     pub fn get_dimensions(&self) -> ImageXYZDimensions {
         ImageXYZDimensions::new(
-            self.pixels.shape()[0] as u32,
             self.pixels.shape()[1] as u32,
+            self.pixels.shape()[0] as u32,
             self.channel_layout.into(),
         )
         .unwrap()
@@ -478,11 +478,15 @@ impl ImageFrame {
     // TODO move these to be SIMD
 
     /// Apply in-place brightness adjustment
-    /// `value` = signed integer added to each channel (only in gamma space)
+    /// `value` = signed integer added to each color channel (only in gamma space). Alpha is kept
     pub fn change_brightness(&mut self, value: i32) {
+        let alpha_channel = self.channel_layout.get_alpha_channel_index();
         match self.color_space {
             ColorSpace::Gamma => {
-                Zip::indexed(&mut self.pixels).par_for_each(|(_y, _x, _c), color_val| {
+                Zip::indexed(&mut self.pixels).par_for_each(|(_y, _x, c), color_val| {
+                    if Some(c) == alpha_channel {
+                        return;
+                    }
                     let v = (*color_val as i32 + value).clamp(0, 255);
                     *color_val = v as u8;
                 });
@@ -492,7 +496,10 @@ impl ImageFrame {
                 let delta = value as f32 / 255.0;
 
                 // TODO make parallel
-                Zip::from(&mut self.pixels).for_each(|px| {
+                Zip::indexed(&mut self.pixels).for_each(|(_y, _x, c), px| {
+                    if Some(c) == alpha_channel {
+                        return;
+                    }
                     let lin = Self::srgb_to_linear(*px as f32);
                     let lin = (lin + delta).clamp(0.0, 1.0);
                     *px = Self::linear_to_srgb(lin);
@@ -502,19 +509,26 @@ impl ImageFrame {
     }
 
     /// Apply in-place contrast adjustment
-    /// `factor` = multiplier (>1 = increase contrast, <1 = reduce)
+    /// `factor` = multiplier (>1 = increase contrast, <1 = reduce). Alpha is kept
     pub fn change_contrast(&mut self, factor: f32) {
+        let alpha_channel = self.channel_layout.get_alpha_channel_index();
         match self.color_space {
             ColorSpace::Gamma => {
                 // Contrast around mid-point 128
-                Zip::from(&mut self.pixels).for_each(|px| {
+                Zip::indexed(&mut self.pixels).for_each(|(_y, _x, c), px| {
+                    if Some(c) == alpha_channel {
+                        return;
+                    }
                     let v = ((*px as f32 - 128.0) * factor + 128.0).clamp(0.0, 255.0);
                     *px = v.round() as u8;
                 });
             }
             ColorSpace::Linear => {
                 // Convert to linear float, adjust contrast around 0.5, then back
-                Zip::from(&mut self.pixels).for_each(|px| {
+                Zip::indexed(&mut self.pixels).for_each(|(_y, _x, c), px| {
+                    if Some(c) == alpha_channel {
+                        return;
+                    }
                     let lin = Self::srgb_to_linear(*px as f32);
                     let lin = ((lin - 0.5) * factor + 0.5).clamp(0.0, 1.0);
                     *px = Self::linear_to_srgb(lin);
@@ -523,6 +537,23 @@ impl ImageFrame {
         }
     }
 
+    /// Apply in-place alpha premultiplication, scaling each color channel by its pixel's alpha.
+    /// This is the image composited over black. Alpha itself is kept, and images without an
+    /// alpha channel are left untouched
+    pub fn premultiply_alpha(&mut self) {
+        let Some(alpha_channel) = self.channel_layout.get_alpha_channel_index() else {
+            return;
+        };
+        for mut pixel in self.pixels.lanes_mut(Axis(2)) {
+            let alpha = pixel[alpha_channel] as f32 / 255.0;
+            for (channel, value) in pixel.iter_mut().enumerate() {
+                if channel != alpha_channel {
+                    *value = (*value as f32 * alpha).round() as u8;
+                }
+            }
+        }
+    }
+
     pub fn blink_image(&mut self) {
         self.pixels.fill(0);
     }
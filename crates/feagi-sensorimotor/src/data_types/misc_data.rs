@@ -1,14 +1,15 @@
-use super::descriptors::MiscDataDimensions;
+use super::descriptors::{MiscDataAxis, MiscDataDimensions};
 use super::ImageFrame;
 use feagi_structures::genomic::cortical_area::descriptors::CorticalChannelIndex;
 use feagi_structures::neuron_voxels::xyzp::NeuronVoxelXYZPArrays;
 use feagi_structures::FeagiDataError;
-use ndarray::{Array3, Zip};
+use ndarray::{Array3, ArrayD, ArrayViewD, Dimension, IxDyn, Zip};
 
 /// A 3D array container for miscellaneous floating-point data.
 ///
 /// Used for storing arbitrary 3D data structures with width, height, and depth dimensions.
-/// Supports conversion from image frames and individual float values.
+/// The data is indexed by (x, y, z) in neuron coordinates, so Y = 0 is at the bottom.
+/// Supports conversion from image frames, N-dimensional tensors, and individual float values.
 ///
 /// # Example
 /// ```
@@ -51,14 +52,15 @@ impl MiscData {
     }
 
     /// Creates MiscData from an image frame by normalizing pixel values to [0.0, 1.0].
+    ///
+    /// Pixels are placed at (column, height - 1 - row, channel), so the image stays upright.
     pub fn new_from_image_frame(image: &ImageFrame) -> Result<MiscData, FeagiDataError> {
         let mut output = MiscData::new(&image.get_dimensions().into())?;
-        let output_data = output.get_internal_data_mut();
-        Zip::from(image.get_internal_data())
-            .and(output_data)
-            .par_for_each(|&src, dst| {
-                *dst = (src as f32) / (u8::MAX as f32);
-            });
+        let image_data = image.get_internal_data();
+        let height = image_data.shape()[0];
+        Zip::indexed(output.get_internal_data_mut()).par_for_each(|(x, y, c), dst| {
+            *dst = (image_data[(height - 1 - y, x, c)] as f32) / (u8::MAX as f32);
+        });
         Ok(output)
     }
 
@@ -70,7 +72,91 @@ impl MiscData {
         Ok(output)
     }
 
-    // TODO multi dimensional f32
+    /// Creates MiscData from an N-dimensional tensor, laying each tensor axis along the neuron
+    /// axis given for it in `axis_mapping`.
+    ///
+    /// Tensor axes mapped to the same neuron axis are flattened together in row major order, so
+    /// the earlier axis varies slowest. Neuron axes with no tensor axes mapped to them have a
+    /// size of 1. Axes along Y must either all be [`MiscDataAxis::Y`] or all be
+    /// [`MiscDataAxis::YTopDown`].
+    ///
+    /// # Example
+    /// ```
+    /// use feagi_sensorimotor::data_types::{MiscData, descriptors::MiscDataAxis};
+    /// use ndarray::ArrayD;
+    ///
+    /// // A 2 row by 3 column matrix, with its first row at the top
+    /// let matrix = ArrayD::<f32>::zeros(vec![2, 3]);
+    /// let misc_data = MiscData::new_from_tensor(
+    ///     matrix.view(),
+    ///     &[MiscDataAxis::YTopDown, MiscDataAxis::X],
+    /// ).unwrap();
+    /// assert_eq!(misc_data.get_dimensions().width, 3);
+    /// assert_eq!(misc_data.get_dimensions().height, 2);
+    /// ```
+    pub fn new_from_tensor(
+        tensor: ArrayViewD<f32>,
+        axis_mapping: &[MiscDataAxis],
+    ) -> Result<MiscData, FeagiDataError> {
+        let (dimensions, y_top_down) = neuron_dimensions_of_tensor(tensor.shape(), axis_mapping)?;
+        let mut output = MiscData::new(&MiscDataDimensions::new(
+            dimensions[0] as u32,
+            dimensions[1] as u32,
+            dimensions[2] as u32,
+        )?)?;
+        for (index, value) in tensor.indexed_iter() {
+            let xyz = neuron_index_of_tensor_index(
+                index.slice(),
+                tensor.shape(),
+                axis_mapping,
+                dimensions,
+                y_top_down,
+            );
+            output.data[xyz] = *value;
+        }
+        Ok(output)
+    }
+
+    //endregion
+
+    //region Export
+
+    /// Exports the data as an N-dimensional tensor of the given shape, with each tensor axis
+    /// laid along the neuron axis given for it in `axis_mapping`.
+    ///
+    /// This is the inverse of [`MiscData::new_from_tensor`]. Returns an error if the shape and
+    /// mapping do not cover the dimensions of this MiscData exactly.
+    pub fn export_as_tensor(
+        &self,
+        tensor_shape: &[usize],
+        axis_mapping: &[MiscDataAxis],
+    ) -> Result<ArrayD<f32>, FeagiDataError> {
+        let (dimensions, y_top_down) = neuron_dimensions_of_tensor(tensor_shape, axis_mapping)?;
+        if dimensions
+            != [
+                self.data.shape()[0],
+                self.data.shape()[1],
+                self.data.shape()[2],
+            ]
+        {
+            return Err(FeagiDataError::BadParameters(format!(
+                "A tensor of shape {:?} mapped as {:?} does not match {}!",
+                tensor_shape, axis_mapping, self
+            )));
+        }
+        let mut output = ArrayD::<f32>::zeros(IxDyn(tensor_shape));
+        for (index, value) in output.indexed_iter_mut() {
+            let xyz = neuron_index_of_tensor_index(
+                index.slice(),
+                tensor_shape,
+                axis_mapping,
+                dimensions,
+                y_top_down,
+            );
+            *value = self.data[xyz];
+        }
+        Ok(output)
+    }
 
     //endregion
 
@@ -117,7 +203,7 @@ impl MiscData {
                 // going from row major to cartesian
                 if val.abs() > EPSILON {
                     x_vec.push(x as u32 + x_offset);
-                    y_vec.push(y as u32); // flip y //TODO wheres the flip part????
+                    y_vec.push(y as u32); // Already in neuron orientation, no flip needed
                     z_vec.push(c as u32);
                     p_vec.push(val.clamp(-1.0, 1.0));
                 }
@@ -129,6 +215,76 @@ impl MiscData {
     // endregion
 }
 
+/// Size of each neuron axis (X, Y, Z) when the given tensor shape is laid out by the given axis
+/// mapping, and whether Y is top down.
+fn neuron_dimensions_of_tensor(
+    tensor_shape: &[usize],
+    axis_mapping: &[MiscDataAxis],
+) -> Result<([usize; 3], bool), FeagiDataError> {
+    if tensor_shape.len() != axis_mapping.len() {
+        return Err(FeagiDataError::BadParameters(format!(
+            "A tensor with {} axes needs {} axis mappings, but {} were given!",
+            tensor_shape.len(),
+            tensor_shape.len(),
+            axis_mapping.len()
+        )));
+    }
+    if tensor_shape.is_empty() || tensor_shape.contains(&0) {
+        return Err(FeagiDataError::BadParameters(
+            "Misc Data cannot be empty!".into(),
+        ));
+    }
+    let has_y = axis_mapping.contains(&MiscDataAxis::Y);
+    let has_y_top_down = axis_mapping.contains(&MiscDataAxis::YTopDown);
+    if has_y && has_y_top_down {
+        return Err(FeagiDataError::BadParameters(
+            "Tensor axes along Y must either all be bottom up or all be top down!".into(),
+        ));
+    }
+
+    let mut dimensions: [usize; 3] = [1, 1, 1];
+    for (axis_size, axis) in tensor_shape.iter().zip(axis_mapping) {
+        dimensions[neuron_axis_index(*axis)] *= axis_size;
+    }
+    if dimensions
+        .iter()
+        .any(|dimension| *dimension > u32::MAX as usize)
+    {
+        return Err(FeagiDataError::BadParameters(format!(
+            "A tensor of shape {:?} is too large for Misc Data!",
+            tensor_shape
+        )));
+    }
+    Ok((dimensions, has_y_top_down))
+}
+
+/// Position (x, y, z) in the Misc Data array of an element of the tensor.
+fn neuron_index_of_tensor_index(
+    tensor_index: &[usize],
+    tensor_shape: &[usize],
+    axis_mapping: &[MiscDataAxis],
+    dimensions: [usize; 3],
+    y_top_down: bool,
+) -> [usize; 3] {
+    let mut xyz: [usize; 3] = [0, 0, 0];
+    for ((index, axis_size), axis) in tensor_index.iter().zip(tensor_shape).zip(axis_mapping) {
+        let neuron_axis = neuron_axis_index(*axis);
+        xyz[neuron_axis] = xyz[neuron_axis] * axis_size + index;
+    }
+    if y_top_down {
+        xyz[1] = dimensions[1] - 1 - xyz[1];
+    }
+    xyz
+}
+
+fn neuron_axis_index(axis: MiscDataAxis) -> usize {
+    match axis {
+        MiscDataAxis::X => 0,
+        MiscDataAxis::Y | MiscDataAxis::YTopDown => 1,
+        MiscDataAxis::Z => 2,
+    }
+}
+
 impl std::fmt::Display for MiscData {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "MiscData({:?})", self.get_dimensions())
@@ -5,13 +5,18 @@
 //! - **[`ImageFrame`]** - Raw image data with color space support
 //! - **[`SegmentedImageFrame`]** - Images with segmentation labels
 //! - **[`MiscData`]** - Generic multi-dimensional data arrays
+//! - **[`AudioFrame`]** - Mono PCM audio samples
+//! - **[`EventStream`]** - Event camera (DVS) events
 //! - **[`Percentage`]** and variants - Normalized values in various dimensionalities
 //! - **[`SignedPercentage`]** and variants - Signed normalized values (-1 to 1)
+//! - **[`TextToken`]** and [`TextTokenizer`] - Text as token streams
 //!
 //! These types handle memory layout, color space conversions, and provide
 //! efficient interfaces for common sensor/actuator data formats.
 
+mod audio_frame;
 pub mod descriptors;
+mod event_stream;
 mod gaze_properties;
 mod image_filtering_settings;
 mod image_frame;
@@ -20,7 +25,10 @@ mod percentages;
 pub mod processing;
 mod segmented_image_frame;
 pub mod text_token;
+mod text_tokenizer;
 
+pub use audio_frame::AudioFrame;
+pub use event_stream::{Aedat2AddressFormat, DvsEvent, EventPolarity, EventStream};
 pub use gaze_properties::GazeProperties;
 pub use image_filtering_settings::ImageFilteringSettings;
 pub use image_frame::ImageFrame;
@@ -32,3 +40,4 @@ pub use text_token::{
     decode_token_id_from_misc_data, decode_token_id_from_xyzp_bitplanes,
     encode_token_id_to_misc_data, encode_token_id_to_xyzp_bitplanes, TextToken,
 };
+pub use text_tokenizer::{TextTokenDecoder, TextTokenizer};
@@ -0,0 +1,333 @@
+use crate::data_types::descriptors::{AudioFrameProperties, MiscDataDimensions};
+use crate::data_types::{AudioFrame, MiscData};
+use feagi_structures::FeagiDataError;
+use std::f32::consts::PI;
+
+/// Computes a magnitude spectrogram of an audio frame with a short-time Fourier transform.
+///
+/// The frame is cut into Hann windowed blocks of `window_size` samples, `hop_size` samples
+/// apart, and each block is run through a radix-2 FFT. The output is a [`MiscData`] of
+/// `number_time_windows` x `window_size / 2 + 1` x 1, with time along x (oldest first) and
+/// frequency bins along y (DC first). Magnitudes are scaled so a full scale sine wave centered
+/// on a bin reads as 1.0, and are clamped to [0.0, 1.0].
+#[derive(Debug, Clone)]
+pub struct ShortTimeFourierTransform {
+    input_properties: AudioFrameProperties,
+    window_size: u32,
+    hop_size: u32,
+    window: Vec<f32>,
+    twiddles: Vec<(f32, f32)>,
+    magnitude_scale: f32,
+    scratch_real: Vec<f32>,
+    scratch_imaginary: Vec<f32>,
+}
+
+impl ShortTimeFourierTransform {
+    pub fn new(
+        input_properties: AudioFrameProperties,
+        window_size: u32,
+        hop_size: u32,
+    ) -> Result<ShortTimeFourierTransform, FeagiDataError> {
+        if window_size < 2 || !window_size.is_power_of_two() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "FFT window size must be a power of two of at least 2, got {}!",
+                window_size
+            )));
+        }
+        if hop_size == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "FFT hop size cannot be zero!".into(),
+            ));
+        }
+        if window_size > input_properties.get_number_samples() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "FFT window size {} is larger than the {} samples of the audio frame!",
+                window_size,
+                input_properties.get_number_samples()
+            )));
+        }
+
+        let size = window_size as usize;
+        let window: Vec<f32> = (0..size)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / size as f32).cos())
+            .collect();
+        let twiddles: Vec<(f32, f32)> = (0..size / 2)
+            .map(|i| {
+                let angle = -2.0 * PI * i as f32 / size as f32;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let magnitude_scale = 2.0 / window.iter().sum::<f32>();
+
+        Ok(ShortTimeFourierTransform {
+            input_properties,
+            window_size,
+            hop_size,
+            window,
+            twiddles,
+            magnitude_scale,
+            scratch_real: vec![0.0; size],
+            scratch_imaginary: vec![0.0; size],
+        })
+    }
+
+    pub fn get_input_properties(&self) -> &AudioFrameProperties {
+        &self.input_properties
+    }
+
+    pub fn get_window_size(&self) -> u32 {
+        self.window_size
+    }
+
+    pub fn get_hop_size(&self) -> u32 {
+        self.hop_size
+    }
+
+    pub fn get_number_time_windows(&self) -> u32 {
+        1 + (self.input_properties.get_number_samples() - self.window_size) / self.hop_size
+    }
+
+    pub fn get_number_frequency_bins(&self) -> u32 {
+        self.window_size / 2 + 1
+    }
+
+    pub fn get_output_dimensions(&self) -> MiscDataDimensions {
+        MiscDataDimensions::new(
+            self.get_number_time_windows(),
+            self.get_number_frequency_bins(),
+            1,
+        )
+        .unwrap()
+    }
+
+    /// Returns the center frequency of a bin, in hertz.
+    pub fn get_bin_frequency_hz(&self, bin_index: u32) -> f32 {
+        bin_index as f32 * self.input_properties.get_sample_rate_hz() as f32
+            / self.window_size as f32
+    }
+
+    pub fn process(
+        &mut self,
+        input: &AudioFrame,
+        target: &mut MiscData,
+    ) -> Result<(), FeagiDataError> {
+        self.input_properties
+            .verify_audio_frame_matches_properties(input)?;
+        if target.get_dimensions() != self.get_output_dimensions() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected spectrogram target of {} but got {}!",
+                self.get_output_dimensions(),
+                target.get_dimensions()
+            )));
+        }
+
+        let samples = input.get_samples();
+        let size = self.window_size as usize;
+        let number_bins = self.get_number_frequency_bins() as usize;
+        let output = target.get_internal_data_mut();
+
+        for time_index in 0..self.get_number_time_windows() as usize {
+            let start = time_index * self.hop_size as usize;
+            for i in 0..size {
+                self.scratch_real[i] = samples[start + i] * self.window[i];
+                self.scratch_imaginary[i] = 0.0;
+            }
+            self.fft_in_place();
+            for bin in 0..number_bins {
+                let magnitude = (self.scratch_real[bin] * self.scratch_real[bin]
+                    + self.scratch_imaginary[bin] * self.scratch_imaginary[bin])
+                    .sqrt();
+                output[(time_index, bin, 0)] = (magnitude * self.magnitude_scale).clamp(0.0, 1.0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Iterative radix-2 decimation in time FFT over the scratch buffers.
+    fn fft_in_place(&mut self) {
+        let size = self.window_size as usize;
+        let bits = size.trailing_zeros();
+
+        for i in 0..size {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if j > i {
+                self.scratch_real.swap(i, j);
+                self.scratch_imaginary.swap(i, j);
+            }
+        }
+
+        let mut length = 2;
+        while length <= size {
+            let half = length / 2;
+            let twiddle_stride = size / length;
+            for start in (0..size).step_by(length) {
+                for k in 0..half {
+                    let (twiddle_real, twiddle_imaginary) = self.twiddles[k * twiddle_stride];
+                    let even = start + k;
+                    let odd = even + half;
+                    let odd_real = self.scratch_real[odd] * twiddle_real
+                        - self.scratch_imaginary[odd] * twiddle_imaginary;
+                    let odd_imaginary = self.scratch_real[odd] * twiddle_imaginary
+                        + self.scratch_imaginary[odd] * twiddle_real;
+                    self.scratch_real[odd] = self.scratch_real[even] - odd_real;
+                    self.scratch_imaginary[odd] = self.scratch_imaginary[even] - odd_imaginary;
+                    self.scratch_real[even] += odd_real;
+                    self.scratch_imaginary[even] += odd_imaginary;
+                }
+            }
+            length *= 2;
+        }
+    }
+}
+
+/// Pools the linear frequency bins of a spectrogram into mel spaced bands.
+///
+/// Each band is a triangular filter spanning its neighbours' centers, with weights normalized
+/// to sum to 1 so a band reads as the average magnitude it covers. When `dynamic_range_db` is
+/// above zero the bands are then log compressed, mapping `-dynamic_range_db` dB and below to
+/// 0.0 and 0 dB (full scale) to 1.0, which is closer to how loudness is perceived.
+#[derive(Debug, Clone)]
+pub struct MelFilterbank {
+    input_dimensions: MiscDataDimensions,
+    number_mel_bands: u32,
+    dynamic_range_db: f32,
+    filters: Vec<Vec<(usize, f32)>>, // Per band, (frequency bin index, weight)
+}
+
+impl MelFilterbank {
+    pub fn new(
+        input_dimensions: MiscDataDimensions,
+        sample_rate_hz: u32,
+        number_mel_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+        dynamic_range_db: f32,
+    ) -> Result<MelFilterbank, FeagiDataError> {
+        if input_dimensions.depth != 1 || input_dimensions.height < 2 {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Mel filterbank input must be a spectrogram with at least 2 frequency bins and a depth of 1, got {}!",
+                input_dimensions
+            )));
+        }
+        if number_mel_bands == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Mel filterbank needs at least one band!".into(),
+            ));
+        }
+        let nyquist_hz = sample_rate_hz as f32 / 2.0;
+        let is_valid_range = min_frequency_hz >= 0.0
+            && min_frequency_hz < max_frequency_hz
+            && max_frequency_hz <= nyquist_hz;
+        if !is_valid_range {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Mel filterbank frequency range must satisfy 0 <= min < max <= {} Hz, got {} to {} Hz!",
+                nyquist_hz, min_frequency_hz, max_frequency_hz
+            )));
+        }
+        if dynamic_range_db.is_nan() || dynamic_range_db < 0.0 {
+            return Err(FeagiDataError::BadParameters(
+                "Mel filterbank dynamic range cannot be negative!".into(),
+            ));
+        }
+
+        let number_bins = input_dimensions.height as usize;
+        let hz_per_bin = nyquist_hz / (number_bins - 1) as f32;
+        let min_mel = hz_to_mel(min_frequency_hz);
+        let max_mel = hz_to_mel(max_frequency_hz);
+        let edges_hz: Vec<f32> = (0..number_mel_bands + 2)
+            .map(|i| {
+                mel_to_hz(min_mel + (max_mel - min_mel) * i as f32 / (number_mel_bands + 1) as f32)
+            })
+            .collect();
+
+        let mut filters: Vec<Vec<(usize, f32)>> = Vec::with_capacity(number_mel_bands as usize);
+        for band in 0..number_mel_bands as usize {
+            let (lower, center, upper) = (edges_hz[band], edges_hz[band + 1], edges_hz[band + 2]);
+            let mut filter: Vec<(usize, f32)> = Vec::new();
+            for bin in 0..number_bins {
+                let frequency = bin as f32 * hz_per_bin;
+                let weight = if frequency > lower && frequency <= center {
+                    (frequency - lower) / (center - lower)
+                } else if frequency > center && frequency < upper {
+                    (upper - frequency) / (upper - center)
+                } else {
+                    0.0
+                };
+                if weight > 0.0 {
+                    filter.push((bin, weight));
+                }
+            }
+            if filter.is_empty() {
+                // Band is narrower than a bin, so just sample the nearest one
+                let nearest = ((center / hz_per_bin).round() as usize).min(number_bins - 1);
+                filter.push((nearest, 1.0));
+            }
+            let total: f32 = filter.iter().map(|(_, weight)| weight).sum();
+            for (_, weight) in filter.iter_mut() {
+                *weight /= total;
+            }
+            filters.push(filter);
+        }
+
+        Ok(MelFilterbank {
+            input_dimensions,
+            number_mel_bands,
+            dynamic_range_db,
+            filters,
+        })
+    }
+
+    pub fn get_input_dimensions(&self) -> &MiscDataDimensions {
+        &self.input_dimensions
+    }
+
+    pub fn get_output_dimensions(&self) -> MiscDataDimensions {
+        MiscDataDimensions::new(self.input_dimensions.width, self.number_mel_bands, 1).unwrap()
+    }
+
+    pub fn process(&self, input: &MiscData, target: &mut MiscData) -> Result<(), FeagiDataError> {
+        if input.get_dimensions() != self.input_dimensions {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected spectrogram of {} but got {}!",
+                self.input_dimensions,
+                input.get_dimensions()
+            )));
+        }
+        if target.get_dimensions() != self.get_output_dimensions() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected mel spectrogram target of {} but got {}!",
+                self.get_output_dimensions(),
+                target.get_dimensions()
+            )));
+        }
+
+        let input = input.get_internal_data();
+        let output = target.get_internal_data_mut();
+        for time_index in 0..self.input_dimensions.width as usize {
+            for (band, filter) in self.filters.iter().enumerate() {
+                let magnitude: f32 = filter
+                    .iter()
+                    .map(|(bin, weight)| input[(time_index, *bin, 0)] * weight)
+                    .sum();
+                output[(time_index, band, 0)] = self.compress(magnitude);
+            }
+        }
+        Ok(())
+    }
+
+    fn compress(&self, magnitude: f32) -> f32 {
+        if self.dynamic_range_db == 0.0 {
+            return magnitude.clamp(0.0, 1.0);
+        }
+        let decibels = 20.0 * magnitude.max(f32::MIN_POSITIVE).log10();
+        (1.0 + decibels / self.dynamic_range_db).clamp(0.0, 1.0)
+    }
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
@@ -0,0 +1,633 @@
+use crate::data_types::descriptors::{ColorChannelLayout, ColorSpace, ImageFrameProperties};
+use crate::data_types::{ImageFrame, Percentage};
+use feagi_structures::FeagiDataError;
+use ndarray::{Array2, Zip};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+// Sobel kernels sum to 4 on each side, so a full 0 -> 255 step edge has a gradient of 1020
+const SOBEL_TO_PIXEL_SCALE: f32 = 0.25;
+// Sobel gradients are 8 times the per pixel change of a smooth ramp
+const SOBEL_TO_PER_PIXEL_SCALE: f32 = 0.125;
+// Below this per pixel brightness slope, the direction of motion cannot be told
+const MINIMUM_MOTION_GRADIENT: f32 = 1.0;
+const HUE_DEGREES: f32 = 360.0;
+
+/// How [`ImageEdgeDetector`] finds edges.
+///
+/// Thresholds are on the gradient magnitude, scaled so a sharp edge from black to full
+/// brightness reads as 255.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EdgeDetectionMethod {
+    /// Gradient magnitude of the Sobel operator, with magnitudes below `threshold` zeroed.
+    Sobel { threshold: u8 },
+    /// Canny edges: the image is smoothed, Sobel gradients are thinned to one pixel wide
+    /// ridges, and ridges at least `high_threshold` strong are kept along with any ridges at
+    /// least `low_threshold` strong connected to them. Edges are output as 255.
+    Canny {
+        low_threshold: u8,
+        high_threshold: u8,
+    },
+}
+
+/// Which motion [`ImageMotionDetector`] outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MotionDirection {
+    /// How much each pixel changed, in whichever direction
+    Any,
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Returns the properties of the single channel feature maps the detectors in this module
+/// output for the given input.
+pub(crate) fn feature_map_properties(
+    input_properties: &ImageFrameProperties,
+) -> ImageFrameProperties {
+    ImageFrameProperties::new(
+        input_properties.get_image_resolution(),
+        input_properties.get_color_space(),
+        ColorChannelLayout::GrayScale,
+    )
+    .unwrap() // Any resolution and color space is valid for grayscale
+}
+
+/// Outputs a grayscale edge map of an image, with the same resolution and color space.
+///
+/// Color images are converted to luminance first.
+#[derive(Debug, Clone)]
+pub struct ImageEdgeDetector {
+    input_properties: ImageFrameProperties,
+    method: EdgeDetectionMethod,
+}
+
+impl ImageEdgeDetector {
+    pub fn new(
+        input_properties: ImageFrameProperties,
+        method: EdgeDetectionMethod,
+    ) -> Result<ImageEdgeDetector, FeagiDataError> {
+        Self::verify_method(&method)?;
+        Ok(ImageEdgeDetector {
+            input_properties,
+            method,
+        })
+    }
+
+    pub fn get_input_properties(&self) -> &ImageFrameProperties {
+        &self.input_properties
+    }
+
+    pub fn get_output_properties(&self) -> ImageFrameProperties {
+        feature_map_properties(&self.input_properties)
+    }
+
+    pub fn get_method(&self) -> &EdgeDetectionMethod {
+        &self.method
+    }
+
+    pub fn set_method(&mut self, method: EdgeDetectionMethod) -> Result<(), FeagiDataError> {
+        Self::verify_method(&method)?;
+        self.method = method;
+        Ok(())
+    }
+
+    pub fn process(
+        &self,
+        source: &ImageFrame,
+        destination: &mut ImageFrame,
+    ) -> Result<(), FeagiDataError> {
+        self.input_properties
+            .verify_image_frame_matches_properties(source)?;
+        self.get_output_properties()
+            .verify_image_frame_matches_properties(destination)?;
+
+        let output = destination.get_internal_data_mut();
+        match self.method {
+            EdgeDetectionMethod::Sobel { threshold } => {
+                let (gradient_x, gradient_y) = sobel(&luminance(source));
+                Zip::from(output.index_axis_mut(ndarray::Axis(2), 0))
+                    .and(&gradient_x)
+                    .and(&gradient_y)
+                    .par_for_each(|pixel, gx, gy| {
+                        let magnitude = gx.hypot(*gy) * SOBEL_TO_PIXEL_SCALE;
+                        *pixel = if magnitude >= threshold as f32 {
+                            magnitude.round().min(255.0) as u8
+                        } else {
+                            0
+                        };
+                    });
+            }
+            EdgeDetectionMethod::Canny {
+                low_threshold,
+                high_threshold,
+            } => {
+                let edges = canny(&luminance(source), low_threshold, high_threshold);
+                Zip::from(output.index_axis_mut(ndarray::Axis(2), 0))
+                    .and(&edges)
+                    .par_for_each(|pixel, is_edge| *pixel = if *is_edge { 255 } else { 0 });
+            }
+        }
+        destination.skip_encoding = false;
+        Ok(())
+    }
+
+    fn verify_method(method: &EdgeDetectionMethod) -> Result<(), FeagiDataError> {
+        if let EdgeDetectionMethod::Canny {
+            low_threshold,
+            high_threshold,
+        } = method
+        {
+            if low_threshold > high_threshold {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "Canny low threshold {} cannot be above the high threshold {}!",
+                    low_threshold, high_threshold
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Outputs a grayscale map of motion between consecutive images, with the same resolution and
+/// color space.
+///
+/// Each pixel is the brightness change from the previous image, and changes smaller than
+/// `difference_threshold` are ignored. For a specific direction, the change is weighted by how
+/// much the brightness edge at that pixel moved in that direction, which is estimated from
+/// the direction of the brightness gradient (normal flow). Changes over flat areas, such as a
+/// light turning on, have no direction and only show up for [`MotionDirection::Any`].
+///
+/// The first image has nothing to compare against, so it outputs no motion.
+#[derive(Debug, Clone)]
+pub struct ImageMotionDetector {
+    input_properties: ImageFrameProperties,
+    difference_threshold: u8,
+    direction: MotionDirection,
+    previous_luminance: Option<Array2<f32>>,
+}
+
+impl ImageMotionDetector {
+    pub fn new(
+        input_properties: ImageFrameProperties,
+        difference_threshold: u8,
+        direction: MotionDirection,
+    ) -> ImageMotionDetector {
+        ImageMotionDetector {
+            input_properties,
+            difference_threshold,
+            direction,
+            previous_luminance: None,
+        }
+    }
+
+    pub fn get_input_properties(&self) -> &ImageFrameProperties {
+        &self.input_properties
+    }
+
+    pub fn get_output_properties(&self) -> ImageFrameProperties {
+        feature_map_properties(&self.input_properties)
+    }
+
+    pub fn get_difference_threshold(&self) -> u8 {
+        self.difference_threshold
+    }
+
+    pub fn get_direction(&self) -> MotionDirection {
+        self.direction
+    }
+
+    pub fn set_difference_threshold(&mut self, difference_threshold: u8) {
+        self.difference_threshold = difference_threshold;
+    }
+
+    pub fn set_direction(&mut self, direction: MotionDirection) {
+        self.direction = direction;
+    }
+
+    pub fn process(
+        &mut self,
+        source: &ImageFrame,
+        destination: &mut ImageFrame,
+    ) -> Result<(), FeagiDataError> {
+        self.input_properties
+            .verify_image_frame_matches_properties(source)?;
+        self.get_output_properties()
+            .verify_image_frame_matches_properties(destination)?;
+
+        destination.skip_encoding = false;
+        let current = luminance(source);
+        let output = destination.get_internal_data_mut();
+        let previous = match self.previous_luminance.replace(current.clone()) {
+            Some(previous) => previous,
+            None => {
+                output.fill(0);
+                return Ok(());
+            }
+        };
+
+        let threshold = self.difference_threshold as f32;
+        let direction = self.direction;
+        let (gradient_x, gradient_y) = sobel(&((&current + &previous) * 0.5));
+        Zip::from(output.index_axis_mut(ndarray::Axis(2), 0))
+            .and(&current)
+            .and(&previous)
+            .and(&gradient_x)
+            .and(&gradient_y)
+            .par_for_each(|pixel, current, previous, gx, gy| {
+                let change = current - previous;
+                if change == 0.0 || change.abs() < threshold {
+                    *pixel = 0;
+                    return;
+                }
+                let weight = direction_weight(direction, change, *gx, *gy);
+                *pixel = (change.abs() * weight).round().min(255.0) as u8;
+            });
+        Ok(())
+    }
+}
+
+/// How much a brightness change at a pixel with the given Sobel gradients is motion in the
+/// given direction, from 0 to 1.
+fn direction_weight(direction: MotionDirection, change: f32, gx: f32, gy: f32) -> f32 {
+    if direction == MotionDirection::Any {
+        return 1.0;
+    }
+    let (gx, gy) = (gx * SOBEL_TO_PER_PIXEL_SCALE, gy * SOBEL_TO_PER_PIXEL_SCALE);
+    let slope = gx.hypot(gy);
+    if slope < MINIMUM_MOTION_GRADIENT {
+        return 0.0;
+    }
+    // Brightening means the brighter side moved over this pixel, which is against the gradient
+    let flow_x = -change.signum() * gx / slope;
+    let flow_y = -change.signum() * gy / slope;
+    let weight = match direction {
+        MotionDirection::Any => 1.0,
+        MotionDirection::Left => -flow_x,
+        MotionDirection::Right => flow_x,
+        MotionDirection::Up => -flow_y, // Y 0 is the top row
+        MotionDirection::Down => flow_y,
+    };
+    weight.max(0.0)
+}
+
+/// Outputs a grayscale mask of the pixels of an RGB(A) image within a range of HSV colors,
+/// with the same resolution and color space. Matching pixels are 255, others 0.
+///
+/// - Hue is in degrees from 0 to 360, starting at red. A range with a start larger than its end
+///   wraps around through 0, so `330.0..=30.0` matches reds.
+/// - Saturation and value (brightness) are from 0 to 1.
+/// - Matching areas (4-connected) smaller than `minimum_blob_area` pixels are dropped, to
+///   remove speckle noise. A minimum of 0 or 1 keeps every pixel.
+///
+/// Alpha is ignored.
+#[derive(Debug, Clone)]
+pub struct ImageColorBlobMasker {
+    input_properties: ImageFrameProperties,
+    hue_range: RangeInclusive<f32>,
+    saturation_range: RangeInclusive<Percentage>,
+    value_range: RangeInclusive<Percentage>,
+    minimum_blob_area: u32,
+}
+
+impl ImageColorBlobMasker {
+    pub fn new(
+        input_properties: ImageFrameProperties,
+        hue_range: RangeInclusive<f32>,
+        saturation_range: RangeInclusive<Percentage>,
+        value_range: RangeInclusive<Percentage>,
+        minimum_blob_area: u32,
+    ) -> Result<ImageColorBlobMasker, FeagiDataError> {
+        match input_properties.get_color_channel_layout() {
+            ColorChannelLayout::RGB | ColorChannelLayout::RGBA => {}
+            layout => {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "Color blob masks need RGB or RGBA images, but got {}!",
+                    layout
+                )))
+            }
+        }
+        verify_color_ranges(&hue_range, &saturation_range, &value_range)?;
+        Ok(ImageColorBlobMasker {
+            input_properties,
+            hue_range,
+            saturation_range,
+            value_range,
+            minimum_blob_area,
+        })
+    }
+
+    pub fn get_input_properties(&self) -> &ImageFrameProperties {
+        &self.input_properties
+    }
+
+    pub fn get_output_properties(&self) -> ImageFrameProperties {
+        feature_map_properties(&self.input_properties)
+    }
+
+    pub fn get_hue_range(&self) -> &RangeInclusive<f32> {
+        &self.hue_range
+    }
+
+    pub fn get_saturation_range(&self) -> &RangeInclusive<Percentage> {
+        &self.saturation_range
+    }
+
+    pub fn get_value_range(&self) -> &RangeInclusive<Percentage> {
+        &self.value_range
+    }
+
+    pub fn get_minimum_blob_area(&self) -> u32 {
+        self.minimum_blob_area
+    }
+
+    pub fn set_color_ranges(
+        &mut self,
+        hue_range: RangeInclusive<f32>,
+        saturation_range: RangeInclusive<Percentage>,
+        value_range: RangeInclusive<Percentage>,
+    ) -> Result<(), FeagiDataError> {
+        verify_color_ranges(&hue_range, &saturation_range, &value_range)?;
+        self.hue_range = hue_range;
+        self.saturation_range = saturation_range;
+        self.value_range = value_range;
+        Ok(())
+    }
+
+    pub fn set_minimum_blob_area(&mut self, minimum_blob_area: u32) {
+        self.minimum_blob_area = minimum_blob_area;
+    }
+
+    pub fn process(
+        &self,
+        source: &ImageFrame,
+        destination: &mut ImageFrame,
+    ) -> Result<(), FeagiDataError> {
+        self.input_properties
+            .verify_image_frame_matches_properties(source)?;
+        self.get_output_properties()
+            .verify_image_frame_matches_properties(destination)?;
+
+        let pixels = source.get_internal_data();
+        let (hue_start, hue_end) = (*self.hue_range.start(), *self.hue_range.end());
+        let saturation_range =
+            self.saturation_range.start().get_as_0_1()..=self.saturation_range.end().get_as_0_1();
+        let value_range =
+            self.value_range.start().get_as_0_1()..=self.value_range.end().get_as_0_1();
+
+        let mut mask = destination
+            .get_internal_data_mut()
+            .index_axis_mut(ndarray::Axis(2), 0);
+        Zip::indexed(&mut mask).par_for_each(|(y, x), pixel| {
+            let (hue, saturation, value) =
+                rgb_to_hsv(pixels[(y, x, 0)], pixels[(y, x, 1)], pixels[(y, x, 2)]);
+            let hue_matches = if hue_start <= hue_end {
+                hue >= hue_start && hue <= hue_end
+            } else {
+                hue >= hue_start || hue <= hue_end
+            };
+            let matches = hue_matches
+                && saturation_range.contains(&saturation)
+                && value_range.contains(&value);
+            *pixel = if matches { 255 } else { 0 };
+        });
+
+        if self.minimum_blob_area > 1 {
+            remove_small_blobs(&mut mask, self.minimum_blob_area as usize);
+        }
+        destination.skip_encoding = false;
+        Ok(())
+    }
+}
+
+fn verify_color_ranges(
+    hue_range: &RangeInclusive<f32>,
+    saturation_range: &RangeInclusive<Percentage>,
+    value_range: &RangeInclusive<Percentage>,
+) -> Result<(), FeagiDataError> {
+    let valid_hue = 0.0..=HUE_DEGREES;
+    if !valid_hue.contains(hue_range.start()) || !valid_hue.contains(hue_range.end()) {
+        return Err(FeagiDataError::BadParameters(format!(
+            "Hue range {:?} must be within 0 to 360 degrees!",
+            hue_range
+        )));
+    }
+    if saturation_range.is_empty() {
+        return Err(FeagiDataError::BadParameters(
+            "Saturation range appears to be empty! Are your bounds correct?".into(),
+        ));
+    }
+    if value_range.is_empty() {
+        return Err(FeagiDataError::BadParameters(
+            "Value range appears to be empty! Are your bounds correct?".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Brightness of each pixel, from 0 to 255, indexed by (y, x).
+fn luminance(image: &ImageFrame) -> Array2<f32> {
+    let pixels = image.get_internal_data();
+    let (red_weight, green_weight, blue_weight) = match image.get_color_space() {
+        ColorSpace::Linear => (0.2126f32, 0.7152f32, 0.0722f32),
+        ColorSpace::Gamma => (0.299f32, 0.587f32, 0.114f32),
+    };
+    let mut output = Array2::<f32>::zeros((pixels.shape()[0], pixels.shape()[1]));
+    let layout = *image.get_channel_layout();
+    Zip::indexed(&mut output).par_for_each(|(y, x), value| {
+        *value = match layout {
+            // Two channel images are grayscale and alpha
+            ColorChannelLayout::GrayScale | ColorChannelLayout::RG => pixels[(y, x, 0)] as f32,
+            ColorChannelLayout::RGB | ColorChannelLayout::RGBA => {
+                red_weight * pixels[(y, x, 0)] as f32
+                    + green_weight * pixels[(y, x, 1)] as f32
+                    + blue_weight * pixels[(y, x, 2)] as f32
+            }
+        };
+    });
+    output
+}
+
+/// Reads a pixel, repeating the border pixels past the edges of the image.
+#[inline]
+fn clamped_pixel(image: &Array2<f32>, y: isize, x: isize) -> f32 {
+    let y = y.clamp(0, image.shape()[0] as isize - 1) as usize;
+    let x = x.clamp(0, image.shape()[1] as isize - 1) as usize;
+    image[(y, x)]
+}
+
+/// Horizontal and vertical Sobel gradients. Positive X gradients get brighter to the right,
+/// positive Y gradients get brighter downward.
+fn sobel(image: &Array2<f32>) -> (Array2<f32>, Array2<f32>) {
+    let mut gradient_x = Array2::<f32>::zeros(image.raw_dim());
+    let mut gradient_y = Array2::<f32>::zeros(image.raw_dim());
+    Zip::indexed(&mut gradient_x)
+        .and(&mut gradient_y)
+        .par_for_each(|(y, x), gx, gy| {
+            let (y, x) = (y as isize, x as isize);
+            let p = |dy: isize, dx: isize| clamped_pixel(image, y + dy, x + dx);
+            *gx = (p(-1, 1) + 2.0 * p(0, 1) + p(1, 1)) - (p(-1, -1) + 2.0 * p(0, -1) + p(1, -1));
+            *gy = (p(1, -1) + 2.0 * p(1, 0) + p(1, 1)) - (p(-1, -1) + 2.0 * p(-1, 0) + p(-1, 1));
+        });
+    (gradient_x, gradient_y)
+}
+
+/// 3x3 binomial blur, an approximation of a Gaussian.
+fn blur(image: &Array2<f32>) -> Array2<f32> {
+    let mut output = Array2::<f32>::zeros(image.raw_dim());
+    Zip::indexed(&mut output).par_for_each(|(y, x), value| {
+        let (y, x) = (y as isize, x as isize);
+        let p = |dy: isize, dx: isize| clamped_pixel(image, y + dy, x + dx);
+        *value = (p(-1, -1)
+            + 2.0 * p(-1, 0)
+            + p(-1, 1)
+            + 2.0 * p(0, -1)
+            + 4.0 * p(0, 0)
+            + 2.0 * p(0, 1)
+            + p(1, -1)
+            + 2.0 * p(1, 0)
+            + p(1, 1))
+            / 16.0;
+    });
+    output
+}
+
+fn canny(image: &Array2<f32>, low_threshold: u8, high_threshold: u8) -> Array2<bool> {
+    let (gradient_x, gradient_y) = sobel(&blur(image));
+    let magnitude = Zip::from(&gradient_x)
+        .and(&gradient_y)
+        .par_map_collect(|gx, gy| gx.hypot(*gy) * SOBEL_TO_PIXEL_SCALE);
+    let (height, width) = (magnitude.shape()[0] as isize, magnitude.shape()[1] as isize);
+    let magnitude_at = |y: isize, x: isize| {
+        if y < 0 || x < 0 || y >= height || x >= width {
+            0.0
+        } else {
+            magnitude[(y as usize, x as usize)]
+        }
+    };
+
+    // Thin edges by only keeping magnitudes that peak across the edge. Ties keep the pixel
+    // on the side the gradient points away from, so plateaus stay one pixel wide
+    let low_threshold = low_threshold as f32;
+    let ridges = Zip::indexed(&magnitude)
+        .and(&gradient_x)
+        .and(&gradient_y)
+        .par_map_collect(|(y, x), magnitude, gx, gy| {
+            if *magnitude < low_threshold || *magnitude == 0.0 {
+                return 0.0;
+            }
+            let angle = gy.atan2(*gx).to_degrees().rem_euclid(180.0);
+            let (dy, dx) = if !(22.5..157.5).contains(&angle) {
+                (0, 1)
+            } else if angle < 67.5 {
+                (1, 1)
+            } else if angle < 112.5 {
+                (1, 0)
+            } else {
+                (1, -1)
+            };
+            let (y, x) = (y as isize, x as isize);
+            if *magnitude > magnitude_at(y - dy, x - dx)
+                && *magnitude >= magnitude_at(y + dy, x + dx)
+            {
+                *magnitude
+            } else {
+                0.0
+            }
+        });
+
+    // Hysteresis: grow strong edges through connected weak edges
+    let high_threshold = (high_threshold as f32).max(f32::MIN_POSITIVE);
+    let mut edges = Array2::<bool>::from_elem(ridges.raw_dim(), false);
+    let mut frontier: VecDeque<(usize, usize)> = ridges
+        .indexed_iter()
+        .filter(|(_, ridge)| **ridge >= high_threshold)
+        .map(|(index, _)| index)
+        .collect();
+    for index in frontier.iter() {
+        edges[*index] = true;
+    }
+    while let Some((y, x)) = frontier.pop_front() {
+        for dy in -1..=1isize {
+            for dx in -1..=1isize {
+                let (neighbor_y, neighbor_x) = (y as isize + dy, x as isize + dx);
+                if neighbor_y < 0 || neighbor_x < 0 || neighbor_y >= height || neighbor_x >= width {
+                    continue;
+                }
+                let neighbor = (neighbor_y as usize, neighbor_x as usize);
+                if !edges[neighbor] && ridges[neighbor] > 0.0 {
+                    edges[neighbor] = true;
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+    }
+    edges
+}
+
+/// Returns hue in degrees, and saturation and value from 0 to 1.
+fn rgb_to_hsv(red: u8, green: u8, blue: u8) -> (f32, f32, f32) {
+    let (red, green, blue) = (
+        red as f32 / 255.0,
+        green as f32 / 255.0,
+        blue as f32 / 255.0,
+    );
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let chroma = max - min;
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == red {
+        60.0 * ((green - blue) / chroma).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / chroma + 2.0)
+    } else {
+        60.0 * ((red - green) / chroma + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+    (hue, saturation, max)
+}
+
+/// Zeroes 4-connected areas of nonzero pixels smaller than `minimum_area`.
+fn remove_small_blobs(mask: &mut ndarray::ArrayViewMut2<u8>, minimum_area: usize) {
+    let (height, width) = (mask.shape()[0], mask.shape()[1]);
+    let mut visited = Array2::<bool>::from_elem((height, width), false);
+    let mut blob: Vec<(usize, usize)> = Vec::new();
+    let mut frontier: VecDeque<(usize, usize)> = VecDeque::new();
+    for start_y in 0..height {
+        for start_x in 0..width {
+            if visited[(start_y, start_x)] || mask[(start_y, start_x)] == 0 {
+                continue;
+            }
+            blob.clear();
+            visited[(start_y, start_x)] = true;
+            frontier.push_back((start_y, start_x));
+            while let Some((y, x)) = frontier.pop_front() {
+                blob.push((y, x));
+                let neighbors = [
+                    (y.wrapping_sub(1), x),
+                    (y + 1, x),
+                    (y, x.wrapping_sub(1)),
+                    (y, x + 1),
+                ];
+                for neighbor in neighbors {
+                    if neighbor.0 < height
+                        && neighbor.1 < width
+                        && !visited[neighbor]
+                        && mask[neighbor] != 0
+                    {
+                        visited[neighbor] = true;
+                        frontier.push_back(neighbor);
+                    }
+                }
+            }
+            if blob.len() < minimum_area {
+                for pixel in blob.iter() {
+                    mask[*pixel] = 0;
+                }
+            }
+        }
+    }
+}
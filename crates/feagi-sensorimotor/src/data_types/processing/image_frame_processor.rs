@@ -19,8 +19,11 @@ pub struct ImageFrameProcessor {
     offset_brightness_by: Option<i32>,
     /// Optional contrast adjustment factor
     change_contrast_by: Option<f32>,
-    /// Whether to convert the image to grayscale (only allowed on RGB/RGBA images)
+    /// Whether to convert the image to grayscale (alpha is dropped)
     convert_to_grayscale: bool,
+    /// Whether to premultiply the color channels by alpha (compositing over black, only allowed on images with alpha)
+    #[serde(default)]
+    premultiply_alpha: bool,
 }
 
 impl std::fmt::Display for ImageFrameProcessor {
@@ -60,6 +63,10 @@ impl std::fmt::Display for ImageFrameProcessor {
             None => String::new(),
             Some(change_contrast_by) => format!("Change contrast by {}", change_contrast_by),
         });
+        steps += &*(match self.premultiply_alpha {
+            false => String::new(),
+            true => "Premultiply alpha".to_string(),
+        });
         steps += &*(match self.convert_to_grayscale {
             false => String::new(),
             true => "Convert to grayscale".to_string(),
@@ -78,6 +85,7 @@ impl ImageFrameProcessor {
             change_contrast_by: None,
             convert_color_space_to: None,
             convert_to_grayscale: false,
+            premultiply_alpha: false,
         }
     }
 
@@ -87,9 +95,7 @@ impl ImageFrameProcessor {
     ) -> Result<Self, FeagiDataError> {
         let mut definition = ImageFrameProcessor::new(*input);
         if output.get_color_channel_layout() != input.get_color_channel_layout() {
-            if output.get_color_channel_layout() == ColorChannelLayout::GrayScale
-                && input.get_color_channel_layout() == ColorChannelLayout::RGB
-            {
+            if output.get_color_channel_layout() == ColorChannelLayout::GrayScale {
                 // supported
                 definition.convert_to_grayscale = true;
             } else {
@@ -138,7 +144,6 @@ impl ImageFrameProcessor {
             .verify_image_frame_matches_properties(verifying_image)
     }
 
-    // TODO 2 / 4 channel pipelines!
     // Due to image segmentor, I would argue the most common route is crop + resize + grayscale
     pub fn process_image(
         &self,
@@ -155,6 +160,7 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: false,
+                premultiply_alpha: false,
             } => {
                 // For pass-through: copy ONLY pixel data into the pre-allocated destination buffer.
                 // Do NOT replace the destination `ImageFrame` struct; downstream stages rely on the
@@ -206,6 +212,7 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: false,
+                premultiply_alpha: false,
             } => {
                 destination.skip_encoding = false;
                 crop(
@@ -225,6 +232,7 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: false,
+                premultiply_alpha: false,
             } => {
                 destination.skip_encoding = false;
                 resize(source, destination)
@@ -239,13 +247,10 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: true,
+                premultiply_alpha: _,
             } => {
                 destination.skip_encoding = false;
-                to_grayscale(
-                    source,
-                    destination,
-                    self.input_image_properties.get_color_space(),
-                )
+                to_grayscale(source, destination, &self.get_grayscale_conversion())
             }
 
             // Cropping, Resizing
@@ -257,6 +262,7 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: false,
+                premultiply_alpha: false,
             } => {
                 destination.skip_encoding = false;
                 crop_and_resize(source, destination, cropping_from)
@@ -271,6 +277,7 @@ impl ImageFrameProcessor {
                 offset_brightness_by: None,
                 change_contrast_by: None,
                 convert_to_grayscale: true,
+                premultiply_alpha: _,
             } => {
                 destination.skip_encoding = false;
                 crop_and_resize_and_grayscale(
@@ -278,7 +285,7 @@ impl ImageFrameProcessor {
                     destination,
                     cropping_from,
                     final_resize_xy_to,
-                    self.input_image_properties.get_color_space(),
+                    &self.get_grayscale_conversion(),
                 )
             }
 
@@ -286,20 +293,30 @@ impl ImageFrameProcessor {
             _ => {
                 // This function is much slower, There may be some optimization work possible, but ensure the most common step combinations have an accelerated path
                 let is_cropping_is_resizing = (self.cropping_from, self.final_resize_xy_to);
-                let final_properties = self.get_output_image_properties();
-                let mut processing =
-                    ImageFrame::new_from_image_frame_properties(&final_properties)?; // TODO we can optimize this
+                // Cropping and resizing keep the input channels, grayscale conversion is done last
+                let mut processing = match is_cropping_is_resizing {
+                    (None, None) => source.clone(),
+                    _ => {
+                        let resized_properties = ImageFrameProperties::new(
+                            self.get_output_image_properties().get_image_resolution(),
+                            self.input_image_properties.get_color_space(),
+                            self.input_image_properties.get_color_channel_layout(),
+                        )?;
+                        ImageFrame::new_from_image_frame_properties(&resized_properties)?
+                        // TODO we can optimize this
+                    }
+                };
 
                 match is_cropping_is_resizing {
                     (None, None) => {
-                        processing = source.clone();
+                        // Already copied
                     }
                     (Some(cropping_from), None) => {
                         crop(
                             source,
                             &mut processing,
                             &cropping_from,
-                            self.get_output_channel_count(),
+                            source.get_color_channel_count(),
                         )?;
                     }
                     (None, Some(_final_resize_xy_to)) => {
@@ -336,7 +353,14 @@ impl ImageFrameProcessor {
                 }
 
                 if self.convert_to_grayscale {
-                    return Err(FeagiDataError::NotImplemented);
+                    // Premultiplication (if any) is done by the conversion
+                    to_grayscale(&processing, destination, &self.get_grayscale_conversion())?;
+                    destination.skip_encoding = false;
+                    return Ok(());
+                }
+
+                if self.premultiply_alpha {
+                    processing.premultiply_alpha();
                 }
 
                 // Copy ONLY pixel data into the pre-allocated destination buffer.
@@ -413,10 +437,27 @@ impl ImageFrameProcessor {
         &mut self,
         convert_to_grayscale: bool,
     ) -> Result<&mut Self, FeagiDataError> {
-        if self.input_image_properties.get_color_channel_layout() == ColorChannelLayout::RG {
-            return Err(FeagiDataError::NotImplemented);
+        match self.input_image_properties.get_color_channel_layout() {
+            // Already grayscale, nothing to convert
+            ColorChannelLayout::GrayScale => self.convert_to_grayscale = false,
+            _ => self.convert_to_grayscale = convert_to_grayscale,
+        }
+        Ok(self)
+    }
+
+    pub fn set_alpha_premultiplication(
+        &mut self,
+        premultiply_alpha: bool,
+    ) -> Result<&mut Self, FeagiDataError> {
+        match self
+            .input_image_properties
+            .get_color_channel_layout()
+            .get_alpha_channel_index()
+        {
+            // No alpha, nothing to premultiply by
+            None => self.premultiply_alpha = false,
+            Some(_) => self.premultiply_alpha = premultiply_alpha,
         }
-        self.convert_to_grayscale = convert_to_grayscale;
         Ok(self)
     }
 
@@ -429,6 +470,7 @@ impl ImageFrameProcessor {
         self.offset_brightness_by = None;
         self.change_contrast_by = None;
         self.convert_to_grayscale = false;
+        self.premultiply_alpha = false;
         self
     }
 
@@ -462,6 +504,11 @@ impl ImageFrameProcessor {
         self
     }
 
+    pub fn clear_alpha_premultiplication(&mut self) -> &Self {
+        self.premultiply_alpha = false;
+        self
+    }
+
     //endregion
 
     //endregion
@@ -477,6 +524,14 @@ impl ImageFrameProcessor {
             .into()
     }
 
+    fn get_grayscale_conversion(&self) -> GrayscaleConversion {
+        GrayscaleConversion::new(
+            self.input_image_properties.get_color_channel_layout(),
+            self.input_image_properties.get_color_space(),
+            self.premultiply_alpha,
+        )
+    }
+
     //endregion
 }
 
@@ -528,30 +583,65 @@ fn resize(source: &ImageFrame, destination: &mut ImageFrame) -> Result<(), Feagi
     Ok(())
 }
 
+/// Per pixel grayscale conversion of a given channel layout.
+struct GrayscaleConversion {
+    channel_weights: [f32; 4], // Alpha is given no weight
+    premultiply_by_channel: Option<usize>,
+}
+
+impl GrayscaleConversion {
+    fn new(
+        input_layout: ColorChannelLayout,
+        color_space: ColorSpace,
+        premultiply_alpha: bool,
+    ) -> GrayscaleConversion {
+        let (r_scale, g_scale, b_scale) = match color_space {
+            ColorSpace::Linear => (0.2126f32, 0.7152f32, 0.0722f32), // Using formula from https://stackoverflow.com/questions/17615963/standard-rgb-to-grayscale-conversion
+            ColorSpace::Gamma => (0.299f32, 0.587f32, 0.114f32), // https://www.youtube.com/watch?v=uKeKuaJ4nlw (I forget)
+        };
+        let channel_weights = match input_layout {
+            ColorChannelLayout::GrayScale | ColorChannelLayout::RG => [1.0, 0.0, 0.0, 0.0],
+            ColorChannelLayout::RGB | ColorChannelLayout::RGBA => [r_scale, g_scale, b_scale, 0.0],
+        };
+        let premultiply_by_channel = match premultiply_alpha {
+            true => input_layout.get_alpha_channel_index(),
+            false => None,
+        };
+        GrayscaleConversion {
+            channel_weights,
+            premultiply_by_channel,
+        }
+    }
+
+    #[inline]
+    fn gray_of_pixel(&self, source: &ArrayView3<u8>, y: usize, x: usize) -> u8 {
+        let mut gray_val: f32 = self
+            .channel_weights
+            .iter()
+            .take(source.shape()[2])
+            .enumerate()
+            .map(|(c, weight)| weight * source[(y, x, c)] as f32)
+            .sum();
+        if let Some(alpha_channel) = self.premultiply_by_channel {
+            gray_val *= source[(y, x, alpha_channel)] as f32 / 255.0;
+        }
+        gray_val.round() as u8 // Skipping clamping since the weights will never allow a value to exceed 255
+    }
+}
+
 fn to_grayscale(
     source: &ImageFrame,
     destination: &mut ImageFrame,
-    output_color_space: ColorSpace,
+    conversion: &GrayscaleConversion,
 ) -> Result<(), FeagiDataError> {
-    // NOTE: destination should be grayscale and source should be RGB or RGBA
-    let source_data = source.get_internal_data();
+    // NOTE: destination should be grayscale
+    let source_data = source.get_internal_data().view();
     let destination_data = destination.get_internal_data_mut();
-    let (r_scale, g_scale, b_scale) = match output_color_space {
-        ColorSpace::Linear => (0.2126f32, 0.7152f32, 0.0722f32), // Using formula from https://stackoverflow.com/questions/17615963/standard-rgb-to-grayscale-conversion
-        ColorSpace::Gamma => (0.299f32, 0.587f32, 0.114f32), // https://www.youtube.com/watch?v=uKeKuaJ4nlw (I forget)
-    };
-    // TODO look into premultiplied alpha handling!
 
     let dest_gray_channel = destination_data.slice_mut(ndarray::s![.., .., 0]);
 
     Zip::indexed(dest_gray_channel).par_for_each(|(y, x), color_val| {
-        // Correct formula: weighted sum of RGB channels, properly handling f32 math
-        let r_val = source_data[(y, x, 0)] as f32;
-        let g_val = source_data[(y, x, 1)] as f32;
-        let b_val = source_data[(y, x, 2)] as f32;
-
-        let gray_val = (r_scale * r_val + g_scale * g_val + b_scale * b_val).round() as u8; // Skipping clamping since the weights will never allow a value to exceed 255
-        *color_val = gray_val;
+        *color_val = conversion.gray_of_pixel(&source_data, y, x);
     });
     Ok(())
 }
@@ -599,15 +689,10 @@ fn crop_and_resize_and_grayscale(
     destination: &mut ImageFrame,
     crop_from: &CornerPoints,
     _resize_xy_to: &ImageXYResolution,
-    output_color_space: ColorSpace,
+    conversion: &GrayscaleConversion,
 ) -> Result<(), FeagiDataError> {
     let number_output_color_channels = source.get_color_channel_count();
 
-    let (r_scale, g_scale, b_scale) = match output_color_space {
-        ColorSpace::Linear => (0.2126f32, 0.7152f32, 0.0722f32), // Using formula from https://stackoverflow.com/questions/17615963/standard-rgb-to-grayscale-conversion
-        ColorSpace::Gamma => (0.299f32, 0.587f32, 0.114f32),
-    };
-
     // crop
     let sliced_array_view: ArrayView3<u8> = source.get_internal_data().slice(s![
         crop_from.upper_left.y as usize..crop_from.lower_right.y as usize,
@@ -640,14 +725,7 @@ fn crop_and_resize_and_grayscale(
     Zip::indexed(dest_gray_channel).par_for_each(|(y, x), out| {
         let src_y = (y as f32 * scale_y).floor() as usize;
         let src_x = (x as f32 * scale_x).floor() as usize;
-
-        // Correct formula: weighted sum of RGB channels, properly handling f32 math
-        let r_val = source_data[(src_y, src_x, 0)] as f32;
-        let g_val = source_data[(src_y, src_x, 1)] as f32;
-        let b_val = source_data[(src_y, src_x, 2)] as f32;
-
-        let gray_val = (r_scale * r_val + g_scale * g_val + b_scale * b_val).round() as u8;
-        *out = gray_val;
+        *out = conversion.gray_of_pixel(&source_data, src_y, src_x);
     });
     Ok(())
 }
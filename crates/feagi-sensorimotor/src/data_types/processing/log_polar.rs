@@ -0,0 +1,306 @@
+use crate::data_types::descriptors::{ColorChannelLayout, ColorSpace, ImageFrameProperties};
+use crate::data_types::{GazeProperties, ImageFrame};
+use feagi_structures::FeagiDataError;
+use std::f32::consts::TAU;
+
+// Keeps the logarithmic ring spacing defined when the gaze asks for a vanishingly small fovea
+const MINIMUM_FOVEA_RADIUS_PIXELS: f32 = 0.5;
+
+/// Resamples an image into a retina like log-polar map around a movable fovea.
+///
+/// The output image has one column per wedge (angle) and one row per ring (eccentricity).
+/// Wedges go counter-clockwise starting to the right of the fovea. The innermost ring is the
+/// fovea itself, and is written to the bottom row so it lands at Y = 0 once encoded. The
+/// remaining rings are spaced logarithmically out to half the diagonal of the input image, so
+/// each output pixel averages an area that grows with its distance from the fovea. Rings
+/// too small to hold any input pixel sample the nearest pixel instead.
+///
+/// The [`GazeProperties`] eccentricity places the fovea within the image, and its modulation
+/// sets the fovea radius as a fraction of the outermost ring. Areas of the field of view
+/// outside the image are black.
+///
+/// The output keeps the input color space, and either keeps its channel layout or is grayscale.
+#[derive(Debug, Clone)]
+pub struct ImageLogPolarTransformer {
+    input_properties: ImageFrameProperties,
+    output_properties: ImageFrameProperties,
+    gaze_being_used: GazeProperties,
+    pixel_bins: Vec<Option<usize>>, // which output pixel each input pixel is averaged into
+    bin_pixel_counts: Vec<u32>,
+    empty_bin_samples: Vec<Option<(usize, usize)>>, // input pixel to sample for bins with none
+}
+
+impl ImageLogPolarTransformer {
+    pub fn new(
+        input_properties: ImageFrameProperties,
+        output_properties: ImageFrameProperties,
+        initial_gaze: GazeProperties,
+    ) -> Result<ImageLogPolarTransformer, FeagiDataError> {
+        Self::verify_properties(&input_properties, &output_properties)?;
+        let mut transformer = ImageLogPolarTransformer {
+            input_properties,
+            output_properties,
+            gaze_being_used: initial_gaze,
+            pixel_bins: Vec::new(),
+            bin_pixel_counts: Vec::new(),
+            empty_bin_samples: Vec::new(),
+        };
+        transformer.update_gaze(&initial_gaze);
+        Ok(transformer)
+    }
+
+    pub fn get_input_properties(&self) -> &ImageFrameProperties {
+        &self.input_properties
+    }
+
+    pub fn get_output_properties(&self) -> &ImageFrameProperties {
+        &self.output_properties
+    }
+
+    pub fn get_used_gaze(&self) -> GazeProperties {
+        self.gaze_being_used
+    }
+
+    /// Moves the fovea, recomputing which input pixels feed each output pixel.
+    pub fn update_gaze(&mut self, gaze: &GazeProperties) {
+        let input_resolution = self.input_properties.get_image_resolution();
+        let output_resolution = self.output_properties.get_image_resolution();
+        let (input_width, input_height) = (
+            input_resolution.width as usize,
+            input_resolution.height as usize,
+        );
+        let number_wedges = output_resolution.width as usize;
+        let number_rings = output_resolution.height as usize;
+
+        let rings = RingSpacing::new(&self.input_properties, gaze, number_rings);
+        let (center_x, center_y) = fovea_center(&self.input_properties, gaze);
+
+        let mut pixel_bins: Vec<Option<usize>> = vec![None; input_width * input_height];
+        let mut bin_pixel_counts: Vec<u32> = vec![0; number_wedges * number_rings];
+        for row in 0..input_height {
+            for column in 0..input_width {
+                // Y is flipped so wedges go counter-clockwise as seen in the image
+                let offset_x = column as f32 + 0.5 - center_x;
+                let offset_y = center_y - (row as f32 + 0.5);
+                let Some(ring) = rings.ring_of_radius(offset_x.hypot(offset_y)) else {
+                    continue;
+                };
+                let angle = offset_y.atan2(offset_x).rem_euclid(TAU);
+                let wedge = ((angle / TAU * number_wedges as f32) as usize).min(number_wedges - 1);
+                let bin = ring * number_wedges + wedge;
+                pixel_bins[row * input_width + column] = Some(bin);
+                bin_pixel_counts[bin] += 1;
+            }
+        }
+
+        let empty_bin_samples: Vec<Option<(usize, usize)>> = (0..number_wedges * number_rings)
+            .map(|bin| {
+                if bin_pixel_counts[bin] != 0 {
+                    return None;
+                }
+                let ring = bin / number_wedges;
+                let wedge = bin % number_wedges;
+                let radius = rings.center_radius_of_ring(ring);
+                let angle = (wedge as f32 + 0.5) / number_wedges as f32 * TAU;
+                let x = center_x + radius * angle.cos();
+                let y = center_y - radius * angle.sin();
+                if x < 0.0 || y < 0.0 || x >= input_width as f32 || y >= input_height as f32 {
+                    return None; // Outside the image
+                }
+                Some((y as usize, x as usize))
+            })
+            .collect();
+
+        self.pixel_bins = pixel_bins;
+        self.bin_pixel_counts = bin_pixel_counts;
+        self.empty_bin_samples = empty_bin_samples;
+        self.gaze_being_used = *gaze;
+    }
+
+    pub fn process(
+        &self,
+        source: &ImageFrame,
+        destination: &mut ImageFrame,
+    ) -> Result<(), FeagiDataError> {
+        self.input_properties
+            .verify_image_frame_matches_properties(source)?;
+        self.output_properties
+            .verify_image_frame_matches_properties(destination)?;
+
+        let input_pixels = source.get_internal_data();
+        let input_width = input_pixels.shape()[1];
+        let number_input_channels = input_pixels.shape()[2];
+        let number_wedges = self.output_properties.get_image_resolution().width as usize;
+        let number_rings = self.output_properties.get_image_resolution().height as usize;
+
+        let mut bin_sums: Vec<u32> = vec![0; self.bin_pixel_counts.len() * number_input_channels];
+        for ((row, column, channel), value) in input_pixels.indexed_iter() {
+            if let Some(bin) = self.pixel_bins[row * input_width + column] {
+                bin_sums[bin * number_input_channels + channel] += *value as u32;
+            }
+        }
+
+        let channel_weights = self.output_channel_weights();
+        let output = destination.get_internal_data_mut();
+        for ring in 0..number_rings {
+            let row = number_rings - 1 - ring; // The fovea is at the bottom
+            for wedge in 0..number_wedges {
+                let bin = ring * number_wedges + wedge;
+                let count = self.bin_pixel_counts[bin];
+                let mut bin_values = [0.0f32; 4];
+                let bin_values = &mut bin_values[..number_input_channels];
+                if count != 0 {
+                    for (channel, bin_value) in bin_values.iter_mut().enumerate() {
+                        *bin_value =
+                            bin_sums[bin * number_input_channels + channel] as f32 / count as f32;
+                    }
+                } else if let Some((sample_row, sample_column)) = self.empty_bin_samples[bin] {
+                    for (channel, bin_value) in bin_values.iter_mut().enumerate() {
+                        *bin_value = input_pixels[(sample_row, sample_column, channel)] as f32;
+                    }
+                }
+
+                match &channel_weights {
+                    Some(weights) => {
+                        let gray: f32 = weights
+                            .iter()
+                            .zip(bin_values.iter())
+                            .map(|(weight, value)| weight * value)
+                            .sum();
+                        output[(row, wedge, 0)] = gray.round().min(255.0) as u8;
+                    }
+                    None => {
+                        for (channel, value) in bin_values.iter().enumerate() {
+                            output[(row, wedge, channel)] = value.round().min(255.0) as u8;
+                        }
+                    }
+                }
+            }
+        }
+        destination.skip_encoding = false;
+        Ok(())
+    }
+
+    /// Per input channel weights for converting to grayscale, or None if channels are kept.
+    fn output_channel_weights(&self) -> Option<Vec<f32>> {
+        let input_layout = self.input_properties.get_color_channel_layout();
+        if self.output_properties.get_color_channel_layout() != ColorChannelLayout::GrayScale
+            || input_layout == ColorChannelLayout::GrayScale
+        {
+            return None;
+        }
+        let (red_weight, green_weight, blue_weight) = match self.input_properties.get_color_space()
+        {
+            ColorSpace::Linear => (0.2126f32, 0.7152f32, 0.0722f32),
+            ColorSpace::Gamma => (0.299f32, 0.587f32, 0.114f32),
+        };
+        Some(match input_layout {
+            ColorChannelLayout::RG => vec![1.0, 0.0], // Grayscale and alpha, alpha is ignored
+            ColorChannelLayout::RGB => vec![red_weight, green_weight, blue_weight],
+            _ => vec![red_weight, green_weight, blue_weight, 0.0], // Alpha is ignored
+        })
+    }
+
+    fn verify_properties(
+        input_properties: &ImageFrameProperties,
+        output_properties: &ImageFrameProperties,
+    ) -> Result<(), FeagiDataError> {
+        if output_properties.get_image_resolution().height < 2 {
+            return Err(FeagiDataError::BadParameters(
+                "Log-polar images need at least 2 rings (output height), the fovea and one peripheral ring!".into(),
+            ));
+        }
+        if input_properties.get_color_space() != output_properties.get_color_space() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Log-polar transforms cannot change the color space from {:?} to {:?}!",
+                input_properties.get_color_space(),
+                output_properties.get_color_space()
+            )));
+        }
+        let output_layout = output_properties.get_color_channel_layout();
+        if output_layout != input_properties.get_color_channel_layout()
+            && output_layout != ColorChannelLayout::GrayScale
+        {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Log-polar transforms can only keep the color channel layout {} or convert to grayscale, not {}!",
+                input_properties.get_color_channel_layout(),
+                output_layout
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Position of the fovea in pixels, with Y increasing downward like the image.
+fn fovea_center(input_properties: &ImageFrameProperties, gaze: &GazeProperties) -> (f32, f32) {
+    let resolution = input_properties.get_image_resolution();
+    (
+        gaze.eccentricity_location_xy.a.get_as_0_1() * resolution.width as f32,
+        (1.0 - gaze.eccentricity_location_xy.b.get_as_0_1()) * resolution.height as f32, // Remember that in an image, Y increases downward
+    )
+}
+
+/// The fovea radius and the log scale of the rings around it.
+struct RingSpacing {
+    fovea_radius: f32,
+    maximum_radius: f32,
+    number_rings: usize,
+    rings_per_log_radius: f32,
+}
+
+impl RingSpacing {
+    fn new(
+        input_properties: &ImageFrameProperties,
+        gaze: &GazeProperties,
+        number_rings: usize,
+    ) -> RingSpacing {
+        let resolution = input_properties.get_image_resolution();
+        let maximum_radius = (resolution.width as f32).hypot(resolution.height as f32) / 2.0;
+        let fovea_radius = (gaze.modulation_size.get_as_0_1() * maximum_radius).clamp(
+            MINIMUM_FOVEA_RADIUS_PIXELS.min(maximum_radius),
+            maximum_radius,
+        );
+        let log_span = (maximum_radius / fovea_radius).ln();
+        let rings_per_log_radius = if log_span > 0.0 {
+            (number_rings - 1) as f32 / log_span
+        } else {
+            0.0 // The fovea fills the field of view
+        };
+        RingSpacing {
+            fovea_radius,
+            maximum_radius,
+            number_rings,
+            rings_per_log_radius,
+        }
+    }
+
+    fn ring_of_radius(&self, radius: f32) -> Option<usize> {
+        if radius >= self.maximum_radius {
+            return None;
+        }
+        if radius < self.fovea_radius {
+            return Some(0);
+        }
+        let ring = 1 + ((radius / self.fovea_radius).ln() * self.rings_per_log_radius) as usize;
+        Some(ring.min(self.number_rings - 1))
+    }
+
+    fn inner_radius_of_ring(&self, ring: usize) -> f32 {
+        if ring == 0 {
+            return 0.0;
+        }
+        if self.rings_per_log_radius == 0.0 {
+            return self.maximum_radius;
+        }
+        self.fovea_radius * ((ring - 1) as f32 / self.rings_per_log_radius).exp()
+    }
+
+    fn center_radius_of_ring(&self, ring: usize) -> f32 {
+        let outer_radius = if ring + 1 == self.number_rings {
+            self.maximum_radius
+        } else {
+            self.inner_radius_of_ring(ring + 1)
+        };
+        (self.inner_radius_of_ring(ring) + outer_radius) / 2.0
+    }
+}
@@ -1,10 +1,22 @@
 //! Internal processing utilities for data type transformations.
 //!
-//! Provides helper functions and processors for manipulating image frames
-//! and other data types. Not part of the public API.
+//! Provides helper functions and processors for manipulating image frames,
+//! audio frames and other data types. Not part of the public API.
 
+mod audio_spectrum;
+mod image_features;
 mod image_frame_processor;
 mod image_frame_segmentator;
+mod log_polar;
+mod tone_synthesizer;
 
+pub use audio_spectrum::{MelFilterbank, ShortTimeFourierTransform};
+pub(crate) use image_features::feature_map_properties;
+pub use image_features::{
+    EdgeDetectionMethod, ImageColorBlobMasker, ImageEdgeDetector, ImageMotionDetector,
+    MotionDirection,
+};
 pub use image_frame_processor::ImageFrameProcessor;
 pub use image_frame_segmentator::ImageFrameSegmentator;
+pub use log_polar::ImageLogPolarTransformer;
+pub use tone_synthesizer::ToneSynthesizer;
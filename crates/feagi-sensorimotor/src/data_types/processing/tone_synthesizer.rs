@@ -0,0 +1,183 @@
+use crate::data_types::descriptors::{AudioFrameProperties, MiscDataDimensions};
+use crate::data_types::{AudioFrame, MiscData};
+use feagi_structures::FeagiDataError;
+use std::f32::consts::TAU;
+
+/// Renders per band tone amplitudes into PCM audio frames with a bank of sine oscillators.
+///
+/// The input is a [`MiscData`] of 1 x `number_tone_bands` x 1 holding the amplitude (0.0 to 1.0)
+/// of each band. Band frequencies are spaced logarithmically (equal musical intervals) from
+/// `min_frequency_hz` for band 0 up to `max_frequency_hz` for the last band.
+///
+/// Oscillator phases carry over between frames, and amplitudes are ramped linearly from the
+/// previous frame's values across each frame, so consecutive frames play back without clicks.
+/// When the amplitudes of all sounding bands add up to more than 1.0, the mix is scaled down to
+/// stay within full scale.
+#[derive(Debug, Clone)]
+pub struct ToneSynthesizer {
+    output_properties: AudioFrameProperties,
+    number_tone_bands: u32,
+    min_frequency_hz: f32,
+    max_frequency_hz: f32,
+    phase_increments: Vec<f32>, // Radians per sample, per band
+    phases: Vec<f32>,
+    amplitudes: Vec<f32>, // Amplitudes at the end of the last rendered frame
+    scratch_mix_gain: Vec<f32>,
+}
+
+impl ToneSynthesizer {
+    pub fn new(
+        output_properties: AudioFrameProperties,
+        number_tone_bands: u32,
+        min_frequency_hz: f32,
+        max_frequency_hz: f32,
+    ) -> Result<ToneSynthesizer, FeagiDataError> {
+        if number_tone_bands == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Tone synthesizer needs at least one band!".into(),
+            ));
+        }
+        let nyquist_hz = output_properties.get_sample_rate_hz() as f32 / 2.0;
+        let is_valid_range = min_frequency_hz > 0.0
+            && min_frequency_hz <= max_frequency_hz
+            && max_frequency_hz < nyquist_hz
+            && (number_tone_bands == 1 || min_frequency_hz < max_frequency_hz);
+        if !is_valid_range {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Tone synthesizer frequency range must satisfy 0 < min < max < {} Hz, got {} to {} Hz!",
+                nyquist_hz, min_frequency_hz, max_frequency_hz
+            )));
+        }
+
+        let sample_rate_hz = output_properties.get_sample_rate_hz() as f32;
+        let phase_increments: Vec<f32> = (0..number_tone_bands)
+            .map(|band| {
+                TAU * band_frequency_hz(band, number_tone_bands, min_frequency_hz, max_frequency_hz)
+                    / sample_rate_hz
+            })
+            .collect();
+
+        Ok(ToneSynthesizer {
+            output_properties,
+            number_tone_bands,
+            min_frequency_hz,
+            max_frequency_hz,
+            phase_increments,
+            phases: vec![0.0; number_tone_bands as usize],
+            amplitudes: vec![0.0; number_tone_bands as usize],
+            scratch_mix_gain: vec![0.0; output_properties.get_number_samples() as usize],
+        })
+    }
+
+    pub fn get_output_properties(&self) -> &AudioFrameProperties {
+        &self.output_properties
+    }
+
+    pub fn get_number_tone_bands(&self) -> u32 {
+        self.number_tone_bands
+    }
+
+    pub fn get_min_frequency_hz(&self) -> f32 {
+        self.min_frequency_hz
+    }
+
+    pub fn get_max_frequency_hz(&self) -> f32 {
+        self.max_frequency_hz
+    }
+
+    pub fn get_input_dimensions(&self) -> MiscDataDimensions {
+        MiscDataDimensions::new(1, self.number_tone_bands, 1).unwrap()
+    }
+
+    /// Returns the frequency a band plays at, in hertz.
+    pub fn get_band_frequency_hz(&self, band_index: u32) -> f32 {
+        band_frequency_hz(
+            band_index,
+            self.number_tone_bands,
+            self.min_frequency_hz,
+            self.max_frequency_hz,
+        )
+    }
+
+    /// Silences all oscillators and resets their phases, as if freshly created.
+    pub fn reset(&mut self) {
+        self.phases.fill(0.0);
+        self.amplitudes.fill(0.0);
+    }
+
+    pub fn process(
+        &mut self,
+        band_amplitudes: &MiscData,
+        target: &mut AudioFrame,
+    ) -> Result<(), FeagiDataError> {
+        if band_amplitudes.get_dimensions() != self.get_input_dimensions() {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Expected tone band amplitudes of {} but got {}!",
+                self.get_input_dimensions(),
+                band_amplitudes.get_dimensions()
+            )));
+        }
+        self.output_properties
+            .verify_audio_frame_matches_properties(target)?;
+
+        let band_amplitudes = band_amplitudes.get_internal_data();
+        let samples = target.get_samples_mut();
+        let number_samples = samples.len();
+        samples.fill(0.0);
+
+        let mut starting_total: f32 = 0.0;
+        let mut ending_total: f32 = 0.0;
+        for band in 0..self.number_tone_bands as usize {
+            let starting_amplitude = self.amplitudes[band];
+            let ending_amplitude = band_amplitudes[(0, band, 0)].clamp(0.0, 1.0);
+            let phase_increment = self.phase_increments[band];
+            starting_total += starting_amplitude;
+            ending_total += ending_amplitude;
+
+            if starting_amplitude == 0.0 && ending_amplitude == 0.0 {
+                // Silent, but keep the phase moving so it lines up if it starts again
+                self.phases[band] =
+                    (self.phases[band] + phase_increment * number_samples as f32) % TAU;
+                continue;
+            }
+
+            let amplitude_step = (ending_amplitude - starting_amplitude) / number_samples as f32;
+            let mut phase = self.phases[band];
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let amplitude = starting_amplitude + amplitude_step * (i + 1) as f32;
+                *sample += amplitude * phase.sin();
+                phase += phase_increment;
+                if phase >= TAU {
+                    phase -= TAU;
+                }
+            }
+            self.phases[band] = phase;
+            self.amplitudes[band] = ending_amplitude;
+        }
+
+        if starting_total > 1.0 || ending_total > 1.0 {
+            // The total amplitude ramps linearly too, so the mix gain can be computed per sample
+            let total_step = (ending_total - starting_total) / number_samples as f32;
+            for (i, gain) in self.scratch_mix_gain.iter_mut().enumerate() {
+                *gain = 1.0 / (starting_total + total_step * (i + 1) as f32).max(1.0);
+            }
+            for (sample, gain) in samples.iter_mut().zip(self.scratch_mix_gain.iter()) {
+                *sample *= gain;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn band_frequency_hz(
+    band_index: u32,
+    number_tone_bands: u32,
+    min_frequency_hz: f32,
+    max_frequency_hz: f32,
+) -> f32 {
+    if number_tone_bands == 1 {
+        return min_frequency_hz;
+    }
+    let position = band_index as f32 / (number_tone_bands - 1) as f32;
+    min_frequency_hz * (max_frequency_hz / min_frequency_hz).powf(position)
+}
@@ -39,7 +39,7 @@ use rayon::prelude::*;
 /// This design allows FEAGI to process visual information with varying levels of detail,
 /// concentrating computational resources in the center of attention while maintaining
 /// awareness of the broader visual field.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SegmentedImageFrame {
     /// Lower-left segment of the vision frame
     lower_left: ImageFrame,
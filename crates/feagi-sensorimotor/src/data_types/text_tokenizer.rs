@@ -0,0 +1,555 @@
+use crate::data_types::TextToken;
+use feagi_structures::genomic::cortical_area::descriptors::NeuronDepth;
+use feagi_structures::FeagiDataError;
+use std::collections::HashMap;
+use std::path::Path;
+
+// The contractions GPT-2 splits off words before applying merges
+const BYTE_PAIR_CONTRACTIONS: [&str; 7] = ["'s", "'t", "'re", "'ve", "'m", "'ll", "'d"];
+
+/// Converts text to and from streams of [`TextToken`] using a local vocabulary.
+///
+/// Two kinds of vocabularies are supported:
+/// - **Word level**: a list of tokens, one per line, where the order gives the token ids. Text
+///   is split into words (runs of letters, numbers and apostrophes) and single punctuation
+///   characters. Words missing from the vocabulary are looked up lowercase, and then fall back
+///   to the unknown token if one is set.
+/// - **Byte pair encoding**: GPT-2 style byte level BPE, loaded from a `vocab.json` mapping
+///   tokens to ids and a `merges.txt` list of merge rules. Any text can be encoded.
+///
+/// Streams of tokens are turned back into text with a [`TextTokenDecoder`].
+#[derive(Debug, Clone)]
+pub struct TextTokenizer {
+    vocabulary: TextVocabulary,
+    token_to_id: HashMap<String, u32>,
+    id_to_token: HashMap<u32, String>,
+}
+
+#[derive(Debug, Clone)]
+enum TextVocabulary {
+    WordLevel {
+        unknown_token_id: Option<u32>,
+    },
+    BytePairEncoding {
+        merge_ranks: HashMap<(String, String), usize>,
+        byte_to_char: Vec<char>,
+        char_to_byte: HashMap<char, u8>,
+    },
+}
+
+impl TextTokenizer {
+    //region Constructors
+
+    /// Creates a word level tokenizer, where each token's id is its index in the given list.
+    pub fn new_word_level(
+        tokens: Vec<String>,
+        unknown_token: Option<&str>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        let mut token_to_id: HashMap<String, u32> = HashMap::with_capacity(tokens.len());
+        for (token_id, token) in tokens.into_iter().enumerate() {
+            if token_to_id.insert(token.clone(), token_id as u32).is_some() {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "Token '{}' appears more than once in the vocabulary!",
+                    token
+                )));
+            }
+        }
+        let unknown_token_id = match unknown_token {
+            None => None,
+            Some(unknown_token) => Some(*token_to_id.get(unknown_token).ok_or_else(|| {
+                FeagiDataError::BadParameters(format!(
+                    "Unknown token '{}' is not in the vocabulary!",
+                    unknown_token
+                ))
+            })?),
+        };
+        Self::new(TextVocabulary::WordLevel { unknown_token_id }, token_to_id)
+    }
+
+    /// Creates a word level tokenizer from the contents of a vocabulary file with one token per
+    /// line. Blank lines are skipped.
+    pub fn new_word_level_from_text(
+        vocabulary: &str,
+        unknown_token: Option<&str>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        let tokens: Vec<String> = vocabulary
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect();
+        Self::new_word_level(tokens, unknown_token)
+    }
+
+    /// Creates a word level tokenizer from a vocabulary file with one token per line.
+    pub fn new_word_level_from_file(
+        vocabulary_path: impl AsRef<Path>,
+        unknown_token: Option<&str>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        Self::new_word_level_from_text(&read_text_file(vocabulary_path)?, unknown_token)
+    }
+
+    /// Creates a byte level BPE tokenizer from a token to id vocabulary and merge rules, in
+    /// order of priority.
+    pub fn new_byte_pair_encoding(
+        vocabulary: HashMap<String, u32>,
+        merges: Vec<(String, String)>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        let byte_to_char = byte_to_unicode_chars();
+        let char_to_byte: HashMap<char, u8> = byte_to_char
+            .iter()
+            .enumerate()
+            .map(|(byte, character)| (*character, byte as u8))
+            .collect();
+        let merge_ranks: HashMap<(String, String), usize> = merges
+            .into_iter()
+            .enumerate()
+            .map(|(rank, pair)| (pair, rank))
+            .collect();
+        Self::new(
+            TextVocabulary::BytePairEncoding {
+                merge_ranks,
+                byte_to_char,
+                char_to_byte,
+            },
+            vocabulary,
+        )
+    }
+
+    /// Creates a byte level BPE tokenizer from the contents of GPT-2 style `vocab.json` and
+    /// `merges.txt` files.
+    pub fn new_byte_pair_encoding_from_text(
+        vocabulary_json: &str,
+        merges: &str,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        let vocabulary: HashMap<String, u32> = serde_json::from_str(vocabulary_json)
+            .map_err(|err| FeagiDataError::DeserializationError(err.to_string()))?;
+        let merges: Vec<(String, String)> = merges
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.is_empty() && !line.starts_with("#version"))
+            .map(|line| {
+                line.split_once(' ')
+                    .map(|(left, right)| (left.to_string(), right.to_string()))
+                    .ok_or_else(|| {
+                        FeagiDataError::DeserializationError(format!(
+                            "Invalid BPE merge rule '{}', expected two tokens separated by a space!",
+                            line
+                        ))
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Self::new_byte_pair_encoding(vocabulary, merges)
+    }
+
+    /// Creates a byte level BPE tokenizer from GPT-2 style `vocab.json` and `merges.txt` files.
+    pub fn new_byte_pair_encoding_from_files(
+        vocabulary_json_path: impl AsRef<Path>,
+        merges_path: impl AsRef<Path>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        Self::new_byte_pair_encoding_from_text(
+            &read_text_file(vocabulary_json_path)?,
+            &read_text_file(merges_path)?,
+        )
+    }
+
+    fn new(
+        vocabulary: TextVocabulary,
+        token_to_id: HashMap<String, u32>,
+    ) -> Result<TextTokenizer, FeagiDataError> {
+        if token_to_id.is_empty() {
+            return Err(FeagiDataError::BadParameters(
+                "Cannot create a tokenizer with an empty vocabulary!".into(),
+            ));
+        }
+        let mut id_to_token: HashMap<u32, String> = HashMap::with_capacity(token_to_id.len());
+        for (token, token_id) in token_to_id.iter() {
+            if *token_id == u32::MAX {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "Token id {} of token '{}' is too large to be sent to FEAGI!",
+                    token_id, token
+                )));
+            }
+            if let Some(existing) = id_to_token.insert(*token_id, token.clone()) {
+                return Err(FeagiDataError::BadParameters(format!(
+                    "Tokens '{}' and '{}' share the token id {}!",
+                    existing, token, token_id
+                )));
+            }
+        }
+        Ok(TextTokenizer {
+            vocabulary,
+            token_to_id,
+            id_to_token,
+        })
+    }
+
+    //endregion
+
+    //region Properties
+
+    pub fn get_vocabulary_size(&self) -> usize {
+        self.token_to_id.len()
+    }
+
+    /// The number of bitplanes (neuron depth) needed to send any token of this vocabulary.
+    pub fn get_required_token_depth(&self) -> NeuronDepth {
+        let largest_value = self.id_to_token.keys().max().copied().unwrap_or(0) + 1; // Token ids are sent offset by one
+        let required_bits = u32::BITS - largest_value.leading_zeros();
+        NeuronDepth::new(required_bits).unwrap() // largest_value is never 0
+    }
+
+    pub fn try_get_token_id(&self, token: &str) -> Option<TextToken> {
+        self.token_to_id
+            .get(token)
+            .map(|token_id| TextToken::new_unchecked(*token_id))
+    }
+
+    pub fn try_get_token_text(&self, token: TextToken) -> Result<&str, FeagiDataError> {
+        self.id_to_token
+            .get(&token.token_id())
+            .map(|token| token.as_str())
+            .ok_or_else(|| {
+                FeagiDataError::BadParameters(format!(
+                    "Token id {} is not in the vocabulary!",
+                    token.token_id()
+                ))
+            })
+    }
+
+    //endregion
+
+    /// Converts text into the stream of tokens to send.
+    pub fn encode(&self, text: &str) -> Result<Vec<TextToken>, FeagiDataError> {
+        match &self.vocabulary {
+            TextVocabulary::WordLevel { unknown_token_id } => {
+                self.encode_word_level(text, *unknown_token_id)
+            }
+            TextVocabulary::BytePairEncoding {
+                merge_ranks,
+                byte_to_char,
+                ..
+            } => self.encode_byte_pair(text, merge_ranks, byte_to_char),
+        }
+    }
+
+    /// Converts a complete stream of tokens back into text.
+    pub fn decode(&self, tokens: &[TextToken]) -> Result<String, FeagiDataError> {
+        let mut decoder = TextTokenDecoder::new();
+        let mut text = String::new();
+        for token in tokens {
+            text.push_str(&decoder.decode_token(self, *token)?);
+        }
+        text.push_str(&decoder.finish());
+        Ok(text)
+    }
+
+    //region Internal
+
+    fn encode_word_level(
+        &self,
+        text: &str,
+        unknown_token_id: Option<u32>,
+    ) -> Result<Vec<TextToken>, FeagiDataError> {
+        split_words_and_punctuation(text)
+            .into_iter()
+            .map(|word| {
+                let token_id = self
+                    .token_to_id
+                    .get(word)
+                    .or_else(|| self.token_to_id.get(&word.to_lowercase()))
+                    .copied()
+                    .or(unknown_token_id)
+                    .ok_or_else(|| {
+                        FeagiDataError::BadParameters(format!(
+                            "Word '{}' is not in the vocabulary and no unknown token is set!",
+                            word
+                        ))
+                    })?;
+                Ok(TextToken::new_unchecked(token_id))
+            })
+            .collect()
+    }
+
+    fn encode_byte_pair(
+        &self,
+        text: &str,
+        merge_ranks: &HashMap<(String, String), usize>,
+        byte_to_char: &[char],
+    ) -> Result<Vec<TextToken>, FeagiDataError> {
+        let mut tokens: Vec<TextToken> = Vec::new();
+        for piece in split_byte_pair_pieces(text) {
+            let mut symbols: Vec<String> = piece
+                .bytes()
+                .map(|byte| byte_to_char[byte as usize].to_string())
+                .collect();
+
+            // Repeatedly merge the adjacent pair with the highest priority
+            loop {
+                let best_pair = symbols
+                    .windows(2)
+                    .filter_map(|pair| {
+                        merge_ranks
+                            .get(&(pair[0].clone(), pair[1].clone()))
+                            .map(|rank| (*rank, pair[0].clone(), pair[1].clone()))
+                    })
+                    .min();
+                let Some((_, left, right)) = best_pair else {
+                    break;
+                };
+                let mut merged: Vec<String> = Vec::with_capacity(symbols.len());
+                let mut index = 0;
+                while index < symbols.len() {
+                    if index + 1 < symbols.len()
+                        && symbols[index] == left
+                        && symbols[index + 1] == right
+                    {
+                        merged.push(format!("{}{}", left, right));
+                        index += 2;
+                    } else {
+                        merged.push(symbols[index].clone());
+                        index += 1;
+                    }
+                }
+                symbols = merged;
+            }
+
+            for symbol in symbols {
+                let token_id = self.token_to_id.get(&symbol).ok_or_else(|| {
+                    FeagiDataError::BadParameters(format!(
+                        "BPE symbol '{}' is not in the vocabulary!",
+                        symbol
+                    ))
+                })?;
+                tokens.push(TextToken::new_unchecked(*token_id));
+            }
+        }
+        Ok(tokens)
+    }
+
+    //endregion
+}
+
+/// Incrementally turns a stream of [`TextToken`] back into text, one token at a time.
+///
+/// Byte level BPE tokens may split a multi-byte character, so incomplete characters are held
+/// until the tokens completing them arrive. Word level tokens are separated by spaces, except
+/// for punctuation.
+#[derive(Debug, Clone, Default)]
+pub struct TextTokenDecoder {
+    pending_bytes: Vec<u8>,
+    has_decoded_text: bool,
+}
+
+impl TextTokenDecoder {
+    pub fn new() -> TextTokenDecoder {
+        TextTokenDecoder::default()
+    }
+
+    /// Decodes the next token of the stream, returning the text it completes.
+    pub fn decode_token(
+        &mut self,
+        tokenizer: &TextTokenizer,
+        token: TextToken,
+    ) -> Result<String, FeagiDataError> {
+        let token_text = tokenizer.try_get_token_text(token)?;
+        match &tokenizer.vocabulary {
+            TextVocabulary::WordLevel { .. } => {
+                let is_punctuation = token_text.chars().all(|character| !is_word_char(character));
+                let text = if self.has_decoded_text && !is_punctuation {
+                    format!(" {}", token_text)
+                } else {
+                    token_text.to_string()
+                };
+                self.has_decoded_text = true;
+                Ok(text)
+            }
+            TextVocabulary::BytePairEncoding { char_to_byte, .. } => {
+                for character in token_text.chars() {
+                    match char_to_byte.get(&character) {
+                        Some(byte) => self.pending_bytes.push(*byte),
+                        None => {
+                            // Not a byte level character, such as in a special token
+                            let mut buffer = [0u8; 4];
+                            self.pending_bytes
+                                .extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                        }
+                    }
+                }
+                Ok(self.take_complete_characters())
+            }
+        }
+    }
+
+    /// Returns any incomplete characters still held (lossily), and resets the decoder.
+    pub fn finish(&mut self) -> String {
+        let remaining = String::from_utf8_lossy(&self.pending_bytes).into_owned();
+        self.pending_bytes.clear();
+        self.has_decoded_text = false;
+        remaining
+    }
+
+    fn take_complete_characters(&mut self) -> String {
+        match std::str::from_utf8(&self.pending_bytes) {
+            Ok(text) => {
+                let text = text.to_string();
+                self.pending_bytes.clear();
+                text
+            }
+            Err(error) => {
+                if error.error_len().is_some() {
+                    // Invalid rather than incomplete, nothing later can fix it
+                    return self.finish();
+                }
+                let remaining = self.pending_bytes.split_off(error.valid_up_to());
+                let text = String::from_utf8(std::mem::replace(&mut self.pending_bytes, remaining))
+                    .unwrap(); // Checked valid above
+                text
+            }
+        }
+    }
+}
+
+fn read_text_file(path: impl AsRef<Path>) -> Result<String, FeagiDataError> {
+    std::fs::read_to_string(path.as_ref()).map_err(|err| {
+        FeagiDataError::BadParameters(format!(
+            "Unable to read vocabulary file {}: {}",
+            path.as_ref().display(),
+            err
+        ))
+    })
+}
+
+fn is_word_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '\''
+}
+
+/// Splits text into words and single punctuation characters, dropping whitespace.
+fn split_words_and_punctuation(text: &str) -> Vec<&str> {
+    let mut pieces: Vec<&str> = Vec::new();
+    let mut word_start: Option<usize> = None;
+    for (index, character) in text.char_indices() {
+        if is_word_char(character) {
+            word_start.get_or_insert(index);
+            continue;
+        }
+        if let Some(start) = word_start.take() {
+            pieces.push(&text[start..index]);
+        }
+        if !character.is_whitespace() {
+            pieces.push(&text[index..index + character.len_utf8()]);
+        }
+    }
+    if let Some(start) = word_start {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+/// Splits text the way GPT-2 does before applying merges: contractions, then runs of letters,
+/// numbers or other symbols (each optionally led by a space), then whitespace.
+fn split_byte_pair_pieces(text: &str) -> Vec<&str> {
+    let characters: Vec<(usize, char)> = text.char_indices().collect();
+    let byte_offset = |index: usize| -> usize {
+        characters
+            .get(index)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(text.len())
+    };
+    let run_length = |start: usize, matches: &dyn Fn(char) -> bool| -> usize {
+        characters[start..]
+            .iter()
+            .take_while(|(_, character)| matches(*character))
+            .count()
+    };
+    let is_letter = |character: char| character.is_alphabetic();
+    let is_number = |character: char| character.is_numeric();
+    let is_other = |character: char| {
+        !character.is_whitespace() && !character.is_alphabetic() && !character.is_numeric()
+    };
+
+    let mut pieces: Vec<&str> = Vec::new();
+    let mut index = 0;
+    while index < characters.len() {
+        let remaining = &text[byte_offset(index)..];
+        let piece_length: usize = if let Some(contraction) = BYTE_PAIR_CONTRACTIONS
+            .iter()
+            .find(|contraction| remaining.starts_with(**contraction))
+        {
+            contraction.chars().count()
+        } else {
+            let leading_space = usize::from(characters[index].1 == ' ');
+            let after_space = index + leading_space;
+            let run = if after_space < characters.len() {
+                [&is_letter as &dyn Fn(char) -> bool, &is_number, &is_other]
+                    .iter()
+                    .map(|matches| run_length(after_space, *matches))
+                    .find(|length| *length > 0)
+            } else {
+                None
+            };
+            match run {
+                Some(length) => leading_space + length,
+                None => {
+                    // Whitespace, leaving the last space to lead the following word
+                    let whitespace =
+                        run_length(index, &|character: char| character.is_whitespace());
+                    if index + whitespace < characters.len() && whitespace > 1 {
+                        whitespace - 1
+                    } else {
+                        whitespace.max(1)
+                    }
+                }
+            }
+        };
+        pieces.push(&text[byte_offset(index)..byte_offset(index + piece_length)]);
+        index += piece_length;
+    }
+    pieces
+}
+
+/// The printable characters GPT-2 style byte level BPE uses to stand in for each byte.
+fn byte_to_unicode_chars() -> Vec<char> {
+    let mut byte_to_char: Vec<char> = vec!['\0'; 256];
+    let mut next_unprintable: u32 = 256;
+    for byte in 0..=255u32 {
+        let printable = (b'!' as u32..=b'~' as u32).contains(&byte)
+            || (0xA1..=0xAC).contains(&byte)
+            || (0xAE..=0xFF).contains(&byte);
+        let code_point = if printable {
+            byte
+        } else {
+            next_unprintable += 1;
+            next_unprintable - 1
+        };
+        byte_to_char[byte as usize] = char::from_u32(code_point).unwrap();
+    }
+    byte_to_char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_pair_pieces_match_gpt2() {
+        assert_eq!(
+            split_byte_pair_pieces("Hello world, it's  42!\n"),
+            vec!["Hello", " world", ",", " it", "'s", " ", " 42", "!", "\n"]
+        );
+    }
+
+    #[test]
+    fn test_word_level_pieces() {
+        assert_eq!(
+            split_words_and_punctuation("Hi, don't go!"),
+            vec!["Hi", ",", "don't", "go", "!"]
+        );
+    }
+
+    #[test]
+    fn test_space_maps_to_g_with_dot() {
+        assert_eq!(byte_to_unicode_chars()[b' ' as usize], 'Ġ');
+        assert_eq!(byte_to_unicode_chars()[b'A' as usize], 'A');
+    }
+}
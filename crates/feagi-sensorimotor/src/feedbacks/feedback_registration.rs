@@ -19,6 +19,7 @@ pub enum FeedBackRegistration {
     SegmentedVisionWithGaze {},
     SegmentedVisionWithImageFiltering {},
     VisionWithImageFiltering {},
+    LogPolarVisionWithGaze {},
 }
 
 impl Display for FeedBackRegistration {
@@ -33,6 +34,9 @@ impl Display for FeedBackRegistration {
             FeedBackRegistration::VisionWithImageFiltering {} => {
                 write!(f, "VisionWithImageFiltering")
             }
+            FeedBackRegistration::LogPolarVisionWithGaze {} => {
+                write!(f, "LogPolarVisionWithGaze")
+            }
         }
     }
 }
@@ -109,6 +113,14 @@ impl FeedBackRegistration {
                     target_stage_index,
                 )?;
             }
+            FeedBackRegistration::LogPolarVisionWithGaze {} => {
+                feedback_log_polar_vision_with_gaze(
+                    &target,
+                    sensor_cache.clone(),
+                    motor_cache.clone(),
+                    target_stage_index,
+                )?;
+            }
         }
         Ok(())
     }
@@ -127,6 +139,9 @@ impl FeedBackRegistration {
                 SensoryCorticalUnit::Vision,
                 MotorCorticalUnit::DynamicImageProcessing,
             ),
+            FeedBackRegistration::LogPolarVisionWithGaze {} => {
+                (SensoryCorticalUnit::LogPolarVision, MotorCorticalUnit::Gaze)
+            }
         }
     }
 
@@ -168,6 +183,23 @@ impl FeedBackRegistration {
                     .unwrap(),
                 }
             }
+            FeedBackRegistration::LogPolarVisionWithGaze {} => {
+                PipelineStageProperties::ImageLogPolarTransformer {
+                    input_image_properties: ImageFrameProperties::new(
+                        (1, 1).try_into().unwrap(),
+                        ColorSpace::Linear,
+                        ColorChannelLayout::GrayScale,
+                    )
+                    .unwrap(),
+                    output_image_properties: ImageFrameProperties::new(
+                        (1, 2).try_into().unwrap(),
+                        ColorSpace::Linear,
+                        ColorChannelLayout::GrayScale,
+                    )
+                    .unwrap(),
+                    foveation_gaze: GazeProperties::create_default_centered(),
+                }
+            }
         }
     }
 }
@@ -349,3 +381,55 @@ fn feedback_simple_vision_with_image_filtering(
     )?;
     Ok(index)
 }
+
+fn feedback_log_polar_vision_with_gaze(
+    target: &FeedbackRegistrationTargets,
+    sensors: Arc<Mutex<SensorDeviceCache>>,
+    motors: Arc<Mutex<MotorDeviceCache>>,
+    stage_index: PipelineStagePropertyIndex,
+) -> Result<FeagiSignalIndex, FeagiDataError> {
+    let sensor_unit = target.get_sensor_unit_index();
+    let sensor_channel = target.get_sensor_channel_index();
+
+    let sensor_ref = sensors.clone();
+
+    let closure = move |wrapped_data: &WrappedIOData| {
+        let gaze_properties: GazeProperties = wrapped_data.try_into().unwrap();
+
+        let mut sensors = sensor_ref.lock().unwrap();
+        let stage_properties = sensors
+            .log_polar_vision_get_single_stage_properties(sensor_unit, sensor_channel, stage_index)
+            .unwrap();
+        let new_properties: PipelineStageProperties = match stage_properties {
+            PipelineStageProperties::ImageLogPolarTransformer {
+                input_image_properties,
+                output_image_properties,
+                foveation_gaze: _,
+            } => PipelineStageProperties::ImageLogPolarTransformer {
+                input_image_properties,
+                output_image_properties,
+                foveation_gaze: gaze_properties,
+            },
+            _ => {
+                panic!("Invalid pipeline stage properties for log-polar gaze vision feedback!")
+            }
+        };
+
+        _ = sensors.log_polar_vision_update_single_stage_properties(
+            sensor_unit,
+            sensor_channel,
+            stage_index,
+            new_properties,
+        );
+    };
+
+    let motor_ref = motors.clone();
+    let mut motors = motor_ref.lock().unwrap();
+
+    let index = motors.gaze_try_register_motor_callback(
+        target.get_motor_unit_index(),
+        target.get_motor_channel_index(),
+        closure,
+    )?;
+    Ok(index)
+}
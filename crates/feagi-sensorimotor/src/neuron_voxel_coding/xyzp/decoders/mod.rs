@@ -3,6 +3,7 @@
 mod gaze_properties_decoder;
 mod misc_data;
 mod percentage_decoder;
+mod tone_bands;
 
 mod cartesian_plane;
 mod image_filtering_settings;
@@ -12,3 +13,4 @@ pub(crate) use gaze_properties_decoder::GazePropertiesNeuronVoxelXYZPDecoder;
 pub(crate) use image_filtering_settings::ImageFilteringSettingsNeuronVoxelXYZPDecoder;
 pub(crate) use misc_data::MiscDataNeuronVoxelXYZPDecoder;
 pub(crate) use percentage_decoder::PercentageNeuronVoxelXYZPDecoder;
+pub(crate) use tone_bands::ToneBandsNeuronVoxelXYZPDecoder;
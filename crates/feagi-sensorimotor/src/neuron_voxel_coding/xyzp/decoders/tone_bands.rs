@@ -0,0 +1,131 @@
+use crate::configuration::jsonable::JSONDecoderProperties;
+use crate::data_pipeline::per_channel_stream_caches::MotorPipelineStageRunner;
+use crate::data_types::descriptors::MiscDataDimensions;
+use crate::data_types::{MiscData, Percentage};
+use crate::neuron_voxel_coding::xyzp::coder_shared_functions::decode_unsigned_percentage_from_linear_neurons;
+use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPDecoder;
+use crate::wrapped_io_data::WrappedIOType;
+use feagi_structures::genomic::cortical_area::descriptors::{CorticalChannelCount, NeuronDepth};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
+use feagi_structures::FeagiDataError;
+use std::time::Instant;
+
+/// Decodes tone bands (one column per channel along x, frequency bands along y, loudness along z
+/// with z = 0 being loudest) into a [`MiscData`] of 1 x bands x 1 holding each band's amplitude.
+/// Bands with no firing neurons are silent. When several neurons fire in one band, their
+/// amplitudes are averaged.
+#[derive(Debug)]
+pub struct ToneBandsNeuronVoxelXYZPDecoder {
+    cortical_read_target: CorticalID,
+    number_tone_bands: u32,
+    amplitude_depth: NeuronDepth,
+    z_indexes_scratch: Vec<Vec<Vec<u32>>>, // Per channel, per band, fired z indexes
+}
+
+impl NeuronVoxelXYZPDecoder for ToneBandsNeuronVoxelXYZPDecoder {
+    fn get_decodable_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.get_band_amplitude_dimensions()))
+    }
+
+    fn get_as_properties(&self) -> JSONDecoderProperties {
+        JSONDecoderProperties::ToneBands(self.number_tone_bands, self.amplitude_depth)
+    }
+
+    fn read_neuron_data_multi_channel_into_pipeline_input_cache(
+        &mut self,
+        neurons_to_read: &CorticalMappedXYZPNeuronVoxels,
+        __time_of_read: Instant,
+        pipelines_with_data_to_update: &mut Vec<MotorPipelineStageRunner>,
+        channel_changed: &mut Vec<bool>,
+    ) -> Result<(), FeagiDataError> {
+        let neuron_array = neurons_to_read.get_neurons_of(&self.cortical_read_target);
+
+        if neuron_array.is_none() {
+            return Ok(());
+        }
+
+        let neuron_array = neuron_array.unwrap();
+        if neuron_array.is_empty() {
+            return Ok(());
+        }
+
+        for channel_scratch in self.z_indexes_scratch.iter_mut() {
+            for band_scratch in channel_scratch.iter_mut() {
+                band_scratch.clear();
+            }
+        }
+
+        let number_of_channels = pipelines_with_data_to_update.len() as u32;
+        let amplitude_depth: u32 = *self.amplitude_depth;
+        for neuron in neuron_array.iter() {
+            if neuron.neuron_voxel_coordinate.x >= number_of_channels
+                || neuron.neuron_voxel_coordinate.y >= self.number_tone_bands
+                || neuron.neuron_voxel_coordinate.z >= amplitude_depth
+            {
+                continue;
+            }
+            let channel_index = neuron.neuron_voxel_coordinate.x as usize;
+            self.z_indexes_scratch[channel_index][neuron.neuron_voxel_coordinate.y as usize]
+                .push(neuron.neuron_voxel_coordinate.z);
+            channel_changed[channel_index] = true;
+        }
+
+        let mut amplitude: Percentage = Percentage::new_zero();
+        for (channel_index, channel_scratch) in self.z_indexes_scratch.iter().enumerate() {
+            if !channel_changed[channel_index] {
+                continue;
+            }
+            let band_amplitudes: &mut MiscData = pipelines_with_data_to_update
+                .get_mut(channel_index)
+                .unwrap()
+                .get_preprocessed_cached_value_mut()
+                .try_into()?;
+            let internal_data = band_amplitudes.get_internal_data_mut();
+            for (band, z_indexes) in channel_scratch.iter().enumerate() {
+                if z_indexes.is_empty() {
+                    internal_data[(0, band, 0)] = 0.0;
+                    continue;
+                }
+                decode_unsigned_percentage_from_linear_neurons(
+                    z_indexes,
+                    amplitude_depth,
+                    &mut amplitude,
+                );
+                internal_data[(0, band, 0)] = amplitude.get_as_0_1();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ToneBandsNeuronVoxelXYZPDecoder {
+    #[allow(dead_code)]
+    pub fn new_box(
+        cortical_read_target: CorticalID,
+        number_tone_bands: u32,
+        amplitude_depth: NeuronDepth,
+        number_of_channels: CorticalChannelCount,
+    ) -> Result<Box<dyn NeuronVoxelXYZPDecoder + Sync + Send>, FeagiDataError> {
+        if number_tone_bands == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Tone bands decoder needs at least one band!".into(),
+            ));
+        }
+        let decoder = ToneBandsNeuronVoxelXYZPDecoder {
+            cortical_read_target,
+            number_tone_bands,
+            amplitude_depth,
+            z_indexes_scratch: vec![
+                vec![Vec::new(); number_tone_bands as usize];
+                *number_of_channels as usize
+            ],
+        };
+        Ok(Box::new(decoder))
+    }
+
+    fn get_band_amplitude_dimensions(&self) -> MiscDataDimensions {
+        MiscDataDimensions::new(1, self.number_tone_bands, 1).unwrap()
+    }
+}
@@ -0,0 +1,138 @@
+use crate::configuration::jsonable::JSONEncoderProperties;
+use crate::data_pipeline::per_channel_stream_caches::{
+    PipelineStageRunner, SensoryPipelineStageRunner,
+};
+use crate::data_types::descriptors::MiscDataDimensions;
+use crate::data_types::{MiscData, Percentage};
+use crate::neuron_voxel_coding::xyzp::coder_shared_functions::encode_unsigned_percentage_to_linear_neuron_z_index;
+use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPEncoder;
+use crate::wrapped_io_data::WrappedIOType;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalChannelIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use feagi_structures::FeagiDataError;
+use rayon::prelude::*;
+
+/// Encodes spectrograms (time along x, frequency bands along y, stored as a [`MiscData`] with a
+/// depth of 1) by firing one neuron per time / band column, with louder bands closer to z = 0.
+/// Silent bands do not fire. Channels are laid out side by side along x.
+#[derive(Debug)]
+pub struct AudioSpectrogramNeuronVoxelXYZPEncoder {
+    spectrogram_dimensions: MiscDataDimensions,
+    intensity_depth: NeuronDepth,
+    cortical_write_target: CorticalID,
+    scratch_space: Vec<NeuronVoxelXYZPArrays>,
+}
+
+impl NeuronVoxelXYZPEncoder for AudioSpectrogramNeuronVoxelXYZPEncoder {
+    fn get_encodable_data_type(&self) -> WrappedIOType {
+        WrappedIOType::MiscData(Some(self.spectrogram_dimensions))
+    }
+
+    fn get_as_properties(&self) -> JSONEncoderProperties {
+        JSONEncoderProperties::AudioSpectrogram(self.spectrogram_dimensions, self.intensity_depth)
+    }
+
+    fn write_neuron_data_multi_channel_from_processed_cache(
+        &mut self,
+        pipelines: &[SensoryPipelineStageRunner],
+        channels_to_encode: &[bool],
+        write_target: &mut CorticalMappedXYZPNeuronVoxels,
+    ) -> Result<(), FeagiDataError> {
+        const EPSILON: f32 = 0.0001; // Silence does not fire
+
+        let neuron_array_target =
+            write_target.ensure_clear_and_borrow_mut(&self.cortical_write_target);
+        let number_time_windows = self.spectrogram_dimensions.width;
+        let z_depth: u32 = *self.intensity_depth;
+        let z_depth_float: f32 = z_depth as f32;
+
+        pipelines
+            .par_iter()
+            .zip(self.scratch_space.par_iter_mut())
+            .enumerate()
+            .try_for_each(
+                |(current_channel_index, (pipeline, scratch))| -> Result<(), FeagiDataError> {
+                    if !channels_to_encode[current_channel_index] {
+                        scratch.clear();
+                        return Ok(()); // Not encoded this burst, send nothing
+                    }
+                    let channel_write_target =
+                        pipeline.get_channel_index_override().unwrap_or_else(|| {
+                            CorticalChannelIndex::from(current_channel_index as u32)
+                        }); // Get override if available
+                    let updated_data = pipeline.get_postprocessed_sensor_value();
+                    let spectrogram: &MiscData = updated_data.try_into()?;
+                    let x_offset: u32 = *channel_write_target * number_time_windows;
+
+                    scratch.clear();
+                    let mut z_indexes: Vec<u32> = Vec::with_capacity(1);
+                    scratch.update_vectors_from_external(|x_vec, y_vec, z_vec, p_vec| {
+                        for ((time_index, band, _), magnitude) in
+                            spectrogram.get_internal_data().indexed_iter()
+                        {
+                            if *magnitude <= EPSILON {
+                                continue;
+                            }
+                            encode_unsigned_percentage_to_linear_neuron_z_index(
+                                &Percentage::new_from_0_1_unchecked(magnitude.clamp(0.0, 1.0)),
+                                z_depth_float,
+                                &mut z_indexes,
+                            );
+                            x_vec.push(time_index as u32 + x_offset);
+                            y_vec.push(band as u32);
+                            z_vec.push(z_indexes[0].min(z_depth - 1));
+                            p_vec.push(1.0);
+                        }
+                        Ok(())
+                    })
+                },
+            )?;
+
+        let total_neurons: usize = self.scratch_space.iter().map(|scratch| scratch.len()).sum();
+
+        neuron_array_target.ensure_capacity(total_neurons);
+
+        neuron_array_target.update_vectors_from_external(
+            |target_x, target_y, target_z, target_p| {
+                for scratch in self.scratch_space.iter() {
+                    let (scratch_x, scratch_y, scratch_z, scratch_p) =
+                        scratch.borrow_xyzp_vectors();
+                    target_x.extend_from_slice(scratch_x);
+                    target_y.extend_from_slice(scratch_y);
+                    target_z.extend_from_slice(scratch_z);
+                    target_p.extend_from_slice(scratch_p);
+                }
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+}
+
+impl AudioSpectrogramNeuronVoxelXYZPEncoder {
+    pub fn new_box(
+        cortical_write_target: CorticalID,
+        spectrogram_dimensions: MiscDataDimensions,
+        intensity_depth: NeuronDepth,
+        number_channels: CorticalChannelCount,
+    ) -> Result<Box<dyn NeuronVoxelXYZPEncoder + Sync + Send>, FeagiDataError> {
+        if spectrogram_dimensions.depth != 1 {
+            return Err(FeagiDataError::BadParameters(format!(
+                "Spectrograms must have a depth of 1, got {}!",
+                spectrogram_dimensions
+            )));
+        }
+        let encoder = AudioSpectrogramNeuronVoxelXYZPEncoder {
+            spectrogram_dimensions,
+            intensity_depth,
+            cortical_write_target,
+            scratch_space: vec![NeuronVoxelXYZPArrays::new(); *number_channels as usize],
+        };
+        Ok(Box::new(encoder))
+    }
+}
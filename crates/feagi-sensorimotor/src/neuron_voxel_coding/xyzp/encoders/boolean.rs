@@ -11,7 +11,6 @@ use feagi_structures::genomic::cortical_area::CorticalID;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::FeagiDataError;
 use rayon::prelude::*;
-use std::time::Instant;
 
 const NEURON_TRUE_VAL: f32 = 1.0;
 const NEURON_FALSE_VAL: f32 = 0.0;
@@ -42,10 +41,9 @@ impl NeuronVoxelXYZPEncoder for BooleanNeuronVoxelXYZPEncoder {
     fn write_neuron_data_multi_channel_from_processed_cache(
         &mut self,
         pipelines: &[SensoryPipelineStageRunner],
-        time_of_previous_burst: Instant,
+        channels_to_encode: &[bool],
         write_target: &mut CorticalMappedXYZPNeuronVoxels,
     ) -> Result<(), FeagiDataError> {
-        // If this is called, then at least one channel has had something updated
         let neuron_array_target =
             write_target.ensure_clear_and_borrow_mut(&self.cortical_write_target);
 
@@ -54,11 +52,10 @@ impl NeuronVoxelXYZPEncoder for BooleanNeuronVoxelXYZPEncoder {
             .zip(self.scratch_space.par_iter_mut())
             .enumerate()
             .try_for_each(
-                |(_channel_index, (pipeline, scratch))| -> Result<(), FeagiDataError> {
-                    let channel_updated = pipeline.get_last_processed_instant();
-                    if channel_updated < time_of_previous_burst {
+                |(channel_index, (pipeline, scratch))| -> Result<(), FeagiDataError> {
+                    if !channels_to_encode[channel_index] {
                         *scratch = BoolState::Unchanged;
-                        return Ok(()); // We haven't updated, do nothing
+                        return Ok(()); // Not encoded this burst, send nothing
                     }
                     let updated_data = pipeline.get_postprocessed_sensor_value();
                     let updated_bool: bool = updated_data.try_into()?;
@@ -72,28 +69,22 @@ impl NeuronVoxelXYZPEncoder for BooleanNeuronVoxelXYZPEncoder {
             )?;
 
         // Cannot parallelize due to data writing of various lengths
-        for channel_index in 0..self.scratch_space.len() {
-            const Y: u32 = 0;
-            const Z: u32 = 0;
-            for (current_channel_x, changed) in self.scratch_space.iter().enumerate() {
-                let channel_stage_runner = pipelines.get(channel_index).unwrap(); // Should always be the right length
-                let channel_to_write = channel_stage_runner
-                    .get_channel_index_override()
-                    .unwrap_or_else(|| CorticalChannelIndex::from(current_channel_x as u32)); // Get override if available
+        const Y: u32 = 0;
+        const Z: u32 = 0;
+        for (current_channel_x, (state, channel_stage_runner)) in
+            self.scratch_space.iter().zip(pipelines.iter()).enumerate()
+        {
+            let channel_to_write = channel_stage_runner
+                .get_channel_index_override()
+                .unwrap_or_else(|| CorticalChannelIndex::from(current_channel_x as u32)); // Get override if available
 
-                match changed {
-                    BoolState::Unchanged => {
-                        // Not possible
-                        return Err(FeagiDataError::InternalError(
-                            "Unable to send unchanged boolean as a changed!".into(),
-                        ));
-                    }
-                    BoolState::True => {
-                        neuron_array_target.push_raw(*channel_to_write, Y, Z, NEURON_TRUE_VAL)
-                    }
-                    BoolState::False => {
-                        neuron_array_target.push_raw(*channel_to_write, Y, Z, NEURON_FALSE_VAL)
-                    }
+            match state {
+                BoolState::Unchanged => {} // Nothing to send
+                BoolState::True => {
+                    neuron_array_target.push_raw(*channel_to_write, Y, Z, NEURON_TRUE_VAL)
+                }
+                BoolState::False => {
+                    neuron_array_target.push_raw(*channel_to_write, Y, Z, NEURON_FALSE_VAL)
                 }
             }
         }
@@ -0,0 +1,137 @@
+use crate::configuration::jsonable::JSONEncoderProperties;
+use crate::data_pipeline::per_channel_stream_caches::{
+    PipelineStageRunner, SensoryPipelineStageRunner,
+};
+use crate::data_types::descriptors::ImageXYResolution;
+use crate::data_types::{EventPolarity, EventStream};
+use crate::neuron_voxel_coding::xyzp::NeuronVoxelXYZPEncoder;
+use crate::wrapped_io_data::WrappedIOType;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalChannelIndex,
+};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use feagi_structures::FeagiDataError;
+use rayon::prelude::*;
+
+/// Bins the events written since the previous burst directly into neurons, one per pixel and
+/// polarity, with ON events at z = 0 and OFF events at z = 1. The potential is the number of
+/// events binned into that neuron. Like images, y is flipped so (0,0) is the bottom left, and
+/// channels are laid out side by side along x.
+///
+/// Events only fire in the burst they were written for. Channels without new events are silent.
+#[derive(Debug)]
+pub struct EventStreamNeuronVoxelXYZPEncoder {
+    resolution: ImageXYResolution,
+    cortical_write_target: CorticalID,
+    scratch_space: Vec<NeuronVoxelXYZPArrays>,
+    event_counts: Vec<Vec<u32>>, // Per channel, per pixel and polarity
+}
+
+impl NeuronVoxelXYZPEncoder for EventStreamNeuronVoxelXYZPEncoder {
+    fn get_encodable_data_type(&self) -> WrappedIOType {
+        WrappedIOType::EventStream(Some(self.resolution))
+    }
+
+    fn get_as_properties(&self) -> JSONEncoderProperties {
+        JSONEncoderProperties::EventStream(self.resolution)
+    }
+
+    fn write_neuron_data_multi_channel_from_processed_cache(
+        &mut self,
+        pipelines: &[SensoryPipelineStageRunner],
+        channels_to_encode: &[bool],
+        write_target: &mut CorticalMappedXYZPNeuronVoxels,
+    ) -> Result<(), FeagiDataError> {
+        let neuron_array_target =
+            write_target.ensure_clear_and_borrow_mut(&self.cortical_write_target);
+        let width = self.resolution.width;
+        let height = self.resolution.height;
+
+        pipelines
+            .par_iter()
+            .zip(self.scratch_space.par_iter_mut())
+            .zip(self.event_counts.par_iter_mut())
+            .enumerate()
+            .try_for_each(
+                |(current_channel_index, ((pipeline, scratch), event_counts))| -> Result<(), FeagiDataError> {
+                    scratch.clear(); // Events are transient, so stale channels do not fire again
+                    if !channels_to_encode[current_channel_index] {
+                        return Ok(()); // Not encoded this burst, send nothing
+                    }
+                    let channel_write_target =
+                        pipeline.get_channel_index_override().unwrap_or_else(|| {
+                            CorticalChannelIndex::from(current_channel_index as u32)
+                        }); // Get override if available
+                    let updated_data = pipeline.get_postprocessed_sensor_value();
+                    let events: &EventStream = updated_data.try_into()?;
+                    let x_offset: u32 = *channel_write_target * width;
+
+                    // Bin the events, remembering the order each neuron was first hit in
+                    let mut hit_indexes: Vec<usize> = Vec::new();
+                    for event in events.get_events() {
+                        let z: u32 = match event.polarity {
+                            EventPolarity::On => 0,
+                            EventPolarity::Off => 1,
+                        };
+                        let index = ((event.y * width + event.x) * 2 + z) as usize;
+                        if event_counts[index] == 0 {
+                            hit_indexes.push(index);
+                        }
+                        event_counts[index] += 1;
+                    }
+
+                    scratch.ensure_capacity(hit_indexes.len());
+                    scratch.update_vectors_from_external(|x_vec, y_vec, z_vec, p_vec| {
+                        for index in hit_indexes.iter() {
+                            let pixel = (*index / 2) as u32;
+                            x_vec.push(pixel % width + x_offset);
+                            y_vec.push(height - 1 - pixel / width); // Flip Y, like images
+                            z_vec.push((*index % 2) as u32);
+                            p_vec.push(event_counts[*index] as f32);
+                            event_counts[*index] = 0;
+                        }
+                        Ok(())
+                    })
+                },
+            )?;
+
+        let total_neurons: usize = self.scratch_space.iter().map(|scratch| scratch.len()).sum();
+
+        neuron_array_target.ensure_capacity(total_neurons);
+
+        neuron_array_target.update_vectors_from_external(
+            |target_x, target_y, target_z, target_p| {
+                for scratch in self.scratch_space.iter() {
+                    let (scratch_x, scratch_y, scratch_z, scratch_p) =
+                        scratch.borrow_xyzp_vectors();
+                    target_x.extend_from_slice(scratch_x);
+                    target_y.extend_from_slice(scratch_y);
+                    target_z.extend_from_slice(scratch_z);
+                    target_p.extend_from_slice(scratch_p);
+                }
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+}
+
+impl EventStreamNeuronVoxelXYZPEncoder {
+    pub fn new_box(
+        cortical_write_target: CorticalID,
+        resolution: ImageXYResolution,
+        number_channels: CorticalChannelCount,
+    ) -> Result<Box<dyn NeuronVoxelXYZPEncoder + Sync + Send>, FeagiDataError> {
+        let number_bins = (resolution.width * resolution.height * 2) as usize;
+        let encoder = EventStreamNeuronVoxelXYZPEncoder {
+            resolution,
+            cortical_write_target,
+            scratch_space: vec![NeuronVoxelXYZPArrays::new(); *number_channels as usize],
+            event_counts: vec![vec![0; number_bins]; *number_channels as usize],
+        };
+        Ok(Box::new(encoder))
+    }
+}
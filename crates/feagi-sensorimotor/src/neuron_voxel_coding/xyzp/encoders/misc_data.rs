@@ -15,7 +15,6 @@ use feagi_structures::neuron_voxels::xyzp::{
 };
 use feagi_structures::FeagiDataError;
 use rayon::prelude::*;
-use std::time::Instant;
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -37,11 +36,9 @@ impl NeuronVoxelXYZPEncoder for MiscDataNeuronVoxelXYZPEncoder {
     fn write_neuron_data_multi_channel_from_processed_cache(
         &mut self,
         pipelines: &[SensoryPipelineStageRunner],
-        time_of_previous_burst: Instant,
+        channels_to_encode: &[bool],
         write_target: &mut CorticalMappedXYZPNeuronVoxels,
     ) -> Result<(), FeagiDataError> {
-        // If this is called, then at least one channel has had something updated
-
         let neuron_array_target =
             write_target.ensure_clear_and_borrow_mut(&self.cortical_write_target);
 
@@ -51,9 +48,9 @@ impl NeuronVoxelXYZPEncoder for MiscDataNeuronVoxelXYZPEncoder {
             .enumerate()
             .try_for_each(
                 |(current_channel_index, (pipeline, scratch))| -> Result<(), FeagiDataError> {
-                    let channel_updated = pipeline.get_last_processed_instant();
-                    if channel_updated < time_of_previous_burst {
-                        return Ok(()); // We haven't updated, do nothing
+                    if !channels_to_encode[current_channel_index] {
+                        scratch.clear();
+                        return Ok(()); // Not encoded this burst, send nothing
                     }
                     let channel_write_target =
                         pipeline.get_channel_index_override().unwrap_or_else(|| {
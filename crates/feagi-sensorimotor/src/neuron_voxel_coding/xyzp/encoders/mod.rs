@@ -1,15 +1,20 @@
-// TODO there is a bug on all encoders in that they will send all channel data even if not updated since scratch is cleared only if channel is active!
+mod audio_spectrogram;
 mod boolean;
 mod cartesian_plane;
+mod event_stream;
 mod misc_data;
 mod percentage_encoder;
 mod segmented_image_frame;
 
+#[allow(unused_imports)]
+pub(crate) use audio_spectrogram::AudioSpectrogramNeuronVoxelXYZPEncoder;
 #[allow(unused_imports)]
 pub(crate) use boolean::BooleanNeuronVoxelXYZPEncoder;
 #[allow(unused_imports)]
 pub(crate) use cartesian_plane::CartesianPlaneNeuronVoxelXYZPEncoder;
 #[allow(unused_imports)]
+pub(crate) use event_stream::EventStreamNeuronVoxelXYZPEncoder;
+#[allow(unused_imports)]
 pub(crate) use misc_data::MiscDataNeuronVoxelXYZPEncoder;
 #[allow(unused_imports)]
 pub(crate) use segmented_image_frame::SegmentedImageFrameNeuronVoxelXYZPEncoder;
@@ -25,7 +25,6 @@ use feagi_structures::genomic::cortical_area::CorticalID;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::FeagiDataError;
 use rayon::prelude::*;
-use std::time::Instant;
 
 /// Scratch space sized appropriately for dimension count
 #[derive(Debug)]
@@ -138,7 +137,7 @@ impl NeuronVoxelXYZPEncoder for PercentageNeuronVoxelXYZPEncoder {
     fn write_neuron_data_multi_channel_from_processed_cache(
         &mut self,
         pipelines: &[SensoryPipelineStageRunner],
-        time_of_previous_burst: Instant,
+        channels_to_encode: &[bool],
         write_target: &mut CorticalMappedXYZPNeuronVoxels,
     ) -> Result<(), FeagiDataError> {
         let neuron_array_target =
@@ -157,22 +156,27 @@ impl NeuronVoxelXYZPEncoder for PercentageNeuronVoxelXYZPEncoder {
             (ScratchSpace::D1(scratch), ScratchSpace::D1(scratch_neg)) => {
                 pipelines
                     .par_iter()
+                    .zip(channels_to_encode.par_iter())
                     .zip(scratch.par_iter_mut())
                     .zip(scratch_neg.par_iter_mut())
-                    .try_for_each(|((pipeline, s), s_neg)| -> Result<(), FeagiDataError> {
-                        if pipeline.get_last_processed_instant() < time_of_previous_burst {
-                            return Ok(());
-                        }
-                        let data = pipeline.get_postprocessed_sensor_value();
-                        if is_signed {
-                            let p: SignedPercentage = data.try_into()?;
-                            encode_signed(interpolation, &p, z_depth, z_depth_float, s, s_neg);
-                        } else {
-                            let p: Percentage = data.try_into()?;
-                            encode_unsigned(interpolation, &p, z_depth, z_depth_float, s);
-                        }
-                        Ok(())
-                    })?;
+                    .try_for_each(
+                        |(((pipeline, encode), s), s_neg)| -> Result<(), FeagiDataError> {
+                            if !encode {
+                                s.clear();
+                                s_neg.clear();
+                                return Ok(()); // Not encoded this burst, send nothing
+                            }
+                            let data = pipeline.get_postprocessed_sensor_value();
+                            if is_signed {
+                                let p: SignedPercentage = data.try_into()?;
+                                encode_signed(interpolation, &p, z_depth, z_depth_float, s, s_neg);
+                            } else {
+                                let p: Percentage = data.try_into()?;
+                                encode_unsigned(interpolation, &p, z_depth, z_depth_float, s);
+                            }
+                            Ok(())
+                        },
+                    )?;
 
                 // Write to neurons
                 for (current_channel_index, (s, s_neg)) in
@@ -199,38 +203,57 @@ impl NeuronVoxelXYZPEncoder for PercentageNeuronVoxelXYZPEncoder {
             (ScratchSpace::D2(scratch), ScratchSpace::D2(scratch_neg)) => {
                 pipelines
                     .par_iter()
+                    .zip(channels_to_encode.par_iter())
                     .zip(scratch.par_iter_mut())
                     .zip(scratch_neg.par_iter_mut())
-                    .try_for_each(|((pipeline, s), s_neg)| -> Result<(), FeagiDataError> {
-                        if pipeline.get_last_processed_instant() < time_of_previous_burst {
-                            return Ok(());
-                        }
-                        let data = pipeline.get_postprocessed_sensor_value();
-                        if is_signed {
-                            let p: SignedPercentage2D = data.try_into()?;
-                            encode_signed(
-                                interpolation,
-                                &p.a,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.0,
-                                &mut s_neg.0,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.b,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.1,
-                                &mut s_neg.1,
-                            );
-                        } else {
-                            let p: Percentage2D = data.try_into()?;
-                            encode_unsigned(interpolation, &p.a, z_depth, z_depth_float, &mut s.0);
-                            encode_unsigned(interpolation, &p.b, z_depth, z_depth_float, &mut s.1);
-                        }
-                        Ok(())
-                    })?;
+                    .try_for_each(
+                        |(((pipeline, encode), s), s_neg)| -> Result<(), FeagiDataError> {
+                            if !encode {
+                                s.0.clear();
+                                s.1.clear();
+                                s_neg.0.clear();
+                                s_neg.1.clear();
+                                return Ok(()); // Not encoded this burst, send nothing
+                            }
+                            let data = pipeline.get_postprocessed_sensor_value();
+                            if is_signed {
+                                let p: SignedPercentage2D = data.try_into()?;
+                                encode_signed(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                    &mut s_neg.0,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                    &mut s_neg.1,
+                                );
+                            } else {
+                                let p: Percentage2D = data.try_into()?;
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                );
+                            }
+                            Ok(())
+                        },
+                    )?;
 
                 for (current_channel_index, (s, s_neg)) in
                     scratch.iter().zip(scratch_neg.iter()).enumerate()
@@ -269,47 +292,74 @@ impl NeuronVoxelXYZPEncoder for PercentageNeuronVoxelXYZPEncoder {
             (ScratchSpace::D3(scratch), ScratchSpace::D3(scratch_neg)) => {
                 pipelines
                     .par_iter()
+                    .zip(channels_to_encode.par_iter())
                     .zip(scratch.par_iter_mut())
                     .zip(scratch_neg.par_iter_mut())
-                    .try_for_each(|((pipeline, s), s_neg)| -> Result<(), FeagiDataError> {
-                        if pipeline.get_last_processed_instant() < time_of_previous_burst {
-                            return Ok(());
-                        }
-                        let data = pipeline.get_postprocessed_sensor_value();
-                        if is_signed {
-                            let p: SignedPercentage3D = data.try_into()?;
-                            encode_signed(
-                                interpolation,
-                                &p.a,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.0,
-                                &mut s_neg.0,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.b,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.1,
-                                &mut s_neg.1,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.c,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.2,
-                                &mut s_neg.2,
-                            );
-                        } else {
-                            let p: Percentage3D = data.try_into()?;
-                            encode_unsigned(interpolation, &p.a, z_depth, z_depth_float, &mut s.0);
-                            encode_unsigned(interpolation, &p.b, z_depth, z_depth_float, &mut s.1);
-                            encode_unsigned(interpolation, &p.c, z_depth, z_depth_float, &mut s.2);
-                        }
-                        Ok(())
-                    })?;
+                    .try_for_each(
+                        |(((pipeline, encode), s), s_neg)| -> Result<(), FeagiDataError> {
+                            if !encode {
+                                s.0.clear();
+                                s.1.clear();
+                                s.2.clear();
+                                s_neg.0.clear();
+                                s_neg.1.clear();
+                                s_neg.2.clear();
+                                return Ok(()); // Not encoded this burst, send nothing
+                            }
+                            let data = pipeline.get_postprocessed_sensor_value();
+                            if is_signed {
+                                let p: SignedPercentage3D = data.try_into()?;
+                                encode_signed(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                    &mut s_neg.0,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                    &mut s_neg.1,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.c,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.2,
+                                    &mut s_neg.2,
+                                );
+                            } else {
+                                let p: Percentage3D = data.try_into()?;
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.c,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.2,
+                                );
+                            }
+                            Ok(())
+                        },
+                    )?;
 
                 for (current_channel_index, (s, s_neg)) in
                     scratch.iter().zip(scratch_neg.iter()).enumerate()
@@ -357,56 +407,91 @@ impl NeuronVoxelXYZPEncoder for PercentageNeuronVoxelXYZPEncoder {
             (ScratchSpace::D4(scratch), ScratchSpace::D4(scratch_neg)) => {
                 pipelines
                     .par_iter()
+                    .zip(channels_to_encode.par_iter())
                     .zip(scratch.par_iter_mut())
                     .zip(scratch_neg.par_iter_mut())
-                    .try_for_each(|((pipeline, s), s_neg)| -> Result<(), FeagiDataError> {
-                        if pipeline.get_last_processed_instant() < time_of_previous_burst {
-                            return Ok(());
-                        }
-                        let data = pipeline.get_postprocessed_sensor_value();
-                        if is_signed {
-                            let p: SignedPercentage4D = data.try_into()?;
-                            encode_signed(
-                                interpolation,
-                                &p.a,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.0,
-                                &mut s_neg.0,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.b,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.1,
-                                &mut s_neg.1,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.c,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.2,
-                                &mut s_neg.2,
-                            );
-                            encode_signed(
-                                interpolation,
-                                &p.d,
-                                z_depth,
-                                z_depth_float,
-                                &mut s.3,
-                                &mut s_neg.3,
-                            );
-                        } else {
-                            let p: Percentage4D = data.try_into()?;
-                            encode_unsigned(interpolation, &p.a, z_depth, z_depth_float, &mut s.0);
-                            encode_unsigned(interpolation, &p.b, z_depth, z_depth_float, &mut s.1);
-                            encode_unsigned(interpolation, &p.c, z_depth, z_depth_float, &mut s.2);
-                            encode_unsigned(interpolation, &p.d, z_depth, z_depth_float, &mut s.3);
-                        }
-                        Ok(())
-                    })?;
+                    .try_for_each(
+                        |(((pipeline, encode), s), s_neg)| -> Result<(), FeagiDataError> {
+                            if !encode {
+                                s.0.clear();
+                                s.1.clear();
+                                s.2.clear();
+                                s.3.clear();
+                                s_neg.0.clear();
+                                s_neg.1.clear();
+                                s_neg.2.clear();
+                                s_neg.3.clear();
+                                return Ok(()); // Not encoded this burst, send nothing
+                            }
+                            let data = pipeline.get_postprocessed_sensor_value();
+                            if is_signed {
+                                let p: SignedPercentage4D = data.try_into()?;
+                                encode_signed(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                    &mut s_neg.0,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                    &mut s_neg.1,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.c,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.2,
+                                    &mut s_neg.2,
+                                );
+                                encode_signed(
+                                    interpolation,
+                                    &p.d,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.3,
+                                    &mut s_neg.3,
+                                );
+                            } else {
+                                let p: Percentage4D = data.try_into()?;
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.a,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.0,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.b,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.1,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.c,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.2,
+                                );
+                                encode_unsigned(
+                                    interpolation,
+                                    &p.d,
+                                    z_depth,
+                                    z_depth_float,
+                                    &mut s.3,
+                                );
+                            }
+                            Ok(())
+                        },
+                    )?;
 
                 for (current_channel_index, (s, s_neg)) in
                     scratch.iter().zip(scratch_neg.iter()).enumerate()
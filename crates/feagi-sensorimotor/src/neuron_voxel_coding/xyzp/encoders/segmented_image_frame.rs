@@ -15,7 +15,6 @@ use feagi_structures::neuron_voxels::xyzp::{
 };
 use feagi_structures::FeagiDataError;
 use rayon::prelude::*;
-use std::time::Instant;
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -38,7 +37,7 @@ impl NeuronVoxelXYZPEncoder for SegmentedImageFrameNeuronVoxelXYZPEncoder {
     fn write_neuron_data_multi_channel_from_processed_cache(
         &mut self,
         pipelines: &[SensoryPipelineStageRunner],
-        time_of_previous_burst: Instant,
+        channels_to_encode: &[bool],
         write_target: &mut CorticalMappedXYZPNeuronVoxels,
     ) -> Result<(), FeagiDataError> {
         // Parallel iterate over channels
@@ -48,10 +47,9 @@ impl NeuronVoxelXYZPEncoder for SegmentedImageFrameNeuronVoxelXYZPEncoder {
             .enumerate()
             .try_for_each(
                 |(current_channel_index, (pipeline, scratches))| -> Result<(), FeagiDataError> {
-                    let channel_updated = pipeline.get_last_processed_instant();
-
-                    if channel_updated < time_of_previous_burst {
-                        return Ok(()); // We haven't updated, do nothing
+                    if !channels_to_encode[current_channel_index] {
+                        scratches.iter_mut().for_each(|scratch| scratch.clear());
+                        return Ok(()); // Not encoded this burst, send nothing
                     }
 
                     let channel_write_target =
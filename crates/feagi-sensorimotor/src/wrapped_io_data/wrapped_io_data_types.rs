@@ -1,7 +1,7 @@
 use crate::data_types::{
-    GazeProperties, ImageFilteringSettings, ImageFrame, MiscData, Percentage, Percentage2D,
-    Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage, SignedPercentage2D,
-    SignedPercentage3D, SignedPercentage4D,
+    AudioFrame, EventStream, GazeProperties, ImageFilteringSettings, ImageFrame, MiscData,
+    Percentage, Percentage2D, Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage,
+    SignedPercentage2D, SignedPercentage3D, SignedPercentage4D,
 };
 use feagi_structures::FeagiDataError;
 
@@ -28,7 +28,7 @@ macro_rules! define_wrapped_io_data_enum {
         /// // Extract back to concrete type
         /// let extracted: Percentage = wrapped.try_into().unwrap();
         /// ```
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, PartialEq)]
         #[allow(non_camel_case_types)]
         #[allow(clippy::large_enum_variant)] // SegmentedImageFrame is large but necessary
         /// Due to Rust's memory management, WrappedIOData is used to pass around various data structures around.
@@ -112,6 +112,8 @@ define_wrapped_io_data_enum!(
     ImageFrame: ImageFrame => "{}",
     SegmentedImageFrame: SegmentedImageFrame => "{}",
     MiscData: MiscData => "{}",
+    AudioFrame: AudioFrame => "{}",
+    EventStream: EventStream => "{}",
     GazeProperties: GazeProperties => "{}",
     ImageFilteringSettings: ImageFilteringSettings => "{}"
 );
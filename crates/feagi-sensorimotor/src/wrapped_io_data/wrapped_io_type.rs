@@ -1,10 +1,11 @@
 use crate::data_types::descriptors::{
-    ImageFrameProperties, MiscDataDimensions, SegmentedImageFrameProperties,
+    AudioFrameProperties, ImageFrameProperties, ImageXYResolution, MiscDataDimensions,
+    SegmentedImageFrameProperties,
 };
 use crate::data_types::{
-    GazeProperties, ImageFilteringSettings, ImageFrame, MiscData, Percentage, Percentage2D,
-    Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage, SignedPercentage2D,
-    SignedPercentage3D, SignedPercentage4D,
+    AudioFrame, EventStream, GazeProperties, ImageFilteringSettings, ImageFrame, MiscData,
+    Percentage, Percentage2D, Percentage3D, Percentage4D, SegmentedImageFrame, SignedPercentage,
+    SignedPercentage2D, SignedPercentage3D, SignedPercentage4D,
 };
 use crate::wrapped_io_data::WrappedIOData;
 use feagi_structures::FeagiDataError;
@@ -17,7 +18,7 @@ use std::mem::discriminant;
 /// the actual data. Used for type checking, validation, and creating appropriately-typed
 /// blank data instances.
 ///
-/// Some variants (images, misc data) can optionally include dimensional properties
+/// Some variants (images, misc data, audio, events) can optionally include dimensional properties
 /// to enable efficient memory pre-allocation.
 ///
 /// # Examples
@@ -42,6 +43,8 @@ pub enum WrappedIOType {
     ImageFrame(Option<ImageFrameProperties>),
     SegmentedImageFrame(Option<SegmentedImageFrameProperties>),
     MiscData(Option<MiscDataDimensions>),
+    AudioFrame(Option<AudioFrameProperties>),
+    EventStream(Option<ImageXYResolution>),
     GazeProperties,
     ImageFilteringSettings,
 }
@@ -71,7 +74,7 @@ impl WrappedIOType {
 
     /// Creates a zero-initialized instance of wrapped data for this type.
     ///
-    /// For types with associated properties (images, misc data), those properties
+    /// For types with associated properties (images, misc data, audio), those properties
     /// must be provided or this will return an error.
     pub fn create_blank_data_of_type(&self) -> Result<WrappedIOData, FeagiDataError> {
         match self {
@@ -139,6 +142,28 @@ impl WrappedIOType {
                     &misc_dimensions.unwrap(),
                 )?))
             }
+            WrappedIOType::AudioFrame(audio_properties) => {
+                if audio_properties.is_none() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Audio frame properties is None! Cannot Created Default Wrapped Data!"
+                            .into(),
+                    ));
+                }
+                Ok(WrappedIOData::AudioFrame(AudioFrame::new(
+                    &audio_properties.unwrap(),
+                )?))
+            }
+            WrappedIOType::EventStream(resolution) => {
+                if resolution.is_none() {
+                    return Err(FeagiDataError::BadParameters(
+                        "Event stream resolution is None! Cannot Created Default Wrapped Data!"
+                            .into(),
+                    ));
+                }
+                Ok(WrappedIOData::EventStream(EventStream::new(
+                    resolution.unwrap(),
+                )))
+            }
             WrappedIOType::GazeProperties => Ok(WrappedIOData::GazeProperties(
                 GazeProperties::create_default_centered(),
             )),
@@ -182,6 +207,20 @@ impl std::fmt::Display for WrappedIOType {
                 };
                 write!(f, "Misc({})", s)
             }
+            WrappedIOType::AudioFrame(audio_properties) => {
+                let s: String = match audio_properties {
+                    Some(properties) => properties.to_string(),
+                    None => "No Requirements".to_string(),
+                };
+                write!(f, "AudioFrame({})", s)
+            }
+            WrappedIOType::EventStream(resolution) => {
+                let s: String = match resolution {
+                    Some(resolution) => format!("<{}, {}>", resolution.width, resolution.height),
+                    None => "No Requirements".to_string(),
+                };
+                write!(f, "EventStream({})", s)
+            }
             WrappedIOType::GazeProperties => write!(f, "IOTypeVariant(GazeProperties)"),
             WrappedIOType::ImageFilteringSettings => {
                 write!(f, "IOTypeVariant(ImageFilteringSettings)")
@@ -211,6 +250,12 @@ impl From<WrappedIOData> for WrappedIOType {
             WrappedIOData::MiscData(dimensions) => {
                 WrappedIOType::MiscData(Some(dimensions.get_dimensions()))
             }
+            WrappedIOData::AudioFrame(audio) => {
+                WrappedIOType::AudioFrame(Some(audio.get_audio_frame_properties()))
+            }
+            WrappedIOData::EventStream(events) => {
+                WrappedIOType::EventStream(Some(events.get_resolution()))
+            }
             WrappedIOData::GazeProperties(_) => WrappedIOType::GazeProperties,
             WrappedIOData::ImageFilteringSettings(_) => WrappedIOType::ImageFilteringSettings,
         }
@@ -238,6 +283,12 @@ impl From<&WrappedIOData> for WrappedIOType {
             WrappedIOData::MiscData(dimensions) => {
                 WrappedIOType::MiscData(Some(dimensions.get_dimensions()))
             }
+            WrappedIOData::AudioFrame(audio) => {
+                WrappedIOType::AudioFrame(Some(audio.get_audio_frame_properties()))
+            }
+            WrappedIOData::EventStream(events) => {
+                WrappedIOType::EventStream(Some(events.get_resolution()))
+            }
             WrappedIOData::GazeProperties(_) => WrappedIOType::GazeProperties,
             WrappedIOData::ImageFilteringSettings(_) => WrappedIOType::ImageFilteringSettings,
         }
@@ -0,0 +1,733 @@
+//! Tests for audio input and output
+//!
+//! Tests cover:
+//! - AudioFrame construction and conversion
+//! - Spectrogram and mel filterbank pipeline stages
+//! - Microphone registration and spectrogram encoding to neurons
+//! - Tone synthesis of band amplitudes into PCM audio
+//! - Speaker registration and decoding of neurons into audio
+//! - Export and import of microphone and speaker registrations as JSON
+
+use feagi_sensorimotor::data_pipeline::PipelineStageProperties;
+use feagi_sensorimotor::data_types::descriptors::{AudioFrameProperties, MiscDataDimensions};
+use feagi_sensorimotor::data_types::processing::{
+    MelFilterbank, ShortTimeFourierTransform, ToneSynthesizer,
+};
+use feagi_sensorimotor::data_types::{AudioFrame, MiscData};
+use feagi_sensorimotor::wrapped_io_data::WrappedIOType;
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::FrameChangeHandling;
+use feagi_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+const SAMPLE_RATE_HZ: u32 = 16000;
+const NUMBER_SAMPLES: u32 = 512;
+const WINDOW_SIZE: u32 = 128;
+
+fn sine_wave(frequency_hz: f32, amplitude: f32) -> AudioFrame {
+    let samples: Vec<f32> = (0..NUMBER_SAMPLES)
+        .map(|i| amplitude * (2.0 * PI * frequency_hz * i as f32 / SAMPLE_RATE_HZ as f32).sin())
+        .collect();
+    AudioFrame::new_from_samples(SAMPLE_RATE_HZ, samples).unwrap()
+}
+
+fn loudest_row(spectrogram: &MiscData, time_index: usize) -> usize {
+    let data = spectrogram.get_internal_data();
+    (0..data.shape()[1])
+        .max_by(|a, b| {
+            data[(time_index, *a, 0)]
+                .partial_cmp(&data[(time_index, *b, 0)])
+                .unwrap()
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test_audio_frame {
+    use super::*;
+
+    #[test]
+    fn test_properties_reject_zero() {
+        assert!(AudioFrameProperties::new(0, 512).is_err());
+        assert!(AudioFrameProperties::new(16000, 0).is_err());
+    }
+
+    #[test]
+    fn test_samples_are_clamped() {
+        let audio = AudioFrame::new_from_samples(8000, vec![-2.0, 0.5, 3.0]).unwrap();
+        assert_eq!(audio.get_samples(), &[-1.0, 0.5, 1.0]);
+        assert_eq!(
+            audio.get_audio_frame_properties(),
+            AudioFrameProperties::new(8000, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_i16_samples() {
+        let audio = AudioFrame::new_from_i16_samples(8000, &[i16::MIN, 0, 16384]).unwrap();
+        assert_eq!(audio.get_samples(), &[-1.0, 0.0, 0.5]);
+        assert_eq!(audio.get_duration_seconds(), 3.0 / 8000.0);
+    }
+
+    #[test]
+    fn test_update_samples_checks_length() {
+        let mut audio = AudioFrame::new(&AudioFrameProperties::new(8000, 4).unwrap()).unwrap();
+        assert!(audio.update_samples(&[0.0; 3]).is_err());
+        audio.update_samples(&[0.25; 4]).unwrap();
+        assert_eq!(audio.get_samples(), &[0.25; 4]);
+    }
+}
+
+#[cfg(test)]
+mod test_audio_spectrum {
+    use super::*;
+
+    fn transform() -> ShortTimeFourierTransform {
+        let properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        ShortTimeFourierTransform::new(properties, WINDOW_SIZE, WINDOW_SIZE / 2).unwrap()
+    }
+
+    fn linear_spectrogram(
+        transform: &mut ShortTimeFourierTransform,
+        audio: &AudioFrame,
+    ) -> MiscData {
+        let mut output = MiscData::new(&transform.get_output_dimensions()).unwrap();
+        transform.process(audio, &mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_transform_rejects_bad_windows() {
+        let properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        assert!(ShortTimeFourierTransform::new(properties, 100, 50).is_err()); // Not a power of two
+        assert!(ShortTimeFourierTransform::new(properties, 1024, 512).is_err()); // Larger than frame
+        assert!(ShortTimeFourierTransform::new(properties, 128, 0).is_err());
+    }
+
+    #[test]
+    fn test_spectrogram_peaks_at_sine_frequency() {
+        let mut transform = transform();
+        assert_eq!(
+            transform.get_output_dimensions(),
+            MiscDataDimensions::new(7, 65, 1).unwrap()
+        );
+        // Bins are 125 Hz apart, so 2 kHz lands exactly on bin 16
+        assert_eq!(transform.get_bin_frequency_hz(16), 2000.0);
+        let output = linear_spectrogram(&mut transform, &sine_wave(2000.0, 1.0));
+
+        for time_index in 0..7 {
+            assert_eq!(loudest_row(&output, time_index), 16);
+            let peak = output.get_internal_data()[(time_index, 16, 0)];
+            assert!(
+                (peak - 1.0).abs() < 0.01,
+                "full scale sine read as {}",
+                peak
+            );
+            assert!(output.get_internal_data()[(time_index, 40, 0)] < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_spectrogram_of_silence_is_zero() {
+        let mut transform = transform();
+        let silence = AudioFrame::new(transform.get_input_properties()).unwrap();
+        let output = linear_spectrogram(&mut transform, &silence);
+        assert!(output.get_internal_data().iter().all(|value| *value == 0.0));
+    }
+
+    #[test]
+    fn test_spectrogram_rejects_mismatched_audio() {
+        let mut transform = transform();
+        let mut output = MiscData::new(&transform.get_output_dimensions()).unwrap();
+        let wrong =
+            AudioFrame::new(&AudioFrameProperties::new(8000, NUMBER_SAMPLES).unwrap()).unwrap();
+        assert!(transform.process(&wrong, &mut output).is_err());
+    }
+
+    #[test]
+    fn test_mel_filterbank_orders_bands_by_pitch() {
+        let mut transform = transform();
+        let mel = MelFilterbank::new(
+            transform.get_output_dimensions(),
+            SAMPLE_RATE_HZ,
+            16,
+            0.0,
+            8000.0,
+            60.0,
+        )
+        .unwrap();
+        assert_eq!(
+            mel.get_output_dimensions(),
+            MiscDataDimensions::new(7, 16, 1).unwrap()
+        );
+
+        let mut peak_bands: Vec<usize> = Vec::new();
+        for frequency_hz in [250.0, 1000.0, 4000.0] {
+            let linear = linear_spectrogram(&mut transform, &sine_wave(frequency_hz, 0.5));
+            let mut output = MiscData::new(&mel.get_output_dimensions()).unwrap();
+            mel.process(&linear, &mut output).unwrap();
+            assert!(output
+                .get_internal_data()
+                .iter()
+                .all(|value| (0.0..=1.0).contains(value)));
+            peak_bands.push(loudest_row(&output, 3));
+        }
+        assert!(peak_bands[0] < peak_bands[1] && peak_bands[1] < peak_bands[2]);
+    }
+
+    #[test]
+    fn test_mel_filterbank_rejects_bad_ranges() {
+        let dimensions = MiscDataDimensions::new(7, 65, 1).unwrap();
+        assert!(MelFilterbank::new(dimensions, 16000, 16, 0.0, 9000.0, 60.0).is_err());
+        assert!(MelFilterbank::new(dimensions, 16000, 16, 500.0, 100.0, 60.0).is_err());
+        assert!(MelFilterbank::new(dimensions, 16000, 0, 0.0, 8000.0, 60.0).is_err());
+        assert!(MelFilterbank::new(dimensions, 16000, 16, 0.0, 8000.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_stage_properties_types() {
+        let audio_properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        let spectrogram = PipelineStageProperties::new_audio_spectrogram(
+            audio_properties,
+            WINDOW_SIZE,
+            WINDOW_SIZE / 2,
+        );
+        assert_eq!(
+            spectrogram.get_input_data_type(),
+            WrappedIOType::AudioFrame(Some(audio_properties))
+        );
+        let spectrogram_dimensions = MiscDataDimensions::new(7, 65, 1).unwrap();
+        assert_eq!(
+            spectrogram.get_output_data_type(),
+            WrappedIOType::MiscData(Some(spectrogram_dimensions))
+        );
+
+        let mel = PipelineStageProperties::new_audio_mel_filterbank(
+            spectrogram_dimensions,
+            SAMPLE_RATE_HZ,
+            24,
+            100.0,
+            6000.0,
+            0.0,
+        );
+        assert_eq!(
+            mel.get_output_data_type(),
+            WrappedIOType::MiscData(Some(MiscDataDimensions::new(7, 24, 1).unwrap()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_microphone {
+    use super::*;
+
+    const MEL_BANDS: u32 = 32;
+    const INTENSITY_DEPTH: u32 = 8;
+
+    fn register_microphone(cache: &ConnectorCache, number_channels: u32) {
+        cache
+            .get_sensor_cache()
+            .microphone_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(number_channels).unwrap(),
+                FrameChangeHandling::Absolute,
+                AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap(),
+                WINDOW_SIZE,
+                MEL_BANDS,
+                NeuronDepth::new(INTENSITY_DEPTH).unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_default_topology_matches_registration_defaults() {
+        let topology = SensoryCorticalUnit::Microphone.get_unit_default_topology();
+        let area = topology.get(&0.into()).unwrap();
+        assert_eq!(
+            area.channel_dimensions_default,
+            [7, MEL_BANDS, INTENSITY_DEPTH]
+        );
+    }
+
+    #[test]
+    fn test_microphone_encodes_spectrogram_to_neurons() {
+        let cache = ConnectorCache::new();
+        register_microphone(&cache, 2);
+
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .microphone_write(0.into(), 1.into(), sine_wave(1000.0, 1.0).into())
+            .unwrap();
+        let mel: MiscData = sensors
+            .microphone_read_postprocessed_cache_value(0.into(), 1.into())
+            .unwrap();
+        assert_eq!(
+            mel.get_dimensions(),
+            MiscDataDimensions::new(7, MEL_BANDS, 1).unwrap()
+        );
+        let peak_band = loudest_row(&mel, 0) as u32;
+
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now() + Duration::from_millis(1))
+            .unwrap();
+        let cortical_id =
+            SensoryCorticalUnit::get_cortical_ids_array_for_microphone_with_parameters(
+                FrameChangeHandling::Absolute,
+                0.into(),
+            )[0];
+        let neurons = sensors.get_neurons().get_neurons_of(&cortical_id).unwrap();
+        let (x, y, z, p) = neurons.borrow_xyzp_vectors();
+
+        assert!(!x.is_empty());
+        // Only channel 1 was written, which sits after channel 0's 7 time windows
+        assert!(x.iter().all(|x| (7..14).contains(x)));
+        assert!(y.iter().all(|y| *y < MEL_BANDS));
+        assert!(z.iter().all(|z| *z < INTENSITY_DEPTH));
+        assert!(p.iter().all(|p| *p == 1.0));
+        // One neuron per time / band column
+        let mut columns: Vec<(u32, u32)> = x.iter().copied().zip(y.iter().copied()).collect();
+        columns.sort();
+        columns.dedup();
+        assert_eq!(columns.len(), x.len());
+        // The tone is the loudest band, so it fires closest to z = 0
+        let tone_z: Vec<u32> = (0..x.len())
+            .filter(|i| y[*i] == peak_band)
+            .map(|i| z[i])
+            .collect();
+        assert_eq!(tone_z.len(), 7);
+        assert!(tone_z
+            .iter()
+            .all(|tone| z.iter().all(|other| tone <= other)));
+    }
+
+    #[test]
+    fn test_microphone_installs_spectrogram_stages() {
+        let cache = ConnectorCache::new();
+        register_microphone(&cache, 1);
+        let stages = cache
+            .get_sensor_cache()
+            .microphone_get_all_stage_properties(0.into(), 0.into())
+            .unwrap();
+        assert_eq!(stages.len(), 2);
+        assert_eq!(stages[0].variant_name(), "AudioSpectrogram");
+        assert_eq!(stages[1].variant_name(), "AudioMelFilterbank");
+    }
+
+    #[test]
+    fn test_microphone_rejects_invalid_stage_properties() {
+        let cache = ConnectorCache::new();
+        register_microphone(&cache, 1);
+        let audio_properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        let spectrogram_dimensions = MiscDataDimensions::new(7, 65, 1).unwrap();
+        let mel = |min_frequency_hz: f32, max_frequency_hz: f32| {
+            PipelineStageProperties::new_audio_mel_filterbank(
+                spectrogram_dimensions,
+                SAMPLE_RATE_HZ,
+                MEL_BANDS,
+                min_frequency_hz,
+                max_frequency_hz,
+                60.0,
+            )
+        };
+        let invalid = [
+            vec![
+                PipelineStageProperties::new_audio_spectrogram(audio_properties, 0, 0),
+                mel(0.0, 8000.0),
+            ],
+            vec![
+                PipelineStageProperties::new_audio_spectrogram(
+                    audio_properties,
+                    WINDOW_SIZE,
+                    WINDOW_SIZE / 2,
+                ),
+                mel(500.0, 100.0),
+            ],
+        ];
+        let mut sensors = cache.get_sensor_cache();
+        for stages in invalid {
+            assert!(sensors
+                .microphone_replace_all_stages(0.into(), 0.into(), stages)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_microphone_rejects_wrong_audio_format() {
+        let cache = ConnectorCache::new();
+        register_microphone(&cache, 1);
+        let wrong =
+            AudioFrame::new(&AudioFrameProperties::new(SAMPLE_RATE_HZ, 256).unwrap()).unwrap();
+        assert!(cache
+            .get_sensor_cache()
+            .microphone_write(0.into(), 0.into(), wrong.into())
+            .is_err());
+    }
+
+    #[test]
+    fn test_export_import_roundtrip_with_microphone() {
+        let cache = ConnectorCache::new();
+        register_microphone(&cache, 1);
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+        imported
+            .get_sensor_cache()
+            .microphone_write(0.into(), 0.into(), sine_wave(500.0, 0.5).into())
+            .unwrap();
+    }
+}
+
+mod test_tone_synthesizer {
+    use super::*;
+
+    const BANDS: u32 = 25; // Two octaves of semitones
+
+    fn synthesizer() -> ToneSynthesizer {
+        ToneSynthesizer::new(
+            AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap(),
+            BANDS,
+            110.0,
+            440.0,
+        )
+        .unwrap()
+    }
+
+    fn band_amplitudes(amplitudes: &[(u32, f32)]) -> MiscData {
+        let mut data = MiscData::new(&MiscDataDimensions::new(1, BANDS, 1).unwrap()).unwrap();
+        for (band, amplitude) in amplitudes {
+            data.get_internal_data_mut()[(0, *band as usize, 0)] = *amplitude;
+        }
+        data
+    }
+
+    fn render(synthesizer: &mut ToneSynthesizer, amplitudes: &MiscData) -> AudioFrame {
+        let mut frame = AudioFrame::new(synthesizer.get_output_properties()).unwrap();
+        synthesizer.process(amplitudes, &mut frame).unwrap();
+        frame
+    }
+
+    fn expected_sine(frequency_hz: f32, sample_index: u32) -> f32 {
+        (2.0 * PI * frequency_hz * sample_index as f32 / SAMPLE_RATE_HZ as f32).sin()
+    }
+
+    #[test]
+    fn test_band_frequencies_are_log_spaced() {
+        let synthesizer = synthesizer();
+        assert!((synthesizer.get_band_frequency_hz(0) - 110.0).abs() < 0.01);
+        assert!((synthesizer.get_band_frequency_hz(12) - 220.0).abs() < 0.01);
+        assert!((synthesizer.get_band_frequency_hz(24) - 440.0).abs() < 0.01);
+        // A semitone apart
+        let ratio = synthesizer.get_band_frequency_hz(1) / synthesizer.get_band_frequency_hz(0);
+        assert!((ratio - 2f32.powf(1.0 / 12.0)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_single_band_renders_continuous_sine() {
+        let mut synthesizer = synthesizer();
+        let amplitudes = band_amplitudes(&[(12, 0.5)]);
+
+        // The first frame fades in from silence, the second holds steady with a continuous phase
+        let first = render(&mut synthesizer, &amplitudes);
+        for (i, sample) in first.get_samples().iter().enumerate() {
+            let ramp = (i + 1) as f32 / NUMBER_SAMPLES as f32;
+            let expected = 0.5 * ramp * expected_sine(220.0, i as u32);
+            assert!((sample - expected).abs() < 0.001, "sample {}", i);
+        }
+        let second = render(&mut synthesizer, &amplitudes);
+        for (i, sample) in second.get_samples().iter().enumerate() {
+            let expected = 0.5 * expected_sine(220.0, NUMBER_SAMPLES + i as u32);
+            assert!((sample - expected).abs() < 0.001, "sample {}", i);
+        }
+    }
+
+    #[test]
+    fn test_rendered_tone_peaks_at_band_frequency() {
+        let mut synthesizer = synthesizer();
+        let amplitudes = band_amplitudes(&[(24, 1.0)]);
+        render(&mut synthesizer, &amplitudes);
+        let steady = render(&mut synthesizer, &amplitudes);
+
+        let mut transform = ShortTimeFourierTransform::new(
+            steady.get_audio_frame_properties(),
+            NUMBER_SAMPLES,
+            NUMBER_SAMPLES,
+        )
+        .unwrap();
+        let mut spectrogram = MiscData::new(&transform.get_output_dimensions()).unwrap();
+        transform.process(&steady, &mut spectrogram).unwrap();
+        let peak_frequency = transform.get_bin_frequency_hz(loudest_row(&spectrogram, 0) as u32);
+        assert!((peak_frequency - 440.0).abs() <= SAMPLE_RATE_HZ as f32 / NUMBER_SAMPLES as f32);
+    }
+
+    #[test]
+    fn test_chord_is_scaled_to_full_scale() {
+        let mut synthesizer = synthesizer();
+        let amplitudes = band_amplitudes(&[(0, 1.0), (12, 1.0)]);
+        render(&mut synthesizer, &amplitudes);
+        let steady = render(&mut synthesizer, &amplitudes);
+        for (i, sample) in steady.get_samples().iter().enumerate() {
+            let sample_index = NUMBER_SAMPLES + i as u32;
+            let expected =
+                0.5 * (expected_sine(110.0, sample_index) + expected_sine(220.0, sample_index));
+            assert!((sample - expected).abs() < 0.001, "sample {}", i);
+            assert!(sample.abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_silence_and_fade_out() {
+        let mut synthesizer = synthesizer();
+        let silent = render(&mut synthesizer, &band_amplitudes(&[]));
+        assert!(silent.get_samples().iter().all(|sample| *sample == 0.0));
+
+        render(&mut synthesizer, &band_amplitudes(&[(5, 1.0)]));
+        let fading = render(&mut synthesizer, &band_amplitudes(&[]));
+        let samples = fading.get_samples();
+        assert_eq!(*samples.last().unwrap(), 0.0);
+        let first_quarter = samples[..128].iter().map(|s| s.abs()).fold(0.0, f32::max);
+        let last_quarter = samples[384..].iter().map(|s| s.abs()).fold(0.0, f32::max);
+        assert!(last_quarter < first_quarter);
+        let silent_again = render(&mut synthesizer, &band_amplitudes(&[]));
+        assert!(silent_again
+            .get_samples()
+            .iter()
+            .all(|sample| *sample == 0.0));
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        let properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        assert!(ToneSynthesizer::new(properties, 0, 110.0, 440.0).is_err());
+        assert!(ToneSynthesizer::new(properties, 8, 0.0, 440.0).is_err());
+        assert!(ToneSynthesizer::new(properties, 8, 440.0, 110.0).is_err());
+        assert!(ToneSynthesizer::new(properties, 8, 110.0, 8000.0).is_err()); // At nyquist
+        assert!(ToneSynthesizer::new(properties, 1, 440.0, 440.0).is_ok());
+
+        let mut synthesizer = synthesizer();
+        let mut wrong_frame =
+            AudioFrame::new(&AudioFrameProperties::new(SAMPLE_RATE_HZ, 256).unwrap()).unwrap();
+        assert!(synthesizer
+            .process(&band_amplitudes(&[]), &mut wrong_frame)
+            .is_err());
+    }
+}
+
+mod test_speaker {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    const TONE_BANDS: u32 = 25;
+    const AMPLITUDE_DEPTH: u32 = 10;
+
+    fn register_speaker(cache: &ConnectorCache, number_channels: u32) {
+        cache
+            .get_motor_cache()
+            .speaker_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(number_channels).unwrap(),
+                FrameChangeHandling::Absolute,
+                AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap(),
+                TONE_BANDS,
+                NeuronDepth::new(AMPLITUDE_DEPTH).unwrap(),
+                110.0,
+                440.0,
+            )
+            .unwrap();
+    }
+
+    fn fire(cache: &ConnectorCache, neurons: &[(u32, u32, u32)]) {
+        let cortical_id = MotorCorticalUnit::get_cortical_ids_array_for_speaker_with_parameters(
+            FrameChangeHandling::Absolute,
+            0.into(),
+        )[0];
+        let mut arrays = NeuronVoxelXYZPArrays::new();
+        for (x, y, z) in neurons {
+            arrays.push_raw(*x, *y, *z, 1.0);
+        }
+        let mut neuron_data = CorticalMappedXYZPNeuronVoxels::new();
+        neuron_data.insert(cortical_id, arrays);
+        cache
+            .get_motor_cache()
+            .ingest_neuron_data_and_run_callbacks(neuron_data, Instant::now())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_default_topology_is_one_tone_column() {
+        let topology = MotorCorticalUnit::Speaker.get_unit_default_topology();
+        let area = topology.get(&0.into()).unwrap();
+        assert_eq!(area.channel_dimensions_default, [1, 24, AMPLITUDE_DEPTH]);
+    }
+
+    #[test]
+    fn test_speaker_decodes_band_amplitudes() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 2);
+
+        // Channel 0 band 3 at full loudness, band 7 at half loudness. Channel 1 band 10 fires at
+        // two loudness levels, which average out
+        fire(&cache, &[(0, 3, 0), (0, 7, 5), (1, 10, 2), (1, 10, 4)]);
+
+        let motors = cache.get_motor_cache();
+        let channel_0: MiscData = motors
+            .speaker_read_preprocessed_cache_value(0.into(), 0.into())
+            .unwrap();
+        let channel_1: MiscData = motors
+            .speaker_read_preprocessed_cache_value(0.into(), 1.into())
+            .unwrap();
+        assert_eq!(
+            channel_0.get_dimensions(),
+            MiscDataDimensions::new(1, TONE_BANDS, 1).unwrap()
+        );
+        for band in 0..TONE_BANDS as usize {
+            let expected_0 = match band {
+                3 => 1.0,
+                7 => 0.5,
+                _ => 0.0,
+            };
+            let expected_1 = if band == 10 { 0.7 } else { 0.0 };
+            assert!((channel_0.get_internal_data()[(0, band, 0)] - expected_0).abs() < 0.0001);
+            assert!((channel_1.get_internal_data()[(0, band, 0)] - expected_1).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_speaker_synthesizes_decoded_tone() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 1);
+
+        // Band 12 is 220 Hz, z = 5 of 10 is half loudness
+        fire(&cache, &[(0, 12, 5)]);
+        fire(&cache, &[(0, 12, 5)]);
+
+        let audio: AudioFrame = cache
+            .get_motor_cache()
+            .speaker_read_postprocessed_cache_value(0.into(), 0.into())
+            .unwrap();
+        assert_eq!(audio.get_sample_rate_hz(), SAMPLE_RATE_HZ);
+        assert_eq!(audio.get_samples().len(), NUMBER_SAMPLES as usize);
+        for (i, sample) in audio.get_samples().iter().enumerate() {
+            let sample_index = NUMBER_SAMPLES + i as u32;
+            let expected =
+                0.5 * (2.0 * PI * 220.0 * sample_index as f32 / SAMPLE_RATE_HZ as f32).sin();
+            assert!((sample - expected).abs() < 0.001, "sample {}", i);
+        }
+    }
+
+    #[test]
+    fn test_speaker_callback_receives_audio() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 1);
+        let received: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+        let received_clone = received.clone();
+        cache
+            .get_motor_cache()
+            .speaker_try_register_motor_callback(0.into(), 0.into(), move |data| {
+                let audio: &AudioFrame = data.try_into().unwrap();
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push(audio.get_samples().len());
+            })
+            .unwrap();
+
+        fire(&cache, &[(0, 0, 0)]);
+        assert_eq!(*received.lock().unwrap(), vec![NUMBER_SAMPLES as usize]);
+    }
+
+    #[test]
+    fn test_speaker_installs_synthesizer_stage() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 1);
+        let stages = cache
+            .get_motor_cache()
+            .speaker_get_all_stage_properties(0.into(), 0.into())
+            .unwrap();
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0].variant_name(), "AudioToneSynthesizer");
+        assert_eq!(
+            stages[0].get_output_data_type(),
+            WrappedIOType::AudioFrame(Some(
+                AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_speaker_rejects_invalid_frequency_range() {
+        let cache = ConnectorCache::new();
+        let result = cache.get_motor_cache().speaker_register(
+            CorticalUnitIndex::from(0u8),
+            CorticalChannelCount::new(1).unwrap(),
+            FrameChangeHandling::Absolute,
+            AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap(),
+            TONE_BANDS,
+            NeuronDepth::new(AMPLITUDE_DEPTH).unwrap(),
+            110.0,
+            SAMPLE_RATE_HZ as f32,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_speaker_rejects_invalid_stage_properties() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 1);
+        let audio_properties = AudioFrameProperties::new(SAMPLE_RATE_HZ, NUMBER_SAMPLES).unwrap();
+        let invalid = [
+            PipelineStageProperties::new_audio_tone_synthesizer(
+                audio_properties,
+                TONE_BANDS,
+                440.0,
+                110.0,
+            ),
+            PipelineStageProperties::new_audio_tone_synthesizer(audio_properties, 0, 110.0, 440.0),
+        ];
+        let mut motors = cache.get_motor_cache();
+        for invalid_properties in invalid {
+            assert!(motors
+                .speaker_replace_all_stages(0.into(), 0.into(), vec![invalid_properties])
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_export_import_roundtrip_with_speaker() {
+        let cache = ConnectorCache::new();
+        register_speaker(&cache, 1);
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+        fire(&imported, &[(0, 12, 0)]);
+        let audio: AudioFrame = imported
+            .get_motor_cache()
+            .speaker_read_postprocessed_cache_value(0.into(), 0.into())
+            .unwrap();
+        assert!(audio.get_samples().iter().any(|sample| *sample != 0.0));
+    }
+}
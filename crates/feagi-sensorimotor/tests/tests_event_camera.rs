@@ -0,0 +1,302 @@
+//! Tests for event camera (DVS) input
+//!
+//! Tests cover:
+//! - EventStream construction and splitting into time windows
+//! - Reading AEDAT 2.0 and EVT 2.0 RAW event data
+//! - Event camera registration and binning of events to neurons
+//! - Export and import of event camera registrations as JSON
+
+use feagi_sensorimotor::data_types::descriptors::ImageXYResolution;
+use feagi_sensorimotor::data_types::{Aedat2AddressFormat, DvsEvent, EventPolarity, EventStream};
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::FrameChangeHandling;
+use feagi_structures::genomic::SensoryCorticalUnit;
+use std::time::{Duration, Instant};
+
+fn resolution() -> ImageXYResolution {
+    ImageXYResolution::new(8, 4).unwrap()
+}
+
+fn event_stream(events: &[(u32, u32, EventPolarity, u64)]) -> EventStream {
+    EventStream::new_from_events(
+        resolution(),
+        events
+            .iter()
+            .map(|(x, y, polarity, timestamp_us)| DvsEvent::new(*x, *y, *polarity, *timestamp_us))
+            .collect(),
+    )
+    .unwrap()
+}
+
+mod test_event_stream {
+    use super::*;
+
+    #[test]
+    fn test_rejects_events_outside_resolution() {
+        assert!(EventStream::new_from_events(
+            resolution(),
+            vec![DvsEvent::new(8, 0, EventPolarity::On, 0)]
+        )
+        .is_err());
+        let mut events = EventStream::new(resolution());
+        assert!(events
+            .push_event(DvsEvent::new(0, 4, EventPolarity::Off, 0))
+            .is_err());
+        assert!(events
+            .push_event(DvsEvent::new(7, 3, EventPolarity::Off, 0))
+            .is_ok());
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn test_split_by_time_window() {
+        let events = event_stream(&[
+            (0, 0, EventPolarity::On, 1000),
+            (1, 0, EventPolarity::On, 1500),
+            (2, 0, EventPolarity::Off, 3200),
+        ]);
+        let windows = events.split_by_time_window(1000).unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].len(), 2);
+        assert!(windows[1].is_empty()); // Gaps are kept
+        assert_eq!(windows[2].get_events()[0].x, 2);
+        assert!(events.split_by_time_window(0).is_err());
+        assert!(EventStream::new(resolution())
+            .split_by_time_window(1000)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_read_aedat2_dvs128() {
+        let resolution = ImageXYResolution::new(128, 128).unwrap();
+        let mut bytes: Vec<u8> = b"#!AER-DAT2.0\r\n# This is a raw AE data file\r\n".to_vec();
+        for (x, y, off, timestamp) in [(5u32, 9u32, 0u32, 100u32), (127, 0, 1, 250)] {
+            let address: u32 = (y << 8) | (x << 1) | off;
+            bytes.extend_from_slice(&address.to_be_bytes());
+            bytes.extend_from_slice(&timestamp.to_be_bytes());
+        }
+
+        let events =
+            EventStream::new_from_aedat2_bytes(&bytes, resolution, Aedat2AddressFormat::Dvs128)
+                .unwrap();
+        assert_eq!(
+            events.get_events(),
+            &[
+                DvsEvent::new(5, 9, EventPolarity::On, 100),
+                DvsEvent::new(127, 0, EventPolarity::Off, 250),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_aedat2_davis_skips_frame_samples() {
+        let resolution = ImageXYResolution::new(346, 260).unwrap();
+        let mut bytes: Vec<u8> = b"#!AER-DAT2.0\r\n".to_vec();
+        let dvs_address: u32 = (259 << 22) | (345 << 12) | (1 << 11);
+        let aps_address: u32 = 1 << 31;
+        for (address, timestamp) in [(dvs_address, 10u32), (aps_address, 20)] {
+            bytes.extend_from_slice(&address.to_be_bytes());
+            bytes.extend_from_slice(&timestamp.to_be_bytes());
+        }
+
+        let events =
+            EventStream::new_from_aedat2_bytes(&bytes, resolution, Aedat2AddressFormat::Davis)
+                .unwrap();
+        assert_eq!(
+            events.get_events(),
+            &[DvsEvent::new(345, 259, EventPolarity::On, 10)]
+        );
+    }
+
+    #[test]
+    fn test_read_aedat2_rejects_truncated_data() {
+        let mut bytes: Vec<u8> = b"#!AER-DAT2.0\r\n".to_vec();
+        bytes.extend_from_slice(&[0, 0, 0, 2, 0, 0]);
+        assert!(EventStream::new_from_aedat2_bytes(
+            &bytes,
+            ImageXYResolution::new(128, 128).unwrap(),
+            Aedat2AddressFormat::Dvs128
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_read_evt2_raw() {
+        let resolution = ImageXYResolution::new(640, 480).unwrap();
+        let mut bytes: Vec<u8> = b"% evt 2.0\n% geometry 640x480\n% end\n".to_vec();
+        let words: [u32; 5] = [
+            (0x8 << 28) | 3,                           // Time high
+            (0x1 << 28) | (5 << 22) | (639 << 11) | 2, // ON
+            (0xA << 28) | 7,                           // External trigger, skipped
+            (63 << 22) | (10 << 11) | 479,             // OFF (type 0x0)
+            (0x8 << 28) | 4,
+        ];
+        for word in words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+
+        let events = EventStream::new_from_evt2_raw_bytes(&bytes, resolution).unwrap();
+        assert_eq!(
+            events.get_events(),
+            &[
+                DvsEvent::new(639, 2, EventPolarity::On, (3 << 6) | 5),
+                DvsEvent::new(10, 479, EventPolarity::Off, (3 << 6) | 63),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_evt2_raw_header_end_protects_binary_data() {
+        // The first event word starts with the header marker byte ('%' is 0x25)
+        let mut bytes: Vec<u8> = b"% end\n".to_vec();
+        let word: u32 = (0x1 << 28) | (1 << 11) | 0x25;
+        bytes.extend_from_slice(&word.to_le_bytes());
+        let events =
+            EventStream::new_from_evt2_raw_bytes(&bytes, ImageXYResolution::new(64, 64).unwrap())
+                .unwrap();
+        assert_eq!(
+            events.get_events(),
+            &[DvsEvent::new(1, 0x25, EventPolarity::On, 0)]
+        );
+    }
+}
+
+mod test_event_camera {
+    use super::*;
+
+    fn register_event_camera(cache: &ConnectorCache, number_channels: u32) {
+        cache
+            .get_sensor_cache()
+            .event_camera_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(number_channels).unwrap(),
+                FrameChangeHandling::Absolute,
+                resolution(),
+            )
+            .unwrap();
+    }
+
+    fn encoded_neurons(cache: &ConnectorCache, time: Instant) -> Vec<(u32, u32, u32, f32)> {
+        let mut sensors = cache.get_sensor_cache();
+        sensors.encode_all_sensors_to_neurons(time).unwrap();
+        let cortical_id =
+            SensoryCorticalUnit::get_cortical_ids_array_for_event_camera_with_parameters(
+                FrameChangeHandling::Absolute,
+                0.into(),
+            )[0];
+        let neurons = match sensors.get_neurons().get_neurons_of(&cortical_id) {
+            Some(neurons) => neurons,
+            None => return Vec::new(),
+        };
+        let mut voxels: Vec<(u32, u32, u32, f32)> = neurons
+            .iter()
+            .map(|neuron| {
+                (
+                    neuron.neuron_voxel_coordinate.x,
+                    neuron.neuron_voxel_coordinate.y,
+                    neuron.neuron_voxel_coordinate.z,
+                    neuron.potential,
+                )
+            })
+            .collect();
+        voxels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        voxels
+    }
+
+    #[test]
+    fn test_default_topology_has_polarity_layers() {
+        let topology = SensoryCorticalUnit::EventCamera.get_unit_default_topology();
+        let area = topology.get(&0.into()).unwrap();
+        assert_eq!(area.channel_dimensions_default[2], 2);
+    }
+
+    #[test]
+    fn test_events_are_binned_into_neurons() {
+        let cache = ConnectorCache::new();
+        register_event_camera(&cache, 2);
+        cache
+            .get_sensor_cache()
+            .event_camera_write(
+                0.into(),
+                1.into(),
+                event_stream(&[
+                    (2, 0, EventPolarity::On, 10),
+                    (2, 0, EventPolarity::On, 20),
+                    (2, 0, EventPolarity::Off, 30),
+                    (7, 3, EventPolarity::Off, 40),
+                ])
+                .into(),
+            )
+            .unwrap();
+
+        let voxels = encoded_neurons(&cache, Instant::now() + Duration::from_millis(1));
+        // Channel 1 sits after channel 0's 8 columns, and y is flipped so row 0 is at the top
+        assert_eq!(
+            voxels,
+            vec![(10, 3, 0, 2.0), (10, 3, 1, 1.0), (15, 0, 1, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_events_only_fire_once() {
+        let cache = ConnectorCache::new();
+        register_event_camera(&cache, 1);
+        cache
+            .get_sensor_cache()
+            .event_camera_write(
+                0.into(),
+                0.into(),
+                event_stream(&[(1, 1, EventPolarity::On, 0)]).into(),
+            )
+            .unwrap();
+
+        let first_burst = Instant::now() + Duration::from_millis(1);
+        assert_eq!(encoded_neurons(&cache, first_burst).len(), 1);
+        assert!(encoded_neurons(&cache, first_burst + Duration::from_millis(1)).is_empty());
+    }
+
+    #[test]
+    fn test_event_camera_rejects_wrong_resolution() {
+        let cache = ConnectorCache::new();
+        register_event_camera(&cache, 1);
+        let wrong = EventStream::new(ImageXYResolution::new(16, 16).unwrap());
+        assert!(cache
+            .get_sensor_cache()
+            .event_camera_write(0.into(), 0.into(), wrong.into())
+            .is_err());
+    }
+
+    #[test]
+    fn test_export_import_roundtrip_with_event_camera() {
+        let cache = ConnectorCache::new();
+        register_event_camera(&cache, 1);
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+        imported
+            .get_sensor_cache()
+            .event_camera_write(
+                0.into(),
+                0.into(),
+                event_stream(&[(0, 0, EventPolarity::Off, 0)]).into(),
+            )
+            .unwrap();
+        assert_eq!(
+            encoded_neurons(&imported, Instant::now() + Duration::from_millis(1)),
+            vec![(0, 3, 1, 1.0)]
+        );
+    }
+}
@@ -0,0 +1,610 @@
+//! Tests for the image feature map pipeline stages
+//!
+//! Tests cover:
+//! - Sobel and Canny edge maps
+//! - Frame difference motion, in any and in single directions
+//! - HSV color range blob masks
+//! - Stage properties validation
+//! - Encoding feature maps to neurons, and segmenting them
+//! - Export and import of feature stages as JSON
+
+use feagi_sensorimotor::data_pipeline::stages::ImageColorBlobMaskStage;
+use feagi_sensorimotor::data_pipeline::{PipelineStageProperties, PipelineStagePropertyIndex};
+use feagi_sensorimotor::data_types::descriptors::{
+    ColorChannelLayout, ColorSpace, ImageFrameProperties, ImageXYResolution,
+    SegmentedImageFrameProperties, SegmentedXYImageResolutions,
+};
+use feagi_sensorimotor::data_types::processing::{EdgeDetectionMethod, MotionDirection};
+use feagi_sensorimotor::data_types::{GazeProperties, ImageFrame, Percentage, SegmentedImageFrame};
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::FrameChangeHandling;
+use feagi_structures::genomic::SensoryCorticalUnit;
+use ndarray::Array2;
+use std::time::Instant;
+
+const SIZE: u32 = 16;
+
+fn image_properties(color_channel_layout: ColorChannelLayout) -> ImageFrameProperties {
+    ImageFrameProperties::new(
+        ImageXYResolution::new(SIZE, SIZE).unwrap(),
+        ColorSpace::Gamma,
+        color_channel_layout,
+    )
+    .unwrap()
+}
+
+/// Creates an RGB image colored by the given function of (x, y)
+fn rgb_image(color_at: impl Fn(usize, usize) -> [u8; 3]) -> ImageFrame {
+    let mut image =
+        ImageFrame::new_from_image_frame_properties(&image_properties(ColorChannelLayout::RGB))
+            .unwrap();
+    let pixels = image.get_internal_data_mut();
+    for y in 0..SIZE as usize {
+        for x in 0..SIZE as usize {
+            for (channel, value) in color_at(x, y).into_iter().enumerate() {
+                pixels[(y, x, channel)] = value;
+            }
+        }
+    }
+    image
+}
+
+fn gray_image(brightness_at: impl Fn(usize, usize) -> u8) -> ImageFrame {
+    rgb_image(|x, y| {
+        let brightness = brightness_at(x, y);
+        [brightness; 3]
+    })
+}
+
+fn percentage(value: f32) -> Percentage {
+    Percentage::new_from_0_1(value).unwrap()
+}
+
+/// Registers a vision sensor outputting grayscale feature maps through the given stages
+fn register_vision(cache: &ConnectorCache, stages: Vec<PipelineStageProperties>) {
+    let mut sensors = cache.get_sensor_cache();
+    sensors
+        .vision_register(
+            CorticalUnitIndex::from(0u8),
+            CorticalChannelCount::new(1).unwrap(),
+            FrameChangeHandling::Absolute,
+            image_properties(ColorChannelLayout::GrayScale),
+        )
+        .unwrap();
+    sensors
+        .vision_replace_all_stages(0.into(), 0.into(), stages)
+        .unwrap();
+}
+
+/// Writes an image and returns the feature map, indexed by (y, x)
+fn feature_map(cache: &ConnectorCache, image: ImageFrame) -> Array2<u8> {
+    let mut sensors = cache.get_sensor_cache();
+    sensors
+        .vision_write(0.into(), 0.into(), image.into())
+        .unwrap();
+    let output: ImageFrame = sensors
+        .vision_read_postprocessed_cache_value(0.into(), 0.into())
+        .unwrap();
+    assert_eq!(
+        output.get_image_frame_properties(),
+        image_properties(ColorChannelLayout::GrayScale)
+    );
+    output
+        .get_internal_data()
+        .index_axis(ndarray::Axis(2), 0)
+        .to_owned()
+}
+
+/// Returns the (x, y) of every nonzero pixel
+fn active_pixels(map: &Array2<u8>) -> Vec<(usize, usize)> {
+    map.indexed_iter()
+        .filter(|(_, value)| **value != 0)
+        .map(|((y, x), _)| (x, y))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_edge_detection {
+    use super::*;
+
+    fn half_bright() -> ImageFrame {
+        gray_image(|x, _| if x >= 8 { 255 } else { 0 })
+    }
+
+    #[test]
+    fn test_sobel_edges() {
+        let cache = ConnectorCache::new();
+        register_vision(
+            &cache,
+            vec![PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Sobel { threshold: 10 },
+            )],
+        );
+        let edges = feature_map(&cache, half_bright());
+        // The two columns on either side of a sharp black to white edge are at full strength
+        for ((_, x), value) in edges.indexed_iter() {
+            let expected = if x == 7 || x == 8 { 255 } else { 0 };
+            assert_eq!(*value, expected);
+        }
+
+        // Flat images have no edges
+        let edges = feature_map(&cache, gray_image(|_, _| 128));
+        assert!(active_pixels(&edges).is_empty());
+    }
+
+    #[test]
+    fn test_canny_edges_are_thin() {
+        let cache = ConnectorCache::new();
+        register_vision(
+            &cache,
+            vec![PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Canny {
+                    low_threshold: 50,
+                    high_threshold: 100,
+                },
+            )],
+        );
+        let edges = feature_map(&cache, half_bright());
+        let expected: Vec<(usize, usize)> = (0..SIZE as usize).map(|y| (7, y)).collect();
+        let mut active = active_pixels(&edges);
+        active.sort_by_key(|(x, y)| (*x, *y));
+        assert_eq!(active, expected);
+        assert!(edges.iter().all(|value| *value == 0 || *value == 255));
+    }
+
+    #[test]
+    fn test_canny_hysteresis_keeps_weak_edges_connected_to_strong_ones() {
+        let cache = ConnectorCache::new();
+        register_vision(
+            &cache,
+            vec![PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Canny {
+                    low_threshold: 30,
+                    high_threshold: 100,
+                },
+            )],
+        );
+        // The edge fades from strong at the top to weak at the bottom, and is kept whole. A
+        // separate weak line is dropped
+        let edges = feature_map(
+            &cache,
+            gray_image(|x, y| match x {
+                8.. => 255 - 13 * y as u8,
+                2 => 60,
+                _ => 0,
+            }),
+        );
+        for row in edges.rows() {
+            let active: Vec<usize> = (0..SIZE as usize).filter(|x| row[*x] != 0).collect();
+            assert!(active == vec![7] || active == vec![8], "{:?}", edges);
+        }
+    }
+
+    #[test]
+    fn test_canny_thresholds_are_validated() {
+        let cache = ConnectorCache::new();
+        register_vision(
+            &cache,
+            vec![PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Sobel { threshold: 0 },
+            )],
+        );
+        let mut sensors = cache.get_sensor_cache();
+        assert!(sensors
+            .vision_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                PipelineStageProperties::new_image_edge_detector(
+                    image_properties(ColorChannelLayout::RGB),
+                    EdgeDetectionMethod::Canny {
+                        low_threshold: 100,
+                        high_threshold: 50,
+                    },
+                ),
+            )
+            .is_err());
+        // Replacing the stages with invalid properties returns an error instead of panicking
+        assert!(sensors
+            .vision_replace_all_stages(
+                0.into(),
+                0.into(),
+                vec![PipelineStageProperties::new_image_edge_detector(
+                    image_properties(ColorChannelLayout::RGB),
+                    EdgeDetectionMethod::Canny {
+                        low_threshold: 100,
+                        high_threshold: 50,
+                    },
+                )],
+            )
+            .is_err());
+        // Changing the input image in place is rejected too
+        assert!(sensors
+            .vision_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                PipelineStageProperties::new_image_edge_detector(
+                    image_properties(ColorChannelLayout::RGBA),
+                    EdgeDetectionMethod::Sobel { threshold: 0 },
+                ),
+            )
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_motion_detection {
+    use super::*;
+
+    fn motion_stage(direction: MotionDirection) -> PipelineStageProperties {
+        PipelineStageProperties::new_image_motion_detector(
+            image_properties(ColorChannelLayout::RGB),
+            20,
+            direction,
+        )
+    }
+
+    /// A bright square on a dark background, with its left side at the given column
+    fn square_at(left: usize) -> ImageFrame {
+        gray_image(|x, y| {
+            if (left..left + 4).contains(&x) && (4..8).contains(&y) {
+                255
+            } else {
+                0
+            }
+        })
+    }
+
+    #[test]
+    fn test_first_frame_has_no_motion() {
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![motion_stage(MotionDirection::Any)]);
+        assert!(active_pixels(&feature_map(&cache, square_at(4))).is_empty());
+    }
+
+    #[test]
+    fn test_any_direction_is_frame_difference() {
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![motion_stage(MotionDirection::Any)]);
+        feature_map(&cache, square_at(4));
+        let motion = feature_map(&cache, square_at(6));
+
+        // The trailing and leading columns changed fully
+        let mut active = active_pixels(&motion);
+        active.sort();
+        let expected: Vec<(usize, usize)> = [4, 5, 8, 9]
+            .into_iter()
+            .flat_map(|x| (4..8).map(move |y| (x, y)))
+            .collect();
+        assert_eq!(active, expected);
+        assert!(motion.iter().all(|value| *value == 0 || *value == 255));
+
+        // Changes below the threshold are ignored
+        let motion = feature_map(
+            &cache,
+            gray_image(|x, y| square_at(6).get_internal_data()[(y, x, 0)].saturating_sub(10)),
+        );
+        assert!(active_pixels(&motion).is_empty());
+    }
+
+    #[test]
+    fn test_direction_of_motion() {
+        for (direction, expects_motion) in [
+            (MotionDirection::Right, true),
+            (MotionDirection::Left, false),
+        ] {
+            let cache = ConnectorCache::new();
+            register_vision(&cache, vec![motion_stage(direction)]);
+            feature_map(&cache, square_at(4));
+            let motion = feature_map(&cache, square_at(6));
+            if expects_motion {
+                // Both the leading and trailing edges moved right
+                for x in [4, 5, 8, 9] {
+                    assert!(motion[(5, x)] > 128, "{:?} at x {}", motion, x);
+                }
+            } else {
+                assert!(active_pixels(&motion).is_empty(), "{:?}", motion);
+            }
+        }
+
+        // Moving back left flips it
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![motion_stage(MotionDirection::Left)]);
+        feature_map(&cache, square_at(6));
+        let motion = feature_map(&cache, square_at(4));
+        assert!(motion[(5, 4)] > 128 && motion[(5, 9)] > 128);
+    }
+
+    #[test]
+    fn test_flat_changes_have_no_direction() {
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![motion_stage(MotionDirection::Right)]);
+        feature_map(&cache, gray_image(|_, _| 50));
+        assert!(active_pixels(&feature_map(&cache, gray_image(|_, _| 150))).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod test_color_blob_mask {
+    use super::*;
+
+    /// A 4x4 red blob, a single red pixel and a 4x4 green blob on black
+    fn colored_scene() -> ImageFrame {
+        rgb_image(|x, y| match (x, y) {
+            (2..6, 2..6) => [255, 0, 0],
+            (12, 12) => [230, 20, 40],
+            (10..14, 2..6) => [0, 200, 0],
+            _ => [0, 0, 0],
+        })
+    }
+
+    fn blob_mask_stage(
+        hue_range: std::ops::RangeInclusive<f32>,
+        minimum_blob_area: u32,
+    ) -> PipelineStageProperties {
+        PipelineStageProperties::new_image_color_blob_mask(
+            image_properties(ColorChannelLayout::RGB),
+            hue_range,
+            percentage(0.5)..=percentage(1.0),
+            percentage(0.5)..=percentage(1.0),
+            minimum_blob_area,
+        )
+    }
+
+    #[test]
+    fn test_red_mask_wraps_hue_and_drops_small_blobs() {
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![blob_mask_stage(330.0..=30.0, 4)]);
+        let mask = feature_map(&cache, colored_scene());
+        let mut active = active_pixels(&mask);
+        active.sort();
+        let expected: Vec<(usize, usize)> =
+            (2..6).flat_map(|x| (2..6).map(move |y| (x, y))).collect();
+        assert_eq!(active, expected);
+        assert!(mask.iter().all(|value| *value == 0 || *value == 255));
+
+        // Without a minimum area, the single pixel is kept
+        cache
+            .get_sensor_cache()
+            .vision_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                blob_mask_stage(330.0..=30.0, 0),
+            )
+            .unwrap();
+        let mask = feature_map(&cache, colored_scene());
+        assert_eq!(active_pixels(&mask).len(), 17);
+        assert_eq!(mask[(12, 12)], 255);
+    }
+
+    #[test]
+    fn test_green_mask() {
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![blob_mask_stage(90.0..=150.0, 4)]);
+        let mask = feature_map(&cache, colored_scene());
+        let mut active = active_pixels(&mask);
+        active.sort();
+        let expected: Vec<(usize, usize)> =
+            (10..14).flat_map(|x| (2..6).map(move |y| (x, y))).collect();
+        assert_eq!(active, expected);
+    }
+
+    #[test]
+    fn test_invalid_color_blob_masks_are_rejected() {
+        let full = percentage(0.0)..=percentage(1.0);
+        // Grayscale images have no hue
+        assert!(ImageColorBlobMaskStage::new(
+            image_properties(ColorChannelLayout::GrayScale),
+            0.0..=30.0,
+            full.clone(),
+            full.clone(),
+            0,
+        )
+        .is_err());
+        assert!(ImageColorBlobMaskStage::new(
+            image_properties(ColorChannelLayout::RGB),
+            0.0..=400.0,
+            full.clone(),
+            full.clone(),
+            0,
+        )
+        .is_err());
+        assert!(ImageColorBlobMaskStage::new(
+            image_properties(ColorChannelLayout::RGB),
+            0.0..=30.0,
+            percentage(0.8)..=percentage(0.2),
+            full.clone(),
+            0,
+        )
+        .is_err());
+        assert!(ImageColorBlobMaskStage::new(
+            image_properties(ColorChannelLayout::RGBA),
+            0.0..=30.0,
+            full.clone(),
+            full,
+            0,
+        )
+        .is_ok());
+
+        // Registering invalid properties returns an error instead of panicking
+        let cache = ConnectorCache::new();
+        register_vision(&cache, vec![blob_mask_stage(0.0..=30.0, 0)]);
+        assert!(cache
+            .get_sensor_cache()
+            .vision_replace_all_stages(0.into(), 0.into(), vec![blob_mask_stage(0.0..=400.0, 0)])
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_feature_map_composition {
+    use super::*;
+
+    #[test]
+    fn test_feature_map_encodes_to_neurons() {
+        let cache = ConnectorCache::new();
+        register_vision(
+            &cache,
+            vec![PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Canny {
+                    low_threshold: 50,
+                    high_threshold: 100,
+                },
+            )],
+        );
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .vision_write(
+                0.into(),
+                0.into(),
+                gray_image(|x, _| if x >= 8 { 255 } else { 0 }).into(),
+            )
+            .unwrap();
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now())
+            .unwrap();
+        let cortical_id = SensoryCorticalUnit::get_cortical_ids_array_for_vision_with_parameters(
+            FrameChangeHandling::Absolute,
+            0.into(),
+        )[0];
+        let neurons = sensors.get_neurons().get_neurons_of(&cortical_id).unwrap();
+        assert_eq!(neurons.len(), SIZE as usize);
+        assert!(neurons
+            .iter()
+            .all(|neuron| neuron.neuron_voxel_coordinate.x == 7
+                && neuron.neuron_voxel_coordinate.z == 0));
+    }
+
+    #[test]
+    fn test_feature_map_can_be_segmented() {
+        let feature_properties = image_properties(ColorChannelLayout::GrayScale);
+        let segmented_properties = SegmentedImageFrameProperties::new(
+            SegmentedXYImageResolutions::create_with_same_sized_peripheral(
+                (8, 8).try_into().unwrap(),
+                (4, 4).try_into().unwrap(),
+            ),
+            ColorChannelLayout::GrayScale,
+            ColorChannelLayout::GrayScale,
+            ColorSpace::Gamma,
+        );
+        let gaze = GazeProperties::create_default_centered();
+        let cache = ConnectorCache::new();
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .segmented_vision_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(1).unwrap(),
+                FrameChangeHandling::Absolute,
+                feature_properties,
+                segmented_properties,
+                gaze,
+            )
+            .unwrap();
+        sensors
+            .segmented_vision_replace_all_stages(
+                0.into(),
+                0.into(),
+                vec![
+                    PipelineStageProperties::new_image_color_blob_mask(
+                        image_properties(ColorChannelLayout::RGB),
+                        330.0..=30.0,
+                        percentage(0.5)..=percentage(1.0),
+                        percentage(0.5)..=percentage(1.0),
+                        0,
+                    ),
+                    PipelineStageProperties::new_image_frame_segmentator(
+                        feature_properties,
+                        segmented_properties,
+                        gaze,
+                    ),
+                ],
+            )
+            .unwrap();
+
+        // A red dot in the middle shows up in the center segment only
+        sensors
+            .segmented_vision_write(
+                0.into(),
+                0.into(),
+                rgb_image(|x, y| {
+                    if (6..10).contains(&x) && (6..10).contains(&y) {
+                        [255, 0, 0]
+                    } else {
+                        [0, 0, 255]
+                    }
+                })
+                .into(),
+            )
+            .unwrap();
+        let segmented: SegmentedImageFrame = sensors
+            .segmented_vision_read_postprocessed_cache_value(0.into(), 0.into())
+            .unwrap();
+        let segments = segmented.get_ordered_image_frame_references();
+        for (index, segment) in segments.iter().enumerate() {
+            let is_active = segment.get_internal_data().iter().any(|value| *value != 0);
+            assert_eq!(is_active, index == 4, "segment {}", index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_feature_stage_serialization {
+    use super::*;
+
+    #[test]
+    fn test_export_import_roundtrip_with_feature_stages() {
+        for stage in [
+            PipelineStageProperties::new_image_edge_detector(
+                image_properties(ColorChannelLayout::RGB),
+                EdgeDetectionMethod::Canny {
+                    low_threshold: 30,
+                    high_threshold: 90,
+                },
+            ),
+            PipelineStageProperties::new_image_motion_detector(
+                image_properties(ColorChannelLayout::RGB),
+                15,
+                MotionDirection::Up,
+            ),
+            PipelineStageProperties::new_image_color_blob_mask(
+                image_properties(ColorChannelLayout::RGB),
+                200.0..=260.0,
+                percentage(0.3)..=percentage(1.0),
+                percentage(0.2)..=percentage(0.9),
+                6,
+            ),
+        ] {
+            let cache = ConnectorCache::new();
+            register_vision(&cache, vec![stage.clone()]);
+            let json = cache.export_device_registrations_as_config_json().unwrap();
+
+            let mut imported = ConnectorCache::new();
+            imported
+                .import_device_registrations_as_config_json(json.clone())
+                .unwrap();
+            assert_eq!(
+                imported
+                    .get_sensor_cache()
+                    .vision_get_all_stage_properties(0.into(), 0.into())
+                    .unwrap(),
+                vec![stage]
+            );
+            assert_eq!(
+                imported
+                    .export_device_registrations_as_config_json()
+                    .unwrap(),
+                json
+            );
+        }
+    }
+}
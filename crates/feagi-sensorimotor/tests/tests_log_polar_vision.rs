@@ -0,0 +1,518 @@
+//! Tests for log-polar foveated vision
+//!
+//! Tests cover:
+//! - Placement of the fovea, rings and wedges in the log-polar image
+//! - Logarithmic ring spacing and coverage of the field of view
+//! - Moving the fovea with gaze, directly and through the gaze motor feedback
+//! - Stage properties validation
+//! - Encoding log-polar images to neurons
+//! - Export and import of log-polar vision as JSON
+
+use feagi_sensorimotor::data_pipeline::stages::ImageLogPolarTransformerStage;
+use feagi_sensorimotor::data_pipeline::{PipelineStageProperties, PipelineStagePropertyIndex};
+use feagi_sensorimotor::data_types::descriptors::{
+    ColorChannelLayout, ColorSpace, ImageFrameProperties, ImageXYResolution,
+};
+use feagi_sensorimotor::data_types::{GazeProperties, ImageFrame, Percentage, Percentage2D};
+use feagi_sensorimotor::feedbacks::{FeedBackRegistration, FeedbackRegistrationTargets};
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+    FrameChangeHandling, PercentageNeuronPositioning,
+};
+use feagi_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use ndarray::Array2;
+use std::time::Instant;
+
+const SIZE: u32 = 64;
+const WEDGES: u32 = 8;
+const RINGS: u32 = 6;
+const GAZE_Z_DEPTH: u32 = 8;
+
+fn input_properties() -> ImageFrameProperties {
+    ImageFrameProperties::new(
+        ImageXYResolution::new(SIZE, SIZE).unwrap(),
+        ColorSpace::Gamma,
+        ColorChannelLayout::GrayScale,
+    )
+    .unwrap()
+}
+
+fn log_polar_properties() -> ImageFrameProperties {
+    ImageFrameProperties::new(
+        ImageXYResolution::new(WEDGES, RINGS).unwrap(),
+        ColorSpace::Gamma,
+        ColorChannelLayout::GrayScale,
+    )
+    .unwrap()
+}
+
+fn gaze(x: f32, y: f32, modulation: f32) -> GazeProperties {
+    GazeProperties::new(
+        Percentage2D::new(
+            Percentage::new_from_0_1(x).unwrap(),
+            Percentage::new_from_0_1(y).unwrap(),
+        ),
+        Percentage::new_from_0_1(modulation).unwrap(),
+    )
+}
+
+fn gray_image(brightness_at: impl Fn(usize, usize) -> u8) -> ImageFrame {
+    let mut image = ImageFrame::new_from_image_frame_properties(&input_properties()).unwrap();
+    let pixels = image.get_internal_data_mut();
+    for y in 0..SIZE as usize {
+        for x in 0..SIZE as usize {
+            pixels[(y, x, 0)] = brightness_at(x, y);
+        }
+    }
+    image
+}
+
+/// Distance of a pixel center from the center of the image
+fn radius_from_center(x: usize, y: usize) -> f32 {
+    let half = SIZE as f32 / 2.0;
+    (x as f32 + 0.5 - half).hypot(y as f32 + 0.5 - half)
+}
+
+fn register_log_polar_vision(cache: &ConnectorCache, initial_gaze: GazeProperties) {
+    cache
+        .get_sensor_cache()
+        .log_polar_vision_register(
+            CorticalUnitIndex::from(0u8),
+            CorticalChannelCount::new(1).unwrap(),
+            FrameChangeHandling::Absolute,
+            input_properties(),
+            log_polar_properties(),
+            initial_gaze,
+        )
+        .unwrap();
+}
+
+/// Writes an image and returns the log-polar map indexed by (ring, wedge), with the fovea at
+/// ring 0
+fn log_polar_map(cache: &ConnectorCache, image: ImageFrame) -> Array2<u8> {
+    let mut sensors = cache.get_sensor_cache();
+    sensors
+        .log_polar_vision_write(0.into(), 0.into(), image.into())
+        .unwrap();
+    let output: ImageFrame = sensors
+        .log_polar_vision_read_postprocessed_cache_value(0.into(), 0.into())
+        .unwrap();
+    assert_eq!(output.get_image_frame_properties(), log_polar_properties());
+    let rows = output.get_internal_data().index_axis(ndarray::Axis(2), 0);
+    Array2::from_shape_fn((RINGS as usize, WEDGES as usize), |(ring, wedge)| {
+        rows[(RINGS as usize - 1 - ring, wedge)]
+    })
+}
+
+#[cfg(test)]
+mod test_log_polar_transform {
+    use super::*;
+
+    #[test]
+    fn test_bright_center_lands_in_fovea() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+        let map = log_polar_map(
+            &cache,
+            gray_image(|x, y| {
+                if radius_from_center(x, y) < 5.0 {
+                    255
+                } else {
+                    0
+                }
+            }),
+        );
+
+        for wedge in 0..WEDGES as usize {
+            assert_eq!(map[(0, wedge)], 255, "fovea wedge {}", wedge);
+            for ring in 2..RINGS as usize {
+                assert_eq!(map[(ring, wedge)], 0, "ring {} wedge {}", ring, wedge);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wedges_go_counter_clockwise_from_the_right() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+
+        // Right half of the image is bright, so wedges pointing right (0 to 90 and 270 to 360
+        // degrees) are bright
+        let map = log_polar_map(
+            &cache,
+            gray_image(|x, _| if x >= SIZE as usize / 2 { 255 } else { 0 }),
+        );
+        for ring in 0..RINGS as usize {
+            for wedge in 0..WEDGES as usize {
+                let expected = if [0, 1, 6, 7].contains(&wedge) {
+                    255
+                } else {
+                    0
+                };
+                assert_eq!(
+                    map[(ring, wedge)],
+                    expected,
+                    "ring {} wedge {}",
+                    ring,
+                    wedge
+                );
+            }
+        }
+
+        // Top half of the image is bright, so wedges pointing up (0 to 180 degrees) are bright
+        let map = log_polar_map(
+            &cache,
+            gray_image(|_, y| if y < SIZE as usize / 2 { 255 } else { 0 }),
+        );
+        for ring in 0..RINGS as usize {
+            for wedge in 0..WEDGES as usize {
+                let expected = if wedge < 4 { 255 } else { 0 };
+                assert_eq!(
+                    map[(ring, wedge)],
+                    expected,
+                    "ring {} wedge {}",
+                    ring,
+                    wedge
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rings_are_spaced_logarithmically() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+
+        // Brightness grows linearly with distance, so each ring shows its average radius
+        let map = log_polar_map(
+            &cache,
+            gray_image(|x, y| (radius_from_center(x, y) * 5.0) as u8),
+        );
+        let ring_brightness: Vec<f32> = (0..RINGS as usize)
+            .map(|ring| {
+                (0..WEDGES as usize)
+                    .map(|wedge| map[(ring, wedge)] as f32)
+                    .sum::<f32>()
+                    / WEDGES as f32
+            })
+            .collect();
+
+        // Log spaced rings get wider the further out they are
+        for ring in 1..RINGS as usize - 1 {
+            let inner_step = ring_brightness[ring] - ring_brightness[ring - 1];
+            let outer_step = ring_brightness[ring + 1] - ring_brightness[ring];
+            assert!(
+                outer_step > inner_step,
+                "ring brightness {:?} does not grow logarithmically",
+                ring_brightness
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_output_pixel_covers_the_image() {
+        // A fovea narrower than a pixel has rings holding no pixels, which sample the nearest
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.0));
+        let map = log_polar_map(&cache, gray_image(|_, _| 100));
+        assert!(map.iter().all(|value| *value == 100), "{:?}", map);
+    }
+
+    #[test]
+    fn test_field_of_view_outside_image_is_black() {
+        // Looking at the upper left corner, the wedges pointing up and left leave the image
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.0, 1.0, 0.1));
+        let map = log_polar_map(&cache, gray_image(|_, _| 100));
+        let outermost_ring = RINGS as usize - 1;
+        for wedge in 0..WEDGES as usize {
+            let expected = if [6, 7].contains(&wedge) { 100 } else { 0 };
+            assert_eq!(map[(outermost_ring, wedge)], expected, "wedge {}", wedge);
+        }
+    }
+
+    #[test]
+    fn test_color_image_to_grayscale() {
+        let color_properties = ImageFrameProperties::new(
+            ImageXYResolution::new(SIZE, SIZE).unwrap(),
+            ColorSpace::Gamma,
+            ColorChannelLayout::RGB,
+        )
+        .unwrap();
+        let cache = ConnectorCache::new();
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .log_polar_vision_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(1).unwrap(),
+                FrameChangeHandling::Absolute,
+                color_properties,
+                log_polar_properties(),
+                gaze(0.5, 0.5, 0.1),
+            )
+            .unwrap();
+        let mut image = ImageFrame::new_from_image_frame_properties(&color_properties).unwrap();
+        image.get_internal_data_mut().fill(200);
+        sensors
+            .log_polar_vision_write(0.into(), 0.into(), image.into())
+            .unwrap();
+        let output: ImageFrame = sensors
+            .log_polar_vision_read_postprocessed_cache_value(0.into(), 0.into())
+            .unwrap();
+        assert_eq!(output.get_image_frame_properties(), log_polar_properties());
+        assert!(output.get_internal_data().iter().all(|value| *value == 200));
+    }
+
+    #[test]
+    fn test_invalid_properties_rejected() {
+        let resolution = |width, height| ImageXYResolution::new(width, height).unwrap();
+        let properties = |resolution, color_space, layout| {
+            ImageFrameProperties::new(resolution, color_space, layout).unwrap()
+        };
+        let invalid_outputs = [
+            // Only the fovea
+            properties(
+                resolution(WEDGES, 1),
+                ColorSpace::Gamma,
+                ColorChannelLayout::GrayScale,
+            ),
+            // Changed color space
+            properties(
+                resolution(WEDGES, RINGS),
+                ColorSpace::Linear,
+                ColorChannelLayout::GrayScale,
+            ),
+            // Added color channels
+            properties(
+                resolution(WEDGES, RINGS),
+                ColorSpace::Gamma,
+                ColorChannelLayout::RGB,
+            ),
+        ];
+        for output in invalid_outputs {
+            assert!(
+                ImageLogPolarTransformerStage::new(input_properties(), output, gaze(0.5, 0.5, 0.1))
+                    .is_err(),
+                "{:?}",
+                output
+            );
+        }
+
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+        let changed_output = PipelineStageProperties::new_image_log_polar_transformer(
+            input_properties(),
+            properties(
+                resolution(WEDGES * 2, RINGS),
+                ColorSpace::Gamma,
+                ColorChannelLayout::GrayScale,
+            ),
+            gaze(0.5, 0.5, 0.1),
+        );
+        assert!(cache
+            .get_sensor_cache()
+            .log_polar_vision_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                changed_output,
+            )
+            .is_err());
+
+        // Replacing the stages with ones that cannot be created returns the error instead of
+        // panicking
+        let mismatched_color_space = PipelineStageProperties::new_image_log_polar_transformer(
+            properties(
+                resolution(SIZE, SIZE),
+                ColorSpace::Linear,
+                ColorChannelLayout::GrayScale,
+            ),
+            log_polar_properties(),
+            gaze(0.5, 0.5, 0.1),
+        );
+        assert!(cache
+            .get_sensor_cache()
+            .log_polar_vision_replace_all_stages(0.into(), 0.into(), vec![mismatched_color_space])
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_log_polar_gaze {
+    use super::*;
+
+    fn corner_spot(x: usize, y: usize) -> u8 {
+        if x < 16 && y < 16 {
+            255
+        } else {
+            0
+        }
+    }
+
+    #[test]
+    fn test_gaze_moves_fovea() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+        let map = log_polar_map(&cache, gray_image(corner_spot));
+        assert!((0..WEDGES as usize).all(|wedge| map[(0, wedge)] == 0));
+
+        // Look at the spot in the upper left
+        cache
+            .get_sensor_cache()
+            .log_polar_vision_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                PipelineStageProperties::new_image_log_polar_transformer(
+                    input_properties(),
+                    log_polar_properties(),
+                    gaze(0.125, 0.875, 0.1),
+                ),
+            )
+            .unwrap();
+        let map = log_polar_map(&cache, gray_image(corner_spot));
+        assert!((0..WEDGES as usize).all(|wedge| map[(0, wedge)] == 255));
+    }
+
+    #[test]
+    fn test_gaze_motor_feedback_moves_fovea() {
+        let mut cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+        cache
+            .get_motor_cache()
+            .gaze_register(
+                CorticalUnitIndex::from(0u8),
+                CorticalChannelCount::new(1).unwrap(),
+                FrameChangeHandling::Absolute,
+                NeuronDepth::new(GAZE_Z_DEPTH).unwrap(),
+                NeuronDepth::new(GAZE_Z_DEPTH).unwrap(),
+                PercentageNeuronPositioning::Linear,
+            )
+            .unwrap();
+        cache
+            .register_feedback(
+                FeedBackRegistration::LogPolarVisionWithGaze {},
+                FeedbackRegistrationTargets::new(0.into(), 0.into(), 0.into(), 0.into()),
+            )
+            .unwrap();
+
+        // Gaze at (0.125, 0.875), the center of the spot, with a modulation of 0.125
+        let gaze_ids = MotorCorticalUnit::get_cortical_ids_array_for_gaze_with_parameters(
+            FrameChangeHandling::Absolute,
+            PercentageNeuronPositioning::Linear,
+            0.into(),
+        );
+        let mut eccentricity = NeuronVoxelXYZPArrays::new();
+        eccentricity.push_raw(0, 0, 7, 1.0);
+        eccentricity.push_raw(1, 0, 1, 1.0);
+        let mut modulation = NeuronVoxelXYZPArrays::new();
+        modulation.push_raw(0, 0, 7, 1.0);
+        let mut neuron_data = CorticalMappedXYZPNeuronVoxels::new();
+        neuron_data.insert(gaze_ids[0], eccentricity);
+        neuron_data.insert(gaze_ids[1], modulation);
+        cache
+            .get_motor_cache()
+            .ingest_neuron_data_and_run_callbacks(neuron_data, Instant::now())
+            .unwrap();
+
+        let stage = cache
+            .get_sensor_cache()
+            .log_polar_vision_get_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+            )
+            .unwrap();
+        assert_eq!(
+            stage,
+            PipelineStageProperties::new_image_log_polar_transformer(
+                input_properties(),
+                log_polar_properties(),
+                gaze(0.125, 0.875, 0.125),
+            )
+        );
+        let map = log_polar_map(&cache, gray_image(corner_spot));
+        assert!((0..WEDGES as usize).all(|wedge| map[(0, wedge)] == 255));
+    }
+}
+
+#[cfg(test)]
+mod test_log_polar_vision_sensor {
+    use super::*;
+
+    #[test]
+    fn test_encodes_fovea_at_y_zero() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.5, 0.5, 0.1));
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .log_polar_vision_write(
+                0.into(),
+                0.into(),
+                gray_image(|x, y| {
+                    if radius_from_center(x, y) < 5.0 {
+                        255
+                    } else {
+                        0
+                    }
+                })
+                .into(),
+            )
+            .unwrap();
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now())
+            .unwrap();
+
+        let cortical_id =
+            SensoryCorticalUnit::get_cortical_ids_array_for_log_polar_vision_with_parameters(
+                FrameChangeHandling::Absolute,
+                0.into(),
+            )[0];
+        let neurons = sensors.get_neurons().get_neurons_of(&cortical_id).unwrap();
+        let mut fovea_wedges: Vec<u32> = neurons
+            .iter()
+            .filter(|neuron| neuron.neuron_voxel_coordinate.y == 0)
+            .map(|neuron| neuron.neuron_voxel_coordinate.x)
+            .collect();
+        fovea_wedges.sort();
+        assert_eq!(fovea_wedges, (0..WEDGES).collect::<Vec<u32>>());
+        assert!(neurons
+            .iter()
+            .all(|neuron| neuron.neuron_voxel_coordinate.y < 2));
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let cache = ConnectorCache::new();
+        register_log_polar_vision(&cache, gaze(0.25, 0.75, 0.2));
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .get_sensor_cache()
+                .log_polar_vision_get_all_stage_properties(0.into(), 0.into())
+                .unwrap(),
+            vec![PipelineStageProperties::new_image_log_polar_transformer(
+                input_properties(),
+                log_polar_properties(),
+                gaze(0.25, 0.75, 0.2),
+            )]
+        );
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+    }
+}
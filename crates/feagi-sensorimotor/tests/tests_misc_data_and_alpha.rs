@@ -0,0 +1,228 @@
+//! Tests for multi dimensional misc data and images with alpha
+//!
+//! Tests cover:
+//! - Images converted to misc data staying upright
+//! - N-dimensional tensors mapped onto neuron axes, including flattening and top down Y
+//! - Grayscale and alpha (2 channel) and RGBA images through the image processor
+//! - Premultiplied alpha, and brightness changes leaving alpha alone
+
+use feagi_sensorimotor::data_types::descriptors::{
+    ColorChannelLayout, ColorSpace, CornerPoints, ImageFrameProperties, ImageXYPoint,
+    ImageXYResolution, MemoryOrderLayout, MiscDataAxis,
+};
+use feagi_sensorimotor::data_types::processing::ImageFrameProcessor;
+use feagi_sensorimotor::data_types::{ImageFrame, MiscData};
+use ndarray::{Array3, ArrayD, IxDyn};
+
+/// A 2 by 2 image where each pixel has the given channels, listed by (row, column)
+fn image_from_pixels(pixels: [[&[u8]; 2]; 2]) -> ImageFrame {
+    let number_channels = pixels[0][0].len();
+    let data = Array3::from_shape_fn((2, 2, number_channels), |(row, column, channel)| {
+        pixels[row][column][channel]
+    });
+    ImageFrame::from_array(
+        data,
+        &ColorSpace::Gamma,
+        &MemoryOrderLayout::HeightsWidthsChannels,
+    )
+    .unwrap()
+}
+
+fn properties(width: u32, height: u32, layout: ColorChannelLayout) -> ImageFrameProperties {
+    ImageFrameProperties::new(
+        ImageXYResolution::new(width, height).unwrap(),
+        ColorSpace::Gamma,
+        layout,
+    )
+    .unwrap()
+}
+
+fn process(processor: &ImageFrameProcessor, source: &ImageFrame) -> ImageFrame {
+    let mut destination =
+        ImageFrame::new_from_image_frame_properties(&processor.get_output_image_properties())
+            .unwrap();
+    processor.process_image(source, &mut destination).unwrap();
+    destination
+}
+
+#[cfg(test)]
+mod test_misc_data_tensors {
+    use super::*;
+
+    #[test]
+    fn test_image_frame_stays_upright() {
+        // 3 wide and 2 tall, with only the top right pixel lit
+        let mut data = Array3::<u8>::zeros((2, 3, 1));
+        data[(0, 2, 0)] = 255;
+        let image = ImageFrame::from_array(
+            data,
+            &ColorSpace::Gamma,
+            &MemoryOrderLayout::HeightsWidthsChannels,
+        )
+        .unwrap();
+
+        let misc_data = MiscData::new_from_image_frame(&image).unwrap();
+        let dimensions = misc_data.get_dimensions();
+        assert_eq!(
+            (dimensions.width, dimensions.height, dimensions.depth),
+            (3, 2, 1)
+        );
+        assert_eq!(misc_data.get_internal_data()[(2, 1, 0)], 1.0);
+        assert_eq!(misc_data.get_internal_data().sum(), 1.0);
+    }
+
+    #[test]
+    fn test_matrix_with_top_down_rows() {
+        let matrix =
+            ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let mapping = [MiscDataAxis::YTopDown, MiscDataAxis::X];
+        let misc_data = MiscData::new_from_tensor(matrix.view(), &mapping).unwrap();
+
+        // The first row is at the top
+        assert_eq!(misc_data.get_internal_data()[(0, 1, 0)], 0.0);
+        assert_eq!(misc_data.get_internal_data()[(2, 1, 0)], 2.0);
+        assert_eq!(misc_data.get_internal_data()[(0, 0, 0)], 3.0);
+        assert_eq!(
+            misc_data.export_as_tensor(&[2, 3], &mapping).unwrap(),
+            matrix
+        );
+    }
+
+    #[test]
+    fn test_axes_on_the_same_neuron_axis_are_flattened() {
+        // (batch, channel, height, width), with batches and channels stacked along Z
+        let tensor = ArrayD::from_shape_fn(IxDyn(&[2, 3, 4, 5]), |index| {
+            (index[0] * 1000 + index[1] * 100 + index[2] * 10 + index[3]) as f32
+        });
+        let mapping = [
+            MiscDataAxis::Z,
+            MiscDataAxis::Z,
+            MiscDataAxis::Y,
+            MiscDataAxis::X,
+        ];
+        let misc_data = MiscData::new_from_tensor(tensor.view(), &mapping).unwrap();
+        let dimensions = misc_data.get_dimensions();
+        assert_eq!(
+            (dimensions.width, dimensions.height, dimensions.depth),
+            (5, 4, 6)
+        );
+        // Batch 1, channel 2 is at Z = 1 * 3 + 2
+        assert_eq!(misc_data.get_internal_data()[(4, 3, 5)], 1234.0);
+        assert_eq!(
+            misc_data.export_as_tensor(&[2, 3, 4, 5], &mapping).unwrap(),
+            tensor
+        );
+    }
+
+    #[test]
+    fn test_invalid_mappings_rejected() {
+        let tensor = ArrayD::<f32>::zeros(IxDyn(&[2, 2]));
+        assert!(MiscData::new_from_tensor(tensor.view(), &[MiscDataAxis::X]).is_err());
+        assert!(MiscData::new_from_tensor(
+            tensor.view(),
+            &[MiscDataAxis::Y, MiscDataAxis::YTopDown]
+        )
+        .is_err());
+        assert!(MiscData::new_from_tensor(
+            ArrayD::<f32>::zeros(IxDyn(&[2, 0])).view(),
+            &[MiscDataAxis::X, MiscDataAxis::Y]
+        )
+        .is_err());
+
+        let misc_data =
+            MiscData::new_from_tensor(tensor.view(), &[MiscDataAxis::X, MiscDataAxis::Y]).unwrap();
+        assert!(misc_data
+            .export_as_tensor(&[4], &[MiscDataAxis::X])
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_alpha_image_processing {
+    use super::*;
+
+    #[test]
+    fn test_grayscale_alpha_to_grayscale() {
+        let source = image_from_pixels([[&[200, 255], &[100, 0]], [&[50, 128], &[0, 255]]]);
+        let mut processor = ImageFrameProcessor::new_from_input_output_properties(
+            &properties(2, 2, ColorChannelLayout::RG),
+            &properties(2, 2, ColorChannelLayout::GrayScale),
+        )
+        .unwrap();
+        let output = process(&processor, &source);
+        assert_eq!(
+            output.get_internal_data().as_slice().unwrap(),
+            &[200, 100, 50, 0]
+        );
+
+        processor.set_alpha_premultiplication(true).unwrap();
+        let output = process(&processor, &source);
+        assert_eq!(
+            output.get_internal_data().as_slice().unwrap(),
+            &[200, 0, 25, 0]
+        );
+    }
+
+    #[test]
+    fn test_rgba_crop_resize_grayscale_premultiplied() {
+        let source = image_from_pixels([
+            [&[255, 255, 255, 255], &[255, 255, 255, 0]],
+            [&[0, 0, 0, 255], &[255, 255, 255, 51]],
+        ]);
+        let mut processor = ImageFrameProcessor::new(properties(2, 2, ColorChannelLayout::RGBA));
+        processor
+            .set_cropping_from(
+                CornerPoints::new(ImageXYPoint::new(1, 0), ImageXYPoint::new(2, 2)).unwrap(),
+            )
+            .unwrap()
+            .set_resizing_to(ImageXYResolution::new(1, 2).unwrap())
+            .unwrap()
+            .set_conversion_to_grayscale(true)
+            .unwrap()
+            .set_alpha_premultiplication(true)
+            .unwrap();
+        let output = process(&processor, &source);
+        assert_eq!(output.get_internal_data().as_slice().unwrap(), &[0, 51]);
+    }
+
+    #[test]
+    fn test_rgba_premultiplied_keeps_alpha() {
+        let source = image_from_pixels([
+            [&[200, 100, 50, 128], &[10, 20, 30, 255]],
+            [&[255, 255, 255, 0], &[0, 0, 0, 0]],
+        ]);
+        let mut processor = ImageFrameProcessor::new(properties(2, 2, ColorChannelLayout::RGBA));
+        processor.set_alpha_premultiplication(true).unwrap();
+        let output = process(&processor, &source);
+        assert_eq!(
+            output.get_internal_data().as_slice().unwrap(),
+            &[100, 50, 25, 128, 10, 20, 30, 255, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_brightness_and_contrast_keep_alpha() {
+        let source = image_from_pixels([[&[100, 0], &[100, 128]], [&[100, 255], &[100, 200]]]);
+        let mut processor = ImageFrameProcessor::new(properties(2, 2, ColorChannelLayout::RG));
+        processor
+            .set_brightness_offset(50)
+            .unwrap()
+            .set_contrast_change(2.0)
+            .unwrap();
+        let output = process(&processor, &source);
+        assert_eq!(
+            output.get_internal_data().as_slice().unwrap(),
+            &[172, 0, 172, 128, 172, 255, 172, 200]
+        );
+    }
+
+    #[test]
+    fn test_premultiplication_ignored_without_alpha() {
+        let mut processor = ImageFrameProcessor::new(properties(2, 2, ColorChannelLayout::RGB));
+        processor.set_alpha_premultiplication(true).unwrap();
+        assert_eq!(
+            processor,
+            ImageFrameProcessor::new(properties(2, 2, ColorChannelLayout::RGB))
+        );
+    }
+}
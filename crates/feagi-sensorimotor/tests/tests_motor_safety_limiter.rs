@@ -0,0 +1,394 @@
+//! Tests for the motor safety limiter pipeline stage
+//!
+//! Tests cover:
+//! - Stage properties validation
+//! - Position clamping, velocity and acceleration limiting of decoded rotary motor commands
+//! - Watchdog fallback to the safe value, both while decoding and when ticking the pipelines
+//! - Emergency stop latching
+//! - Export and import of the stage as JSON
+
+use feagi_sensorimotor::data_pipeline::{
+    PipelineStageProperties, PipelineStagePropertyIndex, ScalarSignalType,
+};
+use feagi_sensorimotor::data_types::SignedPercentage;
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+    FrameChangeHandling, PercentageNeuronPositioning,
+};
+use feagi_structures::genomic::MotorCorticalUnit;
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const Z_DEPTH: u32 = 10;
+
+fn limiter(
+    position_range: std::ops::RangeInclusive<f32>,
+    max_velocity_per_second: Option<f32>,
+    max_acceleration_per_second_squared: Option<f32>,
+    watchdog_timeout_ms: Option<u32>,
+    emergency_stop: bool,
+) -> PipelineStageProperties {
+    PipelineStageProperties::new_motor_safety_limiter(
+        ScalarSignalType::SignedPercentage,
+        position_range,
+        max_velocity_per_second,
+        max_acceleration_per_second_squared,
+        watchdog_timeout_ms,
+        0.0,
+        emergency_stop,
+    )
+}
+
+fn register_rotary_motor(
+    cache: &ConnectorCache,
+    number_channels: u32,
+    stage: PipelineStageProperties,
+) {
+    let mut motors = cache.get_motor_cache();
+    motors
+        .rotary_motor_register(
+            CorticalUnitIndex::from(0u8),
+            CorticalChannelCount::new(number_channels).unwrap(),
+            FrameChangeHandling::Absolute,
+            NeuronDepth::new(Z_DEPTH).unwrap(),
+            PercentageNeuronPositioning::Linear,
+        )
+        .unwrap();
+    for channel in 0..number_channels {
+        motors
+            .rotary_motor_replace_all_stages(0.into(), channel.into(), vec![stage.clone()])
+            .unwrap();
+    }
+}
+
+/// Fires the neuron commanding the given value (a multiple of 0.1) on each given channel
+fn command(cache: &ConnectorCache, commands: &[(u32, f32)], time_of_command: Instant) {
+    let cortical_id = MotorCorticalUnit::get_cortical_ids_array_for_rotary_motor_with_parameters(
+        FrameChangeHandling::Absolute,
+        PercentageNeuronPositioning::Linear,
+        0.into(),
+    )[0];
+    let mut arrays = NeuronVoxelXYZPArrays::new();
+    for (channel, value) in commands {
+        // Even X columns are positive, odd X columns negative. Z 0 is the largest magnitude
+        let x = channel * 2 + if *value < 0.0 { 1 } else { 0 };
+        let z = ((1.0 - value.abs()) * Z_DEPTH as f32).round() as u32;
+        arrays.push_raw(x, 0, z, 1.0);
+    }
+    let mut neuron_data = CorticalMappedXYZPNeuronVoxels::new();
+    neuron_data.insert(cortical_id, arrays);
+    cache
+        .get_motor_cache()
+        .ingest_neuron_data_and_run_callbacks(neuron_data, time_of_command)
+        .unwrap();
+}
+
+fn tick(cache: &ConnectorCache, time_of_tick: Instant) {
+    cache
+        .get_motor_cache()
+        .tick_motor_pipelines(time_of_tick)
+        .unwrap();
+}
+
+fn read(cache: &ConnectorCache, channel: u32) -> f32 {
+    cache
+        .get_motor_cache()
+        .rotary_motor_read_postprocessed_cache_value(0.into(), channel.into())
+        .unwrap()
+        .get_as_m1_1()
+}
+
+fn record_callbacks(cache: &ConnectorCache, channel: u32) -> Arc<Mutex<Vec<f32>>> {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_in_callback = received.clone();
+    cache
+        .get_motor_cache()
+        .rotary_motor_try_register_motor_callback(0.into(), channel.into(), move |data| {
+            let value: SignedPercentage = data.try_into().unwrap();
+            received_in_callback
+                .lock()
+                .unwrap()
+                .push(value.get_as_m1_1());
+        })
+        .unwrap();
+    received
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {} but got {}",
+        expected,
+        actual
+    );
+}
+
+#[cfg(test)]
+mod test_motor_safety_limiter_properties {
+    use super::*;
+
+    #[test]
+    fn test_invalid_parameters_are_rejected() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, limiter(-1.0..=1.0, None, None, None, false));
+        let invalid = [
+            // Outside of the signal range
+            limiter(-2.0..=1.0, None, None, None, false),
+            // Safe value outside of the position range
+            limiter(0.5..=1.0, None, None, None, false),
+            limiter(-1.0..=1.0, Some(-1.0), None, None, false),
+            limiter(-1.0..=1.0, None, Some(0.0), None, false),
+            limiter(-1.0..=1.0, None, None, Some(0), false),
+            // Changing the signal type in place
+            PipelineStageProperties::new_motor_safety_limiter(
+                ScalarSignalType::Percentage,
+                0.0..=1.0,
+                None,
+                None,
+                None,
+                0.0,
+                false,
+            ),
+        ];
+        let mut motors = cache.get_motor_cache();
+        for invalid_properties in invalid {
+            assert!(motors
+                .rotary_motor_update_single_stage_properties(
+                    0.into(),
+                    0.into(),
+                    PipelineStagePropertyIndex::from(0u32),
+                    invalid_properties
+                )
+                .is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_motor_safety_limiter_processing {
+    use super::*;
+
+    #[test]
+    fn test_starts_at_safe_value_and_clamps_to_position_range() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, limiter(-0.5..=0.5, None, None, None, false));
+        assert_close(read(&cache, 0), 0.0);
+
+        let start = Instant::now();
+        command(&cache, &[(0, 1.0)], start);
+        assert_close(read(&cache, 0), 0.5);
+        command(&cache, &[(0, -1.0)], start + Duration::from_millis(10));
+        assert_close(read(&cache, 0), -0.5);
+        command(&cache, &[(0, 0.3)], start + Duration::from_millis(20));
+        assert_close(read(&cache, 0), 0.3);
+    }
+
+    #[test]
+    fn test_velocity_limit_keeps_ramping_without_new_commands() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, limiter(-1.0..=1.0, Some(1.0), None, None, false));
+        let received = record_callbacks(&cache, 0);
+
+        // The first command only starts the clock
+        let start = Instant::now();
+        command(&cache, &[(0, 1.0)], start);
+        assert_close(read(&cache, 0), 0.0);
+
+        tick(&cache, start + Duration::from_millis(250));
+        assert_close(read(&cache, 0), 0.25);
+        tick(&cache, start + Duration::from_millis(500));
+        assert_close(read(&cache, 0), 0.5);
+        tick(&cache, start + Duration::from_secs(2));
+        assert_close(read(&cache, 0), 1.0);
+
+        // Once at the target, ticking changes nothing
+        tick(&cache, start + Duration::from_secs(3));
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 4); // The first command, then one per moving tick
+        assert_close(*received.last().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_acceleration_limit_ramps_smoothly_without_overshoot() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(
+            &cache,
+            1,
+            limiter(-1.0..=1.0, Some(1.0), Some(2.0), None, false),
+        );
+        let start = Instant::now();
+        command(&cache, &[(0, 1.0)], start);
+
+        // Starting from rest, after 0.1 s the speed is at most 0.2 per second
+        tick(&cache, start + Duration::from_millis(100));
+        assert_close(read(&cache, 0), 0.02);
+
+        let mut previous_position = read(&cache, 0);
+        let mut previous_step = 0.02;
+        for step in 2..=40 {
+            tick(&cache, start + Duration::from_millis(100 * step));
+            let position = read(&cache, 0);
+            assert!(position >= previous_position && position <= 1.0);
+            let step_size = position - previous_position;
+            if position < 1.0 {
+                // Until arriving, the change in speed between steps is at most 2.0 * 0.1 s
+                assert!((step_size - previous_step).abs() <= 0.02 + 1e-4);
+                // and the speed never goes over the velocity limit
+                assert!(step_size <= 0.1 + 1e-4);
+            }
+            previous_position = position;
+            previous_step = step_size;
+        }
+        assert_close(previous_position, 1.0);
+    }
+
+    #[test]
+    fn test_watchdog_falls_back_to_safe_value() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 2, limiter(-1.0..=1.0, None, None, Some(100), false));
+        let received = record_callbacks(&cache, 1);
+
+        let start = Instant::now();
+        command(&cache, &[(0, 0.8), (1, -0.6)], start);
+        assert_close(read(&cache, 0), 0.8);
+        assert_close(read(&cache, 1), -0.6);
+
+        // Channel 1 stops receiving commands, and times out when the next data is decoded
+        command(&cache, &[(0, 0.7)], start + Duration::from_millis(50));
+        assert_close(read(&cache, 1), -0.6);
+        command(&cache, &[(0, 0.7)], start + Duration::from_millis(120));
+        assert_close(read(&cache, 0), 0.7);
+        assert_close(read(&cache, 1), 0.0);
+        assert_eq!(*received.lock().unwrap(), vec![-0.6, 0.0]);
+
+        // Without any data arriving, ticking trips the watchdog
+        tick(&cache, start + Duration::from_millis(300));
+        assert_close(read(&cache, 0), 0.0);
+
+        // Commands resume control
+        command(&cache, &[(1, 0.4)], start + Duration::from_millis(310));
+        assert_close(read(&cache, 1), 0.4);
+    }
+
+    #[test]
+    fn test_emergency_stop_latches_until_released() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, limiter(-1.0..=1.0, Some(1.0), None, None, false));
+        let received = record_callbacks(&cache, 0);
+        let start = Instant::now();
+        command(&cache, &[(0, 1.0)], start);
+        tick(&cache, start + Duration::from_millis(500));
+        assert_close(read(&cache, 0), 0.5);
+
+        // Engaging the stop jumps to the safe value right away, ignoring the velocity limit
+        let stage_index = PipelineStagePropertyIndex::from(0u32);
+        cache
+            .get_motor_cache()
+            .rotary_motor_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                stage_index,
+                limiter(-1.0..=1.0, Some(1.0), None, None, true),
+            )
+            .unwrap();
+        assert_close(read(&cache, 0), 0.0);
+
+        // The change is reported at the next check, and commands are ignored while stopped
+        tick(&cache, start + Duration::from_millis(510));
+        assert_close(*received.lock().unwrap().last().unwrap(), 0.0);
+        command(&cache, &[(0, 1.0)], start + Duration::from_millis(600));
+        tick(&cache, start + Duration::from_secs(2));
+        assert_close(read(&cache, 0), 0.0);
+
+        // Releasing the stop does not move anything until new commands arrive, which ramp up
+        // from the safe value
+        cache
+            .get_motor_cache()
+            .rotary_motor_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                stage_index,
+                limiter(-1.0..=1.0, Some(1.0), None, None, false),
+            )
+            .unwrap();
+        tick(&cache, start + Duration::from_secs(3));
+        assert_close(read(&cache, 0), 0.0);
+        command(&cache, &[(0, 1.0)], start + Duration::from_secs(4));
+        tick(&cache, start + Duration::from_millis(4200));
+        assert_close(read(&cache, 0), 0.2);
+    }
+}
+
+#[cfg(test)]
+mod test_motor_safety_limiter_serialization {
+    use super::*;
+
+    #[test]
+    fn test_export_import_roundtrip_with_safety_limiter() {
+        let stage = PipelineStageProperties::new_motor_safety_limiter(
+            ScalarSignalType::SignedPercentage,
+            -0.8..=0.6,
+            Some(1.5),
+            Some(4.0),
+            Some(250),
+            0.1,
+            false,
+        );
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, stage.clone());
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .get_motor_cache()
+                .rotary_motor_get_all_stage_properties(0.into(), 0.into())
+                .unwrap(),
+            vec![stage]
+        );
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn test_import_with_invalid_safety_limiter_returns_error() {
+        let cache = ConnectorCache::new();
+        register_rotary_motor(&cache, 1, limiter(-0.5..=0.5, None, None, None, false));
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        // Move the safe value outside of the position range, as a hand edited or remote config could
+        fn set_safe_value(value: &mut serde_json::Value) -> bool {
+            match value {
+                serde_json::Value::Object(map) => {
+                    if let Some(safe_value) = map.get_mut("safe_value") {
+                        *safe_value = serde_json::json!(0.9);
+                        return true;
+                    }
+                    map.values_mut().any(set_safe_value)
+                }
+                serde_json::Value::Array(values) => values.iter_mut().any(set_safe_value),
+                _ => false,
+            }
+        }
+        let mut invalid_json = json;
+        assert!(set_safe_value(&mut invalid_json));
+
+        let mut imported = ConnectorCache::new();
+        assert!(imported
+            .import_device_registrations_as_config_json(invalid_json)
+            .is_err());
+    }
+}
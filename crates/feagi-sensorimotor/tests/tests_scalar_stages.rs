@@ -0,0 +1,383 @@
+//! Tests for the scalar signal conditioning pipeline stages
+//!
+//! Tests cover:
+//! - Stage properties types and validation
+//! - Each stage's processing, run through a percentage sensor's pipeline
+//! - Updating stage properties in place
+//! - Export and import of scalar stages as JSON
+
+use feagi_sensorimotor::data_pipeline::{
+    PipelineStageProperties, PipelineStagePropertyIndex, ScalarSignalType,
+};
+use feagi_sensorimotor::data_types::{Percentage, SignedPercentage};
+use feagi_sensorimotor::wrapped_io_data::{WrappedIOData, WrappedIOType};
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+    FrameChangeHandling, PercentageNeuronPositioning,
+};
+
+fn register_proximity(cache: &ConnectorCache, stages: Vec<PipelineStageProperties>) {
+    let mut sensors = cache.get_sensor_cache();
+    sensors
+        .proximity_register(
+            CorticalUnitIndex::from(0u8),
+            CorticalChannelCount::new(1).unwrap(),
+            FrameChangeHandling::Absolute,
+            NeuronDepth::new(10).unwrap(),
+            PercentageNeuronPositioning::Linear,
+        )
+        .unwrap();
+    sensors
+        .proximity_replace_all_stages(0.into(), 0.into(), stages)
+        .unwrap();
+}
+
+/// Writes the given values in order, and returns the processed value after each
+fn run(cache: &ConnectorCache, inputs: &[WrappedIOData]) -> Vec<f32> {
+    let mut sensors = cache.get_sensor_cache();
+    inputs
+        .iter()
+        .map(|input| {
+            sensors
+                .proximity_write(0.into(), 0.into(), input.clone())
+                .unwrap();
+            sensors
+                .proximity_read_postprocessed_cache_value(0.into(), 0.into())
+                .unwrap()
+                .get_as_0_1()
+        })
+        .collect()
+}
+
+fn percentages(values: &[f32]) -> Vec<WrappedIOData> {
+    values
+        .iter()
+        .map(|value| Percentage::new_from_0_1(*value).unwrap().into())
+        .collect()
+}
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual_value, expected_value) in actual.iter().zip(expected.iter()) {
+        assert!(
+            (actual_value - expected_value).abs() < 1e-5,
+            "expected {:?} but got {:?}",
+            expected,
+            actual
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_stage_properties {
+    use super::*;
+
+    #[test]
+    fn test_linear_scale_converts_signal_types() {
+        let properties = PipelineStageProperties::new_scalar_linear_scale(
+            ScalarSignalType::SignedPercentage,
+            -1.0..=1.0,
+            ScalarSignalType::Percentage,
+            0.0..=1.0,
+        );
+        assert_eq!(
+            properties.get_input_data_type(),
+            WrappedIOType::SignedPercentage
+        );
+        assert_eq!(properties.get_output_data_type(), WrappedIOType::Percentage);
+    }
+
+    #[test]
+    fn test_invalid_parameters_are_rejected() {
+        let cache = ConnectorCache::new();
+        register_proximity(&cache, Vec::new());
+        let mut sensors = cache.get_sensor_cache();
+        let invalid = [
+            // Outside of the signal range
+            PipelineStageProperties::new_scalar_clamp(ScalarSignalType::Percentage, -0.5..=0.5),
+            PipelineStageProperties::new_scalar_deadband(ScalarSignalType::Percentage, 1.0),
+            PipelineStageProperties::new_scalar_rolling_average(ScalarSignalType::Percentage, 0),
+            PipelineStageProperties::new_scalar_exponential_average(
+                ScalarSignalType::Percentage,
+                0.0,
+            ),
+            PipelineStageProperties::new_scalar_rate_limit(ScalarSignalType::Percentage, -1.0),
+            PipelineStageProperties::new_scalar_kalman_filter(
+                ScalarSignalType::Percentage,
+                0.01,
+                0.0,
+            ),
+        ];
+        let valid = [
+            PipelineStageProperties::new_scalar_clamp(ScalarSignalType::Percentage, 0.0..=0.5),
+            PipelineStageProperties::new_scalar_deadband(ScalarSignalType::Percentage, 0.1),
+            PipelineStageProperties::new_scalar_rolling_average(ScalarSignalType::Percentage, 3),
+            PipelineStageProperties::new_scalar_exponential_average(
+                ScalarSignalType::Percentage,
+                0.5,
+            ),
+            PipelineStageProperties::new_scalar_rate_limit(ScalarSignalType::Percentage, 1.0),
+            PipelineStageProperties::new_scalar_kalman_filter(
+                ScalarSignalType::Percentage,
+                0.01,
+                0.1,
+            ),
+        ];
+        for (invalid_properties, valid_properties) in invalid.into_iter().zip(valid) {
+            // Creating a stage from invalid properties returns an error instead of panicking
+            assert!(sensors
+                .proximity_replace_all_stages(0.into(), 0.into(), vec![invalid_properties.clone()])
+                .is_err());
+            sensors
+                .proximity_replace_all_stages(0.into(), 0.into(), vec![valid_properties])
+                .unwrap();
+            assert!(sensors
+                .proximity_update_single_stage_properties(
+                    0.into(),
+                    0.into(),
+                    PipelineStagePropertyIndex::from(0u32),
+                    invalid_properties
+                )
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_changing_signal_type_in_place_is_rejected() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_clamp(
+                ScalarSignalType::Percentage,
+                0.0..=1.0,
+            )],
+        );
+        assert!(cache
+            .get_sensor_cache()
+            .proximity_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                PipelineStageProperties::new_scalar_clamp(
+                    ScalarSignalType::SignedPercentage,
+                    0.0..=1.0,
+                ),
+            )
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_stage_processing {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_linear_scale() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_linear_scale(
+                ScalarSignalType::SignedPercentage,
+                -0.5..=0.5,
+                ScalarSignalType::Percentage,
+                0.2..=0.8,
+            )],
+        );
+        let inputs: Vec<WrappedIOData> = [-1.0, -0.5, 0.0, 0.25, 1.0]
+            .iter()
+            .map(|value| SignedPercentage::new_from_m1_1(*value).unwrap().into())
+            .collect();
+        assert_close(&run(&cache, &inputs), &[0.2, 0.2, 0.5, 0.65, 0.8]);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_clamp(
+                ScalarSignalType::Percentage,
+                0.25..=0.75,
+            )],
+        );
+        assert_close(
+            &run(&cache, &percentages(&[0.0, 0.5, 1.0])),
+            &[0.25, 0.5, 0.75],
+        );
+    }
+
+    #[test]
+    fn test_deadband_rescales_outside_band() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![
+                PipelineStageProperties::new_scalar_deadband(
+                    ScalarSignalType::SignedPercentage,
+                    0.2,
+                ),
+                PipelineStageProperties::new_scalar_linear_scale(
+                    ScalarSignalType::SignedPercentage,
+                    -1.0..=1.0,
+                    ScalarSignalType::Percentage,
+                    0.0..=1.0,
+                ),
+            ],
+        );
+        let inputs: Vec<WrappedIOData> = [0.1, -0.15, 0.6, -1.0]
+            .iter()
+            .map(|value| SignedPercentage::new_from_m1_1(*value).unwrap().into())
+            .collect();
+        // Deadband output is 0, 0, 0.5, -1, then mapped from -1..1 onto 0..1
+        assert_close(&run(&cache, &inputs), &[0.5, 0.5, 0.75, 0.0]);
+    }
+
+    #[test]
+    fn test_rolling_average() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_rolling_average(
+                ScalarSignalType::Percentage,
+                3,
+            )],
+        );
+        assert_close(
+            &run(&cache, &percentages(&[0.3, 0.6, 0.9, 0.0, 0.0])),
+            &[0.3, 0.45, 0.6, 0.5, 0.3],
+        );
+    }
+
+    #[test]
+    fn test_exponential_average() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_exponential_average(
+                ScalarSignalType::Percentage,
+                0.5,
+            )],
+        );
+        assert_close(
+            &run(&cache, &percentages(&[0.8, 0.0, 0.0, 1.0])),
+            &[0.8, 0.4, 0.2, 0.6],
+        );
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_rate_limit(
+                ScalarSignalType::Percentage,
+                1.0,
+            )],
+        );
+        let first = run(&cache, &percentages(&[0.0]));
+        assert_eq!(first, vec![0.0]); // The first input passes through
+
+        std::thread::sleep(Duration::from_millis(100));
+        let limited = run(&cache, &percentages(&[1.0]))[0];
+        // At 1.0 per second, roughly 0.1 of the way after 100ms (allowing for a slow scheduler)
+        assert!((0.09..0.5).contains(&limited), "got {}", limited);
+    }
+
+    #[test]
+    fn test_kalman_filter_smooths_noise() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_kalman_filter(
+                ScalarSignalType::Percentage,
+                0.0001,
+                0.01,
+            )],
+        );
+        let noisy: Vec<f32> = (0..50)
+            .map(|i| if i % 2 == 0 { 0.6 } else { 0.4 })
+            .collect();
+        let outputs = run(&cache, &percentages(&noisy));
+        assert_eq!(outputs[0], 0.6); // The first input initializes the estimate
+        let settled = &outputs[40..];
+        assert!(settled.iter().all(|value| (value - 0.5).abs() < 0.05));
+    }
+
+    #[test]
+    fn test_update_properties_in_place() {
+        let cache = ConnectorCache::new();
+        register_proximity(
+            &cache,
+            vec![PipelineStageProperties::new_scalar_clamp(
+                ScalarSignalType::Percentage,
+                0.0..=0.5,
+            )],
+        );
+        assert_close(&run(&cache, &percentages(&[0.9])), &[0.5]);
+        cache
+            .get_sensor_cache()
+            .proximity_update_single_stage_properties(
+                0.into(),
+                0.into(),
+                PipelineStagePropertyIndex::from(0u32),
+                PipelineStageProperties::new_scalar_clamp(ScalarSignalType::Percentage, 0.0..=0.8),
+            )
+            .unwrap();
+        assert_close(&run(&cache, &percentages(&[0.9])), &[0.8]);
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_stage_serialization {
+    use super::*;
+
+    #[test]
+    fn test_export_import_roundtrip_with_scalar_stages() {
+        let stages = vec![
+            PipelineStageProperties::new_scalar_linear_scale(
+                ScalarSignalType::SignedPercentage,
+                -1.0..=1.0,
+                ScalarSignalType::Percentage,
+                0.0..=1.0,
+            ),
+            PipelineStageProperties::new_scalar_clamp(ScalarSignalType::Percentage, 0.1..=0.9),
+            PipelineStageProperties::new_scalar_deadband(ScalarSignalType::Percentage, 0.05),
+            PipelineStageProperties::new_scalar_rolling_average(ScalarSignalType::Percentage, 4),
+            PipelineStageProperties::new_scalar_exponential_average(
+                ScalarSignalType::Percentage,
+                0.3,
+            ),
+            PipelineStageProperties::new_scalar_rate_limit(ScalarSignalType::Percentage, 2.0),
+            PipelineStageProperties::new_scalar_kalman_filter(
+                ScalarSignalType::Percentage,
+                0.001,
+                0.05,
+            ),
+        ];
+        let cache = ConnectorCache::new();
+        register_proximity(&cache, stages.clone());
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .get_sensor_cache()
+                .proximity_get_all_stage_properties(0.into(), 0.into())
+                .unwrap(),
+            stages
+        );
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+    }
+}
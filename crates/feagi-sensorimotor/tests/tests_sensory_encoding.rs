@@ -0,0 +1,273 @@
+//! Tests for which sensory channels get encoded each burst
+//!
+//! Tests cover:
+//! - Channels not written to since the previous burst sending no neurons
+//! - Change-only encoding skipping channels whose value did not change
+//! - Keep-alive re-encoding channels that have been quiet for too long
+//! - Encoding settings validation
+//! - Export and import of encoding settings as JSON
+
+use feagi_sensorimotor::data_pipeline::{SensoryEncodingMode, SensoryEncodingSettings};
+use feagi_sensorimotor::data_types::Percentage;
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{
+    CorticalChannelCount, CorticalUnitIndex, NeuronDepth,
+};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
+    FrameChangeHandling, PercentageNeuronPositioning,
+};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::genomic::SensoryCorticalUnit;
+use std::time::{Duration, Instant};
+
+const CHANNELS: u32 = 3;
+
+fn infrared_cortical_id() -> CorticalID {
+    SensoryCorticalUnit::get_cortical_ids_array_for_infrared_with_parameters(
+        FrameChangeHandling::Absolute,
+        PercentageNeuronPositioning::Linear,
+        CorticalUnitIndex::from(0u8),
+    )[0]
+}
+
+fn register_infrared(cache: &ConnectorCache) {
+    cache
+        .get_sensor_cache()
+        .infrared_register(
+            0.into(),
+            CorticalChannelCount::new(CHANNELS).unwrap(),
+            FrameChangeHandling::Absolute,
+            NeuronDepth::new(10).unwrap(),
+            PercentageNeuronPositioning::Linear,
+        )
+        .unwrap();
+}
+
+fn write_infrared(cache: &ConnectorCache, channel: u32, value: f32) {
+    cache
+        .get_sensor_cache()
+        .infrared_write(
+            0.into(),
+            channel.into(),
+            Percentage::new_from_0_1(value).unwrap().into(),
+        )
+        .unwrap();
+}
+
+/// Encodes a burst and returns the (sorted, deduplicated) channels that sent neurons
+fn encode_and_get_channels(
+    cache: &ConnectorCache,
+    cortical_id: &CorticalID,
+    time_of_burst: Instant,
+) -> Vec<u32> {
+    let mut sensors = cache.get_sensor_cache();
+    sensors
+        .encode_all_sensors_to_neurons(time_of_burst)
+        .unwrap();
+    let mut channels: Vec<u32> = match sensors.get_neurons().get_neurons_of(cortical_id) {
+        Some(neurons) => neurons
+            .iter()
+            .map(|neuron| neuron.neuron_voxel_coordinate.x)
+            .collect(),
+        None => Vec::new(),
+    };
+    channels.sort();
+    channels.dedup();
+    channels
+}
+
+#[cfg(test)]
+mod test_updated_channels {
+    use super::*;
+
+    #[test]
+    fn test_only_written_channels_are_encoded() {
+        let cache = ConnectorCache::new();
+        register_infrared(&cache);
+        let cortical_id = infrared_cortical_id();
+        for channel in 0..CHANNELS {
+            write_infrared(&cache, channel, 0.5);
+        }
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, Instant::now()),
+            vec![0, 1, 2]
+        );
+
+        write_infrared(&cache, 1, 0.8);
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, Instant::now()),
+            vec![1]
+        );
+        assert!(encode_and_get_channels(&cache, &cortical_id, Instant::now()).is_empty());
+    }
+
+    #[test]
+    fn test_boolean_encodes_only_written_channels() {
+        let cache = ConnectorCache::new();
+        cache
+            .get_sensor_cache()
+            .digital_g_p_i_o_register(0.into(), CorticalChannelCount::new(CHANNELS).unwrap())
+            .unwrap();
+        let cortical_id =
+            SensoryCorticalUnit::get_cortical_ids_array_for_digital_g_p_i_o_with_parameters(
+                0.into(),
+            )[0];
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now())
+            .unwrap();
+
+        sensors
+            .digital_g_p_i_o_write(0.into(), 2.into(), true.into())
+            .unwrap();
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now())
+            .unwrap();
+        let neurons = sensors.get_neurons().get_neurons_of(&cortical_id).unwrap();
+        assert_eq!(neurons.len(), 1);
+        assert!(neurons
+            .iter()
+            .all(|neuron| neuron.neuron_voxel_coordinate.x == 2));
+    }
+}
+
+#[cfg(test)]
+mod test_change_only {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_value_is_skipped() {
+        let cache = ConnectorCache::new();
+        register_infrared(&cache);
+        cache
+            .get_sensor_cache()
+            .infrared_set_encoding_settings(
+                0.into(),
+                SensoryEncodingSettings::new(SensoryEncodingMode::ChangeOnly, None).unwrap(),
+            )
+            .unwrap();
+        let cortical_id = infrared_cortical_id();
+        write_infrared(&cache, 0, 0.5);
+        write_infrared(&cache, 1, 0.5);
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, Instant::now()),
+            vec![0, 1, 2]
+        );
+
+        write_infrared(&cache, 0, 0.5);
+        write_infrared(&cache, 1, 0.7);
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, Instant::now()),
+            vec![1]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_keep_alive {
+    use super::*;
+
+    #[test]
+    fn test_quiet_channels_are_resent_after_keep_alive() {
+        let cache = ConnectorCache::new();
+        register_infrared(&cache);
+        cache
+            .get_sensor_cache()
+            .infrared_set_encoding_settings(
+                0.into(),
+                SensoryEncodingSettings::new(SensoryEncodingMode::Updated, Some(100)).unwrap(),
+            )
+            .unwrap();
+        let cortical_id = infrared_cortical_id();
+        for channel in 0..CHANNELS {
+            write_infrared(&cache, channel, 0.5);
+        }
+        let start = Instant::now();
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, start),
+            vec![0, 1, 2]
+        );
+
+        assert!(
+            encode_and_get_channels(&cache, &cortical_id, start + Duration::from_millis(50))
+                .is_empty()
+        );
+        assert_eq!(
+            encode_and_get_channels(&cache, &cortical_id, start + Duration::from_millis(100)),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_never_written_channels_are_not_kept_alive() {
+        let cache = ConnectorCache::new();
+        register_infrared(&cache);
+        cache
+            .get_sensor_cache()
+            .infrared_set_encoding_settings(
+                0.into(),
+                SensoryEncodingSettings::new(SensoryEncodingMode::Updated, Some(100)).unwrap(),
+            )
+            .unwrap();
+        let cortical_id = infrared_cortical_id();
+        write_infrared(&cache, 1, 0.5);
+        let start = Instant::now();
+        encode_and_get_channels(&cache, &cortical_id, start);
+
+        for quiet_ms in [100, 200] {
+            assert_eq!(
+                encode_and_get_channels(
+                    &cache,
+                    &cortical_id,
+                    start + Duration::from_millis(quiet_ms)
+                ),
+                vec![1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_keep_alive_rejected() {
+        assert!(SensoryEncodingSettings::new(SensoryEncodingMode::Updated, Some(0)).is_err());
+        assert!(SensoryEncodingSettings::new(SensoryEncodingMode::ChangeOnly, Some(1)).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_encoding_settings_json {
+    use super::*;
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let cache = ConnectorCache::new();
+        register_infrared(&cache);
+        let default_json = cache.export_device_registrations_as_config_json().unwrap();
+        assert!(!default_json.to_string().contains("encoding_settings"));
+
+        let settings =
+            SensoryEncodingSettings::new(SensoryEncodingMode::ChangeOnly, Some(250)).unwrap();
+        cache
+            .get_sensor_cache()
+            .infrared_set_encoding_settings(0.into(), settings)
+            .unwrap();
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json.clone())
+            .unwrap();
+        assert_eq!(
+            imported
+                .get_sensor_cache()
+                .infrared_get_encoding_settings(0.into())
+                .unwrap(),
+            settings
+        );
+        assert_eq!(
+            imported
+                .export_device_registrations_as_config_json()
+                .unwrap(),
+            json
+        );
+    }
+}
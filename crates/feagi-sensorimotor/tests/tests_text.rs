@@ -0,0 +1,276 @@
+//! Tests for text token streams
+//!
+//! Tests cover:
+//! - Word level and byte level BPE tokenization
+//! - Incremental decoding of token streams, including characters split across tokens
+//! - Sending queued tokens one per burst, with gaps when nothing is queued
+//! - Round trips of text from the text input unit to the text output unit
+
+use feagi_sensorimotor::data_types::{TextToken, TextTokenDecoder, TextTokenizer};
+use feagi_sensorimotor::ConnectorCache;
+use feagi_structures::genomic::cortical_area::descriptors::{CorticalChannelCount, NeuronDepth};
+use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::FrameChangeHandling;
+use feagi_structures::genomic::{MotorCorticalUnit, SensoryCorticalUnit};
+use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
+use std::collections::HashMap;
+use std::time::Instant;
+
+const WORD_VOCABULARY: &str = "<unk>\nhello\nworld\n,\n!\n";
+
+fn word_tokenizer() -> TextTokenizer {
+    TextTokenizer::new_word_level_from_text(WORD_VOCABULARY, Some("<unk>")).unwrap()
+}
+
+fn byte_pair_tokenizer() -> TextTokenizer {
+    // 'Ġ' stands in for the space byte, "Ã" and "©" for the two bytes of 'é'
+    let vocabulary: HashMap<String, u32> = [
+        ("h", 0),
+        ("i", 1),
+        ("Ġ", 2),
+        ("hi", 3),
+        ("Ġhi", 4),
+        ("Ã", 5),
+        ("©", 6),
+    ]
+    .into_iter()
+    .map(|(token, token_id)| (token.to_string(), token_id))
+    .collect();
+    let merges = "#version: 0.2\nh i\nĠ hi\n";
+    TextTokenizer::new_byte_pair_encoding_from_text(
+        &serde_json::to_string(&vocabulary).unwrap(),
+        merges,
+    )
+    .unwrap()
+}
+
+fn token_ids(tokens: &[TextToken]) -> Vec<u32> {
+    tokens.iter().map(|token| token.token_id()).collect()
+}
+
+/// A connector with one text input and one text output channel, using the word vocabulary
+fn text_connector() -> ConnectorCache {
+    let mut cache = ConnectorCache::new();
+    let tokenizer = word_tokenizer();
+    let token_depth = tokenizer.get_required_token_depth();
+    cache.set_text_tokenizer(tokenizer);
+    cache
+        .get_sensor_cache()
+        .text_english_input_register(0.into(), CorticalChannelCount::new(1).unwrap(), token_depth)
+        .unwrap();
+    cache
+        .get_motor_cache()
+        .text_english_output_register(0.into(), CorticalChannelCount::new(1).unwrap(), token_depth)
+        .unwrap();
+    cache
+}
+
+/// Runs a burst, echoing the text input neurons back to the text output as FEAGI would
+fn run_echo_burst(cache: &ConnectorCache) {
+    let input_id =
+        SensoryCorticalUnit::get_cortical_ids_array_for_text_english_input_with_parameters(
+            FrameChangeHandling::Absolute,
+            0.into(),
+        )[0];
+    let output_id =
+        MotorCorticalUnit::get_cortical_ids_array_for_text_english_output_with_parameters(
+            FrameChangeHandling::Absolute,
+            0.into(),
+        )[0];
+
+    let mut motor_neurons = CorticalMappedXYZPNeuronVoxels::new();
+    {
+        let mut sensors = cache.get_sensor_cache();
+        sensors
+            .encode_all_sensors_to_neurons(Instant::now())
+            .unwrap();
+        if let Some(neurons) = sensors.get_neurons().get_neurons_of(&input_id) {
+            motor_neurons.insert(output_id, neurons.clone());
+        }
+    }
+    cache
+        .get_motor_cache()
+        .ingest_neuron_data_and_run_callbacks(motor_neurons, Instant::now())
+        .unwrap();
+}
+
+#[cfg(test)]
+mod test_word_level_tokenizer {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode() {
+        let tokenizer = word_tokenizer();
+        let tokens = tokenizer.encode("Hello, world! foo").unwrap();
+        assert_eq!(token_ids(&tokens), vec![1, 3, 2, 4, 0]);
+        assert_eq!(
+            tokenizer.decode(&tokens).unwrap(),
+            "hello, world! <unk>".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_word_without_unknown_token_rejected() {
+        let tokenizer = TextTokenizer::new_word_level_from_text(WORD_VOCABULARY, None).unwrap();
+        assert!(tokenizer.encode("hello there").is_err());
+    }
+
+    #[test]
+    fn test_invalid_vocabularies_rejected() {
+        assert!(TextTokenizer::new_word_level_from_text("", None).is_err());
+        assert!(TextTokenizer::new_word_level_from_text("a\nb\na\n", None).is_err());
+        assert!(TextTokenizer::new_word_level_from_text("a\nb\n", Some("<unk>")).is_err());
+        assert!(TextTokenizer::new_word_level_from_file("/nonexistent/vocab.txt", None).is_err());
+    }
+
+    #[test]
+    fn test_required_token_depth() {
+        // Largest id 4 is sent as 5, which needs 3 bits
+        assert_eq!(*word_tokenizer().get_required_token_depth(), 3);
+    }
+}
+
+#[cfg(test)]
+mod test_byte_pair_tokenizer {
+    use super::*;
+
+    #[test]
+    fn test_merges_applied() {
+        let tokenizer = byte_pair_tokenizer();
+        let tokens = tokenizer.encode("hi hi").unwrap();
+        assert_eq!(token_ids(&tokens), vec![3, 4]);
+        assert_eq!(tokenizer.decode(&tokens).unwrap(), "hi hi".to_string());
+    }
+
+    #[test]
+    fn test_character_split_across_tokens_is_held() {
+        let tokenizer = byte_pair_tokenizer();
+        let tokens = tokenizer.encode("é").unwrap();
+        assert_eq!(token_ids(&tokens), vec![5, 6]);
+
+        let mut decoder = TextTokenDecoder::new();
+        assert_eq!(decoder.decode_token(&tokenizer, tokens[0]).unwrap(), "");
+        assert_eq!(decoder.decode_token(&tokenizer, tokens[1]).unwrap(), "é");
+    }
+
+    #[test]
+    fn test_missing_byte_rejected() {
+        assert!(byte_pair_tokenizer().encode("hello").is_err());
+    }
+
+    #[test]
+    fn test_invalid_files_rejected() {
+        assert!(TextTokenizer::new_byte_pair_encoding_from_text("not json", "").is_err());
+        assert!(TextTokenizer::new_byte_pair_encoding_from_text("{\"a\": 0}", "ab\n").is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_text_units {
+    use super::*;
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut cache = text_connector();
+        cache
+            .write_text(0.into(), 0.into(), "hello world!")
+            .unwrap();
+        assert_eq!(
+            cache
+                .get_sensor_cache()
+                .text_english_input_get_queued_token_count(0.into(), 0.into())
+                .unwrap(),
+            3
+        );
+
+        run_echo_burst(&cache);
+        assert_eq!(cache.read_text(0.into(), 0.into()).unwrap(), "hello");
+        run_echo_burst(&cache);
+        run_echo_burst(&cache);
+        assert_eq!(cache.read_text(0.into(), 0.into()).unwrap(), " world!");
+        assert_eq!(
+            cache
+                .get_sensor_cache()
+                .text_english_input_get_queued_token_count(0.into(), 0.into())
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_gaps_send_nothing_and_repeats_are_kept() {
+        let mut cache = text_connector();
+        run_echo_burst(&cache); // Initial burst
+        run_echo_burst(&cache);
+        assert_eq!(cache.read_text(0.into(), 0.into()).unwrap(), "");
+
+        cache.write_text(0.into(), 0.into(), "hello hello").unwrap();
+        for _ in 0..4 {
+            run_echo_burst(&cache);
+        }
+        assert_eq!(
+            token_ids(
+                &cache
+                    .get_motor_cache()
+                    .text_english_output_read_tokens(0.into(), 0.into())
+                    .unwrap()
+            ),
+            vec![1, 1]
+        );
+    }
+
+    #[test]
+    fn test_token_too_deep_rejected() {
+        let cache = ConnectorCache::new();
+        cache
+            .get_sensor_cache()
+            .text_english_input_register(
+                0.into(),
+                CorticalChannelCount::new(1).unwrap(),
+                NeuronDepth::new(2).unwrap(),
+            )
+            .unwrap();
+        let mut sensors = cache.get_sensor_cache();
+        assert!(sensors
+            .text_english_input_queue_tokens(0.into(), 0.into(), &[TextToken::new_unchecked(2)])
+            .is_ok());
+        assert!(sensors
+            .text_english_input_queue_tokens(0.into(), 0.into(), &[TextToken::new_unchecked(3)])
+            .is_err());
+        assert!(sensors
+            .text_english_input_register(
+                1.into(),
+                CorticalChannelCount::new(1).unwrap(),
+                NeuronDepth::new(33).unwrap(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_text_requires_tokenizer() {
+        let cache = ConnectorCache::new();
+        cache
+            .get_sensor_cache()
+            .text_english_input_register(
+                0.into(),
+                CorticalChannelCount::new(1).unwrap(),
+                NeuronDepth::new(16).unwrap(),
+            )
+            .unwrap();
+        assert!(cache.write_text(0.into(), 0.into(), "hello").is_err());
+    }
+
+    #[test]
+    fn test_export_import_keeps_text_units() {
+        let cache = text_connector();
+        let json = cache.export_device_registrations_as_config_json().unwrap();
+
+        let mut imported = ConnectorCache::new();
+        imported
+            .import_device_registrations_as_config_json(json)
+            .unwrap();
+        imported.set_text_tokenizer(word_tokenizer());
+        imported.write_text(0.into(), 0.into(), "world").unwrap();
+        run_echo_burst(&imported);
+        assert_eq!(imported.read_text(0.into(), 0.into()).unwrap(), "world");
+    }
+}
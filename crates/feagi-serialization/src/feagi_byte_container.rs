@@ -1,4 +1,8 @@
 use crate::feagi_serializable::FeagiSerializable;
+use crate::payload_compression::{
+    decompress_payload_into_with_limit, is_compressed_payload, PayloadCompression,
+    MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT,
+};
 use crate::FeagiByteStructureType;
 use byteorder::{ByteOrder, LittleEndian};
 use feagi_structures::FeagiDataError;
@@ -173,6 +177,52 @@ impl FeagiByteContainer {
         self.verify_container_valid_and_populate()
     }
 
+    /// Like [`Self::try_write_data_by_copy_and_verify`], but also accepts payloads wrapped in a
+    /// compressed envelope (see [`crate::payload_compression`]), inflating them first. Returns the
+    /// compression the payload arrived with.
+    ///
+    /// # Example
+    /// ```
+    /// use feagi_serialization::{FeagiByteContainer, PayloadCompression};
+    ///
+    /// let sent = FeagiByteContainer::new_empty();
+    /// let mut received = FeagiByteContainer::new_empty();
+    /// let compression = received
+    ///     .try_write_possibly_compressed_data_by_copy_and_verify(sent.get_byte_ref())
+    ///     .unwrap();
+    /// assert_eq!(compression, PayloadCompression::None);
+    /// ```
+    pub fn try_write_possibly_compressed_data_by_copy_and_verify(
+        &mut self,
+        new_data: &[u8],
+    ) -> Result<PayloadCompression, FeagiDataError> {
+        self.try_write_possibly_compressed_data_by_copy_and_verify_with_limit(
+            new_data,
+            MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT,
+        )
+    }
+
+    /// Same as [`Self::try_write_possibly_compressed_data_by_copy_and_verify`], but refuses
+    /// compressed payloads that would inflate past `max_uncompressed_byte_count`.
+    pub fn try_write_possibly_compressed_data_by_copy_and_verify_with_limit(
+        &mut self,
+        new_data: &[u8],
+        max_uncompressed_byte_count: usize,
+    ) -> Result<PayloadCompression, FeagiDataError> {
+        if !is_compressed_payload(new_data) {
+            self.try_write_data_by_copy_and_verify(new_data)?;
+            return Ok(PayloadCompression::None);
+        }
+        self.is_data_valid = false;
+        let compression = decompress_payload_into_with_limit(
+            new_data,
+            &mut self.bytes,
+            max_uncompressed_byte_count,
+        )?;
+        self.verify_container_valid_and_populate()?;
+        Ok(compression)
+    }
+
     //endregion
 
     //region Get Properties
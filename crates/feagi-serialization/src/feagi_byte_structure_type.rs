@@ -1,3 +1,4 @@
+use crate::xyzp_delta::CorticalMappedXYZPNeuronVoxelsDelta;
 use crate::FeagiSerializable;
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::FeagiDataError;
@@ -30,6 +31,12 @@ pub enum FeagiByteStructureType {
     /// Binary format specifically designed for neuron data
     /// with X, Y, Z coordinates and potential (P) values.
     NeuronCategoricalXYZP = 11u8,
+
+    /// Delta-coded neuron categorical XYZP data.
+    ///
+    /// Voxels added and removed per cortical area relative to the previous frame of the
+    /// stream, with periodic keyframes. See [`crate::xyzp_delta`].
+    NeuronCategoricalXYZPDelta = 12u8,
 }
 
 impl FeagiByteStructureType {
@@ -74,6 +81,9 @@ impl FeagiByteStructureType {
             FeagiByteStructureType::NeuronCategoricalXYZP => {
                 Box::new(CorticalMappedXYZPNeuronVoxels::new())
             }
+            FeagiByteStructureType::NeuronCategoricalXYZPDelta => {
+                Box::new(CorticalMappedXYZPNeuronVoxelsDelta::default())
+            }
             FeagiByteStructureType::JSON => Box::new(FeagiJSON::new_empty()),
         }
     }
@@ -85,6 +95,7 @@ impl TryFrom<u8> for FeagiByteStructureType {
         match value {
             1 => Ok(FeagiByteStructureType::JSON),
             11 => Ok(FeagiByteStructureType::NeuronCategoricalXYZP),
+            12 => Ok(FeagiByteStructureType::NeuronCategoricalXYZPDelta),
             _ => Err(FeagiDataError::DeserializationError(format!(
                 "Unknown FeagiByteStructure type {}",
                 value
@@ -98,6 +109,7 @@ impl Display for FeagiByteStructureType {
         let name = match self {
             FeagiByteStructureType::JSON => "JSON",
             FeagiByteStructureType::NeuronCategoricalXYZP => "NeuronCategoricalXYZP",
+            FeagiByteStructureType::NeuronCategoricalXYZPDelta => "NeuronCategoricalXYZPDelta",
         };
         write!(f, "{name}")
     }
@@ -1,3 +1,4 @@
+use crate::xyzp_delta::CorticalMappedXYZPNeuronVoxelsDelta;
 use crate::{FeagiByteContainer, FeagiByteStructureType};
 use feagi_structures::neuron_voxels::xyzp::CorticalMappedXYZPNeuronVoxels;
 use feagi_structures::{FeagiDataError, FeagiJSON};
@@ -98,3 +99,16 @@ impl TryFrom<Box<dyn FeagiSerializable>> for CorticalMappedXYZPNeuronVoxels {
         }
     }
 }
+
+impl TryFrom<Box<dyn FeagiSerializable>> for CorticalMappedXYZPNeuronVoxelsDelta {
+    type Error = FeagiDataError;
+    fn try_from(value: Box<dyn FeagiSerializable>) -> Result<Self, Self::Error> {
+        let option = value
+            .as_any()
+            .downcast_ref::<CorticalMappedXYZPNeuronVoxelsDelta>();
+        match option {
+            Some(value) => Ok(value.clone()),
+            None => Err(FeagiDataError::DeserializationError("This struct is not a CorticalMappedXYZPNeuronVoxelsDelta struct and cannot be deserialized as such!".into()))
+        }
+    }
+}
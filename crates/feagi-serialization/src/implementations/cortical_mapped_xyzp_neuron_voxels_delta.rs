@@ -0,0 +1,215 @@
+//! Serialization implementation for delta-coded cortical-mapped neuron voxel xyzp data.
+//!
+//! Layout after the per struct header:
+//! - Sequence (u32), flags (u8, bit 0 set for keyframes), cortical area count (u16)
+//! - Per cortical area: cortical ID, upserted voxel count (u32), removed voxel count (u32),
+//!   followed by the upserted X, Y, Z, P arrays and the removed X, Y, Z arrays
+
+use crate::xyzp_delta::CorticalMappedXYZPNeuronVoxelsDelta;
+use crate::{FeagiByteContainer, FeagiByteStructureType, FeagiSerializable};
+use byteorder::{ByteOrder, LittleEndian};
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::neuron_voxels::xyzp::NeuronVoxelXYZP;
+use feagi_structures::FeagiDataError;
+use std::any::Any;
+
+/// Current version of the delta XYZP serialization format.
+const BYTE_STRUCT_VERSION: u8 = 1;
+
+/// Bytes for the sequence, flags and cortical area count header.
+const NUMBER_BYTES_DELTA_HEADER: usize = size_of::<u32>() + size_of::<u8>() + size_of::<u16>();
+
+/// Bytes per cortical area header: 8 (ID) + 4 (upserted count) + 4 (removed count).
+const NUMBER_BYTES_PER_CORTICAL_ID_HEADER: usize =
+    CorticalID::NUMBER_OF_BYTES + size_of::<u32>() + size_of::<u32>();
+
+/// Bytes per removed voxel (X, Y, Z).
+const NUMBER_BYTES_PER_REMOVED_VOXEL: usize = size_of::<u32>() * 3;
+
+const KEYFRAME_FLAG: u8 = 0b0000_0001;
+
+impl FeagiSerializable for CorticalMappedXYZPNeuronVoxelsDelta {
+    fn get_type(&self) -> FeagiByteStructureType {
+        FeagiByteStructureType::NeuronCategoricalXYZPDelta
+    }
+
+    fn get_version(&self) -> u8 {
+        BYTE_STRUCT_VERSION
+    }
+
+    fn get_number_of_bytes_needed(&self) -> usize {
+        let mut number_bytes_needed: usize =
+            FeagiByteContainer::STRUCT_HEADER_BYTE_COUNT + NUMBER_BYTES_DELTA_HEADER;
+        for area_delta in self.mappings.values() {
+            number_bytes_needed += NUMBER_BYTES_PER_CORTICAL_ID_HEADER
+                + area_delta.upserted.get_size_in_number_of_bytes()
+                + area_delta.removed.len() * NUMBER_BYTES_PER_REMOVED_VOXEL;
+        }
+        number_bytes_needed
+    }
+
+    fn try_serialize_struct_to_byte_slice(
+        &self,
+        byte_destination: &mut [u8],
+    ) -> Result<(), FeagiDataError> {
+        if byte_destination.len() != self.get_number_of_bytes_needed() {
+            return Err(FeagiDataError::SerializationError(format!(
+                "Need exactly {} bytes to write delta xyzp neuron data, but given a space of {} bytes!",
+                self.get_number_of_bytes_needed(),
+                byte_destination.len()
+            )));
+        }
+
+        // write per struct header
+        byte_destination[0] = self.get_type() as u8;
+        byte_destination[1] = self.get_version();
+
+        let mut write_index: usize = FeagiByteContainer::STRUCT_HEADER_BYTE_COUNT;
+        LittleEndian::write_u32(
+            &mut byte_destination[write_index..write_index + 4],
+            self.get_sequence(),
+        );
+        byte_destination[write_index + 4] = if self.is_keyframe() { KEYFRAME_FLAG } else { 0 };
+        LittleEndian::write_u16(
+            &mut byte_destination[write_index + 5..write_index + 7],
+            self.mappings.len() as u16,
+        );
+        write_index += NUMBER_BYTES_DELTA_HEADER;
+
+        for (cortical_id, area_delta) in &self.mappings {
+            let cortical_id_slice: &mut [u8; CorticalID::NUMBER_OF_BYTES] = (&mut byte_destination
+                [write_index..write_index + CorticalID::NUMBER_OF_BYTES])
+                .try_into()
+                .unwrap();
+            cortical_id.write_id_to_bytes(cortical_id_slice);
+            write_index += CorticalID::NUMBER_OF_BYTES;
+
+            let number_upserted = area_delta.upserted.len();
+            let number_removed = area_delta.removed.len();
+            LittleEndian::write_u32(
+                &mut byte_destination[write_index..write_index + 4],
+                number_upserted as u32,
+            );
+            LittleEndian::write_u32(
+                &mut byte_destination[write_index + 4..write_index + 8],
+                number_removed as u32,
+            );
+            write_index += size_of::<u32>() * 2;
+
+            // Upserted voxels in structure-of-arrays order, like the full XYZP format
+            let (x, y, z, p) = area_delta.upserted.borrow_xyzp_vectors();
+            for values in [x, y, z] {
+                LittleEndian::write_u32_into(
+                    values,
+                    &mut byte_destination[write_index..write_index + number_upserted * 4],
+                );
+                write_index += number_upserted * 4;
+            }
+            LittleEndian::write_f32_into(
+                p,
+                &mut byte_destination[write_index..write_index + number_upserted * 4],
+            );
+            write_index += number_upserted * 4;
+
+            let removed = &area_delta.removed;
+            for axis in 0..3 {
+                for (i, coordinate) in removed.iter().enumerate() {
+                    let value = match axis {
+                        0 => coordinate.0,
+                        1 => coordinate.1,
+                        _ => coordinate.2,
+                    };
+                    let offset = write_index + i * 4;
+                    LittleEndian::write_u32(&mut byte_destination[offset..offset + 4], value);
+                }
+                write_index += number_removed * 4;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn try_deserialize_and_update_self_from_byte_slice(
+        &mut self,
+        byte_reading: &[u8],
+    ) -> Result<(), FeagiDataError> {
+        // Assuming type is correct
+        self.verify_byte_slice_is_of_correct_version(byte_reading)?;
+        self.clear_changes_only();
+
+        let mut read_index: usize = FeagiByteContainer::STRUCT_HEADER_BYTE_COUNT;
+        if byte_reading.len() < read_index + NUMBER_BYTES_DELTA_HEADER {
+            return Err(FeagiDataError::DeserializationError(
+                "Byte structure for NeuronCategoricalXYZPDelta is too short to fit its header!"
+                    .into(),
+            ));
+        }
+        let sequence = LittleEndian::read_u32(&byte_reading[read_index..read_index + 4]);
+        let is_keyframe = byte_reading[read_index + 4] & KEYFRAME_FLAG != 0;
+        let number_cortical_areas =
+            LittleEndian::read_u16(&byte_reading[read_index + 5..read_index + 7]) as usize;
+        self.set_header(sequence, is_keyframe);
+        read_index += NUMBER_BYTES_DELTA_HEADER;
+
+        for _cortical_index in 0..number_cortical_areas {
+            if byte_reading.len() < read_index + NUMBER_BYTES_PER_CORTICAL_ID_HEADER {
+                return Err(FeagiDataError::DeserializationError("Byte structure for NeuronCategoricalXYZPDelta is too short to fit the cortical areas the header says it contains!".into()));
+            }
+            let cortical_id = CorticalID::try_from_bytes(
+                <&[u8; CorticalID::NUMBER_OF_BYTES]>::try_from(
+                    &byte_reading[read_index..read_index + CorticalID::NUMBER_OF_BYTES],
+                )
+                .unwrap(),
+            )?;
+            read_index += CorticalID::NUMBER_OF_BYTES;
+            let number_upserted =
+                LittleEndian::read_u32(&byte_reading[read_index..read_index + 4]) as usize;
+            let number_removed =
+                LittleEndian::read_u32(&byte_reading[read_index + 4..read_index + 8]) as usize;
+            read_index += size_of::<u32>() * 2;
+
+            let number_bytes_to_read = number_upserted * NeuronVoxelXYZP::NUMBER_BYTES_PER_NEURON
+                + number_removed * NUMBER_BYTES_PER_REMOVED_VOXEL;
+            if byte_reading.len() < read_index + number_bytes_to_read {
+                return Err(FeagiDataError::DeserializationError("Byte structure for NeuronCategoricalXYZPDelta is too short to fit the data the header says it contains!".into()));
+            }
+
+            let area_delta = self.ensure_clear_and_borrow_mut(&cortical_id);
+            area_delta.upserted.ensure_capacity(number_upserted);
+            let x_start = read_index;
+            let y_start = x_start + number_upserted * 4;
+            let z_start = y_start + number_upserted * 4;
+            let p_start = z_start + number_upserted * 4;
+            for i in 0..number_upserted {
+                let offset = i * 4;
+                area_delta.upserted.push_raw(
+                    LittleEndian::read_u32(&byte_reading[x_start + offset..x_start + offset + 4]),
+                    LittleEndian::read_u32(&byte_reading[y_start + offset..y_start + offset + 4]),
+                    LittleEndian::read_u32(&byte_reading[z_start + offset..z_start + offset + 4]),
+                    LittleEndian::read_f32(&byte_reading[p_start + offset..p_start + offset + 4]),
+                );
+            }
+            read_index = p_start + number_upserted * 4;
+
+            let x_start = read_index;
+            let y_start = x_start + number_removed * 4;
+            let z_start = y_start + number_removed * 4;
+            area_delta.removed.reserve(number_removed);
+            for i in 0..number_removed {
+                let offset = i * 4;
+                area_delta.removed.push((
+                    LittleEndian::read_u32(&byte_reading[x_start + offset..x_start + offset + 4]),
+                    LittleEndian::read_u32(&byte_reading[y_start + offset..y_start + offset + 4]),
+                    LittleEndian::read_u32(&byte_reading[z_start + offset..z_start + offset + 4]),
+                ));
+            }
+            read_index = z_start + number_removed * 4;
+        }
+
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
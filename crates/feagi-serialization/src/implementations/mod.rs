@@ -3,4 +3,5 @@
 // These modules contain trait implementations that need to be compiled
 // even though they're not directly exported
 pub mod cortical_mapped_xyzp_neuron_data;
+pub mod cortical_mapped_xyzp_neuron_voxels_delta;
 pub mod feagi_json;
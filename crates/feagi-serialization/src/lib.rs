@@ -9,6 +9,8 @@
 //! - **[`FeagiSerializable`]** - Common trait for structures that can be serialized to/from bytes
 //! - **[`FeagiByteContainer`]** - Container that manages and owns byte data for multiple structures
 //! - **[`FeagiByteStructureType`]** - Enum identifying different serializable structure types
+//! - **[`PayloadCompressor`]** - Optional LZ4/zstd compression of container payloads for data streams
+//! - **[`XYZPDeltaEncoder`] / [`XYZPDeltaDecoder`]** - Delta coding of XYZP fire-queue streams
 //!
 //!
 //! ## Basic Usage
@@ -32,7 +34,14 @@ mod feagi_byte_container;
 mod feagi_byte_structure_type;
 mod feagi_serializable;
 pub mod implementations;
+pub mod payload_compression;
+pub mod xyzp_delta;
 
 pub use feagi_byte_container::{AgentIdentifier, FeagiByteContainer};
 pub use feagi_byte_structure_type::FeagiByteStructureType;
 pub use feagi_serializable::FeagiSerializable;
+pub use payload_compression::{PayloadCompression, PayloadCompressor};
+pub use xyzp_delta::{
+    CorticalMappedXYZPNeuronVoxelsDelta, XYZPDeltaContainerEncoding, XYZPDeltaDecoder,
+    XYZPDeltaEncoder,
+};
@@ -0,0 +1,296 @@
+//! Opt-in compression of serialized [`FeagiByteContainer`](crate::FeagiByteContainer) payloads.
+//!
+//! Agent data streams (sensory, motor, visualization) may wrap container bytes in a compressed
+//! envelope once both sides agreed on an algorithm during agent registration:
+//!
+//! - Marker (1 byte): [`COMPRESSED_PAYLOAD_MARKER`], which is never a valid container version
+//! - Algorithm (1 byte): [`PayloadCompression`]
+//! - Uncompressed length (4 bytes, little endian)
+//! - Compressed container bytes
+//!
+//! Because the marker cannot collide with the container version byte, receivers can accept
+//! compressed and raw payloads on the same stream.
+
+use byteorder::{ByteOrder, LittleEndian};
+use feagi_structures::FeagiDataError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// First byte of a compressed payload envelope
+pub const COMPRESSED_PAYLOAD_MARKER: u8 = 0xC7;
+
+/// Size of the envelope header preceding the compressed bytes
+pub const COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT: usize = 6; // 1 u8, 1 u8, 1 u32
+
+/// Largest uncompressed payload a receiver will inflate, to refuse decompression bombs
+pub const MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT: usize = 256 * 1024 * 1024;
+
+/// Compression algorithm applied to a stream payload
+///
+/// # Example
+/// ```
+/// use feagi_serialization::PayloadCompression;
+///
+/// assert_eq!(PayloadCompression::from_name("LZ4").unwrap(), PayloadCompression::Lz4);
+/// assert_eq!(PayloadCompression::Zstd.name(), "zstd");
+/// assert!(PayloadCompression::from_name("brotli").is_err());
+/// ```
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadCompression {
+    /// Payloads are sent as raw container bytes
+    #[default]
+    None = 0u8,
+    /// LZ4 block compression, cheap enough for every burst
+    Lz4 = 1u8,
+    /// Zstandard, smaller output at a higher CPU cost
+    Zstd = 2u8,
+}
+
+impl PayloadCompression {
+    /// Algorithms this build can both compress and decompress
+    pub const SUPPORTED: [PayloadCompression; 2] =
+        [PayloadCompression::Lz4, PayloadCompression::Zstd];
+
+    /// Parse an algorithm name as used in configuration files (case-insensitive)
+    pub fn from_name(name: &str) -> Result<Self, FeagiDataError> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "none" | "" => Ok(PayloadCompression::None),
+            "lz4" => Ok(PayloadCompression::Lz4),
+            "zstd" => Ok(PayloadCompression::Zstd),
+            other => Err(FeagiDataError::BadParameters(format!(
+                "Unknown payload compression algorithm '{}'! Expected one of: none, lz4, zstd",
+                other
+            ))),
+        }
+    }
+
+    /// Largest inflation factor the algorithm's format allows
+    ///
+    /// A claimed uncompressed length above `compressed bytes * ratio` cannot be genuine, so
+    /// receivers refuse it before allocating. LZ4 match lengths grow by at most 255 bytes per
+    /// input byte; a 4-byte Zstandard RLE block expands to at most one 128 KiB block.
+    pub fn max_expansion_ratio(&self) -> usize {
+        match self {
+            PayloadCompression::None => 1,
+            PayloadCompression::Lz4 => 255,
+            PayloadCompression::Zstd => 128 * 1024 / 4,
+        }
+    }
+
+    /// Configuration name of the algorithm
+    pub fn name(&self) -> &'static str {
+        match self {
+            PayloadCompression::None => "none",
+            PayloadCompression::Lz4 => "lz4",
+            PayloadCompression::Zstd => "zstd",
+        }
+    }
+}
+
+impl TryFrom<u8> for PayloadCompression {
+    type Error = FeagiDataError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PayloadCompression::None),
+            1 => Ok(PayloadCompression::Lz4),
+            2 => Ok(PayloadCompression::Zstd),
+            _ => Err(FeagiDataError::DeserializationError(format!(
+                "Unknown payload compression algorithm id {}!",
+                value
+            ))),
+        }
+    }
+}
+
+impl Display for PayloadCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns true if the given bytes are a compressed payload envelope rather than a raw container
+pub fn is_compressed_payload(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&COMPRESSED_PAYLOAD_MARKER)
+}
+
+/// Writes the compressed envelope of `payload` into `output`, replacing its contents.
+///
+/// Compressing with [`PayloadCompression::None`] is an error; callers should send the raw bytes.
+pub fn compress_payload_into(
+    payload: &[u8],
+    algorithm: PayloadCompression,
+    output: &mut Vec<u8>,
+) -> Result<(), FeagiDataError> {
+    if payload.len() > MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT {
+        return Err(FeagiDataError::SerializationError(format!(
+            "Payload of {} bytes exceeds the maximum compressible size of {} bytes!",
+            payload.len(),
+            MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT
+        )));
+    }
+
+    output.clear();
+    output.resize(COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT, 0);
+    output[0] = COMPRESSED_PAYLOAD_MARKER;
+    output[1] = algorithm as u8;
+    LittleEndian::write_u32(&mut output[2..6], payload.len() as u32);
+
+    match algorithm {
+        PayloadCompression::None => {
+            return Err(FeagiDataError::BadParameters(
+                "Cannot compress a payload without a compression algorithm!".into(),
+            ));
+        }
+        PayloadCompression::Lz4 => {
+            output.resize(
+                COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT
+                    + lz4_flex::block::get_maximum_output_size(payload.len()),
+                0,
+            );
+            let written = lz4_flex::block::compress_into(
+                payload,
+                &mut output[COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT..],
+            )
+            .map_err(|err| FeagiDataError::SerializationError(format!("LZ4: {}", err)))?;
+            output.truncate(COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT + written);
+        }
+        PayloadCompression::Zstd => {
+            ruzstd::encoding::compress(
+                payload,
+                &mut *output,
+                ruzstd::encoding::CompressionLevel::Fastest,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Inflates a compressed payload envelope into `output`, replacing its contents.
+///
+/// Returns the algorithm the payload was compressed with. Equivalent to
+/// [`decompress_payload_into_with_limit`] with [`MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT`].
+pub fn decompress_payload_into(
+    payload: &[u8],
+    output: &mut Vec<u8>,
+) -> Result<PayloadCompression, FeagiDataError> {
+    decompress_payload_into_with_limit(payload, output, MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT)
+}
+
+/// Inflates a compressed payload envelope into `output`, refusing payloads that would inflate past
+/// `max_uncompressed_byte_count` (capped at [`MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT`]).
+///
+/// The claimed uncompressed length is also checked against the compressed length times the
+/// algorithm's [`max_expansion_ratio`](PayloadCompression::max_expansion_ratio), so a small
+/// frame cannot make the receiver allocate a large buffer.
+pub fn decompress_payload_into_with_limit(
+    payload: &[u8],
+    output: &mut Vec<u8>,
+    max_uncompressed_byte_count: usize,
+) -> Result<PayloadCompression, FeagiDataError> {
+    if payload.len() < COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT || !is_compressed_payload(payload) {
+        return Err(FeagiDataError::DeserializationError(
+            "Given bytes are not a compressed payload!".into(),
+        ));
+    }
+    let algorithm = PayloadCompression::try_from(payload[1])?;
+    let uncompressed_length = LittleEndian::read_u32(&payload[2..6]) as usize;
+    let limit = max_uncompressed_byte_count.min(MAX_DECOMPRESSED_PAYLOAD_BYTE_COUNT);
+    if uncompressed_length > limit {
+        return Err(FeagiDataError::DeserializationError(format!(
+            "Compressed payload claims {} uncompressed bytes, over the limit of {}!",
+            uncompressed_length, limit
+        )));
+    }
+    let compressed = &payload[COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT..];
+    let plausible = compressed
+        .len()
+        .saturating_mul(algorithm.max_expansion_ratio());
+    if uncompressed_length > plausible {
+        return Err(FeagiDataError::DeserializationError(format!(
+            "Compressed payload claims {} uncompressed bytes from {} {} bytes, more than the format can encode!",
+            uncompressed_length,
+            compressed.len(),
+            algorithm
+        )));
+    }
+
+    output.clear();
+    output.resize(uncompressed_length, 0);
+    let written = match algorithm {
+        PayloadCompression::None => {
+            return Err(FeagiDataError::DeserializationError(
+                "Compressed payload does not specify a compression algorithm!".into(),
+            ));
+        }
+        PayloadCompression::Lz4 => lz4_flex::block::decompress_into(compressed, output)
+            .map_err(|err| FeagiDataError::DeserializationError(format!("LZ4: {}", err)))?,
+        PayloadCompression::Zstd => ruzstd::decoding::FrameDecoder::new()
+            .decode_all(compressed, output)
+            .map_err(|err| FeagiDataError::DeserializationError(format!("Zstd: {}", err)))?,
+    };
+    if written != uncompressed_length {
+        return Err(FeagiDataError::DeserializationError(format!(
+            "Compressed payload inflated to {} bytes when {} were expected!",
+            written, uncompressed_length
+        )));
+    }
+    Ok(algorithm)
+}
+
+/// Compresses outgoing payloads of one stream, reusing its output allocation between sends.
+///
+/// Payloads below the size threshold, or that do not shrink, are passed through unchanged.
+///
+/// # Example
+/// ```
+/// use feagi_serialization::{FeagiByteContainer, PayloadCompression, PayloadCompressor};
+///
+/// let mut compressor = PayloadCompressor::new(PayloadCompression::Lz4, 100);
+/// let container = FeagiByteContainer::new_empty();
+/// // 12 bytes is below the threshold, so the raw container is sent
+/// let payload = compressor.compress(container.get_byte_ref()).unwrap();
+/// assert_eq!(payload, container.get_byte_ref());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PayloadCompressor {
+    algorithm: PayloadCompression,
+    min_size_threshold: usize,
+    buffer: Vec<u8>,
+}
+
+impl PayloadCompressor {
+    pub fn new(algorithm: PayloadCompression, min_size_threshold: usize) -> Self {
+        Self {
+            algorithm,
+            min_size_threshold,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// A compressor that passes every payload through
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn algorithm(&self) -> PayloadCompression {
+        self.algorithm
+    }
+
+    pub fn min_size_threshold(&self) -> usize {
+        self.min_size_threshold
+    }
+
+    /// Returns the bytes to put on the wire for `payload`
+    pub fn compress<'a>(&'a mut self, payload: &'a [u8]) -> Result<&'a [u8], FeagiDataError> {
+        if self.algorithm == PayloadCompression::None || payload.len() < self.min_size_threshold {
+            return Ok(payload);
+        }
+        compress_payload_into(payload, self.algorithm, &mut self.buffer)?;
+        if self.buffer.len() >= payload.len() {
+            return Ok(payload);
+        }
+        Ok(&self.buffer)
+    }
+}
@@ -0,0 +1,424 @@
+//! Delta-coded XYZP frames for fire-queue streams with mostly static activity.
+//!
+//! Instead of every fired voxel, a [`CorticalMappedXYZPNeuronVoxelsDelta`] carries per cortical
+//! area the voxels that were added (or whose potential changed) and the voxels that were removed
+//! relative to the previous frame. Every few frames a keyframe carrying the complete frame is
+//! sent so that late subscribers and receivers that dropped a frame can resynchronize.
+//!
+//! - [`XYZPDeltaEncoder`] turns a sequence of full frames into deltas (sender side)
+//! - [`XYZPDeltaDecoder`] reconstructs the full frames from the deltas (receiver side)
+//!
+//! # Example
+//! ```
+//! use feagi_serialization::xyzp_delta::{XYZPDeltaDecoder, XYZPDeltaEncoder};
+//! use feagi_structures::genomic::cortical_area::CoreCorticalType;
+//! use feagi_structures::neuron_voxels::xyzp::{CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays};
+//!
+//! let mut encoder = XYZPDeltaEncoder::new(30).unwrap();
+//! let mut decoder = XYZPDeltaDecoder::new();
+//! let mut reconstructed = CorticalMappedXYZPNeuronVoxels::new();
+//!
+//! let mut frame = CorticalMappedXYZPNeuronVoxels::new();
+//! let mut voxels = NeuronVoxelXYZPArrays::new();
+//! voxels.push_raw(1, 2, 3, 1.0);
+//! frame.insert(CoreCorticalType::Power.to_cortical_id(), voxels);
+//!
+//! // The first frame is always a keyframe
+//! let delta = encoder.encode(&frame).unwrap();
+//! assert!(delta.is_keyframe());
+//! assert!(decoder.apply(&delta, &mut reconstructed));
+//! assert_eq!(reconstructed, frame);
+//!
+//! // Unchanged frames produce no delta at all
+//! assert!(encoder.encode(&frame).is_none());
+//! ```
+
+use crate::FeagiByteContainer;
+use feagi_structures::genomic::cortical_area::CorticalID;
+use feagi_structures::neuron_voxels::xyzp::{
+    CorticalMappedXYZPNeuronVoxels, NeuronVoxelXYZPArrays,
+};
+use feagi_structures::FeagiDataError;
+use std::collections::HashMap;
+
+/// Voxel coordinate within a cortical area
+pub type VoxelCoordinate = (u32, u32, u32);
+
+/// Changes of a single cortical area relative to the previous frame
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NeuronVoxelXYZPDelta {
+    /// Voxels that were added, or whose potential changed
+    pub upserted: NeuronVoxelXYZPArrays,
+    /// Voxels that stopped firing
+    pub removed: Vec<VoxelCoordinate>,
+}
+
+impl NeuronVoxelXYZPDelta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upserted.is_empty() && self.removed.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.upserted.clear();
+        self.removed.clear();
+    }
+}
+
+/// Delta-coded neuron voxel data organized by cortical area.
+///
+/// Areas absent from a delta are unchanged. A keyframe replaces the entire previous frame, so
+/// every firing area is listed with all of its voxels as upserts.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CorticalMappedXYZPNeuronVoxelsDelta {
+    sequence: u32,
+    is_keyframe: bool,
+    /// Changes per cortical area
+    pub mappings: HashMap<CorticalID, NeuronVoxelXYZPDelta>,
+}
+
+impl CorticalMappedXYZPNeuronVoxelsDelta {
+    pub fn new(sequence: u32, is_keyframe: bool) -> Self {
+        Self {
+            sequence,
+            is_keyframe,
+            mappings: HashMap::new(),
+        }
+    }
+
+    /// Position of this delta in its stream; consecutive deltas have consecutive sequences
+    pub fn get_sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    pub fn is_keyframe(&self) -> bool {
+        self.is_keyframe
+    }
+
+    pub(crate) fn set_header(&mut self, sequence: u32, is_keyframe: bool) {
+        self.sequence = sequence;
+        self.is_keyframe = is_keyframe;
+    }
+
+    /// Total number of upserted and removed voxels over all areas
+    pub fn number_of_changes(&self) -> usize {
+        self.mappings
+            .values()
+            .map(|delta| delta.upserted.len() + delta.removed.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mappings.values().all(|delta| delta.is_empty())
+    }
+
+    /// Removes all area changes while keeping the allocated voxel arrays
+    pub fn clear_changes_only(&mut self) {
+        for delta in self.mappings.values_mut() {
+            delta.clear();
+        }
+    }
+
+    /// Returns the (cleared) change set of an area, creating it if necessary
+    pub fn ensure_clear_and_borrow_mut(
+        &mut self,
+        cortical_id: &CorticalID,
+    ) -> &mut NeuronVoxelXYZPDelta {
+        let delta = self.mappings.entry(*cortical_id).or_default();
+        delta.clear();
+        delta
+    }
+}
+
+type AreaVoxels = HashMap<VoxelCoordinate, f32>;
+
+/// Collects the voxels of every non-empty area of a frame, the last duplicate coordinate winning
+fn index_frame(frame: &CorticalMappedXYZPNeuronVoxels) -> HashMap<CorticalID, AreaVoxels> {
+    let mut indexed = HashMap::with_capacity(frame.mappings.len());
+    for (cortical_id, neurons) in &frame.mappings {
+        if neurons.is_empty() {
+            continue;
+        }
+        let (x, y, z, p) = neurons.borrow_xyzp_vectors();
+        let mut voxels: AreaVoxels = HashMap::with_capacity(neurons.len());
+        for i in 0..neurons.len() {
+            voxels.insert((x[i], y[i], z[i]), p[i]);
+        }
+        indexed.insert(*cortical_id, voxels);
+    }
+    indexed
+}
+
+/// Result of delta coding a serialized frame with [`XYZPDeltaEncoder::try_encode_from_container`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XYZPDeltaContainerEncoding {
+    /// The container holds no XYZP frame; send it unchanged
+    NotXYZP,
+    /// Nothing changed and no keyframe is due; nothing needs to be sent
+    ///
+    /// Never returned by encoders built with
+    /// [`XYZPDeltaEncoder::with_empty_deltas_for_unchanged_frames`].
+    Unchanged,
+    /// The output container holds the delta to send
+    Encoded,
+}
+
+/// Turns consecutive full XYZP frames of one stream into deltas.
+///
+/// One encoder must be used per receiving stream, since each delta is relative to the frame
+/// last encoded by the same encoder.
+#[derive(Debug, Clone)]
+pub struct XYZPDeltaEncoder {
+    keyframe_interval: u32,
+    frames_since_keyframe: u32,
+    next_sequence: u32,
+    keyframe_requested: bool,
+    empty_deltas_for_unchanged_frames: bool,
+    previous: HashMap<CorticalID, AreaVoxels>,
+    received_frame: CorticalMappedXYZPNeuronVoxels,
+}
+
+impl XYZPDeltaEncoder {
+    /// Keyframe interval used when none is configured, in frames
+    pub const DEFAULT_KEYFRAME_INTERVAL: u32 = 30;
+
+    /// Creates an encoder that emits a keyframe every `keyframe_interval` frames
+    pub fn new(keyframe_interval: u32) -> Result<Self, FeagiDataError> {
+        if keyframe_interval == 0 {
+            return Err(FeagiDataError::BadParameters(
+                "Delta keyframe interval must be at least 1 frame!".into(),
+            ));
+        }
+        Ok(Self {
+            keyframe_interval,
+            frames_since_keyframe: 0,
+            next_sequence: 0,
+            keyframe_requested: true,
+            empty_deltas_for_unchanged_frames: false,
+            previous: HashMap::new(),
+            received_frame: CorticalMappedXYZPNeuronVoxels::new(),
+        })
+    }
+
+    /// Emits an empty delta instead of nothing for unchanged frames.
+    ///
+    /// Used for streams where silence means something to the receiver, such as motor streams
+    /// whose watchdogs treat a silent FEAGI as lost; the receiver sees the held frame again.
+    pub fn with_empty_deltas_for_unchanged_frames(mut self) -> Self {
+        self.empty_deltas_for_unchanged_frames = true;
+        self
+    }
+
+    pub fn get_keyframe_interval(&self) -> u32 {
+        self.keyframe_interval
+    }
+
+    /// Forces the next encoded frame to be a keyframe (e.g. when a new receiver subscribes)
+    pub fn request_keyframe(&mut self) {
+        self.keyframe_requested = true;
+    }
+
+    /// Encodes `frame` relative to the previously encoded frame.
+    ///
+    /// Returns `None` if nothing changed and no keyframe is due; nothing needs to be sent then,
+    /// and the stream sequence does not advance. Encoders built with
+    /// [`with_empty_deltas_for_unchanged_frames`](Self::with_empty_deltas_for_unchanged_frames)
+    /// return an empty delta instead. Frames count towards the keyframe interval whether or not
+    /// they produced a delta.
+    pub fn encode(
+        &mut self,
+        frame: &CorticalMappedXYZPNeuronVoxels,
+    ) -> Option<CorticalMappedXYZPNeuronVoxelsDelta> {
+        let is_keyframe =
+            self.keyframe_requested || self.frames_since_keyframe + 1 >= self.keyframe_interval;
+        let current = index_frame(frame);
+        let mut delta = CorticalMappedXYZPNeuronVoxelsDelta::new(self.next_sequence, is_keyframe);
+
+        if is_keyframe {
+            for (cortical_id, voxels) in &current {
+                let area_delta = delta.ensure_clear_and_borrow_mut(cortical_id);
+                area_delta.upserted.ensure_capacity(voxels.len());
+                for (&(x, y, z), &p) in voxels {
+                    area_delta.upserted.push_raw(x, y, z, p);
+                }
+            }
+        } else {
+            for (cortical_id, voxels) in &current {
+                let previous_voxels = self.previous.get(cortical_id);
+                let mut area_delta = NeuronVoxelXYZPDelta::new();
+                for (&(x, y, z), &p) in voxels {
+                    let unchanged = previous_voxels
+                        .and_then(|previous| previous.get(&(x, y, z)))
+                        .is_some_and(|previous_p| previous_p.to_bits() == p.to_bits());
+                    if !unchanged {
+                        area_delta.upserted.push_raw(x, y, z, p);
+                    }
+                }
+                if let Some(previous_voxels) = previous_voxels {
+                    area_delta.removed.extend(
+                        previous_voxels
+                            .keys()
+                            .filter(|coordinate| !voxels.contains_key(coordinate)),
+                    );
+                }
+                if !area_delta.is_empty() {
+                    delta.mappings.insert(*cortical_id, area_delta);
+                }
+            }
+            for (cortical_id, previous_voxels) in &self.previous {
+                if !current.contains_key(cortical_id) {
+                    let area_delta = delta.ensure_clear_and_borrow_mut(cortical_id);
+                    area_delta.removed.extend(previous_voxels.keys());
+                }
+            }
+        }
+
+        self.previous = current;
+        if is_keyframe {
+            self.keyframe_requested = false;
+            self.frames_since_keyframe = 0;
+        } else {
+            self.frames_since_keyframe += 1;
+            if delta.is_empty() && !self.empty_deltas_for_unchanged_frames {
+                return None;
+            }
+        }
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        Some(delta)
+    }
+
+    /// Delta-codes the first full XYZP structure in `container` into `output`.
+    ///
+    /// The increment counter of `container` is carried over to `output`. Nothing is written to
+    /// `output` unless [`XYZPDeltaContainerEncoding::Encoded`] is returned.
+    pub fn try_encode_from_container(
+        &mut self,
+        container: &FeagiByteContainer,
+        output: &mut FeagiByteContainer,
+    ) -> Result<XYZPDeltaContainerEncoding, FeagiDataError> {
+        if !container.try_update_struct_from_first_found_struct_of_type(&mut self.received_frame)? {
+            return Ok(XYZPDeltaContainerEncoding::NotXYZP);
+        }
+        let frame = std::mem::take(&mut self.received_frame);
+        let delta = self.encode(&frame);
+        self.received_frame = frame;
+        let Some(delta) = delta else {
+            return Ok(XYZPDeltaContainerEncoding::Unchanged);
+        };
+        output.overwrite_byte_data_with_single_struct_data(
+            &delta,
+            container.get_increment_counter()?,
+        )?;
+        Ok(XYZPDeltaContainerEncoding::Encoded)
+    }
+}
+
+impl Default for XYZPDeltaEncoder {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_KEYFRAME_INTERVAL).unwrap()
+    }
+}
+
+/// Reconstructs full XYZP frames from a stream of deltas.
+///
+/// Until the first keyframe arrives, and after a missed delta is detected, deltas are dropped
+/// and the reconstructed frame is left untouched. Voxel order within an area is not preserved.
+#[derive(Debug, Clone, Default)]
+pub struct XYZPDeltaDecoder {
+    state: HashMap<CorticalID, AreaVoxels>,
+    last_sequence: Option<u32>,
+    received_delta: CorticalMappedXYZPNeuronVoxelsDelta,
+}
+
+impl XYZPDeltaDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True once a keyframe was applied and no delta has been missed since
+    pub fn is_synchronized(&self) -> bool {
+        self.last_sequence.is_some()
+    }
+
+    /// Forgets all state; the next keyframe resynchronizes the decoder
+    pub fn reset(&mut self) {
+        self.state.clear();
+        self.last_sequence = None;
+    }
+
+    /// Applies a delta to the reconstructed `frame`.
+    ///
+    /// Returns true if `frame` was updated, false if the delta was dropped because the decoder
+    /// is waiting for a keyframe.
+    pub fn apply(
+        &mut self,
+        delta: &CorticalMappedXYZPNeuronVoxelsDelta,
+        frame: &mut CorticalMappedXYZPNeuronVoxels,
+    ) -> bool {
+        if delta.is_keyframe() {
+            self.state.clear();
+            frame.clear();
+        } else {
+            match self.last_sequence {
+                Some(last_sequence) if delta.get_sequence() == last_sequence.wrapping_add(1) => {}
+                _ => {
+                    // Missed a delta (or never got a keyframe); wait for the next keyframe
+                    self.reset();
+                    return false;
+                }
+            }
+        }
+        self.last_sequence = Some(delta.get_sequence());
+
+        for (cortical_id, area_delta) in &delta.mappings {
+            if area_delta.is_empty() {
+                continue;
+            }
+            let voxels = self.state.entry(*cortical_id).or_default();
+            for coordinate in &area_delta.removed {
+                voxels.remove(coordinate);
+            }
+            let (x, y, z, p) = area_delta.upserted.borrow_xyzp_vectors();
+            for i in 0..area_delta.upserted.len() {
+                voxels.insert((x[i], y[i], z[i]), p[i]);
+            }
+
+            if voxels.is_empty() {
+                self.state.remove(cortical_id);
+                frame.remove(*cortical_id);
+                continue;
+            }
+            let neurons = frame.ensure_clear_and_borrow_mut(cortical_id);
+            neurons.ensure_capacity(voxels.len());
+            for (&(x, y, z), &p) in voxels.iter() {
+                neurons.push_raw(x, y, z, p);
+            }
+        }
+        true
+    }
+
+    /// Updates `frame` from the first XYZP structure in `container`, delta-coded or full.
+    ///
+    /// A full frame replaces `frame` and desynchronizes the decoder, since following deltas
+    /// cannot be related to it. Returns true if `frame` was updated.
+    pub fn try_apply_from_container(
+        &mut self,
+        container: &FeagiByteContainer,
+        frame: &mut CorticalMappedXYZPNeuronVoxels,
+    ) -> Result<bool, FeagiDataError> {
+        if container.try_update_struct_from_first_found_struct_of_type(&mut self.received_delta)? {
+            let delta = std::mem::take(&mut self.received_delta);
+            let applied = self.apply(&delta, frame);
+            self.received_delta = delta;
+            return Ok(applied);
+        }
+        if container.try_update_struct_from_first_found_struct_of_type(frame)? {
+            self.reset();
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
@@ -3,7 +3,11 @@
 //! This module tests the serialization and deserialization of neuron data
 //! using the FeagiByteContainer format.
 
-use feagi_serialization::{FeagiByteContainer, FeagiByteStructureType};
+use feagi_serialization::{
+    payload_compression, CorticalMappedXYZPNeuronVoxelsDelta, FeagiByteContainer,
+    FeagiByteStructureType, PayloadCompression, PayloadCompressor, XYZPDeltaDecoder,
+    XYZPDeltaEncoder,
+};
 use feagi_structures::genomic::cortical_area::descriptors::{
     CorticalAreaDimensions, CorticalUnitIndex,
 };
@@ -470,3 +474,240 @@ fn test_byte_container_memory_efficiency() {
         byte_container.get_number_of_bytes_used()
     );
 }
+
+#[test]
+fn test_compressed_payload_round_trip() {
+    let source_neurons = sample_cortical_mapped_neurons(
+        CorticalAreaDimensions::new(32, 32, 4).unwrap(),
+        CoreCorticalType::Power.to_cortical_id(),
+    );
+    let mut sent = FeagiByteContainer::new_empty();
+    sent.overwrite_byte_data_with_single_struct_data(&source_neurons, 7)
+        .unwrap();
+    let raw = sent.get_byte_ref();
+
+    for algorithm in PayloadCompression::SUPPORTED {
+        let mut compressor = PayloadCompressor::new(algorithm, 100);
+        let payload = compressor.compress(raw).unwrap().to_vec();
+        assert!(payload_compression::is_compressed_payload(&payload));
+        assert!(payload.len() < raw.len(), "{} did not shrink", algorithm);
+
+        let mut received = FeagiByteContainer::new_empty();
+        let arrived_with = received
+            .try_write_possibly_compressed_data_by_copy_and_verify(&payload)
+            .unwrap();
+        assert_eq!(arrived_with, algorithm);
+        assert_eq!(received.get_byte_ref(), raw);
+        assert_eq!(received.get_increment_counter().unwrap(), 7);
+        let decoded: CorticalMappedXYZPNeuronVoxels = received
+            .try_create_new_struct_from_index(0)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(decoded, source_neurons);
+    }
+}
+
+#[test]
+fn test_compressed_payload_passthrough_and_corruption() {
+    let source_neurons = sample_cortical_mapped_neurons(
+        CorticalAreaDimensions::new(16, 16, 1).unwrap(),
+        CoreCorticalType::Death.to_cortical_id(),
+    );
+    let mut sent = FeagiByteContainer::new_empty();
+    sent.overwrite_byte_data_with_single_struct_data(&source_neurons, 0)
+        .unwrap();
+    let raw = sent.get_byte_ref();
+
+    // Disabled, or below the threshold: the raw container goes out and is still accepted
+    let mut disabled = PayloadCompressor::disabled();
+    assert_eq!(disabled.compress(raw).unwrap(), raw);
+    let mut high_threshold = PayloadCompressor::new(PayloadCompression::Zstd, raw.len() + 1);
+    assert_eq!(high_threshold.compress(raw).unwrap(), raw);
+    let mut received = FeagiByteContainer::new_empty();
+    assert_eq!(
+        received
+            .try_write_possibly_compressed_data_by_copy_and_verify(raw)
+            .unwrap(),
+        PayloadCompression::None
+    );
+
+    // A lying length header or truncated body is rejected and leaves the container invalid
+    let mut payload = Vec::new();
+    payload_compression::compress_payload_into(raw, PayloadCompression::Lz4, &mut payload).unwrap();
+    let mut wrong_length = payload.clone();
+    wrong_length[2] = wrong_length[2].wrapping_add(1);
+    assert!(received
+        .try_write_possibly_compressed_data_by_copy_and_verify(&wrong_length)
+        .is_err());
+    assert!(!received.is_valid());
+    assert!(received
+        .try_write_possibly_compressed_data_by_copy_and_verify(&payload[..payload.len() / 2])
+        .is_err());
+
+    // A tiny frame claiming a huge payload is refused before anything is allocated
+    let mut bomb =
+        payload[..payload_compression::COMPRESSED_PAYLOAD_HEADER_BYTE_COUNT + 4].to_vec();
+    bomb[2..6].copy_from_slice(&(64u32 * 1024 * 1024).to_le_bytes());
+    assert!(received
+        .try_write_possibly_compressed_data_by_copy_and_verify(&bomb)
+        .is_err());
+    let mut inflated = Vec::new();
+    assert!(payload_compression::decompress_payload_into(&bomb, &mut inflated).is_err());
+    assert_eq!(inflated.capacity(), 0);
+
+    // Receivers can cap the inflated size below the global maximum
+    assert!(received
+        .try_write_possibly_compressed_data_by_copy_and_verify_with_limit(&payload, raw.len() - 1)
+        .is_err());
+    assert_eq!(
+        received
+            .try_write_possibly_compressed_data_by_copy_and_verify_with_limit(&payload, raw.len())
+            .unwrap(),
+        PayloadCompression::Lz4
+    );
+
+    // Plain loading does not silently accept compressed payloads
+    assert!(received
+        .try_write_data_by_copy_and_verify(&payload)
+        .is_err());
+}
+
+fn voxel_set(neurons: &CorticalMappedXYZPNeuronVoxels) -> Vec<(CorticalID, u32, u32, u32, u32)> {
+    let mut voxels: Vec<_> = neurons
+        .mappings
+        .iter()
+        .flat_map(|(cortical_id, arrays)| {
+            arrays.iter().map(move |neuron| {
+                let (x, y, z, p) = neuron.as_tuple();
+                (*cortical_id, x, y, z, p.to_bits())
+            })
+        })
+        .collect();
+    voxels.sort_by_key(|(id, x, y, z, p)| (id.as_base_64(), *x, *y, *z, *p));
+    voxels
+}
+
+#[test]
+fn test_delta_xyzp_stream_reconstruction() {
+    let power = CoreCorticalType::Power.to_cortical_id();
+    let death = CoreCorticalType::Death.to_cortical_id();
+    let frame_of = |power_voxels: &[(u32, f32)], death_voxels: &[(u32, f32)]| {
+        let mut frame = CorticalMappedXYZPNeuronVoxels::new();
+        for (cortical_id, voxels) in [(power, power_voxels), (death, death_voxels)] {
+            if voxels.is_empty() {
+                continue;
+            }
+            let mut arrays = NeuronVoxelXYZPArrays::new();
+            for (x, p) in voxels {
+                arrays.push_raw(*x, 0, 0, *p);
+            }
+            frame.insert(cortical_id, arrays);
+        }
+        frame
+    };
+    let frames = [
+        frame_of(&[(0, 1.0), (1, 1.0)], &[(5, 0.5)]),
+        frame_of(&[(0, 1.0), (1, 1.0)], &[(5, 0.5)]), // unchanged, nothing sent
+        frame_of(&[(0, 1.0), (2, 1.0)], &[(5, 0.75)]), // one swapped, one potential change
+        frame_of(&[(0, 1.0), (2, 1.0)], &[]),         // area stops firing
+        frame_of(&[], &[(7, 1.0)]),
+        frame_of(&[(3, 1.0)], &[(7, 1.0)]), // fourth frame since the keyframe
+    ];
+
+    let mut encoder = XYZPDeltaEncoder::new(5).unwrap();
+    let mut decoder = XYZPDeltaDecoder::new();
+    let mut reconstructed = CorticalMappedXYZPNeuronVoxels::new();
+    let mut container = FeagiByteContainer::new_empty();
+    let mut sent = Vec::new();
+    for (index, frame) in frames.iter().enumerate() {
+        let Some(delta) = encoder.encode(frame) else {
+            assert_eq!(index, 1, "only the unchanged frame is skipped");
+            continue;
+        };
+        assert_eq!(delta.is_keyframe(), index == 0 || index == 5);
+        if index == 2 {
+            // 1 added, 1 removed in power; 1 potential update in death
+            assert_eq!(delta.number_of_changes(), 3);
+        }
+
+        container
+            .overwrite_byte_data_with_single_struct_data(&delta, index as u16)
+            .unwrap();
+        assert_eq!(
+            container.get_contained_struct_types(),
+            vec![FeagiByteStructureType::NeuronCategoricalXYZPDelta]
+        );
+        let round_tripped: CorticalMappedXYZPNeuronVoxelsDelta = container
+            .try_create_new_struct_from_index(0)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(round_tripped, delta);
+        sent.push(container.get_byte_ref().to_vec());
+
+        assert!(decoder
+            .try_apply_from_container(&container, &mut reconstructed)
+            .unwrap());
+        assert_eq!(voxel_set(&reconstructed), voxel_set(frame));
+    }
+
+    // A receiver that joins late or drops a delta waits for the next keyframe
+    let mut late_decoder = XYZPDeltaDecoder::new();
+    let mut late_frame = CorticalMappedXYZPNeuronVoxels::new();
+    for bytes in &sent[2..] {
+        container.try_write_data_by_copy_and_verify(bytes).unwrap();
+        let updated = late_decoder
+            .try_apply_from_container(&container, &mut late_frame)
+            .unwrap();
+        let delta: CorticalMappedXYZPNeuronVoxelsDelta = container
+            .try_create_new_struct_from_index(0)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(updated, delta.is_keyframe());
+        assert_eq!(late_decoder.is_synchronized(), delta.is_keyframe());
+    }
+    assert_eq!(voxel_set(&late_frame), voxel_set(&frames[5]));
+
+    // Full frames are still accepted and replace the reconstruction
+    container
+        .overwrite_byte_data_with_single_struct_data(&frames[0], 0)
+        .unwrap();
+    assert!(late_decoder
+        .try_apply_from_container(&container, &mut late_frame)
+        .unwrap());
+    assert!(!late_decoder.is_synchronized());
+    assert_eq!(voxel_set(&late_frame), voxel_set(&frames[0]));
+}
+
+#[test]
+fn test_delta_xyzp_empty_deltas_keep_receiver_in_sync() {
+    let mut frame = CorticalMappedXYZPNeuronVoxels::new();
+    let mut arrays = NeuronVoxelXYZPArrays::new();
+    arrays.push_raw(1, 0, 0, 1.0);
+    frame.insert(CoreCorticalType::Power.to_cortical_id(), arrays);
+
+    let mut encoder = XYZPDeltaEncoder::new(30)
+        .unwrap()
+        .with_empty_deltas_for_unchanged_frames();
+    let mut decoder = XYZPDeltaDecoder::new();
+    let mut reconstructed = CorticalMappedXYZPNeuronVoxels::new();
+    let mut container = FeagiByteContainer::new_empty();
+    for index in 0..3u16 {
+        let delta = encoder
+            .encode(&frame)
+            .expect("unchanged frames still send a delta");
+        assert_eq!(delta.is_keyframe(), index == 0);
+        assert_eq!(delta.is_empty(), index > 0);
+        container
+            .overwrite_byte_data_with_single_struct_data(&delta, index)
+            .unwrap();
+        // The held frame is handed to the receiver again
+        assert!(decoder
+            .try_apply_from_container(&container, &mut reconstructed)
+            .unwrap());
+        assert!(decoder.is_synchronized());
+        assert_eq!(voxel_set(&reconstructed), voxel_set(&frame));
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compile a saved connectome into a static image for the embedded runtime
+//!
+//! ```text
+//! cargo run -p feagi-services --features connectome-serialization \
+//!     --example compile_embedded_connectome -- brain.connectome brain.femb [max_neurons] [max_synapses]
+//! ```
+//!
+//! Capacities default to the `EmbeddedRuntime` array sizes. Embed the output
+//! in firmware with `include_bytes!` and load it with `EmbeddedImage`.
+
+use feagi_npu_runtime::embedded_impl::image::compile_image;
+use feagi_npu_runtime::embedded_impl::EmbeddedImage;
+use feagi_services::connectome::load_connectome;
+use std::process::ExitCode;
+
+/// `EmbeddedRuntime` neuron capacity
+const DEFAULT_MAX_NEURONS: usize = 10_000;
+/// `EmbeddedRuntime` synapse capacity
+const DEFAULT_MAX_SYNAPSES: usize = 50_000;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 3 {
+        eprintln!(
+            "usage: {} <input.connectome> <output.femb> [max_neurons] [max_synapses]",
+            args[0]
+        );
+        return ExitCode::FAILURE;
+    }
+    let parse_limit = |idx: usize, default: usize| {
+        args.get(idx)
+            .map(|value| value.parse::<usize>().expect("limit must be a number"))
+            .unwrap_or(default)
+    };
+    let max_neurons = parse_limit(3, DEFAULT_MAX_NEURONS);
+    let max_synapses = parse_limit(4, DEFAULT_MAX_SYNAPSES);
+
+    let snapshot = match load_connectome(&args[1]) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            eprintln!("❌ Failed to load {}: {}", args[1], e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let blob = match compile_image(&snapshot, max_neurons, max_synapses) {
+        Ok(blob) => blob,
+        Err(e) => {
+            eprintln!("❌ Failed to compile image: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = std::fs::write(&args[2], &blob) {
+        eprintln!("❌ Failed to write {}: {}", args[2], e);
+        return ExitCode::FAILURE;
+    }
+
+    let image = EmbeddedImage::parse(&blob).expect("freshly compiled image is valid");
+    println!(
+        "✓ {} neurons, {} synapses, {} bytes → {}",
+        image.neuron_count(),
+        image.synapse_count(),
+        image.len(),
+        args[2]
+    );
+    ExitCode::SUCCESS
+}
@@ -8,6 +8,8 @@
 //!
 //! This module provides:
 //! - File I/O (`save_connectome`, `load_connectome`)
+//! - Memory-mapped synapses (`save_connectome_mappable`, `open_connectome_mapped`,
+//!   behind the `connectome-mmap` feature) for connectomes larger than RAM
 //! - Future: Network transport (ZMQ, WebSocket) for connectome transfer
 //!
 //! ## Usage
@@ -55,6 +57,12 @@ pub enum ConnectomeError {
 
     #[error("Compression error: {0}")]
     Compression(String),
+
+    #[error("Synapse image error: {0}")]
+    SynapseImage(String),
+
+    #[error("NPU error: {0}")]
+    Npu(String),
 }
 
 pub type Result<T> = std::result::Result<T, ConnectomeError>;
@@ -65,7 +73,9 @@ const MAGIC: &[u8; 5] = b"FEAGI";
 /// Current format version (increment when format changes)
 /// Version 1: Original format without compression
 /// Version 2: Added flags byte for compression support
-const FORMAT_VERSION: u32 = 2;
+/// Version 3: Neurons carry threshold limits, consecutive fire limits, snooze periods and
+/// charge accumulation flags
+const FORMAT_VERSION: u32 = 3;
 
 /// Save a connectome to a file with optional LZ4 compression
 ///
@@ -78,61 +88,276 @@ const FORMAT_VERSION: u32 = 2;
 /// [Header]
 /// - Magic: "FEAGI" (5 bytes)
 /// - Version: u32 (4 bytes)
-/// - Flags: u8 (1 byte) - bit 0: compressed
+/// - Flags: u8 (1 byte) - bit 0: compressed, bit 1: synapse image appended
 /// - Uncompressed Size: u64 (8 bytes, original size before compression)
 /// - Checksum: u64 (8 bytes, CRC64 of data)
+/// - Data Length: u64 (8 bytes, only present when bit 1 is set)
+/// - Image Offset: u64 (8 bytes, file offset of the synapse image, only present when bit 1 is set)
 /// [Data]
 /// - Bincode-serialized ConnectomeSnapshot (optionally LZ4 compressed)
+/// [Synapse Image] (only when bit 1 is set, see `save_connectome_mappable`)
 /// ```
 pub fn save_connectome<P: AsRef<Path>>(snapshot: &ConnectomeSnapshot, path: P) -> Result<()> {
     let mut file = File::create(path)?;
+    let (data, flags, uncompressed_size) = encode_payload(snapshot)?;
+    write_header(&mut file, flags, uncompressed_size, &data)?;
+
+    // Write data
+    file.write_all(&data)?;
+
+    Ok(())
+}
+
+/// Save a connectome whose synapses can be memory-mapped by `open_connectome_mapped`
+///
+/// Neurons and metadata are stored as in `save_connectome`; synapses are
+/// written uncompressed as a page-aligned synapse image after them, sorted by
+/// source neuron (synapse indices are renumbered). The checksum covers the
+/// serialized data only, so opening never has to read the whole image.
+///
+/// Files written this way can still be fully loaded with `load_connectome`.
+#[cfg(feature = "connectome-mmap")]
+pub fn save_connectome_mappable<P: AsRef<Path>>(
+    snapshot: &ConnectomeSnapshot,
+    path: P,
+) -> Result<()> {
+    use feagi_npu_neural::types::connectome::SerializableSynapseArray;
+    use feagi_npu_runtime::std_impl::mmap_synapse_array::{
+        align_up, page_size, write_synapse_image, SynapseColumns,
+    };
+
+    // Synapses travel in the image, not in the serialized data
+    let without_synapses = ConnectomeSnapshot {
+        version: snapshot.version,
+        neurons: snapshot.neurons.clone(),
+        synapses: SerializableSynapseArray::default(),
+        cortical_area_names: snapshot.cortical_area_names.clone(),
+        burst_count: snapshot.burst_count,
+        power_amount: snapshot.power_amount,
+        fire_ledger_window: snapshot.fire_ledger_window,
+        metadata: snapshot.metadata.clone(),
+    };
+    let (data, flags, uncompressed_size) = encode_payload(&without_synapses)?;
+
+    let mut file = std::io::BufWriter::new(File::create(path)?);
+    write_header(
+        &mut file,
+        flags | FLAG_SYNAPSE_IMAGE,
+        uncompressed_size,
+        &data,
+    )?;
+    let data_end = (HEADER_LEN + 16 + data.len()) as u64;
+    let image_offset = align_up(data_end, page_size());
+    file.write_all(&(data.len() as u64).to_le_bytes())?;
+    file.write_all(&image_offset.to_le_bytes())?;
+    file.write_all(&data)?;
+
+    file.write_all(&vec![0u8; (image_offset - data_end) as usize])?;
+
+    let synapses = &snapshot.synapses;
+    let columns = SynapseColumns {
+        source_neurons: &synapses.source_neurons,
+        target_neurons: &synapses.target_neurons,
+        weights: &synapses.weights,
+        postsynaptic_potentials: &synapses.postsynaptic_potentials,
+        types: &synapses.types,
+        valid_mask: &synapses.valid_mask,
+    };
+    write_synapse_image(&mut file, &columns, synapses.capacity)?;
+    file.flush()?;
+
+    Ok(())
+}
+
+/// A connectome opened with memory-mapped synapses
+#[cfg(feature = "connectome-mmap")]
+pub struct MappedConnectome {
+    /// Everything except synapses (`snapshot.synapses` is empty)
+    pub snapshot: ConnectomeSnapshot,
+    /// Runtime whose synapse storage maps this file's synapse image
+    pub runtime: feagi_npu_runtime::MmapRuntime,
+}
+
+#[cfg(feature = "connectome-mmap")]
+impl MappedConnectome {
+    /// Map the synapse image directly (the runtime does the same on demand)
+    pub fn synapses(&self) -> Result<feagi_npu_runtime::MmapSynapseArray> {
+        feagi_npu_runtime::MmapSynapseArray::open_at(
+            self.runtime.image_path(),
+            self.runtime.image_offset(),
+        )
+        .map_err(|e| ConnectomeError::SynapseImage(e.to_string()))
+    }
+
+    /// Build an NPU whose synapses are served by this file's synapse image
+    ///
+    /// Neurons are restored at their saved IDs; the NPU never loads the
+    /// synapses into RAM and looks up outgoing rows in the image itself.
+    pub fn into_npu<B>(
+        self,
+        backend: B,
+    ) -> Result<feagi_npu_burst_engine::RustNPU<feagi_npu_runtime::MmapRuntime, f32, B>>
+    where
+        B: feagi_npu_burst_engine::backend::ComputeBackend<
+            f32,
+            feagi_npu_runtime::StdNeuronArray<f32>,
+            feagi_npu_runtime::MmapSynapseArray,
+        >,
+    {
+        self.into_npu_with_backend_config(
+            backend,
+            &feagi_npu_burst_engine::backend::BackendConfig::default(),
+        )
+    }
+
+    /// Like [`Self::into_npu`], with the burst threads configured in `backend_config`
+    pub fn into_npu_with_backend_config<B>(
+        self,
+        backend: B,
+        backend_config: &feagi_npu_burst_engine::backend::BackendConfig,
+    ) -> Result<feagi_npu_burst_engine::RustNPU<feagi_npu_runtime::MmapRuntime, f32, B>>
+    where
+        B: feagi_npu_burst_engine::backend::ComputeBackend<
+            f32,
+            feagi_npu_runtime::StdNeuronArray<f32>,
+            feagi_npu_runtime::MmapSynapseArray,
+        >,
+    {
+        let neuron_capacity = self
+            .snapshot
+            .neurons
+            .capacity
+            .max(self.snapshot.neurons.count);
+        let mut npu = feagi_npu_burst_engine::RustNPU::new_with_backend_config(
+            self.runtime,
+            backend,
+            neuron_capacity,
+            0, // The image fixes the synapse capacity
+            self.snapshot.fire_ledger_window,
+            backend_config,
+        )
+        .map_err(|e| ConnectomeError::Npu(e.to_string()))?;
+        npu.restore_neurons(&self.snapshot)
+            .map_err(|e| ConnectomeError::Npu(e.to_string()))?;
+        Ok(npu)
+    }
+}
+
+/// Open a connectome saved with `save_connectome_mappable` without loading its synapses
+///
+/// Neurons and metadata are loaded; synapses stay on disk and are served by
+/// the returned runtime's memory-mapped synapse storage.
+#[cfg(feature = "connectome-mmap")]
+pub fn open_connectome_mapped<P: AsRef<Path>>(path: P) -> Result<MappedConnectome> {
+    let path = path.as_ref();
+    let mut file = File::open(path)?;
+    let header = read_header(&mut file)?;
+    let Some(image_offset) = header.image_offset else {
+        return Err(ConnectomeError::SynapseImage(
+            "file has no synapse image; save it with save_connectome_mappable".to_string(),
+        ));
+    };
+    let snapshot = read_payload(&mut file, &header)?;
+
+    Ok(MappedConnectome {
+        snapshot,
+        runtime: feagi_npu_runtime::MmapRuntime::new(path, image_offset),
+    })
+}
+
+/// Load a connectome from a file with automatic LZ4 decompression
+///
+/// # Arguments
+/// * `path` - File path to read from
+///
+/// # Returns
+/// The deserialized connectome snapshot
+pub fn load_connectome<P: AsRef<Path>>(path: P) -> Result<ConnectomeSnapshot> {
+    let path = path.as_ref();
+    let mut file = File::open(path)?;
+    let header = read_header(&mut file)?;
+    #[allow(unused_mut)]
+    let mut snapshot = read_payload(&mut file, &header)?;
+
+    if let Some(_image_offset) = header.image_offset {
+        #[cfg(feature = "connectome-mmap")]
+        {
+            snapshot.synapses = read_synapse_image(path, _image_offset)?;
+        }
+        #[cfg(not(feature = "connectome-mmap"))]
+        {
+            return Err(ConnectomeError::SynapseImage(
+                "File has a synapse image but connectome-mmap feature is not enabled".to_string(),
+            ));
+        }
+    }
+
+    Ok(snapshot)
+}
 
-    // Write header
-    file.write_all(MAGIC)?;
-    file.write_all(&FORMAT_VERSION.to_le_bytes())?;
+/// Size of the fixed header (magic, version, flags, uncompressed size, checksum)
+#[cfg(feature = "connectome-mmap")]
+const HEADER_LEN: usize = 5 + 4 + 1 + 8 + 8;
+
+/// Flag bit 0: data is LZ4 compressed
+const FLAG_COMPRESSED: u8 = 1;
+
+/// Flag bit 1: synapses are stored in a page-aligned synapse image after the data
+const FLAG_SYNAPSE_IMAGE: u8 = 2;
+
+struct FileHeader {
+    version: u32,
+    is_compressed: bool,
+    uncompressed_size: usize,
+    checksum: u64,
+    /// Length of the serialized data when a synapse image follows it
+    data_len: Option<u64>,
+    /// File offset of the synapse image, if any
+    image_offset: Option<u64>,
+}
 
+/// Serialize (and compress, if enabled) a snapshot
+fn encode_payload(snapshot: &ConnectomeSnapshot) -> Result<(Vec<u8>, u8, u64)> {
     // Serialize data
     let data =
         bincode::serialize(snapshot).map_err(|e| ConnectomeError::Serialization(e.to_string()))?;
 
     // Compress if feature enabled
     #[cfg(feature = "connectome-compression")]
-    let (final_data, flags, uncompressed_size) = {
+    {
         let original_size = data.len();
         let compressed = lz4::block::compress(&data, None, false)
             .map_err(|e| ConnectomeError::Compression(e.to_string()))?;
-        (compressed, 1u8, original_size as u64) // Flag bit 0 = compressed
-    };
+        Ok((compressed, FLAG_COMPRESSED, original_size as u64))
+    }
 
     #[cfg(not(feature = "connectome-compression"))]
-    let (final_data, flags, uncompressed_size) = (data, 0u8, 0u64);
+    Ok((data, 0u8, 0u64))
+}
+
+fn write_header<W: Write>(
+    writer: &mut W,
+    flags: u8,
+    uncompressed_size: u64,
+    data: &[u8],
+) -> Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
 
     // Write flags
-    file.write_all(&[flags])?;
+    writer.write_all(&[flags])?;
 
     // Write uncompressed size (only meaningful if compressed)
-    file.write_all(&uncompressed_size.to_le_bytes())?;
+    writer.write_all(&uncompressed_size.to_le_bytes())?;
 
     // Calculate checksum
-    let checksum = calculate_checksum(&final_data);
-    file.write_all(&checksum.to_le_bytes())?;
-
-    // Write data
-    file.write_all(&final_data)?;
+    let checksum = calculate_checksum(data);
+    writer.write_all(&checksum.to_le_bytes())?;
 
     Ok(())
 }
 
-/// Load a connectome from a file with automatic LZ4 decompression
-///
-/// # Arguments
-/// * `path` - File path to read from
-///
-/// # Returns
-/// The deserialized connectome snapshot
-pub fn load_connectome<P: AsRef<Path>>(path: P) -> Result<ConnectomeSnapshot> {
-    let mut file = File::open(path)?;
-
+fn read_header(file: &mut File) -> Result<FileHeader> {
     // Read and verify magic number
     let mut magic = [0u8; 5];
     file.read_exact(&mut magic)?;
@@ -145,50 +370,81 @@ pub fn load_connectome<P: AsRef<Path>>(path: P) -> Result<ConnectomeSnapshot> {
     file.read_exact(&mut version_bytes)?;
     let version = u32::from_le_bytes(version_bytes);
 
-    // Support version 1 (no compression) and version 2 (with compression)
-    if version != 1 && version != 2 {
+    // Support version 1 (no compression), version 2 (with compression) and version 3
+    if !(1..=FORMAT_VERSION).contains(&version) {
         return Err(ConnectomeError::VersionMismatch {
             file_version: version,
             expected_version: FORMAT_VERSION,
         });
     }
 
-    // Read flags (only in version 2)
-    let (is_compressed, uncompressed_size) = if version == 2 {
+    // Read flags (since version 2)
+    let (flags, uncompressed_size) = if version >= 2 {
         let mut flags = [0u8; 1];
         file.read_exact(&mut flags)?;
-        let compressed = (flags[0] & 1) != 0;
 
         // Read uncompressed size
         let mut size_bytes = [0u8; 8];
         file.read_exact(&mut size_bytes)?;
         let size = u64::from_le_bytes(size_bytes);
 
-        (compressed, size as usize)
+        (flags[0], size as usize)
     } else {
-        (false, 0) // Version 1 files are never compressed
+        (0, 0) // Version 1 files are never compressed
     };
 
     // Read checksum
     let mut checksum_bytes = [0u8; 8];
     file.read_exact(&mut checksum_bytes)?;
-    let expected_checksum = u64::from_le_bytes(checksum_bytes);
+    let checksum = u64::from_le_bytes(checksum_bytes);
+
+    // Read data length and image offset (only when a synapse image follows the data)
+    let (data_len, image_offset) = if flags & FLAG_SYNAPSE_IMAGE != 0 {
+        let mut len_bytes = [0u8; 8];
+        file.read_exact(&mut len_bytes)?;
+        let mut offset_bytes = [0u8; 8];
+        file.read_exact(&mut offset_bytes)?;
+        (
+            Some(u64::from_le_bytes(len_bytes)),
+            Some(u64::from_le_bytes(offset_bytes)),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(FileHeader {
+        version,
+        is_compressed: flags & FLAG_COMPRESSED != 0,
+        uncompressed_size,
+        checksum,
+        data_len,
+        image_offset,
+    })
+}
 
+fn read_payload(file: &mut File, header: &FileHeader) -> Result<ConnectomeSnapshot> {
     // Read data
     let mut compressed_data = Vec::new();
-    file.read_to_end(&mut compressed_data)?;
+    match header.data_len {
+        Some(len) => {
+            file.take(len).read_to_end(&mut compressed_data)?;
+        }
+        None => {
+            file.read_to_end(&mut compressed_data)?;
+        }
+    }
 
     // Verify checksum
     let actual_checksum = calculate_checksum(&compressed_data);
-    if actual_checksum != expected_checksum {
+    if actual_checksum != header.checksum {
         return Err(ConnectomeError::ChecksumMismatch);
     }
 
     // Decompress if needed
-    let data = if is_compressed {
+    let data = if header.is_compressed {
         #[cfg(feature = "connectome-compression")]
         {
-            lz4::block::decompress(&compressed_data, Some(uncompressed_size as i32))
+            lz4::block::decompress(&compressed_data, Some(header.uncompressed_size as i32))
                 .map_err(|e| ConnectomeError::Compression(format!("Decompression failed: {}", e)))?
         }
         #[cfg(not(feature = "connectome-compression"))]
@@ -202,12 +458,115 @@ pub fn load_connectome<P: AsRef<Path>>(path: P) -> Result<ConnectomeSnapshot> {
     };
 
     // Deserialize
+    if header.version < 3 {
+        let snapshot: LegacyConnectomeSnapshot = bincode::deserialize(&data)
+            .map_err(|e| ConnectomeError::Deserialization(e.to_string()))?;
+        return Ok(snapshot.into());
+    }
     let snapshot: ConnectomeSnapshot =
         bincode::deserialize(&data).map_err(|e| ConnectomeError::Deserialization(e.to_string()))?;
 
     Ok(snapshot)
 }
 
+/// Snapshot layout of format versions 1 and 2
+#[derive(serde::Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+struct LegacyConnectomeSnapshot {
+    version: u32,
+    neurons: LegacyNeuronArray,
+    synapses: feagi_npu_neural::types::connectome::SerializableSynapseArray,
+    cortical_area_names: ahash::AHashMap<u32, String>,
+    burst_count: u64,
+    power_amount: f32,
+    fire_ledger_window: usize,
+    metadata: feagi_npu_neural::types::connectome::ConnectomeMetadata,
+}
+
+/// Neuron layout of format versions 1 and 2 (no limits, snooze or accumulation flags)
+#[derive(serde::Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize))]
+struct LegacyNeuronArray {
+    count: usize,
+    capacity: usize,
+    membrane_potentials: Vec<f32>,
+    thresholds: Vec<f32>,
+    leak_coefficients: Vec<f32>,
+    resting_potentials: Vec<f32>,
+    neuron_types: Vec<i32>,
+    refractory_periods: Vec<u16>,
+    refractory_countdowns: Vec<u16>,
+    excitabilities: Vec<f32>,
+    cortical_areas: Vec<u32>,
+    coordinates: Vec<u32>,
+    valid_mask: Vec<bool>,
+}
+
+impl From<LegacyConnectomeSnapshot> for ConnectomeSnapshot {
+    fn from(legacy: LegacyConnectomeSnapshot) -> Self {
+        let neurons = legacy.neurons;
+        // Older files never stored these; they load as unlimited, accumulating neurons
+        let len = neurons.valid_mask.len();
+        Self {
+            version: legacy.version,
+            neurons: feagi_npu_neural::types::connectome::SerializableNeuronArray {
+                count: neurons.count,
+                capacity: neurons.capacity,
+                membrane_potentials: neurons.membrane_potentials,
+                thresholds: neurons.thresholds,
+                leak_coefficients: neurons.leak_coefficients,
+                resting_potentials: neurons.resting_potentials,
+                neuron_types: neurons.neuron_types,
+                refractory_periods: neurons.refractory_periods,
+                refractory_countdowns: neurons.refractory_countdowns,
+                excitabilities: neurons.excitabilities,
+                threshold_limits: vec![f32::MAX; len],
+                consecutive_fire_limits: vec![u16::MAX; len],
+                snooze_periods: vec![0; len],
+                mp_charge_accumulation: vec![true; len],
+                cortical_areas: neurons.cortical_areas,
+                coordinates: neurons.coordinates,
+                valid_mask: neurons.valid_mask,
+            },
+            synapses: legacy.synapses,
+            cortical_area_names: legacy.cortical_area_names,
+            burst_count: legacy.burst_count,
+            power_amount: legacy.power_amount,
+            fire_ledger_window: legacy.fire_ledger_window,
+            metadata: legacy.metadata,
+        }
+    }
+}
+
+/// Copy a mapped synapse image back into a serializable synapse array
+///
+/// Columns are padded to the image capacity, as in `SerializableSynapseArray::new`.
+#[cfg(feature = "connectome-mmap")]
+fn read_synapse_image(
+    path: &Path,
+    offset: u64,
+) -> Result<feagi_npu_neural::types::connectome::SerializableSynapseArray> {
+    use feagi_npu_runtime::SynapseStorage;
+
+    let image = feagi_npu_runtime::MmapSynapseArray::open_at(path, offset)
+        .map_err(|e| ConnectomeError::SynapseImage(e.to_string()))?;
+
+    let count = image.count();
+    let mut synapses =
+        feagi_npu_neural::types::connectome::SerializableSynapseArray::new(image.capacity());
+    synapses.count = count;
+    synapses.source_neurons[..count].copy_from_slice(image.source_neurons());
+    synapses.target_neurons[..count].copy_from_slice(image.target_neurons());
+    synapses.weights[..count].copy_from_slice(image.weights());
+    synapses.postsynaptic_potentials[..count].copy_from_slice(image.postsynaptic_potentials());
+    synapses.types[..count].copy_from_slice(image.types());
+    synapses.valid_mask[..count].copy_from_slice(image.valid_mask());
+    for (idx, &source) in image.source_neurons().iter().enumerate() {
+        synapses.source_index.entry(source).or_default().push(idx);
+    }
+    Ok(synapses)
+}
+
 /// Calculate a simple checksum (CRC64-like)
 fn calculate_checksum(data: &[u8]) -> u64 {
     // Simple FNV-1a hash for now (can upgrade to proper CRC64 later)
@@ -257,6 +616,225 @@ mod tests {
         assert_eq!(loaded.power_amount, snapshot.power_amount);
     }
 
+    #[cfg(feature = "connectome-mmap")]
+    #[test]
+    fn test_mappable_connectome() {
+        use feagi_npu_runtime::{Runtime, SynapseStorage};
+
+        let mut synapses = SerializableSynapseArray::new(0);
+        for (source, target, weight) in [(2u32, 20u32, 50u8), (1, 10, 60), (2, 21, 70)] {
+            synapses.source_neurons.push(source);
+            synapses.target_neurons.push(target);
+            synapses.weights.push(weight);
+            synapses.postsynaptic_potentials.push(1);
+            synapses.types.push(0);
+            synapses.valid_mask.push(true);
+        }
+        synapses.count = 3;
+        synapses.capacity = 16;
+        let snapshot = ConnectomeSnapshot {
+            version: FORMAT_VERSION,
+            neurons: SerializableNeuronArray::default(),
+            synapses,
+            cortical_area_names: ahash::AHashMap::new(),
+            burst_count: 7,
+            power_amount: 1.0,
+            fire_ledger_window: 20,
+            metadata: ConnectomeMetadata::default(),
+        };
+
+        let temp_file = NamedTempFile::new().unwrap();
+        save_connectome_mappable(&snapshot, temp_file.path()).unwrap();
+
+        // Full load still works, with synapses sorted by source
+        let loaded = load_connectome(temp_file.path()).unwrap();
+        assert_eq!(loaded.burst_count, 7);
+        assert_eq!(loaded.synapses.count, 3);
+        assert_eq!(loaded.synapses.capacity, 16);
+        assert_eq!(loaded.synapses.source_neurons.len(), 16);
+        assert_eq!(loaded.synapses.source_neurons[..3], [1, 2, 2]);
+        assert_eq!(loaded.synapses.target_neurons[..3], [10, 20, 21]);
+        assert!(!loaded.synapses.valid_mask[3]);
+
+        // Mapped open leaves synapses on disk
+        let mapped = open_connectome_mapped(temp_file.path()).unwrap();
+        assert_eq!(mapped.snapshot.burst_count, 7);
+        assert_eq!(mapped.snapshot.synapses.count, 0);
+        let storage = mapped.runtime.create_synapse_storage(0).unwrap();
+        assert_eq!(storage.valid_count(), 3);
+        assert_eq!(storage.capacity(), 16);
+        let fcl = storage.propagate(&[2]);
+        assert_eq!(fcl.len(), 2);
+        assert!(fcl.contains_key(&20) && fcl.contains_key(&21));
+
+        // Plain files have no image to map
+        let plain = NamedTempFile::new().unwrap();
+        save_connectome(&snapshot, plain.path()).unwrap();
+        assert!(matches!(
+            open_connectome_mapped(plain.path()),
+            Err(ConnectomeError::SynapseImage(_))
+        ));
+    }
+
+    #[cfg(feature = "connectome-mmap")]
+    #[test]
+    fn test_mapped_npu_fires_like_in_memory_npu() {
+        use feagi_npu_burst_engine::backend::CPUBackend;
+        use feagi_npu_burst_engine::RustNPU;
+        use feagi_npu_neural::types::{NeuronId, SynapseType, SynapticPsp, SynapticWeight};
+        use feagi_npu_runtime::StdRuntime;
+        use feagi_structures::genomic::cortical_area::CoreCorticalType;
+
+        let mut npu = RustNPU::new(StdRuntime, CPUBackend::new(), 64, 256, 20).unwrap();
+        let name = CoreCorticalType::Death.to_cortical_id().as_base_64();
+        npu.register_cortical_area(0, name.clone());
+        npu.register_cortical_area(1, CoreCorticalType::Power.to_cortical_id().as_base_64());
+        npu.register_cortical_area(2, CoreCorticalType::Fatigue.to_cortical_id().as_base_64());
+        npu.register_cortical_area(10, name);
+        let ids: Vec<u32> = (0..12u32)
+            .map(|x| {
+                npu.add_neuron(
+                    1.0 + (x % 3) as f32,
+                    f32::MAX,
+                    0.1,
+                    0.0,
+                    0,
+                    x as u16 % 2,
+                    1.0,
+                    u16::MAX,
+                    0,
+                    true,
+                    10,
+                    x,
+                    0,
+                    0,
+                )
+                .unwrap()
+                .0
+            })
+            .collect();
+        for (i, &source) in ids.iter().enumerate() {
+            for step in [1, 5] {
+                let synapse_type = if (i + step) % 4 == 0 {
+                    SynapseType::Inhibitory
+                } else {
+                    SynapseType::Excitatory
+                };
+                npu.add_synapse(
+                    NeuronId(source),
+                    NeuronId(ids[(i + step) % ids.len()]),
+                    SynapticWeight(40 + (i * 7 % 50) as u8),
+                    SynapticPsp(60),
+                    synapse_type,
+                )
+                .unwrap();
+            }
+        }
+        // A deleted neuron must not shift the IDs after it
+        npu.delete_neuron(ids[4]);
+        npu.remove_synapses_from_sources(vec![NeuronId(ids[4])]);
+        npu.rebuild_synapse_index();
+
+        let temp_file = NamedTempFile::new().unwrap();
+        save_connectome_mappable(&npu.export_connectome(), temp_file.path()).unwrap();
+        let mut mapped = open_connectome_mapped(temp_file.path())
+            .unwrap()
+            .into_npu(CPUBackend::new())
+            .unwrap();
+        assert_eq!(mapped.get_synapse_count(), npu.get_synapse_count());
+        assert!(!mapped.is_neuron_valid(ids[4]));
+
+        let mut total_fired = 0;
+        for burst in 0..30u32 {
+            let frame = [(NeuronId(ids[(burst % 12) as usize]), 5.0)];
+            npu.inject_sensory_with_potentials(&frame);
+            mapped.inject_sensory_with_potentials(&frame);
+            let fired = |result: feagi_npu_burst_engine::BurstResult| {
+                let mut ids: Vec<u32> = result.fired_neurons.iter().map(|id| id.0).collect();
+                ids.sort_unstable();
+                ids
+            };
+            let expected = fired(npu.process_burst().unwrap());
+            assert_eq!(
+                fired(mapped.process_burst().unwrap()),
+                expected,
+                "burst {}",
+                burst
+            );
+            total_fired += expected.len();
+        }
+        assert!(total_fired > 30, "too little activity");
+    }
+
+    /// Write a one-neuron snapshot in the version 1 or 2 layout
+    fn write_legacy_file(path: &Path, version: u32) {
+        let legacy = LegacyConnectomeSnapshot {
+            version: 1,
+            neurons: LegacyNeuronArray {
+                count: 1,
+                capacity: 1,
+                membrane_potentials: vec![0.5],
+                thresholds: vec![2.0],
+                leak_coefficients: vec![0.1],
+                resting_potentials: vec![0.0],
+                neuron_types: vec![0],
+                refractory_periods: vec![1],
+                refractory_countdowns: vec![0],
+                excitabilities: vec![1.0],
+                cortical_areas: vec![3],
+                coordinates: vec![1, 2, 3],
+                valid_mask: vec![true],
+            },
+            synapses: SerializableSynapseArray::default(),
+            cortical_area_names: ahash::AHashMap::new(),
+            burst_count: 9,
+            power_amount: 1.0,
+            fire_ledger_window: 20,
+            metadata: ConnectomeMetadata::default(),
+        };
+        let data = bincode::serialize(&legacy).unwrap();
+
+        let mut file = File::create(path).unwrap();
+        file.write_all(MAGIC).unwrap();
+        file.write_all(&version.to_le_bytes()).unwrap();
+        if version >= 2 {
+            // Flags and uncompressed size
+            file.write_all(&[0]).unwrap();
+            file.write_all(&0u64.to_le_bytes()).unwrap();
+        }
+        file.write_all(&calculate_checksum(&data).to_le_bytes())
+            .unwrap();
+        file.write_all(&data).unwrap();
+    }
+
+    fn assert_legacy_neuron_defaults(loaded: &ConnectomeSnapshot) {
+        assert_eq!(loaded.burst_count, 9);
+        assert_eq!(loaded.neurons.thresholds, vec![2.0]);
+        assert_eq!(loaded.neurons.coordinates, vec![1, 2, 3]);
+        assert_eq!(loaded.neurons.threshold_limits, vec![f32::MAX]);
+        assert_eq!(loaded.neurons.consecutive_fire_limits, vec![u16::MAX]);
+        assert_eq!(loaded.neurons.snooze_periods, vec![0]);
+        assert_eq!(loaded.neurons.mp_charge_accumulation, vec![true]);
+    }
+
+    #[test]
+    fn test_load_version_1_file_without_neuron_limits() {
+        let temp_file = NamedTempFile::new().unwrap();
+        write_legacy_file(temp_file.path(), 1);
+
+        let loaded = load_connectome(temp_file.path()).unwrap();
+        assert_legacy_neuron_defaults(&loaded);
+    }
+
+    #[test]
+    fn test_load_version_2_file_without_neuron_limits() {
+        let temp_file = NamedTempFile::new().unwrap();
+        write_legacy_file(temp_file.path(), 2);
+
+        let loaded = load_connectome(temp_file.path()).unwrap();
+        assert_legacy_neuron_defaults(&loaded);
+    }
+
     #[test]
     fn test_invalid_magic() {
         let temp_file = NamedTempFile::new().unwrap();
@@ -15,6 +15,7 @@ use feagi_brain_development::models::CorticalAreaExt;
 use feagi_brain_development::ConnectomeManager;
 use feagi_evolutionary::{get_default_neural_properties, MemoryAreaProperties};
 use feagi_npu_burst_engine::BurstLoopRunner;
+use feagi_state_manager::StateEvent;
 use feagi_structures::genomic::brain_regions::{BrainRegion, RegionID, RegionType};
 use feagi_structures::genomic::cortical_area::io_cortical_area_configuration_flag::{
     FrameChangeHandling, PercentageNeuronPositioning,
@@ -32,6 +33,9 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, trace, warn};
 
+/// Actor recorded in the state event journal for connectome changes
+const JOURNAL_ACTOR: &str = "connectome_service";
+
 fn derive_friendly_cortical_name(cortical_id: &CorticalID) -> Option<String> {
     let bytes = cortical_id.as_bytes();
     let is_input = bytes[0] == b'i';
@@ -581,6 +585,13 @@ impl ConnectomeService for ConnectomeServiceImpl {
             }
         }
 
+        super::record_state_event(
+            JOURNAL_ACTOR,
+            StateEvent::CorticalAreaCreated {
+                cortical_id: params.cortical_id.clone(),
+            },
+        );
+
         // Return info
         self.get_cortical_area(&params.cortical_id).await
     }
@@ -754,6 +765,13 @@ impl ConnectomeService for ConnectomeServiceImpl {
         // Refresh burst runner cache after deleting area
         self.refresh_burst_runner_cache();
 
+        super::record_state_event(
+            JOURNAL_ACTOR,
+            StateEvent::CorticalAreaDeleted {
+                cortical_id: deleted_id_base64,
+            },
+        );
+
         Ok(())
     }
 
@@ -1016,14 +1034,13 @@ impl ConnectomeService for ConnectomeServiceImpl {
                         .get("dev_count")
                         .and_then(|v| v.as_u64().map(|n| n as usize))
                     {
-                        if dev_count > 0 {
-                            let total_width = area.dimensions.width as usize;
-                            let height = area.dimensions.height as usize;
-                            let depth = area.dimensions.depth as usize;
-                            Some((total_width / dev_count, height, depth))
-                        } else {
-                            from_properties
-                        }
+                        let total_width = area.dimensions.width as usize;
+                        let height = area.dimensions.height as usize;
+                        let depth = area.dimensions.depth as usize;
+                        total_width
+                            .checked_div(dev_count)
+                            .map(|width| (width, height, depth))
+                            .or(from_properties)
                     } else {
                         from_properties
                     }
@@ -1932,6 +1949,14 @@ impl ConnectomeService for ConnectomeServiceImpl {
             }
         }
 
+        super::record_state_event(
+            JOURNAL_ACTOR,
+            StateEvent::CorticalMappingChanged {
+                src_cortical_id: src_area_id,
+                dst_cortical_id: dst_area_id,
+            },
+        );
+
         Ok(region_io.0)
     }
 
@@ -16,6 +16,7 @@ use feagi_brain_development::neuroembryogenesis::Neuroembryogenesis;
 use feagi_brain_development::ConnectomeManager;
 use feagi_evolutionary::{get_default_neural_properties, MemoryAreaProperties};
 use feagi_npu_burst_engine::{BurstLoopRunner, ParameterUpdateQueue};
+use feagi_state_manager::StateEvent;
 use feagi_structures::genomic::cortical_area::descriptors::{
     CorticalSubUnitIndex, CorticalUnitIndex,
 };
@@ -35,6 +36,9 @@ use tracing::{info, trace, warn};
 
 use crate::genome::{ChangeType, CorticalChangeClassifier};
 
+/// Actor recorded in the state event journal for genome-driven changes
+const JOURNAL_ACTOR: &str = "genome_service";
+
 fn frame_handling_label(frame: FrameChangeHandling) -> &'static str {
     match frame {
         FrameChangeHandling::Absolute => "Absolute",
@@ -324,6 +328,7 @@ impl GenomeService for GenomeServiceImpl {
         info!(target: "feagi-services", "Storing RuntimeGenome with {} cortical areas, {} morphologies",
             genome.cortical_areas.len(), genome.morphologies.iter().count());
         *self.current_genome.write() = Some(genome.clone());
+        let loaded_genome_id = genome.metadata.genome_id.clone();
 
         // Increment genome load counter and set timestamp
         let genome_num = {
@@ -464,6 +469,13 @@ impl GenomeService for GenomeServiceImpl {
         // Refresh burst runner cache after genome load
         self.refresh_burst_runner_cache();
 
+        super::record_state_event(
+            JOURNAL_ACTOR,
+            StateEvent::GenomeLoaded {
+                genome_id: loaded_genome_id,
+            },
+        );
+
         Ok(GenomeInfo {
             genome_id: "current".to_string(),
             genome_title: "Current Genome".to_string(),
@@ -820,6 +832,12 @@ impl GenomeService for GenomeServiceImpl {
         // Step 5: Fetch and return area information
         let mut created_areas = Vec::new();
         for param in &params {
+            super::record_state_event(
+                JOURNAL_ACTOR,
+                StateEvent::CorticalAreaCreated {
+                    cortical_id: param.cortical_id.clone(),
+                },
+            );
             match self.get_cortical_area_info(&param.cortical_id).await {
                 Ok(area_info) => created_areas.push(area_info),
                 Err(e) => {
@@ -872,6 +890,7 @@ impl GenomeService for GenomeServiceImpl {
             self.regenerate_mappings_for_area(&effective_cortical_id)?;
             changes.remove("group_id");
             if changes.is_empty() {
+                record_area_updated(&effective_cortical_id_str);
                 return self
                     .get_cortical_area_info(&effective_cortical_id_str)
                     .await;
@@ -883,7 +902,7 @@ impl GenomeService for GenomeServiceImpl {
         CorticalChangeClassifier::log_classification_result(&changes, change_type);
 
         // Route based on change type
-        match change_type {
+        let updated = match change_type {
             ChangeType::Parameter => {
                 // Fast path: Direct neuron updates (~2-5ms, NO synapse rebuild)
                 self.update_parameters_only(&effective_cortical_id_str, changes)
@@ -938,10 +957,25 @@ impl GenomeService for GenomeServiceImpl {
                 self.get_cortical_area_info(&effective_cortical_id_str)
                     .await
             }
+        };
+
+        if updated.is_ok() {
+            record_area_updated(&effective_cortical_id_str);
         }
+        updated
     }
 }
 
+/// Journal a successful cortical area update
+fn record_area_updated(cortical_id: &str) {
+    super::record_state_event(
+        JOURNAL_ACTOR,
+        StateEvent::CorticalAreaUpdated {
+            cortical_id: cortical_id.to_string(),
+        },
+    );
+}
+
 impl GenomeServiceImpl {
     /// Fast path: Update only neuron parameters without synapse rebuild
     ///
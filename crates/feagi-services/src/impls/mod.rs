@@ -28,3 +28,14 @@ pub use neuron_service_impl::NeuronServiceImpl;
 pub use runtime_service_impl::RuntimeServiceImpl;
 pub use snapshot_service_impl::SnapshotServiceImpl;
 pub use system_service_impl::SystemServiceImpl;
+
+/// Record a state change in the state manager's event journal, if one is attached.
+///
+/// Journal failures are logged and never fail the calling operation.
+pub(crate) fn record_state_event(actor: &str, event: feagi_state_manager::StateEvent) {
+    let state_manager = feagi_state_manager::StateManager::instance();
+    let result = state_manager.read().record_event(actor, event);
+    if let Err(e) = result {
+        tracing::warn!(target: "feagi-services", "Failed to journal state event: {}", e);
+    }
+}
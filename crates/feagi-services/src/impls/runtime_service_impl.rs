@@ -11,16 +11,34 @@ Licensed under the Apache License, Version 2.0
 */
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use ahash::AHashSet;
 use async_trait::async_trait;
-use feagi_npu_burst_engine::BurstLoopRunner;
+use feagi_npu_burst_engine::{BurstLoopRunner, BurstPacing, TickRequest};
+use feagi_state_manager::BurstEngineState;
 use feagi_structures::genomic::cortical_area::CorticalID;
 use parking_lot::RwLock;
 use tracing::{debug, info, warn};
 
 use crate::traits::RuntimeService;
-use crate::types::{RuntimeStatus, ServiceError, ServiceResult};
+use crate::types::{
+    BurstPacingMode, BurstTickRequest, BurstTickResult, CorticalXyzpData, RuntimeStatus,
+    ServiceError, ServiceResult,
+};
+
+/// Tick timeout when the request does not set one
+const DEFAULT_TICK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Publish a burst engine state transition to the state manager
+///
+/// The state manager updates its core state and emits (and journals) the
+/// change event, so API readers and subscribers see the same state.
+fn set_burst_engine_state(state: BurstEngineState) {
+    feagi_state_manager::StateManager::instance()
+        .read()
+        .set_burst_engine_state(state);
+}
 
 /// Default implementation of RuntimeService
 ///
@@ -54,6 +72,7 @@ impl RuntimeService for RuntimeServiceImpl {
         // Clear paused flag
         *self.paused.write() = false;
 
+        set_burst_engine_state(BurstEngineState::Running);
         Ok(())
     }
 
@@ -66,6 +85,7 @@ impl RuntimeService for RuntimeServiceImpl {
         // Clear paused flag
         *self.paused.write() = false;
 
+        set_burst_engine_state(BurstEngineState::Ready);
         Ok(())
     }
 
@@ -86,6 +106,7 @@ impl RuntimeService for RuntimeServiceImpl {
         // For now, we just track the paused state
         warn!(target: "feagi-services", "Pause not yet implemented in BurstLoopRunner - using flag only");
 
+        set_burst_engine_state(BurstEngineState::Paused);
         Ok(())
     }
 
@@ -105,6 +126,7 @@ impl RuntimeService for RuntimeServiceImpl {
         // TODO: Implement actual resume mechanism in BurstLoopRunner
         warn!(target: "feagi-services", "Resume not yet implemented in BurstLoopRunner - using flag only");
 
+        set_burst_engine_state(BurstEngineState::Running);
         Ok(())
     }
 
@@ -148,6 +170,7 @@ impl RuntimeService for RuntimeServiceImpl {
             },
             last_burst_neuron_count: 0, // Not yet tracked
             avg_burst_time_ms: 0.0,     // Not yet tracked
+            error: runner.fault(),
         })
     }
 
@@ -166,6 +189,93 @@ impl RuntimeService for RuntimeServiceImpl {
         Ok(())
     }
 
+    async fn get_pacing(&self) -> ServiceResult<BurstPacingMode> {
+        Ok(match self.burst_runner.read().get_pacing() {
+            BurstPacing::RealTime => BurstPacingMode::RealTime,
+            BurstPacing::Lockstep => BurstPacingMode::Lockstep,
+        })
+    }
+
+    async fn set_pacing(&self, pacing: BurstPacingMode) -> ServiceResult<()> {
+        info!(target: "feagi-services", "Setting burst pacing to {:?}", pacing);
+
+        self.burst_runner.read().set_pacing(match pacing {
+            BurstPacingMode::RealTime => BurstPacing::RealTime,
+            BurstPacingMode::Lockstep => BurstPacing::Lockstep,
+        });
+        Ok(())
+    }
+
+    async fn tick(&self, request: BurstTickRequest) -> ServiceResult<BurstTickResult> {
+        let mut tick_request = TickRequest::default();
+        for area in request.sensory {
+            let len = area.x.len();
+            if area.y.len() != len || area.z.len() != len || area.p.len() != len {
+                return Err(ServiceError::InvalidInput(format!(
+                    "Sensory arrays for '{}' have different lengths",
+                    area.cortical_id
+                )));
+            }
+            let cortical_id = CorticalID::try_from_base_64(&area.cortical_id).map_err(|e| {
+                ServiceError::InvalidInput(format!(
+                    "Invalid cortical ID '{}': {}",
+                    area.cortical_id, e
+                ))
+            })?;
+            let xyzp = (0..len)
+                .map(|i| (area.x[i], area.y[i], area.z[i], area.p[i]))
+                .collect();
+            tick_request = tick_request.with_sensory_xyzp(cortical_id, xyzp);
+        }
+        if let Some(motor_cortical_ids) = request.motor_cortical_ids {
+            tick_request =
+                tick_request.with_motor_cortical_ids(motor_cortical_ids.into_iter().collect());
+        }
+        let timeout = request
+            .timeout_ms
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_TICK_TIMEOUT);
+
+        // Take a handle so the runner lock is not held while the burst runs
+        let handle = {
+            let runner = self.burst_runner.read();
+            if !runner.is_running() {
+                return Err(ServiceError::InvalidState(
+                    "Burst engine is not running".to_string(),
+                ));
+            }
+            if runner.get_pacing() != BurstPacing::Lockstep {
+                return Err(ServiceError::InvalidState(
+                    "Burst engine is not in lockstep pacing".to_string(),
+                ));
+            }
+            runner.tick_handle()
+        };
+        let output = tokio::task::spawn_blocking(move || handle.tick(tick_request, timeout))
+            .await
+            .map_err(|e| ServiceError::Internal(format!("Tick task failed: {}", e)))?
+            .map_err(ServiceError::Backend)?;
+
+        let mut motor: Vec<CorticalXyzpData> = output
+            .motor
+            .into_values()
+            .map(|area| CorticalXyzpData {
+                cortical_id: area.cortical_id,
+                x: area.coords_x,
+                y: area.coords_y,
+                z: area.coords_z,
+                p: area.potentials,
+            })
+            .collect();
+        motor.sort_by(|a, b| a.cortical_id.cmp(&b.cortical_id));
+
+        Ok(BurstTickResult {
+            burst: output.burst,
+            fired_neuron_count: output.fired_neuron_count,
+            motor,
+        })
+    }
+
     async fn get_burst_count(&self) -> ServiceResult<u64> {
         let runner = self.burst_runner.read();
         Ok(runner.get_burst_count())
@@ -93,6 +93,8 @@ pub mod connectome;
 pub mod genome;
 #[cfg(feature = "std")]
 pub mod impls;
+#[cfg(feature = "connectome-nwb")]
+pub mod nwb;
 pub mod traits;
 pub mod types;
 
@@ -115,9 +117,13 @@ pub use types::{
     },
     // DTOs
     BrainRegionInfo,
+    BurstPacingMode,
+    BurstTickRequest,
+    BurstTickResult,
     ConnectivityStats,
     CorticalAreaInfo,
     CorticalAreaStats,
+    CorticalXyzpData,
     CreateBrainRegionParams,
     CreateCorticalAreaParams,
     CreateNeuronParams,
@@ -0,0 +1,1112 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # FEAGI NWB Export
+//!
+//! Export of connectome snapshots and spike recordings to a Zarr equivalent
+//! of a Neurodata Without Borders (NWB) file: the group and table layout
+//! follows NWB conventions, but the store is not a validated NWB file.
+//!
+//! The store is written as an uncompressed Zarr v2 hierarchy rather than
+//! HDF5, so no native library is required. It is read directly with
+//! `zarr`/`numpy`; loading it through `pynwb`/`hdmf-zarr` or converting it to
+//! an `.nwb` HDF5 file is untested and may need a conversion step.
+//! Every table mirrors an NWB `DynamicTable` whose columns are `VectorData`
+//! arrays; ragged columns use a companion `<name>_index` `VectorIndex`.
+//!
+//! ## Layout
+//! ```text
+//! <store>/
+//! ├── .zgroup, .zattrs                 NWBFile attributes + FEAGI runtime state
+//! ├── general/cortical_areas/          id, name
+//! ├── processing/connectome/neurons/   id, x, y, z, cortical_area, membrane_potential,
+//! │                                    threshold, leak_coefficient, resting_potential,
+//! │                                    neuron_type, refractory_period,
+//! │                                    refractory_countdown, excitability, threshold_limit,
+//! │                                    consecutive_fire_limit, snooze_period,
+//! │                                    mp_charge_accumulation, valid
+//! ├── processing/connectome/synapses/  source, target, weight, psp, type
+//! └── units/                           id, spike_times, spike_times_index, spike_bursts
+//! ```
+//!
+//! Each array directory holds a `.zarray` descriptor, a `.zattrs` file with the
+//! NWB column description, and a single little-endian chunk named `0`.
+//! Spike times are stored in seconds (`burst * burst_interval_seconds`);
+//! `spike_bursts` keeps the exact burst numbers so the reader is lossless.
+//!
+//! ## Usage
+//! ```ignore
+//! use feagi_services::nwb::{export_nwb, import_nwb, NwbExportOptions, SpikeRecording};
+//!
+//! let mut recording = SpikeRecording::new();
+//! recording.record_burst(1, &[0, 4, 7]);
+//!
+//! export_nwb(&snapshot, Some(&recording), "brain.nwb.zarr", &NwbExportOptions::default())?;
+//! let dataset = import_nwb("brain.nwb.zarr")?;
+//! ```
+
+use feagi_npu_neural::types::connectome::{
+    ConnectomeMetadata, ConnectomeSnapshot, SerializableNeuronArray, SerializableSynapseArray,
+};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// NWB export errors
+#[derive(Error, Debug)]
+pub enum NwbError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid store: {0}")]
+    InvalidStore(String),
+}
+
+pub type Result<T> = std::result::Result<T, NwbError>;
+
+/// NWB schema version the layout follows
+const NWB_VERSION: &str = "2.7.0";
+
+/// Spikes recorded from a running NPU, keyed by neuron ID
+///
+/// Feed it the fired neuron IDs of each burst (e.g. from the fire queue);
+/// bursts must be recorded in increasing order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpikeRecording {
+    spikes: BTreeMap<u32, Vec<u64>>,
+}
+
+impl SpikeRecording {
+    /// Create an empty recording
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the neurons that fired during `burst`
+    pub fn record_burst(&mut self, burst: u64, fired_neurons: &[u32]) {
+        for &neuron_id in fired_neurons {
+            self.spikes.entry(neuron_id).or_default().push(burst);
+        }
+    }
+
+    /// Bursts in which `neuron_id` fired
+    pub fn spike_bursts(&self, neuron_id: u32) -> Option<&[u64]> {
+        self.spikes.get(&neuron_id).map(|b| b.as_slice())
+    }
+
+    /// Number of neurons with at least one spike
+    pub fn neuron_count(&self) -> usize {
+        self.spikes.len()
+    }
+
+    /// Total number of spikes
+    pub fn spike_count(&self) -> usize {
+        self.spikes.values().map(|b| b.len()).sum()
+    }
+
+    /// Iterate over `(neuron_id, bursts)` in neuron order
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &[u64])> {
+        self.spikes.iter().map(|(&id, b)| (id, b.as_slice()))
+    }
+}
+
+/// Options controlling the exported NWB file attributes
+#[derive(Debug, Clone)]
+pub struct NwbExportOptions {
+    /// Unique identifier of the NWB file
+    pub identifier: String,
+
+    /// Free-text description of the session
+    pub session_description: String,
+
+    /// Wall-clock duration of one burst, used to convert bursts to seconds
+    pub burst_interval_seconds: f64,
+}
+
+impl Default for NwbExportOptions {
+    fn default() -> Self {
+        Self {
+            identifier: uuid::Uuid::new_v4().to_string(),
+            session_description: String::from("FEAGI connectome export"),
+            burst_interval_seconds: 0.01,
+        }
+    }
+}
+
+/// Contents of an NWB store as read back by [`import_nwb`]
+#[derive(Debug, Clone)]
+pub struct NwbDataset {
+    /// Reconstructed connectome (only valid synapses are exported)
+    pub snapshot: ConnectomeSnapshot,
+
+    /// Recorded spikes (empty if none were exported)
+    pub recording: SpikeRecording,
+
+    /// NWB file identifier
+    pub identifier: String,
+
+    /// Burst duration used to compute `spike_times`
+    pub burst_interval_seconds: f64,
+}
+
+/// Export a connectome (and optionally its spike recording) to an NWB-style Zarr store
+///
+/// # Arguments
+/// * `snapshot` - The connectome snapshot to export
+/// * `recording` - Spikes recorded while the connectome was running
+/// * `path` - Directory of the store (created; existing arrays are overwritten)
+/// * `options` - NWB file attributes
+pub fn export_nwb<P: AsRef<Path>>(
+    snapshot: &ConnectomeSnapshot,
+    recording: Option<&SpikeRecording>,
+    path: P,
+    options: &NwbExportOptions,
+) -> Result<()> {
+    let root = path.as_ref();
+    let metadata = &snapshot.metadata;
+    // Reject malformed snapshots before anything is written
+    check_neuron_columns(&snapshot.neurons)?;
+    check_synapse_columns(&snapshot.synapses)?;
+
+    let session_start_time = chrono::DateTime::from_timestamp(metadata.timestamp as i64, 0)
+        .unwrap_or_default()
+        .to_rfc3339();
+
+    write_group(
+        root,
+        json!({
+            "neurodata_type": "NWBFile",
+            "namespace": "core",
+            "nwb_version": NWB_VERSION,
+            "identifier": options.identifier,
+            "session_description": options.session_description,
+            "session_start_time": session_start_time,
+            "feagi": {
+                "version": snapshot.version,
+                "burst_count": snapshot.burst_count,
+                "power_amount": snapshot.power_amount,
+                "fire_ledger_window": snapshot.fire_ledger_window,
+                "description": metadata.description,
+                "source": metadata.source,
+                "timestamp": metadata.timestamp,
+                "tags": metadata.tags.iter().collect::<BTreeMap<_, _>>(),
+            },
+        }),
+    )?;
+
+    // Cortical areas
+    let general = root.join("general");
+    write_group(&general, json!({}))?;
+    let areas: BTreeMap<u32, &String> = snapshot
+        .cortical_area_names
+        .iter()
+        .map(|(k, v)| (*k, v))
+        .collect();
+    let area_table = general.join("cortical_areas");
+    write_table(&area_table, "FEAGI cortical areas", &["name"])?;
+    write_array(
+        &area_table,
+        "id",
+        &areas.keys().copied().collect::<Vec<u32>>(),
+        "Cortical area index",
+    )?;
+    write_string_array(
+        &area_table,
+        "name",
+        &areas.values().map(|s| s.as_str()).collect::<Vec<_>>(),
+        "Cortical area name",
+    )?;
+
+    // Neurons
+    let processing = root.join("processing");
+    write_group(&processing, json!({}))?;
+    let connectome = processing.join("connectome");
+    write_group(
+        &connectome,
+        json!({
+            "neurodata_type": "ProcessingModule",
+            "namespace": "core",
+            "description": "FEAGI connectome (neurons and synapses)",
+        }),
+    )?;
+    write_neurons(&connectome.join("neurons"), &snapshot.neurons)?;
+    write_synapses(&connectome.join("synapses"), &snapshot.synapses)?;
+
+    // Units (spike times)
+    let empty = SpikeRecording::new();
+    write_units(
+        &root.join("units"),
+        recording.unwrap_or(&empty),
+        options.burst_interval_seconds,
+    )?;
+
+    Ok(())
+}
+
+/// Read an NWB-style Zarr store written by [`export_nwb`]
+///
+/// # Returns
+/// The reconstructed connectome snapshot and spike recording
+pub fn import_nwb<P: AsRef<Path>>(path: P) -> Result<NwbDataset> {
+    let root = path.as_ref();
+    let attrs = read_json(&root.join(".zattrs"))?;
+    if attrs["neurodata_type"] != "NWBFile" {
+        return Err(NwbError::InvalidStore(format!(
+            "{} is not an NWBFile group",
+            root.display()
+        )));
+    }
+    let feagi = &attrs["feagi"];
+
+    // Cortical areas
+    let area_table = root.join("general").join("cortical_areas");
+    let area_ids: Vec<u32> = read_array(&area_table, "id")?;
+    let area_names = read_string_array(&area_table, "name")?;
+    if area_ids.len() != area_names.len() {
+        return Err(NwbError::InvalidStore(
+            "cortical_areas columns have different lengths".to_string(),
+        ));
+    }
+
+    let connectome = root.join("processing").join("connectome");
+    let neurons = read_neurons(&connectome.join("neurons"))?;
+    let synapses = read_synapses(&connectome.join("synapses"))?;
+
+    let units = root.join("units");
+    let burst_interval_seconds = read_json(&units.join(".zattrs"))?["burst_interval_seconds"]
+        .as_f64()
+        .unwrap_or(0.0);
+    let recording = read_units(&units)?;
+
+    let metadata = ConnectomeMetadata {
+        timestamp: feagi["timestamp"].as_u64().unwrap_or(0),
+        description: feagi["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        source: feagi["source"].as_str().unwrap_or_default().to_string(),
+        tags: serde_json::from_value(feagi["tags"].clone()).unwrap_or_default(),
+    };
+
+    Ok(NwbDataset {
+        snapshot: ConnectomeSnapshot {
+            version: feagi["version"].as_u64().unwrap_or(0) as u32,
+            neurons,
+            synapses,
+            cortical_area_names: area_ids.into_iter().zip(area_names).collect(),
+            burst_count: feagi["burst_count"].as_u64().unwrap_or(0),
+            power_amount: feagi["power_amount"].as_f64().unwrap_or(0.0) as f32,
+            fire_ledger_window: feagi["fire_ledger_window"].as_u64().unwrap_or(0) as usize,
+            metadata,
+        },
+        recording,
+        identifier: attrs["identifier"].as_str().unwrap_or_default().to_string(),
+        burst_interval_seconds,
+    })
+}
+
+/// Check every neuron column holds at least `count` entries
+fn check_neuron_columns(neurons: &SerializableNeuronArray) -> Result<()> {
+    let n = neurons.count;
+    check_column_len(
+        "neurons",
+        "membrane_potential",
+        neurons.membrane_potentials.len(),
+        n,
+    )?;
+    check_column_len("neurons", "threshold", neurons.thresholds.len(), n)?;
+    check_column_len(
+        "neurons",
+        "leak_coefficient",
+        neurons.leak_coefficients.len(),
+        n,
+    )?;
+    check_column_len(
+        "neurons",
+        "resting_potential",
+        neurons.resting_potentials.len(),
+        n,
+    )?;
+    check_column_len("neurons", "neuron_type", neurons.neuron_types.len(), n)?;
+    check_column_len(
+        "neurons",
+        "refractory_period",
+        neurons.refractory_periods.len(),
+        n,
+    )?;
+    check_column_len(
+        "neurons",
+        "refractory_countdown",
+        neurons.refractory_countdowns.len(),
+        n,
+    )?;
+    check_column_len("neurons", "excitability", neurons.excitabilities.len(), n)?;
+    check_column_len(
+        "neurons",
+        "threshold_limit",
+        neurons.threshold_limits.len(),
+        n,
+    )?;
+    check_column_len(
+        "neurons",
+        "consecutive_fire_limit",
+        neurons.consecutive_fire_limits.len(),
+        n,
+    )?;
+    check_column_len("neurons", "snooze_period", neurons.snooze_periods.len(), n)?;
+    check_column_len(
+        "neurons",
+        "mp_charge_accumulation",
+        neurons.mp_charge_accumulation.len(),
+        n,
+    )?;
+    check_column_len("neurons", "cortical_area", neurons.cortical_areas.len(), n)?;
+    check_column_len("neurons", "valid", neurons.valid_mask.len(), n)?;
+    match n.checked_mul(3) {
+        Some(len) => check_column_len("neurons", "coordinates", neurons.coordinates.len(), len),
+        None => Err(NwbError::InvalidStore(format!(
+            "neurons: count {} is too large",
+            n
+        ))),
+    }
+}
+
+/// Check every synapse column holds at least `count` entries
+fn check_synapse_columns(synapses: &SerializableSynapseArray) -> Result<()> {
+    let n = synapses.count;
+    check_column_len("synapses", "source", synapses.source_neurons.len(), n)?;
+    check_column_len("synapses", "target", synapses.target_neurons.len(), n)?;
+    check_column_len("synapses", "weight", synapses.weights.len(), n)?;
+    check_column_len("synapses", "psp", synapses.postsynaptic_potentials.len(), n)?;
+    check_column_len("synapses", "type", synapses.types.len(), n)?;
+    check_column_len("synapses", "valid", synapses.valid_mask.len(), n)
+}
+
+fn check_column_len(table: &str, name: &str, len: usize, required: usize) -> Result<()> {
+    if len < required {
+        return Err(NwbError::InvalidStore(format!(
+            "{}/{}: expected at least {} values, found {}",
+            table, name, required, len
+        )));
+    }
+    Ok(())
+}
+
+fn write_neurons(table: &Path, neurons: &SerializableNeuronArray) -> Result<()> {
+    let n = neurons.count;
+    write_table(
+        table,
+        "FEAGI neurons (id is the NPU neuron index)",
+        &[
+            "x",
+            "y",
+            "z",
+            "cortical_area",
+            "membrane_potential",
+            "threshold",
+            "leak_coefficient",
+            "resting_potential",
+            "neuron_type",
+            "refractory_period",
+            "refractory_countdown",
+            "excitability",
+            "threshold_limit",
+            "consecutive_fire_limit",
+            "snooze_period",
+            "mp_charge_accumulation",
+            "valid",
+        ],
+    )?;
+
+    let axis = |offset: usize| -> Vec<u32> {
+        neurons.coordinates[..n * 3]
+            .chunks_exact(3)
+            .map(|xyz| xyz[offset])
+            .collect()
+    };
+    write_array(
+        table,
+        "id",
+        &(0..n as u32).collect::<Vec<_>>(),
+        "Neuron index",
+    )?;
+    write_array(
+        table,
+        "x",
+        &axis(0),
+        "Voxel X coordinate within the cortical area",
+    )?;
+    write_array(
+        table,
+        "y",
+        &axis(1),
+        "Voxel Y coordinate within the cortical area",
+    )?;
+    write_array(
+        table,
+        "z",
+        &axis(2),
+        "Voxel Z coordinate within the cortical area",
+    )?;
+    write_array(
+        table,
+        "cortical_area",
+        &neurons.cortical_areas[..n],
+        "Cortical area index",
+    )?;
+    write_array(
+        table,
+        "membrane_potential",
+        &neurons.membrane_potentials[..n],
+        "Membrane potential",
+    )?;
+    write_array(
+        table,
+        "threshold",
+        &neurons.thresholds[..n],
+        "Firing threshold",
+    )?;
+    write_array(
+        table,
+        "leak_coefficient",
+        &neurons.leak_coefficients[..n],
+        "Leak coefficient (0-1)",
+    )?;
+    write_array(
+        table,
+        "resting_potential",
+        &neurons.resting_potentials[..n],
+        "Resting potential",
+    )?;
+    write_array(
+        table,
+        "neuron_type",
+        &neurons.neuron_types[..n],
+        "Neuron type",
+    )?;
+    write_array(
+        table,
+        "refractory_period",
+        &neurons.refractory_periods[..n],
+        "Refractory period (bursts)",
+    )?;
+    write_array(
+        table,
+        "refractory_countdown",
+        &neurons.refractory_countdowns[..n],
+        "Remaining refractory bursts",
+    )?;
+    write_array(
+        table,
+        "excitability",
+        &neurons.excitabilities[..n],
+        "Excitability multiplier",
+    )?;
+    write_array(
+        table,
+        "threshold_limit",
+        &neurons.threshold_limits[..n],
+        "Threshold limit (float max = no limit)",
+    )?;
+    write_array(
+        table,
+        "consecutive_fire_limit",
+        &neurons.consecutive_fire_limits[..n],
+        "Consecutive fire limit (65535 = no limit)",
+    )?;
+    write_array(
+        table,
+        "snooze_period",
+        &neurons.snooze_periods[..n],
+        "Snooze period after the consecutive fire limit (bursts)",
+    )?;
+    write_array(
+        table,
+        "mp_charge_accumulation",
+        &neurons.mp_charge_accumulation[..n],
+        "Membrane potential accumulates across bursts",
+    )?;
+    write_array(
+        table,
+        "valid",
+        &neurons.valid_mask[..n],
+        "Neuron slot is in use",
+    )?;
+    Ok(())
+}
+
+fn read_neurons(table: &Path) -> Result<SerializableNeuronArray> {
+    let x: Vec<u32> = read_array(table, "x")?;
+    let count = x.len();
+    let y: Vec<u32> = read_column(table, "y", count)?;
+    let z: Vec<u32> = read_column(table, "z", count)?;
+
+    Ok(SerializableNeuronArray {
+        count,
+        capacity: count,
+        membrane_potentials: read_column(table, "membrane_potential", count)?,
+        thresholds: read_column(table, "threshold", count)?,
+        leak_coefficients: read_column(table, "leak_coefficient", count)?,
+        resting_potentials: read_column(table, "resting_potential", count)?,
+        neuron_types: read_column(table, "neuron_type", count)?,
+        refractory_periods: read_column(table, "refractory_period", count)?,
+        refractory_countdowns: read_column(table, "refractory_countdown", count)?,
+        excitabilities: read_column(table, "excitability", count)?,
+        threshold_limits: read_column(table, "threshold_limit", count)?,
+        consecutive_fire_limits: read_column(table, "consecutive_fire_limit", count)?,
+        snooze_periods: read_column(table, "snooze_period", count)?,
+        mp_charge_accumulation: read_column(table, "mp_charge_accumulation", count)?,
+        cortical_areas: read_column(table, "cortical_area", count)?,
+        coordinates: x
+            .into_iter()
+            .zip(y)
+            .zip(z)
+            .flat_map(|((x, y), z)| [x, y, z])
+            .collect(),
+        valid_mask: read_column(table, "valid", count)?,
+    })
+}
+
+fn write_synapses(table: &Path, synapses: &SerializableSynapseArray) -> Result<()> {
+    let valid: Vec<usize> = (0..synapses.count)
+        .filter(|&i| synapses.valid_mask[i])
+        .collect();
+    let column = |values: &[u8]| -> Vec<u8> { valid.iter().map(|&i| values[i]).collect() };
+
+    write_table(
+        table,
+        "FEAGI synapses (valid synapses only)",
+        &["source", "target", "weight", "psp", "type"],
+    )?;
+    write_array(
+        table,
+        "source",
+        &valid
+            .iter()
+            .map(|&i| synapses.source_neurons[i])
+            .collect::<Vec<_>>(),
+        "Presynaptic neuron index",
+    )?;
+    write_array(
+        table,
+        "target",
+        &valid
+            .iter()
+            .map(|&i| synapses.target_neurons[i])
+            .collect::<Vec<_>>(),
+        "Postsynaptic neuron index",
+    )?;
+    write_array(
+        table,
+        "weight",
+        &column(&synapses.weights),
+        "Synaptic weight (0-255)",
+    )?;
+    write_array(
+        table,
+        "psp",
+        &column(&synapses.postsynaptic_potentials),
+        "Postsynaptic potential (0-255)",
+    )?;
+    write_array(
+        table,
+        "type",
+        &column(&synapses.types),
+        "Synapse type (0=excitatory, 1=inhibitory)",
+    )?;
+    Ok(())
+}
+
+fn read_synapses(table: &Path) -> Result<SerializableSynapseArray> {
+    let source_neurons: Vec<u32> = read_array(table, "source")?;
+    let count = source_neurons.len();
+
+    let mut synapses = SerializableSynapseArray {
+        count,
+        capacity: count,
+        target_neurons: read_column(table, "target", count)?,
+        weights: read_column(table, "weight", count)?,
+        postsynaptic_potentials: read_column(table, "psp", count)?,
+        types: read_column(table, "type", count)?,
+        valid_mask: vec![true; count],
+        source_neurons,
+        ..Default::default()
+    };
+    for (i, &source) in synapses.source_neurons.iter().enumerate() {
+        synapses.source_index.entry(source).or_default().push(i);
+    }
+    Ok(synapses)
+}
+
+fn write_units(
+    table: &Path,
+    recording: &SpikeRecording,
+    burst_interval_seconds: f64,
+) -> Result<()> {
+    let mut ids = Vec::with_capacity(recording.neuron_count());
+    let mut bursts = Vec::with_capacity(recording.spike_count());
+    let mut index = Vec::with_capacity(recording.neuron_count());
+    for (neuron_id, neuron_bursts) in recording.iter() {
+        ids.push(neuron_id);
+        bursts.extend_from_slice(neuron_bursts);
+        index.push(bursts.len() as u64);
+    }
+    let spike_times: Vec<f64> = bursts
+        .iter()
+        .map(|&b| b as f64 * burst_interval_seconds)
+        .collect();
+
+    write_group(
+        table,
+        json!({
+            "neurodata_type": "Units",
+            "namespace": "core",
+            "description": "Spike times of FEAGI neurons",
+            "colnames": ["spike_times", "spike_bursts"],
+            "burst_interval_seconds": burst_interval_seconds,
+        }),
+    )?;
+    write_array(table, "id", &ids, "Neuron index")?;
+    write_array(table, "spike_times", &spike_times, "Spike times (seconds)")?;
+    write_array(table, "spike_bursts", &bursts, "Spike burst numbers")?;
+    write_array(
+        table,
+        "spike_times_index",
+        &index,
+        "Index into spike_times / spike_bursts",
+    )?;
+    Ok(())
+}
+
+fn read_units(table: &Path) -> Result<SpikeRecording> {
+    let ids: Vec<u32> = read_array(table, "id")?;
+    let bursts: Vec<u64> = read_array(table, "spike_bursts")?;
+    let index: Vec<u64> = read_column(table, "spike_times_index", ids.len())?;
+
+    let mut recording = SpikeRecording::new();
+    let mut start = 0usize;
+    for (neuron_id, end) in ids.into_iter().zip(index) {
+        let end = end as usize;
+        if end < start || end > bursts.len() {
+            return Err(NwbError::InvalidStore(
+                "spike_times_index is not monotonic".to_string(),
+            ));
+        }
+        recording
+            .spikes
+            .insert(neuron_id, bursts[start..end].to_vec());
+        start = end;
+    }
+    Ok(recording)
+}
+
+// ============================================================================
+// Zarr v2 primitives
+// ============================================================================
+
+/// Element types that can be stored as an uncompressed Zarr array
+trait ZarrElement: Copy {
+    /// Zarr/NumPy dtype string
+    const DTYPE: &'static str;
+    /// Size in bytes
+    const SIZE: usize;
+
+    fn write_le(self, out: &mut Vec<u8>);
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_zarr_element {
+    ($($ty:ty => $dtype:literal),* $(,)?) => {
+        $(
+            impl ZarrElement for $ty {
+                const DTYPE: &'static str = $dtype;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(bytes.try_into().expect("chunk sized by SIZE"))
+                }
+            }
+        )*
+    };
+}
+
+impl_zarr_element!(
+    u8 => "|u1",
+    u16 => "<u2",
+    u32 => "<u4",
+    u64 => "<u8",
+    i32 => "<i4",
+    f32 => "<f4",
+    f64 => "<f8",
+);
+
+impl ZarrElement for bool {
+    const DTYPE: &'static str = "|b1";
+    const SIZE: usize = 1;
+
+    fn write_le(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+
+    fn read_le(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+}
+
+fn write_group(dir: &Path, attrs: Value) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(".zgroup"), json!({ "zarr_format": 2 }).to_string())?;
+    fs::write(dir.join(".zattrs"), serde_json::to_string_pretty(&attrs)?)?;
+    Ok(())
+}
+
+fn write_table(dir: &Path, description: &str, colnames: &[&str]) -> Result<()> {
+    write_group(
+        dir,
+        json!({
+            "neurodata_type": "DynamicTable",
+            "namespace": "hdmf-common",
+            "description": description,
+            "colnames": colnames,
+        }),
+    )
+}
+
+fn write_raw_array(
+    dir: &Path,
+    name: &str,
+    dtype: &str,
+    len: usize,
+    chunk: Vec<u8>,
+    description: &str,
+) -> Result<()> {
+    let array_dir = dir.join(name);
+    fs::create_dir_all(&array_dir)?;
+    let zarray = json!({
+        "zarr_format": 2,
+        "shape": [len],
+        "chunks": [len.max(1)],
+        "dtype": dtype,
+        "compressor": null,
+        "fill_value": null,
+        "filters": null,
+        "order": "C",
+    });
+    let neurodata_type = if name.ends_with("_index") {
+        "VectorIndex"
+    } else if name == "id" {
+        "ElementIdentifiers"
+    } else {
+        "VectorData"
+    };
+    fs::write(
+        array_dir.join(".zarray"),
+        serde_json::to_string_pretty(&zarray)?,
+    )?;
+    fs::write(
+        array_dir.join(".zattrs"),
+        serde_json::to_string_pretty(&json!({
+            "neurodata_type": neurodata_type,
+            "namespace": "hdmf-common",
+            "description": description,
+        }))?,
+    )?;
+
+    let chunk_path = array_dir.join("0");
+    if len > 0 {
+        fs::write(chunk_path, chunk)?;
+    } else if chunk_path.exists() {
+        fs::remove_file(chunk_path)?;
+    }
+    Ok(())
+}
+
+fn write_array<T: ZarrElement>(
+    dir: &Path,
+    name: &str,
+    data: &[T],
+    description: &str,
+) -> Result<()> {
+    let mut chunk = Vec::with_capacity(data.len() * T::SIZE);
+    for &value in data {
+        value.write_le(&mut chunk);
+    }
+    write_raw_array(dir, name, T::DTYPE, data.len(), chunk, description)
+}
+
+/// Write strings as a fixed-width, null-padded byte array (`|S<n>`)
+fn write_string_array(dir: &Path, name: &str, data: &[&str], description: &str) -> Result<()> {
+    let width = data.iter().map(|s| s.len()).max().unwrap_or(0).max(1);
+    let mut chunk = Vec::with_capacity(data.len() * width);
+    for s in data {
+        chunk.extend_from_slice(s.as_bytes());
+        chunk.resize(chunk.len() + width - s.len(), 0);
+    }
+    write_raw_array(
+        dir,
+        name,
+        &format!("|S{}", width),
+        data.len(),
+        chunk,
+        description,
+    )
+}
+
+fn read_json(path: &Path) -> Result<Value> {
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+/// Read an array's descriptor and raw chunk, returning `(dtype, len, bytes)`
+fn read_raw_array(dir: &Path, name: &str) -> Result<(String, usize, Vec<u8>)> {
+    let array_dir: PathBuf = dir.join(name);
+    let zarray = read_json(&array_dir.join(".zarray"))?;
+    if !zarray["compressor"].is_null() || !zarray["filters"].is_null() {
+        return Err(NwbError::InvalidStore(format!(
+            "{}: compressed arrays are not supported",
+            array_dir.display()
+        )));
+    }
+    let len = zarray["shape"][0]
+        .as_u64()
+        .ok_or_else(|| NwbError::InvalidStore(format!("{}: missing shape", array_dir.display())))?
+        as usize;
+    let dtype = zarray["dtype"].as_str().unwrap_or_default().to_string();
+    let bytes = if len > 0 {
+        fs::read(array_dir.join("0"))?
+    } else {
+        Vec::new()
+    };
+    Ok((dtype, len, bytes))
+}
+
+fn read_array<T: ZarrElement>(dir: &Path, name: &str) -> Result<Vec<T>> {
+    let (dtype, len, bytes) = read_raw_array(dir, name)?;
+    if dtype != T::DTYPE {
+        return Err(NwbError::InvalidStore(format!(
+            "{}/{}: expected dtype {}, found {}",
+            dir.display(),
+            name,
+            T::DTYPE,
+            dtype
+        )));
+    }
+    if bytes.len() < len * T::SIZE {
+        return Err(NwbError::InvalidStore(format!(
+            "{}/{}: chunk is truncated",
+            dir.display(),
+            name
+        )));
+    }
+    Ok(bytes[..len * T::SIZE]
+        .chunks_exact(T::SIZE)
+        .map(T::read_le)
+        .collect())
+}
+
+/// Read a table column and check it matches the table length
+fn read_column<T: ZarrElement>(dir: &Path, name: &str, expected_len: usize) -> Result<Vec<T>> {
+    let column: Vec<T> = read_array(dir, name)?;
+    if column.len() != expected_len {
+        return Err(NwbError::InvalidStore(format!(
+            "{}/{}: expected {} rows, found {}",
+            dir.display(),
+            name,
+            expected_len,
+            column.len()
+        )));
+    }
+    Ok(column)
+}
+
+fn read_string_array(dir: &Path, name: &str) -> Result<Vec<String>> {
+    let (dtype, len, bytes) = read_raw_array(dir, name)?;
+    let width: usize = dtype
+        .strip_prefix("|S")
+        .and_then(|w| w.parse().ok())
+        .ok_or_else(|| {
+            NwbError::InvalidStore(format!("{}/{}: not a string array", dir.display(), name))
+        })?;
+    if bytes.len() < len * width {
+        return Err(NwbError::InvalidStore(format!(
+            "{}/{}: chunk is truncated",
+            dir.display(),
+            name
+        )));
+    }
+    Ok(bytes[..len * width]
+        .chunks_exact(width)
+        .map(|raw| {
+            let end = raw.iter().position(|&b| b == 0).unwrap_or(width);
+            String::from_utf8_lossy(&raw[..end]).into_owned()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_snapshot() -> ConnectomeSnapshot {
+        let mut neurons = SerializableNeuronArray::new(4);
+        neurons.count = 3;
+        for i in 0..3 {
+            neurons.thresholds[i] = 1.0 + i as f32;
+            neurons.membrane_potentials[i] = 0.25 * i as f32;
+            neurons.cortical_areas[i] = if i < 2 { 2 } else { 3 };
+            neurons.coordinates[i * 3..i * 3 + 3].copy_from_slice(&[i as u32, 1, 7 - i as u32]);
+            neurons.valid_mask[i] = true;
+        }
+        neurons.consecutive_fire_limits[1] = 3;
+        neurons.snooze_periods[1] = 2;
+
+        let mut synapses = SerializableSynapseArray::new(4);
+        synapses.count = 3;
+        for (i, (src, dst)) in [(0u32, 1u32), (1, 2), (2, 0)].into_iter().enumerate() {
+            synapses.source_neurons[i] = src;
+            synapses.target_neurons[i] = dst;
+            synapses.weights[i] = 10 * (i as u8 + 1);
+            synapses.postsynaptic_potentials[i] = 100;
+            synapses.types[i] = (i % 2) as u8;
+            synapses.valid_mask[i] = i != 1;
+        }
+
+        let mut cortical_area_names = ahash::AHashMap::new();
+        cortical_area_names.insert(2, "iv00_C".to_string());
+        cortical_area_names.insert(3, "o__mot".to_string());
+
+        let mut metadata = ConnectomeMetadata {
+            timestamp: 1_700_000_000,
+            description: "test".to_string(),
+            source: "unit test".to_string(),
+            ..Default::default()
+        };
+        metadata
+            .tags
+            .insert("lab".to_string(), "neuraville".to_string());
+
+        ConnectomeSnapshot {
+            version: 2,
+            neurons,
+            synapses,
+            cortical_area_names,
+            burst_count: 1234,
+            power_amount: 0.5,
+            fire_ledger_window: 20,
+            metadata,
+        }
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = dir.path().join("brain.nwb.zarr");
+        let snapshot = sample_snapshot();
+
+        let mut recording = SpikeRecording::new();
+        recording.record_burst(1, &[0, 2]);
+        recording.record_burst(2, &[2]);
+        recording.record_burst(5, &[1, 2]);
+
+        let options = NwbExportOptions {
+            identifier: "session-1".to_string(),
+            burst_interval_seconds: 0.05,
+            ..Default::default()
+        };
+        export_nwb(&snapshot, Some(&recording), &store, &options).unwrap();
+        let dataset = import_nwb(&store).unwrap();
+
+        assert_eq!(dataset.identifier, "session-1");
+        assert_eq!(dataset.burst_interval_seconds, 0.05);
+        assert_eq!(dataset.recording, recording);
+        assert_eq!(dataset.recording.spike_bursts(2), Some(&[1, 2, 5][..]));
+
+        let loaded = dataset.snapshot;
+        assert_eq!(loaded.burst_count, 1234);
+        assert_eq!(loaded.power_amount, 0.5);
+        assert_eq!(loaded.fire_ledger_window, 20);
+        assert_eq!(loaded.cortical_area_names, snapshot.cortical_area_names);
+        assert_eq!(loaded.metadata.tags, snapshot.metadata.tags);
+
+        assert_eq!(loaded.neurons.count, 3);
+        assert_eq!(loaded.neurons.thresholds, &snapshot.neurons.thresholds[..3]);
+        assert_eq!(
+            loaded.neurons.coordinates,
+            &snapshot.neurons.coordinates[..9]
+        );
+        assert_eq!(loaded.neurons.cortical_areas, vec![2, 2, 3]);
+        assert_eq!(
+            loaded.neurons.consecutive_fire_limits,
+            vec![u16::MAX, 3, u16::MAX]
+        );
+        assert_eq!(loaded.neurons.snooze_periods, vec![0, 2, 0]);
+
+        // The invalid synapse (index 1) is dropped
+        assert_eq!(loaded.synapses.count, 2);
+        assert_eq!(loaded.synapses.source_neurons, vec![0, 2]);
+        assert_eq!(loaded.synapses.target_neurons, vec![1, 0]);
+        assert_eq!(loaded.synapses.weights, vec![10, 30]);
+        assert_eq!(loaded.synapses.source_index[&2], vec![1]);
+        assert!(loaded.validate().is_ok());
+    }
+
+    #[test]
+    fn test_spike_times_in_seconds() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut recording = SpikeRecording::new();
+        recording.record_burst(10, &[0]);
+        recording.record_burst(20, &[0]);
+
+        let options = NwbExportOptions {
+            burst_interval_seconds: 0.1,
+            ..Default::default()
+        };
+        export_nwb(&sample_snapshot(), Some(&recording), dir.path(), &options).unwrap();
+
+        let times: Vec<f64> = read_array(&dir.path().join("units"), "spike_times").unwrap();
+        assert_eq!(times, vec![1.0, 2.0]);
+        let zarray = read_json(&dir.path().join("units/spike_times/.zarray")).unwrap();
+        assert_eq!(zarray["dtype"], "<f8");
+    }
+
+    #[test]
+    fn test_rejects_non_nwb_store() {
+        let dir = tempfile::tempdir().unwrap();
+        write_group(dir.path(), json!({})).unwrap();
+        assert!(matches!(
+            import_nwb(dir.path()),
+            Err(NwbError::InvalidStore(_))
+        ));
+    }
+
+    #[test]
+    fn test_export_rejects_short_columns() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = NwbExportOptions::default();
+
+        let mut snapshot = sample_snapshot();
+        snapshot.neurons.coordinates.truncate(4);
+        let result = export_nwb(&snapshot, None, dir.path().join("coords"), &options);
+        assert!(matches!(result, Err(NwbError::InvalidStore(_))));
+        // Nothing is written for a rejected snapshot
+        assert!(!dir.path().join("coords").exists());
+
+        let mut snapshot = sample_snapshot();
+        snapshot.neurons.snooze_periods.clear();
+        let result = export_nwb(&snapshot, None, dir.path().join("snooze"), &options);
+        assert!(matches!(result, Err(NwbError::InvalidStore(_))));
+
+        let mut snapshot = sample_snapshot();
+        snapshot.synapses.weights.truncate(1);
+        let result = export_nwb(&snapshot, None, dir.path().join("weights"), &options);
+        assert!(matches!(result, Err(NwbError::InvalidStore(_))));
+    }
+}
@@ -87,6 +87,35 @@ pub trait RuntimeService: Send + Sync {
     ///
     async fn set_frequency(&self, frequency_hz: f64) -> ServiceResult<()>;
 
+    /// Get burst pacing
+    ///
+    /// # Returns
+    /// * `BurstPacingMode` - Wall-clock or lockstep pacing
+    ///
+    async fn get_pacing(&self) -> ServiceResult<BurstPacingMode>;
+
+    /// Set burst pacing
+    ///
+    /// In lockstep pacing the burst engine runs one burst per [`tick`](Self::tick)
+    /// instead of following the burst frequency.
+    ///
+    /// # Arguments
+    /// * `pacing` - Wall-clock or lockstep pacing
+    ///
+    async fn set_pacing(&self, pacing: BurstPacingMode) -> ServiceResult<()>;
+
+    /// Run one lockstep burst
+    ///
+    /// Injects the request's sensory data, runs exactly one burst and returns
+    /// the motor output of that burst.
+    ///
+    /// # Errors
+    /// * `ServiceError::InvalidState` - Not running, or not in lockstep pacing
+    /// * `ServiceError::InvalidInput` - Malformed sensory data
+    /// * `ServiceError::Backend` - The burst failed or timed out
+    ///
+    async fn tick(&self, request: BurstTickRequest) -> ServiceResult<BurstTickResult>;
+
     /// Get current burst count
     ///
     /// Returns the total number of bursts executed since start.
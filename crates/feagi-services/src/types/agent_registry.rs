@@ -333,8 +333,13 @@ impl AgentRegistry {
             agent_info.agent_type,
             self.agents.len() + if is_reregistration { 0 } else { 1 }
         );
-        self.agents.insert(agent_id, agent_info);
+        self.agents.insert(agent_id.clone(), agent_info);
         self.refresh_agent_data_hash();
+        #[cfg(feature = "std")]
+        crate::impls::record_state_event(
+            "agent_registry",
+            feagi_state_manager::StateEvent::AgentRegistered { agent_id },
+        );
         Ok(())
     }
 
@@ -347,6 +352,13 @@ impl AgentRegistry {
                 self.agents.len()
             );
             self.refresh_agent_data_hash();
+            #[cfg(feature = "std")]
+            crate::impls::record_state_event(
+                "agent_registry",
+                feagi_state_manager::StateEvent::AgentDeregistered {
+                    agent_id: agent_id.to_string(),
+                },
+            );
             Ok(())
         } else {
             Err(format!("Agent {} not found", agent_id))
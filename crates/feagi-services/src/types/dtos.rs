@@ -397,6 +397,52 @@ pub struct RuntimeStatus {
 
     /// Average processing time per burst (milliseconds)
     pub avg_burst_time_ms: f64,
+
+    /// Why the burst engine stopped itself, if it did (e.g. a lost partition)
+    pub error: Option<String>,
+}
+
+/// How the burst engine decides when to run the next burst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BurstPacingMode {
+    /// Wall-clock pacing at the configured burst frequency
+    RealTime,
+    /// One burst per explicit tick (simulated time)
+    Lockstep,
+}
+
+/// XYZP neuron data for one cortical area (parallel arrays)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CorticalXyzpData {
+    /// Base64 cortical ID
+    pub cortical_id: String,
+    pub x: Vec<u32>,
+    pub y: Vec<u32>,
+    pub z: Vec<u32>,
+    pub p: Vec<f32>,
+}
+
+/// Input for one lockstep burst
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BurstTickRequest {
+    /// Sensory data for this burst; empty falls back to the agents' sensory stream
+    pub sensory: Vec<CorticalXyzpData>,
+    /// Motor cortical IDs to return; `None` returns every brain output area
+    pub motor_cortical_ids: Option<Vec<String>>,
+    /// How long to wait for the burst (milliseconds, default 5000)
+    pub timeout_ms: Option<u64>,
+}
+
+/// Result of one lockstep burst
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BurstTickResult {
+    /// Burst number of the burst this tick ran
+    pub burst: u64,
+    /// Neurons fired in the burst
+    pub fired_neuron_count: usize,
+    /// Fired neurons of the requested motor areas, sorted by cortical ID
+    pub motor: Vec<CorticalXyzpData>,
 }
 
 // ============================================================================
@@ -10,6 +10,7 @@ use atomic_polyfill::{AtomicU32, AtomicU64, AtomicU8, Ordering};
 
 /// Burst engine state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum BurstEngineState {
     Unavailable = 0,
@@ -39,6 +40,7 @@ impl From<u8> for BurstEngineState {
 
 /// Genome state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum GenomeState {
     Missing = 0,
@@ -0,0 +1,675 @@
+// Copyright 2025 Neuraville Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Append-only event journal (std only)
+//!
+//! Persists [`StateEvent`]s as JSON lines so a long-running brain keeps an
+//! audit trail of who changed what and when. Each line is one [`EventRecord`]
+//! with a monotonically increasing sequence number, a wall-clock timestamp
+//! (milliseconds since the Unix epoch) and the actor that caused the change.
+//!
+//! The journal is split into segment files next to the configured path, each
+//! named after its first sequence number (`state_events.jsonl` →
+//! `state_events.00000000000000000001.jsonl`). A new segment is started once
+//! the active one reaches [`JournalLimits::max_segment_bytes`], and the oldest
+//! segments beyond [`JournalLimits::max_segments`] are deleted.
+//!
+//! An in-memory index keeps each segment's sequence and time bounds plus the
+//! byte offset of every [`CHECKPOINT_INTERVAL`]-th record, so queries only
+//! open the segments they overlap and seek close to the first wanted
+//! sequence instead of parsing the whole history.
+//!
+//! ```ignore
+//! use feagi_state_manager::{EventJournal, JournalQuery, StateEvent};
+//!
+//! let journal = EventJournal::open("feagi_events.jsonl")?;
+//! let live = journal.subscribe();
+//! journal.append("api", StateEvent::GenomeLoaded { genome_id: "g1".into() })?;
+//! let recent = journal.query(&JournalQuery { start_ms: Some(start_ms), limit: Some(100), ..Default::default() })?;
+//! ```
+
+use crate::events::StateEvent;
+use crate::{Result, StateError};
+use parking_lot::{Mutex, RwLock};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tokio::sync::broadcast;
+
+/// Records between two index checkpoints within a segment
+pub const CHECKPOINT_INTERVAL: u64 = 256;
+
+/// One persisted journal entry
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventRecord {
+    /// Sequence number, starting at 1 and increasing by one per record
+    pub sequence: u64,
+    /// Milliseconds since the Unix epoch when the event was recorded
+    pub timestamp_ms: u64,
+    /// Component or client that caused the change
+    pub actor: String,
+    /// The state change itself
+    pub event: StateEvent,
+}
+
+/// Segment rotation and retention limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JournalLimits {
+    /// Start a new segment once the active one reaches this many bytes
+    pub max_segment_bytes: u64,
+    /// Delete the oldest segments beyond this count (0 = keep all)
+    pub max_segments: usize,
+}
+
+impl Default for JournalLimits {
+    fn default() -> Self {
+        Self {
+            max_segment_bytes: 16 * 1024 * 1024,
+            max_segments: 8,
+        }
+    }
+}
+
+/// Filters for [`EventJournal::query`]; all bounds are optional and inclusive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JournalQuery {
+    /// Only records with `sequence >= from_sequence`
+    pub from_sequence: Option<u64>,
+    /// Only records with `timestamp_ms >= start_ms`
+    pub start_ms: Option<u64>,
+    /// Only records with `timestamp_ms <= end_ms`
+    pub end_ms: Option<u64>,
+    /// Keep only the most recent `limit` matches
+    pub limit: Option<usize>,
+}
+
+impl JournalQuery {
+    #[allow(clippy::unnecessary_map_or)] // Option::is_none_or needs Rust 1.82 (MSRV is 1.75)
+    fn matches(&self, record: &EventRecord) -> bool {
+        self.from_sequence
+            .map_or(true, |from| record.sequence >= from)
+            && self
+                .start_ms
+                .map_or(true, |start| record.timestamp_ms >= start)
+            && self.end_ms.map_or(true, |end| record.timestamp_ms <= end)
+    }
+}
+
+/// Index entry for one segment file
+#[derive(Debug, Clone)]
+struct Segment {
+    path: PathBuf,
+    first_sequence: u64,
+    /// `first_sequence - 1` while the segment is empty
+    last_sequence: u64,
+    min_timestamp_ms: u64,
+    max_timestamp_ms: u64,
+    /// Bytes of complete records
+    len: u64,
+    /// `(sequence, byte offset)` of every `CHECKPOINT_INTERVAL`-th record
+    checkpoints: Vec<(u64, u64)>,
+}
+
+impl Segment {
+    fn new(path: PathBuf, first_sequence: u64) -> Self {
+        Self {
+            path,
+            first_sequence,
+            last_sequence: first_sequence - 1,
+            min_timestamp_ms: u64::MAX,
+            max_timestamp_ms: 0,
+            len: 0,
+            checkpoints: Vec::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.last_sequence < self.first_sequence
+    }
+
+    /// Index a record written at the end of the segment
+    #[allow(clippy::manual_is_multiple_of)] // u64::is_multiple_of needs Rust 1.87 (MSRV is 1.75)
+    fn push(&mut self, record: &EventRecord, line_len: u64) {
+        if (record.sequence - self.first_sequence) % CHECKPOINT_INTERVAL == 0 {
+            self.checkpoints.push((record.sequence, self.len));
+        }
+        self.last_sequence = record.sequence;
+        self.min_timestamp_ms = self.min_timestamp_ms.min(record.timestamp_ms);
+        self.max_timestamp_ms = self.max_timestamp_ms.max(record.timestamp_ms);
+        self.len += line_len;
+    }
+
+    #[allow(clippy::unnecessary_map_or)] // Option::is_none_or needs Rust 1.82 (MSRV is 1.75)
+    fn overlaps(&self, query: &JournalQuery) -> bool {
+        !self.is_empty()
+            && query
+                .from_sequence
+                .map_or(true, |from| self.last_sequence >= from)
+            && query
+                .start_ms
+                .map_or(true, |start| self.max_timestamp_ms >= start)
+            && query
+                .end_ms
+                .map_or(true, |end| self.min_timestamp_ms <= end)
+    }
+
+    /// Byte offset of the last checkpoint at or before `from_sequence`
+    fn seek_offset(&self, from_sequence: Option<u64>) -> u64 {
+        let Some(from) = from_sequence else {
+            return 0;
+        };
+        let after = self.checkpoints.partition_point(|&(seq, _)| seq <= from);
+        after
+            .checked_sub(1)
+            .map_or(0, |idx| self.checkpoints[idx].1)
+    }
+}
+
+struct JournalWriter {
+    file: File,
+    next_sequence: u64,
+}
+
+/// Append-only, replayable journal of state events backed by rotating JSON-lines segments
+pub struct EventJournal {
+    path: PathBuf,
+    limits: JournalLimits,
+    writer: Mutex<JournalWriter>,
+    /// Oldest first; the last segment is the one being appended to
+    segments: RwLock<Vec<Segment>>,
+    /// Live appends (bounded; slow subscribers lag)
+    live_tx: broadcast::Sender<EventRecord>,
+}
+
+impl EventJournal {
+    /// Open (or create) a journal with default limits, continuing the existing sequence
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open_with_limits(path, JournalLimits::default())
+    }
+
+    /// Open (or create) a journal, continuing the existing sequence
+    ///
+    /// Scans the existing segments once to build the index.
+    pub fn open_with_limits(path: impl AsRef<Path>, limits: JournalLimits) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut segments = Vec::new();
+        let existing = list_segments(&path)?;
+        for (position, (first_sequence, segment_path)) in existing.iter().enumerate() {
+            if position + 1 == existing.len() {
+                truncate_torn_tail(segment_path)?;
+            }
+            segments.push(index_segment(segment_path.clone(), *first_sequence)?);
+        }
+        if segments.is_empty() {
+            segments.push(Segment::new(segment_path(&path, 1), 1));
+        }
+
+        let active = segments.last().expect("journal has an active segment");
+        let next_sequence = active.last_sequence + 1;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&active.path)?;
+
+        Ok(Self {
+            path,
+            limits,
+            writer: Mutex::new(JournalWriter {
+                file,
+                next_sequence,
+            }),
+            segments: RwLock::new(segments),
+            live_tx: broadcast::channel(crate::CHANGE_BUFFER).0,
+        })
+    }
+
+    /// Configured journal path; segment files are named after it
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Sequence number of the most recent record (0 if the journal is empty)
+    pub fn last_sequence(&self) -> u64 {
+        self.writer.lock().next_sequence - 1
+    }
+
+    /// Append an event, flush it to disk and forward it to subscribers
+    pub fn append(&self, actor: &str, event: StateEvent) -> Result<EventRecord> {
+        let record = {
+            let mut writer = self.writer.lock();
+            let record = EventRecord {
+                sequence: writer.next_sequence,
+                timestamp_ms: now_ms(),
+                actor: actor.to_string(),
+                event,
+            };
+
+            let mut line = serde_json::to_vec(&record)
+                .map_err(|e| StateError::SerializationError(e.to_string()))?;
+            line.push(b'\n');
+            let line_len = line.len() as u64;
+
+            let active_len = self.segments.read().last().map_or(0, |s| s.len);
+            if active_len > 0 && active_len + line_len > self.limits.max_segment_bytes {
+                self.rotate(&mut writer)?;
+            }
+
+            writer.file.write_all(&line)?;
+            writer.file.flush()?;
+            writer.next_sequence += 1;
+            if let Some(active) = self.segments.write().last_mut() {
+                active.push(&record, line_len);
+            }
+            record
+        };
+
+        // Err only means nobody is subscribed
+        let _ = self.live_tx.send(record.clone());
+
+        Ok(record)
+    }
+
+    /// Start a new segment at the writer's next sequence and apply retention
+    fn rotate(&self, writer: &mut JournalWriter) -> Result<()> {
+        let path = segment_path(&self.path, writer.next_sequence);
+        writer.file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        let mut segments = self.segments.write();
+        segments.push(Segment::new(path, writer.next_sequence));
+        if self.limits.max_segments > 0 && segments.len() > self.limits.max_segments {
+            let expired = segments.len() - self.limits.max_segments;
+            for segment in segments.drain(..expired) {
+                // Readers that already opened the file keep their handle. A file
+                // that cannot be deleted is only dropped from the index.
+                let _ = std::fs::remove_file(&segment.path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Read every retained record, oldest first
+    pub fn replay(&self) -> Result<Vec<EventRecord>> {
+        self.query(&JournalQuery::default())
+    }
+
+    /// Read all retained records with `sequence >= from_sequence`
+    pub fn replay_from(&self, from_sequence: u64) -> Result<Vec<EventRecord>> {
+        self.query(&JournalQuery {
+            from_sequence: Some(from_sequence),
+            ..Default::default()
+        })
+    }
+
+    /// Read records whose timestamp falls in `[start_ms, end_ms]` (bounds optional)
+    pub fn query_time_range(
+        &self,
+        start_ms: Option<u64>,
+        end_ms: Option<u64>,
+    ) -> Result<Vec<EventRecord>> {
+        self.query(&JournalQuery {
+            start_ms,
+            end_ms,
+            ..Default::default()
+        })
+    }
+
+    /// Read the retained records matching `query`, oldest first
+    ///
+    /// Only segments overlapping the query are read, starting from the
+    /// nearest checkpoint. With a `limit`, segments are read newest first
+    /// and reading stops once enough records are found.
+    ///
+    /// Reads go through their own file handles and stop at the length
+    /// indexed when the query started, so appends are never blocked and a
+    /// record being appended concurrently is never seen half-written.
+    pub fn query(&self, query: &JournalQuery) -> Result<Vec<EventRecord>> {
+        let segments: Vec<Segment> = self
+            .segments
+            .read()
+            .iter()
+            .filter(|segment| segment.overlaps(query))
+            .cloned()
+            .collect();
+
+        let mut chunks = Vec::new();
+        let mut found = 0;
+        for segment in segments.iter().rev() {
+            let records = read_segment(segment, query)?;
+            found += records.len();
+            chunks.push(records);
+            if query.limit.is_some_and(|limit| found >= limit) {
+                break;
+            }
+        }
+
+        let mut records: Vec<EventRecord> = chunks.into_iter().rev().flatten().collect();
+        if let Some(limit) = query.limit {
+            let skip = records.len().saturating_sub(limit);
+            records.drain(..skip);
+        }
+        Ok(records)
+    }
+
+    /// Receive every record appended from now on
+    ///
+    /// A subscriber more than [`CHANGE_BUFFER`](crate::CHANGE_BUFFER) records
+    /// behind gets `RecvError::Lagged` and can catch up with
+    /// [`replay_from`](Self::replay_from).
+    pub fn subscribe(&self) -> broadcast::Receiver<EventRecord> {
+        self.live_tx.subscribe()
+    }
+}
+
+/// Segment file for records starting at `first_sequence`
+fn segment_path(base: &Path, first_sequence: u64) -> PathBuf {
+    let stem = base
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match base.extension() {
+        Some(ext) => format!("{}.{:020}.{}", stem, first_sequence, ext.to_string_lossy()),
+        None => format!("{}.{:020}", stem, first_sequence),
+    };
+    base.with_file_name(name)
+}
+
+/// Existing segments of the journal at `base`, ordered by first sequence
+fn list_segments(base: &Path) -> Result<Vec<(u64, PathBuf)>> {
+    let dir = match base.parent().filter(|p| !p.as_os_str().is_empty()) {
+        Some(parent) => parent.to_path_buf(),
+        None => PathBuf::from("."),
+    };
+    let stem = base
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let prefix = format!("{}.", stem);
+    let suffix = base
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut segments = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let first_sequence = name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(&suffix))
+            .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<u64>().ok())
+            .filter(|&first| first > 0);
+        if let Some(first_sequence) = first_sequence {
+            segments.push((first_sequence, entry.path()));
+        }
+    }
+    segments.sort_unstable_by_key(|&(first_sequence, _)| first_sequence);
+    Ok(segments)
+}
+
+/// Build the index entry for an existing segment file
+fn index_segment(path: PathBuf, first_sequence: u64) -> Result<Segment> {
+    let mut segment = Segment::new(path, first_sequence);
+    let mut reader = BufReader::new(File::open(&segment.path)?);
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        let line_len = reader.read_line(&mut line)? as u64;
+        if line_len == 0 {
+            break;
+        }
+        line_number += 1;
+        if line.trim().is_empty() {
+            segment.len += line_len;
+            continue;
+        }
+        let record = parse_record(&segment.path, line_number, &line)?;
+        segment.push(&record, line_len);
+    }
+    Ok(segment)
+}
+
+/// Read the records of one segment that match `query`
+fn read_segment(segment: &Segment, query: &JournalQuery) -> Result<Vec<EventRecord>> {
+    let mut file = match File::open(&segment.path) {
+        Ok(file) => file,
+        // Deleted by retention after the query started
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let offset = segment.seek_offset(query.from_sequence);
+    file.seek(SeekFrom::Start(offset))?;
+    let reader = BufReader::new(file.take(segment.len - offset));
+
+    let mut records = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = parse_record(&segment.path, index + 1, &line)?;
+        if query.matches(&record) {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+fn parse_record(path: &Path, line_number: usize, line: &str) -> Result<EventRecord> {
+    serde_json::from_str::<EventRecord>(line).map_err(|e| {
+        StateError::PersistenceError(format!(
+            "Corrupt event journal {} at line {}: {}",
+            path.display(),
+            line_number,
+            e
+        ))
+    })
+}
+
+/// Drop a partially written final line (crash mid-append) so new records start on a fresh line
+fn truncate_torn_tail(path: &Path) -> Result<()> {
+    let data = std::fs::read(path)?;
+    if data.last().is_some_and(|byte| *byte != b'\n') {
+        let keep = data
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |pos| pos + 1);
+        OpenOptions::new()
+            .write(true)
+            .open(path)?
+            .set_len(keep as u64)?;
+    }
+    Ok(())
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_state::BurstEngineState;
+
+    fn temp_journal_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "feagi_event_journal_{}_{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::remove_dir_all(&dir).ok();
+        dir.join("events.jsonl")
+    }
+
+    fn agent_event(agent_id: &str) -> StateEvent {
+        StateEvent::AgentRegistered {
+            agent_id: agent_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_append_replay_and_reopen() {
+        let path = temp_journal_path("reopen");
+
+        {
+            let journal = EventJournal::open(&path).unwrap();
+            journal
+                .append(
+                    "api",
+                    StateEvent::GenomeLoaded {
+                        genome_id: "g1".to_string(),
+                    },
+                )
+                .unwrap();
+            journal
+                .append(
+                    "burst_engine",
+                    StateEvent::BurstEngineStateChanged {
+                        state: BurstEngineState::Running,
+                    },
+                )
+                .unwrap();
+        }
+
+        let journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.last_sequence(), 2);
+        let record = journal
+            .append(
+                "api",
+                StateEvent::CorticalAreaDeleted {
+                    cortical_id: "abc".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(record.sequence, 3);
+
+        let records = journal.replay().unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1].actor, "burst_engine");
+        assert_eq!(
+            records[1].event,
+            StateEvent::BurstEngineStateChanged {
+                state: BurstEngineState::Running
+            }
+        );
+        assert_eq!(journal.replay_from(3).unwrap(), vec![record]);
+
+        // Reads do not wait for an append in progress
+        {
+            let _append_in_progress = journal.writer.lock();
+            assert_eq!(journal.replay().unwrap().len(), 3);
+            assert_eq!(journal.query_time_range(None, None).unwrap().len(), 3);
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn test_time_range_subscribe_and_torn_tail() {
+        let path = temp_journal_path("query");
+        let journal = EventJournal::open(&path).unwrap();
+        let mut rx = journal.subscribe();
+
+        let record = journal
+            .append("agent_registry", agent_event("video"))
+            .unwrap();
+        assert_eq!(rx.try_recv().unwrap(), record);
+
+        let ts = record.timestamp_ms;
+        assert_eq!(
+            journal.query_time_range(Some(ts), Some(ts)).unwrap().len(),
+            1
+        );
+        assert!(journal
+            .query_time_range(Some(ts + 1), None)
+            .unwrap()
+            .is_empty());
+        assert!(journal
+            .query_time_range(None, Some(ts - 1))
+            .unwrap()
+            .is_empty());
+
+        // Simulate a crash halfway through writing a record
+        drop(journal);
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(segment_path(&path, 1))
+            .unwrap();
+        file.write_all(b"{\"sequence\":2,\"times").unwrap();
+        let journal = EventJournal::open(&path).unwrap();
+        assert_eq!(journal.replay().unwrap().len(), 1);
+        let record = journal
+            .append(
+                "agent_registry",
+                StateEvent::AgentDeregistered {
+                    agent_id: "video".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(record.sequence, 2);
+        assert_eq!(journal.replay().unwrap().len(), 2);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn test_rotation_retention_and_indexed_queries() {
+        let path = temp_journal_path("rotation");
+        let limits = JournalLimits {
+            max_segment_bytes: 4096,
+            max_segments: 3,
+        };
+        let journal = EventJournal::open_with_limits(&path, limits).unwrap();
+        for i in 0..1000 {
+            journal
+                .append("agent_registry", agent_event(&format!("agent{}", i)))
+                .unwrap();
+        }
+
+        // Only the newest segments are kept, and they are contiguous up to the last record
+        assert_eq!(list_segments(&path).unwrap().len(), 3);
+        let records = journal.replay().unwrap();
+        let first = records[0].sequence;
+        assert!(first > 1);
+        assert!(records
+            .iter()
+            .zip(first..)
+            .all(|(record, sequence)| record.sequence == sequence));
+        assert_eq!(records.last().unwrap().sequence, 1000);
+
+        // Seeks within a segment land on the requested sequence
+        let from = journal.replay_from(990).unwrap();
+        assert_eq!(from.len(), 11);
+        assert_eq!(from[0].sequence, 990);
+
+        let tail = journal
+            .query(&JournalQuery {
+                from_sequence: Some(first),
+                limit: Some(5),
+                ..Default::default()
+            })
+            .unwrap();
+        let sequences: Vec<u64> = tail.iter().map(|record| record.sequence).collect();
+        assert_eq!(sequences, vec![996, 997, 998, 999, 1000]);
+
+        // Reopening rebuilds the index from the retained segments
+        drop(journal);
+        let journal = EventJournal::open_with_limits(&path, limits).unwrap();
+        assert_eq!(journal.last_sequence(), 1000);
+        assert_eq!(journal.replay().unwrap(), records);
+        assert_eq!(
+            journal.append("api", agent_event("next")).unwrap().sequence,
+            1001
+        );
+
+        std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+}
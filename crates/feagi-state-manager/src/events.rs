@@ -2,14 +2,77 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Event streaming for state changes
+//!
+//! On `std` targets events can be persisted with
+//! [`EventJournal`](crate::event_journal::EventJournal).
+
+use crate::core_state::{BurstEngineState, GenomeState};
 
 /// State change event
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
 pub enum StateEvent {
-    BurstEngineStateChanged,
-    GenomeStateChanged,
-    AgentRegistered(String),
-    AgentDeregistered(String),
+    BurstEngineStateChanged {
+        state: BurstEngineState,
+    },
+    GenomeStateChanged {
+        state: GenomeState,
+    },
+    GenomeLoaded {
+        genome_id: String,
+    },
+    CorticalAreaCreated {
+        cortical_id: String,
+    },
+    CorticalAreaUpdated {
+        cortical_id: String,
+    },
+    CorticalAreaDeleted {
+        cortical_id: String,
+    },
+    CorticalMappingChanged {
+        src_cortical_id: String,
+        dst_cortical_id: String,
+    },
+    AgentRegistered {
+        agent_id: String,
+    },
+    AgentDeregistered {
+        agent_id: String,
+    },
+}
+
+impl StateEvent {
+    /// Short snake_case name of the event kind (matches the serialized `type` tag)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            StateEvent::BurstEngineStateChanged { .. } => "burst_engine_state_changed",
+            StateEvent::GenomeStateChanged { .. } => "genome_state_changed",
+            StateEvent::GenomeLoaded { .. } => "genome_loaded",
+            StateEvent::CorticalAreaCreated { .. } => "cortical_area_created",
+            StateEvent::CorticalAreaUpdated { .. } => "cortical_area_updated",
+            StateEvent::CorticalAreaDeleted { .. } => "cortical_area_deleted",
+            StateEvent::CorticalMappingChanged { .. } => "cortical_mapping_changed",
+            StateEvent::AgentRegistered { .. } => "agent_registered",
+            StateEvent::AgentDeregistered { .. } => "agent_deregistered",
+        }
+    }
+}
+
+/// Live state change notification (see `StateManager::subscribe_changes`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateChange {
+    /// One of the [`HashState`](crate::hash_state::HashState) hashes changed value
+    HashChanged {
+        kind: crate::hash_state::HashKind,
+        hash: u64,
+    },
+    /// A state event was recorded
+    Event(StateEvent),
 }
 
 /// Event channel
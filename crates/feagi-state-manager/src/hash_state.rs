@@ -7,6 +7,41 @@
 
 use core::sync::atomic::{AtomicU64, Ordering};
 
+/// Identifies one of the hashes tracked by [`HashState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashKind {
+    BrainRegions,
+    CorticalAreas,
+    BrainGeometry,
+    Morphologies,
+    CorticalMappings,
+    AgentData,
+}
+
+impl HashKind {
+    /// All hash kinds, in declaration order.
+    pub const ALL: [HashKind; 6] = [
+        HashKind::BrainRegions,
+        HashKind::CorticalAreas,
+        HashKind::BrainGeometry,
+        HashKind::Morphologies,
+        HashKind::CorticalMappings,
+        HashKind::AgentData,
+    ];
+
+    /// snake_case name matching the health_check `*_hash` field prefix.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashKind::BrainRegions => "brain_regions",
+            HashKind::CorticalAreas => "cortical_areas",
+            HashKind::BrainGeometry => "brain_geometry",
+            HashKind::Morphologies => "morphologies",
+            HashKind::CorticalMappings => "cortical_mappings",
+            HashKind::AgentData => "agent_data",
+        }
+    }
+}
+
 /// Atomic storage for FEAGI data hashes (event-driven updates).
 #[derive(Debug, Default)]
 pub struct HashState {
@@ -83,4 +118,25 @@ impl HashState {
     pub fn set_agent_data_hash(&self, value: u64) {
         self.agent_data_hash.store(value, Ordering::Release);
     }
+
+    /// Get a hash by kind.
+    pub fn get(&self, kind: HashKind) -> u64 {
+        self.slot(kind).load(Ordering::Acquire)
+    }
+
+    /// Set a hash by kind, returning the previous value.
+    pub fn swap(&self, kind: HashKind, value: u64) -> u64 {
+        self.slot(kind).swap(value, Ordering::AcqRel)
+    }
+
+    fn slot(&self, kind: HashKind) -> &AtomicU64 {
+        match kind {
+            HashKind::BrainRegions => &self.brain_regions_hash,
+            HashKind::CorticalAreas => &self.cortical_areas_hash,
+            HashKind::BrainGeometry => &self.brain_geometry_hash,
+            HashKind::Morphologies => &self.morphologies_hash,
+            HashKind::CorticalMappings => &self.cortical_mappings_hash,
+            HashKind::AgentData => &self.agent_data_hash,
+        }
+    }
 }
@@ -73,6 +73,8 @@ extern crate alloc;
 pub mod agent_registry; // Agent management
 pub mod core_state; // Memory-mapped atomic state
 pub mod cortical_locks; // Cortical locking
+#[cfg(feature = "std")]
+pub mod event_journal; // Persistent event log
 pub mod events; // Event streaming
 pub mod fcl_cache; // FCL window size cache
 pub mod hash_state; // Event-driven data hashes
@@ -89,11 +91,16 @@ pub use agent_registry::{AgentInfo, AgentRegistry, AgentType};
 #[cfg(feature = "std")]
 pub use cortical_locks::CorticalLockManager;
 
+pub use events::{StateChange, StateEvent};
+
+#[cfg(feature = "std")]
+pub use event_journal::{EventJournal, EventRecord, JournalLimits, JournalQuery};
+
 #[cfg(feature = "std")]
 pub use fcl_cache::FCLWindowCache;
 
 #[cfg(feature = "std")]
-pub use hash_state::HashState;
+pub use hash_state::{HashKind, HashState};
 
 #[cfg(feature = "std")]
 pub use persistence::StateSnapshot;
@@ -338,6 +345,12 @@ pub struct StateManager {
 
     /// Event-driven data hashes for health check change detection
     hash_state: HashState,
+
+    /// Optional persistent journal of state change events
+    event_journal: RwLock<Option<Arc<EventJournal>>>,
+
+    /// Live hash changes and recorded events (bounded; slow subscribers lag)
+    change_tx: tokio::sync::broadcast::Sender<StateChange>,
 }
 
 #[cfg(feature = "std")]
@@ -356,6 +369,8 @@ impl StateManager {
             fcl_cache: std::sync::Arc::new(FCLWindowCache::new(fcl_window)),
             cortical_area_stats: std::sync::Arc::new(CorticalAreaStatsRegistry::new()),
             hash_state: HashState::new(),
+            event_journal: RwLock::new(None),
+            change_tx: tokio::sync::broadcast::channel(CHANGE_BUFFER).0,
         })
     }
 
@@ -366,9 +381,16 @@ impl StateManager {
         self.core_state.get_burst_engine_state()
     }
 
-    /// Set burst engine state
+    /// Set burst engine state (journaled when it changes)
     pub fn set_burst_engine_state(&self, state: BurstEngineState) {
-        self.core_state.set_burst_engine_state(state)
+        let previous = self.core_state.get_burst_engine_state();
+        self.core_state.set_burst_engine_state(state);
+        if previous != state {
+            let _ = self.record_event(
+                STATE_MANAGER_ACTOR,
+                StateEvent::BurstEngineStateChanged { state },
+            );
+        }
     }
 
     /// Get genome state
@@ -376,9 +398,16 @@ impl StateManager {
         self.core_state.get_genome_state()
     }
 
-    /// Set genome state
+    /// Set genome state (journaled when it changes)
     pub fn set_genome_state(&self, state: GenomeState) {
-        self.core_state.set_genome_state(state)
+        let previous = self.core_state.get_genome_state();
+        self.core_state.set_genome_state(state);
+        if previous != state {
+            let _ = self.record_event(
+                STATE_MANAGER_ACTOR,
+                StateEvent::GenomeStateChanged { state },
+            );
+        }
     }
 
     /// Check if brain is ready
@@ -400,7 +429,7 @@ impl StateManager {
 
     /// Set brain regions hash.
     pub fn set_brain_regions_hash(&self, value: u64) {
-        self.hash_state.set_brain_regions_hash(value)
+        self.set_hash(HashKind::BrainRegions, value)
     }
 
     /// Get cortical areas hash.
@@ -410,7 +439,7 @@ impl StateManager {
 
     /// Set cortical areas hash.
     pub fn set_cortical_areas_hash(&self, value: u64) {
-        self.hash_state.set_cortical_areas_hash(value)
+        self.set_hash(HashKind::CorticalAreas, value)
     }
 
     /// Get brain geometry hash.
@@ -420,7 +449,7 @@ impl StateManager {
 
     /// Set brain geometry hash.
     pub fn set_brain_geometry_hash(&self, value: u64) {
-        self.hash_state.set_brain_geometry_hash(value)
+        self.set_hash(HashKind::BrainGeometry, value)
     }
 
     /// Get morphologies hash.
@@ -430,7 +459,7 @@ impl StateManager {
 
     /// Set morphologies hash.
     pub fn set_morphologies_hash(&self, value: u64) {
-        self.hash_state.set_morphologies_hash(value)
+        self.set_hash(HashKind::Morphologies, value)
     }
 
     /// Get cortical mappings hash.
@@ -440,7 +469,7 @@ impl StateManager {
 
     /// Set cortical mappings hash.
     pub fn set_cortical_mappings_hash(&self, value: u64) {
-        self.hash_state.set_cortical_mappings_hash(value)
+        self.set_hash(HashKind::CorticalMappings, value)
     }
 
     /// Get agent data hash.
@@ -450,15 +479,79 @@ impl StateManager {
 
     /// Set agent data hash.
     pub fn set_agent_data_hash(&self, value: u64) {
-        self.hash_state.set_agent_data_hash(value)
+        self.set_hash(HashKind::AgentData, value)
+    }
+
+    /// Set a hash by kind, notifying change subscribers if the value changed.
+    pub fn set_hash(&self, kind: HashKind, value: u64) {
+        if self.hash_state.swap(kind, value) != value {
+            self.publish_change(StateChange::HashChanged { kind, hash: value });
+        }
+    }
+
+    /// Get a hash by kind.
+    pub fn get_hash(&self, kind: HashKind) -> u64 {
+        self.hash_state.get(kind)
+    }
+
+    // ===== Change Notifications =====
+
+    /// Receive hash changes and recorded events from now on
+    ///
+    /// The channel holds the last [`CHANGE_BUFFER`] changes. A subscriber that
+    /// falls further behind gets `RecvError::Lagged` and should resync from
+    /// the current state instead of blocking publishers.
+    pub fn subscribe_changes(&self) -> tokio::sync::broadcast::Receiver<StateChange> {
+        self.change_tx.subscribe()
+    }
+
+    fn publish_change(&self, change: StateChange) {
+        // Err only means nobody is subscribed
+        let _ = self.change_tx.send(change);
+    }
+
+    // ===== Event Journal =====
+
+    /// Attach a persistent event journal; subsequent state changes are recorded to it
+    pub fn attach_event_journal(&self, journal: Arc<EventJournal>) {
+        *self.event_journal.write() = Some(journal);
+    }
+
+    /// Detach the event journal, returning it if one was attached
+    pub fn detach_event_journal(&self) -> Option<Arc<EventJournal>> {
+        self.event_journal.write().take()
+    }
+
+    /// Get the attached event journal, if any
+    pub fn get_event_journal(&self) -> Option<Arc<EventJournal>> {
+        self.event_journal.read().clone()
+    }
+
+    /// Record a state change event on behalf of `actor`
+    ///
+    /// The event is always published to change subscribers; it is persisted
+    /// only when a journal is attached (otherwise `Ok(None)` is returned).
+    /// Callers that must never block a state change on journal I/O should
+    /// ignore the error.
+    pub fn record_event(&self, actor: &str, event: StateEvent) -> Result<Option<EventRecord>> {
+        self.publish_change(StateChange::Event(event.clone()));
+        match self.get_event_journal() {
+            Some(journal) => journal.append(actor, event).map(Some),
+            None => Ok(None),
+        }
     }
 
     // ===== Agent Management =====
 
     /// Register a new agent
     pub fn register_agent(&self, info: AgentInfo) -> Result<()> {
+        let agent_id = info.agent_id.clone();
         self.agent_registry.register(info)?;
         self.core_state.increment_agent_count();
+        let _ = self.record_event(
+            STATE_MANAGER_ACTOR,
+            StateEvent::AgentRegistered { agent_id },
+        );
         Ok(())
     }
 
@@ -466,6 +559,12 @@ impl StateManager {
     pub fn deregister_agent(&self, agent_id: &str) -> Result<()> {
         self.agent_registry.deregister(agent_id)?;
         self.core_state.decrement_agent_count();
+        let _ = self.record_event(
+            STATE_MANAGER_ACTOR,
+            StateEvent::AgentDeregistered {
+                agent_id: agent_id.to_string(),
+            },
+        );
         Ok(())
     }
 
@@ -592,6 +691,14 @@ impl StateManager {
     }
 }
 
+/// Actor recorded for events emitted by the state manager itself
+#[cfg(feature = "std")]
+const STATE_MANAGER_ACTOR: &str = "state_manager";
+
+/// State changes buffered per subscriber before it starts lagging
+#[cfg(feature = "std")]
+pub const CHANGE_BUFFER: usize = 1024;
+
 // ===== Singleton Pattern =====
 
 /// Global singleton instance of StateManager
@@ -701,6 +808,93 @@ mod tests {
         assert_eq!(state.get_fcl_window(0), 30);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_state_manager_event_journal() {
+        let state = StateManager::new().unwrap();
+        let dir =
+            std::env::temp_dir().join(format!("feagi_state_manager_events_{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let path = dir.join("events.jsonl");
+
+        // Without a journal, recording is a no-op
+        assert!(state
+            .record_event(
+                "test",
+                StateEvent::GenomeLoaded {
+                    genome_id: "g0".to_string()
+                }
+            )
+            .unwrap()
+            .is_none());
+
+        state.attach_event_journal(Arc::new(EventJournal::open(&path).unwrap()));
+        state.set_burst_engine_state(BurstEngineState::Running);
+        state.set_burst_engine_state(BurstEngineState::Running); // unchanged, not journaled
+        state
+            .register_agent(AgentInfo::new("agent1".to_string(), AgentType::Sensory))
+            .unwrap();
+        state
+            .record_event(
+                "api",
+                StateEvent::CorticalAreaCreated {
+                    cortical_id: "area".to_string(),
+                },
+            )
+            .unwrap();
+
+        let records = state.get_event_journal().unwrap().replay().unwrap();
+        let kinds: Vec<_> = records.iter().map(|r| r.event.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "burst_engine_state_changed",
+                "agent_registered",
+                "cortical_area_created"
+            ]
+        );
+        assert_eq!(records[2].actor, "api");
+
+        state.detach_event_journal();
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_state_manager_change_subscription() {
+        let state = StateManager::new().unwrap();
+        let mut changes = state.subscribe_changes();
+
+        state.set_cortical_areas_hash(42);
+        state.set_cortical_areas_hash(42); // unchanged, not published
+        state.set_burst_engine_state(BurstEngineState::Running);
+
+        assert_eq!(
+            changes.try_recv().unwrap(),
+            StateChange::HashChanged {
+                kind: HashKind::CorticalAreas,
+                hash: 42
+            }
+        );
+        assert_eq!(
+            changes.try_recv().unwrap(),
+            StateChange::Event(StateEvent::BurstEngineStateChanged {
+                state: BurstEngineState::Running
+            })
+        );
+        assert!(changes.try_recv().is_err());
+        assert_eq!(state.get_hash(HashKind::CorticalAreas), 42);
+
+        // A subscriber that falls behind is told how much it missed
+        for hash in 0..=CHANGE_BUFFER as u64 {
+            state.set_agent_data_hash(hash + 1);
+        }
+        assert_eq!(
+            changes.try_recv(),
+            Err(tokio::sync::broadcast::error::TryRecvError::Lagged(1))
+        );
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_state_manager_persistence() {
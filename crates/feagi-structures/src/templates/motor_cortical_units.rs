@@ -81,6 +81,21 @@ macro_rules! motor_cortical_units {
                     }
                 },
 
+                #[doc = "Speaker / tone output - frequency bands along y with loudness along z (louder is closer to z=0), synthesized into audio."]
+                Speaker => {
+                    friendly_name: "Speaker",
+                    accepted_wrapped_io_data_type: AudioFrame,
+                    cortical_id_unit_reference: *b"spk",
+                    number_cortical_areas: 1,
+                    cortical_type_parameters: {
+                        frame_change_handling: FrameChangeHandling,
+                    },
+                    cortical_area_properties: {
+                        // 1x24x10 default: 24 log spaced tone bands (2 octaves of semitones), 10 loudness levels
+                        0 => (IOCorticalAreaConfigurationFlag::Misc(frame_change_handling), relative_position: [80, 0, -10], channel_dimensions_default: [1, 24, 10], channel_dimensions_min: [1, 1, 1], channel_dimensions_max: [1, 1024, 1024])
+                    }
+                },
+
                 #[doc = "Count output - unsigned percentage encoding (linear, absolute)."]
                 CountOutput => {
                     friendly_name: "Count Output",
@@ -191,6 +191,49 @@ macro_rules! sensor_cortical_units {
                 },
 
 
+                #[doc = "Log-polar foveated vision, with angle around the fovea along x and rings of logarithmically increasing eccentricity along y (the fovea is at y=0)."]
+                LogPolarVision => {
+                    friendly_name: "Log-Polar Vision",
+                    accepted_wrapped_io_data_type: ImageFrame,
+                    cortical_id_unit_reference: *b"lpv",
+                    number_cortical_areas: 1,
+                    cortical_type_parameters: {
+                        frame_change_handling: FrameChangeHandling,
+                    },
+                    cortical_area_properties: {
+                        0 => (IOCorticalAreaConfigurationFlag::CartesianPlane(frame_change_handling), relative_position: [-100, -40, 0], channel_dimensions_default: [32, 16, 1], channel_dimensions_min: [1, 2, 1], channel_dimensions_max: [4096, 4096, 3])
+                    }
+                },
+
+                #[doc = "Event camera (DVS) input, with events binned per burst into ON (z=0) and OFF (z=1) polarity layers."]
+                EventCamera => {
+                    friendly_name: "Event Camera",
+                    accepted_wrapped_io_data_type: EventStream,
+                    cortical_id_unit_reference: *b"dvs",
+                    number_cortical_areas: 1,
+                    cortical_type_parameters: {
+                        frame_change_handling: FrameChangeHandling,
+                    },
+                    cortical_area_properties: {
+                        0 => (IOCorticalAreaConfigurationFlag::CartesianPlane(frame_change_handling), relative_position: [-100, 100, 0], channel_dimensions_default: [128, 128, 2], channel_dimensions_min: [1, 1, 2], channel_dimensions_max: [4096, 4096, 2])
+                    }
+                },
+
+                #[doc = "Microphone audio input, encoded as a mel spectrogram with time along x, frequency bands along y and loudness along z (louder is closer to z=0)."]
+                Microphone => {
+                    friendly_name: "Microphone",
+                    accepted_wrapped_io_data_type: AudioFrame,
+                    cortical_id_unit_reference: *b"mic",
+                    number_cortical_areas: 1,
+                    cortical_type_parameters: {
+                        frame_change_handling: FrameChangeHandling,
+                    },
+                    cortical_area_properties: {
+                        // 7x32x8 default: 512 samples with a 128 sample FFT window at 50% overlap, 32 mel bands, 8 loudness levels
+                        0 => (IOCorticalAreaConfigurationFlag::Misc(frame_change_handling), relative_position: [-100, 30, -40], channel_dimensions_default: [7, 32, 8], channel_dimensions_min: [1, 1, 1], channel_dimensions_max: [1024, 1024, 1024])
+                    }
+                },
+
                 #[doc = "Accelerometer, allows for relative tracking of position and motion"]
                 Accelerometer => {
                     friendly_name: "Accelerometer",
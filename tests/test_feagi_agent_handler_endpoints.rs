@@ -26,3 +26,57 @@ fn unregistered_session_ids_not_in_registered_agents() {
         "Handler should not contain unregistered session IDs"
     );
 }
+
+#[test]
+fn stream_compression_is_only_negotiated_with_agents_that_support_it() {
+    use feagi_agent::server::StreamCompressionPolicy;
+    use feagi_agent::AgentCapabilities;
+    use feagi_serialization::PayloadCompression;
+
+    let mut handler = build_handler();
+    assert_eq!(
+        handler.get_stream_compression_policy().algorithm,
+        PayloadCompression::None
+    );
+
+    let policy = StreamCompressionPolicy {
+        algorithm: PayloadCompression::Lz4,
+        min_size_threshold: 100,
+        compress_sensory: false,
+        compress_motor: true,
+        compress_visualization: true,
+        xyzp_delta_keyframe_interval: 30,
+        ..StreamCompressionPolicy::default()
+    };
+    handler.set_stream_compression_policy(policy.clone());
+    let capabilities = [
+        AgentCapabilities::SendSensorData,
+        AgentCapabilities::ReceiveMotorData,
+    ];
+
+    // Legacy agents advertise nothing and keep raw payloads
+    assert!(!policy.negotiate(&[], false, &capabilities).is_enabled());
+    assert!(!policy
+        .negotiate(&[PayloadCompression::Zstd], false, &capabilities)
+        .is_enabled());
+
+    let negotiated = policy.negotiate(&PayloadCompression::SUPPORTED, false, &capabilities);
+    assert_eq!(negotiated.algorithm(), PayloadCompression::Lz4);
+    assert_eq!(negotiated.min_size_threshold(), 100);
+    assert!(negotiated.is_enabled_for(AgentCapabilities::ReceiveMotorData));
+    assert!(!negotiated.is_enabled_for(AgentCapabilities::SendSensorData));
+    // Visualization was not requested, so it stays off
+    assert!(!negotiated.is_enabled_for(AgentCapabilities::ReceiveNeuronVisualizations));
+    assert_eq!(negotiated.xyzp_delta_keyframe_interval(), None);
+
+    // Delta coding is negotiated independently of compression
+    let delta_only = policy.negotiate(&[], true, &capabilities);
+    assert_eq!(delta_only.algorithm(), PayloadCompression::None);
+    assert_eq!(delta_only.xyzp_delta_keyframe_interval(), Some(30));
+    assert!(delta_only
+        .delta_encoder_for(AgentCapabilities::ReceiveMotorData)
+        .is_some());
+    assert!(delta_only
+        .delta_encoder_for(AgentCapabilities::SendSensorData)
+        .is_none());
+}